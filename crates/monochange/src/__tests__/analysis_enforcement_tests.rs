@@ -0,0 +1,135 @@
+use std::path::PathBuf;
+
+use monochange_core::Ecosystem;
+use monochange_core::PublishState;
+use semver::Version;
+
+use super::*;
+
+fn under_declared() -> UnderDeclaredBump {
+	UnderDeclaredBump {
+		package: "core".to_string(),
+		declared: BumpSeverity::Patch,
+		implied: BumpSeverity::Major,
+		baseline: "core/v1.0.0".to_string(),
+		summary: "function `render` removed".to_string(),
+	}
+}
+
+fn evidence() -> AnalysisEvidence {
+	AnalysisEvidence {
+		assessments: vec![CompatibilityAssessment {
+			package_id: "cargo:crates/core/Cargo.toml".to_string(),
+			provider_id: monochange_analysis::SEMANTIC_ANALYSIS_PROVIDER_ID.to_string(),
+			severity: BumpSeverity::Major,
+			confidence: "medium".to_string(),
+			summary: "function `render` removed".to_string(),
+			evidence_location: Some("src/lib.rs".to_string()),
+		}],
+		under_declared: vec![under_declared()],
+	}
+}
+
+#[test]
+fn enforce_warns_without_raising_the_bump() {
+	let (floor, warnings) = evidence()
+		.enforce(AnalysisEnforcement::Warn)
+		.unwrap_or_else(|error| panic!("enforce: {error}"));
+
+	assert!(floor.is_empty());
+	assert_eq!(
+		warnings,
+		[
+			"`core` declares a patch bump but semantic analysis since `core/v1.0.0` implies major: function `render` removed"
+		]
+	);
+}
+
+#[test]
+fn enforce_raises_the_bump_to_the_evidence() {
+	let (floor, warnings) = evidence()
+		.enforce(AnalysisEnforcement::Raise)
+		.unwrap_or_else(|error| panic!("enforce: {error}"));
+
+	assert_eq!(floor.len(), 1);
+	assert_eq!(
+		warnings,
+		[
+			"raised `core` from patch to major because semantic analysis since `core/v1.0.0` found: function `render` removed"
+		]
+	);
+}
+
+#[test]
+fn enforce_fails_only_when_a_package_is_under_declared() {
+	let error = evidence()
+		.enforce(AnalysisEnforcement::Error)
+		.err()
+		.unwrap_or_else(|| panic!("expected enforcement error"))
+		.to_string();
+	assert!(
+		error.contains(
+			"changesets declare weaker bumps than semantic analysis implies:\n- `core` declares a patch bump"
+		),
+		"{error}"
+	);
+
+	let satisfied = AnalysisEvidence {
+		under_declared: Vec::new(),
+		..evidence()
+	};
+	let (floor, warnings) = satisfied
+		.enforce(AnalysisEnforcement::Error)
+		.unwrap_or_else(|error| panic!("enforce: {error}"));
+	assert!(floor.is_empty());
+	assert!(warnings.is_empty());
+}
+
+#[test]
+fn declared_bump_covers_every_member_of_a_version_group() {
+	let package = |id: &str, group: Option<&str>| {
+		let mut package = PackageRecord::new(
+			Ecosystem::Cargo,
+			id,
+			PathBuf::from(format!("/repo/crates/{id}/Cargo.toml")),
+			PathBuf::from("/repo"),
+			Some(Version::new(1, 0, 0)),
+			PublishState::Public,
+		);
+		package.version_group_id = group.map(ToString::to_string);
+		package
+	};
+	let packages = vec![
+		package("core", Some("sdk")),
+		package("macros", Some("sdk")),
+		package("cli", None),
+	];
+	let signal = |package: &PackageRecord, bump| {
+		ChangeSignal {
+			package_id: package.id.clone(),
+			requested_bump: Some(bump),
+			explicit_version: None,
+			change_origin: "direct-change".to_string(),
+			evidence_refs: Vec::new(),
+			notes: None,
+			details: None,
+			change_type: None,
+			caused_by: Vec::new(),
+			source_path: PathBuf::from(".changeset/change.md"),
+		}
+	};
+	let signals = [
+		signal(&packages[0], BumpSeverity::Patch),
+		signal(&packages[1], BumpSeverity::Major),
+	];
+	let declared = declared_bumps(&packages, &signals);
+
+	assert_eq!(
+		declared_bump(&declared, &packages, &packages[0]),
+		BumpSeverity::Major
+	);
+	assert_eq!(
+		declared_bump(&declared, &packages, &packages[2]),
+		BumpSeverity::None
+	);
+}
@@ -2,6 +2,7 @@
 use std::path::Path;
 
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 use monochange_test_helpers::copy_directory;
 use monochange_test_helpers::fs::fixture_path_from;
 use monochange_test_helpers::git::git;
@@ -109,6 +110,7 @@ fn tag_prefix_for_identity_matches_primary_and_namespaced_tags() {
 		tag: true,
 		release: true,
 		version_format: VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
 		members: vec!["core".to_string()],
 	};
 	let primary = EffectiveReleaseIdentity {
@@ -118,11 +120,20 @@ fn tag_prefix_for_identity_matches_primary_and_namespaced_tags() {
 		tag: true,
 		release: true,
 		version_format: VersionFormat::Primary,
+		version_scheme: VersionScheme::Semver,
 		members: vec!["core".to_string(), "app".to_string()],
 	};
 
+	let calver = EffectiveReleaseIdentity {
+		version_scheme: VersionScheme::Calver {
+			format: monochange_core::CalverFormat::default(),
+		},
+		..namespaced.clone()
+	};
+
 	assert_eq!(tag_prefix_for_identity(&namespaced), "core/v");
 	assert_eq!(tag_prefix_for_identity(&primary), "v");
+	assert_eq!(tag_prefix_for_identity(&calver), "core/");
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -217,6 +228,7 @@ async fn default_branch_and_release_tag_resolution_cover_origin_head_and_missing
 		tag: false,
 		release: false,
 		version_format: VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
 		members: vec!["core".to_string()],
 	};
 	assert_eq!(
@@ -291,6 +303,7 @@ async fn latest_release_tag_and_text_rendering_cover_warning_branches() {
 		tag: true,
 		release: true,
 		version_format: VersionFormat::Primary,
+		version_scheme: VersionScheme::Semver,
 		members: vec!["core".to_string()],
 	};
 	let tag_error = latest_release_tag_for_identity(missing_repo.path(), Some(&identity))
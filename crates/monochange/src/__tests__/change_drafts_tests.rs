@@ -0,0 +1,184 @@
+use std::fs;
+use std::path::PathBuf;
+
+use monochange_core::Ecosystem;
+use monochange_test_helpers::copy_directory;
+use monochange_test_helpers::git;
+use tempfile::tempdir;
+
+use super::*;
+
+fn fixture_path(relative: &str) -> PathBuf {
+	monochange_test_helpers::fs::fixture_path_from(env!("CARGO_MANIFEST_DIR"), relative)
+}
+
+fn fixture_configuration() -> WorkspaceConfiguration {
+	load_workspace_configuration(&fixture_path("analysis/cargo-public-api-diff/after"))
+		.unwrap_or_else(|error| panic!("configuration: {error}"))
+}
+
+fn setup_analysis_workspace() -> tempfile::TempDir {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	copy_directory(
+		&fixture_path("analysis/cargo-public-api-diff/before"),
+		tempdir.path(),
+	);
+	git(tempdir.path(), &["init"]);
+	git(tempdir.path(), &["config", "user.name", "monochange-tests"]);
+	git(
+		tempdir.path(),
+		&["config", "user.email", "monochange-tests@example.com"],
+	);
+	git(tempdir.path(), &["add", "."]);
+	git(tempdir.path(), &["commit", "-m", "base"]);
+	copy_directory(
+		&fixture_path("analysis/cargo-public-api-diff/after"),
+		tempdir.path(),
+	);
+	tempdir
+}
+
+fn change(
+	category: SemanticChangeCategory,
+	kind: SemanticChangeKind,
+	item_kind: &str,
+	item_path: &str,
+) -> SemanticChange {
+	SemanticChange {
+		category,
+		kind,
+		item_kind: item_kind.to_string(),
+		item_path: item_path.to_string(),
+		summary: format!("{item_kind} `{item_path}` changed"),
+		file_path: PathBuf::from("src/lib.rs"),
+		before_signature: None,
+		after_signature: None,
+	}
+}
+
+fn package_analysis(semantic_changes: Vec<SemanticChange>) -> PackageChangeAnalysis {
+	PackageChangeAnalysis {
+		package_id: "core".to_string(),
+		package_record_id: "cargo:crates/core/Cargo.toml".to_string(),
+		package_name: "core".to_string(),
+		ecosystem: Ecosystem::Cargo,
+		analyzer_id: Some("cargo/public-api".to_string()),
+		changed_files: vec![PathBuf::from("src/lib.rs")],
+		semantic_changes,
+		warnings: Vec::new(),
+	}
+}
+
+#[test]
+fn build_changeset_draft_follows_the_strongest_semantic_change() {
+	let configuration = fixture_configuration();
+	let analysis = package_analysis(vec![
+		change(
+			SemanticChangeCategory::Dependency,
+			SemanticChangeKind::Added,
+			"dependency",
+			"tracing",
+		),
+		change(
+			SemanticChangeCategory::PublicApi,
+			SemanticChangeKind::Added,
+			"struct",
+			"Greeter",
+		),
+	]);
+
+	let draft = build_changeset_draft(&configuration, &analysis);
+
+	assert_eq!(draft.bump, BumpSeverity::Minor);
+	assert_eq!(draft.change_type.as_deref(), Some("feat"));
+	assert_eq!(draft.summary, "struct `Greeter` changed");
+}
+
+#[test]
+fn build_changeset_draft_marks_removed_api_as_breaking() {
+	let configuration = fixture_configuration();
+	let analysis = package_analysis(vec![change(
+		SemanticChangeCategory::PublicApi,
+		SemanticChangeKind::Removed,
+		"function",
+		"render",
+	)]);
+
+	let draft = build_changeset_draft(&configuration, &analysis);
+
+	assert_eq!(draft.bump, BumpSeverity::Major);
+	assert_eq!(draft.change_type.as_deref(), Some("breaking"));
+}
+
+#[test]
+fn build_changeset_draft_falls_back_to_a_patch_without_semantic_changes() {
+	let configuration = fixture_configuration();
+
+	let draft = build_changeset_draft(&configuration, &package_analysis(Vec::new()));
+
+	assert_eq!(draft.bump, BumpSeverity::Patch);
+	assert_eq!(draft.change_type, None);
+	assert_eq!(draft.summary, "update core");
+	assert_eq!(draft.details, None);
+}
+
+#[test]
+fn render_symbol_details_lists_symbols_by_kind() {
+	let details = render_symbol_details(&[
+		change(
+			SemanticChangeCategory::PublicApi,
+			SemanticChangeKind::Modified,
+			"function",
+			"greet",
+		),
+		change(
+			SemanticChangeCategory::PublicApi,
+			SemanticChangeKind::Added,
+			"struct",
+			"Greeter",
+		),
+		change(
+			SemanticChangeCategory::Dependency,
+			SemanticChangeKind::Added,
+			"dependency",
+			"tracing",
+		),
+	]);
+
+	assert_eq!(
+		details.as_deref(),
+		Some(
+			"Added:\n\n- dependency `tracing`\n- struct `Greeter`\n\nChanged:\n\n- function `greet`"
+		)
+	);
+}
+
+#[test]
+fn draft_changesets_from_analysis_skips_packages_with_pending_changesets() {
+	let tempdir = setup_analysis_workspace();
+	let root = tempdir.path();
+
+	let report = draft_changesets_from_analysis(root, &ChangeFrame::WorkingDirectory)
+		.unwrap_or_else(|error| panic!("draft: {error}"));
+
+	assert!(report.covered.is_empty());
+	let [draft] = report.drafts.as_slice() else {
+		panic!("expected one draft, got {:?}", report.drafts);
+	};
+	assert_eq!(draft.package, "core");
+	assert_eq!(draft.bump, BumpSeverity::Major);
+	assert_eq!(draft.change_type.as_deref(), Some("breaking"));
+	assert!(draft.path.starts_with(".changeset"));
+	let contents = fs::read_to_string(root.join(&draft.path))
+		.unwrap_or_else(|error| panic!("read draft: {error}"));
+	assert!(contents.starts_with("---\ncore: breaking\n---\n\n# function `greet` modified\n"));
+	assert!(contents.contains("- struct `Greeter`"));
+	assert!(contents.contains("- function `greet`"));
+
+	let report = draft_changesets_from_analysis(root, &ChangeFrame::WorkingDirectory)
+		.unwrap_or_else(|error| panic!("second draft: {error}"));
+
+	assert!(report.drafts.is_empty());
+	assert_eq!(report.covered, ["core"]);
+	assert!(report.render_text().contains("already covered by pending changesets: core"));
+}
@@ -4,8 +4,11 @@ use std::process::Command;
 
 use monochange_core::PackageDefinition;
 use monochange_core::PackageType;
+use monochange_core::PreStablePolicy;
+use monochange_core::PropagationByKind;
 use monochange_core::SourceProvider as ProviderKind;
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 
 use super::*;
 
@@ -31,6 +34,9 @@ fn sample_package() -> PackageDefinition {
 		release: true,
 		publish: monochange_core::PublishSettings::default(),
 		version_format: VersionFormat::Primary,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
+		propagation_by_kind: PropagationByKind::default(),
 	}
 }
 
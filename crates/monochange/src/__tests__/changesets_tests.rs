@@ -4,6 +4,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use monochange_core::BumpSeverity;
+use monochange_core::ChangeSource;
 use monochange_core::ChangesetContext;
 use monochange_core::ChangesetRevision;
 use monochange_core::ChangesetTargetKind;
@@ -14,11 +15,15 @@ use monochange_core::HostedReviewRequestKind;
 use monochange_core::HostedReviewRequestRef;
 use monochange_core::HostingCapabilities;
 use monochange_core::HostingProviderKind;
+use monochange_test_helpers::git::git;
+use monochange_test_helpers::git::git_output_trimmed;
 
 use super::batch_git_log;
 use super::build_prepared_changesets;
 use super::diagnose_changesets;
 use super::discover_changeset_paths;
+use super::discover_configured_changeset_paths;
+use super::no_pending_changes_error;
 use super::parse_batch_git_log_bytes;
 use super::parse_batch_git_log_output;
 use super::render_changeset_diagnostics;
@@ -377,6 +382,7 @@ fn build_prepared_changesets_moves_loaded_fields_into_prepared_changesets() {
 			caused_by: vec!["core".to_string()],
 		}],
 		signals: Vec::new(),
+		source_revision: None,
 	}];
 
 	let prepared = build_prepared_changesets(tempdir.path(), loaded);
@@ -398,6 +404,151 @@ fn build_prepared_changesets_moves_loaded_fields_into_prepared_changesets() {
 	assert_eq!(context.provider, HostingProviderKind::GenericGit);
 }
 
+#[test]
+fn build_prepared_changesets_uses_the_source_commit_for_commit_changesets() {
+	let tempdir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let revision = ChangesetRevision {
+		actor: None,
+		commit: Some(HostedCommitRef {
+			provider: HostingProviderKind::GenericGit,
+			host: None,
+			sha: "0123456789abcdef".to_string(),
+			short_sha: "0123456".to_string(),
+			url: None,
+			authored_at: None,
+			committed_at: None,
+			author_name: Some("Ada".to_string()),
+			author_email: None,
+		}),
+		review_request: None,
+	};
+	let loaded = vec![monochange_config::LoadedChangesetFile {
+		path: PathBuf::from("commit:0123456"),
+		summary: Some("add streaming reads".to_string()),
+		details: None,
+		targets: Vec::new(),
+		signals: Vec::new(),
+		source_revision: Some(revision.clone()),
+	}];
+
+	let prepared = build_prepared_changesets(tempdir.path(), loaded);
+
+	assert_eq!(prepared.len(), 1);
+	assert_eq!(prepared[0].path, PathBuf::from("commit:0123456"));
+	let context = prepared[0]
+		.context
+		.as_ref()
+		.unwrap_or_else(|| panic!("expected commit context"));
+	assert_eq!(context.introduced.as_ref(), Some(&revision));
+	assert_eq!(context.last_updated.as_ref(), Some(&revision));
+}
+
+#[test]
+fn discover_configured_changeset_paths_follows_configured_sources() {
+	let fixture = setup_fixture("monochange/changeset-policy-base");
+	let mut configuration = monochange_config::load_workspace_configuration(fixture.path())
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+
+	let error = discover_configured_changeset_paths(fixture.path(), &configuration, false)
+		.err()
+		.unwrap_or_else(|| panic!("expected missing changesets error"));
+	assert!(error.to_string().contains("no markdown changesets"));
+
+	configuration.changesets.sources = vec![ChangeSource::Files, ChangeSource::ConventionalCommits];
+	let paths = discover_configured_changeset_paths(fixture.path(), &configuration, false)
+		.unwrap_or_else(|error| panic!("paths: {error}"));
+	assert!(paths.is_empty());
+	assert_eq!(
+		no_pending_changes_error(&configuration).to_string(),
+		"config error: no markdown changesets under .changeset or conventional commits since \
+		 the last release found"
+	);
+
+	fs::create_dir_all(fixture.path().join(".changeset"))
+		.unwrap_or_else(|error| panic!("create changeset dir: {error}"));
+	fs::write(
+		fixture.path().join(".changeset/feature.md"),
+		"---\ncore: patch\n---\n\n# feature\n",
+	)
+	.unwrap_or_else(|error| panic!("write changeset: {error}"));
+	configuration.changesets.sources = vec![ChangeSource::ConventionalCommits];
+	let paths = discover_configured_changeset_paths(fixture.path(), &configuration, false)
+		.unwrap_or_else(|error| panic!("paths: {error}"));
+	assert!(paths.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn diagnose_changesets_lists_commit_trailer_changesets() {
+	let fixture = setup_fixture("monochange/changeset-policy-base");
+	let root = fixture.path();
+	let config_path = root.join("monochange.toml");
+	let config = fs::read_to_string(&config_path)
+		.unwrap_or_else(|error| panic!("read configuration: {error}"));
+	fs::write(
+		&config_path,
+		format!(
+			"[changesets]\nsources = [\"files\", \"conventional_commits\", \"commit_trailers\"]\n\n{config}"
+		),
+	)
+	.unwrap_or_else(|error| panic!("write configuration: {error}"));
+	git(root, &["init"]);
+	git(root, &["config", "user.name", "monochange-tests"]);
+	git(
+		root,
+		&["config", "user.email", "monochange-tests@example.com"],
+	);
+	git(root, &["add", "."]);
+	git(
+		root,
+		&[
+			"commit",
+			"-m",
+			"fix(core): handle empty input\n\nChangeset: core: minor",
+		],
+	);
+	let sha = git_output_trimmed(root, &["rev-parse", "HEAD"]);
+
+	let report = diagnose_changesets(root, &[])
+		.await
+		.unwrap_or_else(|error| panic!("diagnose changesets: {error}"));
+
+	let short_sha = sha.chars().take(7).collect::<String>();
+	assert_eq!(
+		report.requested_changesets,
+		vec![PathBuf::from(format!("commit:{short_sha}"))]
+	);
+	let [changeset] = report.changesets.as_slice() else {
+		panic!("expected one commit changeset, got {:?}", report.changesets);
+	};
+	assert_eq!(
+		changeset.summary.as_deref(),
+		Some("fix(core): handle empty input")
+	);
+	let [target] = changeset.targets.as_slice() else {
+		panic!("expected one target, got {:?}", changeset.targets);
+	};
+	assert_eq!(target.id, "core");
+	assert_eq!(target.bump, Some(BumpSeverity::Minor));
+	assert_eq!(target.origin, "commit-trailer");
+	let introduced = changeset
+		.context
+		.as_ref()
+		.and_then(|context| context.introduced.as_ref())
+		.and_then(|revision| revision.commit.as_ref())
+		.unwrap_or_else(|| panic!("expected originating commit"));
+	assert_eq!(introduced.sha, sha);
+
+	let requested = diagnose_changesets(root, &[format!("commit:{short_sha}")])
+		.await
+		.unwrap_or_else(|error| panic!("diagnose requested commit: {error}"));
+	assert_eq!(requested.changesets.len(), 1);
+	let error = diagnose_changesets(root, &["commit:ffffff0".to_string()])
+		.await
+		.err()
+		.unwrap_or_else(|| panic!("expected missing commit changeset error"));
+	assert!(error.to_string().contains("`commit:ffffff0` does not exist"));
+}
+
 #[test]
 fn discover_changeset_paths_reports_io_for_non_directory_changeset_path() {
 	let tempdir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
@@ -366,6 +366,7 @@ fn input_options_document_common_input_names() {
 		"mode",
 		"ci",
 		"interactive",
+		"from_analysis",
 		"bump",
 		"version",
 		"reason",
@@ -242,6 +242,7 @@ fn sample_configuration(root: &Path) -> monochange_core::WorkspaceConfiguration
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -252,6 +253,9 @@ fn sample_configuration(root: &Path) -> monochange_core::WorkspaceConfiguration
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	}
 }
 
@@ -276,6 +280,7 @@ fn sample_prepared_release() -> PreparedRelease {
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -291,6 +296,7 @@ fn sample_prepared_release() -> PreparedRelease {
 		updated_changelogs: Vec::new(),
 		deleted_changesets: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	}
 }
 
@@ -306,6 +312,7 @@ fn sample_prepared_release_with_versions() -> PreparedRelease {
 					planned_version: Some(semver::Version::new(1, 2, 0)),
 					group_id: Some("sdk".to_string()),
 					reasons: vec!["feature".to_string()],
+					causes: Vec::new(),
 					upstream_sources: Vec::new(),
 					warnings: Vec::new(),
 				},
@@ -316,6 +323,7 @@ fn sample_prepared_release_with_versions() -> PreparedRelease {
 					planned_version: Some(semver::Version::new(1, 2, 1)),
 					group_id: Some("sdk".to_string()),
 					reasons: vec!["fix".to_string()],
+					causes: Vec::new(),
 					upstream_sources: Vec::new(),
 					warnings: Vec::new(),
 				},
@@ -326,6 +334,7 @@ fn sample_prepared_release_with_versions() -> PreparedRelease {
 					planned_version: Some(semver::Version::new(9, 9, 9)),
 					group_id: None,
 					reasons: Vec::new(),
+					causes: Vec::new(),
 					upstream_sources: Vec::new(),
 					warnings: Vec::new(),
 				},
@@ -339,6 +348,7 @@ fn sample_prepared_release_with_versions() -> PreparedRelease {
 				recommended_bump: BumpSeverity::Minor,
 			}],
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -354,6 +364,7 @@ fn sample_prepared_release_with_versions() -> PreparedRelease {
 		updated_changelogs: Vec::new(),
 		deleted_changesets: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	}
 }
 
@@ -688,6 +699,7 @@ fn render_cli_command_results_include_release_details_policy_and_logs() {
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -707,6 +719,7 @@ fn render_cli_command_results_include_release_details_policy_and_logs() {
 			members: Vec::new(),
 			rendered_title: "core v1.2.3".to_string(),
 			rendered_changelog_title: "core v1.2.3".to_string(),
+			prerelease: None,
 		}],
 		changed_files: vec![PathBuf::from("Cargo.toml")],
 		changelogs: Vec::new(),
@@ -714,6 +727,7 @@ fn render_cli_command_results_include_release_details_policy_and_logs() {
 		deleted_changesets: vec![PathBuf::from(".changeset/feature.md")],
 		package_publications: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	});
 	context.prepared_file_diffs = vec![PreparedFileDiff {
 		path: PathBuf::from("Cargo.toml"),
@@ -1853,6 +1867,7 @@ async fn execute_cli_command_reports_command_failures_after_progress_callbacks()
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -1863,6 +1878,9 @@ async fn execute_cli_command_reports_command_failures_after_progress_callbacks()
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let error = execute_cli_command(
 		tempdir.path(),
@@ -1888,6 +1906,7 @@ fn build_release_template_value_serializes_file_diffs() {
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -1903,6 +1922,7 @@ fn build_release_template_value_serializes_file_diffs() {
 		deleted_changesets: Vec::new(),
 		package_publications: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	});
 	context.prepared_file_diffs = vec![PreparedFileDiff {
 		path: PathBuf::from("Cargo.toml"),
@@ -2314,6 +2334,7 @@ fn render_cli_command_result_and_markdown_include_release_target_details_without
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -2333,6 +2354,7 @@ fn render_cli_command_result_and_markdown_include_release_target_details_without
 			members: Vec::new(),
 			rendered_title: "core v1.2.3".to_string(),
 			rendered_changelog_title: "core v1.2.3".to_string(),
+			prerelease: None,
 		}],
 		changed_files: vec![PathBuf::from("Cargo.toml")],
 		changelogs: Vec::new(),
@@ -2340,6 +2362,7 @@ fn render_cli_command_result_and_markdown_include_release_target_details_without
 		deleted_changesets: Vec::new(),
 		package_publications: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	});
 	context.changeset_policy_evaluation = Some(ChangesetPolicyEvaluation {
 		enforce: false,
@@ -283,14 +283,31 @@ fn command_step_with_default_inputs_adds_and_inherits_expected_inputs() {
 	let step = command_step_with_default_inputs("PrepareRelease", &mut command_inputs)
 		.unwrap_or_else(|error| panic!("step should be created: {error}"));
 
-	assert_eq!(command_inputs.len(), 1);
-	assert_eq!(command_inputs[0].name, "format");
+	assert_eq!(
+		command_inputs
+			.iter()
+			.map(|input| input.name.as_str())
+			.collect::<Vec<_>>(),
+		vec![
+			"format",
+			"pre",
+			"pre_package",
+			"exit_pre",
+			"snapshot",
+			"snapshot_template",
+			"graduate",
+		]
+	);
 	assert_eq!(command_inputs[0].kind, "choice");
 	assert_eq!(command_inputs[0].choices[0], "text");
 	assert_eq!(
 		step.inputs.get("format"),
 		Some(&CliStepInputValue::Inherited)
 	);
+	assert_eq!(
+		step.inputs.get("exit_pre"),
+		Some(&CliStepInputValue::Inherited)
+	);
 	assert_config_error(
 		command_step_with_default_inputs("NotARealStep", &mut command_inputs).map(|_| ()),
 		"unknown CLI step type `NotARealStep`",
@@ -219,6 +219,7 @@ fn build_prepared_changesets_uses_generic_context_without_a_git_repository() {
 			caused_by: Vec::new(),
 		}],
 		signals: Vec::new(),
+		source_revision: None,
 	}];
 
 	let prepared = build_prepared_changesets(tempdir.path(), &loaded);
@@ -0,0 +1,114 @@
+use monochange_config::load_workspace_configuration;
+use monochange_test_helpers::fs::setup_scenario_workspace_from;
+use tempfile::TempDir;
+
+use super::*;
+use crate::discover_workspace;
+
+fn setup_policy_workspace() -> (TempDir, WorkspaceConfiguration, Vec<PackageRecord>) {
+	let tempdir =
+		setup_scenario_workspace_from(env!("CARGO_MANIFEST_DIR"), "pre-stable-policy/workspace");
+	let configuration = load_workspace_configuration(tempdir.path())
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let packages = discover_workspace(tempdir.path())
+		.unwrap_or_else(|error| panic!("discovery: {error}"))
+		.packages;
+	(tempdir, configuration, packages)
+}
+
+fn request(owners: &[&str]) -> GraduationRequest {
+	GraduationRequest {
+		owners: owners.iter().map(ToString::to_string).collect(),
+	}
+}
+
+#[test]
+fn graduation_request_reads_step_inputs() {
+	let request = GraduationRequest::from_step_inputs(&BTreeMap::from([(
+		"graduate".to_string(),
+		vec!["cli".to_string(), " ".to_string()],
+	)]));
+	assert_eq!(request.owners, vec!["cli".to_string()]);
+	assert!(request.is_requested());
+	assert!(!GraduationRequest::from_step_inputs(&BTreeMap::new()).is_requested());
+}
+
+#[test]
+fn planner_graduations_map_packages_and_groups_to_planner_ids() {
+	let (_tempdir, configuration, packages) = setup_policy_workspace();
+	let cli_id = packages
+		.iter()
+		.find(|package| package.name == "policy-cli")
+		.unwrap_or_else(|| panic!("expected policy-cli"))
+		.id
+		.clone();
+
+	let graduations = request(&["cli", "plugin"])
+		.planner_graduations(&configuration, &packages)
+		.unwrap_or_else(|error| panic!("graduations: {error}"));
+
+	assert_eq!(
+		graduations,
+		BTreeMap::from([
+			(cli_id, "cli".to_string()),
+			("sdk".to_string(), "plugin".to_string()),
+		])
+	);
+}
+
+#[test]
+fn planner_graduations_check_every_member_of_a_linked_group() {
+	let tempdir =
+		setup_scenario_workspace_from(env!("CARGO_MANIFEST_DIR"), "linked-groups/workspace");
+	let configuration = load_workspace_configuration(tempdir.path())
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let packages = discover_workspace(tempdir.path())
+		.unwrap_or_else(|error| panic!("discovery: {error}"))
+		.packages;
+
+	let error = request(&["tools"])
+		.planner_graduations(&configuration, &packages)
+		.err()
+		.unwrap_or_else(|| panic!("expected a stable linked member to be rejected"));
+	assert!(
+		error
+			.to_string()
+			.contains("cannot graduate `tools`: `linked-core` is already at 1.4.0")
+	);
+
+	let docs_id = packages
+		.iter()
+		.find(|package| package.name == "linked-docs")
+		.unwrap_or_else(|| panic!("expected linked-docs"))
+		.id
+		.clone();
+	let graduations = request(&["docs"])
+		.planner_graduations(&configuration, &packages)
+		.unwrap_or_else(|error| panic!("graduations: {error}"));
+	assert_eq!(graduations, BTreeMap::from([(docs_id, "docs".to_string())]));
+}
+
+#[test]
+fn planner_graduations_reject_unknown_and_stable_owners() {
+	let (_tempdir, configuration, packages) = setup_policy_workspace();
+
+	let error = request(&["missing"])
+		.planner_graduations(&configuration, &packages)
+		.err()
+		.unwrap_or_else(|| panic!("expected unknown owner to be rejected"));
+	assert!(
+		error
+			.to_string()
+			.contains("graduation target `missing` does not match a configured package or group")
+	);
+
+	let error = request(&["stable"])
+		.planner_graduations(&configuration, &packages)
+		.err()
+		.unwrap_or_else(|| panic!("expected stable owner to be rejected"));
+	assert!(
+		error
+			.to_string()
+			.contains("cannot graduate `stable`: `policy-stable` is already at 1.2.0")
+	);
+}
@@ -10,8 +10,11 @@ use monochange_core::GroupDefinition;
 use monochange_core::MonochangeError;
 use monochange_core::PackageDefinition;
 use monochange_core::PackageType;
+use monochange_core::PreStablePolicy;
+use monochange_core::PropagationByKind;
 use monochange_core::PublishSettings;
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 use monochange_core::WorkspaceConfiguration;
 use monochange_core::WorkspaceDefaults;
 use monochange_test_helpers::current_test_name;
@@ -62,6 +65,7 @@ fn empty_configuration() -> WorkspaceConfiguration {
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: ChangesetSettings::default(),
 		source: None,
@@ -72,6 +76,9 @@ fn empty_configuration() -> WorkspaceConfiguration {
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	}
 }
 
@@ -283,6 +290,9 @@ fn build_selectable_targets_includes_standalone_packages_before_group_members()
 				release: true,
 				publish: PublishSettings::default(),
 				version_format: VersionFormat::Primary,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 			},
 			PackageDefinition {
 				id: "core".to_string(),
@@ -301,6 +311,9 @@ fn build_selectable_targets_includes_standalone_packages_before_group_members()
 				release: true,
 				publish: PublishSettings::default(),
 				version_format: VersionFormat::Primary,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 			},
 			PackageDefinition {
 				id: "web".to_string(),
@@ -319,6 +332,9 @@ fn build_selectable_targets_includes_standalone_packages_before_group_members()
 				release: true,
 				publish: PublishSettings::default(),
 				version_format: VersionFormat::Primary,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 			},
 		],
 		groups: vec![GroupDefinition {
@@ -334,7 +350,10 @@ fn build_selectable_targets_includes_standalone_packages_before_group_members()
 			tag: true,
 			release: true,
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
 		}],
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: ChangesetSettings::default(),
 		source: None,
@@ -345,6 +364,9 @@ fn build_selectable_targets_includes_standalone_packages_before_group_members()
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	};
 	let displays = build_selectable_targets(&configuration)
 		.into_iter()
@@ -413,7 +435,10 @@ fn build_selectable_targets_handles_group_with_empty_packages() {
 			tag: true,
 			release: true,
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
 		}],
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: ChangesetSettings::default(),
 		source: None,
@@ -424,6 +449,9 @@ fn build_selectable_targets_handles_group_with_empty_packages() {
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	};
 	let targets = build_selectable_targets(&configuration);
 	assert_eq!(targets.len(), 1);
@@ -456,6 +484,9 @@ fn build_selectable_targets_with_only_standalone_packages() {
 				release: true,
 				publish: PublishSettings::default(),
 				version_format: VersionFormat::Primary,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 			},
 			PackageDefinition {
 				id: "beta".to_string(),
@@ -474,9 +505,13 @@ fn build_selectable_targets_with_only_standalone_packages() {
 				release: true,
 				publish: PublishSettings::default(),
 				version_format: VersionFormat::Primary,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 			},
 		],
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: ChangesetSettings::default(),
 		source: None,
@@ -487,6 +522,9 @@ fn build_selectable_targets_with_only_standalone_packages() {
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	};
 	let targets = build_selectable_targets(&configuration);
 	let ids: Vec<&str> = targets.iter().map(|t| t.id.as_str()).collect();
@@ -562,8 +600,12 @@ fn build_selectable_targets_deduplicates_and_sorts_change_types() {
 			release: true,
 			publish: PublishSettings::default(),
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		}],
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: ChangesetSettings::default(),
 		source: None,
@@ -574,6 +616,9 @@ fn build_selectable_targets_deduplicates_and_sorts_change_types() {
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	};
 	let target = build_selectable_targets(&configuration)
 		.into_iter()
@@ -36,8 +36,11 @@ use monochange_core::CliInputKind;
 use monochange_core::CliStepDefinition;
 use monochange_core::Ecosystem;
 use monochange_core::GroupChangelogInclude;
+use monochange_core::PreStablePolicy;
 use monochange_core::PreparedChangesetTarget;
+use monochange_core::PropagationByKind;
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 use monochange_test_helpers::copy_directory;
 use monochange_test_helpers::current_test_name;
 use monochange_test_helpers::snapshot_settings;
@@ -2526,7 +2529,7 @@ fn changes_add_requires_package_or_interactive_mode() {
 	assert!(
 		error
 			.to_string()
-			.contains("requires at least one `--package` value or `--interactive` mode")
+			.contains("requires at least one `--package` value, `--interactive` mode, or `--from-analysis`")
 	);
 }
 
@@ -5218,6 +5221,7 @@ fn sample_release_manifest_for_commit_message(
 			members: vec!["monochange".to_string(), "monochange_core".to_string()],
 			rendered_title: "monochange 1.2.3".to_string(),
 			rendered_changelog_title: "1.2.3".to_string(),
+			prerelease: None,
 		}],
 		released_packages: vec!["monochange".to_string(), "monochange_core".to_string()],
 		changed_files: vec![Path::new("Cargo.lock").to_path_buf()],
@@ -5296,6 +5300,7 @@ fn sample_release_record_for_discovery_text() -> monochange_core::ReleaseRecord
 			release: true,
 			tag_name: "v1.2.3".to_string(),
 			members: vec!["monochange".to_string(), "monochange_core".to_string()],
+			prerelease: None,
 		}],
 		released_packages: vec!["monochange".to_string(), "monochange_core".to_string()],
 		changed_files: vec![Path::new("Cargo.lock").to_path_buf()],
@@ -5429,6 +5434,7 @@ fn write_release_record_file_dedupes_existing_records_with_overlapping_tags() {
 			members: Vec::new(),
 			rendered_title: String::new(),
 			rendered_changelog_title: String::new(),
+			prerelease: None,
 		}];
 		manifest.released_packages = vec![id.to_string()];
 		manifest.plan = monochange_core::ReleaseManifestPlan {
@@ -5481,6 +5487,7 @@ fn write_release_record_file_dedupes_existing_records_with_overlapping_tags() {
 			members: Vec::new(),
 			rendered_title: String::new(),
 			rendered_changelog_title: String::new(),
+			prerelease: None,
 		});
 	third.released_packages.push("other".to_string());
 	let third_path = write_release_record_file(root, None, &third)
@@ -5625,6 +5632,7 @@ fn write_release_record_file_dedupes_multiple_overlapping_records() {
 		members: Vec::new(),
 		rendered_title: String::new(),
 		rendered_changelog_title: String::new(),
+		prerelease: None,
 	}];
 	manifest_a.released_packages = vec!["sdk".to_string()];
 
@@ -5640,6 +5648,7 @@ fn write_release_record_file_dedupes_multiple_overlapping_records() {
 		members: Vec::new(),
 		rendered_title: String::new(),
 		rendered_changelog_title: String::new(),
+		prerelease: None,
 	}];
 	manifest_b.released_packages = vec!["ui".to_string()];
 
@@ -5662,6 +5671,7 @@ fn write_release_record_file_dedupes_multiple_overlapping_records() {
 			members: Vec::new(),
 			rendered_title: String::new(),
 			rendered_changelog_title: String::new(),
+			prerelease: None,
 		},
 		monochange_core::ReleaseManifestTarget {
 			id: "ui".to_string(),
@@ -5674,6 +5684,7 @@ fn write_release_record_file_dedupes_multiple_overlapping_records() {
 			members: Vec::new(),
 			rendered_title: String::new(),
 			rendered_changelog_title: String::new(),
+			prerelease: None,
 		},
 	];
 	manifest_c.released_packages = vec!["sdk".to_string(), "ui".to_string()];
@@ -5727,6 +5738,7 @@ fn write_release_record_file_reports_error_when_dedupe_cannot_remove_stale_dir()
 			members: Vec::new(),
 			rendered_title: String::new(),
 			rendered_changelog_title: String::new(),
+			prerelease: None,
 		}];
 		manifest.released_packages = vec!["sdk".to_string()];
 		manifest.plan = monochange_core::ReleaseManifestPlan {
@@ -5757,6 +5769,7 @@ fn write_release_record_file_reports_error_when_dedupe_cannot_remove_stale_dir()
 			members: Vec::new(),
 			rendered_title: String::new(),
 			rendered_changelog_title: String::new(),
+			prerelease: None,
 		}];
 		manifest.released_packages = vec!["ui".to_string()];
 		manifest.plan = monochange_core::ReleaseManifestPlan {
@@ -5798,6 +5811,7 @@ fn write_release_record_file_reports_error_when_dedupe_cannot_remove_stale_dir()
 				members: Vec::new(),
 				rendered_title: String::new(),
 				rendered_changelog_title: String::new(),
+				prerelease: None,
 			},
 			monochange_core::ReleaseManifestTarget {
 				id: "ui".to_string(),
@@ -5810,6 +5824,7 @@ fn write_release_record_file_reports_error_when_dedupe_cannot_remove_stale_dir()
 				members: Vec::new(),
 				rendered_title: String::new(),
 				rendered_changelog_title: String::new(),
+				prerelease: None,
 			},
 		];
 		manifest.released_packages = vec!["sdk".to_string(), "ui".to_string()];
@@ -9335,11 +9350,13 @@ fn build_versioned_file_updates_skips_unreleased_package_definitions() {
 			),
 			group_id: None,
 			reasons: vec!["release".to_string()],
+			causes: Vec::new(),
 			upstream_sources: Vec::new(),
 			warnings: Vec::new(),
 		}],
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -9509,6 +9526,7 @@ fn build_manifest_updates_preserve_npm_deno_and_dart_formatting() {
 					),
 					group_id: None,
 					reasons: vec!["release".to_string()],
+					causes: Vec::new(),
 					upstream_sources: Vec::new(),
 					warnings: Vec::new(),
 				}
@@ -9516,6 +9534,7 @@ fn build_manifest_updates_preserve_npm_deno_and_dart_formatting() {
 			.collect(),
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -9541,6 +9560,156 @@ fn build_manifest_updates_preserve_npm_deno_and_dart_formatting() {
 	);
 }
 
+#[test]
+fn build_npm_manifest_updates_move_pnpm_catalog_entries_for_released_packages() {
+	let fixture_root = monochange_core::normalize_path(
+		&Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/npm/workspace-pnpm-catalog"),
+	);
+	let packages = monochange_npm::discover_npm_packages(&fixture_root)
+		.unwrap_or_else(|error| panic!("discover npm packages: {error}"))
+		.packages;
+	let shared = packages
+		.iter()
+		.find(|package| package.name == "pnpm-catalog-shared")
+		.unwrap_or_else(|| panic!("shared package"));
+	let plan = monochange_core::ReleasePlan {
+		workspace_root: fixture_root.clone(),
+		decisions: vec![monochange_core::ReleaseDecision {
+			package_id: shared.id.clone(),
+			trigger_type: "changeset".to_string(),
+			recommended_bump: BumpSeverity::Minor,
+			planned_version: Some(
+				Version::parse("1.1.0").unwrap_or_else(|error| panic!("planned version: {error}")),
+			),
+			group_id: None,
+			reasons: vec!["release".to_string()],
+			causes: Vec::new(),
+			upstream_sources: Vec::new(),
+			warnings: Vec::new(),
+		}],
+		groups: Vec::new(),
+		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
+		unresolved_items: Vec::new(),
+		compatibility_evidence: Vec::new(),
+	};
+
+	let updates = crate::build_npm_manifest_updates(&packages, &plan)
+		.unwrap_or_else(|error| panic!("npm manifest updates: {error}"));
+
+	let paths = updates
+		.iter()
+		.map(|update| {
+			update
+				.path
+				.strip_prefix(&fixture_root)
+				.unwrap_or(&update.path)
+				.to_string_lossy()
+				.replace('\\', "/")
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(
+		paths,
+		vec![
+			"packages/shared/package.json".to_string(),
+			"pnpm-workspace.yaml".to_string()
+		]
+	);
+	let catalog = String::from_utf8_lossy(&updates[1].content);
+	assert!(catalog.contains("  pnpm-catalog-shared: ^1.1.0 # internal package\n"));
+	assert!(catalog.contains("    \"pnpm-catalog-shared\": \"~1.1.0\"\n"));
+}
+
+#[cfg(feature = "go")]
+#[test]
+fn build_go_manifest_updates_move_major_releases_to_suffixed_module_paths() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	for (relative, contents) in [
+		("greeter/go.mod", "module example.com/greeter\n\ngo 1.22\n"),
+		(
+			"app/go.mod",
+			"module example.com/app\n\ngo 1.22\n\nrequire example.com/greeter v1.4.0\n",
+		),
+		(
+			"app/main.go",
+			"package main\n\nimport \"example.com/greeter\"\n\nfunc main() { greeter.Greet() }\n",
+		),
+	] {
+		let path = root.join(relative);
+		fs::create_dir_all(path.parent().unwrap_or_else(|| panic!("parent")))
+			.unwrap_or_else(|error| panic!("create {relative}: {error}"));
+		fs::write(&path, contents).unwrap_or_else(|error| panic!("write {relative}: {error}"));
+	}
+	let packages = monochange_go::discover_go_modules(root)
+		.unwrap_or_else(|error| panic!("discover go modules: {error}"))
+		.packages;
+	let decision = |name: &str, version: &str| {
+		let package = packages
+			.iter()
+			.find(|package| package.name == name)
+			.unwrap_or_else(|| panic!("{name} module"));
+		monochange_core::ReleaseDecision {
+			package_id: package.id.clone(),
+			trigger_type: "changeset".to_string(),
+			recommended_bump: BumpSeverity::Major,
+			planned_version: Some(
+				Version::parse(version).unwrap_or_else(|error| panic!("planned version: {error}")),
+			),
+			group_id: None,
+			reasons: vec!["release".to_string()],
+			causes: Vec::new(),
+			upstream_sources: Vec::new(),
+			warnings: Vec::new(),
+		}
+	};
+	let plan = monochange_core::ReleasePlan {
+		workspace_root: root.to_path_buf(),
+		decisions: vec![decision("greeter", "2.0.0"), decision("app", "1.0.1")],
+		groups: Vec::new(),
+		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
+		unresolved_items: Vec::new(),
+		compatibility_evidence: Vec::new(),
+	};
+
+	let updates = crate::build_go_manifest_updates(&packages, &plan)
+		.unwrap_or_else(|error| panic!("go manifest updates: {error}"))
+		.into_iter()
+		.map(|update| {
+			(
+				update
+					.path
+					.strip_prefix(root)
+					.unwrap_or(&update.path)
+					.to_string_lossy()
+					.replace('\\', "/"),
+				String::from_utf8_lossy(&update.content).into_owned(),
+			)
+		})
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		updates,
+		vec![
+			(
+				"app/go.mod".to_string(),
+				"module example.com/app\n\ngo 1.22\n\nrequire example.com/greeter/v2 v2.0.0\n"
+					.to_string()
+			),
+			(
+				"app/main.go".to_string(),
+				"package main\n\nimport \"example.com/greeter/v2\"\n\nfunc main() { greeter.Greet() }\n"
+					.to_string()
+			),
+			(
+				"greeter/go.mod".to_string(),
+				"module example.com/greeter/v2\n\ngo 1.22\n".to_string()
+			),
+		]
+	);
+}
+
 #[test]
 fn build_manifest_updates_report_parse_and_io_errors() {
 	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
@@ -9614,6 +9783,7 @@ fn build_manifest_updates_report_parse_and_io_errors() {
 				),
 				group_id: None,
 				reasons: vec!["release".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			}
@@ -9621,6 +9791,7 @@ fn build_manifest_updates_report_parse_and_io_errors() {
 		.collect(),
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -9742,6 +9913,7 @@ fn build_manifest_updates_report_parse_and_io_errors() {
 				),
 				group_id: None,
 				reasons: vec!["release".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			}
@@ -9749,6 +9921,7 @@ fn build_manifest_updates_report_parse_and_io_errors() {
 		.collect(),
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -9798,6 +9971,7 @@ fn build_manifest_updates_report_parse_and_io_errors() {
 		decisions: Vec::new(),
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -9864,11 +10038,13 @@ fn build_cargo_manifest_updates_updates_dependents_of_released_packages() {
 			),
 			group_id: None,
 			reasons: vec!["release".to_string()],
+			causes: Vec::new(),
 			upstream_sources: Vec::new(),
 			warnings: Vec::new(),
 		}],
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -9907,11 +10083,13 @@ fn build_npm_manifest_updates_reports_read_errors() {
 			),
 			group_id: None,
 			reasons: vec!["release".to_string()],
+			causes: Vec::new(),
 			upstream_sources: Vec::new(),
 			warnings: Vec::new(),
 		}],
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -11182,6 +11360,7 @@ fn sample_release_record_for_retarget() -> monochange_core::ReleaseRecord {
 			release: true,
 			tag_name: "v1.2.3".to_string(),
 			members: vec!["monochange".to_string()],
+			prerelease: None,
 		}],
 		released_packages: vec!["monochange".to_string()],
 		changed_files: vec![Path::new("Cargo.lock").to_path_buf()],
@@ -11402,6 +11581,8 @@ fn sample_group_definition(include: GroupChangelogInclude) -> monochange_core::G
 		tag: false,
 		release: false,
 		version_format: VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
 	}
 }
 
@@ -11439,6 +11620,9 @@ fn build_command_and_configured_change_type_choices_include_runtime_metadata() {
 			release: true,
 			publish: monochange_core::PublishSettings::default(),
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		}],
 		groups: vec![monochange_core::GroupDefinition {
 			id: "sdk".to_string(),
@@ -11453,7 +11637,10 @@ fn build_command_and_configured_change_type_choices_include_runtime_metadata() {
 			tag: true,
 			release: true,
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
 		}],
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -11464,6 +11651,9 @@ fn build_command_and_configured_change_type_choices_include_runtime_metadata() {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	assert_eq!(
 		crate::configured_change_type_choices(&configuration),
@@ -11553,8 +11743,12 @@ fn apply_runtime_change_type_choices_updates_only_unconfigured_change_inputs() {
 			release: true,
 			publish: monochange_core::PublishSettings::default(),
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		}],
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -11565,6 +11759,9 @@ fn apply_runtime_change_type_choices_updates_only_unconfigured_change_inputs() {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let mut cli = vec![
 		CliCommandDefinition {
@@ -11620,6 +11817,7 @@ fn apply_runtime_change_type_choices_preserves_existing_choice_inputs_and_empty_
 		changelog: monochange_core::ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -11630,6 +11828,9 @@ fn apply_runtime_change_type_choices_preserves_existing_choice_inputs_and_empty_
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let mut cli = vec![CliCommandDefinition {
 		name: "change".to_string(),
@@ -12365,13 +12566,34 @@ async fn create_release_tags_creates_and_pushes_tags_in_process() {
 fn render_tag_name_and_provider_urls_follow_provider_conventions() {
 	let github = sample_github_source_configuration("https://api.github.com");
 	assert_eq!(
-		crate::render_tag_name("core", "1.2.3", VersionFormat::Primary),
+		crate::render_tag_name(
+			"core",
+			"1.2.3",
+			VersionFormat::Primary,
+			&VersionScheme::Semver
+		),
 		"v1.2.3"
 	);
 	assert_eq!(
-		crate::render_tag_name("core", "1.2.3", VersionFormat::Namespaced),
+		crate::render_tag_name(
+			"core",
+			"1.2.3",
+			VersionFormat::Namespaced,
+			&VersionScheme::Semver
+		),
 		"core/v1.2.3"
 	);
+	let calver = VersionScheme::Calver {
+		format: monochange_core::CalverFormat::default(),
+	};
+	assert_eq!(
+		crate::render_tag_name("app", "2026.10.0", VersionFormat::Namespaced, &calver),
+		"app/2026.10.0"
+	);
+	assert_eq!(
+		crate::render_tag_name("app", "2026.10.0", VersionFormat::Primary, &calver),
+		"2026.10.0"
+	);
 	assert!(crate::tag_url_for_provider(&github, "v1.2.3").contains("/releases/tag/v1.2.3"));
 	assert!(
 		crate::compare_url_for_provider(&github, "v1.2.2", "v1.2.3")
@@ -12402,6 +12624,15 @@ fn parse_tag_prefix_and_version_parses_primary_and_namespaced_tags() {
 		.unwrap_or_else(|| panic!("expected namespaced tag"));
 	assert_eq!(namespaced.0, "core/v");
 	assert_eq!(namespaced.1, Version::new(2, 0, 0));
+
+	let calver = crate::parse_tag_prefix_and_version("dev-tools/2026.10.1")
+		.unwrap_or_else(|| panic!("expected calendar-versioned tag"));
+	assert_eq!(calver.0, "dev-tools/");
+	assert_eq!(calver.1, Version::new(2026, 10, 1));
+	assert_eq!(
+		crate::parse_tag_prefix_and_version("2026.10.1").map(|(prefix, _)| prefix),
+		Some(String::new())
+	);
 	assert_eq!(crate::parse_tag_prefix_and_version("not-a-tag"), None);
 }
 
@@ -12870,6 +13101,7 @@ fn render_discovery_report_supports_json_and_text_formats() {
 			mismatch_detected: false,
 		}],
 		warnings: vec!["warning text".to_string()],
+		skipped: Vec::new(),
 	};
 	let json = crate::render_discovery_report(&report, crate::OutputFormat::Json)
 		.unwrap_or_else(|error| panic!("json discovery: {error}"));
@@ -12937,11 +13169,13 @@ fn sample_prepared_release_for_cli_render() -> crate::PreparedRelease {
 				),
 				group_id: Some("sdk".to_string()),
 				reasons: vec!["release core".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			}],
 			groups: vec![sample_planned_group()],
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -12961,6 +13195,7 @@ fn sample_prepared_release_for_cli_render() -> crate::PreparedRelease {
 			members: vec!["core".to_string(), "app".to_string()],
 			rendered_title: "sdk 1.2.3".to_string(),
 			rendered_changelog_title: "sdk changelog".to_string(),
+			prerelease: None,
 		}],
 		changed_files: vec![PathBuf::from("Cargo.toml")],
 		changelogs: Vec::new(),
@@ -12968,6 +13203,7 @@ fn sample_prepared_release_for_cli_render() -> crate::PreparedRelease {
 		deleted_changesets: Vec::new(),
 		package_publications: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	}
 }
 
@@ -13122,17 +13358,45 @@ fn discovery_report_helpers_include_version_groups_and_warnings() {
 			mismatch_detected: true,
 		}],
 		warnings: vec!["workspace warning".to_string()],
+		skipped: vec![
+			monochange_core::DiscoverySkip {
+				ecosystem: Ecosystem::Npm,
+				path: PathBuf::from("/workspace/examples/demo/package.json"),
+				reason: monochange_core::DiscoverySkipReason::Excluded {
+					pattern: "examples/**".to_string(),
+				},
+			},
+			monochange_core::DiscoverySkip {
+				ecosystem: Ecosystem::Cargo,
+				path: PathBuf::from("/workspace/tools"),
+				reason: monochange_core::DiscoverySkipReason::OutsideRoots,
+			},
+		],
 	};
 
 	let json = crate::json_discovery_report(&report);
 	assert_eq!(json["versionGroups"][0]["id"], "sdk");
 	assert_eq!(json["warnings"][0], "workspace warning");
+	assert_eq!(
+		json["skipped"][0],
+		serde_json::json!({
+			"ecosystem": "npm",
+			"path": "examples/demo/package.json",
+			"reason": "excluded",
+			"pattern": "examples/**",
+		})
+	);
+	assert_eq!(json["skipped"][1]["reason"], "outside_roots");
+	assert!(json["skipped"][1]["pattern"].is_null());
 
 	let text = crate::text_discovery_report(&report);
 	assert!(text.contains("Version groups:"));
 	assert!(text.contains("- sdk (1)"));
 	assert!(text.contains("Warnings:"));
 	assert!(text.contains("- workspace warning"));
+	assert!(text.contains("Skipped: 2"));
+	assert!(text.contains("- examples/demo/package.json (npm): excluded by `examples/**`"));
+	assert!(text.contains("- tools (cargo): outside configured roots"));
 }
 
 #[test]
@@ -14233,6 +14497,7 @@ fn build_release_manifest_from_record_populates_manifest_from_release_record() {
 			release: true,
 			tag_name: "core/v1.0.0".to_string(),
 			members: vec!["core".to_string()],
+			prerelease: None,
 		}],
 		released_packages: vec!["workflow-core".to_string()],
 		changed_files: vec![PathBuf::from("Cargo.toml")],
@@ -14417,6 +14682,7 @@ branches = ["release/*"]
 			release: true,
 			tag_name: "v1.0.0".to_string(),
 			members: vec![],
+			prerelease: None,
 		}],
 		released_packages: vec![],
 		changed_files: vec![],
@@ -14571,6 +14837,7 @@ repo = "monochange"
 			release: true,
 			tag_name: "v1.0.0".to_string(),
 			members: vec![],
+			prerelease: None,
 		}],
 		released_packages: vec![],
 		changed_files: vec![],
@@ -57,6 +57,12 @@ fn render_lint_catalog_lists_rules_and_presets() {
 	assert!(text.contains("dart/flutter-package-metadata-consistent"));
 	assert!(text.contains("dart/recommended"));
 	assert!(text.contains("dart/strict"));
+	assert!(text.contains("python/requires-python-consistent"));
+	assert!(text.contains("python/recommended"));
+	assert!(text.contains("go/go-directive-consistent"));
+	assert!(text.contains("go/recommended"));
+	assert!(text.contains("deno/jsr-exports"));
+	assert!(text.contains("deno/recommended"));
 
 	let json = render_lint_catalog(OutputFormat::Json).unwrap();
 	assert!(json.contains("\"rules\""));
@@ -301,6 +307,24 @@ fn scaffold_lint_rule_validates_ids_and_creates_expected_files() {
 			.join("crates/monochange_dart/src/lints/sdk_constraint_present.rs")
 			.exists()
 	);
+
+	for (id, file) in [
+		(
+			"python/no-path-or-git-dependencies",
+			"crates/monochange_python/src/lints/no_path_or_git_dependencies.rs",
+		),
+		(
+			"go/required-directives",
+			"crates/monochange_go/src/lints/required_directives.rs",
+		),
+		(
+			"deno/jsr-exports",
+			"crates/monochange_deno/src/lints/jsr_exports.rs",
+		),
+	] {
+		scaffold_lint_rule(tempdir.path(), id).unwrap();
+		assert!(tempdir.path().join(file).exists());
+	}
 }
 
 #[test]
@@ -386,3 +410,20 @@ fn scaffold_lint_rule_reports_write_failures() {
 		.expect_err("expected fixture note write to fail");
 	assert!(!error.to_string().is_empty());
 }
+
+#[test]
+fn collect_workspace_validation_issues_warns_about_dependency_cycles() {
+	let tempdir = monochange_test_helpers::fs::setup_scenario_workspace_from(
+		env!("CARGO_MANIFEST_DIR"),
+		"workspace-graph/workspace",
+	);
+
+	let (warnings, errors) = collect_workspace_validation_issues(tempdir.path());
+	assert!(errors.is_empty(), "{errors:?}");
+	assert_eq!(
+		warnings,
+		[
+			"dependency cycle api -> core (runtime), core -> testkit (development), testkit -> api (runtime); breakable: it only closes through development dependencies, so publish order can be derived once those are left out of `publish_order.dependency_fields`"
+		]
+	);
+}
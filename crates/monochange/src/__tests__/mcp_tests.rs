@@ -29,6 +29,7 @@ use super::LintExplainParam;
 use super::McpChangeBump;
 use super::MonochangeMcpServer;
 use super::PathParam;
+use super::ReleaseExplainParam;
 use super::json_error_result;
 use super::json_result;
 use super::parse_frame;
@@ -206,6 +207,7 @@ fn sample_changeset(summary: Option<&str>, package_id: &str) -> LoadedChangesetF
 			caused_by: Vec::new(),
 			source_path: PathBuf::from(".changeset/test.md"),
 		}],
+		source_revision: None,
 	}
 }
 
@@ -562,6 +564,41 @@ async fn release_preview_returns_dry_run_release_summary() {
 	assert_snapshot!(content_text(&result));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn release_explain_returns_the_causal_chain() {
+	let tempdir = setup_scenario_workspace("release-explain/workspace");
+
+	let result = MonochangeMcpServer::new()
+		.release_explain(Parameters(ReleaseExplainParam {
+			path: Some(tempdir.path().display().to_string()),
+			package: "api".to_string(),
+		}))
+		.await
+		.unwrap_or_else(|error| panic!("release explain: {error}"));
+
+	let value = serde_json::from_str::<serde_json::Value>(&content_text(&result))
+		.unwrap_or_else(|error| panic!("parse release explain result: {error}"));
+	assert_eq!(value["ok"], true);
+	assert_eq!(
+		value["summary"],
+		"`api` plans a patch release (transitive-dependency)."
+	);
+	let upstream = &value["explanation"]["package"]["causes"][0]["upstream"];
+	assert_eq!(upstream["id"], "core");
+	assert_eq!(upstream["causes"][0]["path"], ".changeset/core-storage.md");
+
+	let result = MonochangeMcpServer::new()
+		.release_explain(Parameters(ReleaseExplainParam {
+			path: Some(tempdir.path().display().to_string()),
+			package: "missing".to_string(),
+		}))
+		.await
+		.unwrap_or_else(|error| panic!("release explain missing package: {error}"));
+	let value = serde_json::from_str::<serde_json::Value>(&content_text(&result))
+		.unwrap_or_else(|error| panic!("parse release explain error: {error}"));
+	assert_eq!(value["ok"], false);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn release_manifest_returns_dry_run_manifest() {
 	let mut settings = snapshot_settings();
@@ -791,6 +828,40 @@ async fn analyze_changes_returns_semantic_diff_for_cargo_workspace() {
 	});
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn draft_changesets_writes_drafts_that_validate_against_the_diff() {
+	let tempdir = setup_analysis_workspace();
+	let result = MonochangeMcpServer::new()
+		.draft_changesets(Parameters(super::DraftChangesetsParam {
+			path: Some(tempdir.path().display().to_string()),
+			frame: Some("working".to_string()),
+		}))
+		.await
+		.unwrap_or_else(|error| panic!("draft_changesets: {error}"));
+	let rendered = content_text(&result);
+	let value: serde_json::Value = serde_json::from_str(&rendered)
+		.unwrap_or_else(|error| panic!("parse draft_changesets output: {error}"));
+
+	assert_eq!(value["ok"], true);
+	assert_eq!(value["drafts"][0]["package"], "core");
+	assert_eq!(value["drafts"][0]["bump"], "major");
+	assert_eq!(value["drafts"][0]["type"], "breaking");
+	let draft_path = value["drafts"][0]["path"]
+		.as_str()
+		.unwrap_or_else(|| panic!("expected a draft path"))
+		.to_string();
+
+	let result = MonochangeMcpServer::new()
+		.validate_changeset(Parameters(super::ValidateChangesetParam {
+			path: Some(tempdir.path().display().to_string()),
+			changeset_path: draft_path,
+		}))
+		.await
+		.unwrap_or_else(|error| panic!("validate_changeset: {error}"));
+
+	assert!(content_text(&result).contains("\"lifecycle_status\": \"current\""));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn validate_changeset_reports_stale_symbol_references() {
 	let tempdir = setup_analysis_workspace();
@@ -144,6 +144,7 @@ fn sample_request(registry: RegistryKind) -> PublishRequest {
 		},
 		attestations: PublishAttestationSettings::default(),
 		placeholder_readme: "placeholder".to_string(),
+		dist_tag: None,
 	}
 }
 
@@ -185,6 +186,7 @@ fn sample_prepared_release(
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -200,6 +202,7 @@ fn sample_prepared_release(
 		updated_changelogs: Vec::new(),
 		deleted_changesets: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	}
 }
 
@@ -247,6 +250,7 @@ fn sample_endpoints(base_url: &str) -> RegistryEndpoints {
 		jsr_base: base_url.to_string(),
 		pypi_api: base_url.to_string(),
 		go_proxy: base_url.to_string(),
+		rubygems_api: base_url.to_string(),
 	}
 }
 
@@ -358,6 +362,9 @@ fn sample_configuration(
 					tag: true,
 					release: true,
 					version_format: monochange_core::VersionFormat::Primary,
+					version_scheme: monochange_core::VersionScheme::Semver,
+					pre_stable_policy: monochange_core::PreStablePolicy::Shift,
+					propagation_by_kind: monochange_core::PropagationByKind::default(),
 					publish: monochange_core::PublishSettings {
 						enabled: *enabled,
 						..monochange_core::PublishSettings::default()
@@ -366,6 +373,7 @@ fn sample_configuration(
 			})
 			.collect(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -376,6 +384,9 @@ fn sample_configuration(
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	}
 }
 
@@ -396,6 +407,7 @@ fn commit_release_record(root: &Path, publications: Vec<PackagePublicationTarget
 			version_format: monochange_core::VersionFormat::Primary,
 			tag_name: "pkg-v1.2.3".to_string(),
 			members: Vec::new(),
+			prerelease: None,
 		}],
 		released_packages: vec!["pkg".to_string()],
 		changed_files: vec![PathBuf::from("tracked.txt")],
@@ -677,12 +689,16 @@ fn resolve_registry_kind_defaults_and_custom_errors_match_expectations() {
 			.to_string()
 			.contains("does not support custom registry `internal`")
 	);
-	let unsupported = default_registry_kind_for_ecosystem("ruby")
+	assert_eq!(
+		default_registry_kind_for_ecosystem("ruby").expect("rubygems registry:"),
+		RegistryKind::Rubygems
+	);
+	let unsupported = default_registry_kind_for_ecosystem("swift")
 		.expect_err("expected unsupported ecosystem error");
 	assert!(
 		unsupported
 			.to_string()
-			.contains("does not support ecosystem `ruby`")
+			.contains("does not support ecosystem `swift`")
 	);
 }
 
@@ -825,6 +841,44 @@ fn build_publish_command_covers_all_supported_registries() {
 		false,
 	);
 	assert_eq!(trusted_pnpm.program, "npm");
+	let yarn_request = PublishRequest {
+		package_manager: Some("yarn".to_string()),
+		..sample_request(RegistryKind::Npm)
+	};
+	let yarn_placeholder = build_publish_command(
+		&yarn_request,
+		PackagePublishRunMode::Placeholder,
+		Some(tempdir.path()),
+		false,
+	);
+	assert_eq!(yarn_placeholder.program, "npm");
+	let yarn = build_publish_command(&yarn_request, PackagePublishRunMode::Release, None, true);
+	assert_eq!(yarn.program, "yarn");
+	assert_eq!(
+		yarn.args,
+		vec![
+			"npm".to_string(),
+			"publish".to_string(),
+			"--access".to_string(),
+			"public".to_string(),
+			"--dry-run".to_string(),
+		]
+	);
+	let trusted_yarn = build_publish_command(
+		&PublishRequest {
+			trusted_publishing: TrustedPublishingSettings {
+				enabled: true,
+				repository: None,
+				workflow: None,
+				environment: None,
+			},
+			..yarn_request
+		},
+		PackagePublishRunMode::Release,
+		None,
+		false,
+	);
+	assert_eq!(trusted_yarn.program, "npm");
 	let cargo_placeholder = build_publish_command(
 		&sample_request(RegistryKind::CratesIo),
 		PackagePublishRunMode::Placeholder,
@@ -926,6 +980,25 @@ fn build_publish_command_covers_all_supported_registries() {
 	assert_eq!(go.args, vec!["tag".to_string(), "api/v1.2.3".to_string()]);
 }
 
+#[test]
+fn npm_release_publish_command_uses_the_snapshot_dist_tag() {
+	let request = PublishRequest {
+		dist_tag: Some("canary".to_string()),
+		..sample_request(RegistryKind::Npm)
+	};
+	let npm = build_publish_command(&request, PackagePublishRunMode::Release, None, false);
+	assert_eq!(
+		npm.args,
+		vec![
+			"publish".to_string(),
+			"--access".to_string(),
+			"public".to_string(),
+			"--tag".to_string(),
+			"canary".to_string(),
+		]
+	);
+}
+
 #[test]
 fn go_publish_command_uses_root_tag_when_relative_path_is_current_directory() {
 	let request = PublishRequest {
@@ -1190,6 +1263,13 @@ async fn registry_version_exists_parses_all_supported_registry_shapes() {
 			"Time": "2026-04-28T00:00:00Z"
 		}));
 	});
+	server.mock(|when, then| {
+		when.method(GET).path("/versions/pkg.json");
+		then.status(200).json_body_obj(&serde_json::json!([
+			{ "number": "1.2.4" },
+			{ "number": "1.2.3" }
+		]));
+	});
 	let client = Client::builder().build().expect("http client:");
 	let endpoints = RegistryEndpoints {
 		npm_registry: server.base_url(),
@@ -1199,6 +1279,7 @@ async fn registry_version_exists_parses_all_supported_registry_shapes() {
 		jsr_base: server.base_url(),
 		pypi_api: server.base_url(),
 		go_proxy: server.base_url(),
+		rubygems_api: server.base_url(),
 	};
 
 	assert!(
@@ -1238,6 +1319,11 @@ async fn registry_version_exists_parses_all_supported_registry_shapes() {
 			.await
 			.expect("Go exists:")
 	);
+	assert!(
+		registry_version_exists(&client, &endpoints, &sample_request(RegistryKind::Rubygems))
+			.await
+			.expect("RubyGems exists:")
+	);
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -1482,6 +1568,7 @@ async fn registry_version_exists_returns_false_for_missing_packages() {
 		jsr_base: server.base_url(),
 		pypi_api: server.base_url(),
 		go_proxy: server.base_url(),
+		rubygems_api: server.base_url(),
 	};
 	let request = sample_request(RegistryKind::Npm);
 	let request = PublishRequest {
@@ -2107,6 +2194,12 @@ fn build_release_requests_detects_publish_relevant_dependency_cycles() {
 	assert!(message.contains("cyclic publish dependencies"));
 	assert!(message.contains("core -> utils"));
 	assert!(message.contains("utils -> core"));
+	assert!(
+		message.contains(
+			"- dependency cycle core -> utils (runtime), utils -> core (workspace); not breakable"
+		),
+		"{message}"
+	);
 }
 
 #[test]
@@ -2139,6 +2232,12 @@ fn build_release_requests_reports_development_dependency_cycles() {
 	assert!(message.contains("cyclic publish dependencies detected"));
 	assert!(message.contains("core -> utils"));
 	assert!(message.contains("utils -> core"));
+	assert!(
+		message.contains(
+			"- dependency cycle core -> utils (development), utils -> core (development); breakable"
+		),
+		"{message}"
+	);
 }
 
 #[test]
@@ -3673,6 +3772,7 @@ async fn execute_publish_requests_skips_external_and_existing_versions() {
 		jsr_base: server.base_url(),
 		pypi_api: server.base_url(),
 		go_proxy: server.base_url(),
+		rubygems_api: server.base_url(),
 	};
 	let request = PublishRequest {
 		mode: PublishMode::External,
@@ -723,6 +723,7 @@ async fn tracked_path_snapshots_hashes_existing_changed_files() {
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -738,6 +739,7 @@ async fn tracked_path_snapshots_hashes_existing_changed_files() {
 		updated_changelogs: Vec::new(),
 		deleted_changesets: Vec::new(),
 		dry_run: false,
+		snapshot: None,
 	};
 
 	let tracked_paths = tracked_path_snapshots(root, &prepared_release)
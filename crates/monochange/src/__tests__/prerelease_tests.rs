@@ -0,0 +1,305 @@
+use monochange_config::load_changeset_file;
+use monochange_config::load_workspace_configuration;
+use monochange_test_helpers::fs::setup_scenario_workspace_from;
+use tempfile::TempDir;
+
+use super::*;
+use crate::discover_workspace;
+
+fn setup_prerelease_workspace() -> (TempDir, WorkspaceConfiguration, Vec<PackageRecord>) {
+	let tempdir =
+		setup_scenario_workspace_from(env!("CARGO_MANIFEST_DIR"), "prerelease-channels/workspace");
+	let configuration = load_workspace_configuration(tempdir.path())
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let packages = discover_workspace(tempdir.path())
+		.unwrap_or_else(|error| panic!("discovery: {error}"))
+		.packages;
+	(tempdir, configuration, packages)
+}
+
+fn inputs(entries: &[(&str, &[&str])]) -> BTreeMap<String, Vec<String>> {
+	entries
+		.iter()
+		.map(|(name, values)| {
+			(
+				(*name).to_string(),
+				values.iter().map(ToString::to_string).collect(),
+			)
+		})
+		.collect()
+}
+
+#[test]
+fn prerelease_request_reads_step_inputs() {
+	let request = PrereleaseRequest::from_step_inputs(&inputs(&[
+		("pre", &["beta"]),
+		("pre_package", &["core", " "]),
+	]))
+	.unwrap_or_else(|error| panic!("request: {error}"));
+
+	assert_eq!(request.channel.as_deref(), Some("beta"));
+	assert_eq!(request.owners, vec!["core".to_string()]);
+	assert!(!request.exit);
+	assert!(request.is_requested());
+	assert!(
+		!PrereleaseRequest::from_step_inputs(&BTreeMap::new())
+			.unwrap_or_else(|error| panic!("request: {error}"))
+			.is_requested()
+	);
+}
+
+#[test]
+fn prerelease_request_rejects_invalid_channels_and_conflicting_inputs() {
+	for channel in ["beta.1", "12", "rc_1"] {
+		let error = PrereleaseRequest::from_step_inputs(&inputs(&[("pre", &[channel])]))
+			.err()
+			.unwrap_or_else(|| panic!("expected `{channel}` to be rejected"));
+		assert!(error.to_string().contains("invalid prerelease channel"));
+	}
+
+	let error = PrereleaseRequest::from_step_inputs(&inputs(&[
+		("pre", &["beta"]),
+		("exit_pre", &["true"]),
+	]))
+	.err()
+	.unwrap_or_else(|| panic!("expected conflicting inputs to be rejected"));
+	assert!(error.to_string().contains("cannot be used together"));
+}
+
+#[test]
+fn prerelease_session_enters_and_exits_channels() {
+	let (_tempdir, configuration, _packages) = setup_prerelease_workspace();
+	let entered = PrereleaseSession::resolve(
+		&configuration,
+		PrereleaseState::default(),
+		&PrereleaseRequest {
+			channel: Some("beta".to_string()),
+			..PrereleaseRequest::default()
+		},
+	)
+	.unwrap_or_else(|error| panic!("resolve: {error}"));
+	assert_eq!(
+		entered.channels,
+		BTreeMap::from([
+			("app".to_string(), "beta".to_string()),
+			("core".to_string(), "beta".to_string()),
+		])
+	);
+
+	let previous = entered.next_state(BTreeMap::new());
+	let exited = PrereleaseSession::resolve(
+		&configuration,
+		previous.clone(),
+		&PrereleaseRequest {
+			owners: vec!["app".to_string()],
+			exit: true,
+			..PrereleaseRequest::default()
+		},
+	)
+	.unwrap_or_else(|error| panic!("resolve: {error}"));
+	assert_eq!(
+		exited.channels,
+		BTreeMap::from([("core".to_string(), "beta".to_string())])
+	);
+	assert!(exited.state_changed(&exited.next_state(BTreeMap::new())));
+}
+
+#[test]
+fn prerelease_session_enters_each_member_of_a_linked_group() {
+	let tempdir =
+		setup_scenario_workspace_from(env!("CARGO_MANIFEST_DIR"), "linked-groups/workspace");
+	let configuration = load_workspace_configuration(tempdir.path())
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let session = PrereleaseSession::resolve(
+		&configuration,
+		PrereleaseState::default(),
+		&PrereleaseRequest {
+			channel: Some("rc".to_string()),
+			owners: vec!["tools".to_string()],
+			exit: false,
+		},
+	)
+	.unwrap_or_else(|error| panic!("resolve: {error}"));
+	assert_eq!(
+		session.channels,
+		BTreeMap::from([
+			("cli".to_string(), "rc".to_string()),
+			("core".to_string(), "rc".to_string()),
+			("docs".to_string(), "rc".to_string()),
+		])
+	);
+}
+
+#[test]
+fn prerelease_session_rejects_unknown_or_inactive_owners() {
+	let (_tempdir, configuration, _packages) = setup_prerelease_workspace();
+	let unknown = PrereleaseSession::resolve(
+		&configuration,
+		PrereleaseState::default(),
+		&PrereleaseRequest {
+			channel: Some("beta".to_string()),
+			owners: vec!["missing".to_string()],
+			exit: false,
+		},
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected unknown owner error"));
+	assert!(
+		unknown
+			.to_string()
+			.contains("does not match a configured package or group")
+	);
+
+	let inactive = PrereleaseSession::resolve(
+		&configuration,
+		PrereleaseState::default(),
+		&PrereleaseRequest {
+			owners: vec!["core".to_string()],
+			exit: true,
+			..PrereleaseRequest::default()
+		},
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected inactive owner error"));
+	assert!(
+		inactive
+			.to_string()
+			.contains("`core` is not in prerelease mode")
+	);
+}
+
+#[test]
+fn retain_changesets_keeps_consumed_files_until_stable_release() {
+	let (tempdir, configuration, packages) = setup_prerelease_workspace();
+	let root = tempdir.path();
+	let changeset_path = root.join(".changeset/breaking-core.md");
+	let load = || {
+		vec![
+			load_changeset_file(&changeset_path, &configuration, &packages)
+				.unwrap_or_else(|error| panic!("changeset: {error}")),
+		]
+	};
+	let request = PrereleaseRequest {
+		channel: Some("beta".to_string()),
+		..PrereleaseRequest::default()
+	};
+
+	let session = PrereleaseSession::resolve(&configuration, PrereleaseState::default(), &request)
+		.unwrap_or_else(|error| panic!("resolve: {error}"));
+	let first = session.retain_changesets(root, &configuration, &packages, load());
+	assert_eq!(first.included.len(), 1);
+	assert!(first.deletable.is_empty());
+	let state = session.next_state(first.kept);
+	assert_eq!(
+		state.changesets,
+		BTreeMap::from([(
+			PathBuf::from(".changeset/breaking-core.md"),
+			BTreeSet::from(["core".to_string()]),
+		)])
+	);
+	let planner_channels = session.planner_channels(&configuration, &packages);
+	assert_eq!(planner_channels.len(), packages.len());
+
+	let continued =
+		PrereleaseSession::resolve(&configuration, state.clone(), &PrereleaseRequest::default())
+			.unwrap_or_else(|error| panic!("resolve: {error}"));
+	let second = continued.retain_changesets(root, &configuration, &packages, load());
+	assert!(second.included.is_empty());
+	assert!(second.deletable.is_empty());
+	assert_eq!(second.kept.len(), 1);
+
+	let exited = PrereleaseSession::resolve(
+		&configuration,
+		state,
+		&PrereleaseRequest {
+			exit: true,
+			..PrereleaseRequest::default()
+		},
+	)
+	.unwrap_or_else(|error| panic!("resolve: {error}"));
+	let stable = exited.retain_changesets(root, &configuration, &packages, load());
+	assert_eq!(stable.included.len(), 1);
+	assert_eq!(stable.deletable, vec![changeset_path]);
+	assert!(exited.next_state(stable.kept).is_empty());
+}
+
+#[test]
+fn retain_prereleased_commit_changesets_only_keeps_signals_for_graduating_owners() {
+	let (tempdir, configuration, packages) = setup_prerelease_workspace();
+	let root = tempdir.path();
+	let load = || {
+		vec![
+			load_changeset_file(
+				&root.join(".changeset/breaking-core.md"),
+				&configuration,
+				&packages,
+			)
+			.unwrap_or_else(|error| panic!("changeset: {error}")),
+		]
+	};
+	let state = PrereleaseState {
+		channels: BTreeMap::from([
+			("core".to_string(), "beta".to_string()),
+			("app".to_string(), "beta".to_string()),
+		]),
+		changesets: BTreeMap::new(),
+	};
+
+	let fresh = PrereleaseSession::resolve(
+		&configuration,
+		PrereleaseState::default(),
+		&PrereleaseRequest::default(),
+	)
+	.unwrap_or_else(|error| panic!("resolve: {error}"));
+	assert!(!fresh.was_active());
+
+	let continued =
+		PrereleaseSession::resolve(&configuration, state.clone(), &PrereleaseRequest::default())
+			.unwrap_or_else(|error| panic!("resolve: {error}"));
+	assert!(continued.was_active());
+	assert!(
+		continued
+			.retain_prereleased_commit_changesets(&configuration, &packages, load())
+			.is_empty()
+	);
+
+	let graduated = PrereleaseSession::resolve(
+		&configuration,
+		state,
+		&PrereleaseRequest {
+			exit: true,
+			..PrereleaseRequest::default()
+		},
+	)
+	.unwrap_or_else(|error| panic!("resolve: {error}"));
+	let stable = graduated.retain_prereleased_commit_changesets(&configuration, &packages, load());
+	let [changeset] = stable.as_slice() else {
+		panic!("expected the shipped changeset to graduate, got {stable:?}");
+	};
+	assert_eq!(changeset.signals.len(), load()[0].signals.len());
+}
+
+#[test]
+fn write_prerelease_state_removes_the_file_once_empty() {
+	let (tempdir, _configuration, _packages) = setup_prerelease_workspace();
+	let root = tempdir.path();
+	let state = PrereleaseState {
+		channels: BTreeMap::from([("core".to_string(), "rc".to_string())]),
+		changesets: BTreeMap::new(),
+	};
+
+	write_prerelease_state(root, &state).unwrap_or_else(|error| panic!("write: {error}"));
+	assert_eq!(
+		load_prerelease_state(root).unwrap_or_else(|error| panic!("load: {error}")),
+		state
+	);
+
+	write_prerelease_state(root, &PrereleaseState::default())
+		.unwrap_or_else(|error| panic!("write: {error}"));
+	assert!(!prerelease_state_path(root).exists());
+	assert!(
+		load_prerelease_state(root)
+			.unwrap_or_else(|error| panic!("load: {error}"))
+			.is_empty()
+	);
+}
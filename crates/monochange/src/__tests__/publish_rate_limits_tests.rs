@@ -114,6 +114,7 @@ fn sample_publish_request(
 		trusted_publishing: TrustedPublishingSettings::default(),
 		attestations: PublishAttestationSettings::default(),
 		placeholder_readme: String::new(),
+		dist_tag: None,
 	}
 }
 
@@ -564,6 +565,7 @@ async fn plan_publish_rate_limits_summarizes_pending_publications_and_batches()
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -607,6 +609,7 @@ async fn plan_publish_rate_limits_summarizes_pending_publications_and_batches()
 		updated_changelogs: Vec::new(),
 		deleted_changesets: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	};
 
 	let server = MockServer::start();
@@ -634,6 +637,7 @@ async fn plan_publish_rate_limits_summarizes_pending_publications_and_batches()
 		jsr_base: server.base_url(),
 		pypi_api: server.base_url(),
 		go_proxy: server.base_url(),
+		rubygems_api: server.base_url(),
 	};
 	let requests = build_release_plan_requests_with_transport(
 		tempdir.path(),
@@ -682,6 +686,9 @@ async fn plan_publish_rate_limits_skips_private_and_disabled_packages_from_relea
 				tag: true,
 				release: true,
 				version_format: monochange_core::VersionFormat::Primary,
+				version_scheme: monochange_core::VersionScheme::Semver,
+				pre_stable_policy: monochange_core::PreStablePolicy::Shift,
+				propagation_by_kind: monochange_core::PropagationByKind::default(),
 				publish: monochange_core::PublishSettings::default(),
 			},
 			monochange_core::PackageDefinition {
@@ -700,6 +707,9 @@ async fn plan_publish_rate_limits_skips_private_and_disabled_packages_from_relea
 				tag: true,
 				release: true,
 				version_format: monochange_core::VersionFormat::Primary,
+				version_scheme: monochange_core::VersionScheme::Semver,
+				pre_stable_policy: monochange_core::PreStablePolicy::Shift,
+				propagation_by_kind: monochange_core::PropagationByKind::default(),
 				publish: monochange_core::PublishSettings::default(),
 			},
 			monochange_core::PackageDefinition {
@@ -718,6 +728,9 @@ async fn plan_publish_rate_limits_skips_private_and_disabled_packages_from_relea
 				tag: true,
 				release: true,
 				version_format: monochange_core::VersionFormat::Primary,
+				version_scheme: monochange_core::VersionScheme::Semver,
+				pre_stable_policy: monochange_core::PreStablePolicy::Shift,
+				propagation_by_kind: monochange_core::PropagationByKind::default(),
 				publish: monochange_core::PublishSettings {
 					enabled: false,
 					..monochange_core::PublishSettings::default()
@@ -725,6 +738,7 @@ async fn plan_publish_rate_limits_skips_private_and_disabled_packages_from_relea
 			},
 		],
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -735,6 +749,9 @@ async fn plan_publish_rate_limits_skips_private_and_disabled_packages_from_relea
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let packages = vec![
 		monochange_core::PackageRecord {
@@ -836,6 +853,7 @@ async fn plan_publish_rate_limits_skips_versions_that_are_already_published() {
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -879,6 +897,7 @@ async fn plan_publish_rate_limits_skips_versions_that_are_already_published() {
 		updated_changelogs: Vec::new(),
 		deleted_changesets: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	};
 	let server = MockServer::start();
 	server.mock(|when, then| {
@@ -907,6 +926,7 @@ async fn plan_publish_rate_limits_skips_versions_that_are_already_published() {
 		jsr_base: server.base_url(),
 		pypi_api: server.base_url(),
 		go_proxy: server.base_url(),
+		rubygems_api: server.base_url(),
 	};
 	let requests = build_release_plan_requests_with_transport(
 		tempdir.path(),
@@ -979,6 +999,7 @@ async fn build_placeholder_plan_requests_skips_packages_when_any_registry_versio
 		jsr_base: server.base_url(),
 		pypi_api: server.base_url(),
 		go_proxy: server.base_url(),
+		rubygems_api: server.base_url(),
 	};
 	let requests = build_placeholder_plan_requests_with_transport(
 		tempdir.path(),
@@ -1090,6 +1111,7 @@ async fn plan_publish_rate_limits_supports_placeholder_mode_and_skips_external_r
 		jsr_base: server.base_url(),
 		pypi_api: server.base_url(),
 		go_proxy: server.base_url(),
+		rubygems_api: server.base_url(),
 	};
 	let requests = build_placeholder_plan_requests_with_transport(
 		tempdir.path(),
@@ -1206,9 +1228,13 @@ async fn enforce_publish_rate_limits_returns_ok_when_enforcement_is_not_triggere
 			tag: false,
 			release: false,
 			version_format: monochange_core::VersionFormat::default(),
+			version_scheme: monochange_core::VersionScheme::Semver,
+			pre_stable_policy: monochange_core::PreStablePolicy::Shift,
+			propagation_by_kind: monochange_core::PropagationByKind::default(),
 			publish: monochange_core::PublishSettings::default(),
 		}],
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -1219,6 +1245,9 @@ async fn enforce_publish_rate_limits_returns_ok_when_enforcement_is_not_triggere
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let unenforced = PublishRateLimitReport {
 		dry_run: true,
@@ -1286,6 +1315,7 @@ async fn enforce_publish_rate_limits_blocks_multi_batch_runs_when_enabled() {
 				trusted_publishing: TrustedPublishingSettings::default(),
 				attestations: PublishAttestationSettings::default(),
 				placeholder_readme: String::new(),
+				dist_tag: None,
 			}
 		})
 		.collect::<Vec<_>>();
@@ -1317,6 +1347,9 @@ async fn enforce_publish_rate_limits_blocks_multi_batch_runs_when_enabled() {
 					tag: false,
 					release: false,
 					version_format: monochange_core::VersionFormat::default(),
+					version_scheme: monochange_core::VersionScheme::Semver,
+					pre_stable_policy: monochange_core::PreStablePolicy::Shift,
+					propagation_by_kind: monochange_core::PropagationByKind::default(),
 					publish: monochange_core::PublishSettings {
 						rate_limits: monochange_core::PublishRateLimitSettings { enforce: true },
 						..monochange_core::PublishSettings::default()
@@ -1325,6 +1358,7 @@ async fn enforce_publish_rate_limits_blocks_multi_batch_runs_when_enabled() {
 			})
 			.collect(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -1335,6 +1369,9 @@ async fn enforce_publish_rate_limits_blocks_multi_batch_runs_when_enabled() {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let error = enforce_publish_rate_limits(&configuration, &report, PublishRateLimitMode::Publish)
 		.unwrap_err();
@@ -1413,6 +1450,7 @@ async fn test_sort_requests_by_dependencies_orders_dependencies_first() {
 			trusted_publishing: TrustedPublishingSettings::default(),
 			attestations: PublishAttestationSettings::default(),
 			placeholder_readme: String::new(),
+			dist_tag: None,
 		},
 		package_publish::PublishRequest {
 			package_id: helper.id.clone(),
@@ -1429,6 +1467,7 @@ async fn test_sort_requests_by_dependencies_orders_dependencies_first() {
 			trusted_publishing: TrustedPublishingSettings::default(),
 			attestations: PublishAttestationSettings::default(),
 			placeholder_readme: String::new(),
+			dist_tag: None,
 		},
 	];
 	sort_requests_by_dependencies(&mut requests, &packages);
@@ -1489,6 +1528,7 @@ async fn test_sort_requests_by_dependencies_keeps_original_order_on_cycle() {
 			trusted_publishing: TrustedPublishingSettings::default(),
 			attestations: PublishAttestationSettings::default(),
 			placeholder_readme: String::new(),
+			dist_tag: None,
 		},
 		package_publish::PublishRequest {
 			package_id: b.id.clone(),
@@ -1505,6 +1545,7 @@ async fn test_sort_requests_by_dependencies_keeps_original_order_on_cycle() {
 			trusted_publishing: TrustedPublishingSettings::default(),
 			attestations: PublishAttestationSettings::default(),
 			placeholder_readme: String::new(),
+			dist_tag: None,
 		},
 	];
 	sort_requests_by_dependencies(&mut requests, &packages);
@@ -1512,3 +1553,34 @@ async fn test_sort_requests_by_dependencies_keeps_original_order_on_cycle() {
 	assert_eq!(requests[0].package_id, original_ids[0]);
 	assert_eq!(requests[1].package_id, original_ids[1]);
 }
+
+#[test]
+fn publish_dependency_cycle_warnings_describe_cycles_between_planned_publications() {
+	let dependencies = BTreeMap::from([("a", vec!["b"]), ("b", vec!["a"]), ("c", vec!["a"])]);
+	let packages = dependency_packages(&dependencies, monochange_core::Ecosystem::Npm);
+
+	let warnings = publish_dependency_cycle_warnings(
+		&[
+			publish_request("a", RegistryKind::Npm),
+			publish_request("b", RegistryKind::Npm),
+		],
+		&packages,
+	);
+	assert_eq!(
+		warnings,
+		[
+			"publish order is not dependency-sorted because of a dependency cycle a -> b (runtime), b -> a (runtime); not breakable: it closes through runtime dependencies, so publish order is ambiguous"
+		]
+	);
+
+	assert!(
+		publish_dependency_cycle_warnings(
+			&[
+				publish_request("a", RegistryKind::Npm),
+				publish_request("c", RegistryKind::Npm),
+			],
+			&packages,
+		)
+		.is_empty()
+	);
+}
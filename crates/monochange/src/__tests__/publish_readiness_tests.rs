@@ -80,6 +80,7 @@ fn sample_configuration(root: &Path) -> WorkspaceConfiguration {
 		changelog: monochange_core::ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -90,6 +91,9 @@ fn sample_configuration(root: &Path) -> WorkspaceConfiguration {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	}
 }
 
@@ -114,6 +118,9 @@ fn sample_package_definition(
 		tag: true,
 		release: true,
 		version_format: monochange_core::VersionFormat::default(),
+		version_scheme: monochange_core::VersionScheme::Semver,
+		pre_stable_policy: monochange_core::PreStablePolicy::Shift,
+		propagation_by_kind: monochange_core::PropagationByKind::default(),
 		publish: monochange_core::PublishSettings::default(),
 	}
 }
@@ -125,6 +132,7 @@ fn sample_prepared_release(root: &Path) -> PreparedRelease {
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -140,6 +148,7 @@ fn sample_prepared_release(root: &Path) -> PreparedRelease {
 		updated_changelogs: Vec::new(),
 		deleted_changesets: Vec::new(),
 		dry_run: true,
+		snapshot: None,
 	}
 }
 
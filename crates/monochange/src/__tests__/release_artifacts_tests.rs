@@ -5,6 +5,8 @@ use monochange_core::ChangelogSettings;
 use monochange_core::GroupChangelogInclude;
 use monochange_core::PackageDefinition;
 use monochange_core::PackageType;
+use monochange_core::PreStablePolicy;
+use monochange_core::PropagationByKind;
 use monochange_core::ProviderMergeRequestSettings;
 use monochange_core::ProviderReleaseNotesSource;
 use monochange_core::ProviderReleaseSettings;
@@ -25,6 +27,7 @@ use monochange_core::ReleaseNotesSection;
 use monochange_core::SourceChangeRequest;
 use monochange_core::SourceConfiguration;
 use monochange_core::SourceProvider;
+use monochange_core::VersionScheme;
 use monochange_core::WorkspaceConfiguration;
 use monochange_core::WorkspaceDefaults;
 use semver::Version;
@@ -47,6 +50,7 @@ fn minimal_manifest_with_target(id: &str, version: &str) -> ReleaseManifest {
 			members: vec![],
 			rendered_title: format!("Release {id} {version}"),
 			rendered_changelog_title: format!("{id} {version}"),
+			prerelease: None,
 		}],
 		released_packages: vec![],
 		changed_files: vec![],
@@ -74,6 +78,7 @@ fn empty_configuration(root: &Path) -> WorkspaceConfiguration {
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -84,6 +89,9 @@ fn empty_configuration(root: &Path) -> WorkspaceConfiguration {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	}
 }
 
@@ -120,6 +128,7 @@ fn sample_manifest() -> ReleaseManifest {
 			members: vec!["pkg-a".to_string(), "pkg-b".to_string()],
 			rendered_title: "Release sdk v2.0.0".to_string(),
 			rendered_changelog_title: "sdk v2.0.0".to_string(),
+			prerelease: None,
 		}],
 		released_packages: vec!["pkg-a".to_string(), "pkg-b".to_string()],
 		changed_files: vec![
@@ -231,6 +240,9 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 		release: true,
 		publish: PublishSettings::default(),
 		version_format: VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
+		propagation_by_kind: PropagationByKind::default(),
 	}];
 	configuration.groups = vec![monochange_core::GroupDefinition {
 		id: "sdk".to_string(),
@@ -245,6 +257,8 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 		tag: true,
 		release: true,
 		version_format: VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
 	}];
 	let package = sample_package(root, "pkg-a", PackageType::Cargo);
 	let sorted_tags = vec![
@@ -276,6 +290,7 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 				planned_version: Some(Version::new(1, 0, 1)),
 				group_id: None,
 				reasons: Vec::new(),
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
@@ -286,6 +301,7 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 				planned_version: None,
 				group_id: None,
 				reasons: Vec::new(),
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
@@ -296,6 +312,7 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 				planned_version: Some(Version::new(1, 0, 0)),
 				group_id: None,
 				reasons: vec!["feature".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
@@ -309,6 +326,7 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 			recommended_bump: BumpSeverity::Minor,
 		}],
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -351,6 +369,7 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 		planned_version: Some(Version::new(1, 0, 0)),
 		group_id: None,
 		reasons: Vec::new(),
+		causes: Vec::new(),
 		upstream_sources: Vec::new(),
 		warnings: Vec::new(),
 	}];
@@ -388,6 +407,7 @@ async fn release_target_and_title_helpers_cover_provider_and_skip_paths() {
 				decisions: Vec::new(),
 				groups: Vec::new(),
 				warnings: Vec::new(),
+				version_conflicts: Vec::new(),
 				unresolved_items: Vec::new(),
 				compatibility_evidence: Vec::new(),
 			}
@@ -442,6 +462,9 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				..PublishSettings::default()
 			},
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		},
 		PackageDefinition {
 			id: "web".to_string(),
@@ -464,6 +487,9 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				..PublishSettings::default()
 			},
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		},
 		PackageDefinition {
 			id: "disabled".to_string(),
@@ -486,6 +512,9 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				..PublishSettings::default()
 			},
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		},
 		PackageDefinition {
 			id: "private".to_string(),
@@ -507,6 +536,9 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				..PublishSettings::default()
 			},
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		},
 	];
 
@@ -528,6 +560,7 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				planned_version: Some(Version::new(1, 2, 0)),
 				group_id: None,
 				reasons: vec!["feature".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
@@ -538,6 +571,7 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				planned_version: Some(Version::new(2, 0, 1)),
 				group_id: None,
 				reasons: vec!["fix".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
@@ -548,6 +582,7 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				planned_version: Some(Version::new(1, 0, 1)),
 				group_id: None,
 				reasons: vec!["fix".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
@@ -558,6 +593,7 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				planned_version: Some(Version::new(1, 0, 1)),
 				group_id: None,
 				reasons: vec!["fix".to_string()],
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
@@ -568,12 +604,14 @@ fn build_package_publication_targets_filters_disabled_and_preserves_publish_meta
 				planned_version: Some(Version::new(9, 9, 9)),
 				group_id: None,
 				reasons: Vec::new(),
+				causes: Vec::new(),
 				upstream_sources: Vec::new(),
 				warnings: Vec::new(),
 			},
 		],
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -619,6 +657,7 @@ fn build_release_manifest_copies_package_publications_from_prepared_release() {
 			decisions: Vec::new(),
 			groups: Vec::new(),
 			warnings: Vec::new(),
+			version_conflicts: Vec::new(),
 			unresolved_items: Vec::new(),
 			compatibility_evidence: Vec::new(),
 		},
@@ -642,6 +681,7 @@ fn build_release_manifest_copies_package_publications_from_prepared_release() {
 			attestations: monochange_core::PublishAttestationSettings::default(),
 		}],
 		dry_run: false,
+		snapshot: None,
 	};
 
 	let manifest = build_release_manifest(&cli_command, &prepared_release, &[]);
@@ -831,6 +871,7 @@ fn release_paths_from_manifest_computes_hash_relative_and_absolute() {
 			members: vec![],
 			rendered_title: "1.0.0".to_string(),
 			rendered_changelog_title: "[1.0.0]".to_string(),
+			prerelease: None,
 		}],
 		released_packages: vec![],
 		changed_files: vec![],
@@ -877,6 +918,7 @@ fn release_paths_from_record_produces_same_hash_as_from_manifest() {
 			members: vec![],
 			rendered_title: "1.0.0".to_string(),
 			rendered_changelog_title: "[1.0.0]".to_string(),
+			prerelease: None,
 		}],
 		released_packages: vec![],
 		changed_files: vec![],
@@ -970,6 +1012,7 @@ fn deduplicate_uses_persistent_index_to_skip_scan() {
 		release: true,
 		tag_name: "v2.0.0".to_string(),
 		members: vec![],
+		prerelease: None,
 	};
 	let result = deduplicate_overlapping_release_records(
 		root,
@@ -996,6 +1039,7 @@ fn deduplicate_skips_current_record_dir_during_overlap_scan() {
 		release: true,
 		tag_name: "v1.2.3".to_string(),
 		members: vec![],
+		prerelease: None,
 	};
 
 	let result = deduplicate_overlapping_release_records(root, &[target], &current_record_dir);
@@ -1306,3 +1350,61 @@ fn validate_release_record_file_fast_path_reports_error_for_unreadable_file() {
 	permissions.set_mode(0o644);
 	let _ = fs::set_permissions(&path, permissions);
 }
+
+#[test]
+fn build_manifest_updates_parallel_rewrites_unreleased_dependents_whose_range_no_longer_resolves() {
+	let tempdir = monochange_test_helpers::fs::setup_scenario_workspace_from(
+		env!("CARGO_MANIFEST_DIR"),
+		"dependency-propagation/workspace",
+	);
+	let root = tempdir.path();
+	let packages = crate::discover_workspace(root)
+		.unwrap_or_else(|error| panic!("discover workspace: {error}"))
+		.packages;
+	let core = packages
+		.iter()
+		.find(|package| package.name == "range-core")
+		.unwrap_or_else(|| panic!("range-core package"));
+	let plan = ReleasePlan {
+		workspace_root: root.to_path_buf(),
+		decisions: vec![ReleaseDecision {
+			package_id: core.id.clone(),
+			trigger_type: "changeset".to_string(),
+			recommended_bump: BumpSeverity::Minor,
+			planned_version: Some(Version::new(1, 3, 0)),
+			group_id: None,
+			reasons: vec!["release".to_string()],
+			causes: Vec::new(),
+			upstream_sources: Vec::new(),
+			warnings: Vec::new(),
+		}],
+		groups: Vec::new(),
+		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
+		unresolved_items: Vec::new(),
+		compatibility_evidence: Vec::new(),
+	};
+
+	let updates =
+		build_manifest_updates_parallel(&packages, &plan, DependencyPropagation::OutOfRange)
+			.unwrap_or_else(|error| panic!("manifest updates: {error}"));
+	let updated = |relative: &str| {
+		updates
+			.iter()
+			.find(|update| update.path.ends_with(relative))
+			.map(|update| String::from_utf8_lossy(&update.content).into_owned())
+	};
+
+	let core_manifest = updated("crates/core/Cargo.toml")
+		.unwrap_or_else(|| panic!("expected the released manifest to be rewritten"));
+	assert!(core_manifest.contains("version = \"1.3.0\""));
+	// `~1.2.0` no longer admits 1.3.0, so the unreleased `range-cli` manifest
+	// is still rewritten to keep the workspace resolving.
+	let cli_manifest = updated("crates/cli/Cargo.toml")
+		.unwrap_or_else(|| panic!("expected the out-of-range dependent to be rewritten"));
+	assert!(cli_manifest.contains("version = \"1.0.0\""));
+	assert!(!cli_manifest.contains("~1.2.0"));
+	// `1.2` and `1` still admit the planned versions, so those manifests stay untouched.
+	assert_eq!(updated("crates/app/Cargo.toml"), None);
+	assert_eq!(updated("crates/leaf/Cargo.toml"), None);
+}
@@ -0,0 +1,220 @@
+use monochange_test_helpers::fs::setup_scenario_workspace_from;
+use tempfile::TempDir;
+
+use super::*;
+
+fn setup_explain_workspace() -> TempDir {
+	setup_scenario_workspace_from(env!("CARGO_MANIFEST_DIR"), "release-explain/workspace")
+}
+
+fn explain(tempdir: &TempDir, package: &str) -> ReleaseExplanation {
+	explain_release(tempdir.path(), package).unwrap_or_else(|error| panic!("explain: {error}"))
+}
+
+fn dependency<'a>(node: &'a ExplanationNode, id: &str) -> &'a DependencyCause {
+	node.causes
+		.iter()
+		.find_map(|cause| {
+			match cause {
+				ExplanationCause::Dependency(dependency) if dependency.upstream.id == id => {
+					Some(dependency)
+				}
+				_ => None,
+			}
+		})
+		.unwrap_or_else(|| panic!("expected `{}` to depend on `{id}`", node.id))
+}
+
+fn changeset_paths(node: &ExplanationNode) -> Vec<&str> {
+	node.causes
+		.iter()
+		.filter_map(|cause| {
+			match cause {
+				ExplanationCause::Changeset(changeset) => Some(changeset.path.as_str()),
+				_ => None,
+			}
+		})
+		.collect()
+}
+
+#[test]
+fn explain_release_traces_dependencies_back_to_their_changesets() {
+	let tempdir = setup_explain_workspace();
+	let explanation = explain(&tempdir, "web");
+
+	assert_eq!(explanation.package.bump, BumpSeverity::Major);
+	assert_eq!(
+		explanation.package.planned_version.as_deref(),
+		Some("2.1.0")
+	);
+	assert_eq!(
+		changeset_paths(&explanation.package),
+		[".changeset/web-dashboard.md", ".changeset/web-shell.md"]
+	);
+
+	let api = dependency(&explanation.package, "api");
+	assert_eq!(api.reason, "depends on `api`");
+	assert_eq!(api.dependency_kind, DependencyKind::Runtime);
+	assert_eq!(api.upstream.trigger, "transitive-dependency");
+	let core = dependency(&api.upstream, "core");
+	assert_eq!(core.upstream.bump, BumpSeverity::Major);
+	assert_eq!(
+		changeset_paths(&core.upstream),
+		[".changeset/core-storage.md"]
+	);
+	assert_eq!(explanation.origins, ["core", "web"]);
+
+	assert_eq!(explanation.version_conflicts.len(), 1);
+	assert!(
+		explanation.version_conflicts[0].contains("using highest version `2.1.0`"),
+		"{:?}",
+		explanation.version_conflicts
+	);
+}
+
+#[test]
+fn explain_release_names_the_group_members_that_drove_a_synchronized_bump() {
+	let tempdir = setup_explain_workspace();
+	let explanation = explain(&tempdir, "sdk-a");
+
+	assert_eq!(explanation.group.as_deref(), Some("sdk"));
+	assert_eq!(explanation.package.bump, BumpSeverity::Minor);
+	assert_eq!(explanation.package.trigger, "version-group-synchronization");
+	let members = explanation
+		.package
+		.causes
+		.iter()
+		.find_map(|cause| {
+			match cause {
+				ExplanationCause::Group(group) => Some(group),
+				_ => None,
+			}
+		})
+		.unwrap_or_else(|| panic!("expected a group cause"));
+	assert_eq!(members.group, "sdk");
+	assert_eq!(members.members.len(), 1);
+	assert_eq!(members.members[0].id, "sdk-b");
+	assert_eq!(
+		changeset_paths(&members.members[0]),
+		[".changeset/sdk-helpers.md"]
+	);
+	// `sdk-b` is explained as part of the group, so it does not loop back.
+	assert!(
+		members.members[0]
+			.causes
+			.iter()
+			.all(|cause| !matches!(cause, ExplanationCause::Group(_)))
+	);
+}
+
+#[test]
+fn explain_release_names_the_linked_group_members_that_raised_a_bump() {
+	let tempdir = setup_scenario_workspace_from(
+		env!("CARGO_MANIFEST_DIR"),
+		"linked-groups/workspace",
+	);
+	let explanation = explain(&tempdir, "cli");
+
+	assert_eq!(explanation.group, None);
+	assert_eq!(explanation.package.bump, BumpSeverity::Minor);
+	assert_eq!(
+		changeset_paths(&explanation.package),
+		[".changeset/cli-help.md"]
+	);
+	let linked = explanation
+		.package
+		.causes
+		.iter()
+		.find_map(|cause| {
+			match cause {
+				ExplanationCause::LinkedGroup(group) => Some(group),
+				_ => None,
+			}
+		})
+		.unwrap_or_else(|| panic!("expected a linked group cause"));
+	assert_eq!(linked.group, "tools");
+	assert_eq!(linked.members.len(), 1);
+	assert_eq!(linked.members[0].id, "core");
+	assert_eq!(linked.members[0].bump, BumpSeverity::Minor);
+	assert_eq!(
+		changeset_paths(&linked.members[0]),
+		[".changeset/streaming-parser.md"]
+	);
+
+	// `core` set the shared bump itself, so it has no linked group cause.
+	let core = explain(&tempdir, "core");
+	assert!(
+		core.package
+			.causes
+			.iter()
+			.all(|cause| !matches!(cause, ExplanationCause::LinkedGroup(_)))
+	);
+}
+
+#[test]
+fn explain_release_reports_dependencies_held_back_by_caused_by() {
+	let tempdir = setup_explain_workspace();
+	let explanation = explain(&tempdir, "docs");
+
+	assert_eq!(explanation.package.bump, BumpSeverity::Patch);
+	let ExplanationCause::Changeset(changeset) = &explanation.package.causes[0] else {
+		panic!("expected a changeset cause");
+	};
+	assert_eq!(changeset.caused_by, ["sdk"]);
+	assert_eq!(
+		explanation.held_back,
+		[HeldBackDependency {
+			id: "sdk-a".to_string(),
+			bump: BumpSeverity::Minor,
+			reason: "suppressed by `caused_by` in `.changeset/docs-sdk.md`".to_string(),
+		}]
+	);
+}
+
+#[test]
+fn explain_release_rejects_unknown_packages() {
+	let tempdir = setup_explain_workspace();
+	let error = explain_release(tempdir.path(), "missing")
+		.err()
+		.unwrap_or_else(|| panic!("expected unknown package to be rejected"));
+	assert!(
+		error
+			.to_string()
+			.contains("package `missing` was not found in the workspace")
+	);
+}
+
+#[test]
+fn render_release_explanation_draws_text_and_markdown_trees() {
+	let tempdir = setup_explain_workspace();
+	let explanation = explain(&tempdir, "api");
+
+	let text = render_release_explanation(&explanation, OutputFormat::Text);
+	assert!(
+		text.contains(
+			"  api: patch -> 1.0.1 (transitive-dependency)\n    depends on `core`\n      core: major -> 2.0.0 (direct-change)\n        changeset .changeset/core-storage.md requests major: break the storage api\n"
+		),
+		"{text}"
+	);
+
+	let markdown = render_release_explanation(&explanation, OutputFormat::Markdown);
+	assert!(
+		markdown.starts_with("# Release explanation for `api`\n"),
+		"{markdown}"
+	);
+	assert!(
+		markdown.contains(
+			"- **api**: `patch` -> `1.0.1` (transitive-dependency)\n  - depends on `core`\n    - **core**: `major` -> `2.0.0` (direct-change)\n"
+		),
+		"{markdown}"
+	);
+
+	let json = render_release_explanation(&explanation, OutputFormat::Json);
+	let value = serde_json::from_str::<serde_json::Value>(&json)
+		.unwrap_or_else(|error| panic!("parse explanation json: {error}"));
+	assert_eq!(value["package"]["causes"][0]["kind"], "dependency");
+	assert_eq!(
+		value["package"]["causes"][0]["upstream"]["causes"][0]["kind"],
+		"changeset"
+	);
+}
@@ -0,0 +1,207 @@
+use chrono::NaiveDate;
+use monochange_core::BumpSeverity;
+use monochange_core::PlannedVersionGroup;
+use monochange_core::ReleaseDecision;
+
+use super::*;
+
+fn inputs(entries: &[(&str, &str)]) -> BTreeMap<String, Vec<String>> {
+	entries
+		.iter()
+		.map(|(name, value)| ((*name).to_string(), vec![(*value).to_string()]))
+		.collect()
+}
+
+fn release_datetime() -> chrono::NaiveDateTime {
+	NaiveDate::from_ymd_opt(2026, 10, 17)
+		.and_then(|date| date.and_hms_opt(9, 30, 5))
+		.unwrap_or_else(|| panic!("valid datetime"))
+}
+
+#[test]
+fn snapshot_request_reads_step_inputs() {
+	assert_eq!(
+		SnapshotRequest::from_step_inputs(&BTreeMap::new())
+			.unwrap_or_else(|error| panic!("request: {error}")),
+		None
+	);
+	let request = SnapshotRequest::from_step_inputs(&inputs(&[("snapshot", "canary")]))
+		.unwrap_or_else(|error| panic!("request: {error}"))
+		.unwrap_or_else(|| panic!("expected snapshot request"));
+	assert_eq!(request.tag, "canary");
+	assert_eq!(request.template, DEFAULT_SNAPSHOT_TEMPLATE);
+
+	let request = SnapshotRequest::from_step_inputs(&inputs(&[
+		("snapshot", "pr-42"),
+		("snapshot_template", "{{ tag }}.{{ timestamp }}"),
+	]))
+	.unwrap_or_else(|error| panic!("request: {error}"))
+	.unwrap_or_else(|| panic!("expected snapshot request"));
+	assert_eq!(request.template, "{{ tag }}.{{ timestamp }}");
+}
+
+#[test]
+fn snapshot_request_rejects_invalid_tags() {
+	for tag in ["latest", "canary.1", "42"] {
+		let error = SnapshotRequest::from_step_inputs(&inputs(&[("snapshot", tag)]))
+			.err()
+			.unwrap_or_else(|| panic!("expected `{tag}` to be rejected"));
+		assert!(error.to_string().contains("invalid snapshot tag"));
+	}
+}
+
+#[test]
+fn render_suffix_fills_the_default_template() {
+	let request = SnapshotRequest {
+		tag: "canary".to_string(),
+		template: DEFAULT_SNAPSHOT_TEMPLATE.to_string(),
+	};
+	let suffix = request
+		.render_suffix(release_datetime(), Some("abc1234def"))
+		.unwrap_or_else(|error| panic!("suffix: {error}"));
+	assert_eq!(suffix.as_str(), "canary.20261017.abc1234");
+
+	let suffix = request
+		.render_suffix(release_datetime(), Some("0123456789"))
+		.unwrap_or_else(|error| panic!("suffix: {error}"));
+	assert_eq!(suffix.as_str(), "canary.20261017.g0123456");
+}
+
+#[test]
+fn render_suffix_rejects_invalid_prereleases() {
+	let request = SnapshotRequest {
+		tag: "canary".to_string(),
+		template: DEFAULT_SNAPSHOT_TEMPLATE.to_string(),
+	};
+	let error = request
+		.render_suffix(release_datetime(), None)
+		.err()
+		.unwrap_or_else(|| panic!("expected missing commit to produce an invalid suffix"));
+	assert!(
+		error
+			.to_string()
+			.contains("is not a valid semver prerelease")
+	);
+
+	let request = SnapshotRequest {
+		tag: "canary".to_string(),
+		template: "{{ tag }}.{{ timestamp }}".to_string(),
+	};
+	let suffix = request
+		.render_suffix(release_datetime(), None)
+		.unwrap_or_else(|error| panic!("suffix: {error}"));
+	assert_eq!(suffix.as_str(), "canary.20261017093005");
+}
+
+#[test]
+fn apply_snapshot_versions_rewrites_decisions_and_groups() {
+	let mut plan = ReleasePlan {
+		workspace_root: PathBuf::from("."),
+		decisions: vec![
+			ReleaseDecision {
+				package_id: "cargo:core".to_string(),
+				trigger_type: "direct-change".to_string(),
+				recommended_bump: BumpSeverity::Minor,
+				planned_version: Some(Version::parse("1.4.0-beta.2").unwrap()),
+				group_id: Some("sdk".to_string()),
+				reasons: Vec::new(),
+				causes: Vec::new(),
+				upstream_sources: Vec::new(),
+				warnings: Vec::new(),
+			},
+			ReleaseDecision {
+				package_id: "cargo:app".to_string(),
+				trigger_type: "none".to_string(),
+				recommended_bump: BumpSeverity::None,
+				planned_version: None,
+				group_id: None,
+				reasons: Vec::new(),
+				causes: Vec::new(),
+				upstream_sources: Vec::new(),
+				warnings: Vec::new(),
+			},
+		],
+		groups: vec![PlannedVersionGroup {
+			group_id: "sdk".to_string(),
+			display_name: "sdk".to_string(),
+			members: vec!["cargo:core".to_string()],
+			mismatch_detected: false,
+			planned_version: Some(Version::new(1, 4, 0)),
+			recommended_bump: BumpSeverity::Minor,
+		}],
+		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
+		unresolved_items: Vec::new(),
+		compatibility_evidence: Vec::new(),
+	};
+	let suffix = Prerelease::new("canary.20261017.abc1234").unwrap();
+
+	apply_snapshot_versions(&mut plan, &suffix);
+
+	let expected = Version::parse("1.4.0-canary.20261017.abc1234").unwrap();
+	assert_eq!(plan.decisions[0].planned_version.as_ref(), Some(&expected));
+	assert_eq!(plan.decisions[1].planned_version, None);
+	assert_eq!(plan.groups[0].planned_version.as_ref(), Some(&expected));
+}
+
+#[test]
+fn create_snapshot_working_tree_copies_sources_and_rebases_updates() {
+	let root = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	fs::create_dir_all(root.path().join("crates/core/src")).unwrap();
+	fs::create_dir_all(root.path().join("target/debug")).unwrap();
+	fs::create_dir_all(root.path().join("node_modules/left-pad")).unwrap();
+	fs::write(root.path().join("crates/core/Cargo.toml"), "[package]\n").unwrap();
+	fs::write(root.path().join("crates/core/src/lib.rs"), "").unwrap();
+	fs::write(root.path().join("target/debug/core"), "").unwrap();
+	fs::write(root.path().join("node_modules/left-pad/index.js"), "").unwrap();
+
+	let guard = create_snapshot_working_tree(root.path())
+		.unwrap_or_else(|error| panic!("working tree: {error}"));
+	let working_tree = guard.path().to_path_buf();
+
+	assert!(working_tree.join("crates/core/Cargo.toml").is_file());
+	assert!(working_tree.join("crates/core/src/lib.rs").is_file());
+	assert!(!working_tree.join("target").exists());
+	assert!(!working_tree.join("node_modules").exists());
+
+	let updates = rebase_file_updates(
+		root.path(),
+		&working_tree,
+		&[FileUpdate {
+			path: root.path().join("crates/core/Cargo.toml"),
+			content: b"[package]\nversion = \"1.0.0-canary.1\"\n".to_vec(),
+		}],
+	);
+	assert_eq!(updates[0].path, working_tree.join("crates/core/Cargo.toml"));
+
+	let clone = guard.clone();
+	drop(guard);
+	assert!(working_tree.is_dir());
+	drop(clone);
+	assert!(!working_tree.exists());
+}
+
+#[test]
+fn prepared_snapshot_does_not_serialize_its_temporary_working_tree() {
+	let guard = create_snapshot_working_tree(
+		tempfile::tempdir()
+			.unwrap_or_else(|error| panic!("tempdir: {error}"))
+			.path(),
+	)
+	.unwrap_or_else(|error| panic!("working tree: {error}"));
+	let snapshot = PreparedSnapshot {
+		tag: "canary".to_string(),
+		suffix: "canary.20261017".to_string(),
+		working_tree: Some(guard.path().to_path_buf()),
+		working_tree_guard: Some(guard),
+	};
+
+	let json = serde_json::to_value(&snapshot).unwrap_or_else(|error| panic!("json: {error}"));
+	assert_eq!(
+		json,
+		serde_json::json!({ "tag": "canary", "suffix": "canary.20261017" })
+	);
+	let restored = serde_json::from_value::<PreparedSnapshot>(json)
+		.unwrap_or_else(|error| panic!("restore: {error}"));
+	assert_eq!(restored.working_tree, None);
+}
@@ -272,11 +272,13 @@ enabled = true
 			planned_version: Some(semver::Version::new(1, 2, 0)),
 			group_id: None,
 			reasons: Vec::new(),
+			causes: Vec::new(),
 			upstream_sources: Vec::new(),
 			warnings: Vec::new(),
 		}],
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -310,3 +312,172 @@ fn inferred_lockfile_ecosystem_type_maps_python_when_commands_are_not_configured
 		Some(EcosystemType::Python)
 	);
 }
+
+#[test]
+fn apply_versioned_file_definition_syncs_helm_app_version_with_the_owner() {
+	let tempdir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let chart_dir = tempdir.path().join("charts/api");
+	std::fs::create_dir_all(&chart_dir).unwrap_or_else(|error| panic!("mkdir chart: {error}"));
+	std::fs::write(
+		chart_dir.join("Chart.yaml"),
+		"apiVersion: v2\nname: api\nversion: 1.2.0\nappVersion: \"2.4.1\"\n",
+	)
+	.unwrap_or_else(|error| panic!("write Chart.yaml: {error}"));
+	let chart = monochange_helm::parse_chart(&chart_dir.join("Chart.yaml"), tempdir.path())
+		.unwrap_or_else(|error| panic!("parse chart: {error}"))
+		.unwrap_or_else(|| panic!("chart"));
+	let configuration =
+		monochange_config::load_workspace_configuration(&fixture_path("monochange/release-base"))
+			.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let context = VersionedFileUpdateContext {
+		package_by_config_id: BTreeMap::new(),
+		package_by_native_name: BTreeMap::from([("api", &chart)]),
+		current_versions_by_native_name: BTreeMap::new(),
+		released_versions_by_native_name: BTreeMap::from([
+			("api".to_string(), "1.3.0".to_string()),
+			("server".to_string(), "2.5.0".to_string()),
+		]),
+		configuration: &configuration,
+	};
+	let definition = monochange_core::VersionedFileDefinition {
+		path: "charts/api/Chart.yaml".to_string(),
+		ecosystem_type: Some(EcosystemType::Helm),
+		prefix: None,
+		fields: Some(vec!["appVersion".to_string()]),
+		name: None,
+		regex: None,
+	};
+	let mut updates = BTreeMap::new();
+
+	apply_versioned_file_definition(
+		tempdir.path(),
+		&mut updates,
+		&definition,
+		"2.5.0",
+		None,
+		&["server".to_string()],
+		&context,
+	)
+	.unwrap_or_else(|error| panic!("apply helm update: {error}"));
+	let updated_document = updates
+		.into_values()
+		.next()
+		.unwrap_or_else(|| panic!("updated Chart.yaml"));
+	assert!(matches!(
+		updated_document,
+		CachedDocument::Text(contents)
+			if contents == "apiVersion: v2\nname: api\nversion: 1.3.0\nappVersion: \"2.5.0\"\n"
+	));
+}
+
+#[test]
+#[cfg(feature = "ruby")]
+fn apply_versioned_file_definition_rewrites_ruby_version_constants() {
+	let tempdir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let version_dir = tempdir.path().join("engines/admin/lib/admin");
+	std::fs::create_dir_all(&version_dir)
+		.unwrap_or_else(|error| panic!("mkdir version dir: {error}"));
+	std::fs::write(
+		version_dir.join("version.rb"),
+		"module Admin\n  VERSION = \"0.9.0\"\n  API_VERSION = '0.9.0'.freeze\nend\n",
+	)
+	.unwrap_or_else(|error| panic!("write version.rb: {error}"));
+	let configuration =
+		monochange_config::load_workspace_configuration(&fixture_path("monochange/release-base"))
+			.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let context = VersionedFileUpdateContext {
+		package_by_config_id: BTreeMap::new(),
+		package_by_native_name: BTreeMap::new(),
+		current_versions_by_native_name: BTreeMap::new(),
+		released_versions_by_native_name: BTreeMap::from([(
+			"server".to_string(),
+			"2.5.0".to_string(),
+		)]),
+		configuration: &configuration,
+	};
+	let definition = monochange_core::VersionedFileDefinition {
+		path: "engines/admin/lib/admin/version.rb".to_string(),
+		ecosystem_type: Some(EcosystemType::Ruby),
+		prefix: None,
+		fields: Some(vec!["VERSION".to_string(), "API_VERSION".to_string()]),
+		name: None,
+		regex: None,
+	};
+	let mut updates = BTreeMap::new();
+
+	apply_versioned_file_definition(
+		tempdir.path(),
+		&mut updates,
+		&definition,
+		"2.5.0",
+		None,
+		&["server".to_string()],
+		&context,
+	)
+	.unwrap_or_else(|error| panic!("apply ruby update: {error}"));
+	let updated_document = updates
+		.into_values()
+		.next()
+		.unwrap_or_else(|| panic!("updated version.rb"));
+	assert!(matches!(
+		updated_document,
+		CachedDocument::Text(contents)
+			if contents == "module Admin\n  VERSION = \"2.5.0\"\n  API_VERSION = '2.5.0'.freeze\nend\n"
+	));
+}
+
+#[test]
+#[cfg(feature = "jvm")]
+fn apply_versioned_file_definition_rewrites_jvm_properties_keys() {
+	let tempdir = tempfile::tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let resources_dir = tempdir.path().join("tools/cli/src/main/resources");
+	std::fs::create_dir_all(&resources_dir)
+		.unwrap_or_else(|error| panic!("mkdir resources dir: {error}"));
+	std::fs::write(
+		resources_dir.join("version.properties"),
+		"# generated at build time\ncli.version = 0.9.0\ncli.name=tools\n",
+	)
+	.unwrap_or_else(|error| panic!("write version.properties: {error}"));
+	let configuration =
+		monochange_config::load_workspace_configuration(&fixture_path("monochange/release-base"))
+			.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let context = VersionedFileUpdateContext {
+		package_by_config_id: BTreeMap::new(),
+		package_by_native_name: BTreeMap::new(),
+		current_versions_by_native_name: BTreeMap::new(),
+		released_versions_by_native_name: BTreeMap::from([(
+			"server".to_string(),
+			"0.10.0".to_string(),
+		)]),
+		configuration: &configuration,
+	};
+	let definition = monochange_core::VersionedFileDefinition {
+		path: "tools/cli/src/main/resources/version.properties".to_string(),
+		ecosystem_type: Some(EcosystemType::Jvm),
+		prefix: None,
+		fields: Some(vec!["cli.version".to_string()]),
+		name: None,
+		regex: None,
+	};
+	let mut updates = BTreeMap::new();
+
+	apply_versioned_file_definition(
+		tempdir.path(),
+		&mut updates,
+		&definition,
+		"0.10.0",
+		None,
+		&["server".to_string()],
+		&context,
+	)
+	.unwrap_or_else(|error| panic!("apply jvm update: {error}"));
+	let updated_document = updates
+		.into_values()
+		.next()
+		.unwrap_or_else(|| panic!("updated version.properties"));
+	assert!(matches!(
+		updated_document,
+		CachedDocument::Text(contents)
+			if contents == "# generated at build time\ncli.version = 0.10.0\ncli.name=tools\n"
+	));
+}
@@ -0,0 +1,259 @@
+use monochange_test_helpers::fs::setup_scenario_workspace_from;
+use tempfile::TempDir;
+
+use super::*;
+
+fn setup_graph_workspace() -> TempDir {
+	setup_scenario_workspace_from(env!("CARGO_MANIFEST_DIR"), "workspace-graph/workspace")
+}
+
+fn graph(tempdir: &TempDir, request: &GraphRequest) -> WorkspaceGraph {
+	build_workspace_graph(tempdir.path(), request)
+		.unwrap_or_else(|error| panic!("build graph: {error}"))
+}
+
+fn node_ids(graph: &WorkspaceGraph) -> Vec<&str> {
+	graph.nodes.iter().map(|node| node.id.as_str()).collect()
+}
+
+fn node<'a>(graph: &'a WorkspaceGraph, id: &str) -> &'a GraphNode {
+	graph
+		.nodes
+		.iter()
+		.find(|node| node.id == id)
+		.unwrap_or_else(|| panic!("expected node `{id}`"))
+}
+
+#[test]
+fn build_workspace_graph_includes_every_package_edge_and_group() {
+	let tempdir = setup_graph_workspace();
+	let graph = graph(&tempdir, &GraphRequest::default());
+
+	assert_eq!(node_ids(&graph), ["api", "core", "testkit", "ui", "web"]);
+	let edges = graph
+		.edges
+		.iter()
+		.map(|edge| (edge.from.as_str(), edge.to.as_str(), edge.kind))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		edges,
+		[
+			("api", "core", DependencyKind::Runtime),
+			("core", "testkit", DependencyKind::Development),
+			("testkit", "api", DependencyKind::Runtime),
+			("web", "ui", DependencyKind::Runtime),
+		]
+	);
+	assert_eq!(
+		graph.groups,
+		[GraphGroup {
+			id: "frontend".to_string(),
+			members: vec!["ui".to_string(), "web".to_string()],
+		}]
+	);
+	assert!(
+		graph
+			.nodes
+			.iter()
+			.all(|node| node.bump.is_none() && node.publish_level.is_none())
+	);
+}
+
+#[test]
+fn build_workspace_graph_combines_ecosystem_group_and_package_filters() {
+	let tempdir = setup_graph_workspace();
+
+	let cargo = graph(
+		&tempdir,
+		&GraphRequest {
+			ecosystems: vec!["cargo".to_string()],
+			..GraphRequest::default()
+		},
+	);
+	assert_eq!(node_ids(&cargo), ["api", "core", "testkit"]);
+
+	let frontend = graph(
+		&tempdir,
+		&GraphRequest {
+			groups: vec!["frontend".to_string()],
+			..GraphRequest::default()
+		},
+	);
+	assert_eq!(node_ids(&frontend), ["ui", "web"]);
+	assert_eq!(frontend.edges.len(), 1);
+
+	let neighbourhood = graph(
+		&tempdir,
+		&GraphRequest {
+			packages: vec!["ui".to_string()],
+			..GraphRequest::default()
+		},
+	);
+	assert_eq!(node_ids(&neighbourhood), ["ui", "web"]);
+
+	let nothing = graph(
+		&tempdir,
+		&GraphRequest {
+			ecosystems: vec!["cargo".to_string()],
+			groups: vec!["frontend".to_string()],
+			..GraphRequest::default()
+		},
+	);
+	assert!(nothing.nodes.is_empty());
+	assert!(nothing.groups.is_empty());
+}
+
+#[test]
+fn build_workspace_graph_rejects_unknown_groups_and_packages() {
+	let tempdir = setup_graph_workspace();
+	let group_error = build_workspace_graph(
+		tempdir.path(),
+		&GraphRequest {
+			groups: vec!["backend".to_string()],
+			..GraphRequest::default()
+		},
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected unknown group to be rejected"));
+	assert!(
+		group_error
+			.to_string()
+			.contains("group `backend` was not found in the workspace configuration")
+	);
+
+	let package_error = build_workspace_graph(
+		tempdir.path(),
+		&GraphRequest {
+			packages: vec!["missing".to_string()],
+			..GraphRequest::default()
+		},
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected unknown package to be rejected"));
+	assert!(
+		package_error
+			.to_string()
+			.contains("package `missing` was not found in the workspace")
+	);
+}
+
+#[test]
+fn build_workspace_graph_overlays_the_release_plan_and_publish_levels() {
+	let tempdir = setup_graph_workspace();
+	let graph = graph(
+		&tempdir,
+		&GraphRequest {
+			plan: true,
+			publish_levels: true,
+			..GraphRequest::default()
+		},
+	);
+
+	let core = node(&graph, "core");
+	assert_eq!(core.bump, Some(BumpSeverity::Minor));
+	assert_eq!(core.planned_version.as_deref(), Some("1.1.0"));
+	assert_eq!(node(&graph, "api").bump, Some(BumpSeverity::Patch));
+	assert_eq!(node(&graph, "ui").bump, None);
+
+	// The development dependency from `core` on `testkit` would otherwise
+	// form a cycle.
+	let levels = graph
+		.nodes
+		.iter()
+		.map(|node| (node.id.as_str(), node.publish_level))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		levels,
+		[
+			("api", Some(1)),
+			("core", Some(0)),
+			("testkit", Some(2)),
+			("ui", Some(0)),
+			("web", Some(1)),
+		]
+	);
+}
+
+#[test]
+fn render_workspace_graph_draws_dot_with_bump_colors_and_levels() {
+	let tempdir = setup_graph_workspace();
+	let graph = graph(
+		&tempdir,
+		&GraphRequest {
+			plan: true,
+			publish_levels: true,
+			..GraphRequest::default()
+		},
+	);
+	let dot = render_workspace_graph(&graph, GraphFormat::Dot);
+
+	assert!(dot.starts_with("digraph workspace {\n"), "{dot}");
+	assert!(
+		dot.contains("\tsubgraph \"cluster_frontend\" {\n\t\tlabel=\"frontend\";\n"),
+		"{dot}"
+	);
+	assert!(
+		dot.contains(
+			"\t\"core\" [label=\"core\\n1.0.0 -> 1.1.0 (minor)\\nlevel 0\", fillcolor=\"#fff3cd\"];\n"
+		),
+		"{dot}"
+	);
+	assert!(
+		dot.contains("\t{ rank=same; /* level 0 */ \"core\"; \"ui\"; }\n"),
+		"{dot}"
+	);
+	assert!(
+		dot.contains("\t\"core\" -> \"testkit\" [style=dashed, label=\"development\"];\n"),
+		"{dot}"
+	);
+	assert!(dot.contains("\t\"api\" -> \"core\";\n"), "{dot}");
+}
+
+#[test]
+fn render_workspace_graph_draws_mermaid_and_json() {
+	let tempdir = setup_graph_workspace();
+	let planned = graph(
+		&tempdir,
+		&GraphRequest {
+			plan: true,
+			..GraphRequest::default()
+		},
+	);
+	let mermaid = render_workspace_graph(&planned, GraphFormat::Mermaid);
+	assert!(mermaid.starts_with("flowchart BT\n"), "{mermaid}");
+	assert!(
+		mermaid.contains("\tsubgraph group0[\"frontend\"]\n\t\tn3[\"ui<br/>1.0.0\"]\n"),
+		"{mermaid}"
+	);
+	assert!(mermaid.contains("\tn1 -.->|development| n2\n"), "{mermaid}");
+	assert!(
+		mermaid.contains("\tclassDef minor fill:#fff3cd\n"),
+		"{mermaid}"
+	);
+	assert!(mermaid.contains("\tclass n0,n2 patch\n"), "{mermaid}");
+
+	let levelled = graph(
+		&tempdir,
+		&GraphRequest {
+			publish_levels: true,
+			..GraphRequest::default()
+		},
+	);
+	let mermaid = render_workspace_graph(&levelled, GraphFormat::Mermaid);
+	assert!(
+		mermaid.contains("\tsubgraph level2[\"level 2\"]\n\t\tn2[\"testkit<br/>1.0.0\"]\n\tend\n"),
+		"{mermaid}"
+	);
+
+	let json = render_workspace_graph(&planned, GraphFormat::Json);
+	let value = serde_json::from_str::<serde_json::Value>(&json)
+		.unwrap_or_else(|error| panic!("parse graph json: {error}"));
+	assert_eq!(value["nodes"][1]["id"], "core");
+	assert_eq!(
+		value["nodes"][1]["packageId"],
+		"cargo:crates/core/Cargo.toml"
+	);
+	assert_eq!(value["nodes"][1]["bump"], "minor");
+	assert_eq!(value["edges"][1]["kind"], "development");
+	assert!(value["nodes"][3].get("bump").is_none());
+}
@@ -11,13 +11,16 @@ use monochange_core::HostedCommitRef;
 use monochange_core::HostingCapabilities;
 use monochange_core::HostingProviderKind;
 use monochange_core::PackageDefinition;
+use monochange_core::PreStablePolicy;
 use monochange_core::PreparedChangeset;
+use monochange_core::PropagationByKind;
 use monochange_core::ProviderMergeRequestSettings;
 use monochange_core::ProviderReleaseSettings;
 use monochange_core::ShellConfig;
 use monochange_core::SourceConfiguration;
 use monochange_core::SourceProvider;
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 use monochange_core::WorkspaceConfiguration;
 
 use super::*;
@@ -170,6 +173,7 @@ fn workspace_configuration_with_lockfile_commands() -> WorkspaceConfiguration {
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -187,6 +191,9 @@ fn workspace_configuration_with_lockfile_commands() -> WorkspaceConfiguration {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	}
 }
 
@@ -363,6 +370,9 @@ fn validate_and_discover_release_workspace_cover_fallback_and_errors() {
 			release: true,
 			publish: monochange_core::PublishSettings::default(),
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		}],
 		..configuration
 	};
@@ -401,8 +411,12 @@ fn validate_and_discover_release_workspace_cover_fallback_and_errors() {
 			release: true,
 			publish: monochange_core::PublishSettings::default(),
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		}],
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -413,6 +427,9 @@ fn validate_and_discover_release_workspace_cover_fallback_and_errors() {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let undetected_error = discover_release_workspace(undetected_root.path(), &undetected)
 		.err()
@@ -451,8 +468,12 @@ fn validate_and_discover_release_workspace_cover_fallback_and_errors() {
 			release: true,
 			publish: monochange_core::PublishSettings::default(),
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
+			propagation_by_kind: PropagationByKind::default(),
 		}],
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -463,6 +484,9 @@ fn validate_and_discover_release_workspace_cover_fallback_and_errors() {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	};
 	let missing_manifest_error =
 		discover_release_workspace(missing_manifest_root.path(), &missing_manifest)
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use monochange_analysis::AnalysisConfig;
+use monochange_analysis::ChangeFrame;
+use monochange_core::AnalysisEnforcement;
+use monochange_core::BumpSeverity;
+use monochange_core::ChangeSignal;
+use monochange_core::CompatibilityAssessment;
+use monochange_core::DiscoveryReport;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageRecord;
+use monochange_core::WorkspaceConfiguration;
+use monochange_core::git::git_command;
+
+use crate::release_artifacts::render_tag_name;
+
+/// A package whose changesets declare a weaker bump than semantic analysis
+/// of its release range implies.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct UnderDeclaredBump {
+	pub package: String,
+	pub declared: BumpSeverity,
+	pub implied: BumpSeverity,
+	pub baseline: String,
+	pub summary: String,
+}
+
+impl UnderDeclaredBump {
+	fn describe(&self, enforcement: AnalysisEnforcement) -> String {
+		let Self {
+			package,
+			declared,
+			implied,
+			baseline,
+			summary,
+		} = self;
+		match enforcement {
+			AnalysisEnforcement::Raise => {
+				format!(
+					"raised `{package}` from {declared} to {implied} because semantic analysis since `{baseline}` found: {summary}"
+				)
+			}
+			_ => {
+				format!(
+					"`{package}` declares a {declared} bump but semantic analysis since `{baseline}` implies {implied}: {summary}"
+				)
+			}
+		}
+	}
+}
+
+/// Semantic analysis evidence gathered for the packages that are about to
+/// release.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct AnalysisEvidence {
+	pub assessments: Vec<CompatibilityAssessment>,
+	pub under_declared: Vec<UnderDeclaredBump>,
+}
+
+impl AnalysisEvidence {
+	/// Apply `[defaults] analysis_enforcement` to the evidence.
+	///
+	/// Returns the assessments the planner should treat as a bump floor and
+	/// the warnings to attach to the plan, or an error when enforcement is
+	/// `error` and a package is under-declared.
+	pub(crate) fn enforce(
+		self,
+		enforcement: AnalysisEnforcement,
+	) -> MonochangeResult<(Vec<CompatibilityAssessment>, Vec<String>)> {
+		let messages = self
+			.under_declared
+			.iter()
+			.map(|bump| bump.describe(enforcement))
+			.collect::<Vec<_>>();
+		match enforcement {
+			AnalysisEnforcement::Raise => Ok((self.assessments, messages)),
+			AnalysisEnforcement::Error if !messages.is_empty() => {
+				let details = messages
+					.iter()
+					.map(|message| format!("\n- {message}"))
+					.collect::<Vec<_>>()
+					.concat();
+				Err(MonochangeError::Config(format!(
+					"changesets declare weaker bumps than semantic analysis implies:{details}\nraise the bumps in the changesets, or set `analysis_enforcement = \"raise\"` under [defaults] to apply them automatically"
+				)))
+			}
+			AnalysisEnforcement::Warn => Ok((Vec::new(), messages)),
+			_ => Ok((Vec::new(), Vec::new())),
+		}
+	}
+}
+
+/// Run the registered semantic analyzers over the release range of every
+/// package with a change signal.
+///
+/// A package's release range starts at the tag of its current version and
+/// ends at `HEAD`. Packages without such a tag have never been released
+/// from this repository and are skipped.
+pub(crate) fn collect_analysis_evidence(
+	configuration: &WorkspaceConfiguration,
+	discovery: &DiscoveryReport,
+	change_signals: &[ChangeSignal],
+) -> MonochangeResult<AnalysisEvidence> {
+	if configuration.defaults.analysis_enforcement == AnalysisEnforcement::Off
+		|| change_signals.is_empty()
+	{
+		return Ok(AnalysisEvidence::default());
+	}
+
+	let root = discovery.workspace_root.as_path();
+	let declared = declared_bumps(&discovery.packages, change_signals);
+	let mut packages_by_baseline = BTreeMap::<String, Vec<&PackageRecord>>::new();
+	for package in discovery
+		.packages
+		.iter()
+		.filter(|package| declared.contains_key(package.id.as_str()))
+	{
+		if let Some(baseline) = release_baseline(root, configuration, package) {
+			packages_by_baseline
+				.entry(baseline)
+				.or_default()
+				.push(package);
+		}
+	}
+
+	let mut evidence = AnalysisEvidence::default();
+	for (baseline, packages) in packages_by_baseline {
+		let frame = ChangeFrame::CustomRange {
+			base: baseline.clone(),
+			head: "HEAD".to_string(),
+		};
+		let analysis =
+			monochange_analysis::analyze_changes(root, &frame, &AnalysisConfig::default())?;
+		for package in packages {
+			let Some(assessment) = analysis
+				.package_analyses
+				.values()
+				.find(|package_analysis| package_analysis.package_record_id == package.id)
+				.and_then(monochange_analysis::PackageChangeAnalysis::compatibility_assessment)
+			else {
+				continue;
+			};
+			let declared = declared_bump(&declared, &discovery.packages, package);
+			if assessment.severity > declared {
+				evidence.under_declared.push(UnderDeclaredBump {
+					package: crate::package_display_id(package),
+					declared,
+					implied: assessment.severity,
+					baseline: baseline.clone(),
+					summary: assessment.summary.clone(),
+				});
+			}
+			evidence.assessments.push(assessment);
+		}
+	}
+	Ok(evidence)
+}
+
+/// The bump each changed package requests before propagation.
+fn declared_bumps<'a>(
+	packages: &'a [PackageRecord],
+	change_signals: &[ChangeSignal],
+) -> BTreeMap<&'a str, BumpSeverity> {
+	let mut declared = BTreeMap::<&str, BumpSeverity>::new();
+	for signal in change_signals {
+		let Some(package) = packages
+			.iter()
+			.find(|package| package.id == signal.package_id)
+		else {
+			continue;
+		};
+		let severity = signal.requested_bump.unwrap_or(BumpSeverity::Patch);
+		let entry = declared
+			.entry(package.id.as_str())
+			.or_insert(BumpSeverity::None);
+		*entry = (*entry).max(severity);
+	}
+	declared
+}
+
+/// Version group members release together, so the strongest bump declared
+/// for any member covers the whole group.
+fn declared_bump(
+	declared: &BTreeMap<&str, BumpSeverity>,
+	packages: &[PackageRecord],
+	package: &PackageRecord,
+) -> BumpSeverity {
+	let Some(group_id) = package.version_group_id.as_deref() else {
+		return declared
+			.get(package.id.as_str())
+			.copied()
+			.unwrap_or(BumpSeverity::None);
+	};
+	packages
+		.iter()
+		.filter(|member| member.version_group_id.as_deref() == Some(group_id))
+		.filter_map(|member| declared.get(member.id.as_str()).copied())
+		.max()
+		.unwrap_or(BumpSeverity::None)
+}
+
+/// The tag of the package's current release, when it exists locally.
+fn release_baseline(
+	root: &Path,
+	configuration: &WorkspaceConfiguration,
+	package: &PackageRecord,
+) -> Option<String> {
+	let config_id = package
+		.metadata
+		.get("config_id")
+		.map_or(package.name.as_str(), String::as_str);
+	let identity = configuration.effective_release_identity(config_id)?;
+	if !identity.tag {
+		return None;
+	}
+	let version = package.current_version.as_ref()?;
+	let tag = render_tag_name(
+		&identity.owner_id,
+		&version.to_string(),
+		identity.version_format,
+		&identity.version_scheme,
+	);
+	let exists = git_command(root)
+		.args(["rev-parse", "--verify", "--quiet"])
+		.arg(format!("refs/tags/{tag}"))
+		.output()
+		.is_ok_and(|output| output.status.success());
+	exists.then_some(tag)
+}
+
+#[cfg(test)]
+#[path = "__tests__/analysis_enforcement_tests.rs"]
+mod tests;
@@ -295,9 +295,10 @@ async fn latest_release_tag_for_identity(
 }
 
 fn tag_prefix_for_identity(release_identity: &EffectiveReleaseIdentity) -> String {
+	let version_prefix = release_identity.version_scheme.tag_version_prefix();
 	match release_identity.version_format {
-		VersionFormat::Namespaced => format!("{}/v", release_identity.owner_id),
-		_ => "v".to_string(),
+		VersionFormat::Namespaced => format!("{}/{version_prefix}", release_identity.owner_id),
+		_ => version_prefix.to_string(),
 	}
 }
 
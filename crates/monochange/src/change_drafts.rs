@@ -0,0 +1,309 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_analysis::AnalysisConfig;
+use monochange_analysis::ChangeFrame;
+use monochange_analysis::PackageChangeAnalysis;
+use monochange_analysis::SemanticChange;
+use monochange_analysis::SemanticChangeCategory;
+use monochange_analysis::SemanticChangeKind;
+use monochange_config::build_changeset_load_context;
+use monochange_config::load_changeset_file_with_context;
+use monochange_config::load_workspace_configuration;
+use monochange_core::BumpSeverity;
+use monochange_core::DiscoveryReport;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::WorkspaceConfiguration;
+use serde::Serialize;
+
+use crate::PrereleaseRequest;
+use crate::PrereleaseSession;
+use crate::default_change_path;
+use crate::discover_configured_changeset_paths;
+use crate::discover_workspace;
+use crate::load_commit_changesets;
+use crate::load_prerelease_state;
+use crate::render_changeset_markdown;
+use crate::root_relative;
+use crate::workspace_ops::change_type_default_bump;
+
+/// One `.changeset/*.md` file drafted from semantic analysis.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChangesetDraft {
+	pub package: String,
+	pub path: PathBuf,
+	pub bump: BumpSeverity,
+	#[serde(rename = "type")]
+	pub change_type: Option<String>,
+	pub summary: String,
+}
+
+/// The drafts written for one change frame and the affected packages that
+/// pending changesets already cover.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChangesetDraftReport {
+	pub frame: String,
+	pub drafts: Vec<ChangesetDraft>,
+	pub covered: Vec<String>,
+}
+
+impl ChangesetDraftReport {
+	pub(crate) fn render_text(&self) -> String {
+		let mut lines = Vec::new();
+		if self.drafts.is_empty() {
+			lines.push(format!("no draft change files needed for {}", self.frame));
+		} else {
+			lines.push(format!(
+				"wrote {} draft change file(s) from semantic analysis of {}:",
+				self.drafts.len(),
+				self.frame
+			));
+			for draft in &self.drafts {
+				let change_type = draft
+					.change_type
+					.as_deref()
+					.map(|change_type| format!(", {change_type}"))
+					.unwrap_or_default();
+				lines.push(format!(
+					"- {} ({}: {}{change_type})",
+					draft.path.display(),
+					draft.package,
+					draft.bump
+				));
+			}
+		}
+		if !self.covered.is_empty() {
+			lines.push(format!(
+				"already covered by pending changesets: {}",
+				self.covered.join(", ")
+			));
+		}
+		lines.join("\n")
+	}
+}
+
+/// Detect the change frame for the current git state.
+pub(crate) fn detect_change_frame(root: &Path) -> MonochangeResult<ChangeFrame> {
+	ChangeFrame::detect(root).map_err(|error| {
+		MonochangeError::Config(format!("failed to detect change frame: {error}"))
+	})
+}
+
+/// Write a draft changeset for every package that semantic analysis of
+/// `frame` reports as changed and that no pending changeset covers yet.
+///
+/// Drafts request the strongest bump implied by the package's semantic
+/// changes and list the added, removed, and changed symbols so a human only
+/// has to edit the prose.
+pub(crate) fn draft_changesets_from_analysis(
+	root: &Path,
+	frame: &ChangeFrame,
+) -> MonochangeResult<ChangesetDraftReport> {
+	let configuration = load_workspace_configuration(root)?;
+	let discovery = discover_workspace(root)?;
+	let analysis = monochange_analysis::analyze_changes(root, frame, &AnalysisConfig::default())?;
+	let covered_ids = covered_package_ids(root, &configuration, &discovery)?;
+
+	let mut report = ChangesetDraftReport {
+		frame: frame.to_string(),
+		drafts: Vec::new(),
+		covered: Vec::new(),
+	};
+	for package_analysis in analysis.package_analyses.values() {
+		if covered_ids.contains(package_analysis.package_record_id.as_str()) {
+			report.covered.push(package_analysis.package_id.clone());
+			continue;
+		}
+		let draft = build_changeset_draft(&configuration, package_analysis);
+		let package_refs = [package_analysis.package_id.clone()];
+		let content = render_changeset_markdown(
+			&configuration,
+			&package_refs,
+			draft.bump,
+			None,
+			&draft.summary,
+			draft.change_type.as_deref(),
+			&[],
+			draft.details.as_deref(),
+		)?;
+		let path = default_change_path(root, &package_refs);
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent).map_err(|error| {
+				MonochangeError::Io(format!("failed to create {}: {error}", parent.display()))
+			})?;
+		}
+		fs::write(&path, content).map_err(|error| {
+			MonochangeError::Io(format!("failed to write {}: {error}", path.display()))
+		})?;
+		report.drafts.push(ChangesetDraft {
+			package: package_analysis.package_id.clone(),
+			path: root_relative(root, &path),
+			bump: draft.bump,
+			change_type: draft.change_type,
+			summary: draft.summary,
+		});
+	}
+	Ok(report)
+}
+
+/// Package record ids that pending changesets already release.
+///
+/// Version group members release together, so a changeset for any member
+/// covers the whole group.
+fn covered_package_ids(
+	root: &Path,
+	configuration: &WorkspaceConfiguration,
+	discovery: &DiscoveryReport,
+) -> MonochangeResult<BTreeSet<String>> {
+	let context = build_changeset_load_context(configuration, &discovery.packages);
+	let mut changesets = discover_configured_changeset_paths(root, configuration, true)?
+		.iter()
+		.map(|path| load_changeset_file_with_context(path, &context))
+		.collect::<MonochangeResult<Vec<_>>>()?;
+	let prerelease_session = PrereleaseSession::resolve(
+		configuration,
+		load_prerelease_state(root)?,
+		&PrereleaseRequest::default(),
+	)?;
+	let commit_changesets = load_commit_changesets(
+		root,
+		configuration,
+		&context,
+		&changesets,
+		&prerelease_session,
+		&discovery.packages,
+	)?;
+	changesets.extend(commit_changesets);
+	let signaled = changesets
+		.into_iter()
+		.flat_map(|changeset| changeset.signals)
+		.map(|signal| signal.package_id)
+		.collect::<BTreeSet<_>>();
+	let covered_groups = discovery
+		.packages
+		.iter()
+		.filter(|package| signaled.contains(&package.id))
+		.filter_map(|package| package.version_group_id.as_deref())
+		.collect::<BTreeSet<_>>();
+	Ok(discovery
+		.packages
+		.iter()
+		.filter(|package| {
+			signaled.contains(&package.id)
+				|| package
+					.version_group_id
+					.as_deref()
+					.is_some_and(|group_id| covered_groups.contains(group_id))
+		})
+		.map(|package| package.id.clone())
+		.collect())
+}
+
+/// The release intent drafted for one package.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct DraftContent {
+	pub bump: BumpSeverity,
+	pub change_type: Option<String>,
+	pub summary: String,
+	pub details: Option<String>,
+}
+
+/// Draft the bump, type, summary, and symbol list for one package.
+///
+/// The strongest semantic change decides the bump, the type, and the
+/// summary. Packages whose changes imply no release still get a patch draft
+/// because their files changed.
+pub(crate) fn build_changeset_draft(
+	configuration: &WorkspaceConfiguration,
+	package_analysis: &PackageChangeAnalysis,
+) -> DraftContent {
+	let strongest = strongest_change(&package_analysis.semantic_changes);
+	let bump = strongest
+		.map(SemanticChange::implied_bump)
+		.filter(|bump| bump.is_release())
+		.unwrap_or(BumpSeverity::Patch);
+	let change_type = strongest
+		.and_then(|change| draft_change_type(configuration, &package_analysis.package_id, change));
+	let summary = strongest.map_or_else(
+		|| format!("update {}", package_analysis.package_id),
+		|change| change.summary.clone(),
+	);
+	DraftContent {
+		bump,
+		change_type,
+		summary,
+		details: render_symbol_details(&package_analysis.semantic_changes),
+	}
+}
+
+/// The first change implying the strongest bump.
+fn strongest_change(changes: &[SemanticChange]) -> Option<&SemanticChange> {
+	let strongest = changes.iter().map(SemanticChange::implied_bump).max()?;
+	changes
+		.iter()
+		.find(|change| change.implied_bump() == strongest)
+}
+
+/// Map the category of `change` onto a configured changelog type.
+///
+/// Returns `None` when the type is not configured or `package_id` excludes
+/// it, leaving the draft with a plain bump.
+fn draft_change_type(
+	configuration: &WorkspaceConfiguration,
+	package_id: &str,
+	change: &SemanticChange,
+) -> Option<String> {
+	let change_type = match change.category {
+		SemanticChangeCategory::PublicApi | SemanticChangeCategory::Export
+			if change.implied_bump() == BumpSeverity::Major =>
+		{
+			"breaking"
+		}
+		SemanticChangeCategory::PublicApi | SemanticChangeCategory::Export => "feat",
+		SemanticChangeCategory::Dependency => "change",
+		SemanticChangeCategory::Metadata => "docs",
+		_ => return None,
+	};
+	change_type_default_bump(configuration, package_id, change_type)?;
+	let excluded = configuration
+		.package_by_id(package_id)
+		.is_some_and(|package| {
+			package
+				.excluded_changelog_types
+				.iter()
+				.any(|excluded| excluded == change_type)
+		});
+	(!excluded).then(|| change_type.to_string())
+}
+
+/// List the added, removed, and changed symbols as markdown sections.
+pub(crate) fn render_symbol_details(changes: &[SemanticChange]) -> Option<String> {
+	let sections = [
+		("Added", SemanticChangeKind::Added),
+		("Removed", SemanticChangeKind::Removed),
+		("Changed", SemanticChangeKind::Modified),
+	]
+	.into_iter()
+	.filter_map(|(heading, kind)| {
+		let symbols = changes
+			.iter()
+			.filter(|change| change.kind == kind)
+			.map(|change| format!("- {} `{}`", change.item_kind, change.item_path))
+			.collect::<BTreeSet<_>>();
+		(!symbols.is_empty()).then(|| {
+			let symbols = symbols.into_iter().collect::<Vec<_>>();
+			format!("{heading}:\n\n{}", symbols.join("\n"))
+		})
+	})
+	.collect::<Vec<_>>();
+	(!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+#[cfg(test)]
+#[path = "__tests__/change_drafts_tests.rs"]
+mod tests;
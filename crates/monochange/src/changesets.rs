@@ -1,7 +1,15 @@
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::fmt::Write as _;
 
+use monochange_config::commits::load_commit_trailer_changesets;
+use monochange_config::commits::load_conventional_commit_changesets;
+use monochange_config::commits::ReleaseRangeCommit;
+use monochange_config::commits::load_release_range_commits;
+use monochange_core::ChangeSource;
+
 use super::*;
+use crate::analysis_enforcement::collect_analysis_evidence;
 use crate::changeset_policy::configuration_package_records;
 
 pub(crate) async fn diagnose_changesets(
@@ -9,16 +17,20 @@ pub(crate) async fn diagnose_changesets(
 	requested: &[String],
 ) -> MonochangeResult<ChangesetDiagnosticsReport> {
 	let configuration = load_workspace_configuration(root)?;
+	let reads_commits = reads_commit_sources(&configuration);
+	let (requested_commits, requested_files) = requested
+		.iter()
+		.partition::<Vec<_>, _>(|path| reads_commits && requested_commit_sha(path).is_some());
 
 	let changeset_paths = if requested.is_empty() {
-		discover_changeset_paths(root, false)?
+		discover_configured_changeset_paths(root, &configuration, false)?
 			.into_iter()
 			.map(|path| root.join(path))
 			.collect::<Vec<_>>()
 	} else {
 		let mut resolved = Vec::new();
 
-		for path in requested {
+		for path in requested_files {
 			resolved.push(resolve_changeset_path(root, path)?);
 		}
 
@@ -27,7 +39,32 @@ pub(crate) async fn diagnose_changesets(
 		resolved
 	};
 
-	let loaded_changesets = load_diagnostic_changesets(root, &configuration, &changeset_paths)?;
+	let mut loaded_changesets =
+		load_diagnostic_changesets(root, &configuration, &changeset_paths)?;
+	if reads_commits && (requested.is_empty() || !requested_commits.is_empty()) {
+		let packages = configuration_package_records(&configuration);
+		let context = monochange_config::build_changeset_load_context(&configuration, &packages);
+		let prerelease_session = PrereleaseSession::resolve(
+			&configuration,
+			load_prerelease_state(root)?,
+			&PrereleaseRequest::default(),
+		)?;
+		let commit_changesets = load_commit_changesets(
+			root,
+			&configuration,
+			&context,
+			&loaded_changesets,
+			&prerelease_session,
+			&packages,
+		)?;
+		loaded_changesets.extend(select_requested_commit_changesets(
+			commit_changesets,
+			&requested_commits,
+		)?);
+		if requested.is_empty() && loaded_changesets.is_empty() {
+			return Err(no_pending_changes_error(&configuration));
+		}
+	}
 
 	let mut changesets = build_prepared_changesets(root, loaded_changesets);
 
@@ -48,6 +85,53 @@ pub(crate) async fn diagnose_changesets(
 	})
 }
 
+/// The commit sha prefix of a `commit:<sha>` changeset request.
+fn requested_commit_sha(requested: &str) -> Option<&str> {
+	requested
+		.strip_prefix("commit:")
+		.map(str::trim)
+		.filter(|sha| !sha.is_empty())
+}
+
+/// Keep every commit-derived changeset when no commit was requested, and
+/// otherwise only the ones whose commit matches a `commit:<sha>` request.
+fn select_requested_commit_changesets(
+	commit_changesets: Vec<monochange_config::LoadedChangesetFile>,
+	requested_commits: &[&String],
+) -> MonochangeResult<Vec<monochange_config::LoadedChangesetFile>> {
+	if requested_commits.is_empty() {
+		return Ok(commit_changesets);
+	}
+	let commit_sha = |changeset: &monochange_config::LoadedChangesetFile| {
+		changeset
+			.source_revision
+			.as_ref()
+			.and_then(|revision| revision.commit.as_ref())
+			.map(|commit| commit.sha.clone())
+			.unwrap_or_default()
+	};
+	for requested in requested_commits {
+		let sha = requested_commit_sha(requested).unwrap_or_default();
+		if !commit_changesets
+			.iter()
+			.any(|changeset| commit_sha(changeset).starts_with(sha))
+		{
+			return Err(MonochangeError::Config(format!(
+				"requested changeset `{requested}` does not exist"
+			)));
+		}
+	}
+	Ok(commit_changesets
+		.into_iter()
+		.filter(|changeset| {
+			let changeset_sha = commit_sha(changeset);
+			requested_commits.iter().any(|requested| {
+				changeset_sha.starts_with(requested_commit_sha(requested).unwrap_or_default())
+			})
+		})
+		.collect())
+}
+
 fn load_diagnostic_changesets(
 	root: &Path,
 	configuration: &monochange_core::WorkspaceConfiguration,
@@ -326,24 +410,172 @@ pub(crate) fn discover_changeset_paths(
 	Ok(changeset_paths)
 }
 
+/// Discover `.changeset/*.md` files when `[changesets].sources` reads from
+/// files.
+///
+/// When commits are also a change source an empty `.changeset/` directory is
+/// not an error on its own; callers decide once commit changes are loaded.
+#[must_use = "the discovery result must be checked"]
+pub(crate) fn discover_configured_changeset_paths(
+	root: &Path,
+	configuration: &monochange_core::WorkspaceConfiguration,
+	allow_empty: bool,
+) -> MonochangeResult<Vec<PathBuf>> {
+	if !configuration.changesets.reads_from(ChangeSource::Files) {
+		return Ok(Vec::new());
+	}
+	discover_changeset_paths(root, allow_empty || reads_commit_sources(configuration))
+}
+
+/// Return `true` when `[changesets].sources` reads release intent from commits.
+pub(crate) fn reads_commit_sources(
+	configuration: &monochange_core::WorkspaceConfiguration,
+) -> bool {
+	configuration
+		.changesets
+		.reads_from(ChangeSource::ConventionalCommits)
+		|| configuration
+			.changesets
+			.reads_from(ChangeSource::CommitTrailers)
+}
+
+/// Load the changes derived from commits since the last release record when
+/// `[changesets].sources` includes `conventional_commits` or `commit_trailers`.
+///
+/// A `Changeset:` trailer is explicit release intent, so a commit that carries
+/// one is not also read as a conventional commit. Targets already declared by
+/// `file_changesets` are skipped. While prerelease mode is active the range
+/// starts at the last stable release, and commits that earlier prereleases
+/// shipped only count for owners that leave prerelease mode in this run.
+#[must_use = "the changeset result must be checked"]
+pub(crate) fn load_commit_changesets(
+	root: &Path,
+	configuration: &monochange_core::WorkspaceConfiguration,
+	context: &monochange_config::ChangesetLoadContext<'_>,
+	file_changesets: &[monochange_config::LoadedChangesetFile],
+	prerelease_session: &PrereleaseSession,
+	packages: &[PackageRecord],
+) -> MonochangeResult<Vec<monochange_config::LoadedChangesetFile>> {
+	if !reads_commit_sources(configuration) {
+		return Ok(Vec::new());
+	}
+	let (prereleased, pending) = load_release_range_commits(root, prerelease_session.was_active())?
+		.into_iter()
+		.partition::<Vec<_>, _>(|commit| commit.prereleased);
+	let mut changesets =
+		changesets_from_commits(&pending, configuration, context, file_changesets)?;
+	changesets.extend(prerelease_session.retain_prereleased_commit_changesets(
+		configuration,
+		packages,
+		changesets_from_commits(&prereleased, configuration, context, file_changesets)?,
+	));
+	Ok(changesets)
+}
+
+fn changesets_from_commits(
+	commits: &[ReleaseRangeCommit],
+	configuration: &monochange_core::WorkspaceConfiguration,
+	context: &monochange_config::ChangesetLoadContext<'_>,
+	file_changesets: &[monochange_config::LoadedChangesetFile],
+) -> MonochangeResult<Vec<monochange_config::LoadedChangesetFile>> {
+	let reads_trailers = configuration
+		.changesets
+		.reads_from(ChangeSource::CommitTrailers);
+	let mut changesets = Vec::new();
+	if reads_trailers {
+		changesets.extend(load_commit_trailer_changesets(
+			commits,
+			file_changesets,
+			context,
+		)?);
+	}
+	if configuration
+		.changesets
+		.reads_from(ChangeSource::ConventionalCommits)
+	{
+		let commits = commits
+			.iter()
+			.filter(|commit| !(reads_trailers && commit.has_changeset_trailers()))
+			.cloned()
+			.collect::<Vec<_>>();
+		changesets.extend(load_conventional_commit_changesets(
+			&commits,
+			file_changesets,
+			context,
+		)?);
+	}
+	Ok(changesets)
+}
+
+/// The error reported when none of the configured change sources has anything
+/// to release.
+pub(crate) fn no_pending_changes_error(
+	configuration: &monochange_core::WorkspaceConfiguration,
+) -> MonochangeError {
+	let sources = configuration
+		.changesets
+		.sources
+		.iter()
+		.map(|source| {
+			match source {
+				ChangeSource::Files => format!("markdown changesets under {CHANGESET_DIR}"),
+				ChangeSource::ConventionalCommits => {
+					"conventional commits since the last release".to_string()
+				}
+				ChangeSource::CommitTrailers => {
+					"`Changeset` commit trailers since the last release".to_string()
+				}
+				_ => source.to_string(),
+			}
+		})
+		.collect::<Vec<_>>();
+	MonochangeError::Config(format!("no {} found", sources.join(" or ")))
+}
+
 pub(crate) fn build_prepared_changesets(
 	root: &Path,
 	loaded_changesets: Vec<monochange_config::LoadedChangesetFile>,
 ) -> Vec<PreparedChangeset> {
 	let relative_paths = loaded_changesets
 		.iter()
-		.map(|changeset| root_relative(root, &changeset.path))
+		.map(|changeset| {
+			if changeset.source_revision.is_some() {
+				changeset.path.clone()
+			} else {
+				root_relative(root, &changeset.path)
+			}
+		})
+		.collect::<Vec<_>>();
+	let file_paths = loaded_changesets
+		.iter()
+		.zip(&relative_paths)
+		.filter(|(changeset, _)| changeset.source_revision.is_none())
+		.map(|(_, path)| path.clone())
 		.collect::<Vec<_>>();
 
 	// Batch-load all changeset git context in a single pass instead of
 	// spawning two git-log subprocesses per changeset (which was O(2N)
 	// subprocess spawns and dominated release planning time).
-	let mut git_contexts = batch_load_changeset_contexts(root, &relative_paths).into_iter();
+	let mut git_contexts = batch_load_changeset_contexts(root, &file_paths).into_iter();
 
 	loaded_changesets
 		.into_iter()
 		.zip(relative_paths)
 		.map(|(changeset, relative_path)| {
+			// Commit-derived changes already know the commit that introduced them.
+			let context = match changeset.source_revision {
+				Some(revision) => {
+					Some(ChangesetContext {
+						provider: HostingProviderKind::GenericGit,
+						host: None,
+						capabilities: HostingCapabilities::default(),
+						introduced: Some(revision.clone()),
+						last_updated: Some(revision),
+						related_issues: Vec::new(),
+					})
+				}
+				None => git_contexts.next(),
+			};
 			PreparedChangeset {
 				path: relative_path,
 				summary: changeset.summary,
@@ -374,7 +606,7 @@ pub(crate) fn build_prepared_changesets(
 						}
 					})
 					.collect(),
-				context: git_contexts.next(),
+				context,
 			}
 		})
 		.collect()
@@ -573,31 +805,166 @@ pub(crate) fn short_commit_sha(sha: &str) -> String {
 	sha.chars().take(7).collect()
 }
 
+/// Planner options derived from the workspace `[defaults]` and the version
+/// scheme of every configured package and group.
+pub(crate) fn release_plan_options(
+	configuration: &monochange_core::WorkspaceConfiguration,
+	packages: &[PackageRecord],
+) -> ReleasePlanOptions {
+	ReleasePlanOptions {
+		default_parent_bump: configuration.defaults.parent_bump,
+		propagation: configuration.defaults.propagation,
+		propagation_by_kind: planner_propagation_by_kind(configuration, packages),
+		strict_version_conflicts: configuration.defaults.strict_version_conflicts,
+		version_schemes: planner_version_schemes(configuration, packages),
+		release_date: Some(resolve_release_datetime().date()),
+		pre_stable_policies: planner_pre_stable_policies(configuration, packages),
+		linked_groups: planner_linked_groups(configuration, packages),
+		..ReleasePlanOptions::default()
+	}
+}
+
+/// Map the members of every linked group onto the package record ids used
+/// by the release planner.
+fn planner_linked_groups(
+	configuration: &monochange_core::WorkspaceConfiguration,
+	packages: &[PackageRecord],
+) -> BTreeMap<String, BTreeSet<String>> {
+	let mut linked_groups = BTreeMap::<String, BTreeSet<String>>::new();
+	for package in packages {
+		let config_id = package
+			.metadata
+			.get("config_id")
+			.map_or(package.name.as_str(), String::as_str);
+		if let Some(group) = configuration.linked_group_for_package(config_id) {
+			linked_groups
+				.entry(group.id.clone())
+				.or_default()
+				.insert(package.id.clone());
+		}
+	}
+	linked_groups
+}
+
+/// Map non-default per-kind propagation rules onto the package record ids
+/// used by the release planner. Packages missing from the configuration use
+/// the `[defaults]` rules.
+fn planner_propagation_by_kind(
+	configuration: &monochange_core::WorkspaceConfiguration,
+	packages: &[PackageRecord],
+) -> BTreeMap<String, PropagationByKind> {
+	packages
+		.iter()
+		.filter_map(|package| {
+			let config_id = package
+				.metadata
+				.get("config_id")
+				.map_or(package.name.as_str(), String::as_str);
+			let rules = configuration
+				.package_by_id(config_id)
+				.map_or(configuration.defaults.propagation_by_kind, |definition| {
+					definition.propagation_by_kind
+				});
+			(rules != PropagationByKind::default()).then(|| (package.id.clone(), rules))
+		})
+		.collect()
+}
+
+/// Map non-default pre-1.0 bump policies onto the package record ids and
+/// version group ids used by the release planner.
+fn planner_pre_stable_policies(
+	configuration: &monochange_core::WorkspaceConfiguration,
+	packages: &[PackageRecord],
+) -> BTreeMap<String, PreStablePolicy> {
+	let mut pre_stable_policies = BTreeMap::new();
+	for package in packages {
+		let config_id = package
+			.metadata
+			.get("config_id")
+			.map_or(package.name.as_str(), String::as_str);
+		let Some(policy) = configuration.pre_stable_policy_for_package(config_id) else {
+			continue;
+		};
+		if policy == PreStablePolicy::Shift {
+			continue;
+		}
+		let key = package
+			.version_group_id
+			.clone()
+			.unwrap_or_else(|| package.id.clone());
+		pre_stable_policies.insert(key, policy);
+	}
+	pre_stable_policies
+}
+
+/// Map non-default version schemes onto the package record ids and version
+/// group ids used by the release planner.
+fn planner_version_schemes(
+	configuration: &monochange_core::WorkspaceConfiguration,
+	packages: &[PackageRecord],
+) -> BTreeMap<String, VersionScheme> {
+	let mut version_schemes = BTreeMap::new();
+	for package in packages {
+		let config_id = package
+			.metadata
+			.get("config_id")
+			.map_or(package.name.as_str(), String::as_str);
+		let version_scheme = match configuration.group_for_package(config_id) {
+			Some(group) => &group.version_scheme,
+			None => {
+				match configuration.package_by_id(config_id) {
+					Some(definition) => &definition.version_scheme,
+					None => continue,
+				}
+			}
+		};
+		if *version_scheme == VersionScheme::Semver {
+			continue;
+		}
+		let key = package
+			.version_group_id
+			.clone()
+			.unwrap_or_else(|| package.id.clone());
+		version_schemes.insert(key, version_scheme.clone());
+	}
+	version_schemes
+}
+
+/// Build the release plan for `change_signals`, enforcing the workspace's
+/// `analysis_enforcement` policy along the way.
 #[tracing::instrument(skip_all)]
 pub(crate) fn build_release_plan_from_signals(
 	configuration: &monochange_core::WorkspaceConfiguration,
 	discovery: &DiscoveryReport,
 	change_signals: &[ChangeSignal],
+	options: &ReleasePlanOptions,
 ) -> MonochangeResult<ReleasePlan> {
 	#[cfg(feature = "cargo")]
 	let rust_provider = RustSemverProvider;
 	#[cfg(feature = "cargo")]
 	let providers: [&dyn CompatibilityProvider; 1] = [&rust_provider];
 	#[cfg(feature = "cargo")]
-	let compatibility_evidence = collect_assessments(&providers, &discovery.packages, change_signals);
+	let mut compatibility_evidence =
+		collect_assessments(&providers, &discovery.packages, change_signals);
 	#[cfg(not(feature = "cargo"))]
-	let compatibility_evidence = Vec::new();
+	let mut compatibility_evidence = Vec::new();
+
+	let (analysis_floor, analysis_warnings) =
+		collect_analysis_evidence(configuration, discovery, change_signals)?
+			.enforce(configuration.defaults.analysis_enforcement)?;
+	compatibility_evidence.extend(analysis_floor);
 
-	build_release_plan(
+	let mut plan = build_release_plan_with_options(
 		&discovery.workspace_root,
 		&discovery.packages,
 		&discovery.dependencies,
 		&discovery.version_groups,
 		change_signals,
 		&compatibility_evidence,
-		configuration.defaults.parent_bump,
-		configuration.defaults.strict_version_conflicts,
-	)
+		options,
+	)?;
+	plan.warnings.extend(analysis_warnings);
+	Ok(plan)
 }
 
 pub(crate) fn canonical_change_packages(
@@ -229,6 +229,7 @@ When provided, the generated config includes:\n\
 				"Start the monochange MCP (Model Context Protocol) server over stdin/stdout",
 			))
 			.subcommand(build_check_subcommand())
+			.subcommand(build_graph_subcommand())
 			.subcommand(build_help_subcommand());
 
 	command = command.next_help_heading("Step Commands");
@@ -532,6 +533,58 @@ pub(crate) fn build_check_subcommand() -> Command {
 		)
 }
 
+pub(crate) fn build_graph_subcommand() -> Command {
+	Command::new("graph")
+		.about("Export the workspace dependency graph as DOT, Mermaid, or JSON")
+		.after_help(
+			"Examples:\n  mc graph | dot -Tsvg > graph.svg\n  mc graph --format mermaid --plan\n  mc graph --ecosystem npm --publish-levels\n  mc graph --package core --format json",
+		)
+		.arg(
+			Arg::new("format")
+				.long("format")
+				.help("Output format")
+				.default_value("dot")
+				.value_parser(["dot", "mermaid", "json"]),
+		)
+		.arg(
+			Arg::new("ecosystem")
+				.long("ecosystem")
+				.short('e')
+				.help("Only include packages from these ecosystems")
+				.value_name("ECOSYSTEMS")
+				.value_delimiter(',')
+				.action(ArgAction::Append),
+		)
+		.arg(
+			Arg::new("group")
+				.long("group")
+				.help("Only include members of these groups")
+				.value_name("GROUPS")
+				.value_delimiter(',')
+				.action(ArgAction::Append),
+		)
+		.arg(
+			Arg::new("package")
+				.long("package")
+				.help("Only include these packages and their dependencies and dependents")
+				.value_name("PACKAGES")
+				.value_delimiter(',')
+				.action(ArgAction::Append),
+		)
+		.arg(
+			Arg::new("plan")
+				.long("plan")
+				.help("Color packages by the bump planned from pending changesets")
+				.action(ArgAction::SetTrue),
+		)
+		.arg(
+			Arg::new("publish-levels")
+				.long("publish-levels")
+				.help("Group packages by the level at which they can be published")
+				.action(ArgAction::SetTrue),
+		)
+}
+
 pub(crate) fn build_lint_subcommand() -> Command {
 	Command::new("lint")
 		.about("Inspect and scaffold manifest lint rules")
@@ -665,7 +718,9 @@ pub(crate) fn build_cli_command_subcommand(cli_command: &CliCommandDefinition) -
 					.help_heading(RELEASE_OPTIONS_HELP_HEADING)
 					.help("Read or write the prepared release artifact at a specific path")
 					.value_name("PATH"),
-			);
+			)
+			.args_conflicts_with_subcommands(true)
+			.subcommand(build_release_explain_subcommand());
 	}
 
 	if let Some(after_help) = cli_command_after_help(cli_command) {
@@ -680,6 +735,24 @@ pub(crate) fn build_cli_command_subcommand(cli_command: &CliCommandDefinition) -
 	command
 }
 
+fn build_release_explain_subcommand() -> Command {
+	Command::new("explain")
+		.about("Explain why a package receives its planned release")
+		.after_help("Examples:\n  mc release explain core\n  mc release explain core --format json")
+		.arg(
+			Arg::new("package")
+				.required(true)
+				.help("Configured package id or package name to explain"),
+		)
+		.arg(
+			Arg::new("format")
+				.long("format")
+				.help("Output format")
+				.default_value("markdown")
+				.value_parser(["text", "json", "markdown", "md"]),
+		)
+}
+
 pub(crate) fn cli_command_after_help(cli_command: &CliCommandDefinition) -> Option<&'static str> {
 	match cli_command.name.as_str() {
 		"step:publish-release" => {
@@ -170,6 +170,7 @@ const BUILTIN_COMMAND_NAMES: &[&str] = &[
 	"lint",
 	"mcp",
 	"check",
+	"graph",
 	"step:validate",
 	"help",
 ];
@@ -430,6 +431,11 @@ fn builtin_command_helps() -> Vec<CommandHelp> {
 					"",
 					"Select packages, bumps, and options interactively",
 				),
+				(
+					"--from-analysis",
+					"",
+					"Draft change files from semantic analysis of the diff",
+				),
 				(
 					"--package",
 					"<PACKAGE>",
@@ -481,6 +487,10 @@ fn builtin_command_helps() -> Vec<CommandHelp> {
 					r#"mc change --package utils --bump patch --caused-by core --reason "bump for core compat""#,
 				),
 				("Interactive mode:", "mc change --interactive"),
+				(
+					"Draft change files for uncovered packages:",
+					"mc change --from-analysis",
+				),
 				(
 					"Explicit version pin:",
 					r#"mc change --package core --bump major --version 2.0.0 --reason "promote to stable""#,
@@ -491,6 +501,7 @@ fn builtin_command_helps() -> Vec<CommandHelp> {
 				"Use a group id only when the change is intentionally owned by the whole group.",
 				"Dependents and grouped members propagate automatically during planning.",
 				"--caused-by marks a package as only changing because another moved first.",
+				"--from-analysis skips packages that pending changesets already cover.",
 				"Legacy manifest paths resolve during migration, but declared ids are the stable interface.",
 			],
 			see_also: &["release", "versions", "affected"],
@@ -518,6 +529,11 @@ fn builtin_command_helps() -> Vec<CommandHelp> {
 					"<PATH>",
 					"Read/write prepared release artifact path",
 				),
+				(
+					"explain <PACKAGE>",
+					"",
+					"Explain why a package receives its planned bump",
+				),
 			],
 			examples: &[
 				(
@@ -529,6 +545,7 @@ fn builtin_command_helps() -> Vec<CommandHelp> {
 					"mc release --dry-run --format json",
 				),
 				("Preview with file diffs:", "mc release --dry-run --diff"),
+				("Trace a package's bump:", "mc release explain core"),
 				("Execute the release:", "mc release"),
 			],
 			tips: &[
@@ -847,6 +864,56 @@ fn builtin_command_helps() -> Vec<CommandHelp> {
 			],
 			see_also: &["lint", "step:validate", "affected"],
 		},
+		CommandHelp {
+			name: "graph",
+			summary: "Export the workspace dependency graph as DOT, Mermaid, or JSON",
+			description: "Renders discovered packages, their dependency edges, and version groups \
+				as a Graphviz DOT graph, a Mermaid flowchart, or JSON. Filters narrow the graph \
+				to ecosystems, groups, or the neighbourhood of specific packages, and overlays \
+				can color packages by their planned bump or arrange them by publish order.",
+			usage: "mc graph [OPTIONS]",
+			options: &[
+				("--format", "<FORMAT>", "dot, mermaid, json (default: dot)"),
+				(
+					"-e, --ecosystem",
+					"<ECOSYSTEMS>",
+					"Only include these ecosystems (comma-sep)",
+				),
+				(
+					"--group",
+					"<GROUPS>",
+					"Only include members of these groups",
+				),
+				(
+					"--package",
+					"<PACKAGES>",
+					"Only include these packages, their dependencies, and dependents",
+				),
+				("--plan", "", "Color packages by their planned bump"),
+				(
+					"--publish-levels",
+					"",
+					"Group packages by publish order level",
+				),
+			],
+			examples: &[
+				("Render an SVG:", "mc graph | dot -Tsvg > graph.svg"),
+				(
+					"Mermaid with the release plan:",
+					"mc graph --format mermaid --plan",
+				),
+				(
+					"Publish order for npm packages:",
+					"mc graph --ecosystem npm --publish-levels",
+				),
+				("One package's neighbourhood:", "mc graph --package core"),
+			],
+			tips: &[
+				"Development dependencies are drawn dashed and do not affect publish levels.",
+				"Packages inside a dependency cycle have no publish level.",
+			],
+			see_also: &["release", "check"],
+		},
 		CommandHelp {
 			name: "lint",
 			summary: "Inspect and scaffold manifest lint rules",
@@ -1444,6 +1511,7 @@ fn input_description(input: &CliInputDefinition) -> String {
 		"mode" => "Rate-limit planning mode".to_string(),
 		"ci" => "CI provider context used for trust metadata".to_string(),
 		"interactive" => "Prompt interactively when supported".to_string(),
+		"from_analysis" => "Draft change files from semantic analysis of the diff".to_string(),
 		"bump" => "Requested semver bump".to_string(),
 		"version" => "Explicit version to request".to_string(),
 		"reason" => "Human-readable reason for the change".to_string(),
@@ -1458,6 +1526,14 @@ fn input_description(input: &CliInputDefinition) -> String {
 		}
 		// patch-coverage:ignore-end
 		"auto-close-issues" => "Close linked issues after commenting when supported".to_string(),
+		"pre" => "Enter or continue a prerelease channel such as alpha, beta, or rc".to_string(),
+		"pre_package" => "Limit prerelease mode changes to package or group ids".to_string(),
+		"exit_pre" => "Leave prerelease mode and release stable versions".to_string(),
+		"snapshot" => {
+			"Prepare a throwaway snapshot build published under this dist-tag".to_string()
+		}
+		"snapshot_template" => "Template for the snapshot version suffix".to_string(),
+		"graduate" => "Release 1.0.0 for these 0.x package or group ids".to_string(),
 		_ => format!("Value for `{}`", input.name.replace('_', "-")),
 	};
 	if !input.choices.is_empty() {
@@ -344,7 +344,7 @@ async fn ensure_prepared_release_for_consumer_step(
 	step_name: &str,
 ) -> MonochangeResult<()> {
 	if context.prepared_release.is_some() {
-		return Ok(());
+		return reject_snapshot_release(context, step_name);
 	}
 	let loaded = maybe_load_prepared_release_execution(
 		root,
@@ -365,6 +365,21 @@ async fn ensure_prepared_release_for_consumer_step(
 	Ok(())
 }
 
+/// Snapshot builds are published from a throwaway working tree and must never
+/// be committed, tagged, or announced.
+fn reject_snapshot_release(context: &CliContext, step_name: &str) -> MonochangeResult<()> {
+	if context
+		.prepared_release
+		.as_ref()
+		.is_some_and(|prepared_release| prepared_release.snapshot.is_some())
+	{
+		return Err(MonochangeError::Config(format!(
+			"`{step_name}` cannot run after a snapshot `PrepareRelease`"
+		)));
+	}
+	Ok(())
+}
+
 fn publish_release_source_configuration(
 	configured_source: Option<&SourceConfiguration>,
 	step_inputs: &BTreeMap<String, Vec<String>>,
@@ -810,7 +825,18 @@ pub(crate) async fn execute_cli_command_with_options(
 							.steps
 							.get(step_index + 1..)
 							.is_some_and(steps_reference_release_file_diffs);
-					let prepared_execution = if let Some(loaded) =
+					let prerelease_request = PrereleaseRequest::from_step_inputs(&step_inputs)?;
+					let snapshot_request = SnapshotRequest::from_step_inputs(&step_inputs)?;
+					let graduation_request = GraduationRequest::from_step_inputs(&step_inputs);
+					// Entering or leaving prerelease mode and graduating to `1.0.0`
+					// change the plan without touching tracked inputs, so a cached
+					// artifact cannot be trusted. Snapshots always render a fresh suffix.
+					let loaded = if prerelease_request.is_requested()
+						|| snapshot_request.is_some()
+						|| graduation_request.is_requested()
+					{
+						None
+					} else {
 						maybe_load_prepared_release_execution(
 							root,
 							configuration,
@@ -819,15 +845,19 @@ pub(crate) async fn execute_cli_command_with_options(
 							build_file_diffs,
 						)
 						.await?
-					{
+					};
+					let prepared_execution = if let Some(loaded) = loaded {
 						context.command_logs.push(loaded.message);
 						loaded.execution
 					} else {
-						prepare_release_execution_with_file_diffs(
+						prepare_release_execution_with_modes(
 							root,
 							dry_run,
 							build_file_diffs,
 							*allow_empty_changesets,
+							&prerelease_request,
+							snapshot_request.as_ref(),
+							&graduation_request,
 						)
 						.await?
 					};
@@ -843,8 +873,10 @@ pub(crate) async fn execute_cli_command_with_options(
 						prepared_release,
 						&context.command_logs,
 					);
-					let _record_path =
+					// Snapshot builds are throwaway and never leave a release record behind.
+					if prepared_release.snapshot.is_none() {
 						write_release_record_file(root, configuration.source.as_ref(), &manifest)?;
+					}
 					let updated_prepared_release = context.prepared_release.take().unwrap();
 					context.prepared_release = Some(updated_prepared_release);
 					context.release_manifest_path =
@@ -875,6 +907,7 @@ pub(crate) async fn execute_cli_command_with_options(
 					Ok(())
 				}
 				CliStepDefinition::PublishRelease { .. } => {
+					reject_snapshot_release(&context, "PublishRelease")?;
 					let verify_ref = step_inputs
 						.get("from-ref")
 						.and_then(|v| v.first().cloned())
@@ -953,6 +986,35 @@ pub(crate) async fn execute_cli_command_with_options(
 					let selected_ecosystems = selected_ecosystem_ids(&step_inputs)?;
 					let resume_path = optional_publish_resume_artifact_path(&step_inputs)?;
 					let output_path = optional_publish_output_artifact_path(&step_inputs)?;
+					if let Some((prepared_release, snapshot)) =
+						context.prepared_release.as_ref().and_then(|prepared_release| {
+							prepared_release
+								.snapshot
+								.as_ref()
+								.map(|snapshot| (prepared_release, snapshot))
+						}) {
+						let report = package_publish::run_snapshot_publish_packages(
+							root,
+							configuration,
+							prepared_release,
+							snapshot,
+							&selected_packages,
+							context.dry_run,
+						)
+						.await?;
+						// The snapshot working tree is only needed for publishing.
+						if let Some(snapshot) = context
+							.prepared_release
+							.as_mut()
+							.and_then(|prepared_release| prepared_release.snapshot.as_mut())
+						{
+							snapshot.working_tree_guard = None;
+						}
+						monochange_publish::ensure_publish_report_succeeded(&report)?;
+						context.package_publish_report = Some(report);
+						output = None;
+						return Ok(());
+					}
 					if !context.dry_run {
 						release_branch_policy::verify_release_ref_for_publish(
 							root,
@@ -1129,6 +1191,7 @@ pub(crate) async fn execute_cli_command_with_options(
 					Ok(())
 				}
 				CliStepDefinition::CommentReleasedIssues { .. } => {
+					reject_snapshot_release(&context, "CommentReleasedIssues")?;
 					let manifest = if let Some(prepared_release) = context.prepared_release.as_ref()
 					{
 						build_release_manifest(cli_command, prepared_release, &context.command_logs)
@@ -2357,6 +2420,19 @@ fn build_release_template_value(context: &CliContext) -> serde_json::Value {
 		})
 		.collect();
 	release_map.insert("targets".to_string(), serde_json::Value::Array(targets));
+	release_map.insert(
+		"snapshot".to_string(),
+		prepared
+			.snapshot
+			.as_ref()
+			.map_or(serde_json::Value::Null, |snapshot| {
+				serde_json::json!({
+					"tag": snapshot.tag,
+					"suffix": snapshot.suffix,
+					"working_tree": snapshot.working_tree,
+				})
+			}),
+	);
 
 	serde_json::Value::Object(release_map)
 }
@@ -2556,9 +2632,12 @@ fn parse_ecosystem_input(input: &str) -> MonochangeResult<Ecosystem> {
 		"flutter" => Ok(Ecosystem::Flutter),
 		"python" => Ok(Ecosystem::Python),
 		"go" => Ok(Ecosystem::Go),
+		"helm" => Ok(Ecosystem::Helm),
+		"ruby" => Ok(Ecosystem::Ruby),
+		"jvm" => Ok(Ecosystem::Jvm),
 		_ => {
 			Err(MonochangeError::Config(format!(
-				"unknown ecosystem `{input}`; expected one of: cargo, npm, deno, dart, flutter, python, go"
+				"unknown ecosystem `{input}`; expected one of: cargo, npm, deno, dart, flutter, python, go, helm, ruby, jvm"
 			)))
 		}
 	}
@@ -3568,6 +3647,15 @@ pub(crate) fn render_cli_command_markdown_result(
 			}
 			sections.push(render_markdown_section("Release targets", &lines, color));
 		}
+		if !prepared_release.plan.warnings.is_empty() {
+			let lines = prepared_release
+				.plan
+				.warnings
+				.iter()
+				.map(|warning| format!("- {warning}"))
+				.collect::<Vec<_>>();
+			sections.push(render_markdown_section("Warnings", &lines, color));
+		}
 		if let Some(path) = &context.release_manifest_path {
 			sections.push(render_markdown_section(
 				"Release manifest",
@@ -3826,8 +3914,16 @@ fn execute_create_change_file_step(
 		.get("interactive")
 		.and_then(|values| values.first())
 		.is_some_and(|value| value == "true");
+	let from_analysis = step_inputs
+		.get("from_analysis")
+		.and_then(|values| values.first())
+		.is_some_and(|value| value == "true");
 
-	if is_interactive {
+	if from_analysis {
+		let frame = crate::change_drafts::detect_change_frame(root)?;
+		let report = crate::change_drafts::draft_changesets_from_analysis(root, &frame)?;
+		Ok(report.render_text())
+	} else if is_interactive {
 		let options = interactive::InteractiveOptions {
 			caused_by: step_inputs.get("caused_by").cloned().unwrap_or_default(),
 			reason: step_inputs
@@ -3853,7 +3949,7 @@ fn execute_create_change_file_step(
 		let package_refs = step_inputs.get("package").cloned().unwrap_or_default();
 		if package_refs.is_empty() {
 			return Err(MonochangeError::Config(
-				"command `change` requires at least one `--package` value or `--interactive` mode"
+				"command `change` requires at least one `--package` value, `--interactive` mode, or `--from-analysis`"
 					.to_string(),
 			));
 		}
@@ -3988,6 +4084,9 @@ async fn save_prepared_release_artifact(
 	let Some(prepared_release) = &context.prepared_release else {
 		return Ok(());
 	};
+	if prepared_release.snapshot.is_some() {
+		return Ok(());
+	}
 
 	let save_result = save_prepared_release_execution(
 		root,
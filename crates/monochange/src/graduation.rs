@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use monochange_core::BumpSeverity;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageRecord;
+use monochange_core::ReleasePlan;
+use monochange_core::WorkspaceConfiguration;
+
+/// Owners that should leave `0.x` and release `1.0.0` in a single
+/// `PrepareRelease` run.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct GraduationRequest {
+	/// Configured package or group ids passed through the `graduate` input.
+	pub owners: Vec<String>,
+}
+
+impl GraduationRequest {
+	/// Read the `graduate` step input.
+	pub(crate) fn from_step_inputs(inputs: &BTreeMap<String, Vec<String>>) -> Self {
+		let owners = inputs
+			.get("graduate")
+			.into_iter()
+			.flatten()
+			.map(|value| value.trim().to_string())
+			.filter(|value| !value.is_empty())
+			.collect();
+		Self { owners }
+	}
+
+	/// Returns `true` when at least one owner asked to graduate.
+	pub(crate) fn is_requested(&self) -> bool {
+		!self.owners.is_empty()
+	}
+
+	/// Map the package record ids and version group ids used by the release
+	/// planner onto the requested owners.
+	///
+	/// Packages that belong to a group graduate the whole group, and a linked
+	/// group graduates each of its members. Owners whose current version is
+	/// already `1.0.0` or later are rejected.
+	pub(crate) fn planner_graduations(
+		&self,
+		configuration: &WorkspaceConfiguration,
+		packages: &[PackageRecord],
+	) -> MonochangeResult<BTreeMap<String, String>> {
+		let mut graduations = BTreeMap::new();
+		let owners = self
+			.owners
+			.iter()
+			.flat_map(|requested| {
+				configuration
+					.expand_linked_group(requested)
+					.into_iter()
+					.map(move |owner| (requested, owner))
+			})
+			.collect::<Vec<_>>();
+		for (requested, owner) in owners {
+			let group_id = if configuration.group_by_id(owner).is_some() {
+				Some(owner)
+			} else if configuration.package_by_id(owner).is_some() {
+				configuration
+					.group_for_package(owner)
+					.map(|group| group.id.as_str())
+			} else {
+				return Err(MonochangeError::Config(format!(
+					"graduation target `{owner}` does not match a configured package or group"
+				)));
+			};
+			let members = packages
+				.iter()
+				.filter(|package| {
+					match group_id {
+						Some(group_id) => package.version_group_id.as_deref() == Some(group_id),
+						None => {
+							package
+								.metadata
+								.get("config_id")
+								.map_or(package.name.as_str(), String::as_str)
+								== owner
+						}
+					}
+				})
+				.collect::<Vec<_>>();
+			if let Some(stable) = members.iter().find(|package| {
+				package
+					.current_version
+					.as_ref()
+					.is_some_and(|version| !BumpSeverity::is_pre_stable(version))
+			}) {
+				return Err(MonochangeError::Config(format!(
+					"cannot graduate `{requested}`: `{}` is already at {}",
+					stable.name,
+					stable
+						.current_version
+						.as_ref()
+						.map_or_else(String::new, ToString::to_string)
+				)));
+			}
+			match group_id {
+				Some(group_id) => {
+					graduations.insert(group_id.to_string(), requested.clone());
+				}
+				None => {
+					graduations.extend(
+						members
+							.iter()
+							.map(|package| (package.id.clone(), requested.clone())),
+					);
+				}
+			}
+		}
+		Ok(graduations)
+	}
+}
+
+/// Fail when a graduating owner has nothing to release, since `1.0.0` is
+/// only reachable through a release.
+pub(crate) fn ensure_graduations_released(
+	graduations: &BTreeMap<String, String>,
+	plan: &ReleasePlan,
+) -> MonochangeResult<()> {
+	for (planner_id, owner) in graduations {
+		let released = plan
+			.groups
+			.iter()
+			.filter(|group| group.group_id == *planner_id)
+			.map(|group| group.recommended_bump)
+			.chain(
+				plan.decisions
+					.iter()
+					.filter(|decision| decision.package_id == *planner_id)
+					.map(|decision| decision.recommended_bump),
+			)
+			.any(BumpSeverity::is_release);
+		if !released {
+			return Err(MonochangeError::Config(format!(
+				"cannot graduate `{owner}` because it has no pending release; add a changeset for it first"
+			)));
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+#[path = "__tests__/graduation_tests.rs"]
+mod tests;
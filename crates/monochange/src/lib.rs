@@ -113,7 +113,6 @@ pub(crate) use cli_runtime::inferred_retarget_source_configuration;
 #[cfg(test)]
 pub(crate) use cli_runtime::lookup_template_value;
 pub(crate) use cli_runtime::maybe_render_markdown_for_terminal;
-#[cfg(test)]
 pub(crate) use cli_runtime::parse_boolean_step_input;
 #[cfg(test)]
 pub(crate) use cli_runtime::parse_change_bump;
@@ -175,8 +174,10 @@ use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackagePublicationTarget;
 use monochange_core::PackageRecord;
+use monochange_core::PreStablePolicy;
 use monochange_core::PreparedChangeset;
 use monochange_core::PreparedChangesetTarget;
+use monochange_core::PropagationByKind;
 use monochange_core::ReleaseManifest;
 use monochange_core::ReleaseManifestChangelog;
 use monochange_core::ReleaseManifestCompatibilityEvidence;
@@ -204,8 +205,10 @@ use monochange_core::SourceReleaseOperation;
 use monochange_core::SourceReleaseOutcome;
 use monochange_core::SourceReleaseRequest;
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 use monochange_core::VersionedFileDefinition;
 use monochange_core::materialize_dependency_edges;
+use monochange_core::prerelease_channel;
 use monochange_core::relative_to_root;
 #[cfg(feature = "forgejo")]
 use monochange_forgejo as forgejo_provider;
@@ -215,7 +218,8 @@ use monochange_gitea as gitea_provider;
 use monochange_github as github_provider;
 #[cfg(feature = "gitlab")]
 use monochange_gitlab as gitlab_provider;
-use monochange_graph::build_release_plan;
+use monochange_graph::ReleasePlanOptions;
+use monochange_graph::build_release_plan_with_options;
 use monochange_semver::CompatibilityProvider;
 use monochange_semver::collect_assessments;
 #[cfg(test)]
@@ -232,6 +236,14 @@ pub(crate) use workspace_ops::render_interactive_changeset_markdown;
 #[cfg(test)]
 pub(crate) static TEST_ENV_LOCK: std::sync::LazyLock<std::sync::Mutex<()>> =
 	std::sync::LazyLock::new(|| std::sync::Mutex::new(()));
+use graduation::GraduationRequest;
+use graduation::ensure_graduations_released;
+use prerelease::ChangesetRetention;
+use prerelease::PrereleaseRequest;
+use prerelease::PrereleaseSession;
+use prerelease::load_prerelease_state;
+use prerelease::prerelease_state_path;
+use prerelease::write_prerelease_state;
 pub(crate) use release_artifacts::*;
 pub use release_record::discover_release_record;
 pub use release_record::execute_release_retarget;
@@ -244,6 +256,12 @@ use serde::Serialize;
 use serde_json::json;
 use skill::SkillOptions;
 use skill::run_skill;
+pub use snapshot::PreparedSnapshot;
+use snapshot::SnapshotRequest;
+use snapshot::SnapshotWorkingTree;
+use snapshot::apply_snapshot_versions;
+use snapshot::create_snapshot_working_tree;
+use snapshot::rebase_file_updates;
 use subagents::SubagentOptions;
 use subagents::run_subagents;
 pub(crate) use versioned_files::*;
@@ -251,15 +269,21 @@ pub use workspace_ops::AddChangeFileRequest;
 use workspace_ops::PopulateWorkspaceResult;
 pub use workspace_ops::add_change_file;
 pub(crate) use workspace_ops::add_interactive_change_file;
+pub(crate) use workspace_ops::discover_release_workspace;
 pub use workspace_ops::discover_workspace;
+pub(crate) use workspace_ops::find_package_by_reference;
 use workspace_ops::init_workspace;
+pub(crate) use workspace_ops::package_display_id;
+pub(crate) use workspace_ops::plan_pending_release;
 pub use workspace_ops::plan_release;
 use workspace_ops::populate_workspace;
 pub use workspace_ops::prepare_release;
 pub(crate) use workspace_ops::prepare_release_execution_with_file_diffs;
+pub(crate) use workspace_ops::prepare_release_execution_with_modes;
 pub(crate) use workspace_ops::push_change_target_markdown;
 #[cfg(feature = "cargo")]
 pub(crate) use workspace_ops::validate_cargo_workspace_version_groups;
+pub(crate) use workspace_ops::workspace_dependency_cycles;
 
 pub(crate) fn render_config_step_json(
 	root: &Path,
@@ -303,7 +327,9 @@ pub(crate) fn synthetic_step_command_definition(
 	})
 }
 
+mod analysis_enforcement;
 mod analyze;
+mod change_drafts;
 mod changeset_policy;
 mod changesets;
 mod cli;
@@ -313,6 +339,7 @@ mod cli_runtime;
 mod cli_theme;
 mod command_wizard;
 mod git_support;
+mod graduation;
 mod hosted_sources;
 mod interactive;
 mod jq_filter;
@@ -322,16 +349,20 @@ mod mcp;
 mod migration_audit;
 mod package_publish;
 mod prepared_release_cache;
+mod prerelease;
 mod publish_progress;
 mod publish_rate_limits;
 mod publish_readiness;
 mod release_artifacts;
 mod release_branch_policy;
+mod release_explain;
 mod release_record;
 mod skill;
+mod snapshot;
 mod subagents;
 mod tracing_setup;
 mod versioned_files;
+mod workspace_graph;
 mod workspace_ops;
 
 pub(crate) use prepared_release_cache::ensure_monochange_artifact_ignored;
@@ -461,6 +492,8 @@ pub struct ReleaseTarget {
 	pub members: Vec<String>,
 	pub rendered_title: String,
 	pub rendered_changelog_title: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub prerelease: Option<String>,
 }
 
 /// Rendered changelog payload produced during release preparation.
@@ -490,6 +523,9 @@ pub struct PreparedRelease {
 	pub updated_changelogs: Vec<PathBuf>,
 	pub deleted_changesets: Vec<PathBuf>,
 	pub dry_run: bool,
+	/// Set when the release was prepared as a throwaway snapshot build.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub snapshot: Option<PreparedSnapshot>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
@@ -972,6 +1008,34 @@ where
 				.unwrap_or_default();
 			lint::run_check_command(root, fix, &ecosystems, &only_rules, format)
 		}
+		Some(("graph", graph_matches)) => {
+			if quiet {
+				return Ok(String::new());
+			}
+			let values = |name: &str| -> Vec<String> {
+				graph_matches
+					.get_many::<String>(name)
+					.map(|values| values.cloned().collect())
+					.unwrap_or_default()
+			};
+			let request = workspace_graph::GraphRequest {
+				format: graph_matches
+					.get_one::<String>("format")
+					.map_or(Ok(workspace_graph::GraphFormat::Dot), |value| {
+						workspace_graph::GraphFormat::parse(value)
+					})?,
+				ecosystems: values("ecosystem"),
+				groups: values("group"),
+				packages: values("package"),
+				plan: graph_matches.get_flag("plan"),
+				publish_levels: graph_matches.get_flag("publish-levels"),
+			};
+			let graph = workspace_graph::build_workspace_graph(root, &request)?;
+			Ok(workspace_graph::render_workspace_graph(
+				&graph,
+				request.format,
+			))
+		}
 		Some(("lint", lint_matches)) => {
 			if quiet {
 				return Ok(String::new());
@@ -979,6 +1043,30 @@ where
 			lint::handle_lint_subcommand(root, lint_matches)
 		}
 
+		Some((_, cli_command_matches))
+			if cli_command_matches.subcommand_name() == Some("explain") =>
+		{
+			if quiet {
+				return Ok(String::new());
+			}
+			let explain_matches = cli_command_matches
+				.subcommand_matches("explain")
+				.expect("explain subcommand matches");
+			let package = explain_matches
+				.get_one::<String>("package")
+				.expect("clap requires a package")
+				.as_str();
+			let format = explain_matches
+				.get_one::<String>("format")
+				.map_or(Ok(OutputFormat::Markdown), |value| {
+					parse_output_format(value)
+				})?;
+			let explanation = release_explain::explain_release(root, package)?;
+			Ok(release_explain::render_release_explanation(
+				&explanation,
+				format,
+			))
+		}
 		Some((cli_command_name, cli_command_matches)) if cli_command_name.starts_with("step:") => {
 			let configuration = configuration?;
 			let synthetic = synthetic_step_command_definition(cli_command_name)?;
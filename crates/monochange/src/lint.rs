@@ -8,6 +8,7 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use clap::ArgMatches;
+use monochange_config::lints::ChangesetLintSuite;
 use monochange_config::load_workspace_configuration;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
@@ -20,26 +21,72 @@ use monochange_core::lint::LintSuite;
 use monochange_lint::LintSelection;
 use monochange_lint::Linter;
 
+use crate::CHANGESET_DIR;
 use crate::OutputFormat;
+use crate::discover_release_workspace;
+use crate::discover_workspace;
+use crate::workspace_dependency_cycles;
 
-#[allow(clippy::vec_init_then_push)]
 fn lint_suites() -> Vec<Box<dyn LintSuite>> {
+	lint_suites_with_changesets(monochange_config::lints::lint_suite())
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn lint_suites_with_changesets(changesets: ChangesetLintSuite) -> Vec<Box<dyn LintSuite>> {
 	let mut suites: Vec<Box<dyn LintSuite>> = Vec::new();
 	#[cfg(feature = "cargo")]
 	suites.push(Box::new(monochange_cargo::lints::lint_suite()));
 	#[cfg(feature = "npm")]
 	suites.push(Box::new(monochange_npm::lints::lint_suite()));
+	#[cfg(feature = "deno")]
+	suites.push(Box::new(monochange_deno::lints::lint_suite()));
 	#[cfg(feature = "dart")]
 	suites.push(Box::new(monochange_dart::lints::lint_suite()));
-	suites.push(Box::new(monochange_config::lints::lint_suite()));
+	#[cfg(feature = "python")]
+	suites.push(Box::new(monochange_python::lints::lint_suite()));
+	#[cfg(feature = "go")]
+	suites.push(Box::new(monochange_go::lints::lint_suite()));
+	suites.push(Box::new(changesets));
 	suites
 }
 
 fn build_linter(
+	root: &Path,
 	configuration: &monochange_core::WorkspaceConfiguration,
 	selection: LintSelection,
 ) -> Linter {
-	Linter::new(lint_suites(), configuration.lints.clone()).with_selection(selection)
+	let changesets = monochange_config::lints::lint_suite()
+		.with_package_versions(configured_package_versions(root, configuration));
+	Linter::new(
+		lint_suites_with_changesets(changesets),
+		configuration.lints.clone(),
+	)
+	.with_selection(selection)
+}
+
+/// Current versions keyed by configured package id, so the changeset bump
+/// rules can apply each package's pre-1.0 policy.
+///
+/// Discovery failures are reported by validation, so they only fall back to
+/// the declared bumps here.
+fn configured_package_versions(
+	root: &Path,
+	configuration: &monochange_core::WorkspaceConfiguration,
+) -> BTreeMap<String, semver::Version> {
+	if configuration.packages.is_empty() || !root.join(CHANGESET_DIR).exists() {
+		return BTreeMap::new();
+	}
+	let Ok(discovery) = discover_release_workspace(root, configuration) else {
+		return BTreeMap::new();
+	};
+	discovery
+		.packages
+		.into_iter()
+		.filter_map(|package| {
+			let config_id = package.metadata.get("config_id")?.clone();
+			Some((config_id, package.current_version?))
+		})
+		.collect()
 }
 
 pub(crate) fn collect_workspace_validation_issues(root: &Path) -> (Vec<String>, Vec<String>) {
@@ -55,6 +102,17 @@ pub(crate) fn collect_workspace_validation_issues(root: &Path) -> (Vec<String>,
 		Err(error) => errors.push(error.render()),
 	}
 
+	// Cycles do not fail validation, but they leave publish order ambiguous.
+	if errors.is_empty()
+		&& let Ok(discovery) = discover_workspace(root)
+	{
+		warnings.extend(
+			workspace_dependency_cycles(&discovery.packages)
+				.iter()
+				.map(ToString::to_string),
+		);
+	}
+
 	(warnings, errors)
 }
 
@@ -125,7 +183,7 @@ pub(crate) fn run_check_command(
 	let selection = LintSelection::all()
 		.with_suites(ecosystems.iter().cloned())
 		.with_rules(only_rules.iter().cloned());
-	let linter = build_linter(&configuration, selection);
+	let linter = build_linter(root, &configuration, selection);
 
 	let show_progress = matches!(format, OutputFormat::Text | OutputFormat::Markdown);
 	let reporter = if show_progress {
@@ -208,7 +266,7 @@ pub(crate) fn run_check_command(
 /// Run lint as part of a Validate step. Returns (`formatted_output`, `has_errors`).
 pub(crate) fn run_lint_step(root: &Path, fix: bool) -> MonochangeResult<(String, bool)> {
 	let configuration = load_workspace_configuration(root)?;
-	let linter = build_linter(&configuration, LintSelection::all());
+	let linter = build_linter(root, &configuration, LintSelection::all());
 	let report = linter.lint_workspace(
 		root,
 		&configuration,
@@ -369,7 +427,10 @@ pub(crate) fn scaffold_lint_rule(root: &Path, id: &str) -> MonochangeResult<Stri
 	let crate_name = match suite {
 		"cargo" => "monochange_cargo",
 		"npm" => "monochange_npm",
+		"deno" => "monochange_deno",
 		"dart" => "monochange_dart",
+		"python" => "monochange_python",
+		"go" => "monochange_go",
 		other => {
 			return Err(MonochangeError::Config(format!(
 				"scaffolding is not yet supported for lint suite `{other}`"
@@ -46,6 +46,14 @@ pub struct LintExplainParam {
 	pub id: String,
 }
 
+/// Input payload for the MCP release explanation tool.
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ReleaseExplainParam {
+	pub path: Option<String>,
+	/// Configured package id or package name to explain
+	pub package: String,
+}
+
 /// Input payload for the MCP change-file creation tool.
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ChangeParam {
@@ -83,6 +91,14 @@ pub struct AnalyzeChangesParam {
 	pub max_suggestions: Option<usize>,
 }
 
+/// Input payload for the MCP draft-changesets tool.
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct DraftChangesetsParam {
+	pub path: Option<String>,
+	/// Explicit frame specification (e.g., "working", "main...feature", "pr:target,source")
+	pub frame: Option<String>,
+}
+
 /// Input payload for the MCP validate-changeset tool.
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
 pub struct ValidateChangesetParam {
@@ -595,6 +611,42 @@ impl MonochangeMcpServer {
 		})))
 	}
 
+	#[tool(
+		name = "monochange_release_explain",
+		description = "Explain which changesets, dependencies, and version groups produced one package's planned release."
+	)]
+	async fn release_explain(
+		&self,
+		Parameters(params): Parameters<ReleaseExplainParam>,
+	) -> Result<CallToolResult, McpError> {
+		let root = resolve_root(params.path.as_deref());
+
+		let explanation = match crate::release_explain::explain_release(&root, &params.package) {
+			Ok(explanation) => explanation,
+			Err(error) => {
+				return Ok(json_error_result(json!({
+					"ok": false,
+					"action": "release_explain",
+					"root": root,
+					"summary": error.render(),
+					"error": error.render()
+				})));
+			}
+		};
+
+		Ok(json_result(json!({
+			"ok": true,
+			"action": "release_explain",
+			"summary": format!(
+				"`{}` plans a {} release ({}).",
+				explanation.package.id,
+				explanation.package.bump,
+				explanation.package.trigger
+			),
+			"explanation": explanation,
+		})))
+	}
+
 	#[tool(
 		name = "monochange_release_manifest",
 		description = "Generate a dry-run release manifest JSON document for downstream automation."
@@ -805,6 +857,60 @@ impl MonochangeMcpServer {
 		})))
 	}
 
+	#[tool(
+		name = "monochange_draft_changesets",
+		description = "Write draft .changeset files for changed packages that no pending changeset covers, pre-filled from semantic analysis."
+	)]
+	async fn draft_changesets(
+		&self,
+		Parameters(params): Parameters<DraftChangesetsParam>,
+	) -> Result<CallToolResult, McpError> {
+		let root = resolve_root(params.path.as_deref());
+		let frame = match params.frame.as_deref() {
+			Some(frame_str) => parse_frame(frame_str),
+			None => {
+				match crate::change_drafts::detect_change_frame(&root) {
+					Ok(frame) => frame,
+					Err(error) => {
+						return Ok(json_error_result(json!({
+							"ok": false,
+							"action": "draft_changesets",
+							"root": root,
+							"summary": error.render(),
+							"error": error.render()
+						})));
+					}
+				}
+			}
+		};
+
+		match crate::change_drafts::draft_changesets_from_analysis(&root, &frame) {
+			Ok(report) => {
+				Ok(json_result(json!({
+					"ok": true,
+					"action": "draft_changesets",
+					"frame": report.frame,
+					"drafts": report.drafts,
+					"covered": report.covered,
+					"summary": format!(
+						"Wrote {} draft changeset(s); {} changed package(s) were already covered",
+						report.drafts.len(),
+						report.covered.len(),
+					)
+				})))
+			}
+			Err(error) => {
+				Ok(json_error_result(json!({
+					"ok": false,
+					"action": "draft_changesets",
+					"root": root,
+					"summary": format!("Failed to draft changesets: {}", error.render()),
+					"error": error.render()
+				})))
+			}
+		}
+	}
+
 	#[tool(
 		name = "monochange_validate_changeset",
 		description = "Validate that a changeset matches the current semantic diff for its targeted packages."
@@ -19,6 +19,7 @@ use monochange_github::format_manual_trust_context;
 use monochange_github::resolve_github_trust_context;
 use monochange_github::verify_github_trust_context;
 use monochange_go::write_go_placeholder_manifest;
+use monochange_helm::write_helm_placeholder_manifest;
 use monochange_npm::render_npm_trust_command;
 use monochange_npm::write_npm_placeholder_manifest;
 #[cfg(test)]
@@ -59,12 +60,14 @@ use monochange_publish::select_release_publication_targets;
 use monochange_publish::trusted_publishing_capability_message;
 use monochange_publish::trusted_publishing_capability_message_for_builtin;
 use monochange_python::write_python_placeholder_manifest;
+use monochange_ruby::write_ruby_placeholder_manifest;
 #[cfg(test)]
 use reqwest::Client;
 #[cfg(test)]
 use tempfile::TempDir;
 
 use crate::PreparedRelease;
+use crate::PreparedSnapshot;
 use crate::discover_release_record;
 use crate::discover_workspace;
 use crate::publish_progress::StderrPublishProgressReporter;
@@ -219,6 +222,35 @@ async fn execute_release_publish_requests(
 	.await
 }
 
+/// Publish a snapshot release from its temporary working tree under the
+/// snapshot dist-tag so registries never promote it to `latest`.
+pub(crate) async fn run_snapshot_publish_packages(
+	root: &Path,
+	configuration: &WorkspaceConfiguration,
+	prepared_release: &PreparedRelease,
+	snapshot: &PreparedSnapshot,
+	selected_packages: &BTreeSet<String>,
+	dry_run: bool,
+) -> MonochangeResult<PackagePublishReport> {
+	let publish_root = snapshot.working_tree.as_deref().unwrap_or(root);
+	let discovery = discover_workspace(publish_root)?;
+	let requests = build_release_requests(
+		configuration,
+		&discovery.packages,
+		&prepared_release.package_publications,
+		selected_packages,
+	)?
+	.into_iter()
+	.map(|request| {
+		PublishRequest {
+			dist_tag: Some(snapshot.tag.clone()),
+			..request
+		}
+	})
+	.collect::<Vec<_>>();
+	execute_release_publish_requests(publish_root, configuration, dry_run, &requests).await
+}
+
 pub(crate) async fn release_record_package_publications_from_prepared_or_head(
 	root: &Path,
 	prepared_release: Option<&PreparedRelease>,
@@ -497,6 +529,18 @@ fn placeholder_manifest_writer_registry() -> PlaceholderManifestWriterRegistry {
 				write_go_placeholder_manifest(placeholder_dir, request)
 			}),
 		)
+		.with_writer(
+			RegistryKind::Helm,
+			Box::new(|placeholder_dir, request, _root, _source| {
+				write_helm_placeholder_manifest(placeholder_dir, request)
+			}),
+		)
+		.with_writer(
+			RegistryKind::Rubygems,
+			Box::new(|placeholder_dir, request, _root, _source| {
+				write_ruby_placeholder_manifest(placeholder_dir, request)
+			}),
+		)
 }
 
 fn manual_trust_outcome(
@@ -21,6 +21,8 @@ use crate::PreparedFileDiff;
 use crate::PreparedRelease;
 use crate::PreparedReleaseExecution;
 use crate::StepPhaseTiming;
+use crate::prerelease::load_prerelease_state;
+use crate::prerelease::release_targets_match_prerelease_state;
 use crate::resolve_config_path;
 use crate::root_relative;
 
@@ -312,6 +314,16 @@ async fn validate_prepared_release_artifact(
 		));
 	}
 
+	if !release_targets_match_prerelease_state(
+		&load_prerelease_state(root)?,
+		&artifact.prepared_release.release_targets,
+	) {
+		return Err(stale_artifact_error(
+			artifact_path,
+			"prerelease mode changed since the prepared release was saved",
+		));
+	}
+
 	let current_head = git_head_commit(root).await?;
 	if current_head != artifact.head_commit {
 		return Err(stale_artifact_error(
@@ -0,0 +1,412 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_config::LoadedChangesetFile;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageRecord;
+use monochange_core::WorkspaceConfiguration;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::CHANGESET_DIR;
+use crate::ReleaseTarget;
+use crate::root_relative;
+
+/// File inside `.changeset/` that records active prerelease channels.
+pub(crate) const PRERELEASE_STATE_FILE: &str = "pre.json";
+
+/// Persisted prerelease mode state.
+///
+/// Owners are configured package or group ids. Changesets that were already
+/// shipped as prereleases stay on disk and are listed with the owners that
+/// still need them for their stable release.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PrereleaseState {
+	#[serde(default)]
+	pub channels: BTreeMap<String, String>,
+	#[serde(default)]
+	pub changesets: BTreeMap<PathBuf, BTreeSet<String>>,
+}
+
+impl PrereleaseState {
+	pub(crate) fn is_empty(&self) -> bool {
+		self.channels.is_empty() && self.changesets.is_empty()
+	}
+}
+
+/// Prerelease inputs supplied to a single `PrepareRelease` run.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct PrereleaseRequest {
+	/// Channel to enter or continue, such as `alpha`, `beta`, or `rc`.
+	pub channel: Option<String>,
+	/// Package or group ids to scope the request to. Empty means every owner.
+	pub owners: Vec<String>,
+	/// Leave prerelease mode and ship the stable release.
+	pub exit: bool,
+}
+
+impl PrereleaseRequest {
+	/// Read the `pre`, `pre_package`, and `exit_pre` step inputs.
+	pub(crate) fn from_step_inputs(
+		inputs: &BTreeMap<String, Vec<String>>,
+	) -> MonochangeResult<Self> {
+		let channel = inputs
+			.get("pre")
+			.and_then(|values| values.first())
+			.map(|value| value.trim().to_string())
+			.filter(|value| !value.is_empty());
+		if let Some(channel) = &channel {
+			validate_prerelease_channel(channel)?;
+		}
+		let owners = inputs
+			.get("pre_package")
+			.into_iter()
+			.flatten()
+			.map(|value| value.trim().to_string())
+			.filter(|value| !value.is_empty())
+			.collect::<Vec<_>>();
+		let exit = crate::parse_boolean_step_input(inputs, "exit_pre")?.unwrap_or(false);
+		if exit && channel.is_some() {
+			return Err(MonochangeError::Config(
+				"`pre` and `exit_pre` cannot be used together".to_string(),
+			));
+		}
+
+		Ok(Self {
+			channel,
+			owners,
+			exit,
+		})
+	}
+
+	/// Returns `true` when the run enters, changes, or exits prerelease mode.
+	pub(crate) fn is_requested(&self) -> bool {
+		self.channel.is_some() || self.exit
+	}
+}
+
+fn validate_prerelease_channel(channel: &str) -> MonochangeResult<()> {
+	let valid = channel
+		.chars()
+		.all(|character| character.is_ascii_alphanumeric() || character == '-')
+		&& !channel.chars().all(|character| character.is_ascii_digit());
+	if valid {
+		return Ok(());
+	}
+	Err(MonochangeError::Config(format!(
+		"invalid prerelease channel `{channel}`; use letters, digits, and `-` only (for example `beta`)"
+	)))
+}
+
+pub(crate) fn prerelease_state_path(root: &Path) -> PathBuf {
+	root.join(CHANGESET_DIR).join(PRERELEASE_STATE_FILE)
+}
+
+/// Load `.changeset/pre.json`, returning an empty state when it does not exist.
+pub(crate) fn load_prerelease_state(root: &Path) -> MonochangeResult<PrereleaseState> {
+	let path = prerelease_state_path(root);
+	if !path.exists() {
+		return Ok(PrereleaseState::default());
+	}
+	let contents = fs::read_to_string(&path).map_err(|error| {
+		MonochangeError::Io(format!("failed to read {}: {error}", path.display()))
+	})?;
+	serde_json::from_str(&contents).map_err(|error| {
+		MonochangeError::Config(format!(
+			"failed to parse prerelease state {}: {error}",
+			path.display()
+		))
+	})
+}
+
+/// Persist `state` to `.changeset/pre.json`, removing the file once no owner
+/// is in prerelease mode.
+pub(crate) fn write_prerelease_state(root: &Path, state: &PrereleaseState) -> MonochangeResult<()> {
+	let path = prerelease_state_path(root);
+	if state.is_empty() {
+		if path.exists() {
+			fs::remove_file(&path).map_err(|error| {
+				MonochangeError::Io(format!("failed to delete {}: {error}", path.display()))
+			})?;
+		}
+		return Ok(());
+	}
+	let mut rendered = serde_json::to_string_pretty(state)
+		.unwrap_or_else(|error| panic!("serializing prerelease state failed: {error}"));
+	rendered.push('\n');
+	fs::write(&path, rendered).map_err(|error| {
+		MonochangeError::Io(format!("failed to write {}: {error}", path.display()))
+	})
+}
+
+/// Returns `true` when every release target carries the channel that `state`
+/// assigns to its owner, so a prepared release saved earlier still reflects
+/// the current prerelease mode.
+pub(crate) fn release_targets_match_prerelease_state(
+	state: &PrereleaseState,
+	release_targets: &[ReleaseTarget],
+) -> bool {
+	release_targets
+		.iter()
+		.all(|target| target.prerelease.as_ref() == state.channels.get(&target.id))
+}
+
+/// Active prerelease channels for one release preparation.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct PrereleaseSession {
+	/// Channel keyed by configured package or group id after applying the request.
+	pub channels: BTreeMap<String, String>,
+	previous: PrereleaseState,
+}
+
+impl PrereleaseSession {
+	/// Combine the persisted state with the inputs of the current run.
+	pub(crate) fn resolve(
+		configuration: &WorkspaceConfiguration,
+		previous: PrereleaseState,
+		request: &PrereleaseRequest,
+	) -> MonochangeResult<Self> {
+		// Linked group members keep their own versions, so a linked group id
+		// stands for each of its members.
+		let owners = request
+			.owners
+			.iter()
+			.flat_map(|owner| configuration.expand_linked_group(owner))
+			.map(|owner| resolve_prerelease_owner(configuration, owner))
+			.collect::<MonochangeResult<BTreeSet<_>>>()?;
+		let mut channels = previous.channels.clone();
+
+		if request.exit {
+			if owners.is_empty() {
+				channels.clear();
+			} else {
+				for owner in &owners {
+					if channels.remove(owner).is_none() {
+						return Err(MonochangeError::Config(format!(
+							"`{owner}` is not in prerelease mode"
+						)));
+					}
+				}
+			}
+		}
+
+		if let Some(channel) = &request.channel {
+			let owners = if owners.is_empty() {
+				all_release_owners(configuration)
+			} else {
+				owners
+			};
+			for owner in owners {
+				channels.insert(owner, channel.clone());
+			}
+		}
+
+		Ok(Self { channels, previous })
+	}
+
+	/// Map active channels onto the package record ids and version group ids
+	/// used by the release planner.
+	pub(crate) fn planner_channels(
+		&self,
+		configuration: &WorkspaceConfiguration,
+		packages: &[PackageRecord],
+	) -> BTreeMap<String, String> {
+		let mut planner_channels = BTreeMap::new();
+		for package in packages {
+			let Some(channel) = self.channels.get(&release_owner_id(configuration, package)) else {
+				continue;
+			};
+			let key = package
+				.version_group_id
+				.clone()
+				.unwrap_or_else(|| package.id.clone());
+			planner_channels.insert(key, channel.clone());
+		}
+		planner_channels
+	}
+
+	/// Drop signals that were already shipped as prereleases for owners that are
+	/// still in prerelease mode, and return the retention outcome for every file.
+	pub(crate) fn retain_changesets(
+		&self,
+		root: &Path,
+		configuration: &WorkspaceConfiguration,
+		packages: &[PackageRecord],
+		loaded_changesets: Vec<LoadedChangesetFile>,
+	) -> ChangesetRetention {
+		let owner_by_package_id = packages
+			.iter()
+			.map(|package| {
+				(
+					package.id.as_str(),
+					release_owner_id(configuration, package),
+				)
+			})
+			.collect::<BTreeMap<_, _>>();
+		let mut retention = ChangesetRetention::default();
+
+		for mut changeset in loaded_changesets {
+			let relative_path = root_relative(root, &changeset.path);
+			let consumed_by = self.previous.changesets.get(&relative_path);
+			let signal_owners = changeset
+				.signals
+				.iter()
+				.filter_map(|signal| owner_by_package_id.get(signal.package_id.as_str()))
+				.cloned()
+				.collect::<BTreeSet<_>>();
+
+			// Owners that consumed this changeset as a prerelease only need it
+			// again once they leave prerelease mode.
+			let pending_owners = match consumed_by {
+				Some(consumed_by) => consumed_by.clone(),
+				None => signal_owners,
+			};
+			changeset.signals.retain(|signal| {
+				let Some(owner) = owner_by_package_id.get(signal.package_id.as_str()) else {
+					return consumed_by.is_none();
+				};
+				match consumed_by {
+					Some(consumed_by) => {
+						consumed_by.contains(owner) && !self.channels.contains_key(owner)
+					}
+					None => true,
+				}
+			});
+			let keep_for = pending_owners
+				.into_iter()
+				.filter(|owner| self.channels.contains_key(owner))
+				.collect::<BTreeSet<_>>();
+
+			if keep_for.is_empty() {
+				retention.deletable.push(changeset.path.clone());
+			} else {
+				retention.kept.insert(relative_path, keep_for);
+			}
+			if consumed_by.is_none() || !changeset.signals.is_empty() {
+				retention.included.push(changeset);
+			}
+		}
+
+		retention
+	}
+
+	/// Returns `true` when an owner was in prerelease mode before this run.
+	pub(crate) fn was_active(&self) -> bool {
+		!self.previous.channels.is_empty()
+	}
+
+	/// Keep the signals of commit-derived changesets that earlier prereleases
+	/// already shipped only for owners leaving prerelease mode in this run.
+	///
+	/// Commits have no file to retain in `.changeset/pre.json`, so the commit
+	/// range reaches back to the last stable release while prerelease mode is
+	/// active and this filter stands in for [`Self::retain_changesets`].
+	pub(crate) fn retain_prereleased_commit_changesets(
+		&self,
+		configuration: &WorkspaceConfiguration,
+		packages: &[PackageRecord],
+		changesets: Vec<LoadedChangesetFile>,
+	) -> Vec<LoadedChangesetFile> {
+		let owner_by_package_id = packages
+			.iter()
+			.map(|package| {
+				(
+					package.id.as_str(),
+					release_owner_id(configuration, package),
+				)
+			})
+			.collect::<BTreeMap<_, _>>();
+		changesets
+			.into_iter()
+			.filter_map(|mut changeset| {
+				changeset.signals.retain(|signal| {
+					owner_by_package_id
+						.get(signal.package_id.as_str())
+						.is_some_and(|owner| {
+							self.previous.channels.contains_key(owner)
+								&& !self.channels.contains_key(owner)
+						})
+				});
+				(!changeset.signals.is_empty()).then_some(changeset)
+			})
+			.collect()
+	}
+
+	/// Build the state to persist after this run.
+	pub(crate) fn next_state(
+		&self,
+		kept_changesets: BTreeMap<PathBuf, BTreeSet<String>>,
+	) -> PrereleaseState {
+		PrereleaseState {
+			channels: self.channels.clone(),
+			changesets: kept_changesets,
+		}
+	}
+
+	/// Returns `true` when `next` differs from the state loaded at the start of the run.
+	pub(crate) fn state_changed(&self, next: &PrereleaseState) -> bool {
+		self.previous != *next
+	}
+}
+
+/// Outcome of filtering changesets for a prerelease-aware run.
+#[derive(Debug, Default)]
+pub(crate) struct ChangesetRetention {
+	/// Changesets that feed the release plan. Previously consumed changesets
+	/// are only included while they still carry signals.
+	pub included: Vec<LoadedChangesetFile>,
+	/// Changeset files that no prerelease owner still needs.
+	pub deletable: Vec<PathBuf>,
+	/// Changeset files kept on disk, keyed by root-relative path, with the
+	/// owners that still need them for their stable release.
+	pub kept: BTreeMap<PathBuf, BTreeSet<String>>,
+}
+
+fn resolve_prerelease_owner(
+	configuration: &WorkspaceConfiguration,
+	owner: &str,
+) -> MonochangeResult<String> {
+	if configuration.group_by_id(owner).is_some() {
+		return Ok(owner.to_string());
+	}
+	if configuration.package_by_id(owner).is_some() {
+		return Ok(configuration
+			.group_for_package(owner)
+			.map_or_else(|| owner.to_string(), |group| group.id.clone()));
+	}
+	Err(MonochangeError::Config(format!(
+		"prerelease target `{owner}` does not match a configured package or group"
+	)))
+}
+
+fn all_release_owners(configuration: &WorkspaceConfiguration) -> BTreeSet<String> {
+	configuration
+		.packages
+		.iter()
+		.map(|package| {
+			configuration
+				.group_for_package(&package.id)
+				.map_or_else(|| package.id.clone(), |group| group.id.clone())
+		})
+		.collect()
+}
+
+fn release_owner_id(configuration: &WorkspaceConfiguration, package: &PackageRecord) -> String {
+	let config_id = package
+		.metadata
+		.get("config_id")
+		.cloned()
+		.unwrap_or_else(|| package.name.clone());
+	configuration
+		.group_for_package(&config_id)
+		.map_or(config_id, |group| group.id.clone())
+}
+
+#[cfg(test)]
+#[path = "__tests__/prerelease_tests.rs"]
+mod tests;
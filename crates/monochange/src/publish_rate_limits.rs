@@ -22,6 +22,7 @@ use monochange_publish::filter_pending_publish_requests;
 use crate::PreparedRelease;
 use crate::discover_workspace;
 use crate::package_publish;
+use crate::workspace_dependency_cycles;
 
 const CRATES_IO_SOURCE: &str = "https://github.com/rust-lang/crates.io";
 const NPM_TRUST_DOCS: &str = "https://docs.npmjs.com/trusted-publishers";
@@ -77,12 +78,39 @@ pub(crate) async fn plan_publish_rate_limits(
 		)
 		.await?
 	};
-	Ok(plan_publish_rate_limits_for_dependency_ordered_requests(
+	let mut report = plan_publish_rate_limits_for_dependency_ordered_requests(
 		&requests,
 		packages,
 		mode.operation(),
 		dry_run,
-	))
+	);
+	report
+		.warnings
+		.extend(publish_dependency_cycle_warnings(&requests, packages));
+	Ok(report)
+}
+
+/// Describe the dependency cycles that involve at least two of the planned
+/// publications; when any exist, the plan keeps the requests unordered.
+fn publish_dependency_cycle_warnings(
+	requests: &[package_publish::PublishRequest],
+	packages: &[monochange_core::PackageRecord],
+) -> Vec<String> {
+	let request_ids = requests
+		.iter()
+		.map(|request| request.package_id.as_str())
+		.collect::<BTreeSet<_>>();
+	workspace_dependency_cycles(packages)
+		.into_iter()
+		.filter(|cycle| {
+			cycle
+				.package_ids
+				.iter()
+				.filter(|package_id| request_ids.contains(package_id.as_str()))
+				.count() > 1
+		})
+		.map(|cycle| format!("publish order is not dependency-sorted because of a {cycle}"))
+		.collect()
 }
 
 async fn build_placeholder_plan_requests(
@@ -585,6 +613,34 @@ fn registry_policies() -> Vec<RegistryRateLimitPolicy> {
 				notes: "official module publishing guidance documents tag-based publication".to_string(),
 			}],
 		},
+		RegistryRateLimitPolicy {
+			registry: RegistryKind::Helm,
+			operation: RateLimitOperation::Publish,
+			limit: None,
+			window_seconds: None,
+			confidence: RateLimitConfidence::Low,
+			notes: "Helm charts are pushed to user-chosen OCI registries or chart repository directories; quotas depend on the hosting registry".to_string(),
+			evidence: vec![RateLimitEvidence {
+				title: "Helm OCI registries documentation".to_string(),
+				url: "https://helm.sh/docs/topics/registries/".to_string(),
+				kind: RateLimitEvidenceKind::Official,
+				notes: "official `helm push` guidance without a registry-independent quota".to_string(),
+			}],
+		},
+		RegistryRateLimitPolicy {
+			registry: RegistryKind::Rubygems,
+			operation: RateLimitOperation::Publish,
+			limit: None,
+			window_seconds: None,
+			confidence: RateLimitConfidence::Low,
+			notes: "RubyGems.org throttles API requests per IP and API key; `gem push` has no separately documented quota".to_string(),
+			evidence: vec![RateLimitEvidence {
+				title: "RubyGems.org rate limits".to_string(),
+				url: "https://guides.rubygems.org/rubygems-org-rate-limits/".to_string(),
+				kind: RateLimitEvidenceKind::Official,
+				notes: "official request throttling guidance for RubyGems.org endpoints".to_string(),
+			}],
+		},
 	]
 }
 
@@ -5,6 +5,9 @@ use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::IsTerminal;
 
+use monochange_core::DependencyPropagation;
+use monochange_core::DiscoverySkipReason;
+use monochange_graph::constraint_allows;
 use similar::TextDiff;
 
 use super::*;
@@ -168,7 +171,8 @@ pub(crate) async fn build_release_targets(
 		planned_group_by_id.get(group.id.as_str()).and_then(|pg| {
 			pg.planned_version.as_ref().map(|version| {
 				let vs = version.to_string();
-				let tag = render_tag_name(&group.id, &vs, group.version_format);
+				let tag =
+					render_tag_name(&group.id, &vs, group.version_format, &group.version_scheme);
 				let prev = find_previous_tag_in(&tag, &sorted_tags);
 				let ctx = TitleRenderContext::new(
 					&group.id,
@@ -199,6 +203,7 @@ pub(crate) async fn build_release_targets(
 					members: group.packages.clone(),
 					rendered_title: ctx.render(rt),
 					rendered_changelog_title: ctx.render(ct),
+					prerelease: prerelease_channel(version).map(ToString::to_string),
 				}
 			})
 		})
@@ -223,28 +228,37 @@ pub(crate) async fn build_release_targets(
 		else {
 			continue;
 		};
-		let (owner_id, owner_kind, tag_enabled, release_enabled, version_format, members) =
-			if let Some(group) = group_by_package_id.get(config_id.as_str()).copied() {
-				(
-					&group.id,
-					ReleaseOwnerKind::Group,
-					group.tag,
-					group.release,
-					group.version_format,
-					group.packages.clone(),
-				)
-			} else {
-				(
-					&package_definition.id,
-					ReleaseOwnerKind::Package,
-					package_definition.tag,
-					package_definition.release,
-					package_definition.version_format,
-					vec![package_definition.id.clone()],
-				)
-			};
+		let (
+			owner_id,
+			owner_kind,
+			tag_enabled,
+			release_enabled,
+			version_format,
+			version_scheme,
+			members,
+		) = if let Some(group) = group_by_package_id.get(config_id.as_str()).copied() {
+			(
+				&group.id,
+				ReleaseOwnerKind::Group,
+				group.tag,
+				group.release,
+				group.version_format,
+				&group.version_scheme,
+				group.packages.clone(),
+			)
+		} else {
+			(
+				&package_definition.id,
+				ReleaseOwnerKind::Package,
+				package_definition.tag,
+				package_definition.release,
+				package_definition.version_format,
+				&package_definition.version_scheme,
+				vec![package_definition.id.clone()],
+			)
+		};
 		let vs = version.to_string();
-		let tag = render_tag_name(owner_id, &vs, version_format);
+		let tag = render_tag_name(owner_id, &vs, version_format, version_scheme);
 		let prev = find_previous_tag_in(&tag, &sorted_tags);
 		let ctx =
 			TitleRenderContext::new(owner_id, &vs, changes_count, source, &tag, prev.as_deref());
@@ -269,6 +283,7 @@ pub(crate) async fn build_release_targets(
 			members,
 			rendered_title: ctx.render(rt),
 			rendered_changelog_title: ctx.render(ct),
+			prerelease: prerelease_channel(version).map(ToString::to_string),
 		});
 	}
 	release_targets.sort_by(|left, right| left.id.cmp(&right.id));
@@ -328,6 +343,15 @@ pub(crate) fn build_package_publication_targets(
 pub(crate) fn build_manifest_updates_parallel(
 	packages: &[PackageRecord],
 	plan: &ReleasePlan,
+	propagation: DependencyPropagation,
+) -> MonochangeResult<Vec<FileUpdate>> {
+	let updates = build_manifest_updates(packages, plan)?;
+	Ok(retain_manifest_updates(updates, packages, plan, propagation))
+}
+
+fn build_manifest_updates(
+	packages: &[PackageRecord],
+	plan: &ReleasePlan,
 ) -> MonochangeResult<Vec<FileUpdate>> {
 	#[cfg(all(feature = "cargo", feature = "npm", feature = "deno", feature = "dart"))]
 	{
@@ -345,7 +369,16 @@ pub(crate) fn build_manifest_updates_parallel(
 				)
 			},
 		);
-		Ok([cargo_updates?, npm_updates?, deno_updates?, dart_updates?].concat())
+		let updates = [cargo_updates?, npm_updates?, deno_updates?, dart_updates?].concat();
+		#[cfg(feature = "go")]
+		let updates = [updates, build_go_manifest_updates(packages, plan)?].concat();
+		#[cfg(feature = "helm")]
+		let updates = [updates, build_helm_manifest_updates(packages, plan)?].concat();
+		#[cfg(feature = "ruby")]
+		let updates = [updates, build_ruby_manifest_updates(packages, plan)?].concat();
+		#[cfg(feature = "jvm")]
+		let updates = [updates, build_jvm_manifest_updates(packages, plan)?].concat();
+		Ok(updates)
 	}
 
 	#[cfg(not(all(feature = "cargo", feature = "npm", feature = "deno", feature = "dart")))]
@@ -359,16 +392,83 @@ pub(crate) fn build_manifest_updates_parallel(
 		updates.extend(build_deno_manifest_updates(packages, plan)?);
 		#[cfg(feature = "dart")]
 		updates.extend(build_dart_manifest_updates(packages, plan)?);
+		#[cfg(feature = "go")]
+		updates.extend(build_go_manifest_updates(packages, plan)?);
+		#[cfg(feature = "helm")]
+		updates.extend(build_helm_manifest_updates(packages, plan)?);
+		#[cfg(feature = "ruby")]
+		updates.extend(build_ruby_manifest_updates(packages, plan)?);
+		#[cfg(feature = "jvm")]
+		updates.extend(build_jvm_manifest_updates(packages, plan)?);
 		Ok(updates)
 	}
 }
 
+/// Drop manifest rewrites that range-aware propagation leaves alone.
+///
+/// The updaters always see every package so dependency ranges and workspace
+/// manifests are resolved against the whole workspace. With
+/// `DependencyPropagation::OutOfRange`, an unreleased dependent whose declared
+/// ranges still admit every released version keeps its manifest untouched;
+/// one whose range no longer admits a released version is still rewritten so
+/// the workspace keeps resolving.
+fn retain_manifest_updates(
+	updates: Vec<FileUpdate>,
+	packages: &[PackageRecord],
+	plan: &ReleasePlan,
+	propagation: DependencyPropagation,
+) -> Vec<FileUpdate> {
+	if propagation != DependencyPropagation::OutOfRange {
+		return updates;
+	}
+	let released_versions = released_versions_by_record_id(plan);
+	let released = packages
+		.iter()
+		.filter_map(|package| {
+			let version = released_versions.get(&package.id)?;
+			let version = semver::Version::parse(version).ok()?;
+			Some(((package.ecosystem, package.name.as_str()), version))
+		})
+		.collect::<BTreeMap<_, _>>();
+	let released_manifests = packages
+		.iter()
+		.filter(|package| released_versions.contains_key(&package.id))
+		.map(|package| package.manifest_path.as_path())
+		.collect::<BTreeSet<_>>();
+	let untouched_manifests = packages
+		.iter()
+		.filter(|package| !released_manifests.contains(package.manifest_path.as_path()))
+		.filter(|package| {
+			package.declared_dependencies.iter().all(|dependency| {
+				let Some(version) = released.get(&(package.ecosystem, dependency.name.as_str()))
+				else {
+					return true;
+				};
+				dependency.version_constraint.as_deref().is_none_or(|constraint| {
+					constraint_allows(package.ecosystem, constraint, version) == Some(true)
+				})
+			})
+		})
+		.map(|package| package.manifest_path.as_path())
+		.collect::<BTreeSet<_>>();
+	updates
+		.into_iter()
+		.filter(|update| !untouched_manifests.contains(update.path.as_path()))
+		.collect()
+}
+
 #[allow(clippy::match_same_arms)]
-pub(crate) fn render_tag_name(id: &str, version: &str, version_format: VersionFormat) -> String {
+pub(crate) fn render_tag_name(
+	id: &str,
+	version: &str,
+	version_format: VersionFormat,
+	version_scheme: &VersionScheme,
+) -> String {
+	let prefix = version_scheme.tag_version_prefix();
 	match version_format {
-		VersionFormat::Namespaced => format!("{id}/v{version}"),
-		VersionFormat::Primary => format!("v{version}"),
-		_ => format!("v{version}"),
+		VersionFormat::Namespaced => format!("{id}/{prefix}{version}"),
+		VersionFormat::Primary => format!("{prefix}{version}"),
+		_ => format!("{prefix}{version}"),
 	}
 }
 
@@ -456,11 +556,16 @@ pub(crate) async fn find_previous_tag(root: &Path, current_tag: &str) -> Option<
 }
 
 pub(crate) fn parse_tag_prefix_and_version(tag: &str) -> Option<(String, semver::Version)> {
-	let v_pos = tag.rfind('v')?;
-	let prefix = &tag[..=v_pos];
-	let version_str = &tag[v_pos + 1..];
-	let version = semver::Version::parse(version_str).ok()?;
-	Some((prefix.to_string(), version))
+	let v_prefixed = tag.rfind('v').and_then(|v_pos| {
+		let version = semver::Version::parse(&tag[v_pos + 1..]).ok()?;
+		Some((tag[..=v_pos].to_string(), version))
+	});
+	// Calendar-versioned tags such as `app/2026.10.0` carry no `v` marker.
+	v_prefixed.or_else(|| {
+		let version_start = tag.rfind('/').map_or(0, |slash_pos| slash_pos + 1);
+		let version = semver::Version::parse(&tag[version_start..]).ok()?;
+		Some((tag[..version_start].to_string(), version))
+	})
 }
 
 struct TitleRenderContext {
@@ -719,7 +824,7 @@ pub(crate) fn build_npm_manifest_updates(
 	use rayon::prelude::*;
 
 	let released_versions = released_versions_by_record_id(plan);
-	packages
+	let mut updates = packages
 		.iter()
 		.filter(|package| package.ecosystem == Ecosystem::Npm)
 		.par_bridge()
@@ -752,7 +857,20 @@ pub(crate) fn build_npm_manifest_updates(
 				content: rendered.into_bytes(),
 			})
 		})
-		.collect()
+		.collect::<MonochangeResult<Vec<_>>>()?;
+	// Dependencies declared through pnpm catalogs take their range from
+	// `pnpm-workspace.yaml`, so the catalog entry is what moves.
+	updates.extend(
+		monochange_npm::pnpm_catalog_updates(&plan.workspace_root, packages, &released_versions)?
+			.into_iter()
+			.map(|(path, contents)| {
+				FileUpdate {
+					path,
+					content: contents.into_bytes(),
+				}
+			}),
+	);
+	Ok(updates)
 }
 
 #[cfg(feature = "deno")]
@@ -845,6 +963,84 @@ pub(crate) fn build_dart_manifest_updates(
 		.collect()
 }
 
+/// Go keeps versions in tags, so its manifests only change when a module
+/// crosses a major version and its path gains a new `/vN` suffix.
+#[cfg(feature = "go")]
+pub(crate) fn build_go_manifest_updates(
+	packages: &[PackageRecord],
+	plan: &ReleasePlan,
+) -> MonochangeResult<Vec<FileUpdate>> {
+	let released_versions = released_versions_by_record_id(plan);
+	Ok(
+		monochange_go::semantic_import_versioning_updates(packages, &released_versions)?
+			.into_iter()
+			.map(|(path, contents)| {
+				FileUpdate {
+					path,
+					content: contents.into_bytes(),
+				}
+			})
+			.collect(),
+	)
+}
+
+#[cfg(feature = "ruby")]
+pub(crate) fn build_ruby_manifest_updates(
+	packages: &[PackageRecord],
+	plan: &ReleasePlan,
+) -> MonochangeResult<Vec<FileUpdate>> {
+	let released_versions = released_versions_by_record_id(plan);
+	Ok(
+		monochange_ruby::gem_manifest_updates(packages, &released_versions)?
+			.into_iter()
+			.map(|(path, contents)| {
+				FileUpdate {
+					path,
+					content: contents.into_bytes(),
+				}
+			})
+			.collect(),
+	)
+}
+
+#[cfg(feature = "jvm")]
+pub(crate) fn build_jvm_manifest_updates(
+	packages: &[PackageRecord],
+	plan: &ReleasePlan,
+) -> MonochangeResult<Vec<FileUpdate>> {
+	let released_versions = released_versions_by_record_id(plan);
+	Ok(
+		monochange_jvm::jvm_manifest_updates(packages, &released_versions)?
+			.into_iter()
+			.map(|(path, contents)| {
+				FileUpdate {
+					path,
+					content: contents.into_bytes(),
+				}
+			})
+			.collect(),
+	)
+}
+
+#[cfg(feature = "helm")]
+pub(crate) fn build_helm_manifest_updates(
+	packages: &[PackageRecord],
+	plan: &ReleasePlan,
+) -> MonochangeResult<Vec<FileUpdate>> {
+	let released_versions = released_versions_by_record_id(plan);
+	Ok(
+		monochange_helm::chart_manifest_updates(packages, &released_versions)?
+			.into_iter()
+			.map(|(path, contents)| {
+				FileUpdate {
+					path,
+					content: contents.into_bytes(),
+				}
+			})
+			.collect(),
+	)
+}
+
 #[must_use = "the file update result must be checked"]
 pub(crate) fn apply_file_updates(updates: &[FileUpdate]) -> MonochangeResult<()> {
 	for update in updates {
@@ -1113,6 +1309,7 @@ pub(crate) fn build_release_manifest(
 					members: target.members.clone(),
 					rendered_title: target.rendered_title.clone(),
 					rendered_changelog_title: target.rendered_changelog_title.clone(),
+					prerelease: target.prerelease.clone(),
 				}
 			})
 			.collect(),
@@ -1207,6 +1404,7 @@ pub(crate) fn build_release_manifest_from_record(record: &ReleaseRecord) -> Rele
 					members: target.members.clone(),
 					rendered_title: String::new(),
 					rendered_changelog_title: String::new(),
+					prerelease: target.prerelease.clone(),
 				}
 			})
 			.collect(),
@@ -1281,6 +1479,7 @@ pub(crate) fn build_release_record(
 					release: target.release,
 					tag_name: target.tag_name.clone(),
 					members: target.members.clone(),
+					prerelease: target.prerelease.clone(),
 				}
 			})
 			.collect(),
@@ -2011,6 +2210,18 @@ pub(crate) fn json_discovery_report(report: &DiscoveryReport) -> serde_json::Val
 			})
 		}).collect::<Vec<_>>(),
 		"warnings": report.warnings,
+		"skipped": report.skipped.iter().map(|skip| {
+			let (reason, pattern) = match &skip.reason {
+				DiscoverySkipReason::Excluded { pattern } => ("excluded", Some(pattern)),
+				DiscoverySkipReason::OutsideRoots => ("outside_roots", None),
+			};
+			json!({
+				"ecosystem": skip.ecosystem.as_str(),
+				"path": root_relative(&report.workspace_root, &skip.path),
+				"reason": reason,
+				"pattern": pattern,
+			})
+		}).collect::<Vec<_>>(),
 	})
 }
 
@@ -2041,6 +2252,17 @@ pub(crate) fn text_discovery_report(report: &DiscoveryReport) -> String {
 			lines.push(format!("- {warning}"));
 		}
 	}
+	if !report.skipped.is_empty() {
+		lines.push(format!("Skipped: {}", report.skipped.len()));
+		for skip in &report.skipped {
+			lines.push(format!(
+				"- {} ({}): {}",
+				root_relative(&report.workspace_root, &skip.path).display(),
+				skip.ecosystem,
+				skip.reason
+			));
+		}
+	}
 	lines.join("\n")
 }
 
@@ -0,0 +1,734 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use monochange_config::load_workspace_configuration;
+use monochange_core::BumpSeverity;
+use monochange_core::ChangeSignal;
+use monochange_core::DependencyEdge;
+use monochange_core::DependencyKind;
+use monochange_core::DependencyKindRule;
+use monochange_core::DependencyPropagation;
+use monochange_core::DiscoveryReport;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageRecord;
+use monochange_core::ReleaseCause;
+use monochange_core::ReleaseDecision;
+use monochange_core::ReleaseOwnerKind;
+use monochange_core::ReleasePlan;
+use monochange_core::relative_to_root;
+use monochange_graph::ReleasePlanOptions;
+use monochange_graph::constraint_allows;
+use serde::Serialize;
+
+use crate::OutputFormat;
+use crate::discover_release_workspace;
+use crate::find_package_by_reference;
+use crate::package_display_id;
+use crate::plan_pending_release;
+use crate::workspace_ops::PendingReleasePlan;
+
+/// Why one package receives its planned release, traced back through the
+/// release plan to the changesets that started it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReleaseExplanation {
+	/// The package the explanation was requested for and its causal tree.
+	pub package: ExplanationNode,
+	pub group: Option<String>,
+	pub current_version: Option<String>,
+	/// Packages whose changesets ultimately produced this release.
+	pub origins: Vec<String>,
+	/// Explicit version conflicts that were resolved for the package or its
+	/// group.
+	pub version_conflicts: Vec<String>,
+	/// Released dependencies whose bump did not reach the package, and why.
+	pub held_back: Vec<HeldBackDependency>,
+}
+
+/// One package in the causal chain.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ExplanationNode {
+	/// Configured package id, or the package name when none is configured.
+	pub id: String,
+	/// Package record id used by the release planner.
+	pub package_id: String,
+	pub bump: BumpSeverity,
+	pub trigger: String,
+	pub planned_version: Option<String>,
+	/// Set when this package was already expanded earlier in the tree.
+	#[serde(skip_serializing_if = "std::ops::Not::not")]
+	pub repeated: bool,
+	pub causes: Vec<ExplanationCause>,
+}
+
+/// A single reason that contributed to a package's bump.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub(crate) enum ExplanationCause {
+	/// A changeset targets the package directly.
+	Changeset(ChangesetCause),
+	/// A compatibility provider raised the bump requested by changesets.
+	Compatibility(CompatibilityCause),
+	/// A released dependency propagated its bump to the package.
+	Dependency(DependencyCause),
+	/// Another member of the package's version group forced a shared bump.
+	Group(GroupCause),
+	/// A releasing member of the package's linked group raised its bump.
+	LinkedGroup(GroupCause),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChangesetCause {
+	pub path: String,
+	pub bump: Option<BumpSeverity>,
+	pub version: Option<String>,
+	pub summary: Option<String>,
+	/// `caused_by` references that suppress dependency propagation.
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub caused_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CompatibilityCause {
+	pub provider: String,
+	pub severity: BumpSeverity,
+	pub summary: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DependencyCause {
+	pub reason: String,
+	pub dependency_kind: DependencyKind,
+	pub upstream: ExplanationNode,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GroupCause {
+	pub group: String,
+	pub members: Vec<ExplanationNode>,
+}
+
+/// A released dependency that did not propagate to the explained package.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HeldBackDependency {
+	pub id: String,
+	pub bump: BumpSeverity,
+	pub reason: String,
+}
+
+/// Everything the explanation needs from one planning run.
+struct PlanningContext<'a> {
+	root: &'a Path,
+	discovery: &'a DiscoveryReport,
+	signals: &'a [ChangeSignal],
+	plan: &'a ReleasePlan,
+	options: &'a ReleasePlanOptions,
+	decision_by_id: BTreeMap<&'a str, &'a ReleaseDecision>,
+	package_by_id: BTreeMap<&'a str, &'a PackageRecord>,
+}
+
+/// Plan the pending changesets without touching the workspace and explain
+/// the decision for `package`.
+pub(crate) fn explain_release(root: &Path, package: &str) -> MonochangeResult<ReleaseExplanation> {
+	let configuration = load_workspace_configuration(root)?;
+	let discovery = discover_release_workspace(root, &configuration)?;
+	let record = find_package_by_reference(&discovery.packages, package)?;
+	let PendingReleasePlan {
+		signals,
+		options,
+		plan,
+	} = plan_pending_release(root, &configuration, &discovery)?;
+
+	let context = PlanningContext {
+		root,
+		discovery: &discovery,
+		signals: &signals,
+		plan: &plan,
+		options: &options,
+		decision_by_id: plan
+			.decisions
+			.iter()
+			.map(|decision| (decision.package_id.as_str(), decision))
+			.collect(),
+		package_by_id: discovery
+			.packages
+			.iter()
+			.map(|package| (package.id.as_str(), package))
+			.collect(),
+	};
+	Ok(context.explain(record))
+}
+
+impl PlanningContext<'_> {
+	fn explain(&self, record: &PackageRecord) -> ReleaseExplanation {
+		let decision = self.decision_by_id.get(record.id.as_str()).copied();
+		let mut expanded = BTreeSet::new();
+		let package = self.node(&record.id, &mut expanded);
+		let version_conflicts = self
+			.plan
+			.version_conflicts
+			.iter()
+			.filter(|conflict| {
+				match conflict.owner_kind {
+					ReleaseOwnerKind::Package => conflict.owner_id == record.id,
+					ReleaseOwnerKind::Group => {
+						record.version_group_id.as_deref() == Some(conflict.owner_id.as_str())
+					}
+				}
+			})
+			.map(|conflict| conflict.message.clone())
+			.collect();
+		let origins = decision
+			.into_iter()
+			.flat_map(|decision| &decision.upstream_sources)
+			.map(|source| self.display_id_for(source))
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.collect();
+
+		ReleaseExplanation {
+			package,
+			group: record.version_group_id.clone(),
+			current_version: record.current_version.as_ref().map(ToString::to_string),
+			origins,
+			version_conflicts,
+			held_back: self.held_back_dependencies(record),
+		}
+	}
+
+	fn display_id_for(&self, package_id: &str) -> String {
+		self.package_by_id.get(package_id).map_or_else(
+			|| package_id.to_string(),
+			|package| package_display_id(package),
+		)
+	}
+
+	fn node(&self, package_id: &str, expanded: &mut BTreeSet<String>) -> ExplanationNode {
+		let decision = self.decision_by_id.get(package_id).copied();
+		let mut node = ExplanationNode {
+			id: self.display_id_for(package_id),
+			package_id: package_id.to_string(),
+			bump: decision.map_or(BumpSeverity::None, |decision| decision.recommended_bump),
+			trigger: decision.map_or_else(
+				|| "none".to_string(),
+				|decision| decision.trigger_type.clone(),
+			),
+			planned_version: decision
+				.and_then(|decision| decision.planned_version.as_ref())
+				.map(ToString::to_string),
+			repeated: false,
+			causes: Vec::new(),
+		};
+		let Some(decision) = decision else {
+			return node;
+		};
+		if !expanded.insert(package_id.to_string()) {
+			node.repeated = true;
+			return node;
+		}
+
+		for signal in self
+			.signals
+			.iter()
+			.filter(|signal| signal.package_id == package_id)
+		{
+			node.causes
+				.push(ExplanationCause::Changeset(ChangesetCause {
+					path: self.relative_path(&signal.source_path),
+					bump: signal.requested_bump,
+					version: signal.explicit_version.as_ref().map(ToString::to_string),
+					summary: signal.notes.clone(),
+					caused_by: signal
+						.caused_by
+						.iter()
+						.map(|reference| self.display_id_for(reference))
+						.collect(),
+				}));
+		}
+
+		for assessment in self
+			.plan
+			.compatibility_evidence
+			.iter()
+			.filter(|assessment| assessment.package_id == package_id)
+		{
+			node.causes
+				.push(ExplanationCause::Compatibility(CompatibilityCause {
+					provider: assessment.provider_id.clone(),
+					severity: assessment.severity,
+					summary: assessment.summary.clone(),
+				}));
+		}
+
+		for cause in &decision.causes {
+			let ReleaseCause::Dependency {
+				package_id: dependency_id,
+				dependency_kind,
+			} = cause
+			else {
+				continue;
+			};
+			if node.causes.iter().any(|cause| {
+				matches!(cause, ExplanationCause::Dependency(dependency) if &dependency.upstream.package_id == dependency_id)
+			}) {
+				continue;
+			}
+			node.causes
+				.push(ExplanationCause::Dependency(DependencyCause {
+					reason: self.dependency_reason(package_id, dependency_id, *dependency_kind),
+					dependency_kind: *dependency_kind,
+					upstream: self.node(dependency_id, expanded),
+				}));
+		}
+
+		if let Some(group_id) = decision.group_id.as_deref()
+			&& let Some(group) = self.group_cause(
+				decision,
+				&ReleaseCause::VersionGroup {
+					group_id: group_id.to_string(),
+				},
+				self.group_members(group_id),
+				expanded,
+			)
+		{
+			node.causes.push(ExplanationCause::Group(group));
+		}
+
+		for (group_id, members) in &self.options.linked_groups {
+			if let Some(group) = self.group_cause(
+				decision,
+				&ReleaseCause::LinkedGroup {
+					group_id: group_id.clone(),
+				},
+				members.iter().map(String::as_str).collect(),
+				expanded,
+			) {
+				node.causes.push(ExplanationCause::LinkedGroup(group));
+			}
+		}
+
+		node
+	}
+
+	/// Explain a bump that `decision` received through `group_cause` by the
+	/// group members that released for reasons of their own.
+	fn group_cause(
+		&self,
+		decision: &ReleaseDecision,
+		group_cause: &ReleaseCause,
+		members: Vec<&str>,
+		expanded: &mut BTreeSet<String>,
+	) -> Option<GroupCause> {
+		let group = match group_cause {
+			ReleaseCause::VersionGroup { group_id } | ReleaseCause::LinkedGroup { group_id } => {
+				group_id.clone()
+			}
+			ReleaseCause::Dependency { .. } => return None,
+		};
+		if !decision.causes.contains(group_cause) {
+			return None;
+		}
+		let drivers = members
+			.into_iter()
+			.filter(|member| {
+				!expanded.contains(*member) && self.drives_release(member, group_cause)
+			})
+			.collect::<Vec<_>>();
+		let members = drivers
+			.into_iter()
+			.map(|member| self.node(member, expanded))
+			.collect::<Vec<_>>();
+		(!members.is_empty()).then_some(GroupCause { group, members })
+	}
+
+	fn dependencies_of<'b>(
+		&'b self,
+		package_id: &'b str,
+	) -> impl Iterator<Item = &'b DependencyEdge> {
+		self.discovery
+			.dependencies
+			.iter()
+			.filter(move |edge| edge.from_package_id == package_id)
+	}
+
+	fn group_members(&self, group_id: &str) -> Vec<&str> {
+		self.discovery
+			.version_groups
+			.iter()
+			.filter(|group| group.group_id == group_id)
+			.flat_map(|group| group.members.iter().map(String::as_str))
+			.collect()
+	}
+
+	/// Returns `true` when a group member released for a reason of its own
+	/// rather than only following the rest of its group, `group_cause`.
+	fn drives_release(&self, package_id: &str, group_cause: &ReleaseCause) -> bool {
+		self.decision_by_id.get(package_id).is_some_and(|decision| {
+			if !decision.recommended_bump.is_release() {
+				return false;
+			}
+			match group_cause {
+				// Linked groups only raise the members below the strongest
+				// bump, so the members that set it never record the cause.
+				ReleaseCause::LinkedGroup { .. } => !decision.causes.contains(group_cause),
+				_ => {
+					decision.trigger_type == "direct-change"
+						|| decision.causes.iter().any(|cause| cause != group_cause)
+				}
+			}
+		})
+	}
+
+	/// Describe a dependency cause the way the planner words it, using the
+	/// configured package ids.
+	fn dependency_reason(
+		&self,
+		package_id: &str,
+		dependency_id: &str,
+		dependency_kind: DependencyKind,
+	) -> String {
+		let rule = self
+			.options
+			.propagation_by_kind
+			.get(package_id)
+			.copied()
+			.unwrap_or_default()
+			.rule_for(dependency_kind);
+		let dependency = self.display_id_for(dependency_id);
+		match rule {
+			DependencyKindRule::ParentBump => format!("depends on `{dependency}`"),
+			_ => {
+				format!(
+					"depends on `{dependency}` as a {dependency_kind} dependency (`{rule}` propagation)"
+				)
+			}
+		}
+	}
+
+	fn held_back_dependencies(&self, record: &PackageRecord) -> Vec<HeldBackDependency> {
+		let causes = self
+			.decision_by_id
+			.get(record.id.as_str())
+			.map(|decision| decision.causes.as_slice())
+			.unwrap_or_default();
+		let mut edges_by_dependency = BTreeMap::<&str, Vec<&DependencyEdge>>::new();
+		for edge in self.dependencies_of(&record.id) {
+			edges_by_dependency
+				.entry(edge.to_package_id.as_str())
+				.or_default()
+				.push(edge);
+		}
+
+		edges_by_dependency
+			.into_iter()
+			.filter_map(|(dependency_id, edges)| {
+				let dependency = self.decision_by_id.get(dependency_id)?;
+				if !dependency.recommended_bump.is_release()
+					|| causes.iter().any(|cause| {
+						matches!(cause, ReleaseCause::Dependency { package_id, .. } if package_id == dependency_id)
+					})
+				{
+					return None;
+				}
+				Some(HeldBackDependency {
+					id: self.display_id_for(dependency_id),
+					bump: dependency.recommended_bump,
+					reason: self.held_back_reason(record, dependency, &edges),
+				})
+			})
+			.collect()
+	}
+
+	fn held_back_reason(
+		&self,
+		record: &PackageRecord,
+		dependency: &ReleaseDecision,
+		edges: &[&DependencyEdge],
+	) -> String {
+		let suppressing = self
+			.signals
+			.iter()
+			.filter(|signal| signal.package_id == record.id)
+			.filter(|signal| {
+				self.resolve_caused_by(&signal.caused_by)
+					.iter()
+					.any(|source| dependency.upstream_sources.contains(source))
+			})
+			.map(|signal| format!("`{}`", self.relative_path(&signal.source_path)))
+			.collect::<Vec<_>>();
+		if !suppressing.is_empty() {
+			return format!("suppressed by `caused_by` in {}", suppressing.join(", "));
+		}
+
+		if self.options.propagation == DependencyPropagation::OutOfRange
+			&& let Some(version) = dependency.planned_version.as_ref()
+		{
+			let constraints = edges
+				.iter()
+				.map(|edge| {
+					edge.version_constraint.as_deref().filter(|constraint| {
+						constraint_allows(record.ecosystem, constraint, version) == Some(true)
+					})
+				})
+				.collect::<Option<Vec<_>>>();
+			if let Some(constraints) = constraints {
+				let constraints = constraints
+					.into_iter()
+					.map(|constraint| format!("`{constraint}`"))
+					.collect::<Vec<_>>()
+					.join(", ");
+				return format!("declared range {constraints} still admits `{version}`");
+			}
+		}
+
+		let rules = self
+			.options
+			.propagation_by_kind
+			.get(&record.id)
+			.copied()
+			.unwrap_or_default();
+		let kinds = edges
+			.iter()
+			.map(|edge| (edge.dependency_kind, rules.rule_for(edge.dependency_kind)))
+			.collect::<BTreeMap<_, _>>();
+		if kinds.values().all(|rule| {
+			!rule
+				.dependent_bump(BumpSeverity::Patch, dependency.recommended_bump)
+				.is_release()
+		}) {
+			let kinds = kinds
+				.into_iter()
+				.map(|(kind, rule)| format!("`{rule}` propagation for {kind} dependencies"))
+				.collect::<Vec<_>>()
+				.join(", ");
+			return format!("not propagated: {kinds}");
+		}
+
+		"not propagated".to_string()
+	}
+
+	/// Mirror the planner's resolution of `caused_by` references: package ids
+	/// stand for themselves and group ids expand to their members.
+	fn resolve_caused_by(&self, references: &[String]) -> BTreeSet<String> {
+		references
+			.iter()
+			.flat_map(|reference| {
+				if self.package_by_id.contains_key(reference.as_str()) {
+					vec![reference.clone()]
+				} else {
+					self.group_members(reference)
+						.into_iter()
+						.map(ToString::to_string)
+						.collect()
+				}
+			})
+			.collect()
+	}
+
+	fn relative_path(&self, path: &Path) -> String {
+		relative_to_root(self.root, path)
+			.unwrap_or_else(|| path.to_path_buf())
+			.display()
+			.to_string()
+	}
+}
+
+pub(crate) fn render_release_explanation(
+	explanation: &ReleaseExplanation,
+	format: OutputFormat,
+) -> String {
+	match format {
+		OutputFormat::Json => {
+			serde_json::to_string_pretty(explanation).unwrap_or_else(|error| {
+				panic!("serializing release explanations should succeed: {error}")
+			})
+		}
+		OutputFormat::Text => render_text(explanation),
+		OutputFormat::Markdown => render_markdown(explanation),
+	}
+}
+
+fn node_label(node: &ExplanationNode, markdown: bool) -> String {
+	let (strong, code) = if markdown { ("**", "`") } else { ("", "") };
+	let mut label = format!("{strong}{}{strong}: {code}{}{code}", node.id, node.bump);
+	if let Some(version) = &node.planned_version {
+		let _ = write!(label, " -> {code}{version}{code}");
+	}
+	let _ = write!(label, " ({})", node.trigger);
+	if node.repeated {
+		label.push_str(" (explained above)");
+	}
+	label
+}
+
+fn changeset_label(changeset: &ChangesetCause, markdown: bool) -> String {
+	let code = if markdown { "`" } else { "" };
+	let mut label = format!("changeset {code}{}{code}", changeset.path);
+	if let Some(bump) = changeset.bump {
+		let _ = write!(label, " requests {code}{bump}{code}");
+	}
+	if let Some(version) = &changeset.version {
+		let _ = write!(label, " and pins version {code}{version}{code}");
+	}
+	if let Some(summary) = &changeset.summary {
+		let _ = write!(label, ": {summary}");
+	}
+	if !changeset.caused_by.is_empty() {
+		let _ = write!(label, " (caused by {})", changeset.caused_by.join(", "));
+	}
+	label
+}
+
+fn write_tree(output: &mut String, node: &ExplanationNode, depth: usize, markdown: bool) {
+	let bullet = if markdown { "- " } else { "" };
+	let indent = "  ".repeat(depth);
+	let _ = writeln!(output, "{indent}{bullet}{}", node_label(node, markdown));
+	let indent = "  ".repeat(depth + 1);
+	for cause in &node.causes {
+		match cause {
+			ExplanationCause::Changeset(changeset) => {
+				let _ = writeln!(
+					output,
+					"{indent}{bullet}{}",
+					changeset_label(changeset, markdown)
+				);
+			}
+			ExplanationCause::Compatibility(compatibility) => {
+				let _ = writeln!(
+					output,
+					"{indent}{bullet}{} reports {}: {}",
+					compatibility.provider, compatibility.severity, compatibility.summary
+				);
+			}
+			ExplanationCause::Dependency(dependency) => {
+				let _ = writeln!(output, "{indent}{bullet}{}", dependency.reason);
+				write_tree(output, &dependency.upstream, depth + 2, markdown);
+			}
+			ExplanationCause::Group(group) => {
+				let _ = writeln!(
+					output,
+					"{indent}{bullet}shares version group `{}` with",
+					group.group
+				);
+				for member in &group.members {
+					write_tree(output, member, depth + 2, markdown);
+				}
+			}
+			ExplanationCause::LinkedGroup(group) => {
+				let _ = writeln!(
+					output,
+					"{indent}{bullet}shares linked group `{}` with",
+					group.group
+				);
+				for member in &group.members {
+					write_tree(output, member, depth + 2, markdown);
+				}
+			}
+		}
+	}
+}
+
+fn render_text(explanation: &ReleaseExplanation) -> String {
+	let mut output = String::new();
+	let package = &explanation.package;
+	let _ = writeln!(output, "{} ({})", package.id, package.package_id);
+	if let Some(version) = &explanation.current_version {
+		let _ = writeln!(output, "current version: {version}");
+	}
+	if let Some(group) = &explanation.group {
+		let _ = writeln!(output, "group: {group}");
+	}
+	if package.bump.is_release() {
+		output.push_str("\nCausal chain:\n");
+		write_tree(&mut output, package, 1, false);
+		if !explanation.origins.is_empty() {
+			let _ = writeln!(output, "\norigins: {}", explanation.origins.join(", "));
+		}
+	} else {
+		output.push_str("\nNo release is planned for this package.\n");
+	}
+	write_text_section(
+		&mut output,
+		"Version conflicts",
+		&explanation.version_conflicts,
+	);
+	let held_back = explanation
+		.held_back
+		.iter()
+		.map(|dependency| {
+			format!(
+				"{} ({}): {}",
+				dependency.id, dependency.bump, dependency.reason
+			)
+		})
+		.collect::<Vec<_>>();
+	write_text_section(&mut output, "Held back dependencies", &held_back);
+	output
+}
+
+fn write_text_section(output: &mut String, title: &str, lines: &[String]) {
+	if lines.is_empty() {
+		return;
+	}
+	let _ = writeln!(output, "\n{title}:");
+	for line in lines {
+		let _ = writeln!(output, "- {line}");
+	}
+}
+
+fn render_markdown(explanation: &ReleaseExplanation) -> String {
+	let mut output = String::new();
+	let package = &explanation.package;
+	let _ = writeln!(output, "# Release explanation for `{}`\n", package.id);
+	let _ = writeln!(output, "- package: `{}`", package.package_id);
+	if let Some(version) = &explanation.current_version {
+		let _ = writeln!(output, "- current version: `{version}`");
+	}
+	if let Some(group) = &explanation.group {
+		let _ = writeln!(output, "- group: `{group}`");
+	}
+	if !explanation.origins.is_empty() {
+		let origins = explanation
+			.origins
+			.iter()
+			.map(|origin| format!("`{origin}`"))
+			.collect::<Vec<_>>()
+			.join(", ");
+		let _ = writeln!(output, "- origins: {origins}");
+	}
+	output.push_str("\n## Causal chain\n\n");
+	if package.bump.is_release() {
+		write_tree(&mut output, package, 0, true);
+	} else {
+		output.push_str("No release is planned for this package.\n");
+	}
+	if !explanation.version_conflicts.is_empty() {
+		output.push_str("\n## Version conflicts\n\n");
+		for conflict in &explanation.version_conflicts {
+			let _ = writeln!(output, "- {conflict}");
+		}
+	}
+	if !explanation.held_back.is_empty() {
+		output.push_str("\n## Held back dependencies\n\n");
+		for dependency in &explanation.held_back {
+			let _ = writeln!(
+				output,
+				"- `{}` (`{}`): {}",
+				dependency.id, dependency.bump, dependency.reason
+			);
+		}
+	}
+	output
+}
+
+#[cfg(test)]
+#[path = "__tests__/release_explain_tests.rs"]
+mod tests;
@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::ReleasePlan;
+use semver::Prerelease;
+use semver::Version;
+use serde::Deserialize;
+use serde::Serialize;
+use tempfile::TempDir;
+
+use crate::FileUpdate;
+use crate::render_jinja_template;
+
+/// Suffix template used when `snapshot_template` is not provided.
+pub(crate) const DEFAULT_SNAPSHOT_TEMPLATE: &str = "{{ tag }}.{{ date }}.{{ commit }}";
+
+/// Directories that are never copied into a snapshot working tree.
+const SNAPSHOT_SKIPPED_DIRECTORIES: &[&str] = &[".git", ".monochange", "node_modules", "target"];
+
+/// Snapshot inputs supplied to a single `PrepareRelease` run.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct SnapshotRequest {
+	/// Tag used both in the version suffix and as the registry dist-tag, such as `canary`.
+	pub tag: String,
+	/// Minijinja template rendered into the prerelease suffix.
+	pub template: String,
+}
+
+impl SnapshotRequest {
+	/// Read the `snapshot` and `snapshot_template` step inputs.
+	pub(crate) fn from_step_inputs(
+		inputs: &BTreeMap<String, Vec<String>>,
+	) -> MonochangeResult<Option<Self>> {
+		let Some(tag) = inputs
+			.get("snapshot")
+			.and_then(|values| values.first())
+			.map(|value| value.trim().to_string())
+			.filter(|value| !value.is_empty())
+		else {
+			return Ok(None);
+		};
+		validate_snapshot_tag(&tag)?;
+		let template = inputs
+			.get("snapshot_template")
+			.and_then(|values| values.first())
+			.map(|value| value.trim().to_string())
+			.filter(|value| !value.is_empty())
+			.unwrap_or_else(|| DEFAULT_SNAPSHOT_TEMPLATE.to_string());
+
+		Ok(Some(Self { tag, template }))
+	}
+
+	/// Render the prerelease suffix for this snapshot.
+	///
+	/// The template receives `tag`, `date` (`YYYYMMDD`), `timestamp`
+	/// (`YYYYMMDDHHMMSS`), and `commit` (the short `HEAD` sha).
+	pub(crate) fn render_suffix(
+		&self,
+		datetime: chrono::NaiveDateTime,
+		commit: Option<&str>,
+	) -> MonochangeResult<Prerelease> {
+		let commit = commit.map(snapshot_commit_identifier).unwrap_or_default();
+		let context = minijinja::context! {
+			tag => &self.tag,
+			date => datetime.format("%Y%m%d").to_string(),
+			timestamp => datetime.format("%Y%m%d%H%M%S").to_string(),
+			commit => commit,
+		};
+		let rendered = render_jinja_template(&self.template, &context)?;
+		let rendered = rendered.trim();
+		Prerelease::new(rendered)
+			.ok()
+			.filter(|suffix| !suffix.is_empty())
+			.ok_or_else(|| {
+				MonochangeError::Config(format!(
+					"snapshot suffix `{rendered}` rendered from `{}` is not a valid semver prerelease",
+					self.template
+				))
+			})
+	}
+}
+
+/// Snapshot details carried by a prepared release.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreparedSnapshot {
+	/// Registry dist-tag the snapshot is published under.
+	pub tag: String,
+	/// Rendered prerelease suffix shared by every snapshot version.
+	pub suffix: String,
+	/// Temporary copy of the workspace holding the rewritten manifests. `None`
+	/// during dry runs. Not serialized: the directory only lives as long as
+	/// `working_tree_guard`, so a deserialized path would point nowhere.
+	#[serde(skip)]
+	pub working_tree: Option<PathBuf>,
+	/// Keeps `working_tree` on disk until the snapshot is published.
+	#[serde(skip)]
+	pub(crate) working_tree_guard: Option<SnapshotWorkingTree>,
+}
+
+/// Temporary snapshot working tree, removed when the last clone is dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct SnapshotWorkingTree(Arc<TempDir>);
+
+impl SnapshotWorkingTree {
+	pub(crate) fn path(&self) -> &Path {
+		self.0.path()
+	}
+}
+
+impl PartialEq for SnapshotWorkingTree {
+	fn eq(&self, other: &Self) -> bool {
+		self.path() == other.path()
+	}
+}
+
+impl Eq for SnapshotWorkingTree {}
+
+fn validate_snapshot_tag(tag: &str) -> MonochangeResult<()> {
+	let valid = tag
+		.chars()
+		.all(|character| character.is_ascii_alphanumeric() || character == '-')
+		&& !tag.chars().all(|character| character.is_ascii_digit());
+	if valid && tag != "latest" {
+		return Ok(());
+	}
+	Err(MonochangeError::Config(format!(
+		"invalid snapshot tag `{tag}`; use letters, digits, and `-` only and avoid `latest`"
+	)))
+}
+
+/// Semver rejects numeric identifiers with leading zeros, so short shas like
+/// `0123456` get the `g` prefix that `git describe` uses.
+fn snapshot_commit_identifier(commit: &str) -> String {
+	let short = commit.chars().take(7).collect::<String>();
+	if short.starts_with('0') && short.chars().all(|character| character.is_ascii_digit()) {
+		return format!("g{short}");
+	}
+	short
+}
+
+/// Replace every planned version with `<major>.<minor>.<patch>-<suffix>`.
+pub(crate) fn apply_snapshot_versions(plan: &mut ReleasePlan, suffix: &Prerelease) {
+	let snapshot_version = |version: &Version| {
+		let mut snapshot = Version::new(version.major, version.minor, version.patch);
+		snapshot.pre = suffix.clone();
+		snapshot
+	};
+	for decision in &mut plan.decisions {
+		decision.planned_version = decision.planned_version.as_ref().map(snapshot_version);
+	}
+	for group in &mut plan.groups {
+		group.planned_version = group.planned_version.as_ref().map(snapshot_version);
+	}
+}
+
+/// Copy the workspace into a fresh temporary directory that later steps can
+/// publish from. The directory is removed once the returned guard is dropped.
+pub(crate) fn create_snapshot_working_tree(root: &Path) -> MonochangeResult<SnapshotWorkingTree> {
+	let working_tree = tempfile::Builder::new()
+		.prefix("monochange-snapshot-")
+		.tempdir()
+		.map_err(|error| {
+			MonochangeError::Io(format!("failed to create snapshot working tree: {error}"))
+		})?;
+	copy_workspace_entries(root, working_tree.path())?;
+	Ok(SnapshotWorkingTree(Arc::new(working_tree)))
+}
+
+fn copy_workspace_entries(source: &Path, destination: &Path) -> MonochangeResult<()> {
+	let entries = fs::read_dir(source).map_err(|error| {
+		MonochangeError::Io(format!("failed to read {}: {error}", source.display()))
+	})?;
+	for entry in entries {
+		let entry = entry
+			.map_err(|error| MonochangeError::Io(format!("directory entry error: {error}")))?;
+		let path = entry.path();
+		let target = destination.join(entry.file_name());
+		let file_type = entry.file_type().map_err(|error| {
+			MonochangeError::Io(format!("failed to inspect {}: {error}", path.display()))
+		})?;
+		if file_type.is_dir() {
+			if SNAPSHOT_SKIPPED_DIRECTORIES
+				.iter()
+				.any(|skipped| entry.file_name() == *skipped)
+			{
+				continue;
+			}
+			fs::create_dir_all(&target).map_err(|error| {
+				MonochangeError::Io(format!("failed to create {}: {error}", target.display()))
+			})?;
+			copy_workspace_entries(&path, &target)?;
+		} else if file_type.is_file() {
+			fs::copy(&path, &target).map_err(|error| {
+				MonochangeError::Io(format!(
+					"failed to copy {} to {}: {error}",
+					path.display(),
+					target.display()
+				))
+			})?;
+		}
+	}
+	Ok(())
+}
+
+/// Point file updates computed against `root` at the same files inside `working_tree`.
+pub(crate) fn rebase_file_updates(
+	root: &Path,
+	working_tree: &Path,
+	updates: &[FileUpdate],
+) -> Vec<FileUpdate> {
+	updates
+		.iter()
+		.map(|update| {
+			FileUpdate {
+				path: working_tree.join(update.path.strip_prefix(root).unwrap_or(&update.path)),
+				content: update.content.clone(),
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+#[path = "__tests__/snapshot_tests.rs"]
+mod tests;
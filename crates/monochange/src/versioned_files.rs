@@ -52,6 +52,12 @@ pub(crate) enum VersionedFileKind {
 	Python(monochange_python::PythonVersionedFileKind),
 	#[cfg(feature = "go")]
 	Go(monochange_go::GoVersionedFileKind),
+	#[cfg(feature = "helm")]
+	Helm(monochange_helm::HelmVersionedFileKind),
+	#[cfg(feature = "ruby")]
+	Ruby(monochange_ruby::RubyVersionedFileKind),
+	#[cfg(feature = "jvm")]
+	Jvm(monochange_jvm::JvmVersionedFileKind),
 }
 
 pub(crate) fn versioned_file_kind(
@@ -83,6 +89,18 @@ pub(crate) fn versioned_file_kind(
 		monochange_core::EcosystemType::Go => {
 			monochange_go::supported_versioned_file_kind(path).map(VersionedFileKind::Go)
 		}
+		#[cfg(feature = "helm")]
+		monochange_core::EcosystemType::Helm => {
+			monochange_helm::supported_versioned_file_kind(path).map(VersionedFileKind::Helm)
+		}
+		#[cfg(feature = "ruby")]
+		monochange_core::EcosystemType::Ruby => {
+			monochange_ruby::supported_versioned_file_kind(path).map(VersionedFileKind::Ruby)
+		}
+		#[cfg(feature = "jvm")]
+		monochange_core::EcosystemType::Jvm => {
+			monochange_jvm::supported_versioned_file_kind(path).map(VersionedFileKind::Jvm)
+		}
 		_ => None,
 	}
 }
@@ -461,6 +479,9 @@ pub(crate) fn read_cached_document(
 				monochange_core::EcosystemType::Dart => "dart",
 				monochange_core::EcosystemType::Python => "python",
 				monochange_core::EcosystemType::Go => "go",
+				monochange_core::EcosystemType::Helm => "helm",
+				monochange_core::EcosystemType::Ruby => "ruby",
+				monochange_core::EcosystemType::Jvm => "jvm",
 				_ => "unknown",
 			},
 		)));
@@ -620,6 +641,41 @@ pub(crate) fn read_cached_document(
 			};
 			Ok(CachedDocument::Text(contents))
 		}
+		#[cfg(feature = "helm")]
+		VersionedFileKind::Helm(_) => {
+			let Some(contents) = text_contents else {
+				return Err(MonochangeError::Config(format!(
+					"failed to parse {} as text",
+					path.display()
+				)));
+			};
+			monochange_helm::update_chart_text(&contents, None, None, &BTreeMap::new()).map_err(
+				|error| {
+					MonochangeError::Config(format!("failed to parse {}: {error}", path.display()))
+				},
+			)?;
+			Ok(CachedDocument::Text(contents))
+		}
+		#[cfg(feature = "ruby")]
+		VersionedFileKind::Ruby(_) => {
+			let Some(contents) = text_contents else {
+				return Err(MonochangeError::Config(format!(
+					"failed to parse {} as text",
+					path.display()
+				)));
+			};
+			Ok(CachedDocument::Text(contents))
+		}
+		#[cfg(feature = "jvm")]
+		VersionedFileKind::Jvm(_) => {
+			let Some(contents) = text_contents else {
+				return Err(MonochangeError::Config(format!(
+					"failed to parse {} as text",
+					path.display()
+				)));
+			};
+			Ok(CachedDocument::Text(contents))
+		}
 		#[cfg(feature = "dart")]
 		VersionedFileKind::Dart(monochange_dart::DartVersionedFileKind::Manifest) => {
 			let Some(contents) = text_contents else {
@@ -702,6 +758,15 @@ pub(crate) fn resolve_versioned_prefix(
 		monochange_core::EcosystemType::Go => {
 			context.configuration.go.dependency_version_prefix.clone()
 		}
+		monochange_core::EcosystemType::Helm => {
+			context.configuration.helm.dependency_version_prefix.clone()
+		}
+		monochange_core::EcosystemType::Ruby => {
+			context.configuration.ruby.dependency_version_prefix.clone()
+		}
+		monochange_core::EcosystemType::Jvm => {
+			context.configuration.jvm.dependency_version_prefix.clone()
+		}
 		_ => None,
 	};
 	ecosystem_prefix.unwrap_or_else(|| {
@@ -724,6 +789,16 @@ pub(crate) fn resolve_versioned_prefix(
 			monochange_core::EcosystemType::Go => {
 				monochange_go::default_dependency_version_prefix().to_string()
 			}
+			monochange_core::EcosystemType::Helm => {
+				monochange_helm::default_dependency_version_prefix().to_string()
+			}
+			monochange_core::EcosystemType::Ruby => {
+				monochange_ruby::default_dependency_version_prefix().to_string()
+			}
+			#[cfg(feature = "jvm")]
+			monochange_core::EcosystemType::Jvm => {
+				monochange_jvm::default_dependency_version_prefix().to_string()
+			}
 			_ => String::new(),
 		}
 	})
@@ -755,6 +830,14 @@ pub(crate) fn expand_versioned_file_fields<N: AsRef<str>>(
 					monochange_python::default_dependency_fields()
 				}
 				monochange_core::EcosystemType::Go => monochange_go::default_dependency_fields(),
+				monochange_core::EcosystemType::Helm => {
+					monochange_helm::default_dependency_fields()
+				}
+				monochange_core::EcosystemType::Ruby => {
+					monochange_ruby::default_dependency_fields()
+				}
+				#[cfg(feature = "jvm")]
+				monochange_core::EcosystemType::Jvm => monochange_jvm::default_dependency_fields(),
 				_ => &[],
 			};
 			default_fields
@@ -905,6 +988,9 @@ pub(crate) fn apply_versioned_file_definition(
 					monochange_core::EcosystemType::Dart => "dart",
 					monochange_core::EcosystemType::Python => "python",
 					monochange_core::EcosystemType::Go => "go",
+					monochange_core::EcosystemType::Helm => "helm",
+					monochange_core::EcosystemType::Ruby => "ruby",
+					monochange_core::EcosystemType::Jvm => "jvm",
 					_ => "unknown",
 				},
 			)));
@@ -957,13 +1043,14 @@ pub(crate) fn apply_versioned_file_definition(
 			#[cfg(feature = "npm")]
 			(CachedDocument::Text(contents), VersionedFileKind::Npm(kind)) => {
 				if kind == monochange_npm::NpmVersionedFileKind::Manifest {
-					*contents = monochange_core::update_json_manifest_text(
+					*contents = monochange_npm::update_manifest_text(
 						contents,
 						shared_release_version
 							.map(String::as_str)
 							.or(Some(owner_version)),
 						&fields,
 						&versioned_deps,
+						&raw_versions,
 					)
 					.map_err(|error| {
 						MonochangeError::Config(format!(
@@ -1069,6 +1156,72 @@ pub(crate) fn apply_versioned_file_definition(
 			) => {
 				*contents = monochange_go::update_go_mod_text(contents, &versioned_deps);
 			}
+			#[cfg(feature = "helm")]
+			(CachedDocument::Text(contents), VersionedFileKind::Helm(_)) => {
+				*contents = update_helm_chart_versioned_file(
+					contents,
+					&resolved_path,
+					&fields,
+					shared_release_version.map_or(owner_version, String::as_str),
+					context,
+				)
+				.map_err(|error| {
+					MonochangeError::Config(format!(
+						"failed to parse {}: {error}",
+						resolved_path.display()
+					))
+				})?;
+			}
+			#[cfg(feature = "ruby")]
+			(
+				CachedDocument::Text(contents),
+				VersionedFileKind::Ruby(monochange_ruby::RubyVersionedFileKind::VersionFile),
+			) => {
+				let version = shared_release_version.map_or(owner_version, String::as_str);
+				let default_constants = [monochange_ruby::VERSION_CONSTANT.to_string()];
+				for constant in definition.fields.as_deref().unwrap_or(&default_constants) {
+					*contents =
+						monochange_ruby::update_version_constant_text(contents, constant, version);
+				}
+			}
+			#[cfg(feature = "ruby")]
+			(
+				CachedDocument::Text(contents),
+				VersionedFileKind::Ruby(monochange_ruby::RubyVersionedFileKind::Gemspec),
+			) => {
+				*contents = monochange_ruby::update_gemspec_text(
+					contents,
+					Some(owner_version),
+					&raw_versions,
+				);
+			}
+			#[cfg(feature = "jvm")]
+			(
+				CachedDocument::Text(contents),
+				VersionedFileKind::Jvm(monochange_jvm::JvmVersionedFileKind::Pom),
+			) => {
+				let version = shared_release_version.map_or(owner_version, String::as_str);
+				*contents = monochange_jvm::update_pom_text(contents, Some(version), &raw_versions);
+			}
+			#[cfg(feature = "jvm")]
+			(
+				CachedDocument::Text(contents),
+				VersionedFileKind::Jvm(monochange_jvm::JvmVersionedFileKind::GradleProperties),
+			) => {
+				let version = shared_release_version.map_or(owner_version, String::as_str);
+				let default_keys = [monochange_jvm::VERSION_PROPERTY.to_string()];
+				for key in definition.fields.as_deref().unwrap_or(&default_keys) {
+					*contents =
+						monochange_jvm::update_gradle_properties_text(contents, key, version);
+				}
+			}
+			#[cfg(feature = "jvm")]
+			(
+				CachedDocument::Text(contents),
+				VersionedFileKind::Jvm(monochange_jvm::JvmVersionedFileKind::GradleBuild),
+			) => {
+				*contents = monochange_jvm::update_gradle_build_text(contents, &raw_versions);
+			}
 			#[cfg(feature = "python")]
 			(CachedDocument::Text(contents), VersionedFileKind::Python(kind)) => {
 				*contents = monochange_python::update_versioned_file_text(
@@ -1091,6 +1244,55 @@ pub(crate) fn apply_versioned_file_definition(
 	Ok(())
 }
 
+/// Rewrite a `Chart.yaml` matched by a `type = "helm"` versioned file.
+///
+/// A definition whose `fields` include `appVersion` syncs the chart's
+/// `appVersion` to the owning package; otherwise the owner version becomes
+/// the chart `version`. Either way the chart's own release and its `file://`
+/// dependency constraints are reapplied, because this rewrite replaces the
+/// manifest update planned for the same file.
+#[cfg(feature = "helm")]
+fn update_helm_chart_versioned_file(
+	contents: &str,
+	path: &Path,
+	fields: &[&str],
+	owner_version: &str,
+	context: &VersionedFileUpdateContext<'_>,
+) -> MonochangeResult<String> {
+	let charts = context
+		.package_by_native_name
+		.values()
+		.copied()
+		.filter(|package| package.ecosystem == Ecosystem::Helm)
+		.collect::<Vec<_>>();
+	let released_chart_versions = charts
+		.iter()
+		.filter_map(|chart| {
+			context
+				.released_versions_by_native_name
+				.get(&chart.name)
+				.map(|version| (chart.name.clone(), version.clone()))
+		})
+		.collect::<BTreeMap<_, _>>();
+	let syncs_app_version = fields.contains(&monochange_helm::APP_VERSION_FIELD);
+	let chart_version = if syncs_app_version {
+		let path = monochange_core::normalize_path(path);
+		charts
+			.iter()
+			.find(|chart| chart.manifest_path == path)
+			.and_then(|chart| released_chart_versions.get(&chart.name))
+			.map(String::as_str)
+	} else {
+		Some(owner_version)
+	};
+	monochange_helm::update_chart_text(
+		contents,
+		chart_version,
+		syncs_app_version.then_some(owner_version),
+		&released_chart_versions,
+	)
+}
+
 pub(crate) fn released_versions_by_record_id(plan: &ReleasePlan) -> BTreeMap<String, String> {
 	plan.decisions
 		.iter()
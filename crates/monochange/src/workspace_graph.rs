@@ -0,0 +1,544 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use monochange_config::load_workspace_configuration;
+use monochange_core::BumpSeverity;
+use monochange_core::DependencyKind;
+use monochange_core::DiscoveryReport;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::WorkspaceConfiguration;
+use monochange_graph::publish_levels;
+use serde::Serialize;
+
+use crate::discover_release_workspace;
+use crate::find_package_by_reference;
+use crate::package_display_id;
+use crate::plan_pending_release;
+
+/// Output format for `mc graph`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub(crate) enum GraphFormat {
+	#[default]
+	Dot,
+	Mermaid,
+	Json,
+}
+
+impl GraphFormat {
+	pub(crate) fn parse(value: &str) -> MonochangeResult<Self> {
+		match value {
+			"dot" => Ok(Self::Dot),
+			"mermaid" => Ok(Self::Mermaid),
+			"json" => Ok(Self::Json),
+			other => {
+				Err(MonochangeError::Config(format!(
+					"unsupported graph format `{other}`"
+				)))
+			}
+		}
+	}
+}
+
+/// Which part of the workspace graph to export and what to overlay on it.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct GraphRequest {
+	pub format: GraphFormat,
+	/// Keep only packages from these ecosystems.
+	pub ecosystems: Vec<String>,
+	/// Keep only members of these version groups.
+	pub groups: Vec<String>,
+	/// Keep only these packages plus everything they depend on or that
+	/// depends on them.
+	pub packages: Vec<String>,
+	/// Overlay the bumps planned from the pending changesets.
+	pub plan: bool,
+	/// Annotate every package with the level at which it can be published.
+	pub publish_levels: bool,
+}
+
+/// The filtered workspace dependency graph.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WorkspaceGraph {
+	pub nodes: Vec<GraphNode>,
+	pub edges: Vec<GraphEdge>,
+	pub groups: Vec<GraphGroup>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphNode {
+	/// Configured package id, or the package name when none is configured.
+	pub id: String,
+	/// Package record id used by discovery and the release planner.
+	pub package_id: String,
+	pub name: String,
+	pub ecosystem: String,
+	pub version: Option<String>,
+	pub group: Option<String>,
+	/// Publish order level; packages on the same level can be published
+	/// together once every lower level is out.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub publish_level: Option<usize>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub bump: Option<BumpSeverity>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub planned_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphEdge {
+	/// Id of the dependent package.
+	pub from: String,
+	/// Id of the package being depended on.
+	pub to: String,
+	pub kind: DependencyKind,
+	pub constraint: Option<String>,
+	pub optional: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphGroup {
+	pub id: String,
+	/// Ids of the group members that survived filtering.
+	pub members: Vec<String>,
+}
+
+/// Discover the workspace at `root` and build the graph described by
+/// `request`.
+pub(crate) fn build_workspace_graph(
+	root: &Path,
+	request: &GraphRequest,
+) -> MonochangeResult<WorkspaceGraph> {
+	let configuration = load_workspace_configuration(root)?;
+	let discovery = discover_release_workspace(root, &configuration)?;
+	let kept = filter_packages(&configuration, &discovery, request)?;
+
+	let levels = if request.publish_levels {
+		publish_levels(&discovery.packages, &discovery.dependencies)
+	} else {
+		BTreeMap::new()
+	};
+	let decisions = if request.plan {
+		plan_pending_release(root, &configuration, &discovery)?
+			.plan
+			.decisions
+			.into_iter()
+			.filter(|decision| decision.recommended_bump.is_release())
+			.map(|decision| (decision.package_id.clone(), decision))
+			.collect::<BTreeMap<_, _>>()
+	} else {
+		BTreeMap::new()
+	};
+
+	let display_ids = discovery
+		.packages
+		.iter()
+		.map(|package| (package.id.as_str(), package_display_id(package)))
+		.collect::<BTreeMap<_, _>>();
+
+	let nodes = discovery
+		.packages
+		.iter()
+		.filter(|package| kept.contains(package.id.as_str()))
+		.map(|package| {
+			let decision = decisions.get(&package.id);
+			GraphNode {
+				id: package_display_id(package),
+				package_id: package.id.clone(),
+				name: package.name.clone(),
+				ecosystem: package.ecosystem.as_str().to_string(),
+				version: package.current_version.as_ref().map(ToString::to_string),
+				group: package.version_group_id.clone(),
+				publish_level: levels.get(&package.id).copied(),
+				bump: decision.map(|decision| decision.recommended_bump),
+				planned_version: decision
+					.and_then(|decision| decision.planned_version.as_ref())
+					.map(ToString::to_string),
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let mut edges = discovery
+		.dependencies
+		.iter()
+		.filter(|edge| {
+			kept.contains(edge.from_package_id.as_str())
+				&& kept.contains(edge.to_package_id.as_str())
+		})
+		.filter_map(|edge| {
+			Some(GraphEdge {
+				from: display_ids.get(edge.from_package_id.as_str())?.clone(),
+				to: display_ids.get(edge.to_package_id.as_str())?.clone(),
+				kind: edge.dependency_kind,
+				constraint: edge.version_constraint.clone(),
+				optional: edge.is_optional,
+			})
+		})
+		.collect::<Vec<_>>();
+	edges.sort_by(|left, right| {
+		(&left.from, &left.to, left.kind).cmp(&(&right.from, &right.to, right.kind))
+	});
+	edges.dedup();
+
+	let groups = discovery
+		.version_groups
+		.iter()
+		.filter_map(|group| {
+			let members = nodes
+				.iter()
+				.filter(|node| node.group.as_deref() == Some(group.group_id.as_str()))
+				.map(|node| node.id.clone())
+				.collect::<Vec<_>>();
+			(!members.is_empty()).then(|| {
+				GraphGroup {
+					id: group.group_id.clone(),
+					members,
+				}
+			})
+		})
+		.collect();
+
+	Ok(WorkspaceGraph {
+		nodes,
+		edges,
+		groups,
+	})
+}
+
+/// Return the record ids of the packages that pass every filter in
+/// `request`.
+fn filter_packages<'a>(
+	configuration: &WorkspaceConfiguration,
+	discovery: &'a DiscoveryReport,
+	request: &GraphRequest,
+) -> MonochangeResult<BTreeSet<&'a str>> {
+	for group in &request.groups {
+		if configuration.group_by_id(group).is_none() {
+			return Err(MonochangeError::Config(format!(
+				"group `{group}` was not found in the workspace configuration"
+			)));
+		}
+	}
+
+	let neighbourhood = if request.packages.is_empty() {
+		None
+	} else {
+		let roots = request
+			.packages
+			.iter()
+			.map(|reference| {
+				find_package_by_reference(&discovery.packages, reference)
+					.map(|package| package.id.as_str())
+			})
+			.collect::<MonochangeResult<Vec<_>>>()?;
+		Some(connected_packages(discovery, &roots))
+	};
+
+	Ok(discovery
+		.packages
+		.iter()
+		.filter(|package| {
+			request.ecosystems.is_empty()
+				|| request
+					.ecosystems
+					.iter()
+					.any(|ecosystem| ecosystem == package.ecosystem.as_str())
+		})
+		.filter(|package| {
+			request.groups.is_empty()
+				|| package
+					.version_group_id
+					.as_ref()
+					.is_some_and(|group| request.groups.contains(group))
+		})
+		.filter(|package| {
+			neighbourhood
+				.as_ref()
+				.is_none_or(|neighbourhood| neighbourhood.contains(package.id.as_str()))
+		})
+		.map(|package| package.id.as_str())
+		.collect())
+}
+
+/// Collect `roots` together with their transitive dependencies and
+/// transitive dependents.
+fn connected_packages<'a>(discovery: &'a DiscoveryReport, roots: &[&'a str]) -> BTreeSet<&'a str> {
+	let mut connected = roots.iter().copied().collect::<BTreeSet<_>>();
+	for forward in [true, false] {
+		let mut visited = roots.iter().copied().collect::<BTreeSet<_>>();
+		let mut pending = roots.to_vec();
+		while let Some(current) = pending.pop() {
+			for edge in &discovery.dependencies {
+				let (from, to) = if forward {
+					(edge.from_package_id.as_str(), edge.to_package_id.as_str())
+				} else {
+					(edge.to_package_id.as_str(), edge.from_package_id.as_str())
+				};
+				if from == current && visited.insert(to) {
+					pending.push(to);
+				}
+			}
+		}
+		connected.extend(visited);
+	}
+	connected
+}
+
+/// Render the graph in the requested format.
+pub(crate) fn render_workspace_graph(graph: &WorkspaceGraph, format: GraphFormat) -> String {
+	match format {
+		GraphFormat::Dot => render_dot(graph),
+		GraphFormat::Mermaid => render_mermaid(graph),
+		GraphFormat::Json => {
+			serde_json::to_string_pretty(graph).unwrap_or_else(|error| {
+				format!("{{\"error\":\"failed to serialize workspace graph: {error}\"}}")
+			})
+		}
+	}
+}
+
+/// Fill colour used for a planned bump.
+fn bump_color(bump: BumpSeverity) -> &'static str {
+	match bump {
+		BumpSeverity::Major => "#f8d7da",
+		BumpSeverity::Minor => "#fff3cd",
+		BumpSeverity::Patch => "#d4edda",
+		_ => "#ffffff",
+	}
+}
+
+fn node_label_lines(node: &GraphNode) -> Vec<String> {
+	let mut lines = vec![node.id.clone()];
+	match (&node.version, &node.bump) {
+		(Some(version), Some(bump)) => {
+			let planned = node.planned_version.as_deref().unwrap_or("?");
+			lines.push(format!("{version} -> {planned} ({bump})"));
+		}
+		(None, Some(bump)) => lines.push(format!("({bump})")),
+		(Some(version), None) => lines.push(version.clone()),
+		(None, None) => {}
+	}
+	lines
+}
+
+fn levels(graph: &WorkspaceGraph) -> BTreeMap<usize, Vec<&GraphNode>> {
+	let mut levels = BTreeMap::<usize, Vec<&GraphNode>>::new();
+	for node in &graph.nodes {
+		if let Some(level) = node.publish_level {
+			levels.entry(level).or_default().push(node);
+		}
+	}
+	levels
+}
+
+fn dot_escape(value: &str) -> String {
+	value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn dot_string(value: &str) -> String {
+	format!("\"{}\"", dot_escape(value))
+}
+
+fn render_dot(graph: &WorkspaceGraph) -> String {
+	let mut output = String::from("digraph workspace {\n");
+	output.push_str("\trankdir=BT;\n");
+	output.push_str("\tnode [shape=box, style=\"rounded,filled\", fillcolor=\"#ffffff\"];\n");
+
+	let grouped = graph
+		.groups
+		.iter()
+		.flat_map(|group| group.members.iter().map(String::as_str))
+		.collect::<BTreeSet<_>>();
+	let write_node = |output: &mut String, node: &GraphNode, indent: &str| {
+		let mut lines = node_label_lines(node);
+		if let Some(level) = node.publish_level {
+			lines.push(format!("level {level}"));
+		}
+		let label = lines
+			.iter()
+			.map(|line| dot_escape(line))
+			.collect::<Vec<_>>()
+			.join("\\n");
+		let _ = write!(
+			output,
+			"{indent}{} [label=\"{label}\"",
+			dot_string(&node.id)
+		);
+		if let Some(bump) = node.bump {
+			let _ = write!(output, ", fillcolor=\"{}\"", bump_color(bump));
+		}
+		output.push_str("];\n");
+	};
+
+	for group in &graph.groups {
+		let _ = writeln!(
+			output,
+			"\tsubgraph {} {{",
+			dot_string(&format!("cluster_{}", group.id))
+		);
+		let _ = writeln!(output, "\t\tlabel={};", dot_string(&group.id));
+		output.push_str("\t\tstyle=dashed;\n");
+		for node in graph
+			.nodes
+			.iter()
+			.filter(|node| group.members.contains(&node.id))
+		{
+			write_node(&mut output, node, "\t\t");
+		}
+		output.push_str("\t}\n");
+	}
+	for node in graph
+		.nodes
+		.iter()
+		.filter(|node| !grouped.contains(node.id.as_str()))
+	{
+		write_node(&mut output, node, "\t");
+	}
+
+	for (level, nodes) in levels(graph) {
+		let _ = write!(output, "\t{{ rank=same; /* level {level} */");
+		for node in nodes {
+			let _ = write!(output, " {};", dot_string(&node.id));
+		}
+		output.push_str(" }\n");
+	}
+
+	for edge in &graph.edges {
+		let _ = write!(
+			output,
+			"\t{} -> {}",
+			dot_string(&edge.from),
+			dot_string(&edge.to)
+		);
+		let mut attributes = Vec::new();
+		match edge.kind {
+			DependencyKind::Runtime => {}
+			DependencyKind::Development => {
+				attributes.push("style=dashed".to_string());
+				attributes.push(format!("label={}", dot_string(&edge.kind.to_string())));
+			}
+			DependencyKind::Peer => {
+				attributes.push("style=dotted".to_string());
+				attributes.push(format!("label={}", dot_string(&edge.kind.to_string())));
+			}
+			_ => attributes.push(format!("label={}", dot_string(&edge.kind.to_string()))),
+		}
+		if !attributes.is_empty() {
+			let _ = write!(output, " [{}]", attributes.join(", "));
+		}
+		output.push_str(";\n");
+	}
+	output.push_str("}\n");
+	output
+}
+
+fn mermaid_text(value: &str) -> String {
+	value.replace('"', "#quot;")
+}
+
+fn render_mermaid(graph: &WorkspaceGraph) -> String {
+	let node_ids = graph
+		.nodes
+		.iter()
+		.enumerate()
+		.map(|(index, node)| (node.id.as_str(), format!("n{index}")))
+		.collect::<BTreeMap<_, _>>();
+	let node_id = |id: &str| node_ids.get(id).map_or("", String::as_str);
+	let write_node = |output: &mut String, node: &GraphNode, indent: &str| {
+		let label = node_label_lines(node)
+			.iter()
+			.map(|line| mermaid_text(line))
+			.collect::<Vec<_>>()
+			.join("<br/>");
+		let _ = writeln!(output, "{indent}{}[\"{label}\"]", node_id(&node.id));
+	};
+
+	let mut output = String::from("flowchart BT\n");
+	let levels = levels(graph);
+	if levels.is_empty() {
+		// Without publish levels the subgraphs show version groups instead.
+		let grouped = graph
+			.groups
+			.iter()
+			.flat_map(|group| group.members.iter().map(String::as_str))
+			.collect::<BTreeSet<_>>();
+		for (index, group) in graph.groups.iter().enumerate() {
+			let _ = writeln!(
+				output,
+				"\tsubgraph group{index}[\"{}\"]",
+				mermaid_text(&group.id)
+			);
+			for node in graph
+				.nodes
+				.iter()
+				.filter(|node| group.members.contains(&node.id))
+			{
+				write_node(&mut output, node, "\t\t");
+			}
+			output.push_str("\tend\n");
+		}
+		for node in graph
+			.nodes
+			.iter()
+			.filter(|node| !grouped.contains(node.id.as_str()))
+		{
+			write_node(&mut output, node, "\t");
+		}
+	} else {
+		for (level, nodes) in &levels {
+			let _ = writeln!(output, "\tsubgraph level{level}[\"level {level}\"]");
+			for node in nodes {
+				write_node(&mut output, node, "\t\t");
+			}
+			output.push_str("\tend\n");
+		}
+		for node in graph
+			.nodes
+			.iter()
+			.filter(|node| node.publish_level.is_none())
+		{
+			write_node(&mut output, node, "\t");
+		}
+	}
+
+	for edge in &graph.edges {
+		let from = node_id(&edge.from);
+		let to = node_id(&edge.to);
+		let _ = match edge.kind {
+			DependencyKind::Runtime => writeln!(output, "\t{from} --> {to}"),
+			DependencyKind::Development | DependencyKind::Peer => {
+				writeln!(output, "\t{from} -.->|{}| {to}", edge.kind)
+			}
+			_ => writeln!(output, "\t{from} -->|{}| {to}", edge.kind),
+		};
+	}
+
+	let bumps = graph
+		.nodes
+		.iter()
+		.filter_map(|node| node.bump)
+		.collect::<BTreeSet<_>>();
+	for bump in &bumps {
+		let _ = writeln!(output, "\tclassDef {bump} fill:{}", bump_color(*bump));
+	}
+	for bump in bumps {
+		let members = graph
+			.nodes
+			.iter()
+			.filter(|node| node.bump == Some(bump))
+			.map(|node| node_id(&node.id))
+			.collect::<Vec<_>>();
+		let _ = writeln!(output, "\tclass {} {bump}", members.join(","));
+	}
+	output
+}
+
+#[cfg(test)]
+#[path = "__tests__/workspace_graph_tests.rs"]
+mod tests;
@@ -16,9 +16,12 @@ use monochange_config::apply_version_groups;
 use monochange_config::build_changeset_load_context;
 use monochange_config::load_change_signals;
 use monochange_config::load_changeset_contents_with_context;
+use monochange_config::load_changeset_file_with_context;
 use monochange_config::load_workspace_configuration;
 use monochange_core::BumpSeverity;
+use monochange_core::ChangeSignal;
 use monochange_core::CliCommandDefinition;
+use monochange_core::DependencyCycle;
 use monochange_core::DiscoveryReport;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemRegistry;
@@ -31,13 +34,20 @@ use monochange_core::PackageType;
 use monochange_core::ReleasePlan;
 use monochange_core::SourceConfiguration;
 use monochange_core::default_cli_commands;
+use monochange_core::detect_dependency_cycles;
 #[cfg(feature = "dart")]
 use monochange_dart::DartAdapter;
 use monochange_deno::DenoAdapter;
 use monochange_go::GoAdapter;
+#[cfg(feature = "helm")]
+use monochange_helm::HelmAdapter;
+#[cfg(feature = "jvm")]
+use monochange_jvm::JvmAdapter;
 #[cfg(feature = "npm")]
 use monochange_npm::NpmAdapter;
 use monochange_python::PythonAdapter;
+#[cfg(feature = "ruby")]
+use monochange_ruby::RubyAdapter;
 use serde_json::json;
 use tokio::task::JoinHandle;
 use tokio::time::timeout;
@@ -552,6 +562,9 @@ fn render_annotated_init_config(
 			PackageType::Flutter => "flutter",
 			PackageType::Python => "python",
 			PackageType::Go => "go",
+			PackageType::Helm => "helm",
+			PackageType::Ruby => "ruby",
+			PackageType::Jvm => "jvm",
 			_ => unreachable!(),
 		};
 
@@ -631,6 +644,34 @@ fn build_ecosystem_registry() -> EcosystemRegistry {
 	registry.push_adapter(Box::new(PythonAdapter));
 	#[cfg(feature = "go")]
 	registry.push_adapter(Box::new(GoAdapter));
+	#[cfg(feature = "helm")]
+	registry.push_adapter(Box::new(HelmAdapter));
+	#[cfg(feature = "ruby")]
+	registry.push_adapter(Box::new(RubyAdapter));
+	#[cfg(feature = "jvm")]
+	registry.push_adapter(Box::new(JvmAdapter));
+	registry
+}
+
+/// Build the adapter registry with each ecosystem's configured `roots` and
+/// `exclude` globs applied to discovery.
+fn build_discovery_registry(
+	configuration: &monochange_core::WorkspaceConfiguration,
+) -> EcosystemRegistry {
+	let mut registry = build_ecosystem_registry();
+	for (ecosystem, settings) in [
+		(Ecosystem::Cargo, &configuration.cargo),
+		(Ecosystem::Npm, &configuration.npm),
+		(Ecosystem::Deno, &configuration.deno),
+		(Ecosystem::Dart, &configuration.dart),
+		(Ecosystem::Python, &configuration.python),
+		(Ecosystem::Go, &configuration.go),
+		(Ecosystem::Helm, &configuration.helm),
+		(Ecosystem::Ruby, &configuration.ruby),
+		(Ecosystem::Jvm, &configuration.jvm),
+	] {
+		registry.set_discovery_scope(ecosystem, settings);
+	}
 	registry
 }
 
@@ -683,6 +724,9 @@ fn package_type_for_ecosystem(ecosystem: Ecosystem) -> PackageType {
 		Ecosystem::Flutter => PackageType::Flutter,
 		Ecosystem::Python => PackageType::Python,
 		Ecosystem::Go => PackageType::Go,
+		Ecosystem::Helm => PackageType::Helm,
+		Ecosystem::Ruby => PackageType::Ruby,
+		Ecosystem::Jvm => PackageType::Jvm,
 		_ => PackageType::Cargo,
 	}
 }
@@ -696,6 +740,18 @@ fn package_type_for_ecosystem_maps_python() {
 	assert_eq!(PackageType::Python.as_str(), "python");
 	assert_eq!(package_type_for_ecosystem(Ecosystem::Go), PackageType::Go);
 	assert_eq!(PackageType::Go.as_str(), "go");
+	assert_eq!(
+		package_type_for_ecosystem(Ecosystem::Helm),
+		PackageType::Helm
+	);
+	assert_eq!(PackageType::Helm.as_str(), "helm");
+	assert_eq!(
+		package_type_for_ecosystem(Ecosystem::Ruby),
+		PackageType::Ruby
+	);
+	assert_eq!(PackageType::Ruby.as_str(), "ruby");
+	assert_eq!(package_type_for_ecosystem(Ecosystem::Jvm), PackageType::Jvm);
+	assert_eq!(PackageType::Jvm.as_str(), "jvm");
 }
 
 #[test]
@@ -788,6 +844,34 @@ pub(crate) fn build_lockfile_command_executions(
 			package.ecosystem == Ecosystem::Go && released_versions.contains_key(&package.id)
 		}),
 	)?;
+	// Chart.lock pins a digest of the resolved dependencies, so it cannot be
+	// patched in place; Helm has to re-resolve it.
+	#[cfg(feature = "helm")]
+	let helm_executions = if configuration.helm.lockfile_commands.is_empty() {
+		monochange_helm::lockfile_refresh_commands(packages, &released_versions)
+	} else {
+		resolve_lockfile_command_executions(
+			root,
+			&configuration.helm.lockfile_commands,
+			packages.iter().any(|package| {
+				package.ecosystem == Ecosystem::Helm && released_versions.contains_key(&package.id)
+			}),
+		)?
+	};
+	// Gemfile.lock records the resolved versions of `path:` gems, which
+	// Bundler re-resolves rather than having them patched.
+	#[cfg(feature = "ruby")]
+	let ruby_executions = if configuration.ruby.lockfile_commands.is_empty() {
+		monochange_ruby::lockfile_refresh_commands(packages, &released_versions)
+	} else {
+		resolve_lockfile_command_executions(
+			root,
+			&configuration.ruby.lockfile_commands,
+			packages.iter().any(|package| {
+				package.ecosystem == Ecosystem::Ruby && released_versions.contains_key(&package.id)
+			}),
+		)?
+	};
 	let mut executions = Vec::new();
 	#[cfg(feature = "cargo")]
 	executions.extend(cargo_executions);
@@ -801,6 +885,10 @@ pub(crate) fn build_lockfile_command_executions(
 	executions.extend(python_executions);
 	#[cfg(feature = "go")]
 	executions.extend(go_executions);
+	#[cfg(feature = "helm")]
+	executions.extend(helm_executions);
+	#[cfg(feature = "ruby")]
+	executions.extend(ruby_executions);
 	Ok(dedup_lockfile_command_executions(executions))
 }
 // patch-coverage:ignore-end
@@ -923,7 +1011,7 @@ pub(crate) fn validate_cargo_workspace_version_groups(root: &Path) -> Monochange
 #[must_use = "the discovery result must be checked"]
 pub fn discover_workspace(root: &Path) -> MonochangeResult<DiscoveryReport> {
 	let configuration = load_workspace_configuration(root)?;
-	let discovery = build_ecosystem_registry().discover_all(root)?;
+	let discovery = build_discovery_registry(&configuration).discover_all(root)?;
 	let mut warnings = discovery.warnings;
 	let mut packages = discovery.packages;
 	normalize_package_ids(root, &mut packages);
@@ -937,6 +1025,7 @@ pub fn discover_workspace(root: &Path) -> MonochangeResult<DiscoveryReport> {
 	tracing::info!(
 		packages = packages.len(),
 		warnings = warnings.len(),
+		skipped = discovery.skipped.len(),
 		"workspace discovery complete"
 	);
 
@@ -946,6 +1035,7 @@ pub fn discover_workspace(root: &Path) -> MonochangeResult<DiscoveryReport> {
 		dependencies,
 		version_groups,
 		warnings,
+		skipped: discovery.skipped,
 	})
 }
 
@@ -963,7 +1053,7 @@ pub fn discover_workspace(root: &Path) -> MonochangeResult<DiscoveryReport> {
 /// oriented commands while giving release planning a path that parses only the
 /// configured package manifests. The comment is intentionally explicit so future
 /// refactors do not “simplify” the code back to a full repo walk.
-fn discover_release_workspace(
+pub(crate) fn discover_release_workspace(
 	root: &Path,
 	configuration: &monochange_core::WorkspaceConfiguration,
 ) -> MonochangeResult<DiscoveryReport> {
@@ -1002,6 +1092,7 @@ fn discover_release_workspace(
 		dependencies,
 		version_groups,
 		warnings,
+		skipped: Vec::new(),
 	})
 }
 
@@ -1285,7 +1376,114 @@ pub fn plan_release(root: &Path, changes_path: &Path) -> MonochangeResult<Releas
 	let configuration = load_workspace_configuration(root)?;
 	let discovery = discover_workspace(root)?;
 	let change_signals = load_change_signals(changes_path, &configuration, &discovery.packages)?;
-	build_release_plan_from_signals(&configuration, &discovery, &change_signals)
+	build_release_plan_from_signals(
+		&configuration,
+		&discovery,
+		&change_signals,
+		&release_plan_options(&configuration, &discovery.packages),
+	)
+}
+
+/// Release plan for the changesets that are currently pending, built without
+/// preparing any release artifacts.
+pub(crate) struct PendingReleasePlan {
+	pub signals: Vec<ChangeSignal>,
+	pub options: ReleasePlanOptions,
+	pub plan: ReleasePlan,
+}
+
+/// Plan the pending `.changeset` files the same way `PrepareRelease` would,
+/// including prerelease retention, but leave the workspace untouched.
+pub(crate) fn plan_pending_release(
+	root: &Path,
+	configuration: &monochange_core::WorkspaceConfiguration,
+	discovery: &DiscoveryReport,
+) -> MonochangeResult<PendingReleasePlan> {
+	let prerelease_session = PrereleaseSession::resolve(
+		configuration,
+		load_prerelease_state(root)?,
+		&PrereleaseRequest::default(),
+	)?;
+	let context = build_changeset_load_context(configuration, &discovery.packages);
+	let loaded_changesets = discover_configured_changeset_paths(root, configuration, true)?
+		.iter()
+		.map(|path| load_changeset_file_with_context(path, &context))
+		.collect::<MonochangeResult<Vec<_>>>()?;
+	let commit_changesets = load_commit_changesets(
+		root,
+		configuration,
+		&context,
+		&loaded_changesets,
+		&prerelease_session,
+		&discovery.packages,
+	)?;
+	let signals = prerelease_session
+		.retain_changesets(root, configuration, &discovery.packages, loaded_changesets)
+		.included
+		.into_iter()
+		.chain(commit_changesets)
+		.flat_map(|changeset| changeset.signals)
+		.collect::<Vec<_>>();
+	let options = ReleasePlanOptions {
+		prerelease_channels: prerelease_session
+			.planner_channels(configuration, &discovery.packages),
+		..release_plan_options(configuration, &discovery.packages)
+	};
+	let plan = build_release_plan_from_signals(configuration, discovery, &signals, &options)?;
+	Ok(PendingReleasePlan {
+		signals,
+		options,
+		plan,
+	})
+}
+
+/// Find a discovered package by configured package id, package name, or
+/// package record id.
+pub(crate) fn find_package_by_reference<'a>(
+	packages: &'a [PackageRecord],
+	reference: &str,
+) -> MonochangeResult<&'a PackageRecord> {
+	packages
+		.iter()
+		.find(|package| package.metadata.get("config_id").map(String::as_str) == Some(reference))
+		.or_else(|| {
+			packages
+				.iter()
+				.find(|package| package.name == reference || package.id == reference)
+		})
+		.ok_or_else(|| {
+			MonochangeError::Config(format!(
+				"package `{reference}` was not found in the workspace"
+			))
+		})
+}
+
+/// The configured package id of `package`, or its name when the package is
+/// not configured.
+pub(crate) fn package_display_id(package: &PackageRecord) -> String {
+	package
+		.metadata
+		.get("config_id")
+		.cloned()
+		.unwrap_or_else(|| package.name.clone())
+}
+
+/// Detect dependency cycles between `packages`, naming each package by its
+/// display id.
+pub(crate) fn workspace_dependency_cycles(packages: &[PackageRecord]) -> Vec<DependencyCycle> {
+	let display_ids = packages
+		.iter()
+		.map(|package| (package.id.as_str(), package_display_id(package)))
+		.collect::<BTreeMap<_, _>>();
+	let edges = materialize_dependency_edges(packages)
+		.into_iter()
+		.filter_map(|mut edge| {
+			edge.from_package_id = display_ids.get(edge.from_package_id.as_str())?.clone();
+			edge.to_package_id = display_ids.get(edge.to_package_id.as_str())?.clone();
+			Some(edge)
+		})
+		.collect::<Vec<_>>();
+	detect_dependency_cycles(&edges)
 }
 
 #[tracing::instrument(skip_all)]
@@ -1520,13 +1718,75 @@ pub(crate) async fn prepare_release_execution(
 	prepare_release_execution_with_file_diffs(root, dry_run, true, false).await
 }
 
-#[tracing::instrument(skip_all, fields(dry_run, build_file_diffs))]
 pub(crate) async fn prepare_release_execution_with_file_diffs(
 	root: &Path,
 	dry_run: bool,
 	build_file_diffs: bool,
 	allow_empty_changesets: bool,
 ) -> MonochangeResult<PreparedReleaseExecution> {
+	prepare_release_execution_with_modes(
+		root,
+		dry_run,
+		build_file_diffs,
+		allow_empty_changesets,
+		&PrereleaseRequest::default(),
+		None,
+		&GraduationRequest::default(),
+	)
+	.await
+}
+
+fn empty_prepared_release_execution(
+	root: &Path,
+	dry_run: bool,
+	changeset_paths: Vec<PathBuf>,
+	phase_timings: Vec<StepPhaseTiming>,
+) -> PreparedReleaseExecution {
+	PreparedReleaseExecution {
+		prepared_release: PreparedRelease {
+			plan: ReleasePlan {
+				workspace_root: root.to_path_buf(),
+				decisions: Vec::new(),
+				groups: Vec::new(),
+				warnings: Vec::new(),
+				version_conflicts: Vec::new(),
+				unresolved_items: Vec::new(),
+				compatibility_evidence: Vec::new(),
+			},
+			changeset_paths,
+			changesets: Vec::new(),
+			released_packages: Vec::new(),
+			package_publications: Vec::new(),
+			version: None,
+			group_version: None,
+			release_targets: Vec::new(),
+			changed_files: Vec::new(),
+			changelogs: Vec::new(),
+			updated_changelogs: Vec::new(),
+			deleted_changesets: Vec::new(),
+			dry_run,
+			snapshot: None,
+		},
+		file_diffs: Vec::new(),
+		phase_timings,
+	}
+}
+
+#[tracing::instrument(skip_all, fields(dry_run, build_file_diffs))]
+pub(crate) async fn prepare_release_execution_with_modes(
+	root: &Path,
+	dry_run: bool,
+	build_file_diffs: bool,
+	allow_empty_changesets: bool,
+	prerelease_request: &PrereleaseRequest,
+	snapshot_request: Option<&SnapshotRequest>,
+	graduation_request: &GraduationRequest,
+) -> MonochangeResult<PreparedReleaseExecution> {
+	if snapshot_request.is_some() && prerelease_request.is_requested() {
+		return Err(MonochangeError::Config(
+			"`snapshot` cannot be combined with `pre` or `exit_pre`".to_string(),
+		));
+	}
 	let mut phase_timings = Vec::new();
 	let configuration =
 		measure_prepare_phase(&mut phase_timings, "load workspace configuration", || {
@@ -1536,39 +1796,27 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 		measure_prepare_phase(&mut phase_timings, "discover release workspace", || {
 			discover_release_workspace(root, &configuration)
 		})?;
+	let prerelease_session = PrereleaseSession::resolve(
+		&configuration,
+		load_prerelease_state(root)?,
+		prerelease_request,
+	)?;
+	let graduations =
+		graduation_request.planner_graduations(&configuration, &discovery.packages)?;
 	let changeset_paths =
 		measure_prepare_phase(&mut phase_timings, "discover changeset paths", || {
-			discover_changeset_paths(root, allow_empty_changesets)
+			discover_configured_changeset_paths(root, &configuration, allow_empty_changesets)
 		})?;
 	tracing::debug!(count = changeset_paths.len(), "discovered changesets");
+	let reads_commits = reads_commit_sources(&configuration);
 
-	if changeset_paths.is_empty() && allow_empty_changesets {
-		return Ok(PreparedReleaseExecution {
-			prepared_release: PreparedRelease {
-				plan: ReleasePlan {
-					workspace_root: root.to_path_buf(),
-					decisions: Vec::new(),
-					groups: Vec::new(),
-					warnings: Vec::new(),
-					unresolved_items: Vec::new(),
-					compatibility_evidence: Vec::new(),
-				},
-				changeset_paths,
-				changesets: Vec::new(),
-				released_packages: Vec::new(),
-				package_publications: Vec::new(),
-				version: None,
-				group_version: None,
-				release_targets: Vec::new(),
-				changed_files: Vec::new(),
-				changelogs: Vec::new(),
-				updated_changelogs: Vec::new(),
-				deleted_changesets: Vec::new(),
-				dry_run,
-			},
-			file_diffs: Vec::new(),
+	if changeset_paths.is_empty() && allow_empty_changesets && !reads_commits {
+		return Ok(empty_prepared_release_execution(
+			root,
+			dry_run,
+			changeset_paths,
 			phase_timings,
-		});
+		));
 	}
 
 	// Build the shared changeset lookup context once.
@@ -1604,6 +1852,56 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 				})
 				.collect::<MonochangeResult<Vec<_>>>()
 		})?;
+	// Changesets already shipped as prereleases stay on disk until their owners
+	// leave prerelease mode, so only feed the planner what is still pending.
+	let ChangesetRetention {
+		included: mut loaded_changesets,
+		deletable: deletable_changesets,
+		kept: kept_changesets,
+	} = prerelease_session.retain_changesets(
+		root,
+		&configuration,
+		&discovery.packages,
+		loaded_changesets,
+	);
+	let changeset_paths = loaded_changesets
+		.iter()
+		.map(|changeset| changeset.path.clone())
+		.collect::<Vec<_>>();
+	// Commit-derived changes have no file to delete or retain, so they join the
+	// pending set after prerelease retention has decided what stays on disk.
+	let commit_changesets =
+		measure_prepare_phase(&mut phase_timings, "load commit changesets", || {
+			load_commit_changesets(
+				root,
+				&configuration,
+				&changeset_context,
+				&loaded_changesets,
+				&prerelease_session,
+				&discovery.packages,
+			)
+		})?;
+	if reads_commits
+		&& !allow_empty_changesets
+		&& changeset_sources.is_empty()
+		&& commit_changesets.is_empty()
+	{
+		return Err(no_pending_changes_error(&configuration));
+	}
+	loaded_changesets.extend(commit_changesets);
+	if loaded_changesets.is_empty() && allow_empty_changesets {
+		return Ok(empty_prepared_release_execution(
+			root,
+			dry_run,
+			changeset_paths,
+			phase_timings,
+		));
+	}
+	let change_paths = loaded_changesets
+		.iter()
+		.map(|changeset| changeset.path.clone())
+		.collect::<Vec<_>>();
+	let next_prerelease_state = prerelease_session.next_state(kept_changesets);
 	let change_signals = loaded_changesets
 		.iter()
 		.flat_map(|changeset| changeset.signals.clone())
@@ -1639,9 +1937,20 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 		.await;
 	}
 	// patch-coverage:ignore-end
-	let plan = measure_prepare_phase(&mut phase_timings, "build release plan", || {
-		build_release_plan_from_signals(&configuration, &discovery, &change_signals)
+	let mut plan = measure_prepare_phase(&mut phase_timings, "build release plan", || {
+		build_release_plan_from_signals(
+			&configuration,
+			&discovery,
+			&change_signals,
+			&ReleasePlanOptions {
+				prerelease_channels: prerelease_session
+					.planner_channels(&configuration, &discovery.packages),
+				graduations: graduations.keys().cloned().collect(),
+				..release_plan_options(&configuration, &discovery.packages)
+			},
+		)
 	})?;
+	ensure_graduations_released(&graduations, &plan)?;
 	let released_packages = released_package_names(&discovery.packages, &plan);
 	tracing::debug!(
 		count = released_packages.len(),
@@ -1652,6 +1961,17 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 			"no releaseable packages were found in discovered changesets".to_string(),
 		));
 	}
+	// Snapshot builds reuse the plan's versions with a throwaway suffix so every
+	// manifest and internal dependency range below is rewritten consistently.
+	let snapshot_suffix = if let Some(snapshot_request) = snapshot_request {
+		let commit = git_head_commit(root).await.ok();
+		let suffix =
+			snapshot_request.render_suffix(resolve_release_datetime(), commit.as_deref())?;
+		apply_snapshot_versions(&mut plan, &suffix);
+		Some(suffix)
+	} else {
+		None
+	};
 
 	let (
 		(changelog_targets_result, manifest_updates_result),
@@ -1666,7 +1986,11 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 				},
 				|| {
 					capture_prepare_phase("build manifest updates", || {
-						build_manifest_updates_parallel(&discovery.packages, &plan)
+						build_manifest_updates_parallel(
+							&discovery.packages,
+							&plan,
+							configuration.defaults.propagation,
+						)
 					})
 				},
 			)
@@ -1708,7 +2032,7 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 	let release_targets = measure_async_prepare_phase(
 		&mut phase_timings,
 		"build release targets",
-		build_release_targets(&configuration, &discovery.packages, &plan, &changeset_paths),
+		build_release_targets(&configuration, &discovery.packages, &plan, &change_paths),
 	)
 	.await;
 	let lockfile_commands = lockfile_commands_result.0?;
@@ -1738,7 +2062,9 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 			}
 		})
 		.collect::<Vec<_>>();
-	let changelog_updates =
+	let changelog_updates = if snapshot_request.is_some() {
+		Vec::new()
+	} else {
 		measure_prepare_phase(&mut phase_timings, "build changelog updates", || {
 			build_changelog_updates(
 				ChangelogBuildContext::builder()
@@ -1752,7 +2078,8 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 					.release_targets(&changelog_release_targets)
 					.build(),
 			)
-		})?;
+		})?
+	};
 	let changelog_file_updates = changelog_updates
 		.iter()
 		.map(|update| {
@@ -1773,7 +2100,30 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 		lockfile_commands = lockfile_commands.len(),
 		"built manifest and lockfile updates"
 	);
-	let file_updates = if lockfile_commands.is_empty() || dry_run {
+	let snapshot_working_tree = if snapshot_request.is_some() && !dry_run {
+		Some(measure_prepare_phase(
+			&mut phase_timings,
+			"create snapshot working tree",
+			|| create_snapshot_working_tree(root),
+		)?)
+	} else {
+		None
+	};
+	let file_updates = if let Some(working_tree) = &snapshot_working_tree {
+		let working_tree = working_tree.path();
+		let snapshot_updates = rebase_file_updates(root, working_tree, &base_updates);
+		if lockfile_commands.is_empty() {
+			apply_file_updates(&snapshot_updates)?;
+			snapshot_updates
+		} else {
+			materialize_lockfile_command_updates_with_timing(
+				&mut phase_timings,
+				working_tree,
+				&snapshot_updates,
+				&rebase_lockfile_commands(root, working_tree, &lockfile_commands),
+			)?
+		}
+	} else if lockfile_commands.is_empty() || dry_run {
 		// During dry-run, skip the expensive workspace copy and lockfile
 		// command execution. The base updates already contain all version
 		// file and changelog changes; lockfile diffs are omitted from the
@@ -1788,10 +2138,18 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 			&lockfile_commands,
 		)?
 	};
+	let changed_files_root = snapshot_working_tree
+		.as_ref()
+		.map_or(root, SnapshotWorkingTree::path);
 	let mut changed_files = file_updates
 		.iter()
-		.map(|update| root_relative(root, &update.path))
+		.map(|update| root_relative(changed_files_root, &update.path))
 		.collect::<Vec<_>>();
+	let prerelease_state_changed =
+		snapshot_request.is_none() && prerelease_session.state_changed(&next_prerelease_state);
+	if prerelease_state_changed {
+		changed_files.push(root_relative(root, &prerelease_state_path(root)));
+	}
 	changed_files.sort();
 	changed_files.dedup();
 	let changelogs = changelog_updates
@@ -1817,7 +2175,11 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 	// explicitly needs human-readable diff previews.
 	let file_diffs = if build_file_diffs {
 		measure_prepare_phase(&mut phase_timings, "build file diff previews", || {
-			build_file_diff_previews(root, &file_updates)
+			if snapshot_working_tree.is_some() {
+				build_file_diff_previews(root, &base_updates)
+			} else {
+				build_file_diff_previews(root, &file_updates)
+			}
 		})?
 	} else {
 		Vec::new()
@@ -1826,7 +2188,7 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 	let version = shared_release_version(&plan);
 	let group_version = shared_group_version(&plan);
 	let mut deleted_changesets = Vec::new();
-	if !dry_run {
+	if !dry_run && snapshot_request.is_none() {
 		measure_prepare_phase(&mut phase_timings, "apply release changes", || {
 			// When lockfile commands ran, materialize_lockfile_command_updates
 			// already applied base_updates in-place. Only apply when we
@@ -1834,10 +2196,13 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 			if lockfile_commands.is_empty() {
 				apply_file_updates(&file_updates)?;
 			}
-			for path in &changeset_paths {
+			for path in &deletable_changesets {
 				delete_changeset_file(path)?;
 				deleted_changesets.push(root_relative(root, path));
 			}
+			if prerelease_state_changed {
+				write_prerelease_state(root, &next_prerelease_state)?;
+			}
 			Ok(())
 		})?;
 	}
@@ -1863,12 +2228,40 @@ pub(crate) async fn prepare_release_execution_with_file_diffs(
 			updated_changelogs,
 			deleted_changesets,
 			dry_run,
+			snapshot: snapshot_request
+				.zip(snapshot_suffix)
+				.map(|(request, suffix)| {
+					PreparedSnapshot {
+						tag: request.tag.clone(),
+						suffix: suffix.to_string(),
+						working_tree: snapshot_working_tree
+							.as_ref()
+							.map(|working_tree| working_tree.path().to_path_buf()),
+						working_tree_guard: snapshot_working_tree,
+					}
+				}),
 		},
 		file_diffs,
 		phase_timings,
 	})
 }
 
+fn rebase_lockfile_commands(
+	root: &Path,
+	working_tree: &Path,
+	commands: &[LockfileCommandExecution],
+) -> Vec<LockfileCommandExecution> {
+	commands
+		.iter()
+		.map(|command| {
+			LockfileCommandExecution {
+				cwd: working_tree.join(command.cwd.strip_prefix(root).unwrap_or(&command.cwd)),
+				..command.clone()
+			}
+		})
+		.collect()
+}
+
 fn measure_prepare_phase<T>(
 	phase_timings: &mut Vec<StepPhaseTiming>,
 	label: impl Into<String>,
@@ -0,0 +1,129 @@
+#![allow(clippy::disallowed_methods)]
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+
+use monochange_test_helpers::git;
+use serde_json::Value;
+use tempfile::TempDir;
+
+mod test_support;
+use test_support::monochange_command;
+use test_support::setup_scenario_workspace;
+
+/// Tag `core/v1.0.0`, then remove the public `render` function in a commit
+/// that only declares a patch bump.
+fn setup_under_declared_removal(enforcement: &str) -> TempDir {
+	let tempdir = setup_scenario_workspace("analysis-enforcement/workspace");
+	let root = tempdir.path();
+	git(root, &["init"]);
+	git(root, &["branch", "-M", "main"]);
+	git(root, &["config", "user.name", "monochange Tests"]);
+	git(root, &["config", "user.email", "monochange@example.com"]);
+	git(root, &["config", "commit.gpgsign", "false"]);
+	git(root, &["add", "."]);
+	git(root, &["commit", "-m", "initial"]);
+	git(root, &["tag", "core/v1.0.0"]);
+
+	fs::write(
+		root.join("crates/core/src/lib.rs"),
+		"pub fn parse(input: &str) -> usize {\n\tinput.len()\n}\n",
+	)
+	.unwrap_or_else(|error| panic!("write lib.rs: {error}"));
+	fs::create_dir_all(root.join(".changeset"))
+		.unwrap_or_else(|error| panic!("create .changeset: {error}"));
+	fs::write(
+		root.join(".changeset/tidy-rendering.md"),
+		"---\ncore: patch\n---\n\n#### tidy the rendering helpers\n",
+	)
+	.unwrap_or_else(|error| panic!("write changeset: {error}"));
+	git(root, &["add", "."]);
+	git(root, &["commit", "-m", "tidy rendering"]);
+
+	let config_path = root.join("monochange.toml");
+	let config = fs::read_to_string(&config_path)
+		.unwrap_or_else(|error| panic!("read monochange.toml: {error}"));
+	fs::write(
+		&config_path,
+		config.replace(
+			"analysis_enforcement = \"warn\"",
+			&format!("analysis_enforcement = \"{enforcement}\""),
+		),
+	)
+	.unwrap_or_else(|error| panic!("write monochange.toml: {error}"));
+	tempdir
+}
+
+fn release_dry_run(root: &Path) -> Output {
+	monochange_command(Some("2026-10-17"))
+		.current_dir(root)
+		.arg("release")
+		.arg("--dry-run")
+		.arg("--format")
+		.arg("json")
+		.output()
+		.unwrap_or_else(|error| panic!("release output: {error}"))
+}
+
+fn release_json(root: &Path) -> Value {
+	let output = release_dry_run(root);
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	serde_json::from_slice(&output.stdout)
+		.unwrap_or_else(|error| panic!("parse release json: {error}"))
+}
+
+fn core_version(json: &Value) -> Option<&str> {
+	json["releaseTargets"]
+		.as_array()
+		.and_then(|targets| targets.iter().find(|target| target["id"] == "core"))
+		.and_then(|target| target["version"].as_str())
+}
+
+#[test]
+fn analysis_enforcement_warn_keeps_the_declared_bump() {
+	let tempdir = setup_under_declared_removal("warn");
+	let json = release_json(tempdir.path());
+
+	assert_eq!(core_version(&json), Some("1.0.1"));
+	assert_eq!(
+		json["plan"]["warnings"],
+		serde_json::json!([
+			"`core` declares a patch bump but semantic analysis since `core/v1.0.0` implies major: function `render` removed"
+		])
+	);
+}
+
+#[test]
+fn analysis_enforcement_raise_applies_the_implied_bump() {
+	let tempdir = setup_under_declared_removal("raise");
+	let json = release_json(tempdir.path());
+
+	assert_eq!(core_version(&json), Some("2.0.0"));
+	let evidence = json["plan"]["compatibilityEvidence"]
+		.as_array()
+		.unwrap_or_else(|| panic!("compatibility evidence: {json}"));
+	assert!(
+		evidence.iter().any(|assessment| {
+			assessment["provider"] == "semantic-analysis" && assessment["severity"] == "major"
+		}),
+		"{json}"
+	);
+}
+
+#[test]
+fn analysis_enforcement_error_rejects_the_release() {
+	let tempdir = setup_under_declared_removal("error");
+	let output = release_dry_run(tempdir.path());
+
+	assert!(!output.status.success());
+	let stderr = String::from_utf8_lossy(&output.stderr);
+	assert!(
+		stderr.contains("changesets declare weaker bumps than semantic analysis implies"),
+		"{stderr}"
+	);
+	assert!(stderr.contains("function `render` removed"), "{stderr}");
+}
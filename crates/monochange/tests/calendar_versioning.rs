@@ -0,0 +1,72 @@
+#![allow(clippy::disallowed_methods)]
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+use serde_json::json;
+
+mod test_support;
+use test_support::json_subset;
+use test_support::release_target;
+use test_support::release_target_version;
+use test_support::run_json_command_with_args;
+use test_support::setup_scenario_workspace;
+
+fn run_release(root: &Path, date: &str, args: &[&str]) -> Value {
+	run_json_command_with_args(root, "release", args, Some(date))
+}
+
+fn version_and_tag(json: &Value, id: &str) -> Value {
+	json_subset(
+		release_target(json, id),
+		&[("version", "/version"), ("tagName", "/tagName")],
+	)
+}
+
+#[test]
+fn calendar_versioned_packages_release_from_the_release_date() {
+	let tempdir = setup_scenario_workspace("calendar-versioning/workspace");
+	let root = tempdir.path();
+
+	let json = run_release(root, "2026-10-17", &[]);
+
+	assert_eq!(
+		version_and_tag(&json, "app"),
+		json!({ "version": "2026.10.0", "tagName": "app/2026.10.0" })
+	);
+	assert_eq!(
+		version_and_tag(&json, "core"),
+		json!({ "version": "1.1.0", "tagName": "core/v1.1.0" })
+	);
+	let manifest = fs::read_to_string(root.join("crates/app/Cargo.toml"))
+		.unwrap_or_else(|error| panic!("read app manifest: {error}"));
+	assert!(manifest.contains("version = \"2026.10.0\""));
+	let changelog = fs::read_to_string(root.join("crates/app/CHANGELOG.md"))
+		.unwrap_or_else(|error| panic!("read app changelog: {error}"));
+	assert!(changelog.contains("2026.10.0 (2026-10-17)"), "{changelog}");
+}
+
+#[test]
+fn calendar_versioned_packages_increment_patch_within_the_same_month() {
+	let tempdir = setup_scenario_workspace("calendar-versioning/workspace");
+	let root = tempdir.path();
+
+	run_release(root, "2026-10-17", &[]);
+	fs::write(
+		root.join(".changeset/app-fix.md"),
+		"---\napp: major\n---\n\n#### fix app\n",
+	)
+	.unwrap_or_else(|error| panic!("write changeset: {error}"));
+
+	let prerelease = run_release(root, "2026-10-20", &["--pre", "rc", "--dry-run"]);
+	assert_eq!(
+		release_target_version(&prerelease, "app"),
+		"2026.10.1-rc.1"
+	);
+
+	let stable = run_release(root, "2026-10-20", &[]);
+	assert_eq!(
+		version_and_tag(&stable, "app"),
+		json!({ "version": "2026.10.1", "tagName": "app/2026.10.1" })
+	);
+}
@@ -207,6 +207,48 @@ fn discover_cli_json_reports_relative_paths_and_stable_ids() {
 	);
 }
 
+#[test]
+fn discover_cli_json_reports_manifests_skipped_by_ecosystem_roots_and_exclude() {
+	let tempdir = setup_scenario_workspace("cli-output/discover-scoped");
+	let json = command_stdout_json(
+		monochange_command(None)
+			.current_dir(tempdir.path())
+			.arg("discover")
+			.arg("--format")
+			.arg("json"),
+		true,
+	);
+
+	let package_ids = json["packages"]
+		.as_array()
+		.unwrap_or_else(|| panic!("packages array"))
+		.iter()
+		.filter_map(|package| package["id"].as_str())
+		.collect::<Vec<_>>();
+	assert_eq!(
+		package_ids,
+		vec!["npm:packages/api/package.json", "npm:packages/web/package.json"]
+	);
+	assert_eq!(json["dependencies"].as_array().map(Vec::len), Some(1));
+	assert_eq!(
+		json["skipped"],
+		serde_json::json!([
+			{
+				"ecosystem": "npm",
+				"path": "packages/web/examples/basic",
+				"reason": "excluded",
+				"pattern": "**/examples/**",
+			},
+			{
+				"ecosystem": "npm",
+				"path": "tools",
+				"reason": "outside_roots",
+				"pattern": null,
+			},
+		])
+	);
+}
+
 #[test]
 fn analyze_cli_text_defaults_to_group_release_tag_for_selected_package() {
 	let mut settings = snapshot_settings();
@@ -0,0 +1,56 @@
+#![allow(clippy::disallowed_methods)]
+use std::fs;
+
+use serde_json::Value;
+
+mod test_support;
+use test_support::run_json_command;
+use test_support::run_json_command_with_args;
+use test_support::setup_scenario_workspace;
+
+#[test]
+fn out_of_range_propagation_only_releases_dependents_whose_range_breaks() {
+	let tempdir = setup_scenario_workspace("dependency-propagation/workspace");
+	let json = run_json_command(tempdir.path(), "release", Some("2026-10-17"));
+
+	let mut released = json
+		.get("releaseTargets")
+		.and_then(Value::as_array)
+		.unwrap_or_else(|| panic!("release targets array"))
+		.iter()
+		.filter_map(|target| {
+			Some(format!(
+				"{}@{}",
+				target.get("id")?.as_str()?,
+				target.get("version")?.as_str()?
+			))
+		})
+		.collect::<Vec<_>>();
+	released.sort();
+
+	// `app` accepts `1.3.0` through its `1.2` requirement, so neither it nor
+	// `leaf` is released. `cli` pins `~1.2.0` and has to move.
+	assert_eq!(released, ["cli@1.0.1", "core@1.3.0"]);
+}
+
+#[test]
+fn out_of_range_propagation_leaves_in_range_manifests_untouched() {
+	let tempdir = setup_scenario_workspace("dependency-propagation/workspace");
+	let app_manifest_before = fs::read_to_string(tempdir.path().join("crates/app/Cargo.toml"))
+		.unwrap_or_else(|error| panic!("app manifest: {error}"));
+	run_json_command_with_args(tempdir.path(), "release", &[], Some("2026-10-17"));
+
+	let app_manifest = fs::read_to_string(tempdir.path().join("crates/app/Cargo.toml"))
+		.unwrap_or_else(|error| panic!("app manifest: {error}"));
+	let cli_manifest = fs::read_to_string(tempdir.path().join("crates/cli/Cargo.toml"))
+		.unwrap_or_else(|error| panic!("cli manifest: {error}"));
+	assert_eq!(app_manifest, app_manifest_before);
+	assert!(
+		cli_manifest.contains("version = \"1.0.1\""),
+		"{cli_manifest}"
+	);
+	assert!(
+		!cli_manifest.contains("~1.2.0"),
+		"cli dependency range should be rewritten: {cli_manifest}"
+	);
+}
@@ -0,0 +1,55 @@
+#![allow(clippy::disallowed_methods)]
+mod test_support;
+use test_support::run_json_command;
+use test_support::setup_scenario_workspace;
+
+#[test]
+fn linked_group_members_share_the_bump_but_keep_their_own_versions_and_tags() {
+	let tempdir = setup_scenario_workspace("linked-groups/workspace");
+	let json = run_json_command(tempdir.path(), "release", Some("2026-10-17"));
+
+	// `cli` only asked for a patch but follows the minor bump of `core`, and
+	// the untouched `docs` member is left out of the release.
+	let targets = json["releaseTargets"]
+		.as_array()
+		.unwrap_or_else(|| panic!("release targets array: {json}"))
+		.iter()
+		.map(|target| {
+			(
+				target["id"].as_str(),
+				target["kind"].as_str(),
+				target["version"].as_str(),
+				target["tagName"].as_str(),
+			)
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(
+		targets,
+		[
+			(
+				Some("cli"),
+				Some("package"),
+				Some("2.1.0"),
+				Some("cli/v2.1.0")
+			),
+			(
+				Some("core"),
+				Some("package"),
+				Some("1.5.0"),
+				Some("core/v1.5.0")
+			),
+		]
+	);
+
+	let docs = json["plan"]["decisions"]
+		.as_array()
+		.unwrap_or_else(|| panic!("decisions array: {json}"))
+		.iter()
+		.find(|decision| {
+			decision["package"]
+				.as_str()
+				.is_some_and(|package| package.contains("crates/docs"))
+		})
+		.unwrap_or_else(|| panic!("expected docs decision: {json}"));
+	assert_eq!(docs["bump"], "none");
+}
@@ -0,0 +1,88 @@
+#![allow(clippy::disallowed_methods)]
+mod test_support;
+use test_support::monochange_command;
+use test_support::release_target_version;
+use test_support::run_json_command;
+use test_support::run_json_command_with_args;
+use test_support::setup_scenario_workspace;
+
+#[test]
+fn pre_stable_policies_decide_how_zero_versions_move() {
+	let tempdir = setup_scenario_workspace("pre-stable-policy/workspace");
+	let json = run_json_command(tempdir.path(), "release", Some("2026-10-17"));
+
+	// `[defaults] pre_stable_policy = "semver"` sends the major bump to 1.0.0,
+	// while `cli` opts back into the cargo-style shift.
+	assert_eq!(release_target_version(&json, "core"), "1.0.0");
+	assert_eq!(release_target_version(&json, "cli"), "0.4.0");
+	assert_eq!(release_target_version(&json, "sdk"), "0.3.0");
+}
+
+#[test]
+fn display_versions_respects_pre_stable_policies() {
+	let tempdir = setup_scenario_workspace("pre-stable-policy/workspace");
+	let output = monochange_command(Some("2026-10-17"))
+		.current_dir(tempdir.path())
+		.arg("versions")
+		.arg("--format")
+		.arg("text")
+		.output()
+		.unwrap_or_else(|error| panic!("versions output: {error}"));
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	assert!(stdout.contains("- sdk: 0.3.0"), "{stdout}");
+	assert!(
+		stdout.contains("- cargo:crates/cli/Cargo.toml: 0.4.0"),
+		"{stdout}"
+	);
+	assert!(
+		stdout.contains("- cargo:crates/core/Cargo.toml: 1.0.0"),
+		"{stdout}"
+	);
+}
+
+#[test]
+fn graduate_releases_one_point_zero_for_packages_and_groups() {
+	let tempdir = setup_scenario_workspace("pre-stable-policy/workspace");
+	let json = run_json_command_with_args(
+		tempdir.path(),
+		"release",
+		&["--dry-run", "--graduate", "cli", "--graduate", "plugin"],
+		Some("2026-10-17"),
+	);
+
+	assert_eq!(release_target_version(&json, "cli"), "1.0.0");
+	assert_eq!(release_target_version(&json, "sdk"), "1.0.0");
+	assert_eq!(release_target_version(&json, "core"), "1.0.0");
+}
+
+#[test]
+fn graduate_rejects_owners_without_a_pending_release_or_already_stable() {
+	let tempdir = setup_scenario_workspace("pre-stable-policy/workspace");
+	for (owner, message) in [
+		(
+			"extras",
+			"cannot graduate `extras` because it has no pending release",
+		),
+		(
+			"stable",
+			"cannot graduate `stable`: `policy-stable` is already at 1.2.0",
+		),
+	] {
+		let output = monochange_command(Some("2026-10-17"))
+			.current_dir(tempdir.path())
+			.arg("release")
+			.arg("--dry-run")
+			.arg("--graduate")
+			.arg(owner)
+			.output()
+			.unwrap_or_else(|error| panic!("release output: {error}"));
+		assert!(!output.status.success());
+		let stderr = String::from_utf8_lossy(&output.stderr);
+		assert!(stderr.contains(message), "{stderr}");
+	}
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::disallowed_methods)]
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+use serde_json::json;
+
+mod test_support;
+use test_support::json_subset;
+use test_support::monochange_command;
+use test_support::release_target;
+use test_support::release_target_version;
+use test_support::run_json_command_with_args;
+use test_support::setup_scenario_workspace;
+
+fn run_release(root: &Path, args: &[&str]) -> Value {
+	run_json_command_with_args(root, "release", args, Some("2026-04-06"))
+}
+
+fn read_manifest(root: &Path, package: &str) -> String {
+	fs::read_to_string(root.join("crates").join(package).join("Cargo.toml"))
+		.unwrap_or_else(|error| panic!("read {package} manifest: {error}"))
+}
+
+#[test]
+fn prerelease_channel_increments_until_exit() {
+	let tempdir = setup_scenario_workspace("prerelease-channels/workspace");
+	let root = tempdir.path();
+
+	let first = run_release(root, &["--pre", "beta"]);
+	assert_eq!(release_target_version(&first, "core"), "2.0.0-beta.1");
+	assert!(read_manifest(root, "core").contains("version = \"2.0.0-beta.1\""));
+	assert!(root.join(".changeset/breaking-core.md").exists());
+	assert!(root.join(".changeset/pre.json").exists());
+
+	fs::write(
+		root.join(".changeset/fix-core.md"),
+		"---\ncore: patch\n---\n\n#### fix core\n",
+	)
+	.unwrap_or_else(|error| panic!("write changeset: {error}"));
+	let second = run_release(root, &[]);
+	assert_eq!(release_target_version(&second, "core"), "2.0.0-beta.2");
+	assert!(read_manifest(root, "core").contains("version = \"2.0.0-beta.2\""));
+
+	let stable = run_release(root, &["--exit-pre"]);
+	assert_eq!(release_target_version(&stable, "core"), "2.0.0");
+	assert!(read_manifest(root, "core").contains("version = \"2.0.0\""));
+	assert!(!root.join(".changeset/breaking-core.md").exists());
+	assert!(!root.join(".changeset/fix-core.md").exists());
+	assert!(!root.join(".changeset/pre.json").exists());
+}
+
+#[test]
+fn prerelease_channel_can_be_scoped_to_one_package() {
+	let tempdir = setup_scenario_workspace("prerelease-channels/workspace");
+	let root = tempdir.path();
+
+	let json = run_release(root, &["--pre", "alpha", "--pre-package", "core"]);
+	assert_eq!(
+		json_subset(
+			release_target(&json, "core"),
+			&[("version", "/version"), ("prerelease", "/prerelease")]
+		),
+		json!({ "version": "2.0.0-alpha.1", "prerelease": "alpha" })
+	);
+	assert_eq!(release_target_version(&json, "app"), "1.0.1");
+}
+
+#[test]
+fn prerelease_rejects_conflicting_inputs() {
+	let tempdir = setup_scenario_workspace("prerelease-channels/workspace");
+	let output = monochange_command(Some("2026-04-06"))
+		.current_dir(tempdir.path())
+		.arg("release")
+		.arg("--pre")
+		.arg("beta")
+		.arg("--exit-pre")
+		.output()
+		.unwrap_or_else(|error| panic!("release output: {error}"));
+	assert!(!output.status.success());
+	assert!(
+		String::from_utf8_lossy(&output.stderr)
+			.contains("`pre` and `exit_pre` cannot be used together")
+	);
+}
@@ -0,0 +1,69 @@
+#![allow(clippy::disallowed_methods)]
+use serde_json::Value;
+
+mod test_support;
+use test_support::monochange_command;
+use test_support::setup_scenario_workspace;
+
+#[test]
+fn release_explain_prints_the_causal_chain_for_a_package() {
+	let tempdir = setup_scenario_workspace("release-explain/workspace");
+	let output = monochange_command(Some("2026-10-17"))
+		.current_dir(tempdir.path())
+		.arg("release")
+		.arg("explain")
+		.arg("sdk-a")
+		.arg("--format")
+		.arg("text")
+		.output()
+		.unwrap_or_else(|error| panic!("release explain output: {error}"));
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	assert!(
+		stdout.contains(
+			"  sdk-a: minor -> 1.1.0 (version-group-synchronization)\n    depends on `api`\n      api: patch -> 1.0.1 (transitive-dependency)\n        depends on `core`\n"
+		),
+		"{stdout}"
+	);
+	assert!(
+		stdout.contains(
+			"    shares version group `sdk` with\n      sdk-b: minor -> 1.1.0 (direct-change)\n        changeset .changeset/sdk-helpers.md requests minor: add sdk helpers\n"
+		),
+		"{stdout}"
+	);
+
+	// Explaining a release must not consume the changesets.
+	assert!(tempdir.path().join(".changeset/sdk-helpers.md").exists());
+}
+
+#[test]
+fn release_explain_renders_json() {
+	let tempdir = setup_scenario_workspace("release-explain/workspace");
+	let output = monochange_command(Some("2026-10-17"))
+		.current_dir(tempdir.path())
+		.arg("release")
+		.arg("explain")
+		.arg("docs")
+		.arg("--format")
+		.arg("json")
+		.output()
+		.unwrap_or_else(|error| panic!("release explain output: {error}"));
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	let json: Value = serde_json::from_slice(&output.stdout)
+		.unwrap_or_else(|error| panic!("parse release explain json: {error}"));
+	assert_eq!(json["package"]["id"], "docs");
+	assert_eq!(json["package"]["bump"], "patch");
+	assert_eq!(json["heldBack"][0]["id"], "sdk-a");
+	assert_eq!(
+		json["heldBack"][0]["reason"],
+		"suppressed by `caused_by` in `.changeset/docs-sdk.md`"
+	);
+}
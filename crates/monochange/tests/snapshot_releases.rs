@@ -0,0 +1,110 @@
+#![allow(clippy::disallowed_methods)]
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_test_helpers::git;
+use monochange_test_helpers::git_output_trimmed;
+use serde_json::Value;
+use tempfile::TempDir;
+
+mod test_support;
+use test_support::monochange_command;
+use test_support::release_target_version;
+use test_support::run_json_command_with_args;
+use test_support::setup_scenario_workspace;
+
+fn setup_snapshot_repo() -> TempDir {
+	let tempdir = setup_scenario_workspace("snapshot-releases/workspace");
+	let root = tempdir.path();
+	git(root, &["init", "-b", "main"]);
+	git(root, &["config", "user.name", "monochange tests"]);
+	git(root, &["config", "user.email", "monochange@example.com"]);
+	git(root, &["config", "commit.gpgsign", "false"]);
+	git(root, &["add", "."]);
+	git(root, &["commit", "-m", "initial"]);
+	tempdir
+}
+
+fn read_manifest(root: &Path, package: &str) -> String {
+	fs::read_to_string(root.join("crates").join(package).join("Cargo.toml"))
+		.unwrap_or_else(|error| panic!("read {package} manifest: {error}"))
+}
+
+#[test]
+fn snapshot_release_rewrites_a_temporary_working_tree() {
+	let tempdir = setup_snapshot_repo();
+	let root = tempdir.path();
+	let json = run_json_command_with_args(root, "snapshot", &[], Some("2026-10-17"));
+
+	assert_eq!(
+		release_target_version(&json, "core"),
+		"2.0.0-canary.20261017"
+	);
+	assert_eq!(
+		release_target_version(&json, "app"),
+		"1.0.1-canary.20261017"
+	);
+	assert!(read_manifest(root, "core").contains("version = \"1.0.0\""));
+	assert!(root.join(".changeset/breaking-core.md").exists());
+	assert_eq!(
+		json.get("deletedChangesets").and_then(Value::as_array),
+		Some(&Vec::new())
+	);
+	assert_eq!(git_output_trimmed(root, &["status", "--porcelain"]), "");
+}
+
+#[test]
+fn snapshot_release_template_context_exposes_the_working_tree() {
+	let tempdir = setup_snapshot_repo();
+	let root = tempdir.path();
+	let config_path = root.join("monochange.toml");
+	let mut config =
+		fs::read_to_string(&config_path).unwrap_or_else(|error| panic!("read config: {error}"));
+	config.push_str(
+		"\n[[cli.snapshot.steps]]\ntype = \"Command\"\ncommand = \"echo {{ release.snapshot.working_tree }}\"\nshell = true\n",
+	);
+	fs::write(&config_path, config).unwrap_or_else(|error| panic!("write config: {error}"));
+
+	let output = monochange_command(Some("2026-10-17"))
+		.current_dir(root)
+		.arg("snapshot")
+		.output()
+		.unwrap_or_else(|error| panic!("snapshot output: {error}"));
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	let working_tree = stdout
+		.lines()
+		.map(|line| line.trim().trim_start_matches("- "))
+		.find(|line| line.contains("monochange-snapshot-"))
+		.map_or_else(
+			|| panic!("expected snapshot working tree in output:\n{stdout}"),
+			PathBuf::from,
+		);
+	assert!(read_manifest(&working_tree, "core").contains("version = \"2.0.0-canary.20261017\""));
+	assert!(
+		read_manifest(&working_tree, "app").contains(
+			"workflow-core = { path = \"../core\", version = \"2.0.0-canary.20261017\" }"
+		)
+	);
+	fs::remove_dir_all(&working_tree).unwrap_or_else(|error| panic!("cleanup: {error}"));
+}
+
+#[test]
+fn snapshot_release_cannot_be_committed() {
+	let tempdir = setup_snapshot_repo();
+	let output = monochange_command(Some("2026-10-17"))
+		.current_dir(tempdir.path())
+		.arg("snapshot-commit")
+		.output()
+		.unwrap_or_else(|error| panic!("snapshot-commit output: {error}"));
+	assert!(!output.status.success());
+	assert!(
+		String::from_utf8_lossy(&output.stderr)
+			.contains("`CommitRelease` cannot run after a snapshot `PrepareRelease`")
+	);
+}
@@ -387,10 +387,21 @@ pub fn run_in_tty(
 
 #[allow(dead_code)]
 pub fn run_json_command(root: &Path, command: &str, release_date: Option<&str>) -> Value {
+	run_json_command_with_args(root, command, &["--dry-run"], release_date)
+}
+
+/// Run `command` with `args` instead of `--dry-run` and parse its JSON output.
+#[allow(dead_code)]
+pub fn run_json_command_with_args(
+	root: &Path,
+	command: &str,
+	args: &[&str],
+	release_date: Option<&str>,
+) -> Value {
 	let output = monochange_command(release_date)
 		.current_dir(root)
 		.arg(command)
-		.arg("--dry-run")
+		.args(args)
 		.arg("--format")
 		.arg("json")
 		.output()
@@ -416,6 +427,24 @@ pub fn json_subset(value: &Value, fields: &[(&str, &str)]) -> Value {
 	Value::Object(subset)
 }
 
+#[allow(dead_code)]
+pub fn release_target<'a>(json: &'a Value, id: &str) -> &'a Value {
+	json.get("releaseTargets")
+		.and_then(Value::as_array)
+		.unwrap_or_else(|| panic!("release targets array: {json}"))
+		.iter()
+		.find(|target| target.get("id").and_then(Value::as_str) == Some(id))
+		.unwrap_or_else(|| panic!("expected release target `{id}`: {json}"))
+}
+
+#[allow(dead_code)]
+pub fn release_target_version<'a>(json: &'a Value, id: &str) -> &'a str {
+	release_target(json, id)
+		.get("version")
+		.and_then(Value::as_str)
+		.unwrap_or_else(|| panic!("expected a version for release target `{id}`"))
+}
+
 #[cfg(test)]
 mod tests {
 	use std::fs;
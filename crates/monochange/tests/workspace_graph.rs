@@ -0,0 +1,71 @@
+#![allow(clippy::disallowed_methods)]
+use serde_json::Value;
+
+mod test_support;
+use test_support::monochange_command;
+use test_support::setup_scenario_workspace;
+
+#[test]
+fn graph_prints_a_dot_graph_colored_by_the_release_plan() {
+	let tempdir = setup_scenario_workspace("workspace-graph/workspace");
+	let output = monochange_command(Some("2026-10-17"))
+		.current_dir(tempdir.path())
+		.arg("graph")
+		.arg("--plan")
+		.output()
+		.unwrap_or_else(|error| panic!("graph output: {error}"));
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	let stdout = String::from_utf8_lossy(&output.stdout);
+	assert!(stdout.starts_with("digraph workspace {\n"), "{stdout}");
+	assert!(
+		stdout.contains(
+			"\t\"core\" [label=\"core\\n1.0.0 -> 1.1.0 (minor)\", fillcolor=\"#fff3cd\"];\n"
+		),
+		"{stdout}"
+	);
+
+	// Overlaying the plan must not consume the changesets.
+	assert!(tempdir.path().join(".changeset/core-cache.md").exists());
+}
+
+#[test]
+fn graph_filters_json_output_by_package_and_ecosystem() {
+	let tempdir = setup_scenario_workspace("workspace-graph/workspace");
+	let output = monochange_command(Some("2026-10-17"))
+		.current_dir(tempdir.path())
+		.arg("graph")
+		.arg("--format")
+		.arg("json")
+		.arg("--ecosystem")
+		.arg("cargo")
+		.arg("--package")
+		.arg("api")
+		.arg("--publish-levels")
+		.output()
+		.unwrap_or_else(|error| panic!("graph output: {error}"));
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	let value = serde_json::from_slice::<Value>(&output.stdout)
+		.unwrap_or_else(|error| panic!("parse graph json: {error}"));
+	let nodes = value["nodes"]
+		.as_array()
+		.unwrap_or_else(|| panic!("expected nodes: {value}"))
+		.iter()
+		.map(|node| (node["id"].as_str(), node["publishLevel"].as_u64()))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		nodes,
+		[
+			(Some("api"), Some(1)),
+			(Some("core"), Some(0)),
+			(Some("testkit"), Some(2)),
+		]
+	);
+}
@@ -612,3 +612,45 @@ fn git_and_snapshot_helpers_cover_remaining_error_paths() {
 		.render();
 	assert!(utf8_error.contains("invalid utf-8"));
 }
+
+#[test]
+fn compatibility_assessment_cites_the_strongest_semantic_change() {
+	let change = |kind, item_path: &str, file_path: &str| {
+		SemanticChange {
+			category: SemanticChangeCategory::PublicApi,
+			kind,
+			item_kind: "function".to_string(),
+			item_path: item_path.to_string(),
+			summary: format!("function `{item_path}` changed"),
+			file_path: PathBuf::from(file_path),
+			before_signature: None,
+			after_signature: None,
+		}
+	};
+	let mut analysis = PackageChangeAnalysis {
+		package_id: "core".to_string(),
+		package_record_id: "cargo:crates/core/Cargo.toml".to_string(),
+		package_name: "core".to_string(),
+		ecosystem: Ecosystem::Cargo,
+		analyzer_id: Some("cargo".to_string()),
+		changed_files: vec![PathBuf::from("src/lib.rs")],
+		semantic_changes: vec![
+			change(SemanticChangeKind::Added, "stream", "src/stream.rs"),
+			change(SemanticChangeKind::Removed, "render", "src/lib.rs"),
+			change(SemanticChangeKind::Removed, "parse", "src/lib.rs"),
+		],
+		warnings: Vec::new(),
+	};
+
+	let assessment = analysis
+		.compatibility_assessment()
+		.unwrap_or_else(|| panic!("expected an assessment"));
+	assert_eq!(assessment.package_id, "cargo:crates/core/Cargo.toml");
+	assert_eq!(assessment.provider_id, SEMANTIC_ANALYSIS_PROVIDER_ID);
+	assert_eq!(assessment.severity, monochange_core::BumpSeverity::Major);
+	assert_eq!(assessment.summary, "function `render` changed (and 1 more)");
+	assert_eq!(assessment.evidence_location.as_deref(), Some("src/lib.rs"));
+
+	analysis.semantic_changes.clear();
+	assert!(analysis.compatibility_assessment().is_none());
+}
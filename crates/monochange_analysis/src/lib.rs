@@ -6,9 +6,9 @@
 //! git change frame.
 //!
 //! Core contracts and semantic diff types live in `monochange_core`. Ecosystem
-//! crates implement analyzers. Cargo, npm, Deno, and Dart/Flutter analyzers all
-//! plug into the same contract without moving ecosystem logic back into this
-//! crate.
+//! crates implement analyzers. Cargo, npm, Deno, Dart/Flutter, Python, and Go
+//! analyzers all plug into the same contract without moving ecosystem logic
+//! back into this crate.
 //!
 //! This crate is responsible for:
 //!
@@ -29,6 +29,7 @@ use std::process::Command;
 use monochange_cargo::semantic_analyzer as cargo_semantic_analyzer;
 use monochange_config::apply_version_groups;
 use monochange_config::load_workspace_configuration;
+use monochange_core::CompatibilityAssessment;
 use monochange_core::Ecosystem;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
@@ -42,8 +43,12 @@ use monochange_core::relative_to_root;
 use monochange_dart::semantic_analyzer as dart_semantic_analyzer;
 #[cfg(feature = "deno")]
 use monochange_deno::semantic_analyzer as deno_semantic_analyzer;
+#[cfg(feature = "go")]
+use monochange_go::semantic_analyzer as go_semantic_analyzer;
 #[cfg(feature = "npm")]
 use monochange_npm::semantic_analyzer as npm_semantic_analyzer;
+#[cfg(feature = "python")]
+use monochange_python::semantic_analyzer as python_semantic_analyzer;
 use serde::Deserialize;
 use serde::Serialize;
 use walkdir::WalkDir;
@@ -62,6 +67,10 @@ pub use monochange_core::SemanticChange;
 pub use monochange_core::SemanticChangeCategory;
 pub use monochange_core::SemanticChangeKind;
 
+/// Provider id attached to compatibility evidence derived from semantic
+/// analysis.
+pub const SEMANTIC_ANALYSIS_PROVIDER_ID: &str = "semantic-analysis";
+
 /// Placeholder grouping configuration reserved for future lifecycle tooling.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -122,6 +131,43 @@ pub struct PackageChangeAnalysis {
 	pub warnings: Vec<String>,
 }
 
+impl PackageChangeAnalysis {
+	/// Summarize the semantic diffs as compatibility evidence for release
+	/// planning.
+	///
+	/// The assessment carries the strongest bump implied by any semantic
+	/// change and cites the change that implies it. Packages whose changes do
+	/// not require a release produce no assessment.
+	#[must_use]
+	pub fn compatibility_assessment(&self) -> Option<CompatibilityAssessment> {
+		let severity = self
+			.semantic_changes
+			.iter()
+			.map(SemanticChange::implied_bump)
+			.max()
+			.filter(|severity| severity.is_release())?;
+		let mut supporting = self
+			.semantic_changes
+			.iter()
+			.filter(|change| change.implied_bump() == severity);
+		let strongest = supporting.next()?;
+		let supporting = 1 + supporting.count();
+		let summary = match supporting {
+			1 => strongest.summary.clone(),
+			_ => format!("{} (and {} more)", strongest.summary, supporting - 1),
+		};
+
+		Some(CompatibilityAssessment {
+			package_id: self.package_record_id.clone(),
+			provider_id: SEMANTIC_ANALYSIS_PROVIDER_ID.to_string(),
+			severity,
+			confidence: "medium".to_string(),
+			summary,
+			evidence_location: Some(strongest.file_path.display().to_string()),
+		})
+	}
+}
+
 /// Complete semantic analysis for the requested frame.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -189,6 +235,10 @@ impl AnalyzerRegistry {
 		registry.register(Box::new(deno_semantic_analyzer()));
 		#[cfg(feature = "dart")]
 		registry.register(Box::new(dart_semantic_analyzer()));
+		#[cfg(feature = "python")]
+		registry.register(Box::new(python_semantic_analyzer()));
+		#[cfg(feature = "go")]
+		registry.register(Box::new(go_semantic_analyzer()));
 
 		registry
 	}
@@ -446,6 +496,20 @@ fn discover_analysis_workspace(root: &Path) -> MonochangeResult<AnalysisWorkspac
 		packages.extend(discovery.packages);
 	}
 
+	#[cfg(feature = "python")]
+	{
+		let discovery = monochange_python::discover_python_packages(root)?;
+		warnings.extend(discovery.warnings);
+		packages.extend(discovery.packages);
+	}
+
+	#[cfg(feature = "go")]
+	{
+		let discovery = monochange_go::discover_go_modules(root)?;
+		warnings.extend(discovery.warnings);
+		packages.extend(discovery.packages);
+	}
+
 	normalize_package_ids(root, &mut packages);
 	packages.sort_by(|left, right| left.id.cmp(&right.id));
 	packages.dedup_by(|left, right| left.id == right.id);
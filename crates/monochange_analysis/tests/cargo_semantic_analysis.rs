@@ -116,3 +116,63 @@ fn analyze_release_trajectory_reports_release_main_and_head_frames() {
 		assert_json_snapshot!(analysis);
 	});
 }
+
+#[test]
+fn analyze_changes_reports_python_public_api_and_pyproject_diffs() {
+	let before = fixture_path("analysis/python-public-api-diff/before");
+	let after = fixture_path("analysis/python-public-api-diff/after");
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+
+	copy_directory(&before, tempdir.path());
+	git(tempdir.path(), &["init"]);
+	git(tempdir.path(), &["config", "user.name", "monochange-tests"]);
+	git(
+		tempdir.path(),
+		&["config", "user.email", "monochange-tests@example.com"],
+	);
+	git(tempdir.path(), &["add", "."]);
+	git(tempdir.path(), &["commit", "-m", "base"]);
+
+	copy_directory(&after, tempdir.path());
+
+	let analysis = analyze_changes(
+		tempdir.path(),
+		&ChangeFrame::WorkingDirectory,
+		&AnalysisConfig::default(),
+	)
+	.unwrap_or_else(|error| panic!("analysis: {error}"));
+
+	snapshot_settings().bind(|| {
+		assert_json_snapshot!(analysis);
+	});
+}
+
+#[test]
+fn analyze_changes_reports_go_exported_api_and_go_mod_diffs() {
+	let before = fixture_path("analysis/go-exported-api-diff/before");
+	let after = fixture_path("analysis/go-exported-api-diff/after");
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+
+	copy_directory(&before, tempdir.path());
+	git(tempdir.path(), &["init"]);
+	git(tempdir.path(), &["config", "user.name", "monochange-tests"]);
+	git(
+		tempdir.path(),
+		&["config", "user.email", "monochange-tests@example.com"],
+	);
+	git(tempdir.path(), &["add", "."]);
+	git(tempdir.path(), &["commit", "-m", "base"]);
+
+	copy_directory(&after, tempdir.path());
+
+	let analysis = analyze_changes(
+		tempdir.path(),
+		&ChangeFrame::WorkingDirectory,
+		&AnalysisConfig::default(),
+	)
+	.unwrap_or_else(|error| panic!("analysis: {error}"));
+
+	snapshot_settings().bind(|| {
+		assert_json_snapshot!(analysis);
+	});
+}
@@ -1,4 +1,5 @@
 use monochange_core::AnalyzedFileChange;
+use monochange_core::BumpSeverity;
 use monochange_core::FileChangeKind;
 
 use super::*;
@@ -181,6 +182,7 @@ fn module_prefix_and_symbol_diff_cover_root_removed_and_unchanged_paths() {
 				item_path: "greet".to_string(),
 				signature: "pub fn greet()".to_string(),
 				file_path: PathBuf::from("src/lib.rs"),
+				..PublicSymbol::default()
 			},
 		),
 		(
@@ -190,6 +192,7 @@ fn module_prefix_and_symbol_diff_cover_root_removed_and_unchanged_paths() {
 				item_path: "Greeter".to_string(),
 				signature: "pub struct Greeter;".to_string(),
 				file_path: PathBuf::from("src/lib.rs"),
+				..PublicSymbol::default()
 			},
 		),
 	]);
@@ -201,6 +204,7 @@ fn module_prefix_and_symbol_diff_cover_root_removed_and_unchanged_paths() {
 				item_path: "greet".to_string(),
 				signature: "pub fn greet(name: &str)".to_string(),
 				file_path: PathBuf::from("src/lib.rs"),
+				..PublicSymbol::default()
 			},
 		),
 		(
@@ -210,6 +214,7 @@ fn module_prefix_and_symbol_diff_cover_root_removed_and_unchanged_paths() {
 				item_path: "LIMIT".to_string(),
 				signature: "pub const LIMIT: usize = 3;".to_string(),
 				file_path: PathBuf::from("src/lib.rs"),
+				..PublicSymbol::default()
 			},
 		),
 	]);
@@ -254,6 +259,63 @@ fn snapshot_public_symbols_collects_snapshot_parse_warnings() {
 	);
 }
 
+#[test]
+fn snapshot_public_symbols_resolves_impls_on_types_that_share_a_name() {
+	let file = |path: &str, contents: &str| {
+		PackageSnapshotFile {
+			path: PathBuf::from(path),
+			contents: contents.to_string(),
+		}
+	};
+	let snapshot = PackageSnapshot {
+		label: "HEAD".to_string(),
+		files: vec![
+			file(
+				"src/lib.rs",
+				concat!(
+					"pub mod a;\n",
+					"pub mod b;\n",
+					"use a::Widget;\n",
+					"impl Widget { pub fn render(&self) {} }\n",
+				),
+			),
+			file(
+				"src/a.rs",
+				"pub struct Widget;\nimpl Widget { pub fn new() -> Self { Self } }\n",
+			),
+			file(
+				"src/b.rs",
+				concat!(
+					"pub struct Widget;\n",
+					"impl Clone for Widget { fn clone(&self) -> Self { Self } }\n",
+					"impl super::a::Widget { pub fn reset(&mut self) {} }\n",
+				),
+			),
+		],
+	};
+
+	let (symbols, warnings) =
+		snapshot_public_symbols(Some(&snapshot), &[], DetectionLevel::Signature);
+
+	let impls = symbols
+		.keys()
+		.filter(|(kind, _)| kind == "method" || kind == "trait_impl")
+		.map(|(kind, path)| (kind.as_str(), path.as_str()))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		impls,
+		[
+			("method", "a::Widget::new"),
+			("method", "a::Widget::reset"),
+			("trait_impl", "<b::Widget as Clone>"),
+		]
+	);
+	assert_eq!(
+		warnings,
+		["skipped impl for ambiguous type `Widget` in src/lib.rs: could be `a::Widget`, `b::Widget`"]
+	);
+}
+
 #[test]
 fn manifest_helpers_cover_parse_failures_removed_entries_and_table_values() {
 	let mut warnings = Vec::new();
@@ -314,6 +376,7 @@ fn module_prefix_diff_and_manifest_helpers_cover_remaining_branches() {
 				item_path: "greet".to_string(),
 				signature: "pub fn greet()".to_string(),
 				file_path: PathBuf::from("src/lib.rs"),
+				..PublicSymbol::default()
 			},
 		),
 		(
@@ -323,6 +386,7 @@ fn module_prefix_diff_and_manifest_helpers_cover_remaining_branches() {
 				item_path: "Greeter".to_string(),
 				signature: "pub struct Greeter;".to_string(),
 				file_path: PathBuf::from("src/lib.rs"),
+				..PublicSymbol::default()
 			},
 		),
 	]);
@@ -333,6 +397,7 @@ fn module_prefix_diff_and_manifest_helpers_cover_remaining_branches() {
 			item_path: "greet".to_string(),
 			signature: "pub fn greet()".to_string(),
 			file_path: PathBuf::from("src/lib.rs"),
+			..PublicSymbol::default()
 		},
 	)]);
 
@@ -346,3 +411,201 @@ fn module_prefix_diff_and_manifest_helpers_cover_remaining_branches() {
 	assert!(change.summary.contains("removed"));
 	assert_eq!(describe_manifest_value(&Value::Boolean(true)), "true");
 }
+
+fn diff_library_sources(before: &str, after: &str) -> Vec<SemanticChange> {
+	let snapshot = |label: &str, contents: &str| {
+		PackageSnapshot {
+			label: label.to_string(),
+			files: vec![PackageSnapshotFile {
+				path: PathBuf::from("src/lib.rs"),
+				contents: contents.to_string(),
+			}],
+		}
+	};
+	let before_snapshot = snapshot("before", before);
+	let after_snapshot = snapshot("after", after);
+	let (before_symbols, before_warnings) =
+		snapshot_public_symbols(Some(&before_snapshot), &[], DetectionLevel::Signature);
+	let (after_symbols, after_warnings) =
+		snapshot_public_symbols(Some(&after_snapshot), &[], DetectionLevel::Signature);
+	assert!(before_warnings.is_empty() && after_warnings.is_empty());
+
+	diff_public_symbols(&before_symbols, &after_symbols)
+}
+
+fn find_change<'a>(changes: &'a [SemanticChange], summary: &str) -> &'a SemanticChange {
+	changes
+		.iter()
+		.find(|change| change.summary == summary)
+		.unwrap_or_else(|| panic!("expected `{summary}` in {changes:#?}"))
+}
+
+#[test]
+fn type_level_diff_reports_signature_and_generic_bound_changes_as_breaking() {
+	let changes = diff_library_sources(
+		concat!(
+			"pub fn greet<T: Clone>(value: T) {}\n",
+			"pub fn render(name: &str) -> String { name.into() }\n",
+			"pub struct Greeter;\n",
+			"impl Greeter { pub fn new() -> Self { Self } }\n",
+		),
+		concat!(
+			"pub fn greet<T: Clone + Send>(value: T) {}\n",
+			"pub fn render(name: &str, width: usize) -> String { name.into() }\n",
+			"pub struct Greeter;\n",
+			"impl Greeter { pub fn new(name: &str) -> Self { Self } }\n",
+		),
+	);
+
+	let bounds = find_change(&changes, "generic bounds of function `greet` modified");
+	assert_eq!(bounds.before_signature.as_deref(), Some("< T : Clone >"));
+	assert_eq!(
+		bounds.after_signature.as_deref(),
+		Some("< T : Clone + Send >")
+	);
+	let render = find_change(&changes, "function `render` modified");
+	let method = find_change(&changes, "method `Greeter::new` modified");
+	for change in [bounds, render, method] {
+		assert_eq!(change.kind, SemanticChangeKind::Modified);
+		assert_eq!(change.implied_bump(), BumpSeverity::Major);
+	}
+	assert_eq!(changes.len(), 3);
+}
+
+#[test]
+fn type_level_diff_treats_new_fields_and_variants_as_breaking_only_for_exhaustive_types() {
+	let changes = diff_library_sources(
+		concat!(
+			"pub struct Options { pub name: String }\n",
+			"#[non_exhaustive]\n",
+			"pub struct Settings { pub name: String }\n",
+			"pub struct Handle { pub id: u32, inner: u64 }\n",
+			"pub enum Mode { Fast }\n",
+			"#[non_exhaustive]\n",
+			"pub enum Level { Low }\n",
+		),
+		concat!(
+			"pub struct Options { pub name: String, pub width: usize }\n",
+			"#[non_exhaustive]\n",
+			"pub struct Settings { pub name: String, pub width: usize }\n",
+			"pub struct Handle { pub id: u32, pub label: String, inner: u64 }\n",
+			"pub enum Mode { Fast, Slow }\n",
+			"#[non_exhaustive]\n",
+			"pub enum Level { Low, High }\n",
+		),
+	);
+
+	let field = find_change(
+		&changes,
+		"field `width` added to exhaustive struct `Options`",
+	);
+	assert_eq!(field.item_kind, "struct");
+	assert_eq!(field.implied_bump(), BumpSeverity::Major);
+	let variant = find_change(&changes, "variant `Slow` added to exhaustive enum `Mode`");
+	assert_eq!(variant.item_path, "Mode");
+	assert_eq!(variant.implied_bump(), BumpSeverity::Major);
+	for summary in [
+		"field `Settings::width` added",
+		"field `Handle::label` added",
+		"variant `Level::High` added",
+	] {
+		assert_eq!(
+			find_change(&changes, summary).implied_bump(),
+			BumpSeverity::Minor
+		);
+	}
+	assert_eq!(changes.len(), 5);
+}
+
+#[test]
+fn type_level_diff_flags_required_trait_items_and_lost_exhaustiveness() {
+	let changes = diff_library_sources(
+		concat!(
+			"pub trait Renderer { fn render(&self) -> String; fn flush(&self) {} }\n",
+			"pub struct Options { pub name: String }\n",
+			"pub enum Mode { Fast }\n",
+		),
+		concat!(
+			"pub trait Renderer {\n",
+			"\tfn render(&self) -> String;\n",
+			"\tfn flush(&self);\n",
+			"\tfn width(&self) -> usize;\n",
+			"\tfn height(&self) -> usize { 0 }\n",
+			"\ttype Output;\n",
+			"}\n",
+			"pub struct Options { pub name: String, cache: Vec<u8> }\n",
+			"#[non_exhaustive]\n",
+			"pub enum Mode { Fast }\n",
+		),
+	);
+
+	for summary in [
+		"required method `width` added to trait `Renderer`",
+		"required associated type `Output` added to trait `Renderer`",
+		"trait_method `Renderer::flush` lost its default and is now required",
+		"struct `Options` is no longer exhaustive",
+		"enum `Mode` is no longer exhaustive",
+	] {
+		assert_eq!(
+			find_change(&changes, summary).implied_bump(),
+			BumpSeverity::Major
+		);
+	}
+	assert_eq!(
+		find_change(&changes, "trait_method `Renderer::height` added").implied_bump(),
+		BumpSeverity::Minor
+	);
+	assert_eq!(changes.len(), 6);
+}
+
+#[test]
+fn type_level_diff_tracks_derived_and_manual_trait_impls() {
+	let changes = diff_library_sources(
+		concat!(
+			"#[derive(Clone, Debug)]\n",
+			"pub struct Greeter;\n",
+			"impl std::fmt::Display for Greeter {\n",
+			"\tfn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { Ok(()) }\n",
+			"}\n",
+			"impl Default for Greeter { fn default() -> Self { Self } }\n",
+			"struct Hidden;\n",
+			"impl Clone for Hidden { fn clone(&self) -> Self { Self } }\n",
+		),
+		concat!(
+			"#[derive(Debug)]\n",
+			"pub struct Greeter;\n",
+			"impl Clone for Greeter { fn clone(&self) -> Self { Self } }\n",
+			"impl<T: Into<String>> From<T> for Greeter { fn from(_: T) -> Self { Self } }\n",
+			"impl Default for Greeter { fn default() -> Self { Self } }\n",
+			"struct Hidden;\n",
+		),
+	);
+
+	let removed = find_change(&changes, "trait_impl `<Greeter as Display>` removed");
+	assert_eq!(removed.implied_bump(), BumpSeverity::Major);
+	let added = find_change(&changes, "trait_impl `<Greeter as From < T >>` added");
+	assert_eq!(added.implied_bump(), BumpSeverity::Minor);
+	assert_eq!(changes.len(), 2);
+}
+
+#[test]
+fn type_level_diff_ignores_docs_bodies_private_members_and_constant_values() {
+	let changes = diff_library_sources(
+		concat!(
+			"/// Greets.\n",
+			"pub fn greet() -> u32 { 1 }\n",
+			"pub const LIMIT: usize = 3;\n",
+			"pub struct Handle { pub id: u32, inner: u64 }\n",
+			"pub mod api { pub fn render() {} }\n",
+		),
+		concat!(
+			"/// Greets politely.\n",
+			"pub fn greet() -> u32 { 2 }\n",
+			"pub const LIMIT: usize = 4;\n",
+			"pub struct Handle { pub id: u32, inner: u64, cache: Vec<u8> }\n",
+			"pub mod api { pub fn render() { helper(); } fn helper() {} }\n",
+		),
+	);
+
+	assert!(changes.is_empty(), "unexpected changes: {changes:#?}");
+}
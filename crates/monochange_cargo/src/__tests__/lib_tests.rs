@@ -5,6 +5,7 @@ use std::path::PathBuf;
 
 use monochange_core::ChangeSignal;
 use monochange_core::CompatibilityAssessment;
+use monochange_core::DiscoveryPathFilter;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::PackageRecord;
@@ -1348,10 +1349,13 @@ fn cargo_manifest_helpers_cover_workspace_and_error_paths() {
 		tempdir.path().join("crates/core/Cargo.toml"),
 	)
 	.unwrap_or_else(|error| panic!("copy package manifest: {error}"));
-	let discovery_error =
-		discover_workspace_packages(&tempdir.path().join("invalid-workspace.toml"))
-			.err()
-			.unwrap_or_else(|| panic!("expected invalid package discovery error"));
+	let discovery_error = discover_workspace_packages(
+		&tempdir.path().join("invalid-workspace.toml"),
+		&DiscoveryPathFilter::new(tempdir.path()),
+		&mut Vec::new(),
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected invalid package discovery error"));
 	assert!(discovery_error.to_string().contains("missing package.name"));
 }
 
@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -107,21 +108,50 @@ fn display_package_id(package: &PackageRecord) -> String {
 		.unwrap_or_else(|| package.id.clone())
 }
 
-#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
+/// One public API item together with the facts the type-level diff needs.
+///
+/// Members of a type (fields, variants, trait items, inherent methods, and
+/// trait impls) are tracked as their own symbols so additions and removals
+/// can be judged against the item that owns them.
+#[derive(Debug, Clone, Default, Eq, Ord, PartialEq, PartialOrd)]
 struct PublicSymbol {
 	item_kind: String,
 	item_path: String,
+	/// Rendered signature without docs, bodies, private members, or generics.
 	signature: String,
+	/// Rendered generic parameters and `where` clause.
+	generics: String,
+	/// Structs and enums that downstream crates can construct or match
+	/// exhaustively.
+	exhaustive: bool,
+	/// Trait items that every implementor must provide.
+	required: bool,
+	/// Type an `impl` block or derive attaches to, resolved into `item_path`
+	/// once every file has been collected.
+	self_type: Option<SelfType>,
 	file_path: PathBuf,
 }
 
+/// The self type of an `impl` block as written, with the module it appears in.
+#[derive(Debug, Clone, Default, Eq, Ord, PartialEq, PartialOrd)]
+struct SelfType {
+	module: Vec<String>,
+	path: Vec<String>,
+}
+
+impl SelfType {
+	fn display(&self) -> String {
+		self.path.join("::")
+	}
+}
+
 fn snapshot_public_symbols(
 	snapshot: Option<&PackageSnapshot>,
 	changed_files: &[AnalyzedFileChange],
 	_detection_level: DetectionLevel,
 ) -> (BTreeMap<(String, String), PublicSymbol>, Vec<String>) {
 	let mut warnings = Vec::new();
-	let mut symbols = BTreeMap::new();
+	let mut collected = Vec::new();
 
 	if let Some(snapshot) = snapshot {
 		for file in &snapshot.files {
@@ -130,46 +160,153 @@ fn snapshot_public_symbols(
 			}
 
 			match collect_public_symbols(file) {
-				Ok(file_symbols) => {
-					for symbol in file_symbols {
-						symbols
-							.insert((symbol.item_kind.clone(), symbol.item_path.clone()), symbol);
-					}
-				}
+				Ok(mut file_symbols) => collected.append(&mut file_symbols),
 				Err(error) => warnings.push(error),
 			}
 		}
+	} else {
+		for change in changed_files {
+			let contents = change
+				.after_contents
+				.as_deref()
+				.or(change.before_contents.as_deref());
+			let Some(contents) = contents else {
+				continue;
+			};
+			let file = PackageSnapshotFile {
+				path: change.package_path.clone(),
+				contents: contents.to_string(),
+			};
+			if !is_rust_source_file(&file) {
+				continue;
+			}
 
-		return (symbols, warnings);
+			match collect_public_symbols(&file) {
+				Ok(mut file_symbols) => collected.append(&mut file_symbols),
+				Err(error) => warnings.push(error),
+			}
+		}
 	}
 
-	for change in changed_files {
-		let contents = change
-			.after_contents
-			.as_deref()
-			.or(change.before_contents.as_deref());
-		let Some(contents) = contents else {
-			continue;
-		};
-		let file = PackageSnapshotFile {
-			path: change.package_path.clone(),
-			contents: contents.to_string(),
-		};
-		if !is_rust_source_file(&file) {
-			continue;
-		}
+	let (resolved, ambiguous) = resolve_impl_symbols(collected);
+	warnings.extend(ambiguous);
+	let symbols = resolved
+		.into_iter()
+		.map(|symbol| ((symbol.item_kind.clone(), symbol.item_path.clone()), symbol))
+		.collect();
+
+	(symbols, warnings)
+}
 
-		match collect_public_symbols(&file) {
-			Ok(file_symbols) => {
-				for symbol in file_symbols {
-					symbols.insert((symbol.item_kind.clone(), symbol.item_path.clone()), symbol);
+/// Attach inherent methods and trait impls to the public type they belong to.
+///
+/// Impls on types that are not public are dropped. Impls whose type name
+/// matches several public types are dropped as well, and reported in the
+/// returned warnings.
+fn resolve_impl_symbols(symbols: Vec<PublicSymbol>) -> (Vec<PublicSymbol>, Vec<String>) {
+	let type_paths = symbols
+		.iter()
+		.filter(|symbol| matches!(symbol.item_kind.as_str(), "struct" | "enum" | "union"))
+		.map(|symbol| symbol.item_path.clone())
+		.collect::<BTreeSet<_>>();
+	let mut warnings = BTreeSet::new();
+
+	let resolved = symbols
+		.into_iter()
+		.filter_map(|mut symbol| {
+			let Some(self_type) = symbol.self_type.as_ref() else {
+				return Some(symbol);
+			};
+			let type_path = match resolve_self_type(&type_paths, self_type) {
+				Ok(type_path) => type_path?,
+				Err(candidates) => {
+					warnings.insert(format!(
+						"skipped impl for ambiguous type `{}` in {}: could be {}",
+						self_type.display(),
+						symbol.file_path.display(),
+						candidates
+							.iter()
+							.map(|candidate| format!("`{candidate}`"))
+							.collect::<Vec<_>>()
+							.join(", ")
+					));
+					return None;
 				}
+			};
+			symbol.item_path = if symbol.item_kind == "trait_impl" {
+				format!("<{type_path} as {}>", symbol.item_path)
+			} else {
+				format!("{type_path}::{}", symbol.item_path)
+			};
+			Some(symbol)
+		})
+		.collect();
+
+	(resolved, warnings.into_iter().collect())
+}
+
+/// Find the public type an `impl` block names.
+///
+/// `crate::`, `self::`, and `super::` paths resolve from the module of the
+/// impl, and other paths resolve inside that module first and then from the
+/// crate root. A bare name that the module of the impl does not declare
+/// falls back to the only public type with that name, since it was most
+/// likely brought in with `use`. When several types share the name it is
+/// ambiguous and they are returned as the error.
+fn resolve_self_type(
+	type_paths: &BTreeSet<String>,
+	self_type: &SelfType,
+) -> Result<Option<String>, Vec<String>> {
+	let mut module = self_type.module.clone();
+	let mut segments = self_type.path.as_slice();
+	let mut anchored = false;
+	loop {
+		match segments.split_first() {
+			Some((first, rest)) if first == "crate" => {
+				module.clear();
+				segments = rest;
+				anchored = true;
+			}
+			Some((first, rest)) if first == "self" => {
+				segments = rest;
+				anchored = true;
 			}
-			Err(error) => warnings.push(error),
+			Some((first, rest)) if first == "super" => {
+				module.pop();
+				segments = rest;
+				anchored = true;
+			}
+			_ => break,
 		}
 	}
 
-	(symbols, warnings)
+	let in_module = module
+		.iter()
+		.map(String::as_str)
+		.chain(segments.iter().map(String::as_str))
+		.collect::<Vec<_>>()
+		.join("::");
+	if type_paths.contains(&in_module) {
+		return Ok(Some(in_module));
+	}
+	if anchored {
+		return Ok(None);
+	}
+	let [name] = segments else {
+		let from_root = segments.join("::");
+		return Ok(type_paths.contains(&from_root).then_some(from_root));
+	};
+
+	let candidates = type_paths
+		.iter()
+		.filter(|path| path.rsplit("::").next() == Some(name.as_str()))
+		.cloned()
+		.collect::<Vec<_>>();
+	match candidates.as_slice() {
+		[] => Ok(None),
+		[only] => Ok(Some(only.clone())),
+		_ => Err(candidates),
+	}
 }
 
 fn is_rust_source_file(file: &PackageSnapshotFile) -> bool {
@@ -199,29 +336,27 @@ fn collect_public_symbols_from_items(
 					"constant",
 					module_prefix,
 					item.ident.to_string(),
-					render_signature(item),
+					format!("pub const {} : {}", item.ident, render_signature(&item.ty)),
 					file_path,
 				);
 			}
 			syn::Item::Enum(item) if is_public(&item.vis) => {
-				push_symbol(
-					output,
-					"enum",
-					module_prefix,
-					item.ident.to_string(),
-					render_signature(item),
-					file_path,
-				);
+				push_enum_symbols(output, module_prefix, item, file_path);
 			}
 			syn::Item::Fn(item) if is_public(&item.vis) => {
-				push_symbol(
-					output,
-					"function",
-					module_prefix,
-					item.sig.ident.to_string(),
-					render_signature(&item.sig),
-					file_path,
-				);
+				output.push(PublicSymbol {
+					generics: render_generics(&item.sig.generics),
+					..public_symbol(
+						"function",
+						module_prefix,
+						&item.sig.ident.to_string(),
+						render_fn_signature(&item.sig),
+						file_path,
+					)
+				});
+			}
+			syn::Item::Impl(item) => {
+				push_impl_symbols(output, module_prefix, item, file_path);
 			}
 			syn::Item::Mod(item) if is_public(&item.vis) => {
 				push_symbol(
@@ -229,7 +364,7 @@ fn collect_public_symbols_from_items(
 					"module",
 					module_prefix,
 					item.ident.to_string(),
-					render_signature(item),
+					format!("pub mod {}", item.ident),
 					file_path,
 				);
 
@@ -245,52 +380,61 @@ fn collect_public_symbols_from_items(
 				}
 			}
 			syn::Item::Static(item) if is_public(&item.vis) => {
+				let mutability = if matches!(item.mutability, syn::StaticMutability::Mut(_)) {
+					"mut "
+				} else {
+					""
+				};
 				push_symbol(
 					output,
 					"static",
 					module_prefix,
 					item.ident.to_string(),
-					render_signature(item),
+					format!(
+						"pub static {mutability}{} : {}",
+						item.ident,
+						render_signature(&item.ty)
+					),
 					file_path,
 				);
 			}
 			syn::Item::Struct(item) if is_public(&item.vis) => {
-				push_symbol(
-					output,
-					"struct",
-					module_prefix,
-					item.ident.to_string(),
-					render_signature(item),
-					file_path,
-				);
+				push_struct_symbols(output, module_prefix, item, file_path);
 			}
 			syn::Item::Trait(item) if is_public(&item.vis) => {
-				push_symbol(
-					output,
-					"trait",
-					module_prefix,
-					item.ident.to_string(),
-					render_signature(item),
-					file_path,
-				);
+				push_trait_symbols(output, module_prefix, item, file_path);
 			}
 			syn::Item::Type(item) if is_public(&item.vis) => {
-				push_symbol(
-					output,
-					"type_alias",
-					module_prefix,
-					item.ident.to_string(),
-					render_signature(item),
-					file_path,
-				);
+				output.push(PublicSymbol {
+					generics: render_generics(&item.generics),
+					..public_symbol(
+						"type_alias",
+						module_prefix,
+						&item.ident.to_string(),
+						format!("pub type {} = {}", item.ident, render_signature(&item.ty)),
+						file_path,
+					)
+				});
 			}
 			syn::Item::Union(item) if is_public(&item.vis) => {
-				push_symbol(
+				let mut union = item.clone();
+				union.attrs.clear();
+				union.generics = syn::Generics::default();
+				output.push(PublicSymbol {
+					generics: render_generics(&item.generics),
+					..public_symbol(
+						"union",
+						module_prefix,
+						&item.ident.to_string(),
+						render_signature(&union),
+						file_path,
+					)
+				});
+				push_derived_impls(
 					output,
-					"union",
 					module_prefix,
-					item.ident.to_string(),
-					render_signature(item),
+					&item.attrs,
+					&item.ident.to_string(),
 					file_path,
 				);
 			}
@@ -310,6 +454,284 @@ fn collect_public_symbols_from_items(
 	}
 }
 
+fn push_struct_symbols(
+	output: &mut Vec<PublicSymbol>,
+	module_prefix: &[String],
+	item: &syn::ItemStruct,
+	file_path: &Path,
+) {
+	let name = item.ident.to_string();
+	let form = match &item.fields {
+		syn::Fields::Named(_) => " { .. }",
+		syn::Fields::Unnamed(_) => " ( .. )",
+		syn::Fields::Unit => " ;",
+	};
+	output.push(PublicSymbol {
+		generics: render_generics(&item.generics),
+		exhaustive: !is_non_exhaustive(&item.attrs)
+			&& item.fields.iter().all(|field| is_public(&field.vis)),
+		..public_symbol(
+			"struct",
+			module_prefix,
+			&name,
+			format!("pub struct {name}{form}"),
+			file_path,
+		)
+	});
+
+	let member_prefix = member_prefix(module_prefix, &name);
+	for (index, field) in item.fields.iter().enumerate() {
+		if !is_public(&field.vis) {
+			continue;
+		}
+
+		let field_name = field
+			.ident
+			.as_ref()
+			.map_or_else(|| index.to_string(), ToString::to_string);
+		let mut field = field.clone();
+		field.attrs.clear();
+		push_symbol(
+			output,
+			"field",
+			&member_prefix,
+			field_name,
+			render_signature(&field),
+			file_path,
+		);
+	}
+
+	push_derived_impls(output, module_prefix, &item.attrs, &name, file_path);
+}
+
+fn push_enum_symbols(
+	output: &mut Vec<PublicSymbol>,
+	module_prefix: &[String],
+	item: &syn::ItemEnum,
+	file_path: &Path,
+) {
+	let name = item.ident.to_string();
+	output.push(PublicSymbol {
+		generics: render_generics(&item.generics),
+		exhaustive: !is_non_exhaustive(&item.attrs),
+		..public_symbol(
+			"enum",
+			module_prefix,
+			&name,
+			format!("pub enum {name}"),
+			file_path,
+		)
+	});
+
+	let member_prefix = member_prefix(module_prefix, &name);
+	for variant in &item.variants {
+		let marker = if is_non_exhaustive(&variant.attrs) {
+			"#[non_exhaustive] "
+		} else {
+			""
+		};
+		let mut rendered = variant.clone();
+		rendered.attrs.clear();
+		push_symbol(
+			output,
+			"variant",
+			&member_prefix,
+			variant.ident.to_string(),
+			format!("{marker}{}", render_signature(&rendered)),
+			file_path,
+		);
+	}
+
+	push_derived_impls(output, module_prefix, &item.attrs, &name, file_path);
+}
+
+fn push_trait_symbols(
+	output: &mut Vec<PublicSymbol>,
+	module_prefix: &[String],
+	item: &syn::ItemTrait,
+	file_path: &Path,
+) {
+	let name = item.ident.to_string();
+	let mut header = item.clone();
+	header.attrs.clear();
+	header.items.clear();
+	header.generics = syn::Generics::default();
+	output.push(PublicSymbol {
+		generics: render_generics(&item.generics),
+		..public_symbol(
+			"trait",
+			module_prefix,
+			&name,
+			render_signature(&header),
+			file_path,
+		)
+	});
+
+	let member_prefix = member_prefix(module_prefix, &name);
+	for trait_item in &item.items {
+		let symbol = match trait_item {
+			syn::TraitItem::Const(constant) => {
+				let mut rendered = constant.clone();
+				rendered.attrs.clear();
+				rendered.default = None;
+				PublicSymbol {
+					required: constant.default.is_none(),
+					..public_symbol(
+						"associated_constant",
+						&member_prefix,
+						&constant.ident.to_string(),
+						render_signature(&rendered),
+						file_path,
+					)
+				}
+			}
+			syn::TraitItem::Fn(method) => {
+				PublicSymbol {
+					generics: render_generics(&method.sig.generics),
+					required: method.default.is_none(),
+					..public_symbol(
+						"trait_method",
+						&member_prefix,
+						&method.sig.ident.to_string(),
+						render_fn_signature(&method.sig),
+						file_path,
+					)
+				}
+			}
+			syn::TraitItem::Type(associated) => {
+				let mut rendered = associated.clone();
+				rendered.attrs.clear();
+				rendered.default = None;
+				PublicSymbol {
+					required: associated.default.is_none(),
+					..public_symbol(
+						"associated_type",
+						&member_prefix,
+						&associated.ident.to_string(),
+						render_signature(&rendered),
+						file_path,
+					)
+				}
+			}
+			_ => continue,
+		};
+		output.push(symbol);
+	}
+}
+
+/// Record trait impls and public inherent methods. Their owning type is
+/// resolved later by [`resolve_impl_symbols`].
+fn push_impl_symbols(
+	output: &mut Vec<PublicSymbol>,
+	module_prefix: &[String],
+	item: &syn::ItemImpl,
+	file_path: &Path,
+) {
+	let syn::Type::Path(self_type) = item.self_ty.as_ref() else {
+		return;
+	};
+	let Some(self_name) = self_type
+		.path
+		.segments
+		.last()
+		.filter(|_| self_type.qself.is_none())
+		.map(|segment| segment.ident.to_string())
+	else {
+		return;
+	};
+	let self_type = SelfType {
+		module: module_prefix.to_vec(),
+		path: self_type
+			.path
+			.segments
+			.iter()
+			.map(|segment| segment.ident.to_string())
+			.collect(),
+	};
+	let impl_generics = render_generics(&item.generics);
+
+	if let Some((negative, trait_path, _)) = &item.trait_ {
+		if let Some(trait_name) = trait_path.segments.last().map(render_signature)
+			&& negative.is_none()
+		{
+			output.push(PublicSymbol {
+				generics: impl_generics,
+				self_type: Some(self_type),
+				..public_symbol(
+					"trait_impl",
+					&[],
+					&trait_name,
+					format!("impl {trait_name} for {self_name}"),
+					file_path,
+				)
+			});
+		}
+		return;
+	}
+
+	for impl_item in &item.items {
+		let syn::ImplItem::Fn(method) = impl_item else {
+			continue;
+		};
+		if !is_public(&method.vis) {
+			continue;
+		}
+
+		let generics = format!("{impl_generics} {}", render_generics(&method.sig.generics));
+		output.push(PublicSymbol {
+			generics: generics.trim().to_string(),
+			self_type: Some(self_type.clone()),
+			..public_symbol(
+				"method",
+				&[],
+				&method.sig.ident.to_string(),
+				render_fn_signature(&method.sig),
+				file_path,
+			)
+		});
+	}
+}
+
+/// `#[derive(..)]` implements traits just like a handwritten `impl` block,
+/// so both produce the same `trait_impl` symbol.
+fn push_derived_impls(
+	output: &mut Vec<PublicSymbol>,
+	module_prefix: &[String],
+	attributes: &[syn::Attribute],
+	self_name: &str,
+	file_path: &Path,
+) {
+	for attribute in attributes
+		.iter()
+		.filter(|attribute| attribute.path().is_ident("derive"))
+	{
+		let Ok(paths) = attribute.parse_args_with(
+			syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+		) else {
+			continue;
+		};
+
+		for trait_name in paths
+			.iter()
+			.filter_map(|path| path.segments.last().map(render_signature))
+		{
+			output.push(PublicSymbol {
+				self_type: Some(SelfType {
+					module: module_prefix.to_vec(),
+					path: vec![self_name.to_string()],
+				}),
+				..public_symbol(
+					"trait_impl",
+					&[],
+					&trait_name,
+					format!("impl {trait_name} for {self_name}"),
+					file_path,
+				)
+			});
+		}
+	}
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn push_symbol(
 	output: &mut Vec<PublicSymbol>,
@@ -319,28 +741,75 @@ fn push_symbol(
 	signature: String,
 	file_path: &Path,
 ) {
+	output.push(public_symbol(
+		item_kind,
+		module_prefix,
+		&item_name,
+		signature,
+		file_path,
+	));
+}
+
+fn public_symbol(
+	item_kind: &str,
+	module_prefix: &[String],
+	item_name: &str,
+	signature: String,
+	file_path: &Path,
+) -> PublicSymbol {
 	let item_path = if module_prefix.is_empty() {
-		item_name.clone()
+		item_name.to_string()
 	} else {
 		format!("{}::{item_name}", module_prefix.join("::"))
 	};
 
-	output.push(PublicSymbol {
+	PublicSymbol {
 		item_kind: item_kind.to_string(),
 		item_path,
 		signature,
 		file_path: file_path.to_path_buf(),
-	});
+		..PublicSymbol::default()
+	}
+}
+
+fn member_prefix(module_prefix: &[String], owner: &str) -> Vec<String> {
+	let mut prefix = module_prefix.to_vec();
+	prefix.push(owner.to_string());
+	prefix
 }
 
 fn is_public(visibility: &syn::Visibility) -> bool {
 	matches!(visibility, syn::Visibility::Public(_))
 }
 
+fn is_non_exhaustive(attributes: &[syn::Attribute]) -> bool {
+	attributes
+		.iter()
+		.any(|attribute| attribute.path().is_ident("non_exhaustive"))
+}
+
 fn render_signature(value: &impl ToTokens) -> String {
 	value.to_token_stream().to_string()
 }
 
+/// Render a function signature without its generics, which are diffed on
+/// their own so bound changes get a dedicated report.
+fn render_fn_signature(signature: &syn::Signature) -> String {
+	let mut signature = signature.clone();
+	signature.generics = syn::Generics::default();
+	render_signature(&signature)
+}
+
+fn render_generics(generics: &syn::Generics) -> String {
+	let where_clause = generics
+		.where_clause
+		.as_ref()
+		.map_or_else(String::new, render_signature);
+	format!("{} {where_clause}", render_signature(generics))
+		.trim()
+		.to_string()
+}
+
 fn module_prefix_for_file(path: &Path) -> Vec<String> {
 	let mut components = path
 		.components()
@@ -374,23 +843,10 @@ fn diff_public_symbols(
 
 	for (key, after_symbol) in after {
 		match before.get(key) {
-			None => {
-				changes.push(build_symbol_change(
-					SemanticChangeKind::Added,
-					after_symbol,
-					None,
-					Some(after_symbol.signature.clone()),
-				));
+			None => changes.push(build_addition_change(before, after_symbol)),
+			Some(before_symbol) => {
+				changes.extend(compare_public_symbols(before_symbol, after_symbol));
 			}
-			Some(before_symbol) if before_symbol.signature != after_symbol.signature => {
-				changes.push(build_symbol_change(
-					SemanticChangeKind::Modified,
-					after_symbol,
-					Some(before_symbol.signature.clone()),
-					Some(after_symbol.signature.clone()),
-				));
-			}
-			Some(_) => {}
 		}
 	}
 
@@ -410,6 +866,135 @@ fn diff_public_symbols(
 	changes
 }
 
+/// A new member is a feature unless downstream code relies on its owner's
+/// member list being closed. Struct literals and exhaustive matches break
+/// when a field or variant appears, and implementors break when a trait gains
+/// a required item, so those additions are reported as breaking changes to
+/// the owner.
+fn build_addition_change(
+	before: &BTreeMap<(String, String), PublicSymbol>,
+	symbol: &PublicSymbol,
+) -> SemanticChange {
+	let added = || {
+		build_symbol_change(
+			SemanticChangeKind::Added,
+			symbol,
+			None,
+			Some(symbol.signature.clone()),
+		)
+	};
+	let Some((owner_path, member_name)) = symbol.item_path.rsplit_once("::") else {
+		return added();
+	};
+	let (owner_kind, summary) = match symbol.item_kind.as_str() {
+		"field" => {
+			(
+				"struct",
+				format!("field `{member_name}` added to exhaustive struct `{owner_path}`"),
+			)
+		}
+		"variant" => {
+			(
+				"enum",
+				format!("variant `{member_name}` added to exhaustive enum `{owner_path}`"),
+			)
+		}
+		"trait_method" => {
+			(
+				"trait",
+				format!("required method `{member_name}` added to trait `{owner_path}`"),
+			)
+		}
+		"associated_type" => {
+			(
+				"trait",
+				format!("required associated type `{member_name}` added to trait `{owner_path}`"),
+			)
+		}
+		"associated_constant" => {
+			(
+				"trait",
+				format!(
+					"required associated constant `{member_name}` added to trait `{owner_path}`"
+				),
+			)
+		}
+		_ => return added(),
+	};
+	let Some(owner) = before.get(&(owner_kind.to_string(), owner_path.to_string())) else {
+		return added();
+	};
+	let breaking = if owner_kind == "trait" {
+		symbol.required
+	} else {
+		owner.exhaustive
+	};
+	if !breaking {
+		return added();
+	}
+
+	SemanticChange {
+		category: SemanticChangeCategory::PublicApi,
+		kind: SemanticChangeKind::Modified,
+		item_kind: owner_kind.to_string(),
+		item_path: owner_path.to_string(),
+		summary,
+		file_path: symbol.file_path.clone(),
+		before_signature: None,
+		after_signature: Some(symbol.signature.clone()),
+	}
+}
+
+fn compare_public_symbols(before: &PublicSymbol, after: &PublicSymbol) -> Vec<SemanticChange> {
+	let mut changes = Vec::new();
+
+	if before.signature != after.signature {
+		changes.push(build_symbol_change(
+			SemanticChangeKind::Modified,
+			after,
+			Some(before.signature.clone()),
+			Some(after.signature.clone()),
+		));
+	}
+
+	if before.generics != after.generics {
+		changes.push(SemanticChange {
+			summary: format!(
+				"generic bounds of {} `{}` modified",
+				after.item_kind, after.item_path
+			),
+			..build_symbol_change(
+				SemanticChangeKind::Modified,
+				after,
+				Some(before.generics.clone()),
+				Some(after.generics.clone()),
+			)
+		});
+	}
+
+	if before.exhaustive && !after.exhaustive {
+		changes.push(SemanticChange {
+			summary: format!(
+				"{} `{}` is no longer exhaustive",
+				after.item_kind, after.item_path
+			),
+			..build_symbol_change(SemanticChangeKind::Modified, after, None, None)
+		});
+	}
+
+	if !before.required && after.required {
+		changes.push(SemanticChange {
+			summary: format!(
+				"{} `{}` lost its default and is now required",
+				after.item_kind, after.item_path
+			),
+			..build_symbol_change(SemanticChangeKind::Modified, after, None, None)
+		});
+	}
+
+	changes
+}
+
 fn build_symbol_change(
 	kind: SemanticChangeKind,
 	symbol: &PublicSymbol,
@@ -54,9 +54,11 @@ use monochange_core::ChangeSignal;
 use monochange_core::CompatibilityAssessment;
 use monochange_core::DependencyKind;
 use monochange_core::DiscoveryPathFilter;
+use monochange_core::DiscoverySkip;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageDependency;
@@ -73,8 +75,6 @@ use toml_edit::Item;
 use toml_edit::TableLike;
 use toml_edit::Value as EditValue;
 use toml_edit::value;
-use walkdir::DirEntry;
-use walkdir::WalkDir;
 
 pub const CARGO_MANIFEST_FILE: &str = "Cargo.toml";
 pub const RUST_SEMVER_PROVIDER_ID: &str = "rust-semver";
@@ -96,6 +96,14 @@ impl EcosystemAdapter for CargoAdapter {
 		discover_cargo_packages(root)
 	}
 
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_cargo_packages_with_filter(root, filter)
+	}
+
 	fn load_configured(
 		&self,
 		root: &Path,
@@ -704,14 +712,30 @@ impl CompatibilityProvider for RustSemverProvider {
 #[must_use = "the discovery result must be checked"]
 /// Discover Cargo packages rooted at `root`.
 pub fn discover_cargo_packages(root: &Path) -> MonochangeResult<AdapterDiscovery> {
-	let workspace_manifests = find_workspace_manifests(root);
+	discover_cargo_packages_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Cargo packages rooted at `root` without parsing manifests outside
+/// `filter`'s discovery scope.
+///
+/// Workspace manifests outside the scope still name their members, but are
+/// not discovered as packages themselves.
+pub fn discover_cargo_packages_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_manifests(root, filter);
+	let mut skipped = walk.skipped;
+	let workspace_manifests = find_workspace_manifests(&walk.manifests);
 	let mut included_manifests = HashSet::new();
 	let mut packages = Vec::new();
 	let mut warnings = Vec::new();
 
 	for workspace_manifest in &workspace_manifests {
 		let (workspace_packages, workspace_warnings) =
-			discover_workspace_packages(workspace_manifest)?;
+			discover_workspace_packages(workspace_manifest, filter, &mut skipped)?;
 		warnings.extend(workspace_warnings);
 		for package in workspace_packages {
 			included_manifests.insert(package.manifest_path.clone());
@@ -719,7 +743,9 @@ pub fn discover_cargo_packages(root: &Path) -> MonochangeResult<AdapterDiscovery
 		}
 	}
 
-	for manifest_path in find_all_manifests(root) {
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Cargo, walk.manifests, &mut skipped)
+	{
 		if included_manifests.contains(&manifest_path) {
 			continue;
 		}
@@ -735,7 +761,11 @@ pub fn discover_cargo_packages(root: &Path) -> MonochangeResult<AdapterDiscovery
 	packages.dedup_by(|left, right| left.id == right.id);
 	tracing::debug!(packages = packages.len(), "discovered cargo packages");
 
-	Ok(AdapterDiscovery { packages, warnings })
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
 }
 
 /// Load one explicitly configured Cargo package without walking the whole repo.
@@ -802,10 +832,11 @@ fn workspace_package_version_from_manifest(
 	Ok(workspace_package_version(&parsed))
 }
 
-fn find_workspace_manifests(root: &Path) -> Vec<PathBuf> {
-	let mut manifests = find_all_manifests(root)
-		.into_iter()
+fn find_workspace_manifests(manifests: &[PathBuf]) -> Vec<PathBuf> {
+	let mut manifests = manifests
+		.iter()
 		.filter(|manifest_path| has_workspace_section(manifest_path).unwrap_or(false))
+		.cloned()
 		.collect::<Vec<_>>();
 	manifests.sort();
 	manifests
@@ -813,6 +844,8 @@ fn find_workspace_manifests(root: &Path) -> Vec<PathBuf> {
 
 fn discover_workspace_packages(
 	workspace_manifest: &Path,
+	filter: &DiscoveryPathFilter,
+	skipped: &mut Vec<DiscoverySkip>,
 ) -> MonochangeResult<(Vec<PackageRecord>, Vec<String>)> {
 	let contents = fs::read_to_string(workspace_manifest).map_err(|error| {
 		MonochangeError::Io(format!(
@@ -862,6 +895,11 @@ fn discover_workspace_packages(
 		&exclude_patterns,
 		&mut warnings,
 	);
+	let member_manifests = filter.retain_manifests_in_scope(
+		Ecosystem::Cargo,
+		member_manifests.into_iter().collect(),
+		skipped,
+	);
 	let mut packages = Vec::new();
 
 	for manifest_path in member_manifests {
@@ -1081,16 +1119,13 @@ fn has_workspace_section(manifest_path: &Path) -> MonochangeResult<bool> {
 	Ok(parsed.get("workspace").is_some())
 }
 
-fn find_all_manifests(root: &Path) -> Vec<PathBuf> {
-	let filter = DiscoveryPathFilter::new(root);
-	WalkDir::new(root)
-		.into_iter()
-		.filter_entry(|entry| filter.should_descend(entry.path()))
-		.filter_map(Result::ok)
-		.filter(|entry| entry.file_name() == CARGO_MANIFEST_FILE)
-		.map(DirEntry::into_path)
-		.map(|path| normalize_path(&path))
-		.collect()
+fn find_all_manifests(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(
+		Ecosystem::Cargo,
+		root,
+		|entry| filter.should_descend(entry.path()),
+		|entry| entry.file_name() == CARGO_MANIFEST_FILE,
+	)
 }
 
 fn parse_rust_semver_evidence(
@@ -32,11 +32,14 @@ use monochange_core::PackageLabelStyle;
 use monochange_core::PackageRecord;
 use monochange_core::PackageType;
 use monochange_core::PlannedVersionGroup;
+use monochange_core::PreStablePolicy;
 use monochange_core::PreparedChangeset;
 use monochange_core::PreparedChangesetTarget;
+use monochange_core::PropagationByKind;
 use monochange_core::PublishState;
 use monochange_core::ReleaseDecision;
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 use monochange_core::WorkspaceConfiguration;
 use monochange_core::WorkspaceDefaults;
 use semver::Version;
@@ -89,6 +92,7 @@ fn empty_configuration(root: &Path) -> WorkspaceConfiguration {
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -99,6 +103,9 @@ fn empty_configuration(root: &Path) -> WorkspaceConfiguration {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	}
 }
 
@@ -146,6 +153,9 @@ fn sample_package_definition(config_id: &str) -> PackageDefinition {
 		release: true,
 		publish: monochange_core::PublishSettings::default(),
 		version_format: VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
+		propagation_by_kind: PropagationByKind::default(),
 	}
 }
 
@@ -167,6 +177,8 @@ fn sample_group_definition(include: GroupChangelogInclude) -> GroupDefinition {
 		tag: true,
 		release: true,
 		version_format: VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
 	}
 }
 
@@ -178,6 +190,7 @@ fn sample_decision(package_id: &str, group_id: Option<&str>) -> ReleaseDecision
 		planned_version: Some(Version::new(1, 2, 3)),
 		group_id: group_id.map(ToString::to_string),
 		reasons: vec!["covered".to_string()],
+		causes: Vec::new(),
 		upstream_sources: Vec::new(),
 		warnings: Vec::new(),
 	}
@@ -273,6 +286,7 @@ fn build_changelog_updates_writes_package_and_group_initial_headers() {
 		decisions: vec![sample_decision(&package_a_id, None)],
 		groups: vec![sample_group(vec![package_a_id.clone(), other_package_id])],
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -343,6 +357,7 @@ fn build_changelog_updates_reports_package_and_group_append_errors() {
 		decisions: vec![sample_decision(&package_a_id, None)],
 		groups: Vec::new(),
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -373,6 +388,7 @@ fn build_changelog_updates_reports_package_and_group_append_errors() {
 		decisions: vec![sample_decision(&package_a_id, Some("sdk"))],
 		groups: vec![sample_group(vec![package_a_id.clone()])],
 		warnings: Vec::new(),
+		version_conflicts: Vec::new(),
 		unresolved_items: Vec::new(),
 		compatibility_evidence: Vec::new(),
 	};
@@ -0,0 +1,545 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_core::BumpSeverity;
+use monochange_core::ChangesetTargetKind;
+use monochange_core::Ecosystem;
+use monochange_core::PackageRecord;
+use monochange_core::PublishState;
+use monochange_core::WorkspaceConfiguration;
+use monochange_test_helpers::git::git;
+use semver::Version;
+use tempfile::TempDir;
+
+use super::*;
+use crate::apply_version_groups;
+use crate::build_changeset_load_context;
+use crate::load_workspace_configuration;
+
+fn fixture_root() -> PathBuf {
+	monochange_test_helpers::fs::fixture_path_from(
+		env!("CARGO_MANIFEST_DIR"),
+		"config/conventional-commits",
+	)
+}
+
+fn fixture_workspace(root: &Path) -> (WorkspaceConfiguration, Vec<PackageRecord>) {
+	let configuration = load_workspace_configuration(root)
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let mut packages = ["core", "app"]
+		.into_iter()
+		.map(|name| {
+			PackageRecord::new(
+				Ecosystem::Cargo,
+				name,
+				root.join(format!("crates/{name}/Cargo.toml")),
+				root.to_path_buf(),
+				Some(Version::new(1, 0, 0)),
+				PublishState::Public,
+			)
+		})
+		.collect::<Vec<_>>();
+	apply_version_groups(&mut packages, &configuration)
+		.unwrap_or_else(|error| panic!("version groups: {error}"));
+	(configuration, packages)
+}
+
+fn commit(message: &str, paths: &[&str]) -> ReleaseRangeCommit {
+	ReleaseRangeCommit {
+		sha: "0123456789abcdef0123456789abcdef01234567".to_string(),
+		author_name: "Ada".to_string(),
+		author_email: "ada@example.com".to_string(),
+		authored_at: "2026-01-02T03:04:05+00:00".to_string(),
+		committed_at: "2026-01-02T03:04:05+00:00".to_string(),
+		message: message.to_string(),
+		paths: paths.iter().map(PathBuf::from).collect(),
+		prereleased: false,
+	}
+}
+
+#[test]
+fn parse_conventional_commit_reads_type_scopes_and_description() {
+	let parsed = parse_conventional_commit("feat(core, app): add streaming reads")
+		.unwrap_or_else(|| panic!("expected conventional commit"));
+
+	assert_eq!(parsed.commit_type, "feat");
+	assert_eq!(parsed.scopes, vec!["core", "app"]);
+	assert!(!parsed.breaking);
+	assert_eq!(parsed.description, "add streaming reads");
+	assert_eq!(parsed.body, None);
+}
+
+#[test]
+fn parse_conventional_commit_detects_breaking_markers() {
+	let bang = parse_conventional_commit("Fix!: drop legacy flag")
+		.unwrap_or_else(|| panic!("expected conventional commit"));
+	assert_eq!(bang.commit_type, "fix");
+	assert!(bang.scopes.is_empty());
+	assert!(bang.breaking);
+
+	let footer = parse_conventional_commit(
+		"refactor(core): rename reader\n\nThe reader now owns its buffer.\n\nBREAKING CHANGE: `Reader::new` takes a capacity\nRefs: #12",
+	)
+	.unwrap_or_else(|| panic!("expected conventional commit"));
+	assert!(footer.breaking);
+	assert_eq!(
+		footer.body.as_deref(),
+		Some("The reader now owns its buffer.\n\n`Reader::new` takes a capacity")
+	);
+}
+
+#[test]
+fn parse_conventional_commit_rejects_free_form_subjects() {
+	assert_eq!(parse_conventional_commit("Update readme"), None);
+	assert_eq!(parse_conventional_commit("feat(core: missing paren"), None);
+	assert_eq!(parse_conventional_commit("feat: "), None);
+	assert_eq!(parse_conventional_commit("two words: not a type"), None);
+}
+
+#[test]
+fn split_commit_message_only_treats_a_full_trailer_paragraph_as_trailers() {
+	let parts = split_commit_message("subject\n\nSee: this is prose\nthat wraps\n\nSigned-off-by: Ada");
+	assert_eq!(parts.subject, "subject");
+	assert_eq!(parts.body, vec!["See: this is prose\nthat wraps"]);
+	assert_eq!(
+		parts.trailers,
+		vec![("Signed-off-by".to_string(), "Ada".to_string())]
+	);
+}
+
+#[test]
+fn split_commit_message_folds_continuation_lines_into_the_previous_trailer() {
+	let parts = split_commit_message(
+		"feat(core)!: stream reads\n\nBREAKING CHANGE: `Reader::new` now takes a capacity\n\tand returns a `Result`.\n  Callers must handle allocation failures.\nRefs: #12\nSigned-off-by: Ada",
+	);
+	assert!(parts.body.is_empty());
+	assert_eq!(
+		parts.trailers,
+		vec![
+			(
+				"BREAKING CHANGE".to_string(),
+				"`Reader::new` now takes a capacity\nand returns a `Result`.\nCallers must handle allocation failures."
+					.to_string()
+			),
+			("Refs".to_string(), "#12".to_string()),
+			("Signed-off-by".to_string(), "Ada".to_string()),
+		]
+	);
+
+	let commit = parse_conventional_commit(
+		"refactor(core): rename reader\n\nBREAKING CHANGE: readers are renamed\n    to `Source` everywhere",
+	)
+	.unwrap_or_else(|| panic!("expected conventional commit"));
+	assert!(commit.breaking);
+	assert_eq!(
+		commit.body.as_deref(),
+		Some("readers are renamed\nto `Source` everywhere")
+	);
+}
+
+#[test]
+fn split_commit_message_requires_the_trailer_block_to_open_with_a_trailer() {
+	let parts = split_commit_message("subject\n\nprose first\nSigned-off-by: Ada");
+	assert_eq!(parts.body, vec!["prose first\nSigned-off-by: Ada"]);
+	assert!(parts.trailers.is_empty());
+}
+
+#[test]
+fn split_commit_message_keeps_a_final_prose_paragraph_that_opens_like_a_trailer() {
+	let parts = split_commit_message(
+		"fix: handle empty input\n\nNote: the parser used to panic here\nwhen the buffer was empty.",
+	);
+	assert_eq!(
+		parts.body,
+		vec!["Note: the parser used to panic here\nwhen the buffer was empty."]
+	);
+	assert!(parts.trailers.is_empty());
+}
+
+#[test]
+fn parse_release_range_log_reads_commit_records_and_paths() {
+	let stdout = "\u{1e}abc1234def\u{1f}Ada\u{1f}ada@example.com\u{1f}2026-01-02T03:04:05+00:00\u{1f}2026-01-03T03:04:05+00:00\u{1f}feat(core): add reads\n\nbody\n\u{1f}\n\ncrates/core/src/lib.rs\ncrates/core/Cargo.toml\n\u{1e}fff0000\u{1f}Bob\u{1f}bob@example.com\u{1f}2026-01-04T00:00:00+00:00\u{1f}2026-01-04T00:00:00+00:00\u{1f}docs: tweak\n\u{1f}\n";
+	let commits = parse_release_range_log(stdout);
+
+	assert_eq!(commits.len(), 2);
+	let first = commits
+		.first()
+		.unwrap_or_else(|| panic!("expected first commit"));
+	assert_eq!(first.sha, "abc1234def");
+	assert_eq!(first.short_sha(), "abc1234");
+	assert_eq!(first.subject(), "feat(core): add reads");
+	assert_eq!(first.message, "feat(core): add reads\n\nbody");
+	assert_eq!(first.committed_at, "2026-01-03T03:04:05+00:00");
+	assert_eq!(
+		first.paths,
+		vec![
+			PathBuf::from("crates/core/src/lib.rs"),
+			PathBuf::from("crates/core/Cargo.toml"),
+		]
+	);
+	let second = commits
+		.get(1)
+		.unwrap_or_else(|| panic!("expected second commit"));
+	assert_eq!(second.author_name, "Bob");
+	assert!(second.paths.is_empty());
+}
+
+#[test]
+fn load_conventional_commit_changesets_resolves_scopes_aliases_and_paths() {
+	let root = fixture_root();
+	let (configuration, packages) = fixture_workspace(&root);
+	let context = build_changeset_load_context(&configuration, &packages);
+	let commits = vec![
+		commit("feat(engine): add streaming reads", &["crates/app/src/lib.rs"]),
+		commit("fix: handle empty input", &["crates/app/src/main.rs"]),
+		commit("feat(sdk): share the client", &[]),
+		commit("chore(core): bump lockfile", &["crates/core/Cargo.toml"]),
+		commit("Merge upstream", &["crates/core/src/lib.rs"]),
+		commit("docs: explain changesets", &[".changeset/feature.md"]),
+	];
+
+	let changesets = load_conventional_commit_changesets(&commits, &[], &context)
+		.unwrap_or_else(|error| panic!("conventional commits: {error}"));
+
+	assert_eq!(changesets.len(), 3);
+	let [aliased, by_path, grouped] = changesets.as_slice() else {
+		panic!("expected three changesets");
+	};
+
+	assert_eq!(aliased.path, PathBuf::from("commit:0123456"));
+	assert_eq!(aliased.summary.as_deref(), Some("add streaming reads"));
+	assert_eq!(aliased.targets.len(), 1);
+	let target = aliased
+		.targets
+		.first()
+		.unwrap_or_else(|| panic!("expected aliased target"));
+	assert_eq!(target.id, "core");
+	assert_eq!(target.kind, ChangesetTargetKind::Package);
+	assert_eq!(target.origin, "conventional-commit");
+	assert_eq!(target.change_type.as_deref(), Some("feat"));
+	assert_eq!(target.bump, Some(BumpSeverity::Minor));
+	let revision = aliased
+		.source_revision
+		.as_ref()
+		.and_then(|revision| revision.commit.as_ref())
+		.unwrap_or_else(|| panic!("expected source commit"));
+	assert_eq!(revision.short_sha, "0123456");
+
+	let target = by_path
+		.targets
+		.first()
+		.unwrap_or_else(|| panic!("expected path target"));
+	assert_eq!(target.id, "app");
+	assert_eq!(target.bump, Some(BumpSeverity::Patch));
+
+	let target = grouped
+		.targets
+		.first()
+		.unwrap_or_else(|| panic!("expected group target"));
+	assert_eq!(target.id, "sdk");
+	assert_eq!(target.kind, ChangesetTargetKind::Group);
+	assert_eq!(grouped.signals.len(), 2);
+}
+
+#[test]
+fn load_conventional_commit_changesets_keeps_breaking_commits_with_unknown_types() {
+	let root = fixture_root();
+	let (configuration, packages) = fixture_workspace(&root);
+	let context = build_changeset_load_context(&configuration, &packages);
+	let commits = vec![commit(
+		"build(app)!: require the new toolchain",
+		&["crates/app/Cargo.toml"],
+	)];
+
+	let changesets = load_conventional_commit_changesets(&commits, &[], &context)
+		.unwrap_or_else(|error| panic!("conventional commits: {error}"));
+
+	let target = changesets
+		.first()
+		.and_then(|changeset| changeset.targets.first())
+		.unwrap_or_else(|| panic!("expected breaking target"));
+	assert_eq!(target.id, "app");
+	assert_eq!(target.bump, Some(BumpSeverity::Major));
+	assert_eq!(target.change_type, None);
+}
+
+#[test]
+fn load_conventional_commit_changesets_skips_targets_declared_by_a_changeset_file() {
+	let root = fixture_root();
+	let (configuration, packages) = fixture_workspace(&root);
+	let context = build_changeset_load_context(&configuration, &packages);
+	let file = crate::load_changeset_contents_with_context(
+		Path::new(".changeset/streaming.md"),
+		"---\ncore: minor\n---\n\n# add streaming reads\n",
+		&context,
+	)
+	.unwrap_or_else(|error| panic!("changeset file: {error}"));
+	let commits = vec![
+		commit(
+			"feat(core): stream reads",
+			&["crates/core/src/lib.rs", ".changeset/streaming.md"],
+		),
+		commit("feat(core): add streaming reads", &["crates/core/src/lib.rs"]),
+		commit("fix(app): handle empty input", &["crates/app/src/lib.rs"]),
+	];
+
+	let changesets = load_conventional_commit_changesets(&commits, &[file], &context)
+		.unwrap_or_else(|error| panic!("conventional commits: {error}"));
+
+	let ids = changesets
+		.iter()
+		.flat_map(|changeset| &changeset.targets)
+		.map(|target| target.id.as_str())
+		.collect::<Vec<_>>();
+	assert_eq!(ids, ["app"]);
+}
+
+#[test]
+fn load_release_range_commits_starts_after_the_last_release_record() {
+	let tempdir = TempDir::new().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	git(root, &["init"]);
+	git(root, &["config", "user.name", "monochange-tests"]);
+	git(
+		root,
+		&["config", "user.email", "monochange-tests@example.com"],
+	);
+	let write = |relative: &str, contents: &str| {
+		let path = root.join(relative);
+		fs::create_dir_all(path.parent().unwrap_or(root))
+			.unwrap_or_else(|error| panic!("create dir: {error}"));
+		fs::write(path, contents).unwrap_or_else(|error| panic!("write {relative}: {error}"));
+	};
+
+	write("crates/core/src/lib.rs", "pub fn one() {}\n");
+	git(root, &["add", "."]);
+	git(root, &["commit", "-m", "feat(core): before release"]);
+	write(".monochange/releases/abc/release.json", "{}\n");
+	git(root, &["add", "."]);
+	git(root, &["commit", "-m", "chore(release): prepare release"]);
+	write("crates/core/src/lib.rs", "pub fn two() {}\n");
+	git(root, &["add", "."]);
+	git(root, &["commit", "-m", "fix(core): after release"]);
+
+	let commits = load_release_range_commits(root, false)
+		.unwrap_or_else(|error| panic!("commits: {error}"));
+
+	assert_eq!(commits.len(), 1);
+	let commit = commits
+		.first()
+		.unwrap_or_else(|| panic!("expected one commit"));
+	assert_eq!(commit.subject(), "fix(core): after release");
+	assert_eq!(commit.paths, vec![PathBuf::from("crates/core/src/lib.rs")]);
+	assert_eq!(commit.author_name, "monochange-tests");
+}
+
+#[test]
+fn load_release_range_commits_since_stable_release_marks_prereleased_commits() {
+	let tempdir = TempDir::new().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	git(root, &["init"]);
+	git(root, &["config", "user.name", "monochange-tests"]);
+	git(
+		root,
+		&["config", "user.email", "monochange-tests@example.com"],
+	);
+	let commit_file = |relative: &str, contents: &str, message: &str| {
+		let path = root.join(relative);
+		fs::create_dir_all(path.parent().unwrap_or(root))
+			.unwrap_or_else(|error| panic!("create dir: {error}"));
+		fs::write(path, contents).unwrap_or_else(|error| panic!("write {relative}: {error}"));
+		git(root, &["add", "."]);
+		git(root, &["commit", "-m", message]);
+	};
+	let record = |version: &str, prerelease: Option<&str>| {
+		serde_json::json!({
+			"createdAt": "2026-01-01T00:00:00Z",
+			"command": "release",
+			"releaseTargets": [{
+				"id": "core",
+				"kind": "package",
+				"version": version,
+				"versionFormat": "namespaced",
+				"tag": true,
+				"release": true,
+				"tagName": format!("core/v{version}"),
+				"prerelease": prerelease,
+			}],
+			"releasedPackages": ["core"],
+			"changedFiles": [],
+		})
+		.to_string()
+	};
+
+	commit_file("crates/core/src/lib.rs", "pub fn one() {}\n", "feat(core): before stable");
+	commit_file(
+		".monochange/releases/stable/release.json",
+		&record("1.0.0", None),
+		"chore(release): 1.0.0",
+	);
+	commit_file("crates/core/src/lib.rs", "pub fn two() {}\n", "feat(core): in beta");
+	commit_file(
+		".monochange/releases/beta/release.json",
+		&record("1.1.0-beta.1", Some("beta")),
+		"chore(release): 1.1.0-beta.1",
+	);
+	commit_file("crates/core/src/lib.rs", "pub fn three() {}\n", "fix(core): after beta");
+
+	let summarize = |commits: Vec<ReleaseRangeCommit>| {
+		commits
+			.iter()
+			.map(|commit| (commit.subject().to_string(), commit.prereleased))
+			.collect::<Vec<_>>()
+	};
+	let since_last = load_release_range_commits(root, false)
+		.unwrap_or_else(|error| panic!("commits: {error}"));
+	assert_eq!(
+		summarize(since_last),
+		vec![("fix(core): after beta".to_string(), false)]
+	);
+
+	let since_stable = load_release_range_commits(root, true)
+		.unwrap_or_else(|error| panic!("commits: {error}"));
+	assert_eq!(
+		summarize(since_stable),
+		vec![
+			("feat(core): in beta".to_string(), true),
+			("chore(release): 1.1.0-beta.1".to_string(), true),
+			("fix(core): after beta".to_string(), false),
+		]
+	);
+}
+
+#[test]
+fn load_release_range_commits_is_empty_outside_git() {
+	let tempdir = TempDir::new().unwrap_or_else(|error| panic!("tempdir: {error}"));
+
+	let commits = load_release_range_commits(tempdir.path(), true)
+		.unwrap_or_else(|error| panic!("commits: {error}"));
+
+	assert!(commits.is_empty());
+}
+
+#[test]
+fn has_changeset_trailers_only_reads_the_trailer_paragraph() {
+	let trailer = commit("fix: handle empty input\n\nchangeset: core: patch", &[]);
+	let prose = commit("fix: handle empty input\n\nChangeset: core: patch\nthat wraps", &[]);
+
+	assert!(trailer.has_changeset_trailers());
+	assert!(!prose.has_changeset_trailers());
+	assert!(!commit("fix: handle empty input", &[]).has_changeset_trailers());
+}
+
+#[test]
+fn load_commit_trailer_changesets_reads_targets_summary_and_details() {
+	let root = fixture_root();
+	let (configuration, packages) = fixture_workspace(&root);
+	let context = build_changeset_load_context(&configuration, &packages);
+	let commits = vec![
+		commit(
+			"fix: handle empty input\n\nKeeps the parser total.\n\nChangeset: core: patch\nChangeset: sdk: minor\nSigned-off-by: Ada",
+			&["crates/core/src/lib.rs"],
+		),
+		commit("feat: no trailers here", &["crates/app/src/lib.rs"]),
+	];
+
+	let changesets = load_commit_trailer_changesets(&commits, &[], &context)
+		.unwrap_or_else(|error| panic!("commit trailers: {error}"));
+
+	let [changeset] = changesets.as_slice() else {
+		panic!("expected one changeset, got {changesets:?}");
+	};
+	assert_eq!(changeset.path, PathBuf::from("commit:0123456"));
+	assert_eq!(changeset.summary.as_deref(), Some("fix: handle empty input"));
+	assert_eq!(changeset.details.as_deref(), Some("Keeps the parser total."));
+	let [core, sdk] = changeset.targets.as_slice() else {
+		panic!("expected two targets, got {:?}", changeset.targets);
+	};
+	assert_eq!(core.id, "core");
+	assert_eq!(core.kind, ChangesetTargetKind::Package);
+	assert_eq!(core.bump, Some(BumpSeverity::Patch));
+	assert_eq!(core.origin, "commit-trailer");
+	assert_eq!(sdk.id, "sdk");
+	assert_eq!(sdk.kind, ChangesetTargetKind::Group);
+	assert_eq!(sdk.bump, Some(BumpSeverity::Minor));
+	let revision = changeset
+		.source_revision
+		.as_ref()
+		.and_then(|revision| revision.commit.as_ref())
+		.unwrap_or_else(|| panic!("expected source commit"));
+	assert_eq!(revision.short_sha, "0123456");
+}
+
+#[test]
+fn load_commit_trailer_changesets_skips_targets_declared_by_a_changeset_file() {
+	let root = fixture_root();
+	let (configuration, packages) = fixture_workspace(&root);
+	let context = build_changeset_load_context(&configuration, &packages);
+	let by_summary = crate::load_changeset_contents_with_context(
+		Path::new(".changeset/fix.md"),
+		"---\ncore: patch\n---\n\n# fix: handle empty input\n",
+		&context,
+	)
+	.unwrap_or_else(|error| panic!("changeset file: {error}"));
+	let by_path = crate::load_changeset_contents_with_context(
+		Path::new(".changeset/streaming.md"),
+		"---\napp: minor\n---\n\n# add streaming reads\n",
+		&context,
+	)
+	.unwrap_or_else(|error| panic!("changeset file: {error}"));
+	let commits = vec![
+		commit(
+			"fix: handle empty input\n\nChangeset: core: patch\nChangeset: app: patch",
+			&[],
+		),
+		commit("feat: stream reads\n\nChangeset: app: minor", &[".changeset/streaming.md"]),
+	];
+
+	let changesets = load_commit_trailer_changesets(&commits, &[by_summary, by_path], &context)
+		.unwrap_or_else(|error| panic!("commit trailers: {error}"));
+
+	let [changeset] = changesets.as_slice() else {
+		panic!("expected one changeset, got {changesets:?}");
+	};
+	let ids = changeset
+		.targets
+		.iter()
+		.map(|target| target.id.as_str())
+		.collect::<Vec<_>>();
+	assert_eq!(ids, ["app"]);
+}
+
+#[test]
+fn load_commit_trailer_changesets_ignores_a_trailer_paragraph_with_stray_lines() {
+	let root = fixture_root();
+	let (configuration, packages) = fixture_workspace(&root);
+	let context = build_changeset_load_context(&configuration, &packages);
+	let commits = vec![commit(
+		"fix: handle empty input\n\nChangeset: core: patch\nstray note about the fix",
+		&[],
+	)];
+
+	let changesets = load_commit_trailer_changesets(&commits, &[], &context)
+		.unwrap_or_else(|error| panic!("commit trailers: {error}"));
+
+	assert!(changesets.is_empty());
+}
+
+#[test]
+fn load_commit_trailer_changesets_rejects_trailers_without_an_intent() {
+	let root = fixture_root();
+	let (configuration, packages) = fixture_workspace(&root);
+	let context = build_changeset_load_context(&configuration, &packages);
+	let commits = vec![commit("fix: handle empty input\n\nChangeset: core", &[])];
+
+	let error = load_commit_trailer_changesets(&commits, &[], &context)
+		.err()
+		.unwrap_or_else(|| panic!("expected invalid trailer error"));
+
+	assert!(
+		error
+			.to_string()
+			.contains("commit `0123456` has an invalid `Changeset` trailer `core`")
+	);
+}
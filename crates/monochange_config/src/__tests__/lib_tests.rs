@@ -6,6 +6,7 @@ use std::path::PathBuf;
 
 use miette::LabeledSpan;
 use monochange_core::BumpSeverity;
+use monochange_core::CalverFormat;
 use monochange_core::ChangelogDefinition;
 use monochange_core::ChangelogFormat;
 use monochange_core::ChangelogSettings;
@@ -15,18 +16,23 @@ use monochange_core::CliInputDefinition;
 use monochange_core::CliInputKind;
 use monochange_core::CliStepDefinition;
 use monochange_core::CliStepInputValue;
+use monochange_core::DependencyKindRule;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemType;
 use monochange_core::GroupChangelogInclude;
 use monochange_core::GroupDefinition;
+use monochange_core::LinkedGroupDefinition;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageRecord;
+use monochange_core::PreStablePolicy;
+use monochange_core::PropagationByKind;
 use monochange_core::PublishMode;
 use monochange_core::PublishRegistry;
 use monochange_core::PublishState;
 use monochange_core::RegistryKind;
 use monochange_core::ShellConfig;
 use monochange_core::SourceProvider;
+use monochange_core::VersionScheme;
 use monochange_core::lint::ChangesetLintSettings;
 use monochange_core::lint::ChangesetScopedLintSettings;
 use monochange_core::lint::ChangesetSummaryLintSettings;
@@ -1118,6 +1124,55 @@ fn load_workspace_configuration_rejects_multi_group_membership() {
 	assert!(rendered.contains("= note: the first snippet marks the primary failure location"));
 }
 
+#[test]
+fn load_workspace_configuration_loads_linked_groups_apart_from_version_groups() {
+	let root = fixture_path("config/linked-groups");
+	let configuration = load_workspace_configuration(&root)
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+
+	assert!(configuration.groups.is_empty());
+	assert_eq!(
+		configuration.linked_groups,
+		[LinkedGroupDefinition {
+			id: "sdk".to_string(),
+			packages: vec!["core".to_string(), "api".to_string()],
+		}]
+	);
+	assert!(configuration.group_for_package("api").is_none());
+	assert_eq!(
+		configuration
+			.linked_group_for_package("api")
+			.map(|group| group.id.as_str()),
+		Some("sdk")
+	);
+}
+
+#[test]
+fn load_workspace_configuration_rejects_version_settings_on_linked_groups() {
+	let root = fixture_path("config/rejects-linked-group-version-settings");
+	let error = load_workspace_configuration(&root)
+		.err()
+		.unwrap_or_else(|| panic!("expected configuration error"));
+	let rendered = error.render();
+
+	assert!(rendered.contains("linked group `sdk` cannot set `tag`"));
+	assert!(rendered.contains("move the setting to each [package.<id>] entry"));
+}
+
+#[test]
+fn load_workspace_configuration_rejects_packages_in_linked_and_version_groups() {
+	let root = fixture_path("config/rejects-linked-and-version-group-membership");
+	let error = load_workspace_configuration(&root)
+		.err()
+		.unwrap_or_else(|| panic!("expected configuration error"));
+
+	assert!(
+		error
+			.render()
+			.contains("package `api` belongs to multiple groups: `sdk` and `linked`")
+	);
+}
+
 #[test]
 fn load_workspace_configuration_rejects_duplicate_primary_version_format() {
 	let root = fixture_path("config/rejects-duplicate-primary-version");
@@ -1133,6 +1188,132 @@ fn load_workspace_configuration_rejects_duplicate_primary_version_format() {
 	);
 }
 
+#[test]
+fn load_workspace_configuration_parses_version_schemes() {
+	let root = fixture_path("config/version-scheme-calver");
+	let configuration = load_workspace_configuration(&root)
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let version_scheme = |package_id: &str| {
+		configuration
+			.package_by_id(package_id)
+			.unwrap_or_else(|| panic!("expected package `{package_id}`"))
+			.version_scheme
+			.clone()
+	};
+
+	assert_eq!(version_scheme("core"), VersionScheme::Semver);
+	assert_eq!(
+		version_scheme("app"),
+		VersionScheme::Calver {
+			format: CalverFormat::default(),
+		}
+	);
+	assert_eq!(
+		configuration
+			.group_by_id("bundle")
+			.map(|group| group.version_scheme.clone()),
+		Some(VersionScheme::Calver {
+			format: CalverFormat::parse("YY.WW.PATCH")
+				.unwrap_or_else(|error| panic!("calver format: {error}")),
+		})
+	);
+}
+
+#[test]
+fn load_workspace_configuration_resolves_pre_stable_policies() {
+	let root = fixture_path("config/pre-stable-policy");
+	let configuration = load_workspace_configuration(&root)
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let package_policy = |package_id: &str| {
+		configuration
+			.package_by_id(package_id)
+			.unwrap_or_else(|| panic!("expected package `{package_id}`"))
+			.pre_stable_policy
+	};
+
+	assert_eq!(
+		configuration.defaults.pre_stable_policy,
+		PreStablePolicy::Semver
+	);
+	assert_eq!(package_policy("core"), PreStablePolicy::Semver);
+	assert_eq!(package_policy("cli"), PreStablePolicy::Shift);
+	assert_eq!(package_policy("plugin"), PreStablePolicy::Semver);
+	assert_eq!(
+		configuration
+			.group_by_id("sdk")
+			.map(|group| group.pre_stable_policy),
+		Some(PreStablePolicy::Shift)
+	);
+	// Grouped packages follow their group.
+	assert_eq!(
+		configuration.pre_stable_policy_for_package("plugin"),
+		Some(PreStablePolicy::Shift)
+	);
+	assert_eq!(
+		configuration.pre_stable_policy_for_package("core"),
+		Some(PreStablePolicy::Semver)
+	);
+	assert_eq!(configuration.pre_stable_policy_for_package("missing"), None);
+}
+
+#[test]
+fn load_workspace_configuration_merges_propagation_rules_per_kind() {
+	let root = fixture_path("config/propagation-by-kind");
+	let configuration = load_workspace_configuration(&root)
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+	let package_rules = |package_id: &str| {
+		configuration
+			.package_by_id(package_id)
+			.unwrap_or_else(|| panic!("expected package `{package_id}`"))
+			.propagation_by_kind
+	};
+	let defaults = PropagationByKind {
+		runtime: DependencyKindRule::ParentBump,
+		development: DependencyKindRule::None,
+		build: DependencyKindRule::ParentBump,
+		peer: DependencyKindRule::Match,
+	};
+
+	assert_eq!(configuration.defaults.propagation_by_kind, defaults);
+	assert_eq!(package_rules("core"), defaults);
+	// Package tables only override the kinds they name.
+	assert_eq!(
+		package_rules("cli"),
+		PropagationByKind {
+			development: DependencyKindRule::ParentBump,
+			build: DependencyKindRule::Patch,
+			..defaults
+		}
+	);
+}
+
+#[test]
+fn load_workspace_configuration_rejects_zero_padded_calver_segments() {
+	let root = fixture_path("config/rejects-invalid-calver-format");
+	let error = load_workspace_configuration(&root)
+		.err()
+		.unwrap_or_else(|| panic!("expected configuration error"));
+	let rendered = error.render();
+
+	assert!(rendered.contains("package `app` has an invalid calver format"));
+	assert!(rendered.contains("semver versions cannot have leading zeros"));
+	assert!(rendered.contains("version scheme"));
+}
+
+#[test]
+fn load_workspace_configuration_rejects_unknown_version_schemes() {
+	let root = fixture_path("config/rejects-unknown-version-scheme");
+	let error = load_workspace_configuration(&root)
+		.err()
+		.unwrap_or_else(|| panic!("expected configuration error"));
+	let rendered = error.render();
+
+	assert!(rendered.contains("package `app` has unknown version scheme `romver`"));
+	assert!(
+		rendered.contains("use `version_scheme = \"semver\"` or `version_scheme = \"calver\"`")
+	);
+}
+
 #[test]
 fn load_workspace_configuration_rejects_unknown_versioned_file_dependencies() {
 	let root = fixture_path("config/rejects-unknown-versioned-dep");
@@ -1437,6 +1618,92 @@ type = "go"
 	);
 }
 
+#[test]
+fn load_workspace_configuration_defaults_jvm_packages_to_external_publishing() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	std::fs::create_dir_all(root.join("platform/core"))
+		.unwrap_or_else(|error| panic!("create package dir: {error}"));
+	std::fs::write(
+		root.join("platform/core/pom.xml"),
+		"<project><groupId>com.acme</groupId><artifactId>core</artifactId><version>1.0.0</version></project>\n",
+	)
+	.unwrap_or_else(|error| panic!("write pom.xml: {error}"));
+	let config = r#"[package.core]
+path = "platform/core"
+type = "jvm"
+"#;
+	std::fs::write(root.join("monochange.toml"), config)
+		.unwrap_or_else(|error| panic!("write config: {error}"));
+
+	let configuration =
+		load_workspace_configuration(root).unwrap_or_else(|error| panic!("configuration: {error}"));
+	let package = configuration
+		.packages
+		.iter()
+		.find(|package| package.id == "core")
+		.unwrap_or_else(|| panic!("expected core package"));
+	assert_eq!(package.package_type, monochange_core::PackageType::Jvm);
+	assert_eq!(package.publish.mode, PublishMode::External);
+	assert_eq!(
+		package.publish.registry,
+		Some(PublishRegistry::Builtin(RegistryKind::Maven))
+	);
+
+	std::fs::write(
+		root.join("monochange.toml"),
+		format!("{config}\n[package.core.publish]\nmode = \"builtin\"\n"),
+	)
+	.unwrap_or_else(|error| panic!("write config: {error}"));
+	let error =
+		load_workspace_configuration(root).expect_err("built-in JVM publishing should be rejected");
+	let message = error.to_string();
+	assert!(
+		message.contains("package `core` uses built-in publishing for a JVM package"),
+		"unexpected error: {message}"
+	);
+}
+
+#[test]
+fn load_workspace_configuration_accepts_helm_chart_destinations_for_builtin_publishing() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	std::fs::create_dir_all(root.join("charts/api"))
+		.unwrap_or_else(|error| panic!("create chart dir: {error}"));
+	std::fs::write(
+		root.join("charts/api/Chart.yaml"),
+		"apiVersion: v2\nname: api\nversion: 1.0.0\n",
+	)
+	.unwrap_or_else(|error| panic!("write Chart.yaml: {error}"));
+	std::fs::write(
+		root.join("monochange.toml"),
+		r#"[package.api]
+path = "charts/api"
+type = "helm"
+
+[package.api.publish]
+mode = "builtin"
+registry = "oci://ghcr.io/acme/charts"
+"#,
+	)
+	.unwrap_or_else(|error| panic!("write config: {error}"));
+
+	let configuration =
+		load_workspace_configuration(root).unwrap_or_else(|error| panic!("configuration: {error}"));
+	let package = configuration
+		.packages
+		.iter()
+		.find(|package| package.id == "api")
+		.unwrap_or_else(|| panic!("expected api package"));
+	assert_eq!(package.publish.mode, PublishMode::Builtin);
+	assert_eq!(
+		package.publish.registry,
+		Some(PublishRegistry::Custom(
+			"oci://ghcr.io/acme/charts".to_string()
+		))
+	);
+}
+
 #[test]
 fn load_workspace_configuration_reports_python_ecosystem_normalization_errors() {
 	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
@@ -4469,6 +4736,9 @@ fn package_definition(id: &str, path: &str) -> monochange_core::PackageDefinitio
 		tag: true,
 		release: true,
 		version_format: monochange_core::VersionFormat::Namespaced,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
+		propagation_by_kind: PropagationByKind::default(),
 		publish: monochange_core::PublishSettings::default(),
 	}
 }
@@ -4889,6 +5159,8 @@ fn infer_bump_helpers_cover_major_minor_patch_and_none() {
 		tag: true,
 		release: true,
 		version_format: monochange_core::VersionFormat::Primary,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
 	};
 	assert_eq!(
 		infer_group_bump_from_explicit_version(
@@ -4916,6 +5188,8 @@ fn infer_bump_helpers_cover_major_minor_patch_and_none() {
 		tag: true,
 		release: true,
 		version_format: monochange_core::VersionFormat::Primary,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
 	};
 	let error = infer_group_bump_from_explicit_version(
 		&group_with_missing,
@@ -4968,6 +5242,7 @@ fn validate_source_and_changeset_settings_reject_empty_values() {
 				skip_labels: vec![String::new()],
 				..Default::default()
 			},
+			..Default::default()
 		},
 		&[],
 	)
@@ -4985,6 +5260,7 @@ fn validate_source_and_changeset_settings_reject_empty_values() {
 				changed_paths: vec![" ".to_string()],
 				..Default::default()
 			},
+			..Default::default()
 		},
 		&[],
 	)
@@ -5015,6 +5291,88 @@ fn validate_source_and_changeset_settings_reject_empty_values() {
 	);
 }
 
+#[test]
+fn validate_changesets_configuration_rejects_empty_and_duplicate_sources() {
+	let empty = crate::validate_changesets_configuration(
+		&monochange_core::ChangesetSettings {
+			sources: Vec::new(),
+			..Default::default()
+		},
+		&[],
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected empty sources error"));
+	assert!(
+		empty
+			.to_string()
+			.contains("[changesets].sources must include at least one change source")
+	);
+
+	let duplicate = crate::validate_changesets_configuration(
+		&monochange_core::ChangesetSettings {
+			sources: vec![
+				monochange_core::ChangeSource::ConventionalCommits,
+				monochange_core::ChangeSource::ConventionalCommits,
+			],
+			..Default::default()
+		},
+		&[],
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected duplicate sources error"));
+	assert!(
+		duplicate
+			.to_string()
+			.contains("[changesets].sources lists `conventional_commits` more than once")
+	);
+}
+
+#[test]
+fn load_workspace_configuration_reads_conventional_commit_sources() {
+	let configuration = load_workspace_configuration(&fixture_path("config/conventional-commits"))
+		.unwrap_or_else(|error| panic!("configuration: {error}"));
+
+	assert_eq!(
+		configuration.changesets.sources,
+		vec![monochange_core::ChangeSource::ConventionalCommits]
+	);
+	assert!(
+		!configuration
+			.changesets
+			.reads_from(monochange_core::ChangeSource::Files)
+	);
+	assert_eq!(
+		configuration
+			.changesets
+			.conventional_commits
+			.scopes
+			.get("engine")
+			.map(String::as_str),
+		Some("core")
+	);
+}
+
+#[test]
+fn load_workspace_configuration_rejects_unknown_conventional_commit_scope_targets() {
+	let tempdir = setup_fixture("config/conventional-commits");
+	let config_path = tempdir.path().join("monochange.toml");
+	let contents = std::fs::read_to_string(&config_path)
+		.unwrap_or_else(|error| panic!("read config: {error}"));
+	std::fs::write(
+		&config_path,
+		contents.replace("engine = \"core\"", "engine = \"missing\""),
+	)
+	.unwrap_or_else(|error| panic!("write config: {error}"));
+
+	let error = load_workspace_configuration(tempdir.path())
+		.err()
+		.unwrap_or_else(|| panic!("expected unknown scope target error"));
+
+	assert!(error.to_string().contains(
+		"[changesets.conventional_commits.scopes] maps `engine` to unknown package or group `missing`"
+	));
+}
+
 #[test]
 fn validate_changesets_configuration_rejects_invalid_additional_path_globs() {
 	let error = crate::validate_changesets_configuration(
@@ -5469,6 +5827,7 @@ fn validate_cli_runtime_requirements_enforce_affected_package_inputs() {
 				enabled: false,
 				..Default::default()
 			},
+			..Default::default()
 		},
 		Some(&sample_source_configuration(SourceProvider::GitHub)),
 	)
@@ -5540,6 +5899,7 @@ fn validate_package_and_source_settings_cover_duplicate_and_pattern_errors() {
 			package_definition("util", "crates/core"),
 		],
 		&[],
+		&[],
 	)
 	.err()
 	.unwrap_or_else(|| panic!("expected duplicate path error"));
@@ -5558,6 +5918,7 @@ fn validate_package_and_source_settings_cover_duplicate_and_pattern_errors() {
 		"[package.core]\nversion_format = 'primary'\n\n[package.util]\nversion_format = 'primary'\n",
 		&[primary_core, primary_util],
 		&[],
+		&[],
 	)
 	.err()
 	.unwrap_or_else(|| panic!("expected duplicate primary error"));
@@ -5573,6 +5934,7 @@ fn validate_package_and_source_settings_cover_duplicate_and_pattern_errors() {
 				changed_paths: vec!["[".to_string()],
 				..Default::default()
 			},
+			..Default::default()
 		},
 		&[],
 	)
@@ -5607,6 +5969,7 @@ fn validate_package_and_source_settings_cover_duplicate_and_pattern_errors() {
 			package_definition("core", "crates/util"),
 		],
 		&[],
+		&[],
 	)
 	.err()
 	.unwrap_or_else(|| panic!("expected duplicate id error"));
@@ -5624,6 +5987,7 @@ fn validate_package_and_source_settings_cover_duplicate_and_pattern_errors() {
 		"[package.core]\npath = 'crates/core'\ntype = 'cargo'\n",
 		&[package_definition("core", "crates/core")],
 		&[],
+		&[],
 	)
 	.err()
 	.unwrap_or_else(|| panic!("expected missing manifest error"));
@@ -6275,6 +6639,9 @@ fn matching_package_helpers_cover_references_and_definitions() {
 		tag: true,
 		release: true,
 		version_format: monochange_core::VersionFormat::Primary,
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
+		propagation_by_kind: PropagationByKind::default(),
 		publish: monochange_core::PublishSettings::default(),
 	};
 	assert_eq!(
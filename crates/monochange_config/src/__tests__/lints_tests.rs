@@ -28,6 +28,7 @@ fn lint_file(body: &str, changes: Vec<RawChangeEntry>) -> ChangesetLintFile {
 	ChangesetLintFile {
 		body: body.to_string(),
 		changes,
+		effective_bumps: BTreeMap::new(),
 	}
 }
 
@@ -51,6 +52,7 @@ fn workspace_configuration(root: &Path) -> WorkspaceConfiguration {
 		changelog: monochange_core::ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: monochange_core::ChangesetSettings::default(),
 		source: None,
@@ -61,6 +63,9 @@ fn workspace_configuration(root: &Path) -> WorkspaceConfiguration {
 		dart: monochange_core::EcosystemSettings::default(),
 		python: monochange_core::EcosystemSettings::default(),
 		go: monochange_core::EcosystemSettings::default(),
+		helm: monochange_core::EcosystemSettings::default(),
+		ruby: monochange_core::EcosystemSettings::default(),
+		jvm: monochange_core::EcosystemSettings::default(),
 	}
 }
 
@@ -223,6 +228,75 @@ fn collect_targets_filters_and_parses_changeset_files() {
 	);
 }
 
+#[test]
+fn collect_targets_applies_pre_stable_policies_to_bumps() {
+	let tempdir = must(tempfile::tempdir(), "tempdir");
+	for package in ["core", "api", "stable"] {
+		let package_dir = tempdir.path().join("crates").join(package);
+		must(fs::create_dir_all(&package_dir), "package dir");
+		must(
+			fs::write(
+				package_dir.join("Cargo.toml"),
+				format!("[package]\nname = \"{package}\"\nversion = \"0.1.0\"\n"),
+			),
+			"write manifest",
+		);
+	}
+	must(
+		fs::write(
+			tempdir.path().join("monochange.toml"),
+			"[defaults]\npackage_type = \"cargo\"\n\n[package.core]\npath = \"crates/core\"\n\n[package.api]\npath = \"crates/api\"\npre_stable_policy = \"semver\"\n\n[package.stable]\npath = \"crates/stable\"\n",
+		),
+		"write config",
+	);
+	let changeset_dir = tempdir.path().join(".changeset");
+	must(fs::create_dir_all(&changeset_dir), "changeset dir");
+	must(
+		fs::write(
+			changeset_dir.join("change.md"),
+			"---\ncore: major\napi: major\nstable: major\n---\n\n# Break everything\n",
+		),
+		"write changeset",
+	);
+
+	let configuration = must(
+		crate::load_workspace_configuration(tempdir.path()),
+		"configuration",
+	);
+	let suite = ChangesetLintSuite::new().with_package_versions(BTreeMap::from([
+		("core".to_string(), Version::new(0, 3, 1)),
+		("api".to_string(), Version::new(0, 2, 0)),
+		("stable".to_string(), Version::new(2, 0, 0)),
+	]));
+	let targets = must(
+		suite.collect_targets(tempdir.path(), &configuration),
+		"collect targets",
+	);
+	let parsed = targets
+		.first()
+		.and_then(|target| target.parsed.downcast_ref::<ChangesetLintFile>())
+		.expect("changeset lint file");
+	assert_eq!(
+		parsed.effective_bumps,
+		BTreeMap::from([("core".to_string(), BumpSeverity::Minor)])
+	);
+
+	let mut options = BTreeMap::new();
+	options.insert("required_sections".to_string(), json!(["Migration"]));
+	let major_results = run_rule(
+		&BumpScopeRule::new(BumpSeverity::Major),
+		parsed,
+		&detailed(options.clone()),
+	);
+	assert_eq!(major_results.len(), 2, "api and stable release majors");
+	let minor_results = run_rule(
+		&BumpScopeRule::new(BumpSeverity::Minor),
+		parsed,
+		&detailed(options),
+	);
+	assert_eq!(minor_results.len(), 1, "core shifts major to minor");
+}
+
 #[test]
 fn collect_targets_handles_missing_and_invalid_changeset_directories() {
 	let tempdir = must(tempfile::tempdir(), "tempdir");
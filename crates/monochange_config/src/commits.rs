@@ -0,0 +1,626 @@
+#![forbid(clippy::indexing_slicing)]
+
+//! Commit-derived change sources for monochange.
+//!
+//! `[changesets].sources` can ask monochange to read release intent from the
+//! commits made since the last release record instead of (or in addition to)
+//! `.changeset/*.md` files. This module loads that commit range from git and
+//! turns conventional commits and `Changeset:` trailers into the same
+//! [`LoadedChangesetFile`] model the markdown loader produces, so planning,
+//! changelogs, and diagnostics do not need to know where a change came from.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_core::BumpSeverity;
+use monochange_core::ChangesetRevision;
+use monochange_core::HostedActorRef;
+use monochange_core::HostedActorSourceKind;
+use monochange_core::HostedCommitRef;
+use monochange_core::HostingProviderKind;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::ReleaseRecord;
+use monochange_core::git::git_command;
+use monochange_core::git::git_error_detail;
+
+use crate::ChangesetLoadContext;
+use crate::LoadedChangesetFile;
+use crate::RawChangeEntry;
+use crate::RawChangeFile;
+use crate::configured_change_types_with_context;
+use crate::parse_markdown_change_target_with_context;
+use crate::resolve_raw_change_file;
+
+/// Directory that holds committed release records.
+const RELEASE_RECORDS_DIR: &str = ".monochange/releases";
+
+/// Directory that holds markdown changesets; touching it is not a package change.
+const CHANGESET_DIR: &str = ".changeset";
+
+/// Trailer token that declares release intent in a commit message.
+const CHANGESET_TRAILER: &str = "Changeset";
+
+/// One commit in the range since the last release record.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReleaseRangeCommit {
+	pub sha: String,
+	pub author_name: String,
+	pub author_email: String,
+	pub authored_at: String,
+	pub committed_at: String,
+	pub message: String,
+	/// Files touched by the commit, relative to the workspace root.
+	pub paths: Vec<PathBuf>,
+	/// Set when a prerelease record made after this commit already shipped it.
+	/// Only ranges that start at the last stable release report these commits.
+	pub prereleased: bool,
+}
+
+impl ReleaseRangeCommit {
+	/// Return the abbreviated commit sha.
+	#[must_use]
+	pub fn short_sha(&self) -> String {
+		self.sha.chars().take(7).collect()
+	}
+
+	/// Return the first line of the commit message.
+	#[must_use]
+	pub fn subject(&self) -> &str {
+		self.message.lines().next().unwrap_or_default().trim()
+	}
+
+	/// Return `true` when the commit message ends with a `Changeset:` trailer.
+	#[must_use]
+	pub fn has_changeset_trailers(&self) -> bool {
+		split_commit_message(&self.message)
+			.trailers
+			.iter()
+			.any(|(token, _)| token.eq_ignore_ascii_case(CHANGESET_TRAILER))
+	}
+
+	/// Describe the commit as a changeset revision.
+	#[must_use]
+	pub fn revision(&self) -> ChangesetRevision {
+		ChangesetRevision {
+			actor: Some(HostedActorRef {
+				provider: HostingProviderKind::GenericGit,
+				host: None,
+				id: None,
+				login: None,
+				display_name: Some(self.author_name.clone()),
+				url: None,
+				source: HostedActorSourceKind::CommitAuthor,
+			}),
+			commit: Some(HostedCommitRef {
+				provider: HostingProviderKind::GenericGit,
+				host: None,
+				sha: self.sha.clone(),
+				short_sha: self.short_sha(),
+				url: None,
+				authored_at: Some(self.authored_at.clone()),
+				committed_at: Some(self.committed_at.clone()),
+				author_name: Some(self.author_name.clone()),
+				author_email: Some(self.author_email.clone()),
+			}),
+			review_request: None,
+		}
+	}
+}
+
+#[must_use = "the commit result must be checked"]
+/// Load the non-merge commits made since the last release record, oldest first.
+///
+/// The range starts after the most recent commit that added a file under
+/// `.monochange/releases`. With `since_stable_release`, it starts after the
+/// most recent record whose release targets are all stable instead, and the
+/// commits that later prerelease records already shipped are marked as
+/// [`ReleaseRangeCommit::prereleased`]. Repositories without a matching
+/// release record use the full history of `HEAD`, and directories that are
+/// not inside a git repository (or have no commits yet) have no commits to
+/// read.
+pub fn load_release_range_commits(
+	root: &Path,
+	since_stable_release: bool,
+) -> MonochangeResult<Vec<ReleaseRangeCommit>> {
+	let head = git_command(root)
+		.args(["rev-parse", "--verify", "--quiet", "HEAD"])
+		.output()
+		.map_err(|error| MonochangeError::Io(format!("failed to run git rev-parse: {error}")))?;
+	if !head.status.success() {
+		return Ok(Vec::new());
+	}
+
+	let records = release_record_commits(root)?;
+	let last_release = records.first().map(|(sha, _)| sha.as_str());
+	let anchor = if since_stable_release {
+		let mut stable = None;
+		for (sha, paths) in &records {
+			if is_stable_release_record(root, sha, paths)? {
+				stable = Some(sha.as_str());
+				break;
+			}
+		}
+		stable
+	} else {
+		last_release
+	};
+	let range = anchor.map_or_else(|| "HEAD".to_string(), |anchor| format!("{anchor}..HEAD"));
+	let prereleased = match last_release {
+		Some(last_release) if anchor != Some(last_release) => {
+			let shipped = anchor.map_or_else(
+				|| last_release.to_string(),
+				|anchor| format!("{anchor}..{last_release}"),
+			);
+			run_git(
+				root,
+				&["rev-list", shipped.as_str()],
+				"list commits shipped by prerelease records",
+			)?
+			.lines()
+			.map(|line| line.trim().to_string())
+			.collect::<BTreeSet<_>>()
+		}
+		_ => BTreeSet::new(),
+	};
+
+	let output = run_git(
+		root,
+		&[
+			"log",
+			"--reverse",
+			"--no-merges",
+			"--relative",
+			"--name-only",
+			"--format=%x1e%H%x1f%an%x1f%ae%x1f%aI%x1f%cI%x1f%B%x1f",
+			range.as_str(),
+		],
+		"read commits since the last release",
+	)?;
+	let mut commits = parse_release_range_log(&output);
+	for commit in &mut commits {
+		commit.prereleased = prereleased.contains(&commit.sha);
+	}
+	Ok(commits)
+}
+
+/// Commits that added release records, newest first, with the record files
+/// each one added.
+fn release_record_commits(root: &Path) -> MonochangeResult<Vec<(String, Vec<String>)>> {
+	let output = run_git(
+		root,
+		&[
+			"log",
+			"--format=%x1e%H",
+			"--diff-filter=A",
+			"--name-only",
+			"--relative",
+			"--",
+			RELEASE_RECORDS_DIR,
+		],
+		"find release record commits",
+	)?;
+	Ok(output
+		.split('\u{1e}')
+		.filter_map(|record| {
+			let mut lines = record.lines().map(str::trim).filter(|line| !line.is_empty());
+			let sha = lines.next()?.to_string();
+			Some((sha, lines.map(ToString::to_string).collect()))
+		})
+		.collect())
+}
+
+/// Return `true` when every release record `sha` added only releases stable
+/// versions.
+fn is_stable_release_record(root: &Path, sha: &str, paths: &[String]) -> MonochangeResult<bool> {
+	for path in paths.iter().filter(|path| path.ends_with("release.json")) {
+		let object = format!("{sha}:./{path}");
+		let contents = run_git(root, &["show", object.as_str()], "read a release record")?;
+		let Ok(record) = serde_json::from_str::<ReleaseRecord>(&contents) else {
+			continue;
+		};
+		if record
+			.release_targets
+			.iter()
+			.any(|target| target.prerelease.is_some())
+		{
+			return Ok(false);
+		}
+	}
+	Ok(true)
+}
+
+fn run_git(root: &Path, args: &[&str], action: &str) -> MonochangeResult<String> {
+	let output = git_command(root)
+		.args(args)
+		.output()
+		.map_err(|error| MonochangeError::Io(format!("failed to {action}: {error}")))?;
+	if !output.status.success() {
+		return Err(MonochangeError::Config(format!(
+			"failed to {action}: {}",
+			git_error_detail(&output)
+		)));
+	}
+	Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse `git log` output written with the record/unit separator format used
+/// by [`load_release_range_commits`].
+fn parse_release_range_log(stdout: &str) -> Vec<ReleaseRangeCommit> {
+	stdout
+		.split('\u{1e}')
+		.filter_map(|record| {
+			let mut fields = record.splitn(7, '\u{1f}');
+			let sha = fields.next()?.trim();
+			if sha.is_empty() {
+				return None;
+			}
+			let author_name = fields.next()?;
+			let author_email = fields.next()?;
+			let authored_at = fields.next()?;
+			let committed_at = fields.next()?;
+			let message = fields.next()?;
+			let paths = fields
+				.next()
+				.unwrap_or_default()
+				.lines()
+				.map(str::trim)
+				.filter(|line| !line.is_empty())
+				.map(PathBuf::from)
+				.collect();
+			Some(ReleaseRangeCommit {
+				sha: sha.to_string(),
+				author_name: author_name.to_string(),
+				author_email: author_email.to_string(),
+				authored_at: authored_at.to_string(),
+				committed_at: committed_at.to_string(),
+				message: message.trim().to_string(),
+				paths,
+				prereleased: false,
+			})
+		})
+		.collect()
+}
+
+/// A commit message split into its subject, body paragraphs, and trailers.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub(crate) struct CommitMessageParts {
+	pub(crate) subject: String,
+	pub(crate) body: Vec<String>,
+	pub(crate) trailers: Vec<(String, String)>,
+}
+
+/// Split a commit message into subject, body paragraphs, and trailing
+/// `Token: value` lines.
+///
+/// The final paragraph counts as the trailer block only when every line in it
+/// is a trailer or a whitespace-indented continuation of the previous one, so
+/// a wrapped `BREAKING CHANGE` footer keeps its full text. `BREAKING CHANGE` is
+/// accepted as a token even though it contains a space.
+pub(crate) fn split_commit_message(message: &str) -> CommitMessageParts {
+	let message = message.replace("\r\n", "\n");
+	let mut lines = message.lines();
+	let subject = lines.next().unwrap_or_default().trim().to_string();
+	let rest = lines.collect::<Vec<_>>().join("\n");
+	let mut body = rest
+		.split("\n\n")
+		.map(str::trim)
+		.filter(|paragraph| !paragraph.is_empty())
+		.map(ToString::to_string)
+		.collect::<Vec<_>>();
+
+	let mut trailers = Vec::new();
+	if let Some(parsed) = body.last().and_then(|last| parse_trailer_block(last)) {
+		trailers = parsed;
+		body.pop();
+	}
+
+	CommitMessageParts {
+		subject,
+		body,
+		trailers,
+	}
+}
+
+/// Parse a paragraph as a trailer block, folding whitespace-indented
+/// continuation lines into the preceding trailer the way git does. Returns
+/// `None` when any other line is not a `Token: value` trailer.
+fn parse_trailer_block(paragraph: &str) -> Option<Vec<(String, String)>> {
+	let mut trailers: Vec<(String, String)> = Vec::new();
+	for line in paragraph.lines() {
+		let indented = line.starts_with([' ', '\t']);
+		if let (false, Some(trailer)) = (indented, parse_trailer(line)) {
+			trailers.push(trailer);
+		} else if indented {
+			let (_, value) = trailers.last_mut()?;
+			let continuation = line.trim();
+			if !continuation.is_empty() {
+				value.push('\n');
+				value.push_str(continuation);
+			}
+		} else {
+			return None;
+		}
+	}
+	(!trailers.is_empty()).then_some(trailers)
+}
+
+fn parse_trailer(line: &str) -> Option<(String, String)> {
+	let (token, value) = line.split_once(':')?;
+	let token = token.trim();
+	let value = value.trim();
+	let valid_token = token == "BREAKING CHANGE"
+		|| (!token.is_empty()
+			&& token
+				.chars()
+				.all(|character| character.is_ascii_alphanumeric() || character == '-'));
+	(valid_token && !value.is_empty()).then(|| (token.to_string(), value.to_string()))
+}
+
+/// A parsed `type(scope)!: description` commit message.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConventionalCommit {
+	pub commit_type: String,
+	pub scopes: Vec<String>,
+	pub breaking: bool,
+	pub description: String,
+	pub body: Option<String>,
+}
+
+/// Parse a conventional commit message.
+///
+/// Returns `None` when the subject does not follow the
+/// `type(scope)!: description` shape. Multiple scopes may be separated by
+/// commas. A `!` before the colon or a `BREAKING CHANGE:` trailer marks the
+/// commit as breaking, and the trailer text is kept as part of the body.
+#[must_use]
+pub fn parse_conventional_commit(message: &str) -> Option<ConventionalCommit> {
+	let parts = split_commit_message(message);
+	let (prefix, description) = parts.subject.split_once(": ")?;
+	let description = description.trim();
+	if description.is_empty() {
+		return None;
+	}
+	let (prefix, bang) = match prefix.strip_suffix('!') {
+		Some(prefix) => (prefix, true),
+		None => (prefix, false),
+	};
+	let (commit_type, scopes) = match prefix.split_once('(') {
+		Some((commit_type, scope)) => {
+			let scope = scope.strip_suffix(')')?;
+			let scopes = scope
+				.split(',')
+				.map(str::trim)
+				.filter(|scope| !scope.is_empty())
+				.map(ToString::to_string)
+				.collect::<Vec<_>>();
+			(commit_type, scopes)
+		}
+		None => (prefix, Vec::new()),
+	};
+	if commit_type.is_empty()
+		|| !commit_type
+			.chars()
+			.all(|character| character.is_ascii_alphanumeric() || character == '-')
+	{
+		return None;
+	}
+
+	let breaking_notes = parts
+		.trailers
+		.iter()
+		.filter(|(token, _)| token == "BREAKING CHANGE" || token == "BREAKING-CHANGE")
+		.map(|(_, value)| value.clone())
+		.collect::<Vec<_>>();
+	let mut body = parts.body;
+	body.extend(breaking_notes.iter().cloned());
+
+	Some(ConventionalCommit {
+		commit_type: commit_type.to_ascii_lowercase(),
+		scopes,
+		breaking: bang || !breaking_notes.is_empty(),
+		description: description.to_string(),
+		body: (!body.is_empty()).then(|| body.join("\n\n")),
+	})
+}
+
+#[must_use = "the changeset result must be checked"]
+/// Turn conventional commits into loaded changesets.
+///
+/// Each commit targets the packages or groups named by its scopes, either
+/// through `[changesets.conventional_commits.scopes]` or by matching a package
+/// or group id directly. Commits without a usable scope fall back to the
+/// configured packages that own the files the commit touched. A target is kept
+/// when the commit type is one of its configured `[changelog.types]`; breaking
+/// commits are always kept and request a major bump. Commits that do not parse
+/// or resolve to no target are skipped, and so are targets that a markdown
+/// changeset already declares for the same change.
+pub fn load_conventional_commit_changesets(
+	commits: &[ReleaseRangeCommit],
+	file_changesets: &[LoadedChangesetFile],
+	context: &ChangesetLoadContext<'_>,
+) -> MonochangeResult<Vec<LoadedChangesetFile>> {
+	let mut changesets = Vec::new();
+	for commit in commits {
+		let Some(parsed) = parse_conventional_commit(&commit.message) else {
+			continue;
+		};
+		let changes = conventional_commit_targets(commit, &parsed, context)
+			.into_iter()
+			.filter(|target| {
+				!declared_by_file(commit, target, &parsed.description, file_changesets)
+			})
+			.filter_map(|target| {
+				let configured = configured_change_types_with_context(context, &target)
+					.contains(&parsed.commit_type);
+				if !configured && !parsed.breaking {
+					return None;
+				}
+				Some(RawChangeEntry {
+					package: target,
+					bump: parsed.breaking.then_some(BumpSeverity::Major),
+					version: None,
+					reason: Some(parsed.description.clone()),
+					details: parsed.body.clone(),
+					change_type: configured.then(|| parsed.commit_type.clone()),
+					caused_by: Vec::new(),
+				})
+			})
+			.collect::<Vec<_>>();
+		if changes.is_empty() {
+			continue;
+		}
+		let path = PathBuf::from(format!("commit:{}", commit.short_sha()));
+		let mut changeset = resolve_raw_change_file(
+			&path,
+			&commit.message,
+			RawChangeFile { changes },
+			"conventional-commit",
+			context,
+		)?;
+		changeset.source_revision = Some(commit.revision());
+		changesets.push(changeset);
+	}
+	Ok(changesets)
+}
+
+#[must_use = "the changeset result must be checked"]
+/// Turn `Changeset: <target>: <bump or type>` commit trailers into loaded
+/// changesets.
+///
+/// Each trailer value is read like one frontmatter entry of a markdown
+/// changeset, so it accepts a bump or a configured change type. The commit
+/// subject becomes the summary and the remaining body paragraphs become the
+/// details. A trailer is skipped when a markdown changeset already declares
+/// the same target, either because the commit added that file or because the
+/// file carries the same summary.
+pub fn load_commit_trailer_changesets(
+	commits: &[ReleaseRangeCommit],
+	file_changesets: &[LoadedChangesetFile],
+	context: &ChangesetLoadContext<'_>,
+) -> MonochangeResult<Vec<LoadedChangesetFile>> {
+	let mut changesets = Vec::new();
+	for commit in commits {
+		let parts = split_commit_message(&commit.message);
+		let path = PathBuf::from(format!("commit:{}", commit.short_sha()));
+		let details = (!parts.body.is_empty()).then(|| parts.body.join("\n\n"));
+		let mut changes = Vec::new();
+		for (token, value) in &parts.trailers {
+			if !token.eq_ignore_ascii_case(CHANGESET_TRAILER) {
+				continue;
+			}
+			let Some((target, intent)) = value
+				.rsplit_once(':')
+				.map(|(target, intent)| (target.trim(), intent.trim()))
+				.filter(|(target, intent)| !target.is_empty() && !intent.is_empty())
+			else {
+				return Err(MonochangeError::Config(format!(
+					"commit `{}` has an invalid `{CHANGESET_TRAILER}` trailer `{value}`; expected `{CHANGESET_TRAILER}: <package or group>: <bump or type>`",
+					commit.short_sha()
+				)));
+			};
+			if declared_by_file(commit, target, &parts.subject, file_changesets) {
+				continue;
+			}
+			let (bump, version, change_type, caused_by) = parse_markdown_change_target_with_context(
+				&serde_yaml_ng::Value::String(intent.to_string()),
+				&path,
+				target,
+				context,
+			)?;
+			changes.push(RawChangeEntry {
+				package: target.to_string(),
+				bump,
+				version,
+				reason: Some(parts.subject.clone()),
+				details: details.clone(),
+				change_type,
+				caused_by,
+			});
+		}
+		if changes.is_empty() {
+			continue;
+		}
+		let mut changeset = resolve_raw_change_file(
+			&path,
+			&commit.message,
+			RawChangeFile { changes },
+			"commit-trailer",
+			context,
+		)?;
+		changeset.source_revision = Some(commit.revision());
+		changesets.push(changeset);
+	}
+	Ok(changesets)
+}
+
+/// Return `true` when a markdown changeset already declares `target` for the
+/// change `commit` describes.
+fn declared_by_file(
+	commit: &ReleaseRangeCommit,
+	target: &str,
+	summary: &str,
+	file_changesets: &[LoadedChangesetFile],
+) -> bool {
+	file_changesets
+		.iter()
+		.filter(|changeset| changeset.source_revision.is_none())
+		.filter(|changeset| {
+			changeset.summary.as_deref() == Some(summary)
+				|| commit
+					.paths
+					.iter()
+					.any(|path| changeset.path.ends_with(path))
+		})
+		.any(|changeset| changeset.targets.iter().any(|declared| declared.id == target))
+}
+
+fn conventional_commit_targets(
+	commit: &ReleaseRangeCommit,
+	parsed: &ConventionalCommit,
+	context: &ChangesetLoadContext<'_>,
+) -> BTreeSet<String> {
+	let aliases = &context.configuration.changesets.conventional_commits.scopes;
+	let scoped = parsed
+		.scopes
+		.iter()
+		.filter_map(|scope| {
+			aliases.get(scope).cloned().or_else(|| {
+				(context.package_ids.contains(scope.as_str())
+					|| context.groups_by_id.contains_key(scope.as_str()))
+				.then(|| scope.clone())
+			})
+		})
+		.collect::<BTreeSet<_>>();
+	if !scoped.is_empty() {
+		return scoped;
+	}
+
+	commit
+		.paths
+		.iter()
+		.filter(|path| !path.starts_with(CHANGESET_DIR))
+		.filter_map(|path| owning_package_id(path, context))
+		.collect()
+}
+
+/// Return the configured package whose directory most specifically contains
+/// `path`.
+fn owning_package_id(path: &Path, context: &ChangesetLoadContext<'_>) -> Option<String> {
+	context
+		.configuration
+		.packages
+		.iter()
+		.filter_map(|package| {
+			let package_path = package.path.strip_prefix(".").unwrap_or(&package.path);
+			path.starts_with(package_path)
+				.then(|| (package_path.components().count(), package.id.clone()))
+		})
+		.max_by_key(|(depth, _)| *depth)
+		.map(|(_, id)| id)
+}
+
+#[cfg(test)]
+#[path = "__tests__/commits_tests.rs"]
+mod tests;
@@ -86,12 +86,15 @@ use std::path::PathBuf;
 use glob::Pattern;
 use miette::LabeledSpan;
 use miette::SourceSpan;
+use monochange_core::AnalysisEnforcement;
 use monochange_core::BumpSeverity;
+use monochange_core::CalverFormat;
 use monochange_core::ChangeSignal;
 use monochange_core::ChangelogDefinition;
 use monochange_core::ChangelogFormat;
 use monochange_core::ChangelogSettings;
 use monochange_core::ChangelogTarget;
+use monochange_core::ChangesetRevision;
 use monochange_core::ChangesetSettings;
 use monochange_core::ChangesetTargetKind;
 use monochange_core::CliCommandDefinition;
@@ -99,17 +102,24 @@ use monochange_core::CliInputDefinition;
 use monochange_core::CliInputKind;
 use monochange_core::CliStepDefinition;
 use monochange_core::CliStepInputValue;
+use monochange_core::DEFAULT_CALVER_FORMAT;
+use monochange_core::DependencyKindRule;
+use monochange_core::DependencyPropagation;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemSettings;
 use monochange_core::EcosystemType;
 use monochange_core::GroupChangelogInclude;
 use monochange_core::GroupDefinition;
+use monochange_core::GroupKind;
+use monochange_core::LinkedGroupDefinition;
 use monochange_core::LockfileCommandDefinition;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageDefinition;
 use monochange_core::PackageRecord;
 use monochange_core::PackageType;
+use monochange_core::PreStablePolicy;
+use monochange_core::PropagationByKind;
 use monochange_core::ProviderMergeRequestSettings;
 use monochange_core::ProviderReleaseNotesSource;
 use monochange_core::ProviderReleaseSettings;
@@ -125,6 +135,7 @@ use monochange_core::SourceProvider;
 use monochange_core::TrustedPublishingSettings;
 use monochange_core::VersionFormat;
 use monochange_core::VersionGroup;
+use monochange_core::VersionScheme;
 use monochange_core::VersionedFileDefinition;
 use monochange_core::WorkspaceConfiguration;
 use monochange_core::WorkspaceDefaults;
@@ -216,12 +227,20 @@ pub(crate) struct RawWorkspaceDefaults {
 	#[serde(default = "default_parent_bump")]
 	parent_bump: BumpSeverity,
 	#[serde(default)]
+	propagation: DependencyPropagation,
+	#[serde(default)]
+	propagation_by_kind: RawPropagationByKind,
+	#[serde(default)]
 	include_private: bool,
 	#[serde(default = "default_warn_on_group_mismatch")]
 	warn_on_group_mismatch: bool,
 	#[serde(default)]
 	strict_version_conflicts: bool,
 	#[serde(default)]
+	pre_stable_policy: PreStablePolicy,
+	#[serde(default)]
+	analysis_enforcement: AnalysisEnforcement,
+	#[serde(default)]
 	package_type: Option<PackageType>,
 	#[serde(default)]
 	changelog: Option<RawChangelogConfig>,
@@ -237,9 +256,13 @@ impl Default for RawWorkspaceDefaults {
 	fn default() -> Self {
 		Self {
 			parent_bump: default_parent_bump(),
+			propagation: DependencyPropagation::default(),
+			propagation_by_kind: RawPropagationByKind::default(),
 			include_private: false,
 			warn_on_group_mismatch: default_warn_on_group_mismatch(),
 			strict_version_conflicts: false,
+			pre_stable_policy: PreStablePolicy::default(),
+			analysis_enforcement: AnalysisEnforcement::default(),
 			package_type: None,
 			changelog: None,
 			empty_update_message: None,
@@ -276,6 +299,25 @@ pub(crate) enum RawGroupChangelogInclude {
 	Packages(Vec<String>),
 }
 
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schema", schemars(rename = "versionScheme"))]
+pub(crate) enum RawVersionScheme {
+	Name(String),
+	Detailed(RawVersionSchemeTable),
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", schemars(rename = "versionSchemeTable"))]
+pub(crate) struct RawVersionSchemeTable {
+	#[serde(rename = "type")]
+	scheme_type: String,
+	#[serde(default)]
+	format: Option<String>,
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, Deserialize, Default)]
 #[cfg_attr(feature = "schema", schemars(rename = "changelogTable"))]
@@ -324,6 +366,12 @@ pub(crate) struct RawPackageDefinition {
 	#[serde(default)]
 	version_format: VersionFormat,
 	#[serde(default)]
+	version_scheme: Option<RawVersionScheme>,
+	#[serde(default)]
+	pre_stable_policy: Option<PreStablePolicy>,
+	#[serde(default)]
+	propagation_by_kind: RawPropagationByKind,
+	#[serde(default)]
 	publish: RawPublishSettings,
 }
 
@@ -333,6 +381,8 @@ pub(crate) struct RawPackageDefinition {
 pub(crate) struct RawGroupDefinition {
 	packages: Vec<String>,
 	#[serde(default)]
+	kind: GroupKind,
+	#[serde(default)]
 	changelog: Option<RawChangelogConfig>,
 	#[serde(default)]
 	pub excluded_changelog_types: Vec<String>,
@@ -350,6 +400,10 @@ pub(crate) struct RawGroupDefinition {
 	release: bool,
 	#[serde(default)]
 	version_format: VersionFormat,
+	#[serde(default)]
+	version_scheme: Option<RawVersionScheme>,
+	#[serde(default)]
+	pre_stable_policy: Option<PreStablePolicy>,
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -382,6 +436,12 @@ pub(crate) struct RawEcosystems {
 	python: RawEcosystemSettings,
 	#[serde(default)]
 	go: RawEcosystemSettings,
+	#[serde(default)]
+	helm: RawEcosystemSettings,
+	#[serde(default)]
+	ruby: RawEcosystemSettings,
+	#[serde(default)]
+	jvm: RawEcosystemSettings,
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -416,6 +476,34 @@ pub(crate) struct RawPlaceholderSettings {
 	readme_file: Option<PathBuf>,
 }
 
+/// Per-kind propagation rules as written in `[defaults]` or a package. Unset
+/// kinds inherit from the enclosing level.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "schema", schemars(rename = "propagationByKind"))]
+pub(crate) struct RawPropagationByKind {
+	#[serde(default)]
+	runtime: Option<DependencyKindRule>,
+	#[serde(default)]
+	development: Option<DependencyKindRule>,
+	#[serde(default)]
+	build: Option<DependencyKindRule>,
+	#[serde(default)]
+	peer: Option<DependencyKindRule>,
+}
+
+impl RawPropagationByKind {
+	fn resolve(self, inherited: PropagationByKind) -> PropagationByKind {
+		PropagationByKind {
+			runtime: self.runtime.unwrap_or(inherited.runtime),
+			development: self.development.unwrap_or(inherited.development),
+			build: self.build.unwrap_or(inherited.build),
+			peer: self.peer.unwrap_or(inherited.peer),
+		}
+	}
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Deserialize, Default)]
 #[cfg_attr(feature = "schema", schemars(rename = "publishSettings"))]
@@ -567,6 +655,9 @@ pub struct LoadedChangesetFile {
 	pub details: Option<String>,
 	pub targets: Vec<LoadedChangesetTarget>,
 	pub signals: Vec<ChangeSignal>,
+	/// The commit this changeset was derived from when it was not read from a
+	/// `.changeset/*.md` file.
+	pub source_revision: Option<ChangesetRevision>,
 }
 
 fn default_parent_bump() -> BumpSeverity {
@@ -726,6 +817,61 @@ fn resolve_changelog_path(
 	PathBuf::from(path)
 }
 
+fn parse_version_scheme(
+	config_contents: &str,
+	kind: &str,
+	owner_id: &str,
+	version_scheme: Option<&RawVersionScheme>,
+) -> MonochangeResult<VersionScheme> {
+	let (scheme_type, format) = match version_scheme {
+		None => return Ok(VersionScheme::Semver),
+		Some(RawVersionScheme::Name(name)) => (name.as_str(), None),
+		Some(RawVersionScheme::Detailed(table)) => {
+			(table.scheme_type.as_str(), table.format.as_deref())
+		}
+	};
+	let invalid_version_scheme = |message: String, help: &str| {
+		config_diagnostic(
+			config_contents,
+			message,
+			vec![config_field_label(
+				config_contents,
+				kind,
+				owner_id,
+				"version_scheme",
+				"version scheme",
+			)],
+			Some(help.to_string()),
+		)
+	};
+	match (scheme_type, format) {
+		("semver", None) => Ok(VersionScheme::Semver),
+		("semver", Some(_)) => {
+			Err(invalid_version_scheme(
+				format!("{kind} `{owner_id}` sets a `format` for the `semver` version scheme"),
+				"only `version_scheme = { type = \"calver\", format = \"YYYY.MM.PATCH\" }` accepts a format",
+			))
+		}
+		("calver", format) => {
+			let format = format.unwrap_or(DEFAULT_CALVER_FORMAT);
+			CalverFormat::parse(format)
+				.map(|format| VersionScheme::Calver { format })
+				.map_err(|error| {
+					invalid_version_scheme(
+						format!("{kind} `{owner_id}` has an invalid calver format: {error}"),
+						"combine two of `YYYY`, `YY`, `MM`, `WW`, or `DD` with a trailing `PATCH`, for example `YYYY.MM.PATCH`",
+					)
+				})
+		}
+		(other, _) => {
+			Err(invalid_version_scheme(
+				format!("{kind} `{owner_id}` has unknown version scheme `{other}`"),
+				"use `version_scheme = \"semver\"` or `version_scheme = \"calver\"`",
+			))
+		}
+	}
+}
+
 fn parse_group_changelog_include(
 	config_contents: &str,
 	group_id: &str,
@@ -824,6 +970,9 @@ fn package_type_to_ecosystem_type(package_type: PackageType) -> EcosystemType {
 		PackageType::Dart | PackageType::Flutter => EcosystemType::Dart,
 		PackageType::Python => EcosystemType::Python,
 		PackageType::Go => EcosystemType::Go,
+		PackageType::Helm => EcosystemType::Helm,
+		PackageType::Ruby => EcosystemType::Ruby,
+		PackageType::Jvm => EcosystemType::Jvm,
 		_ => EcosystemType::Cargo,
 	}
 }
@@ -917,10 +1066,22 @@ fn default_publish_registry_for_ecosystem(
 		EcosystemType::Dart => Some(PublishRegistry::Builtin(RegistryKind::PubDev)),
 		EcosystemType::Python => Some(PublishRegistry::Builtin(RegistryKind::Pypi)),
 		EcosystemType::Go => Some(PublishRegistry::Builtin(RegistryKind::GoProxy)),
+		EcosystemType::Helm => Some(PublishRegistry::Builtin(RegistryKind::Helm)),
+		EcosystemType::Ruby => Some(PublishRegistry::Builtin(RegistryKind::Rubygems)),
+		EcosystemType::Jvm => Some(PublishRegistry::Builtin(RegistryKind::Maven)),
 		_ => None,
 	}
 }
 
+/// JVM artifacts are deployed by Maven or Gradle with the signing and staging
+/// setup each project already owns, so they default to external publishing.
+fn default_publish_mode_for_ecosystem(inferred_ecosystem_type: EcosystemType) -> PublishMode {
+	match inferred_ecosystem_type {
+		EcosystemType::Jvm => PublishMode::External,
+		_ => PublishMode::Builtin,
+	}
+}
+
 fn normalize_trusted_publishing_settings(
 	base: Option<&TrustedPublishingSettings>,
 	raw: Option<RawTrustedPublishingSettings>,
@@ -972,6 +1133,7 @@ fn normalize_publish_settings(
 ) -> MonochangeResult<PublishSettings> {
 	let mut settings = base.cloned().unwrap_or_else(|| {
 		PublishSettings {
+			mode: default_publish_mode_for_ecosystem(inferred_ecosystem_type),
 			registry: default_publish_registry_for_ecosystem(inferred_ecosystem_type),
 			..PublishSettings::default()
 		}
@@ -1022,8 +1184,31 @@ fn normalize_publish_settings(
 		));
 	}
 
+	if settings.mode == PublishMode::Builtin && inferred_ecosystem_type == EcosystemType::Jvm {
+		return Err(config_diagnostic(
+			contents,
+			format!("{owner_kind} `{owner_id}` uses built-in publishing for a JVM package"),
+			vec![config_section_label(
+				contents,
+				owner_kind,
+				owner_id,
+				"unsupported built-in JVM publishing",
+			)],
+			Some(
+				"JVM packages only support `mode = \"external\"`; run `mvn deploy` or `gradle publish` from your own release workflow".to_string(),
+			),
+		));
+	}
+
+	// Helm charts name their push destination through `registry`, so a custom
+	// value is the expected configuration rather than an override.
+	let helm_destination = inferred_ecosystem_type == EcosystemType::Helm
+		&& matches!(settings.registry, Some(PublishRegistry::Custom(_)));
 	let default_registry = default_publish_registry_for_ecosystem(inferred_ecosystem_type);
-	if settings.mode == PublishMode::Builtin && settings.registry != default_registry {
+	if settings.mode == PublishMode::Builtin
+		&& settings.registry != default_registry
+		&& !helm_destination
+	{
 		return Err(config_diagnostic(
 			contents,
 			format!(
@@ -1070,12 +1255,17 @@ fn build_package_definitions(
 	default_package_type: Option<PackageType>,
 	default_package_changelog: Option<&RawChangelogConfig>,
 	default_changelog_format: ChangelogFormat,
+	default_pre_stable_policy: PreStablePolicy,
+	default_propagation_by_kind: PropagationByKind,
 	cargo_ecosystem: &EcosystemSettings,
 	npm_ecosystem: &EcosystemSettings,
 	deno_ecosystem: &EcosystemSettings,
 	dart_ecosystem: &EcosystemSettings,
 	python_ecosystem: &EcosystemSettings,
 	go_ecosystem: &EcosystemSettings,
+	helm_ecosystem: &EcosystemSettings,
+	ruby_ecosystem: &EcosystemSettings,
+	jvm_ecosystem: &EcosystemSettings,
 ) -> MonochangeResult<Vec<PackageDefinition>> {
 	packages
 		.into_iter()
@@ -1130,6 +1320,9 @@ fn build_package_definitions(
 					EcosystemType::Dart => dart_ecosystem.versioned_files.clone(),
 					EcosystemType::Python => python_ecosystem.versioned_files.clone(),
 					EcosystemType::Go => go_ecosystem.versioned_files.clone(),
+					EcosystemType::Helm => helm_ecosystem.versioned_files.clone(),
+					EcosystemType::Ruby => ruby_ecosystem.versioned_files.clone(),
+					EcosystemType::Jvm => jvm_ecosystem.versioned_files.clone(),
 					_ => Vec::new(),
 				}
 			};
@@ -1151,6 +1344,9 @@ fn build_package_definitions(
 					EcosystemType::Dart => &dart_ecosystem.publish,
 					EcosystemType::Python => &python_ecosystem.publish,
 					EcosystemType::Go => &go_ecosystem.publish,
+					EcosystemType::Helm => &helm_ecosystem.publish,
+					EcosystemType::Ruby => &ruby_ecosystem.publish,
+					EcosystemType::Jvm => &jvm_ecosystem.publish,
 					_ => &cargo_ecosystem.publish,
 				}),
 				package.publish,
@@ -1159,6 +1355,13 @@ fn build_package_definitions(
 				inferred_ecosystem_type,
 			)?;
 
+			let version_scheme = parse_version_scheme(
+				contents,
+				"package",
+				&id,
+				package.version_scheme.as_ref(),
+			)?;
+
 			Ok::<_, MonochangeError>(PackageDefinition {
 				id,
 				path: package.path,
@@ -1175,6 +1378,13 @@ fn build_package_definitions(
 				tag: package.tag,
 				release: package.release,
 				version_format: package.version_format,
+				version_scheme,
+				pre_stable_policy: package
+					.pre_stable_policy
+					.unwrap_or(default_pre_stable_policy),
+				propagation_by_kind: package
+					.propagation_by_kind
+					.resolve(default_propagation_by_kind),
 				publish,
 			})
 		})
@@ -1186,6 +1396,7 @@ fn build_group_definitions(
 	groups: BTreeMap<String, RawGroupDefinition>,
 	default_changelog_format: ChangelogFormat,
 	default_changelog_initial_header: Option<&str>,
+	default_pre_stable_policy: PreStablePolicy,
 ) -> MonochangeResult<Vec<GroupDefinition>> {
 	groups
 		.into_iter()
@@ -1227,6 +1438,8 @@ fn build_group_definitions(
 				&group.packages,
 				group.changelog.as_ref().and_then(RawChangelogConfig::include),
 			)?;
+			let version_scheme =
+				parse_version_scheme(contents, "group", &id, group.version_scheme.as_ref())?;
 			Ok::<_, MonochangeError>(GroupDefinition {
 				id: id.clone(),
 				packages: group.packages,
@@ -1247,11 +1460,69 @@ fn build_group_definitions(
 				tag: group.tag,
 				release: group.release,
 				version_format: group.version_format,
+				version_scheme,
+				pre_stable_policy: group.pre_stable_policy.unwrap_or(default_pre_stable_policy),
 			})
 		})
 		.collect::<Result<Vec<_>, _>>()
 }
 
+/// Build `kind = "linked"` groups, rejecting the settings that only apply
+/// to a synchronized version group.
+fn build_linked_group_definitions(
+	contents: &str,
+	groups: BTreeMap<String, RawGroupDefinition>,
+) -> MonochangeResult<Vec<LinkedGroupDefinition>> {
+	groups
+		.into_iter()
+		.map(|(id, group)| {
+			let version_settings = [
+				("changelog", group.changelog.is_some()),
+				(
+					"excluded_changelog_types",
+					!group.excluded_changelog_types.is_empty(),
+				),
+				("empty_update_message", group.empty_update_message.is_some()),
+				("release_title", group.release_title.is_some()),
+				(
+					"changelog_version_title",
+					group.changelog_version_title.is_some(),
+				),
+				("versioned_files", !group.versioned_files.is_empty()),
+				("tag", group.tag),
+				("release", group.release),
+				(
+					"version_format",
+					group.version_format != VersionFormat::default(),
+				),
+				("version_scheme", group.version_scheme.is_some()),
+				("pre_stable_policy", group.pre_stable_policy.is_some()),
+			];
+			if let Some((field, _)) = version_settings.iter().find(|(_, set)| *set) {
+				return Err(config_diagnostic(
+					contents,
+					format!("linked group `{id}` cannot set `{field}`"),
+					vec![config_field_label(
+						contents,
+						"group",
+						&id,
+						field,
+						"version group setting",
+					)],
+					Some(
+						"linked members are versioned, tagged, and released as individual packages; move the setting to each [package.<id>] entry"
+							.to_string(),
+					),
+				));
+			}
+			Ok(LinkedGroupDefinition {
+				id,
+				packages: group.packages,
+			})
+		})
+		.collect()
+}
+
 fn resolve_source_configuration(
 	source: Option<RawSourceConfiguration>,
 ) -> Option<SourceConfiguration> {
@@ -1305,6 +1576,12 @@ pub fn load_workspace_configuration(root: &Path) -> MonochangeResult<WorkspaceCo
 	)?;
 	let go_ecosystem =
 		normalize_ecosystem_settings(&contents, "go", EcosystemType::Go, ecosystems.go)?;
+	let helm_ecosystem =
+		normalize_ecosystem_settings(&contents, "helm", EcosystemType::Helm, ecosystems.helm)?;
+	let ruby_ecosystem =
+		normalize_ecosystem_settings(&contents, "ruby", EcosystemType::Ruby, ecosystems.ruby)?;
+	let jvm_ecosystem =
+		normalize_ecosystem_settings(&contents, "jvm", EcosystemType::Jvm, ecosystems.jvm)?;
 	let defaults_changelog_policy = defaults
 		.changelog
 		.as_ref()
@@ -1314,34 +1591,48 @@ pub fn load_workspace_configuration(root: &Path) -> MonochangeResult<WorkspaceCo
 		.as_ref()
 		.and_then(RawChangelogConfig::format)
 		.unwrap_or_default();
+	let default_propagation_by_kind = defaults
+		.propagation_by_kind
+		.resolve(PropagationByKind::default());
 	let packages = build_package_definitions(
 		&contents,
 		package,
 		default_package_type,
 		default_package_changelog.as_ref(),
 		default_changelog_format,
+		defaults.pre_stable_policy,
+		default_propagation_by_kind,
 		&cargo_ecosystem,
 		&npm_ecosystem,
 		&deno_ecosystem,
 		&dart_ecosystem,
 		&python_ecosystem,
 		&go_ecosystem,
+		&helm_ecosystem,
+		&ruby_ecosystem,
+		&jvm_ecosystem,
 	)?;
 	let default_changelog_initial_header = defaults
 		.changelog
 		.as_ref()
 		.and_then(RawChangelogConfig::initial_header);
+	let (linked_group, group) = group
+		.into_iter()
+		.partition::<BTreeMap<_, _>, _>(|(_, group)| group.kind == GroupKind::Linked);
+	let linked_groups = build_linked_group_definitions(&contents, linked_group)?;
 	let groups = build_group_definitions(
 		&contents,
 		group,
 		default_changelog_format,
 		default_changelog_initial_header.as_deref(),
+		defaults.pre_stable_policy,
 	)?;
 	let source = resolve_source_configuration(source);
 
 	validate_cli(&cli)?;
 	validate_changelog_configuration(&contents, &changelog, &packages, &groups)?;
 	validate_changesets_configuration(&changesets, &packages)?;
+	validate_conventional_commit_scopes(&changesets, &packages, &groups)?;
 	let changelog = build_changelog_settings(changelog);
 	let changeset_lints = changeset_lint_settings_from_rules(&lints.rules)?;
 	validate_changeset_lint_settings(&changeset_lints, &changelog)?;
@@ -1353,6 +1644,9 @@ pub fn load_workspace_configuration(root: &Path) -> MonochangeResult<WorkspaceCo
 		("dart", &dart_ecosystem),
 		("python", &python_ecosystem),
 		("go", &go_ecosystem),
+		("helm", &helm_ecosystem),
+		("ruby", &ruby_ecosystem),
+		("jvm", &jvm_ecosystem),
 	] {
 		let declared_packages = packages
 			.iter()
@@ -1368,16 +1662,20 @@ pub fn load_workspace_configuration(root: &Path) -> MonochangeResult<WorkspaceCo
 		)?;
 		validate_lockfile_commands(root, ecosystem_id, &ecosystem_settings.lockfile_commands)?;
 	}
-	validate_package_and_group_definitions(root, &contents, &packages, &groups)?;
+	validate_package_and_group_definitions(root, &contents, &packages, &groups, &linked_groups)?;
 	validate_cli_runtime_requirements(&cli, &changesets, source.as_ref())?;
 
 	Ok(WorkspaceConfiguration {
 		root_path: root.to_path_buf(),
 		defaults: WorkspaceDefaults {
 			parent_bump: defaults.parent_bump,
+			propagation: defaults.propagation,
+			propagation_by_kind: default_propagation_by_kind,
 			include_private: defaults.include_private,
 			warn_on_group_mismatch: defaults.warn_on_group_mismatch,
 			strict_version_conflicts: defaults.strict_version_conflicts,
+			pre_stable_policy: defaults.pre_stable_policy,
+			analysis_enforcement: defaults.analysis_enforcement,
 			package_type: defaults.package_type,
 			changelog: defaults_changelog_policy,
 			changelog_format: default_changelog_format,
@@ -1389,6 +1687,7 @@ pub fn load_workspace_configuration(root: &Path) -> MonochangeResult<WorkspaceCo
 		changelog,
 		packages,
 		groups,
+		linked_groups,
 		cli,
 		changesets,
 		source,
@@ -1399,6 +1698,9 @@ pub fn load_workspace_configuration(root: &Path) -> MonochangeResult<WorkspaceCo
 		dart: dart_ecosystem,
 		python: python_ecosystem,
 		go: go_ecosystem,
+		helm: helm_ecosystem,
+		ruby: ruby_ecosystem,
+		jvm: jvm_ecosystem,
 	})
 }
 
@@ -1410,7 +1712,7 @@ struct ChangeTypeLookup {
 
 #[derive(Debug)]
 pub struct ChangesetLoadContext<'a> {
-	_configuration: &'a WorkspaceConfiguration,
+	configuration: &'a WorkspaceConfiguration,
 	package_ids: HashSet<&'a str>,
 	groups_by_id: HashMap<&'a str, &'a GroupDefinition>,
 	package_reference_matches: HashMap<String, Vec<&'a str>>,
@@ -1478,7 +1780,7 @@ pub fn build_changeset_load_context<'a>(
 		);
 	}
 	ChangesetLoadContext {
-		_configuration: configuration,
+		configuration,
 		package_ids,
 		groups_by_id,
 		package_reference_matches,
@@ -1610,6 +1912,20 @@ pub fn load_changeset_contents_with_context(
 		})?
 	};
 
+	resolve_raw_change_file(changes_path, contents, raw, "direct-change", context)
+}
+
+/// Resolve parsed change entries into changeset targets and planner signals.
+///
+/// `origin` records where the entries came from, such as `direct-change` for
+/// `.changeset/*.md` files.
+fn resolve_raw_change_file(
+	changes_path: &Path,
+	contents: &str,
+	raw: RawChangeFile,
+	origin: &str,
+	context: &ChangesetLoadContext<'_>,
+) -> MonochangeResult<LoadedChangesetFile> {
 	let referenced_packages: HashSet<String> = raw
 		.changes
 		.iter()
@@ -1687,7 +2003,7 @@ pub fn load_changeset_contents_with_context(
 				kind: ChangesetTargetKind::Group,
 				bump: inferred_bump,
 				explicit_version: explicit_version.clone(),
-				origin: "direct-change".to_string(),
+				origin: origin.to_string(),
 				evidence_refs: Vec::new(),
 				change_type: change_type.clone(),
 				caused_by: caused_by.clone(),
@@ -1717,7 +2033,7 @@ pub fn load_changeset_contents_with_context(
 					package_id,
 					requested_bump: inferred_bump,
 					explicit_version: explicit_version.clone(),
-					change_origin: "direct-change".to_string(),
+					change_origin: origin.to_string(),
 					evidence_refs: Vec::new(),
 					notes: change.reason.clone(),
 					details: change.details.clone(),
@@ -1758,7 +2074,7 @@ pub fn load_changeset_contents_with_context(
 				kind: ChangesetTargetKind::Package,
 				bump: inferred_bump,
 				explicit_version: explicit_version.clone(),
-				origin: "direct-change".to_string(),
+				origin: origin.to_string(),
 				evidence_refs: Vec::new(),
 				change_type: change_type.clone(),
 				caused_by: caused_by.clone(),
@@ -1783,7 +2099,7 @@ pub fn load_changeset_contents_with_context(
 				package_id,
 				requested_bump: inferred_bump,
 				explicit_version,
-				change_origin: "direct-change".to_string(),
+				change_origin: origin.to_string(),
 				evidence_refs: Vec::new(),
 				notes: change.reason,
 				details: change.details,
@@ -1800,6 +2116,7 @@ pub fn load_changeset_contents_with_context(
 		details,
 		targets,
 		signals,
+		source_revision: None,
 	})
 }
 
@@ -2991,6 +3308,7 @@ fn validate_package_and_group_definitions(
 	config_contents: &str,
 	packages: &[PackageDefinition],
 	groups: &[GroupDefinition],
+	linked_groups: &[LinkedGroupDefinition],
 ) -> MonochangeResult<()> {
 	let mut ids = BTreeSet::new();
 	let mut package_paths = BTreeMap::<PathBuf, String>::new();
@@ -3056,7 +3374,7 @@ fn validate_package_and_group_definitions(
 			));
 		}
 		let expected_manifest = resolved_path.join(expected_manifest_name(package.package_type));
-		if !expected_manifest.exists() {
+		if !package_manifest_exists(&resolved_path, package.package_type) {
 			return Err(config_diagnostic(
 				config_contents,
 				format!(
@@ -3097,7 +3415,6 @@ fn validate_package_and_group_definitions(
 			&package.id,
 		)?;
 	}
-	let mut assigned_packages = BTreeMap::<String, String>::new();
 	for group in groups {
 		validate_versioned_files(
 			root,
@@ -3107,33 +3424,44 @@ fn validate_package_and_group_definitions(
 			"group",
 			&group.id,
 		)?;
-		if !ids.insert(group.id.clone()) {
+		if group.version_format == VersionFormat::Primary {
+			assign_primary_release_owner(config_contents, &mut primary_owner, &group.id)?;
+		}
+	}
+
+	// Version groups and linked groups share the id namespace, and a package
+	// can belong to at most one group of either kind.
+	let mut assigned_packages = BTreeMap::<String, String>::new();
+	let all_groups = groups
+		.iter()
+		.map(|group| (&group.id, &group.packages))
+		.chain(
+			linked_groups
+				.iter()
+				.map(|group| (&group.id, &group.packages)),
+		);
+	for (group_id, group_packages) in all_groups {
+		if !ids.insert(group_id.clone()) {
 			return Err(config_diagnostic(
 				config_contents,
-				format!(
-					"group `{}` collides with an existing package or group id",
-					group.id
-				),
+				format!("group `{group_id}` collides with an existing package or group id"),
 				vec![config_section_label(
 					config_contents,
 					"group",
-					&group.id,
+					group_id,
 					"conflicting group id",
 				)],
 				Some("package and group ids share one namespace; rename one of them".to_string()),
 			));
 		}
-		if group.version_format == VersionFormat::Primary {
-			assign_primary_release_owner(config_contents, &mut primary_owner, &group.id)?;
-		}
-		for package_id in &group.packages {
+		for package_id in group_packages {
 			if !declared_packages.contains(package_id.as_str()) {
 				return Err(config_diagnostic(
 					config_contents,
-					format!("group `{}` references unknown package `{package_id}`", group.id),
+					format!("group `{group_id}` references unknown package `{package_id}`"),
 					vec![config_group_member_label(
 						config_contents,
-						&group.id,
+						group_id,
 						package_id,
 						"unknown package reference",
 					)],
@@ -3141,13 +3469,12 @@ fn validate_package_and_group_definitions(
 				));
 			}
 			if let Some(existing_group) =
-				assigned_packages.insert(package_id.clone(), group.id.clone())
+				assigned_packages.insert(package_id.clone(), group_id.clone())
 			{
 				return Err(config_diagnostic(
 					config_contents,
 					format!(
-						"package `{package_id}` belongs to multiple groups: `{existing_group}` and `{}`",
-						group.id
+						"package `{package_id}` belongs to multiple groups: `{existing_group}` and `{group_id}`"
 					),
 					vec![
 						config_group_member_label(
@@ -3158,7 +3485,7 @@ fn validate_package_and_group_definitions(
 						),
 						config_group_member_label(
 							config_contents,
-							&group.id,
+							group_id,
 							package_id,
 							"conflicting group membership",
 						),
@@ -3276,6 +3603,18 @@ fn path_is_supported_for_ecosystem(path: &Path, ecosystem_type: EcosystemType) -
 		EcosystemType::Deno => matches!(file_name, "deno.json" | "deno.jsonc" | "deno.lock"),
 		EcosystemType::Dart => matches!(file_name, "pubspec.yaml" | "pubspec.yml" | "pubspec.lock"),
 		EcosystemType::Python => matches!(file_name, "pyproject.toml" | "uv.lock" | "poetry.lock"),
+		EcosystemType::Helm => file_name == "Chart.yaml",
+		EcosystemType::Ruby => {
+			path.extension()
+				.and_then(|extension| extension.to_str())
+				.is_some_and(|extension| matches!(extension, "gemspec" | "rb"))
+		}
+		EcosystemType::Jvm => {
+			file_name == "pom.xml"
+				|| [".pom", ".properties", ".gradle", ".gradle.kts"]
+					.iter()
+					.any(|suffix| file_name.ends_with(suffix))
+		}
 		_ => matches!(file_name, "go.mod" | "go.sum"),
 	}
 }
@@ -3400,6 +3739,9 @@ fn validate_versioned_files(
 							EcosystemType::Dart => "dart",
 							EcosystemType::Python => "python",
 							EcosystemType::Go => "go",
+							EcosystemType::Helm => "helm",
+							EcosystemType::Ruby => "ruby",
+							EcosystemType::Jvm => "jvm",
 							_ => "unknown",
 						}
 					),
@@ -3472,10 +3814,39 @@ fn expected_manifest_name(package_type: PackageType) -> &'static str {
 		PackageType::Dart | PackageType::Flutter => "pubspec.yaml",
 		PackageType::Python => "pyproject.toml",
 		PackageType::Go => "go.mod",
+		PackageType::Helm => "Chart.yaml",
+		PackageType::Ruby => "*.gemspec",
+		PackageType::Jvm => "pom.xml",
 		_ => "Cargo.toml",
 	}
 }
 
+/// Gem manifests are named after the gem, so Ruby packages only need some
+/// `*.gemspec` in the package directory. JVM packages are either a Maven
+/// `pom.xml` or a Gradle project versioned through `gradle.properties`.
+fn package_manifest_exists(package_dir: &Path, package_type: PackageType) -> bool {
+	match package_type {
+		PackageType::Ruby => {
+			fs::read_dir(package_dir).is_ok_and(|entries| {
+				entries.filter_map(Result::ok).any(|entry| {
+					entry
+						.path()
+						.extension()
+						.is_some_and(|extension| extension == "gemspec")
+				})
+			})
+		}
+		PackageType::Jvm => {
+			package_dir.join("pom.xml").exists() || package_dir.join("gradle.properties").exists()
+		}
+		_ => {
+			package_dir
+				.join(expected_manifest_name(package_type))
+				.exists()
+		}
+	}
+}
+
 fn build_changelog_settings(raw: RawChangelogSettings) -> ChangelogSettings {
 	if raw.sections.is_empty() && raw.types.is_empty() && raw.templates.is_empty() {
 		let mut defaults = ChangelogSettings::defaults();
@@ -3824,6 +4195,19 @@ fn validate_changesets_configuration(
 	changesets: &ChangesetSettings,
 	packages: &[PackageDefinition],
 ) -> MonochangeResult<()> {
+	if changesets.sources.is_empty() {
+		return Err(MonochangeError::Config(
+			"[changesets].sources must include at least one change source".to_string(),
+		));
+	}
+	let mut seen_sources = BTreeSet::new();
+	for source in &changesets.sources {
+		if !seen_sources.insert(source.as_str()) {
+			return Err(MonochangeError::Config(format!(
+				"[changesets].sources lists `{source}` more than once"
+			)));
+		}
+	}
 	if changesets
 		.affected
 		.skip_labels
@@ -3881,6 +4265,29 @@ fn validate_changesets_configuration(
 	Ok(())
 }
 
+fn validate_conventional_commit_scopes(
+	changesets: &ChangesetSettings,
+	packages: &[PackageDefinition],
+	groups: &[GroupDefinition],
+) -> MonochangeResult<()> {
+	for (scope, target) in &changesets.conventional_commits.scopes {
+		if scope.trim().is_empty() {
+			return Err(MonochangeError::Config(
+				"[changesets.conventional_commits.scopes] must not include empty scopes"
+					.to_string(),
+			));
+		}
+		let known = packages.iter().any(|package| package.id == *target)
+			|| groups.iter().any(|group| group.id == *target);
+		if !known {
+			return Err(MonochangeError::Config(format!(
+				"[changesets.conventional_commits.scopes] maps `{scope}` to unknown package or group `{target}`"
+			)));
+		}
+	}
+	Ok(())
+}
+
 fn validate_changeset_lint_settings(
 	settings: &ChangesetLintSettings,
 	changelog: &ChangelogSettings,
@@ -5176,7 +5583,7 @@ fn validate_ecosystem_version_readable(
 				Err(field_error(field))?;
 			}
 		}
-		EcosystemType::Dart => {
+		EcosystemType::Dart | EcosystemType::Helm => {
 			let value: serde_yaml_ng::Value =
 				serde_yaml_ng::from_str(&contents).map_err(|error| {
 					MonochangeError::Config(format!(
@@ -5296,6 +5703,7 @@ fn validate_changeset_targets(
 	}
 }
 
+pub mod commits;
 pub mod lints;
 
 #[cfg(feature = "schema")]
@@ -9,6 +9,7 @@ use std::path::Path;
 use monochange_core::BumpSeverity;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
+use monochange_core::PreStablePolicy;
 use monochange_core::WorkspaceConfiguration;
 use monochange_core::lint::LintCategory;
 use monochange_core::lint::LintContext;
@@ -23,6 +24,7 @@ use monochange_core::lint::LintSeverity;
 use monochange_core::lint::LintSuite;
 use monochange_core::lint::LintTarget;
 use monochange_core::lint::LintTargetMetadata;
+use semver::Version;
 
 use crate::RawChangeEntry;
 use crate::parse_bump_severity;
@@ -40,17 +42,83 @@ pub struct ChangesetLintFile {
 	pub(crate) body: String,
 	/// The parsed change entries from frontmatter.
 	pub(crate) changes: Vec<RawChangeEntry>,
+	/// Bumps that the target's pre-1.0 policy changes, keyed by change target.
+	pub(crate) effective_bumps: BTreeMap<String, BumpSeverity>,
+}
+
+impl ChangesetLintFile {
+	/// Return the bump `change` applies once its target's pre-1.0 policy is
+	/// taken into account.
+	fn effective_bump(&self, change: &RawChangeEntry) -> Option<BumpSeverity> {
+		self.effective_bumps
+			.get(&change.package)
+			.copied()
+			.or(change.bump)
+	}
 }
 
 /// Changeset lint suite implementation.
 #[derive(Debug, Clone, Default)]
-pub struct ChangesetLintSuite;
+pub struct ChangesetLintSuite {
+	package_versions: BTreeMap<String, Version>,
+}
 
 impl ChangesetLintSuite {
 	/// Create a new changeset lint suite.
 	#[must_use]
 	pub fn new() -> Self {
-		Self
+		Self::default()
+	}
+
+	/// Provide current versions keyed by configured package id.
+	///
+	/// `changesets/bump/*` rules match the bump a change actually applies, so
+	/// a `major` change to a `0.x` package with the default `shift` policy is
+	/// checked by `changesets/bump/minor`. Without versions every change is
+	/// checked against its declared bump.
+	#[must_use]
+	pub fn with_package_versions(mut self, package_versions: BTreeMap<String, Version>) -> Self {
+		self.package_versions = package_versions;
+		self
+	}
+
+	fn effective_bumps(
+		&self,
+		configuration: &WorkspaceConfiguration,
+		changes: &[RawChangeEntry],
+	) -> BTreeMap<String, BumpSeverity> {
+		let mut effective_bumps = BTreeMap::new();
+		for change in changes {
+			let Some(bump) = change.bump else {
+				continue;
+			};
+			let (policy, version) = if let Some(group) = configuration.group_by_id(&change.package)
+			{
+				(
+					group.pre_stable_policy,
+					group
+						.packages
+						.iter()
+						.filter_map(|member| self.package_versions.get(member))
+						.max(),
+				)
+			} else {
+				(
+					configuration
+						.pre_stable_policy_for_package(&change.package)
+						.unwrap_or(PreStablePolicy::Shift),
+					self.package_versions.get(&change.package),
+				)
+			};
+			let Some(version) = version else {
+				continue;
+			};
+			let effective = policy.effective_bump(bump, version);
+			if effective != bump {
+				effective_bumps.insert(change.package.clone(), effective);
+			}
+		}
+		effective_bumps
 	}
 }
 
@@ -88,7 +156,7 @@ impl LintSuite for ChangesetLintSuite {
 	fn collect_targets(
 		&self,
 		workspace_root: &Path,
-		_configuration: &WorkspaceConfiguration,
+		configuration: &WorkspaceConfiguration,
 	) -> MonochangeResult<Vec<LintTarget>> {
 		let changeset_dir = workspace_root.join(".changeset");
 		if !changeset_dir.exists() {
@@ -125,6 +193,7 @@ impl LintSuite for ChangesetLintSuite {
 				continue;
 			};
 
+			let effective_bumps = self.effective_bumps(configuration, &changes);
 			let relative_path = path.strip_prefix(workspace_root).unwrap_or(&path);
 			targets.push(LintTarget::new(
 				workspace_root.to_path_buf(),
@@ -140,7 +209,11 @@ impl LintSuite for ChangesetLintSuite {
 					private: None,
 					publishable: None,
 				},
-				Box::new(ChangesetLintFile { body, changes }),
+				Box::new(ChangesetLintFile {
+					body,
+					changes,
+					effective_bumps,
+				}),
 			));
 		}
 
@@ -482,16 +555,15 @@ impl LintRuleRunner for BumpScopeRule {
 		let mut results = Vec::new();
 
 		for change in &file.changes {
-			if change.bump != Some(self.bump) {
+			let bump = file.effective_bump(change);
+			if bump != Some(self.bump) {
 				continue;
 			}
 
 			if let Some(required) = required_bump
-				&& change.bump != Some(required)
+				&& bump != Some(required)
 			{
-				let actual = change
-					.bump
-					.map_or_else(|| "auto".to_string(), |b| b.to_string());
+				let actual = bump.map_or_else(|| "auto".to_string(), |b| b.to_string());
 				results.push(LintResult::new(
 					self.rule.id.clone(),
 					LintLocation::new(ctx.manifest_path, 1, 1),
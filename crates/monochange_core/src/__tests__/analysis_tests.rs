@@ -39,3 +39,54 @@ fn package_analysis_context_exposes_package_root() {
 
 	assert_eq!(context.package_root(), Path::new("/repo/crates/core"));
 }
+
+#[test]
+fn semantic_change_implied_bump_follows_semver() {
+	let change = |category, kind| {
+		SemanticChange {
+			category,
+			kind,
+			item_kind: "function".to_string(),
+			item_path: "render".to_string(),
+			summary: "function `render` changed".to_string(),
+			file_path: PathBuf::from("src/lib.rs"),
+			before_signature: None,
+			after_signature: None,
+		}
+	};
+
+	let cases = [
+		(
+			SemanticChangeCategory::PublicApi,
+			SemanticChangeKind::Removed,
+			BumpSeverity::Major,
+		),
+		(
+			SemanticChangeCategory::Export,
+			SemanticChangeKind::Modified,
+			BumpSeverity::Major,
+		),
+		(
+			SemanticChangeCategory::PublicApi,
+			SemanticChangeKind::Added,
+			BumpSeverity::Minor,
+		),
+		(
+			SemanticChangeCategory::Dependency,
+			SemanticChangeKind::Removed,
+			BumpSeverity::Patch,
+		),
+		(
+			SemanticChangeCategory::Metadata,
+			SemanticChangeKind::Modified,
+			BumpSeverity::None,
+		),
+	];
+	for (category, kind, expected) in cases {
+		assert_eq!(
+			change(category, kind).implied_bump(),
+			expected,
+			"{category:?} {kind:?}"
+		);
+	}
+}
@@ -9,6 +9,7 @@ use serde_json::json;
 use tempfile::tempdir;
 
 use crate::BumpSeverity;
+use crate::CalverFormat;
 use crate::ChangelogFormat;
 use crate::ChangelogSectionDef;
 use crate::ChangelogSettings;
@@ -19,7 +20,10 @@ use crate::ChangesetPolicyStatus;
 use crate::CliInputKind;
 use crate::CliStepDefinition;
 use crate::CollapsedSectionStyle;
+use crate::DependencyEdge;
 use crate::DependencyKind;
+use crate::DependencyKindRule;
+use crate::DependencySourceKind;
 use crate::Ecosystem;
 use crate::EcosystemSettings;
 use crate::EcosystemType;
@@ -37,6 +41,8 @@ use crate::PackageLabelPlacement;
 use crate::PackageLabelStyle;
 use crate::PackageRecord;
 use crate::PackageType;
+use crate::PreStablePolicy;
+use crate::PropagationByKind;
 use crate::ProviderMergeRequestSettings;
 use crate::ProviderReleaseSettings;
 use crate::PublishMode;
@@ -71,17 +77,20 @@ use crate::ShellConfig;
 use crate::SourceConfiguration;
 use crate::SourceProvider;
 use crate::VersionFormat;
+use crate::VersionScheme;
 use crate::VersionedFileDefinition;
 use crate::WorkspaceConfiguration;
 use crate::WorkspaceDefaults;
 use crate::default_cli_commands;
 use crate::default_publish_order_dependency_fields;
+use crate::detect_dependency_cycles;
 use crate::git::git_checkout_branch_command;
 use crate::git::git_command;
 use crate::git::git_current_branch;
 use crate::git::git_head_commit;
 use crate::git::git_push_branch_command;
 use crate::materialize_dependency_edges;
+use crate::prerelease_channel;
 use crate::render_release_notes;
 
 #[test]
@@ -215,6 +224,59 @@ impl crate::EcosystemAdapter for PanicDiscoveryAdapter {
 	}
 }
 
+struct StaticDiscoveryAdapter {
+	manifests: Vec<&'static str>,
+}
+
+impl crate::EcosystemAdapter for StaticDiscoveryAdapter {
+	fn ecosystem(&self) -> Ecosystem {
+		Ecosystem::Npm
+	}
+
+	fn discover(&self, root: &Path) -> crate::MonochangeResult<crate::AdapterDiscovery> {
+		let packages = self
+			.manifests
+			.iter()
+			.map(|manifest| {
+				PackageRecord::new(
+					Ecosystem::Npm,
+					*manifest,
+					root.join(manifest),
+					root.to_path_buf(),
+					None,
+					PublishState::Public,
+				)
+			})
+			.collect();
+		Ok(crate::AdapterDiscovery {
+			packages,
+			warnings: Vec::new(),
+			skipped: Vec::new(),
+		})
+	}
+
+	fn load_configured(
+		&self,
+		_root: &Path,
+		_package_path: &Path,
+	) -> crate::MonochangeResult<Option<PackageRecord>> {
+		Ok(None)
+	}
+
+	fn supported_versioned_file_kind(&self, _path: &Path) -> bool {
+		false
+	}
+
+	fn validate_versioned_file(
+		&self,
+		_full_path: &Path,
+		_display_path: &str,
+		_custom_fields: Option<&[String]>,
+	) -> crate::MonochangeResult<()> {
+		Ok(())
+	}
+}
+
 fn test_source_configuration(provider: SourceProvider) -> SourceConfiguration {
 	SourceConfiguration {
 		provider,
@@ -796,6 +858,348 @@ fn apply_to_version_pre_stable_at_zero_zero() {
 	);
 }
 
+#[test]
+fn apply_to_version_graduates_covered_prereleases() {
+	let beta = Version::parse("2.0.0-beta.2").unwrap();
+	assert_eq!(
+		BumpSeverity::Patch.apply_to_version(&beta),
+		Version::new(2, 0, 0)
+	);
+	assert_eq!(
+		BumpSeverity::Major.apply_to_version(&beta),
+		Version::new(2, 0, 0)
+	);
+
+	let patch_rc = Version::parse("1.2.4-rc.1").unwrap();
+	assert_eq!(
+		BumpSeverity::Patch.apply_to_version(&patch_rc),
+		Version::new(1, 2, 4)
+	);
+	assert_eq!(
+		BumpSeverity::Minor.apply_to_version(&patch_rc),
+		Version::new(1, 3, 0)
+	);
+
+	let pre_stable = Version::parse("0.3.0-alpha.1").unwrap();
+	assert_eq!(
+		BumpSeverity::Major.apply_to_version(&pre_stable),
+		Version::new(0, 3, 0)
+	);
+}
+
+#[test]
+fn apply_to_prerelease_version_starts_and_increments_channels() {
+	let stable = Version::new(1, 4, 2);
+	let first = BumpSeverity::Major
+		.apply_to_prerelease_version(&stable, "beta")
+		.unwrap();
+	assert_eq!(first, Version::parse("2.0.0-beta.1").unwrap());
+
+	let second = BumpSeverity::Patch
+		.apply_to_prerelease_version(&first, "beta")
+		.unwrap();
+	assert_eq!(second, Version::parse("2.0.0-beta.2").unwrap());
+
+	let rc = BumpSeverity::Minor
+		.apply_to_prerelease_version(&second, "rc")
+		.unwrap();
+	assert_eq!(rc, Version::parse("2.0.0-rc.1").unwrap());
+
+	assert_eq!(
+		BumpSeverity::None
+			.apply_to_prerelease_version(&rc, "rc")
+			.unwrap(),
+		rc
+	);
+}
+
+#[test]
+fn apply_to_prerelease_version_rejects_non_numeric_counters() {
+	let named = Version::parse("2.0.0-beta.next").unwrap();
+	let error = BumpSeverity::Patch
+		.apply_to_prerelease_version(&named, "beta")
+		.unwrap_err();
+	assert!(
+		error.to_string().contains("`next` is not a numeric counter"),
+		"{error}"
+	);
+
+	// Only the identifier right after the channel is the counter, and other
+	// channels restart at `1` without looking at it.
+	assert_eq!(
+		BumpSeverity::Patch
+			.apply_to_prerelease_version(&Version::parse("2.0.0-beta.3.next").unwrap(), "beta")
+			.unwrap(),
+		Version::parse("2.0.0-beta.4").unwrap()
+	);
+	assert_eq!(
+		BumpSeverity::Patch
+			.apply_to_prerelease_version(&named, "rc")
+			.unwrap(),
+		Version::parse("2.0.0-rc.1").unwrap()
+	);
+}
+
+#[test]
+fn apply_to_prerelease_version_moves_base_when_severity_exceeds_pending_bump() {
+	let patch_beta = Version::parse("1.2.4-beta.3").unwrap();
+	assert_eq!(
+		BumpSeverity::Minor
+			.apply_to_prerelease_version(&patch_beta, "beta")
+			.unwrap(),
+		Version::parse("1.3.0-beta.1").unwrap()
+	);
+
+	let pre_stable = Version::new(0, 4, 1);
+	assert_eq!(
+		BumpSeverity::Major
+			.apply_to_prerelease_version(&pre_stable, "alpha")
+			.unwrap(),
+		Version::parse("0.5.0-alpha.1").unwrap()
+	);
+}
+
+#[test]
+fn pre_stable_policy_semver_applies_bumps_unchanged() {
+	let version = Version::new(0, 4, 2);
+	assert_eq!(
+		BumpSeverity::Major.apply_to_version_with_policy(&version, PreStablePolicy::Semver),
+		Version::new(1, 0, 0)
+	);
+	assert_eq!(
+		BumpSeverity::Minor.apply_to_version_with_policy(&version, PreStablePolicy::Semver),
+		Version::new(0, 5, 0)
+	);
+	assert_eq!(
+		BumpSeverity::Major.apply_to_version_with_policy(&version, PreStablePolicy::Shift),
+		Version::new(0, 5, 0)
+	);
+	assert_eq!(
+		BumpSeverity::Major
+			.apply_to_prerelease_version_with_policy(&version, "rc", PreStablePolicy::Semver)
+			.unwrap(),
+		Version::parse("1.0.0-rc.1").unwrap()
+	);
+}
+
+#[test]
+fn pre_stable_policy_effective_bump_only_shifts_pre_stable_versions() {
+	let pre_stable = Version::new(0, 1, 0);
+	let stable = Version::new(1, 0, 0);
+	assert_eq!(
+		PreStablePolicy::Shift.effective_bump(BumpSeverity::Major, &pre_stable),
+		BumpSeverity::Minor
+	);
+	assert_eq!(
+		PreStablePolicy::Shift.effective_bump(BumpSeverity::Minor, &pre_stable),
+		BumpSeverity::Patch
+	);
+	assert_eq!(
+		PreStablePolicy::Shift.effective_bump(BumpSeverity::Major, &stable),
+		BumpSeverity::Major
+	);
+	assert_eq!(
+		PreStablePolicy::Semver.effective_bump(BumpSeverity::Major, &pre_stable),
+		BumpSeverity::Major
+	);
+	assert_eq!(PreStablePolicy::default(), PreStablePolicy::Shift);
+	assert_eq!(PreStablePolicy::Semver.to_string(), "semver");
+}
+
+#[test]
+fn dependency_kind_rules_pick_the_dependent_bump_per_kind() {
+	let rules = PropagationByKind {
+		development: DependencyKindRule::None,
+		build: DependencyKindRule::Patch,
+		peer: DependencyKindRule::Match,
+		..PropagationByKind::default()
+	};
+	let bump = |kind| {
+		rules
+			.rule_for(kind)
+			.dependent_bump(BumpSeverity::Minor, BumpSeverity::Major)
+	};
+
+	assert_eq!(bump(DependencyKind::Runtime), BumpSeverity::Minor);
+	assert_eq!(bump(DependencyKind::Development), BumpSeverity::None);
+	assert_eq!(bump(DependencyKind::Build), BumpSeverity::Patch);
+	assert_eq!(bump(DependencyKind::Peer), BumpSeverity::Major);
+	assert_eq!(bump(DependencyKind::Unknown), BumpSeverity::Minor);
+	assert_eq!(DependencyKindRule::ParentBump.to_string(), "parent_bump");
+}
+
+fn calver_date(year: i32, month: u32, day: u32) -> chrono::NaiveDate {
+	chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn calver_format_parses_and_renders_supported_segments() {
+	let format = CalverFormat::parse("YY.WW.PATCH").unwrap();
+	assert_eq!(format.to_string(), "YY.WW.PATCH");
+	assert_eq!(CalverFormat::default().to_string(), "YYYY.MM.PATCH");
+	assert_eq!(
+		serde_json::to_value(VersionScheme::Calver { format }).unwrap(),
+		json!({ "type": "calver", "format": "YY.WW.PATCH" })
+	);
+}
+
+#[test]
+fn calver_format_rejects_unsupported_formats() {
+	let cases = [
+		("YYYY.MM", "must have three `.`-separated segments"),
+		("YYYY.0M.PATCH", "cannot have leading zeros"),
+		(
+			"YYYY.PATCH.PATCH",
+			"may only use `PATCH` as its last segment",
+		),
+		("YYYY.MM.DD", "must end with `PATCH`"),
+		("YYYY.QQ.PATCH", "unknown calver segment `QQ`"),
+	];
+	for (format, expected) in cases {
+		let error = CalverFormat::parse(format).unwrap_err();
+		assert!(error.contains(expected), "{format}: {error}");
+	}
+}
+
+#[test]
+fn calver_next_version_follows_the_release_date() {
+	let format = CalverFormat::default();
+	let october = calver_date(2026, 10, 17);
+
+	assert_eq!(
+		format
+			.next_version(Some(&Version::new(2026, 9, 4)), october)
+			.unwrap(),
+		Version::new(2026, 10, 0)
+	);
+	assert_eq!(
+		format
+			.next_version(Some(&Version::new(2026, 10, 0)), october)
+			.unwrap(),
+		Version::new(2026, 10, 1)
+	);
+	assert_eq!(
+		format
+			.next_version(Some(&Version::new(1, 4, 2)), october)
+			.unwrap(),
+		Version::new(2026, 10, 0)
+	);
+	assert_eq!(
+		format
+			.next_version(Some(&Version::parse("2026.10.1-rc.2").unwrap()), october)
+			.unwrap(),
+		Version::new(2026, 10, 1)
+	);
+	assert_eq!(
+		format.next_version(None, october).unwrap(),
+		Version::new(2026, 10, 0)
+	);
+
+	let weekly = CalverFormat::parse("YY.WW.PATCH").unwrap();
+	assert_eq!(
+		weekly.next_version(None, calver_date(2026, 1, 7)).unwrap(),
+		Version::new(26, 2, 0)
+	);
+	assert_eq!(
+		weekly
+			.next_version(None, calver_date(2026, 12, 31))
+			.unwrap(),
+		Version::new(26, 53, 0)
+	);
+}
+
+#[test]
+fn calver_next_version_rejects_unrepresentable_years_and_older_versions() {
+	let short = CalverFormat::parse("YY.MM.PATCH").unwrap();
+	let error = short.next_version(None, calver_date(1999, 12, 31)).unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"config error: calver segment `YY` cannot represent the year 1999 of 1999-12-31"
+	);
+
+	let format = CalverFormat::default();
+	let error = format
+		.next_version(Some(&Version::new(2026, 11, 0)), calver_date(2026, 10, 17))
+		.unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"config error: calver version `2026.10.0` for 2026-10-17 would not be newer than the current version `2026.11.0`"
+	);
+	// Switching to a shorter year format would also move backwards.
+	assert!(
+		short
+			.next_version(Some(&Version::new(2026, 9, 0)), calver_date(2026, 10, 17))
+			.is_err()
+	);
+}
+
+#[test]
+fn calver_week_segments_use_iso_weeks_and_week_based_years() {
+	let weekly = CalverFormat::parse("YYYY.WW.PATCH").unwrap();
+
+	// 2025-12-29 is the Monday of ISO week 1 of 2026.
+	assert_eq!(
+		weekly
+			.next_version(None, calver_date(2025, 12, 29))
+			.unwrap(),
+		Version::new(2026, 1, 0)
+	);
+	// 2027-01-01 still falls in ISO week 53 of 2026.
+	assert_eq!(
+		weekly
+			.next_version(Some(&Version::new(2026, 53, 0)), calver_date(2027, 1, 1))
+			.unwrap(),
+		Version::new(2026, 53, 1)
+	);
+	assert_eq!(
+		weekly.next_version(None, calver_date(2027, 1, 4)).unwrap(),
+		Version::new(2027, 1, 0)
+	);
+
+	let monthly = CalverFormat::parse("YYYY.MM.PATCH").unwrap();
+	assert_eq!(
+		monthly
+			.next_version(None, calver_date(2025, 12, 29))
+			.unwrap(),
+		Version::new(2025, 12, 0)
+	);
+}
+
+#[test]
+fn calver_next_prerelease_version_increments_the_channel_counter() {
+	let format = CalverFormat::default();
+	let october = calver_date(2026, 10, 17);
+
+	let first = format
+		.next_prerelease_version(Some(&Version::new(2026, 10, 0)), october, "beta")
+		.unwrap();
+	assert_eq!(first, Version::parse("2026.10.1-beta.1").unwrap());
+
+	let second = format
+		.next_prerelease_version(Some(&first), october, "beta")
+		.unwrap();
+	assert_eq!(second, Version::parse("2026.10.1-beta.2").unwrap());
+
+	let rc = format
+		.next_prerelease_version(Some(&second), october, "rc")
+		.unwrap();
+	assert_eq!(rc, Version::parse("2026.10.1-rc.1").unwrap());
+
+	let november = format
+		.next_prerelease_version(Some(&rc), calver_date(2026, 11, 2), "rc")
+		.unwrap();
+	assert_eq!(november, Version::parse("2026.11.0-rc.1").unwrap());
+}
+
+#[test]
+fn prerelease_channel_reads_the_first_identifier() {
+	assert_eq!(
+		prerelease_channel(&Version::parse("2.0.0-beta.3").unwrap()),
+		Some("beta")
+	);
+	assert_eq!(prerelease_channel(&Version::new(2, 0, 0)), None);
+}
+
 #[test]
 fn is_pre_stable_returns_true_for_zero_major() {
 	assert!(BumpSeverity::is_pre_stable(&Version::new(0, 1, 0)));
@@ -902,6 +1306,231 @@ fn discovery_path_filter_skips_nested_git_worktrees() {
 	assert!(!filter.allows(&nested.join("src/lib.rs")));
 }
 
+#[test]
+fn discovery_path_filter_scope_reports_excluded_and_out_of_root_manifests() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	let filter = crate::DiscoveryPathFilter::new(root)
+		.with_scope(
+			&["packages/*".to_string(), "./apps".to_string()],
+			&["packages/*/fixtures/**".to_string(), "**/examples".to_string()],
+		)
+		.unwrap_or_else(|error| panic!("scope: {error}"));
+
+	assert_eq!(filter.scope_skip_reason(&root.join("packages/web/package.json")), None);
+	assert_eq!(filter.scope_skip_reason(Path::new("apps/site/package.json")), None);
+	assert_eq!(
+		filter.scope_skip_reason(&root.join("packages/web/fixtures/demo/package.json")),
+		Some(crate::DiscoverySkipReason::Excluded {
+			pattern: "packages/*/fixtures/**".to_string(),
+		})
+	);
+	assert_eq!(
+		filter.scope_skip_reason(&root.join("apps/site/examples/basic/package.json")),
+		Some(crate::DiscoverySkipReason::Excluded {
+			pattern: "**/examples".to_string(),
+		})
+	);
+	assert_eq!(
+		filter.scope_skip_reason(&root.join("tools/package.json")),
+		Some(crate::DiscoverySkipReason::OutsideRoots)
+	);
+	// Gitignore handling stays separate from the discovery scope.
+	assert!(filter.should_descend(&root.join("tools")));
+}
+
+#[test]
+fn discovery_path_filter_scope_prunes_excluded_and_out_of_root_directories() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	let filter = crate::DiscoveryPathFilter::new(root)
+		.with_scope(
+			&["packages/*".to_string(), "/apps/site".to_string()],
+			&["**/examples/**".to_string()],
+		)
+		.unwrap_or_else(|error| panic!("scope: {error}"));
+
+	assert_eq!(filter.scope_prune_reason(root), None);
+	assert_eq!(filter.scope_prune_reason(&root.join("packages")), None);
+	assert_eq!(filter.scope_prune_reason(&root.join("packages/web/src")), None);
+	assert_eq!(filter.scope_prune_reason(&root.join("apps")), None);
+	assert_eq!(
+		filter.scope_prune_reason(&root.join("apps/docs")),
+		Some(crate::DiscoverySkipReason::OutsideRoots)
+	);
+	assert_eq!(
+		filter.scope_prune_reason(&root.join("tools")),
+		Some(crate::DiscoverySkipReason::OutsideRoots)
+	);
+	assert_eq!(
+		filter.scope_prune_reason(&root.join("packages/web/examples/basic")),
+		Some(crate::DiscoverySkipReason::Excluded {
+			pattern: "**/examples/**".to_string(),
+		})
+	);
+
+	// Roots that can match at any depth never prune the walk.
+	let unanchored = crate::DiscoveryPathFilter::new(root)
+		.with_scope(&["apps".to_string()], &[])
+		.unwrap_or_else(|error| panic!("scope: {error}"));
+	assert_eq!(unanchored.scope_prune_reason(&root.join("tools")), None);
+}
+
+#[test]
+fn discovery_path_filter_walk_manifests_lists_pruned_directories_as_skipped() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	for manifest in [
+		"package.json",
+		"packages/web/package.json",
+		"packages/web/examples/basic/package.json",
+		"tools/scripts/package.json",
+	] {
+		let path = root.join(manifest);
+		fs::create_dir_all(path.parent().unwrap_or(root))
+			.unwrap_or_else(|error| panic!("create dir: {error}"));
+		fs::write(&path, "{ not json")
+			.unwrap_or_else(|error| panic!("write manifest: {error}"));
+	}
+	let filter = crate::DiscoveryPathFilter::new(root)
+		.with_scope(&["packages/*".to_string()], &["**/examples/**".to_string()])
+		.unwrap_or_else(|error| panic!("scope: {error}"));
+
+	let walk = filter.walk_manifests(
+		Ecosystem::Npm,
+		root,
+		|entry| filter.should_descend(entry.path()),
+		|entry| entry.file_name() == "package.json",
+	);
+
+	let mut manifests = walk.manifests.clone();
+	manifests.sort();
+	assert_eq!(
+		manifests,
+		vec![
+			crate::normalize_path(&root.join("package.json")),
+			crate::normalize_path(&root.join("packages/web/package.json")),
+		]
+	);
+	let mut skipped = walk.skipped;
+	skipped.sort_by(|left, right| left.path.cmp(&right.path));
+	assert_eq!(
+		skipped,
+		vec![
+			crate::DiscoverySkip {
+				ecosystem: Ecosystem::Npm,
+				path: crate::normalize_path(&root.join("packages/web/examples/basic")),
+				reason: crate::DiscoverySkipReason::Excluded {
+					pattern: "**/examples/**".to_string(),
+				},
+			},
+			crate::DiscoverySkip {
+				ecosystem: Ecosystem::Npm,
+				path: crate::normalize_path(&root.join("tools")),
+				reason: crate::DiscoverySkipReason::OutsideRoots,
+			},
+		]
+	);
+
+	let mut skipped = Vec::new();
+	let kept = filter.retain_manifests_in_scope(Ecosystem::Npm, manifests, &mut skipped);
+	assert_eq!(
+		kept,
+		vec![crate::normalize_path(&root.join("packages/web/package.json"))]
+	);
+	assert_eq!(skipped.len(), 1);
+	assert_eq!(skipped[0].reason, crate::DiscoverySkipReason::OutsideRoots);
+}
+
+#[test]
+fn discovery_path_filter_scope_without_roots_only_applies_excludes() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	let filter = crate::DiscoveryPathFilter::new(root)
+		.with_scope(&[".".to_string()], &["vendor/".to_string()])
+		.unwrap_or_else(|error| panic!("scope: {error}"));
+
+	assert_eq!(filter.scope_skip_reason(&root.join("tools/package.json")), None);
+	assert!(filter.scope_skip_reason(&root.join("vendor/lib/package.json")).is_some());
+	assert_eq!(
+		crate::DiscoveryPathFilter::new(root).scope_skip_reason(&root.join("vendor/package.json")),
+		None
+	);
+}
+
+#[test]
+fn discovery_path_filter_scope_rejects_invalid_globs() {
+	let error = crate::DiscoveryPathFilter::new(Path::new("."))
+		.with_scope(&[], &["packages/[".to_string()])
+		.err()
+		.unwrap_or_else(|| panic!("expected invalid glob error"));
+
+	assert!(
+		error
+			.to_string()
+			.contains("invalid discovery exclude glob `packages/[`")
+	);
+}
+
+#[test]
+fn ecosystem_registry_applies_configured_discovery_scope() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	let adapter = StaticDiscoveryAdapter {
+		manifests: vec![
+			"packages/web/package.json",
+			"packages/web/examples/package.json",
+			"scripts/package.json",
+		],
+	};
+	let mut registry = crate::EcosystemRegistry::new().with_adapter(Box::new(adapter));
+	registry.set_discovery_scope(
+		Ecosystem::Npm,
+		&EcosystemSettings {
+			roots: vec!["packages/*".to_string()],
+			exclude: vec!["**/examples/**".to_string()],
+			..EcosystemSettings::default()
+		},
+	);
+
+	let discovery = registry
+		.discover_all(root)
+		.unwrap_or_else(|error| panic!("discover: {error}"));
+
+	assert_eq!(
+		discovery
+			.packages
+			.iter()
+			.map(|package| package.name.as_str())
+			.collect::<Vec<_>>(),
+		vec!["packages/web/package.json"]
+	);
+	assert_eq!(
+		discovery.skipped,
+		vec![
+			crate::DiscoverySkip {
+				ecosystem: Ecosystem::Npm,
+				path: root.join("packages/web/examples/package.json"),
+				reason: crate::DiscoverySkipReason::Excluded {
+					pattern: "**/examples/**".to_string(),
+				},
+			},
+			crate::DiscoverySkip {
+				ecosystem: Ecosystem::Npm,
+				path: root.join("scripts/package.json"),
+				reason: crate::DiscoverySkipReason::OutsideRoots,
+			},
+		]
+	);
+
+	registry.set_discovery_scope(Ecosystem::Npm, &EcosystemSettings::default());
+	let unscoped = registry
+		.discover_all(root)
+		.unwrap_or_else(|error| panic!("discover: {error}"));
+	assert_eq!(unscoped.packages.len(), 3);
+	assert!(unscoped.skipped.is_empty());
+}
+
 #[test]
 fn ecosystem_registry_reports_panicked_discovery_worker() {
 	let registry = crate::EcosystemRegistry::new().with_adapter(Box::new(PanicDiscoveryAdapter));
@@ -964,6 +1593,69 @@ fn materialize_dependency_edges_matches_dependency_names_to_packages() {
 	assert_eq!(edge.to_package_id, target.id);
 }
 
+fn cycle_edge(from: &str, to: &str, kind: DependencyKind) -> DependencyEdge {
+	DependencyEdge {
+		from_package_id: from.to_string(),
+		to_package_id: to.to_string(),
+		dependency_kind: kind,
+		source_kind: DependencySourceKind::Manifest,
+		version_constraint: None,
+		is_optional: false,
+		is_direct: true,
+		source_field: None,
+	}
+}
+
+#[test]
+fn detect_dependency_cycles_reports_edge_kinds_and_whether_cycles_are_breakable() {
+	let cycles = detect_dependency_cycles(&[
+		cycle_edge("api", "core", DependencyKind::Runtime),
+		cycle_edge("core", "testkit", DependencyKind::Development),
+		cycle_edge("testkit", "api", DependencyKind::Runtime),
+		cycle_edge("ui", "theme", DependencyKind::Peer),
+		cycle_edge("theme", "ui", DependencyKind::Runtime),
+		cycle_edge("theme", "ui", DependencyKind::Development),
+		cycle_edge("app", "api", DependencyKind::Runtime),
+		cycle_edge("app", "app", DependencyKind::Development),
+	]);
+
+	assert_eq!(cycles.len(), 2);
+	let cargo = &cycles[0];
+	assert_eq!(cargo.package_ids, ["api", "core", "testkit"]);
+	assert!(cargo.breakable);
+	assert!(cargo.edges[1].is_development_only());
+	assert_eq!(
+		cargo.to_string(),
+		"dependency cycle api -> core (runtime), core -> testkit (development), testkit -> api \
+		 (runtime); breakable: it only closes through development dependencies, so publish \
+		 order can be derived once those are left out of `publish_order.dependency_fields`"
+	);
+
+	let npm = &cycles[1];
+	assert_eq!(npm.package_ids, ["theme", "ui"]);
+	assert!(!npm.breakable);
+	assert_eq!(
+		npm.edges[0].dependency_kinds,
+		[DependencyKind::Runtime, DependencyKind::Development]
+	);
+	assert_eq!(
+		npm.publish_order_note(),
+		"not breakable: it closes through runtime, peer dependencies, so publish order is ambiguous"
+	);
+}
+
+#[test]
+fn detect_dependency_cycles_ignores_acyclic_graphs() {
+	assert!(
+		detect_dependency_cycles(&[
+			cycle_edge("api", "core", DependencyKind::Runtime),
+			cycle_edge("web", "api", DependencyKind::Runtime),
+			cycle_edge("web", "core", DependencyKind::Development),
+		])
+		.is_empty()
+	);
+}
+
 #[test]
 fn changeset_policy_status_renders_stable_strings() {
 	assert_eq!(ChangesetPolicyStatus::Passed.as_str(), "passed");
@@ -1617,6 +2309,7 @@ fn valid_input_names_returns_expected_names_for_create_change_file() {
 	let names = step.valid_input_names().unwrap();
 	for expected in [
 		"interactive",
+		"from_analysis",
 		"package",
 		"bump",
 		"version",
@@ -2309,6 +3002,9 @@ fn sample_workspace_configuration() -> WorkspaceConfiguration {
 				tag: false,
 				release: false,
 				version_format: VersionFormat::Namespaced,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 				publish: PublishSettings::default(),
 			},
 			PackageDefinition {
@@ -2331,6 +3027,9 @@ fn sample_workspace_configuration() -> WorkspaceConfiguration {
 				tag: false,
 				release: false,
 				version_format: VersionFormat::Namespaced,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 				publish: PublishSettings::default(),
 			},
 			PackageDefinition {
@@ -2349,6 +3048,9 @@ fn sample_workspace_configuration() -> WorkspaceConfiguration {
 				tag: false,
 				release: false,
 				version_format: VersionFormat::Namespaced,
+				version_scheme: VersionScheme::Semver,
+				pre_stable_policy: PreStablePolicy::Shift,
+				propagation_by_kind: PropagationByKind::default(),
 				publish: PublishSettings::default(),
 			},
 		],
@@ -2369,7 +3071,10 @@ fn sample_workspace_configuration() -> WorkspaceConfiguration {
 			tag: true,
 			release: true,
 			version_format: VersionFormat::Primary,
+			version_scheme: VersionScheme::Semver,
+			pre_stable_policy: PreStablePolicy::Shift,
 		}],
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: crate::ChangesetSettings::default(),
 		source: None,
@@ -2380,6 +3085,9 @@ fn sample_workspace_configuration() -> WorkspaceConfiguration {
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	}
 }
 
@@ -2854,6 +3562,7 @@ fn sample_release_record() -> ReleaseRecord {
 				"monochange_core".to_string(),
 				"monochange_config".to_string(),
 			],
+			prerelease: None,
 		}],
 		released_packages: vec![
 			"monochange".to_string(),
@@ -3118,6 +3827,7 @@ fn release_record_tag_helpers_deduplicate_tags() {
 		release: true,
 		tag_name: "v1.2.3".to_string(),
 		members: Vec::new(),
+		prerelease: None,
 	});
 
 	assert_eq!(crate::release_record_tag_names(&record), vec!["v1.2.3"]);
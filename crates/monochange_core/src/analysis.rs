@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::BumpSeverity;
 use crate::Ecosystem;
 use crate::MonochangeResult;
 use crate::PackageRecord;
@@ -118,6 +119,28 @@ pub struct SemanticChange {
 	pub after_signature: Option<String>,
 }
 
+impl SemanticChange {
+	/// The smallest bump that keeps this change semver-compatible.
+	///
+	/// Removing or changing a public item breaks callers, adding one is a
+	/// feature, and dependency updates only need a patch. Metadata changes
+	/// do not require a release on their own.
+	#[must_use]
+	pub fn implied_bump(&self) -> BumpSeverity {
+		match (self.category, self.kind) {
+			(
+				SemanticChangeCategory::PublicApi | SemanticChangeCategory::Export,
+				SemanticChangeKind::Added,
+			) => BumpSeverity::Minor,
+			(SemanticChangeCategory::PublicApi | SemanticChangeCategory::Export, _) => {
+				BumpSeverity::Major
+			}
+			(SemanticChangeCategory::Dependency, _) => BumpSeverity::Patch,
+			(SemanticChangeCategory::Metadata, _) => BumpSeverity::None,
+		}
+	}
+}
+
 /// Analyzer output for one package.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
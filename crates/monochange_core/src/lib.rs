@@ -71,12 +71,15 @@ pub mod git;
 pub mod lint;
 
 pub use analysis::*;
+use ignore::Match;
 use ignore::gitignore::Gitignore;
 use ignore::gitignore::GitignoreBuilder;
 use semver::Version;
 use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
+use walkdir::DirEntry;
+use walkdir::WalkDir;
 
 pub type MonochangeResult<T> = Result<T, MonochangeError>;
 
@@ -172,25 +175,35 @@ impl BumpSeverity {
 
 	/// Returns `true` when the version is below `1.0.0`.
 	///
-	/// Pre-1.0 packages use a shifted bump policy where major changes bump
-	/// the minor component and minor changes bump the patch component.
+	/// How bumps apply to pre-1.0 versions is controlled by
+	/// [`PreStablePolicy`].
 	#[must_use]
 	pub fn is_pre_stable(version: &Version) -> bool {
 		version.major == 0
 	}
 
 	/// Apply the severity to `version`, including pre-1.0 bump shifting.
+	///
+	/// When `version` is a prerelease whose base already covers the requested
+	/// severity (for example `2.0.0-beta.2` with a minor bump), the prerelease
+	/// graduates to its base version instead of skipping past it.
 	#[must_use]
 	pub fn apply_to_version(self, version: &Version) -> Version {
-		let effective = if Self::is_pre_stable(version) {
-			match self {
-				Self::Major => Self::Minor,
-				Self::Minor => Self::Patch,
-				other => other,
-			}
-		} else {
-			self
-		};
+		self.apply_to_version_with_policy(version, PreStablePolicy::Shift)
+	}
+
+	/// Apply the severity to `version`, treating pre-1.0 versions according
+	/// to `policy`.
+	#[must_use]
+	pub fn apply_to_version_with_policy(
+		self,
+		version: &Version,
+		policy: PreStablePolicy,
+	) -> Version {
+		let effective = policy.effective_bump(self, version);
+		if effective.is_release() && prerelease_covers(version, effective) {
+			return Version::new(version.major, version.minor, version.patch);
+		}
 
 		let mut next = version.clone();
 		match effective {
@@ -218,6 +231,263 @@ impl BumpSeverity {
 			}
 		}
 	}
+
+	/// Apply the severity to `version` and tag the result for the prerelease
+	/// `channel` (for example `beta`).
+	///
+	/// Later prereleases on the same channel only increment the trailing
+	/// counter (`2.0.0-beta.1` → `2.0.0-beta.2`) unless the severity exceeds
+	/// what the pending base version already covers. Switching channels keeps
+	/// the base version and restarts the counter at `1`.
+	///
+	/// Fails when `version` is already on `channel` but its counter is not
+	/// numeric (`2.0.0-beta.next`), because no numeric counter sorts after it.
+	pub fn apply_to_prerelease_version(
+		self,
+		version: &Version,
+		channel: &str,
+	) -> MonochangeResult<Version> {
+		self.apply_to_prerelease_version_with_policy(version, channel, PreStablePolicy::Shift)
+	}
+
+	/// Prerelease counterpart of [`Self::apply_to_version_with_policy`].
+	pub fn apply_to_prerelease_version_with_policy(
+		self,
+		version: &Version,
+		channel: &str,
+		policy: PreStablePolicy,
+	) -> MonochangeResult<Version> {
+		if !self.is_release() {
+			return Ok(version.clone());
+		}
+
+		let effective = policy.effective_bump(self, version);
+		if prerelease_covers(version, effective) {
+			let counter = prerelease_counter(version, channel)?.map_or(1, |counter| counter + 1);
+			return Ok(with_prerelease(
+				Version::new(version.major, version.minor, version.patch),
+				channel,
+				counter,
+			));
+		}
+
+		Ok(with_prerelease(
+			self.apply_to_version_with_policy(version, policy),
+			channel,
+			1,
+		))
+	}
+}
+
+/// How bump severities apply to versions below `1.0.0`.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PreStablePolicy {
+	/// Cargo-style shifting: major bumps the minor component and minor bumps
+	/// the patch component, so `0.4.2` with a major bump becomes `0.5.0`.
+	#[default]
+	Shift,
+	/// Plain semver: a major bump on `0.4.2` releases `1.0.0`.
+	Semver,
+}
+
+impl PreStablePolicy {
+	/// Return the severity that `severity` actually applies to `version`.
+	///
+	/// Stable versions always keep `severity` unchanged.
+	#[must_use]
+	pub fn effective_bump(self, severity: BumpSeverity, version: &Version) -> BumpSeverity {
+		if self == Self::Semver || !BumpSeverity::is_pre_stable(version) {
+			return severity;
+		}
+		match severity {
+			BumpSeverity::Major => BumpSeverity::Minor,
+			BumpSeverity::Minor => BumpSeverity::Patch,
+			other => other,
+		}
+	}
+}
+
+impl fmt::Display for PreStablePolicy {
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		formatter.write_str(match self {
+			Self::Shift => "shift",
+			Self::Semver => "semver",
+		})
+	}
+}
+
+/// When a release of a dependency also releases the packages depending on it.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DependencyPropagation {
+	/// Every direct dependent receives `parent_bump`.
+	#[default]
+	Always,
+	/// Dependents only receive `parent_bump` when the dependency's planned
+	/// version no longer satisfies the range declared in their manifest.
+	OutOfRange,
+}
+
+/// What release planning does when semantic analysis implies a stronger bump
+/// than the changesets declare for a package.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AnalysisEnforcement {
+	/// Semantic analysis does not run during planning.
+	#[default]
+	Off,
+	/// Report each under-declared package as a plan warning.
+	Warn,
+	/// Fail planning while any package is under-declared.
+	Error,
+	/// Raise each under-declared package to the bump the evidence implies.
+	Raise,
+}
+
+/// How a release propagates to a dependent through one kind of dependency.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DependencyKindRule {
+	/// Release the dependent with `parent_bump`.
+	#[default]
+	ParentBump,
+	/// Never release the dependent.
+	None,
+	/// Release the dependent with a patch bump.
+	Patch,
+	/// Release the dependent with a minor bump.
+	Minor,
+	/// Release the dependent with a major bump.
+	Major,
+	/// Release the dependent with the same bump as the dependency.
+	Match,
+}
+
+impl DependencyKindRule {
+	/// Return the bump this rule gives a dependent when the dependency
+	/// releases with `dependency_bump` and `parent_bump` is the propagated
+	/// default.
+	#[must_use]
+	pub fn dependent_bump(
+		self,
+		parent_bump: BumpSeverity,
+		dependency_bump: BumpSeverity,
+	) -> BumpSeverity {
+		match self {
+			Self::ParentBump => parent_bump,
+			Self::None => BumpSeverity::None,
+			Self::Patch => BumpSeverity::Patch,
+			Self::Minor => BumpSeverity::Minor,
+			Self::Major => BumpSeverity::Major,
+			Self::Match => dependency_bump,
+		}
+	}
+}
+
+impl fmt::Display for DependencyKindRule {
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		formatter.write_str(match self {
+			Self::ParentBump => "parent_bump",
+			Self::None => "none",
+			Self::Patch => "patch",
+			Self::Minor => "minor",
+			Self::Major => "major",
+			Self::Match => "match",
+		})
+	}
+}
+
+/// Propagation rules for each [`DependencyKind`] a dependent declares.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PropagationByKind {
+	#[serde(default)]
+	pub runtime: DependencyKindRule,
+	#[serde(default)]
+	pub development: DependencyKindRule,
+	#[serde(default)]
+	pub build: DependencyKindRule,
+	#[serde(default)]
+	pub peer: DependencyKindRule,
+}
+
+impl PropagationByKind {
+	/// Return the rule for dependencies of `kind`. Workspace and unknown
+	/// dependencies always use `parent_bump`.
+	#[must_use]
+	pub fn rule_for(&self, kind: DependencyKind) -> DependencyKindRule {
+		match kind {
+			DependencyKind::Runtime => self.runtime,
+			DependencyKind::Development => self.development,
+			DependencyKind::Build => self.build,
+			DependencyKind::Peer => self.peer,
+			_ => DependencyKindRule::ParentBump,
+		}
+	}
+}
+
+/// Return the prerelease channel of `version`, if any.
+///
+/// The channel is the first dot-separated prerelease identifier, so
+/// `2.0.0-beta.3` yields `beta`.
+#[must_use]
+pub fn prerelease_channel(version: &Version) -> Option<&str> {
+	if version.pre.is_empty() {
+		return None;
+	}
+	version.pre.as_str().split('.').next()
+}
+
+/// Returns `true` when `version` is a prerelease whose base version already
+/// includes a bump of at least `severity`.
+fn prerelease_covers(version: &Version, severity: BumpSeverity) -> bool {
+	if version.pre.is_empty() {
+		return false;
+	}
+	let implied = if version.minor == 0 && version.patch == 0 {
+		BumpSeverity::Major
+	} else if version.patch == 0 {
+		BumpSeverity::Minor
+	} else {
+		BumpSeverity::Patch
+	};
+	severity <= implied
+}
+
+/// Counter of `version` on `channel`, or `None` when it is on another channel.
+///
+/// A bare channel such as `2.0.0-beta` counts as `0`. A non-numeric counter
+/// is an error: semver sorts numeric identifiers before alphanumeric ones,
+/// so restarting at `1` would produce a version older than `version`.
+fn prerelease_counter(version: &Version, channel: &str) -> MonochangeResult<Option<u64>> {
+	let mut identifiers = version.pre.as_str().split('.');
+	if identifiers.next() != Some(channel) {
+		return Ok(None);
+	}
+	let Some(counter) = identifiers.next() else {
+		return Ok(Some(0));
+	};
+	counter.parse().map(Some).map_err(|_| {
+		MonochangeError::Config(format!(
+			"cannot continue prerelease `{version}` on channel `{channel}`: `{counter}` is not a numeric counter"
+		))
+	})
+}
+
+fn with_prerelease(mut version: Version, channel: &str, counter: u64) -> Version {
+	version.pre = semver::Prerelease::new(&format!("{channel}.{counter}"))
+		.unwrap_or(semver::Prerelease::EMPTY);
+	version.build = semver::BuildMetadata::EMPTY;
+	version
 }
 
 impl fmt::Display for BumpSeverity {
@@ -243,6 +513,9 @@ pub enum Ecosystem {
 	Flutter,
 	Python,
 	Go,
+	Helm,
+	Ruby,
+	Jvm,
 }
 
 impl Ecosystem {
@@ -257,6 +530,9 @@ impl Ecosystem {
 			Self::Flutter => "flutter",
 			Self::Python => "python",
 			Self::Go => "go",
+			Self::Helm => "helm",
+			Self::Ruby => "ruby",
+			Self::Jvm => "jvm",
 		}
 	}
 }
@@ -270,6 +546,9 @@ impl From<EcosystemType> for Ecosystem {
 			EcosystemType::Dart => Self::Dart,
 			EcosystemType::Python => Self::Python,
 			EcosystemType::Go => Self::Go,
+			EcosystemType::Helm => Self::Helm,
+			EcosystemType::Ruby => Self::Ruby,
+			EcosystemType::Jvm => Self::Jvm,
 		}
 	}
 }
@@ -284,6 +563,9 @@ impl From<PackageType> for Ecosystem {
 			PackageType::Flutter => Self::Flutter,
 			PackageType::Python => Self::Python,
 			PackageType::Go => Self::Go,
+			PackageType::Helm => Self::Helm,
+			PackageType::Ruby => Self::Ruby,
+			PackageType::Jvm => Self::Jvm,
 		}
 	}
 }
@@ -306,6 +588,9 @@ impl std::str::FromStr for Ecosystem {
 			"flutter" => Ok(Self::Flutter),
 			"python" => Ok(Self::Python),
 			"go" => Ok(Self::Go),
+			"helm" => Ok(Self::Helm),
+			"ruby" => Ok(Self::Ruby),
+			"jvm" => Ok(Self::Jvm),
 			_ => Err(()),
 		}
 	}
@@ -320,6 +605,9 @@ pub fn default_registry_kind_for_ecosystem(ecosystem: Ecosystem) -> Option<Regis
 		Ecosystem::Dart | Ecosystem::Flutter => Some(RegistryKind::PubDev),
 		Ecosystem::Python => Some(RegistryKind::Pypi),
 		Ecosystem::Go => Some(RegistryKind::GoProxy),
+		Ecosystem::Helm => Some(RegistryKind::Helm),
+		Ecosystem::Ruby => Some(RegistryKind::Rubygems),
+		Ecosystem::Jvm => Some(RegistryKind::Maven),
 	}
 }
 
@@ -458,6 +746,27 @@ pub struct DiscoveryPathFilter {
 	root: PathBuf,
 	input_root: PathBuf,
 	gitignore: Gitignore,
+	scope: Option<DiscoveryScope>,
+}
+
+/// Compiled `[ecosystems.<name>].roots` and `exclude` globs.
+#[derive(Clone, Debug)]
+struct DiscoveryScope {
+	roots: Option<Gitignore>,
+	/// Literal leading directories of every root, or `None` when a root can
+	/// match anywhere and directories outside the roots cannot be pruned.
+	root_prefixes: Option<Vec<PathBuf>>,
+	exclude: Gitignore,
+}
+
+/// Manifests found by [`DiscoveryPathFilter::walk_manifests`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ManifestWalk {
+	/// Manifests in directories the walk entered. Their own paths can still
+	/// fall outside the scope, so adapters check them before parsing.
+	pub manifests: Vec<PathBuf>,
+	/// Directories the scope pruned. Nothing under them is read or listed.
+	pub skipped: Vec<DiscoverySkip>,
 }
 
 impl DiscoveryPathFilter {
@@ -478,9 +787,165 @@ impl DiscoveryPathFilter {
 			root,
 			input_root,
 			gitignore,
+			scope: None,
 		}
 	}
 
+	/// Restrict discovered manifests to the given `roots` and `exclude` globs.
+	///
+	/// Both lists use gitignore-style patterns relative to the repository
+	/// root. An empty `roots` list keeps every manifest that is not excluded.
+	pub fn with_scope(mut self, roots: &[String], exclude: &[String]) -> MonochangeResult<Self> {
+		let roots = roots
+			.iter()
+			.map(|pattern| pattern.trim().trim_start_matches("./"))
+			.collect::<Vec<_>>();
+		// A root that names the repository itself does not narrow anything.
+		let covers_repository = roots.iter().any(|pattern| matches!(*pattern, "" | "."));
+		let (roots, root_prefixes) = if roots.is_empty() || covers_repository {
+			(None, None)
+		} else {
+			let prefixes = roots
+				.iter()
+				.map(|pattern| literal_root_prefix(pattern))
+				.collect::<Option<Vec<_>>>();
+			(Some(self.build_scope_globs("roots", &roots)?), prefixes)
+		};
+		let exclude = exclude.iter().map(String::as_str).collect::<Vec<_>>();
+		let exclude = self.build_scope_globs("exclude", &exclude)?;
+		self.scope = Some(DiscoveryScope {
+			roots,
+			root_prefixes,
+			exclude,
+		});
+		Ok(self)
+	}
+
+	fn build_scope_globs(&self, field: &str, patterns: &[&str]) -> MonochangeResult<Gitignore> {
+		let mut builder = GitignoreBuilder::new(&self.root);
+		for pattern in patterns {
+			builder.add_line(None, pattern).map_err(|error| {
+				MonochangeError::Config(format!(
+					"invalid discovery {field} glob `{pattern}`: {error}"
+				))
+			})?;
+		}
+		builder.build().map_err(|error| {
+			MonochangeError::Config(format!("invalid discovery {field} globs: {error}"))
+		})
+	}
+
+	/// Return why `manifest_path` falls outside the configured discovery
+	/// scope, or `None` when the manifest should be kept.
+	#[must_use]
+	pub fn scope_skip_reason(&self, manifest_path: &Path) -> Option<DiscoverySkipReason> {
+		let scope = self.scope.as_ref()?;
+		let relative = self.relative_path(manifest_path)?;
+		if let Match::Ignore(glob) = scope.exclude.matched_path_or_any_parents(&relative, false) {
+			return Some(DiscoverySkipReason::Excluded {
+				pattern: glob.original().to_string(),
+			});
+		}
+
+		let roots = scope.roots.as_ref()?;
+		if roots.matched_path_or_any_parents(&relative, false).is_ignore() {
+			None
+		} else {
+			Some(DiscoverySkipReason::OutsideRoots)
+		}
+	}
+
+	/// Return why a discovery walk should not enter the directory `dir`, or
+	/// `None` when it may still contain manifests inside the scope.
+	///
+	/// Directories matching an `exclude` glob are pruned. With `roots`, so are
+	/// directories that are neither inside a root nor on the way to one.
+	#[must_use]
+	pub fn scope_prune_reason(&self, dir: &Path) -> Option<DiscoverySkipReason> {
+		let scope = self.scope.as_ref()?;
+		let relative = self
+			.relative_path(dir)
+			.filter(|relative| !relative.as_os_str().is_empty())?;
+		if let Match::Ignore(glob) = scope.exclude.matched_path_or_any_parents(&relative, true) {
+			return Some(DiscoverySkipReason::Excluded {
+				pattern: glob.original().to_string(),
+			});
+		}
+
+		let roots = scope.roots.as_ref()?;
+		let prefixes = scope.root_prefixes.as_ref()?;
+		let inside_root = roots.matched_path_or_any_parents(&relative, true).is_ignore();
+		let leads_to_root = prefixes.iter().any(|prefix| prefix.starts_with(&relative));
+		(!inside_root && !leads_to_root).then_some(DiscoverySkipReason::OutsideRoots)
+	}
+
+	/// Walk `root` for manifest files, pruning directories outside the scope.
+	///
+	/// `descend` decides which entries the walk visits, like
+	/// `WalkDir::filter_entry`, and `is_manifest` picks the manifest files.
+	/// Pruned directories are returned as skipped without being walked.
+	pub fn walk_manifests(
+		&self,
+		ecosystem: Ecosystem,
+		root: &Path,
+		descend: impl Fn(&DirEntry) -> bool,
+		is_manifest: impl Fn(&DirEntry) -> bool,
+	) -> ManifestWalk {
+		let mut walk = ManifestWalk::default();
+		let mut entries = WalkDir::new(root).into_iter();
+		while let Some(entry) = entries.next() {
+			let Ok(entry) = entry else {
+				continue;
+			};
+			let is_dir = entry.file_type().is_dir();
+			if !descend(&entry) {
+				if is_dir {
+					entries.skip_current_dir();
+				}
+				continue;
+			}
+			if is_dir && entry.depth() > 0 {
+				if let Some(reason) = self.scope_prune_reason(entry.path()) {
+					entries.skip_current_dir();
+					walk.skipped.push(DiscoverySkip {
+						ecosystem,
+						path: normalize_path(entry.path()),
+						reason,
+					});
+				}
+				continue;
+			}
+			if is_manifest(&entry) {
+				walk.manifests.push(normalize_path(entry.path()));
+			}
+		}
+		walk
+	}
+
+	/// Keep the manifests inside the scope and record why the others were
+	/// skipped.
+	pub fn retain_manifests_in_scope(
+		&self,
+		ecosystem: Ecosystem,
+		manifests: Vec<PathBuf>,
+		skipped: &mut Vec<DiscoverySkip>,
+	) -> Vec<PathBuf> {
+		manifests
+			.into_iter()
+			.filter(|manifest_path| {
+				let Some(reason) = self.scope_skip_reason(manifest_path) else {
+					return true;
+				};
+				skipped.push(DiscoverySkip {
+					ecosystem,
+					path: manifest_path.clone(),
+					reason,
+				});
+				false
+			})
+			.collect()
+	}
+
 	/// Return `true` when `path` should be considered during discovery.
 	#[must_use]
 	pub fn allows(&self, path: &Path) -> bool {
@@ -553,6 +1018,53 @@ impl DiscoveryPathFilter {
 	}
 }
 
+/// Why discovery dropped a manifest that an adapter found.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum DiscoverySkipReason {
+	/// The manifest matched one of the ecosystem's `exclude` globs.
+	Excluded { pattern: String },
+	/// The ecosystem declares `roots` and none of them contain the manifest.
+	OutsideRoots,
+}
+
+impl fmt::Display for DiscoverySkipReason {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Excluded { pattern } => write!(f, "excluded by `{pattern}`"),
+			Self::OutsideRoots => f.write_str("outside configured roots"),
+		}
+	}
+}
+
+/// A manifest or directory that discovery skipped because of ecosystem
+/// settings.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DiscoverySkip {
+	pub ecosystem: Ecosystem,
+	/// The skipped manifest, or a directory that discovery pruned without
+	/// reading anything under it.
+	pub path: PathBuf,
+	pub reason: DiscoverySkipReason,
+}
+
+/// The directories a root glob names before its first wildcard, or `None`
+/// when the glob can match at any depth.
+fn literal_root_prefix(pattern: &str) -> Option<PathBuf> {
+	let pattern = pattern.trim_end_matches('/');
+	// Gitignore globs without an inner slash match at any depth.
+	let pattern = match pattern.strip_prefix('/') {
+		Some(anchored) => anchored,
+		None if pattern.contains('/') => pattern,
+		None => return None,
+	};
+	let prefix = pattern
+		.split('/')
+		.take_while(|segment| !segment.contains(['*', '?', '[', '{', '\\', '!']))
+		.collect::<PathBuf>();
+	(!prefix.as_os_str().is_empty()).then_some(prefix)
+}
+
 fn ignored_discovery_dir_name(path: &Path) -> bool {
 	path.components().any(|component| {
 		component.as_os_str().to_str().is_some_and(|name| {
@@ -589,6 +1101,9 @@ pub enum PackageType {
 	Flutter,
 	Python,
 	Go,
+	Helm,
+	Ruby,
+	Jvm,
 }
 
 impl PackageType {
@@ -603,6 +1118,9 @@ impl PackageType {
 			Self::Flutter => "flutter",
 			Self::Python => "python",
 			Self::Go => "go",
+			Self::Helm => "helm",
+			Self::Ruby => "ruby",
+			Self::Jvm => "jvm",
 		}
 	}
 }
@@ -617,6 +1135,285 @@ pub enum VersionFormat {
 	Primary,
 }
 
+/// How a `[group.<id>]` declaration ties its members together.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum GroupKind {
+	/// Members share one synchronized version and release together.
+	#[default]
+	Version,
+	/// Members keep independent versions, but every member that releases
+	/// takes the highest bump severity among the released members.
+	Linked,
+}
+
+/// Format used when `version_scheme = "calver"` does not provide one.
+pub const DEFAULT_CALVER_FORMAT: &str = "YYYY.MM.PATCH";
+
+/// Scheme used to compute the next version of a package or group.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum VersionScheme {
+	/// Semantic versioning: the bump severity decides which component moves.
+	#[default]
+	Semver,
+	/// Calendar versioning: the release date decides the version and the
+	/// bump severity only decides whether a release happens.
+	Calver {
+		#[cfg_attr(feature = "schema", schemars(with = "String"))]
+		format: CalverFormat,
+	},
+}
+
+impl VersionScheme {
+	/// Prefix placed between the tag namespace and the version, such as the
+	/// `v` in `core/v1.2.3`. Calendar versions are tagged without one.
+	#[must_use]
+	pub fn tag_version_prefix(&self) -> &'static str {
+		match self {
+			Self::Semver => "v",
+			Self::Calver { .. } => "",
+		}
+	}
+}
+
+/// One `.`-separated segment of a calendar version format.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CalverSegment {
+	/// `YYYY`: full year, such as `2026`.
+	FullYear,
+	/// `YY`: years since 2000, such as `26`.
+	ShortYear,
+	/// `MM`: month of the year, `1` to `12`.
+	Month,
+	/// `WW`: ISO 8601 week of the year, `1` to `53`.
+	Week,
+	/// `DD`: day of the month, `1` to `31`.
+	Day,
+	/// `PATCH`: counter that restarts at `0` whenever the date segments change.
+	Patch,
+}
+
+impl CalverSegment {
+	fn parse(token: &str) -> Option<Self> {
+		match token {
+			"YYYY" => Some(Self::FullYear),
+			"YY" => Some(Self::ShortYear),
+			"MM" => Some(Self::Month),
+			"WW" => Some(Self::Week),
+			"DD" => Some(Self::Day),
+			"PATCH" => Some(Self::Patch),
+			_ => None,
+		}
+	}
+
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::FullYear => "YYYY",
+			Self::ShortYear => "YY",
+			Self::Month => "MM",
+			Self::Week => "WW",
+			Self::Day => "DD",
+			Self::Patch => "PATCH",
+		}
+	}
+
+	/// Value of this segment on `date`. Year segments use the ISO week-based
+	/// year when `iso_week_year` is set so `YY.WW` never pairs the first days
+	/// of January with the previous year's last week.
+	///
+	/// Fails for years a version component cannot hold, such as any year
+	/// before 2000 with `YY`.
+	fn value_for(self, date: chrono::NaiveDate, iso_week_year: bool) -> MonochangeResult<u64> {
+		use chrono::Datelike;
+
+		let year = if iso_week_year {
+			date.iso_week().year()
+		} else {
+			date.year()
+		};
+		let year_value = |year: i32| {
+			u64::try_from(year).map_err(|_| {
+				MonochangeError::Config(format!(
+					"calver segment `{}` cannot represent the year {} of {date}",
+					self.as_str(),
+					date.year()
+				))
+			})
+		};
+		match self {
+			Self::FullYear => year_value(year),
+			Self::ShortYear => year_value(year - 2000),
+			Self::Month => Ok(u64::from(date.month())),
+			Self::Week => Ok(u64::from(date.iso_week().week())),
+			Self::Day => Ok(u64::from(date.day())),
+			Self::Patch => Ok(0),
+		}
+	}
+}
+
+/// Parsed calendar version format such as `YYYY.MM.PATCH`.
+///
+/// Formats map onto the three semver components: the first two segments are
+/// date segments and the last one is always the `PATCH` counter. Zero-padded
+/// segments like `0M` are rejected because semver forbids leading zeros.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CalverFormat {
+	major: CalverSegment,
+	minor: CalverSegment,
+}
+
+impl CalverFormat {
+	/// Parse a format string such as `YYYY.MM.PATCH` or `YY.WW.PATCH`.
+	pub fn parse(format: &str) -> Result<Self, String> {
+		let tokens = format.split('.').map(str::trim).collect::<Vec<_>>();
+		let [major, minor, patch] = tokens.as_slice() else {
+			return Err(format!(
+				"calver format `{format}` must have three `.`-separated segments, such as `{DEFAULT_CALVER_FORMAT}`"
+			));
+		};
+		let parse_date_segment = |token: &str| {
+			match CalverSegment::parse(token) {
+				Some(CalverSegment::Patch) => {
+					Err(format!(
+						"calver format `{format}` may only use `PATCH` as its last segment"
+					))
+				}
+				Some(segment) => Ok(segment),
+				None if token.starts_with('0') => {
+					Err(format!(
+						"calver segment `{token}` is not supported because semver versions cannot have leading zeros"
+					))
+				}
+				None => {
+					Err(format!(
+						"unknown calver segment `{token}` in `{format}`; use `YYYY`, `YY`, `MM`, `WW`, or `DD`"
+					))
+				}
+			}
+		};
+		let major = parse_date_segment(major)?;
+		let minor = parse_date_segment(minor)?;
+		if *patch != "PATCH" {
+			return Err(format!(
+				"calver format `{format}` must end with `PATCH` so releases on the same date get distinct versions"
+			));
+		}
+
+		Ok(Self { major, minor })
+	}
+
+	/// Major and minor values for `date`.
+	fn date_segments(self, date: chrono::NaiveDate) -> MonochangeResult<(u64, u64)> {
+		let iso_week_year = self.major == CalverSegment::Week || self.minor == CalverSegment::Week;
+		Ok((
+			self.major.value_for(date, iso_week_year)?,
+			self.minor.value_for(date, iso_week_year)?,
+		))
+	}
+
+	/// Compute the next stable calendar version released on `date`.
+	///
+	/// The `PATCH` counter increments when `current` already carries the same
+	/// date segments and restarts at `0` otherwise. A prerelease for the same
+	/// date segments graduates to its base version.
+	///
+	/// Fails when `date` cannot be represented in this format or when the
+	/// resulting version would not be newer than `current`, for example when
+	/// releasing with a date before the one `current` was released on.
+	pub fn next_version(
+		self,
+		current: Option<&Version>,
+		date: chrono::NaiveDate,
+	) -> MonochangeResult<Version> {
+		let (major, minor) = self.date_segments(date)?;
+		let patch = match current {
+			Some(current) if current.major == major && current.minor == minor => {
+				if current.pre.is_empty() {
+					current.patch + 1
+				} else {
+					current.patch
+				}
+			}
+			_ => 0,
+		};
+		let next = Version::new(major, minor, patch);
+		if let Some(current) = current.filter(|current| next <= **current) {
+			return Err(MonochangeError::Config(format!(
+				"calver version `{next}` for {date} would not be newer than the current version `{current}`"
+			)));
+		}
+		Ok(next)
+	}
+
+	/// Compute the next calendar prerelease on `channel` released on `date`.
+	///
+	/// Later prereleases on the same channel and date segments only increment
+	/// the trailing counter (`2026.10.0-beta.1` → `2026.10.0-beta.2`). Fails
+	/// when that counter is not numeric.
+	pub fn next_prerelease_version(
+		self,
+		current: Option<&Version>,
+		date: chrono::NaiveDate,
+		channel: &str,
+	) -> MonochangeResult<Version> {
+		let next = self.next_version(current, date)?;
+		match current {
+			Some(current)
+				if !current.pre.is_empty()
+					&& (current.major, current.minor, current.patch)
+						== (next.major, next.minor, next.patch) =>
+			{
+				let counter =
+					prerelease_counter(current, channel)?.map_or(1, |counter| counter + 1);
+				Ok(with_prerelease(next, channel, counter))
+			}
+			_ => Ok(with_prerelease(next, channel, 1)),
+		}
+	}
+}
+
+impl Default for CalverFormat {
+	fn default() -> Self {
+		Self {
+			major: CalverSegment::FullYear,
+			minor: CalverSegment::Month,
+		}
+	}
+}
+
+impl fmt::Display for CalverFormat {
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			formatter,
+			"{}.{}.{}",
+			self.major.as_str(),
+			self.minor.as_str(),
+			CalverSegment::Patch.as_str()
+		)
+	}
+}
+
+impl TryFrom<String> for CalverFormat {
+	type Error = String;
+
+	fn try_from(format: String) -> Result<Self, Self::Error> {
+		Self::parse(&format)
+	}
+}
+
+impl From<CalverFormat> for String {
+	fn from(format: CalverFormat) -> Self {
+		format.to_string()
+	}
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -628,6 +1425,9 @@ pub enum EcosystemType {
 	Dart,
 	Python,
 	Go,
+	Helm,
+	Ruby,
+	Jvm,
 }
 
 impl EcosystemType {
@@ -644,8 +1444,10 @@ impl EcosystemType {
 	)]
 	pub fn default_prefix(self) -> &'static str {
 		match self {
-			Self::Cargo | Self::Go => "",
+			Self::Cargo | Self::Go | Self::Jvm => "",
 			Self::Npm | Self::Deno | Self::Dart => "^",
+			Self::Helm => "~",
+			Self::Ruby => "~> ",
 			Self::Python => ">=",
 		}
 	}
@@ -667,8 +1469,9 @@ impl EcosystemType {
 			Self::Npm => &["dependencies", "devDependencies", "peerDependencies"],
 			Self::Deno => &["imports"],
 			Self::Dart => &["dependencies", "dev_dependencies"],
-			Self::Python => &["dependencies"],
+			Self::Python | Self::Helm | Self::Jvm => &["dependencies"],
 			Self::Go => &["require"],
+			Self::Ruby => &["add_dependency", "add_runtime_dependency"],
 		}
 	}
 }
@@ -1710,6 +2513,9 @@ pub enum RegistryKind {
 	PubDev,
 	Pypi,
 	GoProxy,
+	Helm,
+	Rubygems,
+	Maven,
 }
 
 impl RegistryKind {
@@ -1723,6 +2529,9 @@ impl RegistryKind {
 			Self::PubDev => "pub_dev",
 			Self::Pypi => "pypi",
 			Self::GoProxy => "go_proxy",
+			Self::Helm => "helm",
+			Self::Rubygems => "rubygems",
+			Self::Maven => "maven",
 		}
 	}
 }
@@ -1870,6 +2679,12 @@ pub struct PackageDefinition {
 	pub release: bool,
 	pub version_format: VersionFormat,
 	#[serde(default)]
+	pub version_scheme: VersionScheme,
+	#[serde(default)]
+	pub pre_stable_policy: PreStablePolicy,
+	#[serde(default)]
+	pub propagation_by_kind: PropagationByKind,
+	#[serde(default)]
 	pub publish: PublishSettings,
 }
 
@@ -1900,15 +2715,39 @@ pub struct GroupDefinition {
 	pub tag: bool,
 	pub release: bool,
 	pub version_format: VersionFormat,
+	#[serde(default)]
+	pub version_scheme: VersionScheme,
+	#[serde(default)]
+	pub pre_stable_policy: PreStablePolicy,
+}
+
+/// A `[group.<id>]` declared with `kind = "linked"`.
+///
+/// Linked members are still released, versioned, and tagged as individual
+/// packages. Only the bump severity is shared, and members without changes
+/// stay untouched.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LinkedGroupDefinition {
+	pub id: String,
+	pub packages: Vec<String>,
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct WorkspaceDefaults {
 	pub parent_bump: BumpSeverity,
+	#[serde(default)]
+	pub propagation: DependencyPropagation,
+	#[serde(default)]
+	pub propagation_by_kind: PropagationByKind,
 	pub include_private: bool,
 	pub warn_on_group_mismatch: bool,
 	pub strict_version_conflicts: bool,
+	#[serde(default)]
+	pub pre_stable_policy: PreStablePolicy,
+	#[serde(default)]
+	pub analysis_enforcement: AnalysisEnforcement,
 	pub package_type: Option<PackageType>,
 	pub changelog: Option<ChangelogDefinition>,
 	pub changelog_format: ChangelogFormat,
@@ -1921,9 +2760,13 @@ impl Default for WorkspaceDefaults {
 	fn default() -> Self {
 		Self {
 			parent_bump: BumpSeverity::Patch,
+			propagation: DependencyPropagation::Always,
+			propagation_by_kind: PropagationByKind::default(),
 			include_private: false,
 			warn_on_group_mismatch: true,
 			strict_version_conflicts: false,
+			pre_stable_policy: PreStablePolicy::Shift,
+			analysis_enforcement: AnalysisEnforcement::Off,
 			package_type: None,
 			changelog: None,
 			changelog_format: ChangelogFormat::Monochange,
@@ -2820,8 +3663,17 @@ impl CliStepDefinition {
 			Self::Validate { .. } => Some(&["fix"]),
 			Self::CommitRelease { .. } => Some(&["no_verify", "update_release_json", "stage_all"]),
 			Self::VerifyReleaseBranch { .. } => Some(&["from"]),
-			Self::Discover { .. } | Self::DisplayVersions { .. } | Self::PrepareRelease { .. } => {
-				Some(&["format"])
+			Self::Discover { .. } | Self::DisplayVersions { .. } => Some(&["format"]),
+			Self::PrepareRelease { .. } => {
+				Some(&[
+					"format",
+					"pre",
+					"pre_package",
+					"exit_pre",
+					"snapshot",
+					"snapshot_template",
+					"graduate",
+				])
 			}
 			Self::CommentReleasedIssues { .. } => {
 				Some(&["format", "from-ref", "auto-close-issues"])
@@ -2846,6 +3698,7 @@ impl CliStepDefinition {
 			Self::CreateChangeFile { .. } => {
 				Some(&[
 					"interactive",
+					"from_analysis",
 					"package",
 					"bump",
 					"version",
@@ -2934,9 +3787,18 @@ impl CliStepDefinition {
 				}
 			}
 			Self::Config { .. } | Self::Command { .. } => None,
-			Self::Discover { .. } | Self::DisplayVersions { .. } | Self::PrepareRelease { .. } => {
+			Self::Discover { .. } | Self::DisplayVersions { .. } => {
 				matches!(name, "format").then_some(CliInputKind::Choice)
 			}
+			Self::PrepareRelease { .. } => {
+				match name {
+					"format" => Some(CliInputKind::Choice),
+					"pre" | "snapshot" | "snapshot_template" => Some(CliInputKind::String),
+					"pre_package" | "graduate" => Some(CliInputKind::StringList),
+					"exit_pre" => Some(CliInputKind::Boolean),
+					_ => None,
+				}
+			}
 			Self::CommentReleasedIssues { .. } => {
 				match name {
 					"format" => Some(CliInputKind::Choice),
@@ -2988,7 +3850,7 @@ impl CliStepDefinition {
 			}
 			Self::CreateChangeFile { .. } => {
 				match name {
-					"interactive" => Some(CliInputKind::Boolean),
+					"interactive" | "from_analysis" => Some(CliInputKind::Boolean),
 					"package" => Some(CliInputKind::StringList),
 					"bump" => Some(CliInputKind::Choice),
 					"version" | "reason" | "type" | "details" => Some(CliInputKind::String),
@@ -3295,6 +4157,9 @@ pub struct ReleaseManifestTarget {
 	pub rendered_title: String,
 	#[serde(default)]
 	pub rendered_changelog_title: String,
+	/// Prerelease channel (for example `beta`) when this target ships a prerelease.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub prerelease: Option<String>,
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -3843,6 +4708,9 @@ pub struct ReleaseRecordTarget {
 	pub tag_name: String,
 	#[serde(default)]
 	pub members: Vec<String>,
+	/// Prerelease channel (for example `beta`) when this target shipped a prerelease.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub prerelease: Option<String>,
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -4291,10 +5159,79 @@ impl Default for ChangesetAffectedSettings {
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ChangesetSettings {
 	#[serde(default)]
 	pub affected: ChangesetAffectedSettings,
+	/// Where release intent is read from. Sources can be combined.
+	#[serde(default = "default_change_sources")]
+	pub sources: Vec<ChangeSource>,
+	#[serde(default)]
+	pub conventional_commits: ConventionalCommitSettings,
+}
+
+impl Default for ChangesetSettings {
+	fn default() -> Self {
+		Self {
+			affected: ChangesetAffectedSettings::default(),
+			sources: default_change_sources(),
+			conventional_commits: ConventionalCommitSettings::default(),
+		}
+	}
+}
+
+impl ChangesetSettings {
+	/// Return `true` when `source` is one of the configured change sources.
+	#[must_use]
+	pub fn reads_from(&self, source: ChangeSource) -> bool {
+		self.sources.contains(&source)
+	}
+}
+
+/// A place monochange reads release intent from.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ChangeSource {
+	/// Markdown changeset files under `.changeset/`.
+	Files,
+	/// Conventional commits made since the last release record.
+	ConventionalCommits,
+	/// `Changeset:` trailers on commits made since the last release record.
+	CommitTrailers,
+}
+
+impl ChangeSource {
+	/// Return the canonical serialized name for the change source.
+	#[must_use]
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Files => "files",
+			Self::ConventionalCommits => "conventional_commits",
+			Self::CommitTrailers => "commit_trailers",
+		}
+	}
+}
+
+impl fmt::Display for ChangeSource {
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		formatter.write_str(self.as_str())
+	}
+}
+
+fn default_change_sources() -> Vec<ChangeSource> {
+	vec![ChangeSource::Files]
+}
+
+/// Settings for deriving changes from conventional commits.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub struct ConventionalCommitSettings {
+	/// Commit scopes that map to a package or group id. Scopes that are not
+	/// listed here match package ids and group ids directly.
+	#[serde(default)]
+	pub scopes: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
@@ -4636,6 +5573,8 @@ pub struct EffectiveReleaseIdentity {
 	pub tag: bool,
 	pub release: bool,
 	pub version_format: VersionFormat,
+	#[serde(default)]
+	pub version_scheme: VersionScheme,
 	pub members: Vec<String>,
 }
 
@@ -4647,6 +5586,8 @@ pub struct WorkspaceConfiguration {
 	pub changelog: ChangelogSettings,
 	pub packages: Vec<PackageDefinition>,
 	pub groups: Vec<GroupDefinition>,
+	#[serde(default)]
+	pub linked_groups: Vec<LinkedGroupDefinition>,
 	pub cli: Vec<CliCommandDefinition>,
 	pub changesets: ChangesetSettings,
 	pub source: Option<SourceConfiguration>,
@@ -4658,6 +5599,9 @@ pub struct WorkspaceConfiguration {
 	pub dart: EcosystemSettings,
 	pub python: EcosystemSettings,
 	pub go: EcosystemSettings,
+	pub helm: EcosystemSettings,
+	pub ruby: EcosystemSettings,
+	pub jvm: EcosystemSettings,
 }
 
 impl WorkspaceConfiguration {
@@ -4683,6 +5627,39 @@ impl WorkspaceConfiguration {
 			.find(|group| group.packages.iter().any(|member| member == package_id))
 	}
 
+	/// Return the linked group that `package_id` belongs to, if any.
+	#[must_use]
+	pub fn linked_group_for_package(&self, package_id: &str) -> Option<&LinkedGroupDefinition> {
+		self.linked_groups
+			.iter()
+			.find(|group| group.packages.iter().any(|member| member == package_id))
+	}
+
+	/// Expand `owner` into the members of the linked group with that id.
+	/// Any other id is returned unchanged.
+	#[must_use]
+	pub fn expand_linked_group<'a>(&'a self, owner: &'a str) -> Vec<&'a str> {
+		self.linked_groups
+			.iter()
+			.find(|group| group.id == owner)
+			.map_or_else(
+				|| vec![owner],
+				|group| group.packages.iter().map(String::as_str).collect(),
+			)
+	}
+
+	/// Resolve the pre-1.0 bump policy for `package_id`.
+	///
+	/// Grouped packages follow their group so every member moves together.
+	#[must_use]
+	pub fn pre_stable_policy_for_package(&self, package_id: &str) -> Option<PreStablePolicy> {
+		if let Some(group) = self.group_for_package(package_id) {
+			return Some(group.pre_stable_policy);
+		}
+		self.package_by_id(package_id)
+			.map(|package| package.pre_stable_policy)
+	}
+
 	/// Resolve the effective outward release identity for a package.
 	#[must_use]
 	pub fn effective_release_identity(&self, package_id: &str) -> Option<EffectiveReleaseIdentity> {
@@ -4695,6 +5672,7 @@ impl WorkspaceConfiguration {
 				tag: group.tag,
 				release: group.release,
 				version_format: group.version_format,
+				version_scheme: group.version_scheme.clone(),
 				members: group.packages.clone(),
 			});
 		}
@@ -4706,6 +5684,7 @@ impl WorkspaceConfiguration {
 			tag: package.tag,
 			release: package.release,
 			version_format: package.version_format,
+			version_scheme: package.version_scheme.clone(),
 			members: vec![package.id.clone()],
 		})
 	}
@@ -4901,16 +5880,47 @@ pub struct ReleaseDecision {
 	pub planned_version: Option<Version>,
 	pub group_id: Option<String>,
 	pub reasons: Vec<String>,
+	/// Structured form of the propagation entries in `reasons`.
+	#[serde(default)]
+	pub causes: Vec<ReleaseCause>,
 	pub upstream_sources: Vec<String>,
 	pub warnings: Vec<String>,
 }
 
+/// Why the planner carried a release over to a package from somewhere else.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReleaseCause {
+	/// A released dependency propagated its bump through a dependency of
+	/// `dependency_kind`.
+	Dependency {
+		package_id: String,
+		dependency_kind: DependencyKind,
+	},
+	/// The package shares a version group with a released member.
+	VersionGroup { group_id: String },
+	/// The package shares a linked group with a released member.
+	LinkedGroup { group_id: String },
+}
+
+/// Explicit versions for one package or version group that disagreed; the
+/// planner kept the highest one.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VersionConflict {
+	pub owner_kind: ReleaseOwnerKind,
+	pub owner_id: String,
+	/// The same text reported in [`ReleasePlan::warnings`].
+	pub message: String,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ReleasePlan {
 	pub workspace_root: PathBuf,
 	pub decisions: Vec<ReleaseDecision>,
 	pub groups: Vec<PlannedVersionGroup>,
 	pub warnings: Vec<String>,
+	#[serde(default)]
+	pub version_conflicts: Vec<VersionConflict>,
 	pub unresolved_items: Vec<String>,
 	pub compatibility_evidence: Vec<CompatibilityAssessment>,
 }
@@ -4922,12 +5932,35 @@ pub struct DiscoveryReport {
 	pub dependencies: Vec<DependencyEdge>,
 	pub version_groups: Vec<VersionGroup>,
 	pub warnings: Vec<String>,
+	#[serde(default)]
+	pub skipped: Vec<DiscoverySkip>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct AdapterDiscovery {
 	pub packages: Vec<PackageRecord>,
 	pub warnings: Vec<String>,
+	pub skipped: Vec<DiscoverySkip>,
+}
+
+impl AdapterDiscovery {
+	/// Move packages whose manifests fall outside `filter`'s scope into
+	/// `skipped`.
+	pub fn retain_in_scope(&mut self, filter: &DiscoveryPathFilter) {
+		let packages = std::mem::take(&mut self.packages);
+		for package in packages {
+			match filter.scope_skip_reason(&package.manifest_path) {
+				Some(reason) => {
+					self.skipped.push(DiscoverySkip {
+						ecosystem: package.ecosystem,
+						path: package.manifest_path,
+						reason,
+					});
+				}
+				None => self.packages.push(package),
+			}
+		}
+	}
 }
 
 pub trait EcosystemAdapter: Send + Sync {
@@ -4935,6 +5968,19 @@ pub trait EcosystemAdapter: Send + Sync {
 
 	fn discover(&self, root: &Path) -> MonochangeResult<AdapterDiscovery>;
 
+	/// Discover packages under `root` without reading manifests outside
+	/// `filter`'s configured roots or inside its exclude globs.
+	///
+	/// Adapters should prune their walk with `filter`. The default walks the
+	/// whole repository and leaves scoping to [`EcosystemRegistry`].
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		_filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		self.discover(root)
+	}
+
 	fn load_configured(
 		&self,
 		root: &Path,
@@ -4960,6 +6006,7 @@ pub trait EcosystemAdapter: Send + Sync {
 #[derive(Default)]
 pub struct EcosystemRegistry {
 	adapters: Vec<Box<dyn EcosystemAdapter>>,
+	discovery_scopes: BTreeMap<Ecosystem, (Vec<String>, Vec<String>)>,
 }
 
 impl EcosystemRegistry {
@@ -4976,11 +6023,42 @@ impl EcosystemRegistry {
 		self.adapters.push(adapter);
 	}
 
+	/// Limit discovery for `ecosystem` to the settings' `roots` and `exclude`
+	/// globs.
+	pub fn set_discovery_scope(&mut self, ecosystem: Ecosystem, settings: &EcosystemSettings) {
+		if settings.roots.is_empty() && settings.exclude.is_empty() {
+			self.discovery_scopes.remove(&ecosystem);
+			return;
+		}
+		self.discovery_scopes.insert(
+			ecosystem,
+			(settings.roots.clone(), settings.exclude.clone()),
+		);
+	}
+
+	fn discover_adapter(
+		&self,
+		adapter: &dyn EcosystemAdapter,
+		root: &Path,
+	) -> MonochangeResult<AdapterDiscovery> {
+		match self.discovery_scopes.get(&adapter.ecosystem()) {
+			Some((roots, exclude)) => {
+				let filter = DiscoveryPathFilter::new(root).with_scope(roots, exclude)?;
+				let mut discovery = adapter.discover_with_filter(root, &filter)?;
+				discovery.retain_in_scope(&filter);
+				Ok(discovery)
+			}
+			None => adapter.discover(root),
+		}
+	}
+
 	pub fn discover_all(&self, root: &Path) -> MonochangeResult<AdapterDiscovery> {
 		let results = std::thread::scope(|scope| {
 			self.adapters
 				.iter()
-				.map(|adapter| scope.spawn(move || adapter.discover(root)))
+				.map(|adapter| {
+					scope.spawn(move || self.discover_adapter(adapter.as_ref(), root))
+				})
 				.collect::<Vec<_>>()
 				.into_iter()
 				.map(|handle| {
@@ -4997,11 +6075,19 @@ impl EcosystemRegistry {
 			Vec::with_capacity(results.iter().map(|result| result.packages.len()).sum());
 		let mut warnings =
 			Vec::with_capacity(results.iter().map(|result| result.warnings.len()).sum());
+		let mut skipped = Vec::new();
 		for mut result in results {
 			packages.append(&mut result.packages);
 			warnings.append(&mut result.warnings);
+			skipped.append(&mut result.skipped);
 		}
-		Ok(AdapterDiscovery { packages, warnings })
+		skipped.sort_by(|left, right| left.path.cmp(&right.path));
+		skipped.dedup();
+		Ok(AdapterDiscovery {
+			packages,
+			warnings,
+			skipped,
+		})
 	}
 
 	pub fn adapter_for_ecosystem(&self, ecosystem: Ecosystem) -> Option<&dyn EcosystemAdapter> {
@@ -5055,8 +6141,10 @@ pub fn default_publish_order_dependency_fields(ecosystem: Ecosystem) -> &'static
 		Ecosystem::Npm => &["dependencies", "devDependencies"],
 		Ecosystem::Deno => &["dependencies", "imports"],
 		Ecosystem::Dart | Ecosystem::Flutter => &["dependencies", "dev_dependencies"],
-		Ecosystem::Python => &["dependencies"],
+		Ecosystem::Python | Ecosystem::Helm => &["dependencies"],
 		Ecosystem::Go => &["require"],
+		Ecosystem::Ruby => &["add_dependency", "add_runtime_dependency"],
+		Ecosystem::Jvm => &["parent", "dependencies"],
 	}
 }
 
@@ -5092,6 +6180,190 @@ pub fn materialize_dependency_edges(packages: &[PackageRecord]) -> Vec<Dependenc
 	edges
 }
 
+/// One link inside a [`DependencyCycle`] with every dependency kind that
+/// connects the two packages.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyCycleEdge {
+	pub from_package_id: String,
+	pub to_package_id: String,
+	pub dependency_kinds: Vec<DependencyKind>,
+}
+
+impl DependencyCycleEdge {
+	/// Return `true` when only development dependencies connect the two
+	/// packages.
+	#[must_use]
+	pub fn is_development_only(&self) -> bool {
+		self.dependency_kinds
+			.iter()
+			.all(|kind| *kind == DependencyKind::Development)
+	}
+}
+
+impl fmt::Display for DependencyCycleEdge {
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let kinds = self
+			.dependency_kinds
+			.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join(", ");
+		write!(
+			formatter,
+			"{} -> {} ({kinds})",
+			self.from_package_id, self.to_package_id
+		)
+	}
+}
+
+/// A set of packages that all depend on each other, directly or
+/// transitively.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyCycle {
+	/// Packages in the cycle, sorted by id.
+	pub package_ids: Vec<String>,
+	/// Every dependency between two packages of the cycle.
+	pub edges: Vec<DependencyCycleEdge>,
+	/// `true` when the cycle disappears once development dependencies are
+	/// ignored, so publish ordering can still be derived.
+	pub breakable: bool,
+}
+
+impl DependencyCycle {
+	/// Return every dependency kind that takes part in the cycle.
+	#[must_use]
+	pub fn dependency_kinds(&self) -> BTreeSet<DependencyKind> {
+		self.edges
+			.iter()
+			.flat_map(|edge| edge.dependency_kinds.iter().copied())
+			.collect()
+	}
+
+	/// Explain what the cycle means for publish ordering.
+	#[must_use]
+	pub fn publish_order_note(&self) -> String {
+		if self.breakable {
+			"breakable: it only closes through development dependencies, so publish order \
+			 can be derived once those are left out of `publish_order.dependency_fields`"
+				.to_string()
+		} else {
+			let kinds = self
+				.dependency_kinds()
+				.into_iter()
+				.filter(|kind| *kind != DependencyKind::Development)
+				.map(|kind| kind.to_string())
+				.collect::<Vec<_>>()
+				.join(", ");
+			format!(
+				"not breakable: it closes through {kinds} dependencies, so publish order is ambiguous"
+			)
+		}
+	}
+}
+
+impl fmt::Display for DependencyCycle {
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let edges = self
+			.edges
+			.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join(", ");
+		write!(
+			formatter,
+			"dependency cycle {edges}; {}",
+			self.publish_order_note()
+		)
+	}
+}
+
+/// Find every dependency cycle among `dependency_edges`.
+///
+/// Each cycle is a strongly connected set of packages, so packages that are
+/// part of several overlapping loops are reported once. Self-dependencies
+/// are ignored.
+#[must_use]
+pub fn detect_dependency_cycles(dependency_edges: &[DependencyEdge]) -> Vec<DependencyCycle> {
+	let mut kinds_by_link = BTreeMap::<(&str, &str), BTreeSet<DependencyKind>>::new();
+	for edge in dependency_edges
+		.iter()
+		.filter(|edge| edge.from_package_id != edge.to_package_id)
+	{
+		kinds_by_link
+			.entry((edge.from_package_id.as_str(), edge.to_package_id.as_str()))
+			.or_default()
+			.insert(edge.dependency_kind);
+	}
+	let mut dependencies = BTreeMap::<&str, BTreeSet<&str>>::new();
+	for (from, to) in kinds_by_link.keys() {
+		dependencies.entry(from).or_default().insert(to);
+	}
+
+	let reachable = |start: &str, skip_development: bool| {
+		let mut visited = BTreeSet::new();
+		let mut pending = vec![start];
+		while let Some(current) = pending.pop() {
+			for dependency in dependencies.get(current).into_iter().flatten() {
+				let development_only =
+					kinds_by_link
+						.get(&(current, *dependency))
+						.is_some_and(|kinds| {
+							kinds
+								.iter()
+								.all(|kind| *kind == DependencyKind::Development)
+						});
+				if skip_development && development_only {
+					continue;
+				}
+				if visited.insert(*dependency) {
+					pending.push(dependency);
+				}
+			}
+		}
+		visited
+	};
+
+	let mut assigned = BTreeSet::<&str>::new();
+	let mut cycles = Vec::new();
+	for package_id in dependencies.keys() {
+		if assigned.contains(package_id) {
+			continue;
+		}
+		let forward = reachable(package_id, false);
+		if !forward.contains(package_id) {
+			continue;
+		}
+		let members = forward
+			.into_iter()
+			.filter(|member| reachable(member, false).contains(package_id))
+			.collect::<BTreeSet<_>>();
+		assigned.extend(members.iter().copied());
+
+		let edges = kinds_by_link
+			.iter()
+			.filter(|((from, to), _)| members.contains(from) && members.contains(to))
+			.map(|((from, to), kinds)| {
+				DependencyCycleEdge {
+					from_package_id: (*from).to_string(),
+					to_package_id: (*to).to_string(),
+					dependency_kinds: kinds.iter().copied().collect(),
+				}
+			})
+			.collect();
+		let breakable = members
+			.iter()
+			.all(|member| !reachable(member, true).contains(member));
+		cycles.push(DependencyCycle {
+			package_ids: members.into_iter().map(ToString::to_string).collect(),
+			edges,
+			breakable,
+		});
+	}
+	cycles
+}
+
 #[cfg(feature = "schema")]
 pub mod schema {
 	/// Generate the JSON Schema for the monochange release record.
@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
+use monochange_core::DiscoveryPathFilter;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::PackageRecord;
@@ -364,8 +365,12 @@ fn workspace_and_manifest_helpers_cover_yaml_and_error_paths() {
 	.unwrap_or_else(|| panic!("expected workspace root package"));
 	assert_eq!(workspace_root.publish_state, PublishState::Private);
 
-	let discovery = discover_workspace_packages(&workspace_manifest)
-		.unwrap_or_else(|error| panic!("workspace discovery: {error}"));
+	let discovery = discover_workspace_packages(
+		&workspace_manifest,
+		&DiscoveryPathFilter::new(&fixture_root),
+		&mut Vec::new(),
+	)
+	.unwrap_or_else(|error| panic!("workspace discovery: {error}"));
 	assert_eq!(discovery.0.len(), 2);
 	assert!(discovery.1.iter().any(|warning| {
 		warning.contains("missing/*") && warning.contains("matched no packages")
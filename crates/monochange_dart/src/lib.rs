@@ -47,9 +47,11 @@ use glob::glob;
 use monochange_core::AdapterDiscovery;
 use monochange_core::DependencyKind;
 use monochange_core::DiscoveryPathFilter;
+use monochange_core::DiscoverySkip;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageDependency;
@@ -62,8 +64,6 @@ use monochange_publish::PublishRequest;
 use semver::Version;
 use serde_yaml_ng::Mapping;
 use serde_yaml_ng::Value;
-use walkdir::DirEntry;
-use walkdir::WalkDir;
 
 pub mod lints;
 
@@ -428,6 +428,14 @@ impl EcosystemAdapter for DartAdapter {
 		discover_dart_packages(root)
 	}
 
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_dart_packages_with_filter(root, filter)
+	}
+
 	fn load_configured(
 		&self,
 		root: &Path,
@@ -454,14 +462,27 @@ impl EcosystemAdapter for DartAdapter {
 #[must_use = "the discovery result must be checked"]
 /// Discover Dart and Flutter packages rooted at `root`.
 pub fn discover_dart_packages(root: &Path) -> MonochangeResult<AdapterDiscovery> {
-	let workspace_manifests = find_workspace_manifests(root);
+	discover_dart_packages_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Dart and Flutter packages rooted at `root` without parsing
+/// manifests outside `filter`'s discovery scope.
+pub fn discover_dart_packages_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_manifests(root, filter);
+	let mut skipped = walk.skipped;
+	let workspace_manifests = find_workspace_manifests(&walk.manifests);
 	let mut included_manifests = HashSet::new();
 	let mut packages = Vec::new();
 	let mut warnings = Vec::new();
 
 	for workspace_manifest in workspace_manifests {
 		let (workspace_packages, workspace_warnings) =
-			discover_workspace_packages(&workspace_manifest)?;
+			discover_workspace_packages(&workspace_manifest, filter, &mut skipped)?;
 		warnings.extend(workspace_warnings);
 		for package in workspace_packages {
 			included_manifests.insert(package.manifest_path.clone());
@@ -469,7 +490,9 @@ pub fn discover_dart_packages(root: &Path) -> MonochangeResult<AdapterDiscovery>
 		}
 	}
 
-	for manifest_path in find_all_manifests(root) {
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Dart, walk.manifests, &mut skipped)
+	{
 		if included_manifests.contains(&manifest_path) {
 			continue;
 		}
@@ -485,7 +508,11 @@ pub fn discover_dart_packages(root: &Path) -> MonochangeResult<AdapterDiscovery>
 	packages.dedup_by(|left, right| left.id == right.id);
 	tracing::debug!(packages = packages.len(), "discovered dart packages");
 
-	Ok(AdapterDiscovery { packages, warnings })
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
 }
 
 /// Load one explicitly configured Dart/Flutter package without walking the repo.
@@ -503,10 +530,11 @@ pub fn load_configured_dart_package(
 	parse_manifest(&manifest_path, manifest_path.parent().unwrap_or(root))
 }
 
-fn find_workspace_manifests(root: &Path) -> Vec<PathBuf> {
-	let mut manifests = find_all_manifests(root)
-		.into_iter()
+fn find_workspace_manifests(manifests: &[PathBuf]) -> Vec<PathBuf> {
+	let mut manifests = manifests
+		.iter()
 		.filter(|manifest_path| has_workspace_section(manifest_path).unwrap_or(false))
+		.cloned()
 		.collect::<Vec<_>>();
 	manifests.sort();
 	manifests
@@ -514,6 +542,8 @@ fn find_workspace_manifests(root: &Path) -> Vec<PathBuf> {
 
 fn discover_workspace_packages(
 	workspace_manifest: &Path,
+	filter: &DiscoveryPathFilter,
+	skipped: &mut Vec<DiscoverySkip>,
 ) -> MonochangeResult<(Vec<PackageRecord>, Vec<String>)> {
 	let parsed = parse_yaml_manifest(workspace_manifest)?;
 	let workspace_root = workspace_manifest
@@ -521,7 +551,13 @@ fn discover_workspace_packages(
 		.unwrap_or_else(|| Path::new("."));
 	let patterns = yaml_array_strings(&parsed, "workspace");
 	let mut warnings = Vec::new();
-	let manifests = expand_workspace_patterns(workspace_root, &patterns, &mut warnings);
+	let manifests = filter.retain_manifests_in_scope(
+		Ecosystem::Dart,
+		expand_workspace_patterns(workspace_root, &patterns, &mut warnings)
+			.into_iter()
+			.collect(),
+		skipped,
+	);
 	let mut packages = Vec::new();
 
 	for manifest_path in manifests {
@@ -729,16 +765,13 @@ fn yaml_array_strings(mapping: &Mapping, key: &str) -> Vec<String> {
 		.unwrap_or_default()
 }
 
-fn find_all_manifests(root: &Path) -> Vec<PathBuf> {
-	let filter = DiscoveryPathFilter::new(root);
-	WalkDir::new(root)
-		.into_iter()
-		.filter_entry(|entry| filter.should_descend(entry.path()))
-		.filter_map(Result::ok)
-		.filter(|entry| entry.file_name() == PUBSPEC_FILE)
-		.map(DirEntry::into_path)
-		.map(|path| normalize_path(&path))
-		.collect()
+fn find_all_manifests(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(
+		Ecosystem::Dart,
+		root,
+		|entry| filter.should_descend(entry.path()),
+		|entry| entry.file_name() == PUBSPEC_FILE,
+	)
 }
 
 #[cfg(test)]
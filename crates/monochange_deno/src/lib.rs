@@ -32,6 +32,7 @@
 //! <!-- {/monochangeDenoCrateDocs} -->
 
 pub mod analysis;
+pub mod lints;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -46,9 +47,11 @@ use glob::glob;
 use monochange_core::AdapterDiscovery;
 use monochange_core::DependencyKind;
 use monochange_core::DiscoveryPathFilter;
+use monochange_core::DiscoverySkip;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageDependency;
@@ -59,8 +62,6 @@ use monochange_core::normalize_path;
 use monochange_publish::PublishRequest;
 use semver::Version;
 use serde_json::Value;
-use walkdir::DirEntry;
-use walkdir::WalkDir;
 
 pub const DENO_MANIFEST_FILES: [&str; 2] = ["deno.json", "deno.jsonc"];
 
@@ -216,6 +217,14 @@ impl EcosystemAdapter for DenoAdapter {
 		discover_deno_packages(root)
 	}
 
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_deno_packages_with_filter(root, filter)
+	}
+
 	fn load_configured(
 		&self,
 		root: &Path,
@@ -242,14 +251,27 @@ impl EcosystemAdapter for DenoAdapter {
 #[must_use = "the discovery result must be checked"]
 /// Discover Deno packages rooted at `root`.
 pub fn discover_deno_packages(root: &Path) -> MonochangeResult<AdapterDiscovery> {
-	let workspace_manifests = find_workspace_manifests(root);
+	discover_deno_packages_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Deno packages rooted at `root` without parsing manifests outside
+/// `filter`'s discovery scope.
+pub fn discover_deno_packages_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_manifests(root, filter);
+	let mut skipped = walk.skipped;
+	let workspace_manifests = find_workspace_manifests(&walk.manifests);
 	let mut included_manifests = HashSet::new();
 	let mut packages = Vec::new();
 	let mut warnings = Vec::new();
 
 	for workspace_manifest in workspace_manifests {
 		let (workspace_packages, workspace_warnings) =
-			discover_workspace_packages(&workspace_manifest)?;
+			discover_workspace_packages(&workspace_manifest, filter, &mut skipped)?;
 		warnings.extend(workspace_warnings);
 		for package in workspace_packages {
 			included_manifests.insert(package.manifest_path.clone());
@@ -257,7 +279,9 @@ pub fn discover_deno_packages(root: &Path) -> MonochangeResult<AdapterDiscovery>
 		}
 	}
 
-	for manifest_path in find_all_manifests(root) {
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Deno, walk.manifests, &mut skipped)
+	{
 		if included_manifests.contains(&manifest_path) {
 			continue;
 		}
@@ -273,7 +297,11 @@ pub fn discover_deno_packages(root: &Path) -> MonochangeResult<AdapterDiscovery>
 	packages.dedup_by(|left, right| left.id == right.id);
 	tracing::debug!(packages = packages.len(), "discovered deno packages");
 
-	Ok(AdapterDiscovery { packages, warnings })
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
 }
 
 /// Load one explicitly configured Deno package without scanning unrelated manifests.
@@ -294,10 +322,11 @@ pub fn load_configured_deno_package(
 	parse_manifest(&manifest_path, manifest_path.parent().unwrap_or(root))
 }
 
-fn find_workspace_manifests(root: &Path) -> Vec<PathBuf> {
-	let mut manifests = find_all_manifests(root)
-		.into_iter()
+fn find_workspace_manifests(manifests: &[PathBuf]) -> Vec<PathBuf> {
+	let mut manifests = manifests
+		.iter()
 		.filter(|manifest_path| has_workspace_section(manifest_path).unwrap_or(false))
+		.cloned()
 		.collect::<Vec<_>>();
 	manifests.sort();
 	manifests
@@ -305,6 +334,8 @@ fn find_workspace_manifests(root: &Path) -> Vec<PathBuf> {
 
 fn discover_workspace_packages(
 	workspace_manifest: &Path,
+	filter: &DiscoveryPathFilter,
+	skipped: &mut Vec<DiscoverySkip>,
 ) -> MonochangeResult<(Vec<PackageRecord>, Vec<String>)> {
 	let parsed = parse_json_manifest(workspace_manifest)?;
 	let workspace_root = workspace_manifest
@@ -322,7 +353,13 @@ fn discover_workspace_packages(
 		})
 		.unwrap_or_default();
 	let mut warnings = Vec::new();
-	let member_manifests = expand_workspace_patterns(workspace_root, &patterns, &mut warnings);
+	let member_manifests = filter.retain_manifests_in_scope(
+		Ecosystem::Deno,
+		expand_workspace_patterns(workspace_root, &patterns, &mut warnings)
+			.into_iter()
+			.collect(),
+		skipped,
+	);
 	let mut packages = Vec::new();
 
 	for member_manifest in member_manifests {
@@ -450,16 +487,13 @@ fn parse_json_manifest(manifest_path: &Path) -> MonochangeResult<Value> {
 	})
 }
 
-fn find_all_manifests(root: &Path) -> Vec<PathBuf> {
-	let filter = DiscoveryPathFilter::new(root);
-	WalkDir::new(root)
-		.into_iter()
-		.filter_entry(|entry| filter.should_descend(entry.path()))
-		.filter_map(Result::ok)
-		.filter(|entry| DENO_MANIFEST_FILES.contains(&entry.file_name().to_string_lossy().as_ref()))
-		.map(DirEntry::into_path)
-		.map(|path| normalize_path(&path))
-		.collect()
+fn find_all_manifests(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(
+		Ecosystem::Deno,
+		root,
+		|entry| filter.should_descend(entry.path()),
+		|entry| DENO_MANIFEST_FILES.contains(&entry.file_name().to_string_lossy().as_ref()),
+	)
 }
 
 /// Return the default dependency-version prefix for this ecosystem.
@@ -0,0 +1,229 @@
+use monochange_config::load_workspace_configuration;
+use monochange_core::lint::LintSuite;
+use monochange_core::lint::LintTarget;
+use monochange_test_helpers::fixture_path;
+use serde_json::json;
+
+use super::*;
+
+fn workspace_hygiene_targets() -> Vec<LintTarget> {
+	let root = fixture_path!("deno-lints/workspace-hygiene/workspace");
+	let configuration = load_workspace_configuration(&root)
+		.unwrap_or_else(|error| panic!("load deno lint fixture config: {error}"));
+	lint_suite()
+		.collect_targets(&root, &configuration)
+		.unwrap_or_else(|error| panic!("collect deno lint fixture targets: {error}"))
+}
+
+fn find_target<'a>(targets: &'a [LintTarget], package_name: &str) -> &'a LintTarget {
+	targets
+		.iter()
+		.find(|target| target.metadata.package_name.as_deref() == Some(package_name))
+		.unwrap_or_else(|| panic!("missing target for {package_name}"))
+}
+
+fn ctx(target: &LintTarget) -> LintContext<'_> {
+	LintContext {
+		workspace_root: &target.workspace_root,
+		manifest_path: &target.manifest_path,
+		contents: &target.contents,
+		metadata: &target.metadata,
+		parsed: target.parsed.as_ref(),
+	}
+}
+
+fn config() -> LintRuleConfig {
+	LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("fix".to_string(), json!(true))]),
+	}
+}
+
+fn apply_fix(contents: &str, result: &LintResult) -> String {
+	let edit = result
+		.fix
+		.as_ref()
+		.and_then(|fix| fix.edits.first())
+		.unwrap_or_else(|| panic!("expected an autofix for {}", result.rule_id));
+	let mut fixed = contents.to_string();
+	fixed.replace_range(edit.span.0..edit.span.1, &edit.replacement);
+	fixed
+}
+
+#[test]
+fn presets_are_exposed() {
+	let presets = DenoLintSuite.presets();
+	assert_eq!(presets.len(), 2);
+
+	let recommended = presets.first().expect("expected recommended preset");
+	assert_eq!(recommended.id, "deno/recommended");
+	assert_eq!(
+		recommended.rules.get("deno/sorted-dependencies"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Warning))
+	);
+	assert_eq!(
+		recommended.rules.get("deno/jsr-exports"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Error))
+	);
+
+	let strict = presets.get(1).expect("expected strict preset");
+	assert_eq!(strict.id, "deno/strict");
+	assert_eq!(
+		strict.rules.get("deno/sorted-dependencies"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Error))
+	);
+}
+
+#[test]
+fn collect_targets_reads_jsonc_manifests_and_entrypoints() {
+	let targets = workspace_hygiene_targets();
+	assert_eq!(targets.len(), 4);
+
+	let cli = find_target(&targets, "@acme/cli");
+	assert!(cli.metadata.managed);
+	assert_eq!(cli.metadata.package_id.as_deref(), Some("cli"));
+	assert_eq!(cli.metadata.ecosystem, "deno");
+	assert!(
+		cli.parsed
+			.downcast_ref::<DenoLintFile>()
+			.is_some_and(|file| file.entrypoint.is_none())
+	);
+
+	let http = find_target(&targets, "@acme/http");
+	assert!(
+		http.parsed
+			.downcast_ref::<DenoLintFile>()
+			.is_some_and(|file| file.entrypoint == Some("mod.ts"))
+	);
+
+	let scratch = find_target(&targets, "@acme/scratch");
+	assert!(!scratch.metadata.managed);
+}
+
+#[test]
+fn jsr_exports_rule_inserts_a_conventional_entrypoint() {
+	let targets = workspace_hygiene_targets();
+	let rule = JsrExportsRule::new();
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "@acme/core")), &config())
+			.is_empty()
+	);
+
+	let http = find_target(&targets, "@acme/http");
+	let results = rule.run(&ctx(http), &config());
+	let result = results.first().expect("expected an http result");
+	assert_eq!(
+		result.message,
+		"published Deno packages must declare `exports` for JSR"
+	);
+	assert!(apply_fix(&http.contents, result).contains(
+		"  \"version\": \"0.3.0\",\n  \"exports\": \"./mod.ts\",\n  \"imports\": {"
+	));
+
+	let cli_results = rule.run(&ctx(find_target(&targets, "@acme/cli")), &config());
+	let cli_result = cli_results.first().expect("expected a cli result");
+	assert_eq!(cli_result.message, "`exports` must not be empty");
+	assert!(cli_result.fix.is_none());
+
+	// Without a conventional entrypoint there is nothing mechanical to insert.
+	let scratch_results = rule.run(&ctx(find_target(&targets, "@acme/scratch")), &config());
+	assert!(
+		scratch_results
+			.first()
+			.is_some_and(|result| result.fix.is_none())
+	);
+}
+
+#[test]
+fn required_package_fields_rule_checks_managed_packages() {
+	let targets = workspace_hygiene_targets();
+	let rule = RequiredPackageFieldsRule::new();
+
+	let results = rule.run(&ctx(find_target(&targets, "@acme/http")), &config());
+	assert_eq!(
+		results
+			.iter()
+			.map(|result| result.message.as_str())
+			.collect::<Vec<_>>(),
+		vec!["missing required deno.json field `license`"]
+	);
+	assert!(
+		rule.run(&ctx(find_target(&targets, "@acme/cli")), &config())
+			.is_empty()
+	);
+	assert!(
+		rule.run(&ctx(find_target(&targets, "@acme/scratch")), &config())
+			.is_empty()
+	);
+}
+
+#[test]
+fn sorted_dependencies_rule_reorders_entries_without_touching_layout() {
+	let targets = workspace_hygiene_targets();
+	let rule = SortedDependenciesRule::new();
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "@acme/core")), &config())
+			.is_empty()
+	);
+
+	let http = find_target(&targets, "@acme/http");
+	let results = rule.run(&ctx(http), &config());
+	let result = results.first().expect("expected an http result");
+	assert_eq!((result.location.line, result.location.column), (5, 5));
+	assert!(apply_fix(&http.contents, result).contains(
+		"  \"imports\": {\n    \"@acme/core\": \"jsr:@acme/core@^1.4.0\",\n    \"@acme/shared\": \"../shared/mod.ts\",\n    \"@std/http\": \"jsr:@std/http@^1.0.0\"\n  }"
+	));
+
+	// Comments could end up attached to the wrong entry, so no fix is offered.
+	let cli_results = rule.run(&ctx(find_target(&targets, "@acme/cli")), &config());
+	let cli_result = cli_results.first().expect("expected a cli result");
+	assert_eq!(
+		cli_result.message,
+		"entries in `imports` are not sorted by specifier"
+	);
+	assert!(cli_result.fix.is_none());
+}
+
+#[test]
+fn no_path_or_git_dependencies_rule_allows_paths_inside_the_package() {
+	let targets = workspace_hygiene_targets();
+	let rule = NoPathOrGitDependenciesRule::new();
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "@acme/core")), &config())
+			.is_empty()
+	);
+
+	let http_results = rule.run(&ctx(find_target(&targets, "@acme/http")), &config());
+	let http_result = http_results.first().expect("expected an http result");
+	assert_eq!(http_result.location.line, 6);
+	assert!(http_result.message.contains(
+		"`@acme/shared` to a path outside the package (`../shared/mod.ts`)"
+	));
+
+	let cli_results = rule.run(&ctx(find_target(&targets, "@acme/cli")), &config());
+	assert!(
+		cli_results
+			.first()
+			.is_some_and(|result| result.message.contains("`legacy` to a git repository"))
+	);
+
+	let allow = LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("allow".to_string(), json!(["legacy"]))]),
+	};
+	assert!(
+		rule.run(&ctx(find_target(&targets, "@acme/cli")), &allow)
+			.is_empty()
+	);
+}
+
+#[test]
+fn mask_json_comments_keeps_offsets_stable() {
+	let contents = "{\n  // note\n  \"a\": \"//x\" /* c */\n}";
+	let masked = mask_json_comments(contents);
+	assert_eq!(masked.len(), contents.len());
+	assert_eq!(masked, "{\n         \n  \"a\": \"//x\"        \n}");
+}
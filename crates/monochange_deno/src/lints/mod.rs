@@ -0,0 +1,725 @@
+#![forbid(clippy::indexing_slicing)]
+
+//! Deno `deno.json` / `deno.jsonc` lint suite.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use monochange_core::MonochangeResult;
+use monochange_core::PackageDefinition;
+use monochange_core::PublishState;
+use monochange_core::WorkspaceConfiguration;
+use monochange_core::lint::LintCategory;
+use monochange_core::lint::LintContext;
+use monochange_core::lint::LintFix;
+use monochange_core::lint::LintLocation;
+use monochange_core::lint::LintMaturity;
+use monochange_core::lint::LintOptionDefinition;
+use monochange_core::lint::LintOptionKind;
+use monochange_core::lint::LintPreset;
+use monochange_core::lint::LintResult;
+use monochange_core::lint::LintRule;
+use monochange_core::lint::LintRuleConfig;
+use monochange_core::lint::LintRuleRunner;
+use monochange_core::lint::LintSeverity;
+use monochange_core::lint::LintSuite;
+use monochange_core::lint::LintTarget;
+use monochange_core::lint::LintTargetMetadata;
+use monochange_core::relative_to_root;
+use serde_json::Value;
+
+use crate::discover_deno_packages;
+
+/// Conventional JSR entrypoints, in the order `deno/jsr-exports` offers them
+/// as an autofix.
+const ENTRYPOINT_CANDIDATES: [&str; 4] = ["mod.ts", "mod.tsx", "mod.js", "mod.jsx"];
+
+/// Return the shared Deno lint suite.
+#[must_use]
+pub fn lint_suite() -> DenoLintSuite {
+	DenoLintSuite
+}
+
+/// Deno lint suite implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DenoLintSuite;
+
+#[derive(Debug, Clone)]
+struct DenoLintFile {
+	manifest: Value,
+	/// Conventional entrypoint found next to the manifest, if any.
+	entrypoint: Option<&'static str>,
+}
+
+impl LintSuite for DenoLintSuite {
+	fn suite_id(&self) -> &'static str {
+		"deno"
+	}
+
+	fn rules(&self) -> Vec<Box<dyn LintRuleRunner>> {
+		vec![
+			Box::new(JsrExportsRule::new()),
+			Box::new(NoPathOrGitDependenciesRule::new()),
+			Box::new(RequiredPackageFieldsRule::new()),
+			Box::new(SortedDependenciesRule::new()),
+		]
+	}
+
+	fn presets(&self) -> Vec<LintPreset> {
+		vec![
+			LintPreset::new(
+				"deno/recommended",
+				"Deno recommended",
+				"Balanced deno.json linting for JSR metadata and publishability",
+				LintMaturity::Stable,
+			)
+			.with_rules(BTreeMap::from([
+				(
+					"deno/jsr-exports".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"deno/no-path-or-git-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"deno/required-package-fields".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"deno/sorted-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Warning),
+				),
+			])),
+			LintPreset::new(
+				"deno/strict",
+				"Deno strict",
+				"Opinionated deno.json linting with style rules promoted to errors",
+				LintMaturity::Strict,
+			)
+			.with_rules(BTreeMap::from([
+				(
+					"deno/jsr-exports".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"deno/no-path-or-git-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"deno/required-package-fields".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"deno/sorted-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+			])),
+		]
+	}
+
+	fn collect_targets(
+		&self,
+		workspace_root: &Path,
+		configuration: &WorkspaceConfiguration,
+	) -> MonochangeResult<Vec<LintTarget>> {
+		let discovery = discover_deno_packages(workspace_root)?;
+
+		discovery
+			.packages
+			.into_iter()
+			.filter(|package| {
+				is_lintable_workspace_manifest(workspace_root, &package.manifest_path)
+			})
+			.map(|package| {
+				let contents = fs::read_to_string(&package.manifest_path).map_err(|error| {
+					monochange_core::MonochangeError::IoSource {
+						path: package.manifest_path.clone(),
+						source: error,
+					}
+				})?;
+				let manifest = serde_json::from_str::<Value>(
+					&monochange_core::strip_json_comments(&contents),
+				)
+				.map_err(|error| {
+					monochange_core::MonochangeError::Parse {
+						path: package.manifest_path.clone(),
+						source: Box::new(error),
+					}
+				})?;
+				let manifest_dir = package.manifest_path.parent().unwrap_or(workspace_root);
+				let entrypoint = ENTRYPOINT_CANDIDATES
+					.into_iter()
+					.find(|candidate| manifest_dir.join(candidate).is_file());
+				let configured_package =
+					configured_package(configuration, workspace_root, manifest_dir);
+				let package_id = configured_package.map(|package| package.id.clone());
+				let group_id = configured_package.and_then(|package| {
+					configuration
+						.group_for_package(&package.id)
+						.map(|group| group.id.clone())
+				});
+				let relative_path = relative_to_root(workspace_root, &package.manifest_path)
+					.unwrap_or_else(|| package.manifest_path.clone());
+				let private = matches!(package.publish_state, PublishState::Private);
+				let publish_enabled =
+					configured_package.is_none_or(|package| package.publish.enabled);
+
+				Ok(LintTarget::new(
+					workspace_root.to_path_buf(),
+					package.manifest_path.clone(),
+					contents,
+					LintTargetMetadata {
+						ecosystem: "deno".to_string(),
+						relative_path,
+						package_name: Some(package.name),
+						package_id,
+						group_id,
+						managed: configured_package.is_some(),
+						private: Some(private),
+						publishable: Some(!private && publish_enabled),
+					},
+					Box::new(DenoLintFile {
+						manifest,
+						entrypoint,
+					}),
+				))
+			})
+			.collect()
+	}
+}
+
+fn is_lintable_workspace_manifest(workspace_root: &Path, manifest_path: &Path) -> bool {
+	!(manifest_path.starts_with(workspace_root.join("fixtures"))
+		|| manifest_path.starts_with(workspace_root.join("target"))
+		|| manifest_path.starts_with(workspace_root.join(".git")))
+}
+
+fn configured_package<'a>(
+	configuration: &'a WorkspaceConfiguration,
+	workspace_root: &Path,
+	manifest_dir: &Path,
+) -> Option<&'a PackageDefinition> {
+	let relative_dir = relative_to_root(workspace_root, manifest_dir)?;
+	configuration
+		.packages
+		.iter()
+		.find(|package| package.path == relative_dir)
+}
+
+fn deno_file<'a>(ctx: &'a LintContext<'a>) -> Option<&'a DenoLintFile> {
+	ctx.parsed_as::<DenoLintFile>()
+}
+
+/// Manifest sections that map specifiers to dependency targets.
+fn dependency_sections() -> [&'static str; 2] {
+	["imports", "dependencies"]
+}
+
+fn location(ctx: &LintContext<'_>, offset: usize) -> LintLocation {
+	let prefix = ctx.contents.get(..offset).unwrap_or_default();
+	let line = prefix.bytes().filter(|byte| *byte == b'\n').count() + 1;
+	let column = prefix
+		.rsplit('\n')
+		.next()
+		.map_or(1, |segment| segment.chars().count() + 1);
+	LintLocation::new(ctx.manifest_path, line, column)
+}
+
+/// Copy of `contents` with `//` and `/* */` comments blanked out, so byte
+/// offsets in the copy still point at the same places in the original.
+fn mask_json_comments(contents: &str) -> String {
+	let bytes = contents.as_bytes();
+	let mut masked = Vec::with_capacity(bytes.len());
+	let mut cursor = 0usize;
+	while let Some(&byte) = bytes.get(cursor) {
+		if byte == b'"' {
+			let end = skip_json_string(bytes, cursor).unwrap_or(bytes.len());
+			masked.extend_from_slice(bytes.get(cursor..end).unwrap_or_default());
+			cursor = end;
+			continue;
+		}
+		let comment_end = match (byte, bytes.get(cursor + 1)) {
+			(b'/', Some(b'/')) => {
+				bytes
+					.get(cursor..)
+					.and_then(|rest| rest.iter().position(|byte| *byte == b'\n'))
+					.map_or(bytes.len(), |offset| cursor + offset)
+			}
+			(b'/', Some(b'*')) => {
+				bytes
+					.get(cursor + 2..)
+					.and_then(|rest| rest.windows(2).position(|pair| pair == b"*/"))
+					.map_or(bytes.len(), |offset| cursor + 2 + offset + 2)
+			}
+			_ => {
+				masked.push(byte);
+				cursor += 1;
+				continue;
+			}
+		};
+		for &comment_byte in bytes.get(cursor..comment_end).unwrap_or_default() {
+			masked.push(if comment_byte == b'\n' { b'\n' } else { b' ' });
+		}
+		cursor = comment_end;
+	}
+	String::from_utf8(masked).unwrap_or_else(|_| contents.to_string())
+}
+
+/// One `"key": value` member of a JSON object, as byte offsets into the
+/// manifest text.
+#[derive(Debug, Clone)]
+struct JsonMember {
+	key: String,
+	/// Offset of the opening quote of the key.
+	start: usize,
+	value_start: usize,
+	value_end: usize,
+}
+
+fn skip_json_whitespace(bytes: &[u8], mut cursor: usize) -> usize {
+	while bytes.get(cursor).is_some_and(u8::is_ascii_whitespace) {
+		cursor += 1;
+	}
+	cursor
+}
+
+/// Return the offset just past the string that opens at `start`.
+fn skip_json_string(bytes: &[u8], start: usize) -> Option<usize> {
+	let mut cursor = start + 1;
+	loop {
+		match bytes.get(cursor)? {
+			b'\\' => cursor += 2,
+			b'"' => return Some(cursor + 1),
+			_ => cursor += 1,
+		}
+	}
+}
+
+/// Return the offset just past the value that starts at `start`.
+fn skip_json_value(bytes: &[u8], start: usize) -> Option<usize> {
+	match bytes.get(start)? {
+		b'"' => skip_json_string(bytes, start),
+		b'{' | b'[' => {
+			let mut depth = 0usize;
+			let mut cursor = start;
+			loop {
+				match bytes.get(cursor)? {
+					b'"' => {
+						cursor = skip_json_string(bytes, cursor)?;
+						continue;
+					}
+					b'{' | b'[' => depth += 1,
+					b'}' | b']' => {
+						depth = depth.saturating_sub(1);
+						if depth == 0 {
+							return Some(cursor + 1);
+						}
+					}
+					_ => {}
+				}
+				cursor += 1;
+			}
+		}
+		_ => {
+			let mut cursor = start;
+			while bytes
+				.get(cursor)
+				.is_some_and(|byte| !matches!(byte, b',' | b'}' | b']') && !byte.is_ascii_whitespace())
+			{
+				cursor += 1;
+			}
+			Some(cursor)
+		}
+	}
+}
+
+/// Members of the object that opens at `object_start` in comment-masked text.
+fn json_object_members(masked: &str, object_start: usize) -> Option<Vec<JsonMember>> {
+	let bytes = masked.as_bytes();
+	if bytes.get(object_start) != Some(&b'{') {
+		return None;
+	}
+	let mut members = Vec::new();
+	let mut cursor = skip_json_whitespace(bytes, object_start + 1);
+	loop {
+		match bytes.get(cursor)? {
+			b'}' => return Some(members),
+			b',' => cursor = skip_json_whitespace(bytes, cursor + 1),
+			b'"' => {
+				let key_end = skip_json_string(bytes, cursor)?;
+				let key = serde_json::from_str::<String>(masked.get(cursor..key_end)?).ok()?;
+				let colon = skip_json_whitespace(bytes, key_end);
+				if bytes.get(colon) != Some(&b':') {
+					return None;
+				}
+				let value_start = skip_json_whitespace(bytes, colon + 1);
+				let value_end = skip_json_value(bytes, value_start)?;
+				members.push(JsonMember {
+					key,
+					start: cursor,
+					value_start,
+					value_end,
+				});
+				cursor = skip_json_whitespace(bytes, value_end);
+			}
+			_ => return None,
+		}
+	}
+}
+
+fn root_members(masked: &str) -> Option<Vec<JsonMember>> {
+	json_object_members(masked, skip_json_whitespace(masked.as_bytes(), 0))
+}
+
+fn section_members(masked: &str, section: &str) -> Option<Vec<JsonMember>> {
+	let member = root_members(masked)?
+		.into_iter()
+		.find(|member| member.key == section)?;
+	json_object_members(masked, member.value_start)
+}
+
+/// Reorder the members of one section by key while every position keeps its
+/// original separator, so the file layout survives. Returns `None` when the
+/// section carries comments that a reorder could detach.
+fn sorted_section_fix(
+	contents: &str,
+	masked: &str,
+	members: &[JsonMember],
+) -> Option<((usize, usize), String)> {
+	let first = members.first()?;
+	let last = members.last()?;
+	let span = (first.start, last.value_end);
+	if contents.get(span.0..span.1)? != masked.get(span.0..span.1)? {
+		return None;
+	}
+
+	let mut sorted = members.iter().collect::<Vec<_>>();
+	sorted.sort_by(|left, right| left.key.cmp(&right.key));
+	let mut rendered = String::new();
+	for (index, member) in sorted.into_iter().enumerate() {
+		if index > 0 {
+			let previous = members.get(index - 1)?;
+			let current = members.get(index)?;
+			rendered.push_str(contents.get(previous.value_end..current.start)?);
+		}
+		rendered.push_str(contents.get(member.start..member.value_end)?);
+	}
+	Some((span, rendered))
+}
+
+/// Insert `"exports": "./<entrypoint>"` after the `version` (or `name`) member,
+/// matching that member's indentation.
+fn exports_insertion(contents: &str, masked: &str, entrypoint: &str) -> Option<(usize, String)> {
+	let members = root_members(masked)?;
+	let anchor = members
+		.iter()
+		.find(|member| member.key == "version")
+		.or_else(|| members.iter().find(|member| member.key == "name"))?;
+	let line_start = contents
+		.get(..anchor.start)?
+		.rfind('\n')
+		.map_or(0, |offset| offset + 1);
+	let indent = contents.get(line_start..anchor.start)?;
+	let separator = if indent.chars().all(char::is_whitespace) && line_start > 0 {
+		format!(",\n{indent}")
+	} else {
+		", ".to_string()
+	};
+	Some((
+		anchor.value_end,
+		format!("{separator}\"exports\": \"./{entrypoint}\""),
+	))
+}
+
+/// Describe why a dependency target cannot be published to JSR, if it can't.
+/// Paths inside the package (`./...`) stay valid because `deno publish`
+/// bundles them.
+fn unpublishable_target_kind(target: &str) -> Option<&'static str> {
+	if target.starts_with("../") || target.starts_with('/') || target.starts_with("file:") {
+		Some("a path outside the package")
+	} else if target.starts_with("git+") {
+		Some("a git repository")
+	} else {
+		None
+	}
+}
+
+#[derive(Debug)]
+struct JsrExportsRule {
+	rule: LintRule,
+}
+
+impl JsrExportsRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"deno/jsr-exports",
+				"JSR exports",
+				"Requires published Deno packages to declare the `exports` entrypoints JSR needs",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				true,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"fix",
+				"apply an autofix that points `exports` at a conventional `mod.ts` entrypoint when one exists",
+				LintOptionKind::Boolean,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for JsrExportsRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		if ctx.metadata.publishable != Some(true) {
+			return Vec::new();
+		}
+		let Some(file) = deno_file(ctx) else {
+			return Vec::new();
+		};
+		let message = match file.manifest.get("exports") {
+			None => "published Deno packages must declare `exports` for JSR",
+			Some(Value::String(entrypoint)) if !entrypoint.is_empty() => return Vec::new(),
+			Some(Value::Object(entrypoints)) if !entrypoints.is_empty() => return Vec::new(),
+			Some(Value::String(_) | Value::Object(_)) => "`exports` must not be empty",
+			Some(_) => "`exports` must be a path string or an object of entrypoints",
+		};
+
+		let mut result = LintResult::new(
+			self.rule.id.clone(),
+			location(ctx, 0),
+			message,
+			config.severity(),
+		);
+		if file.manifest.get("exports").is_none()
+			&& config.bool_option("fix", true)
+			&& let Some(entrypoint) = file.entrypoint
+			&& let Some((offset, insertion)) =
+				exports_insertion(ctx.contents, &mask_json_comments(ctx.contents), entrypoint)
+		{
+			result = result.with_fix(LintFix::single(
+				format!("export `./{entrypoint}`"),
+				(offset, offset),
+				insertion,
+			));
+		}
+		vec![result]
+	}
+}
+
+#[derive(Debug)]
+struct NoPathOrGitDependenciesRule {
+	rule: LintRule,
+}
+
+impl NoPathOrGitDependenciesRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"deno/no-path-or-git-dependencies",
+				"No path or git dependencies",
+				"Prevents published Deno packages from importing paths outside the package or git repositories",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				false,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"allow",
+				"list of import specifiers that may point at local paths or git repositories",
+				LintOptionKind::StringList,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for NoPathOrGitDependenciesRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		if ctx.metadata.publishable != Some(true) {
+			return Vec::new();
+		}
+		let Some(file) = deno_file(ctx) else {
+			return Vec::new();
+		};
+		let allowed = config
+			.string_list_option("allow")
+			.unwrap_or_default()
+			.into_iter()
+			.collect::<BTreeSet<_>>();
+		let masked = mask_json_comments(ctx.contents);
+		let mut results = Vec::new();
+
+		for section in dependency_sections() {
+			let Some(object) = file.manifest.get(section).and_then(Value::as_object) else {
+				continue;
+			};
+			let offsets = section_members(&masked, section)
+				.unwrap_or_default()
+				.into_iter()
+				.map(|member| (member.key, member.start))
+				.collect::<BTreeMap<_, _>>();
+			for (name, target) in object {
+				let Some(target) = target.as_str() else {
+					continue;
+				};
+				let Some(kind) = unpublishable_target_kind(target) else {
+					continue;
+				};
+				if allowed.contains(name) {
+					continue;
+				}
+				results.push(LintResult::new(
+					self.rule.id.clone(),
+					location(ctx, offsets.get(name).copied().unwrap_or_default()),
+					format!(
+						"published Deno packages must not map `{name}` to {kind} (`{target}`) in `{section}`"
+					),
+					config.severity(),
+				));
+			}
+		}
+
+		results
+	}
+}
+
+#[derive(Debug)]
+struct RequiredPackageFieldsRule {
+	rule: LintRule,
+}
+
+impl RequiredPackageFieldsRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"deno/required-package-fields",
+				"Required package fields",
+				"Requires selected deno.json fields for managed publishable Deno packages",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				false,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"fields",
+				"list of deno.json fields that must be present",
+				LintOptionKind::StringList,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for RequiredPackageFieldsRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		if !ctx.metadata.managed || ctx.metadata.publishable != Some(true) {
+			return Vec::new();
+		}
+		let Some(file) = deno_file(ctx) else {
+			return Vec::new();
+		};
+		config
+			.string_list_option("fields")
+			.unwrap_or_else(|| vec!["version".to_string(), "license".to_string()])
+			.into_iter()
+			.filter(|field| file.manifest.get(field).is_none())
+			.map(|field| {
+				LintResult::new(
+					self.rule.id.clone(),
+					location(ctx, 0),
+					format!("missing required deno.json field `{field}`"),
+					config.severity(),
+				)
+			})
+			.collect()
+	}
+}
+
+#[derive(Debug)]
+struct SortedDependenciesRule {
+	rule: LintRule,
+}
+
+impl SortedDependenciesRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"deno/sorted-dependencies",
+				"Sorted dependencies",
+				"Requires deno.json `imports` and `dependencies` to be sorted by specifier",
+				LintCategory::Style,
+				LintMaturity::Stable,
+				true,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"fix",
+				"apply an autofix that sorts each section in place when it carries no comments",
+				LintOptionKind::Boolean,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for SortedDependenciesRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		let masked = mask_json_comments(ctx.contents);
+		let mut results = Vec::new();
+
+		for section in dependency_sections() {
+			let Some(members) = section_members(&masked, section) else {
+				continue;
+			};
+			let sorted = members
+				.windows(2)
+				.all(|pair| {
+					match pair {
+						[left, right] => left.key <= right.key,
+						_ => true,
+					}
+				});
+			if sorted {
+				continue;
+			}
+
+			let mut result = LintResult::new(
+				self.rule.id.clone(),
+				location(ctx, members.first().map_or(0, |member| member.start)),
+				format!("entries in `{section}` are not sorted by specifier"),
+				config.severity(),
+			);
+			if config.bool_option("fix", true)
+				&& let Some((span, replacement)) =
+					sorted_section_fix(ctx.contents, &masked, &members)
+			{
+				result = result.with_fix(LintFix::single(
+					format!("sort `{section}` by specifier"),
+					span,
+					replacement,
+				));
+			}
+			results.push(result);
+		}
+
+		results
+	}
+}
+
+#[cfg(test)]
+#[path = "__tests__/mod_tests.rs"]
+mod tests;
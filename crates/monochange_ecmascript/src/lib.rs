@@ -574,7 +574,8 @@ fn parse_declaration_export(
 		return Some(("namespace", name));
 	}
 
-	Some(("default_export", "default".to_string())).filter(|_| line.starts_with("export default "))
+	line.starts_with("export default ")
+		.then(|| ("default_export", "default".to_string()))
 }
 
 fn take_identifier(text: &str) -> Option<String> {
@@ -1033,6 +1033,7 @@ fn sample_manifest() -> ReleaseManifest {
 			rendered_title: "test title".to_string(),
 			rendered_changelog_title: "test changelog title".to_string(),
 			members: vec!["core".to_string(), "app".to_string()],
+			prerelease: None,
 		}],
 		package_publications: vec![],
 		released_packages: vec!["workflow-core".to_string(), "workflow-app".to_string()],
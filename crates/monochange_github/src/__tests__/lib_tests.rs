@@ -520,6 +520,7 @@ fn build_release_requests_fall_back_to_minimal_release_bodies() {
 			rendered_title: "test title".to_string(),
 			rendered_changelog_title: "test changelog title".to_string(),
 			members: vec!["cargo:crates/core/Cargo.toml".to_string()],
+			prerelease: None,
 		}],
 		package_publications: vec![],
 		released_packages: vec!["workflow-core".to_string()],
@@ -2859,6 +2860,7 @@ fn sample_manifest() -> ReleaseManifest {
 				"cargo:crates/core/Cargo.toml".to_string(),
 				"cargo:crates/app/Cargo.toml".to_string(),
 			],
+			prerelease: None,
 		}],
 		package_publications: vec![],
 		released_packages: vec!["workflow-core".to_string(), "workflow-app".to_string()],
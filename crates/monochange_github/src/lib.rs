@@ -56,6 +56,7 @@
 //!         members: vec!["core".to_string(), "app".to_string()],
 //!         rendered_title: "1.2.0 (2026-04-06)".to_string(),
 //!         rendered_changelog_title: "[1.2.0](https://example.com) (2026-04-06)".to_string(),
+//!         prerelease: None,
 //!     }],
 //!     released_packages: vec!["workflow-core".to_string(), "workflow-app".to_string()],
 //!     package_publications: Vec::new(),
@@ -637,7 +638,7 @@ pub fn build_release_requests(
 				},
 				body: release_body(source, manifest, target),
 				draft: source.releases.draft,
-				prerelease: source.releases.prerelease,
+				prerelease: source.releases.prerelease || target.prerelease.is_some(),
 				generate_release_notes: source.releases.generate_notes,
 			}
 		})
@@ -351,7 +351,7 @@ pub fn build_release_requests(
 				},
 				body: release_body(source, manifest, target),
 				draft: source.releases.draft,
-				prerelease: source.releases.prerelease,
+				prerelease: source.releases.prerelease || target.prerelease.is_some(),
 				generate_release_notes: source.releases.generate_notes,
 			}
 		})
@@ -0,0 +1,322 @@
+use monochange_core::BumpSeverity;
+use monochange_core::FileChangeKind;
+use monochange_core::PublishState;
+
+use super::*;
+
+fn source_file(path: &str, contents: &str) -> PackageSnapshotFile {
+	PackageSnapshotFile {
+		path: PathBuf::from(path),
+		contents: contents.to_string(),
+	}
+}
+
+fn diff_package_sources(before: &str, after: &str) -> Vec<SemanticChange> {
+	let snapshot = |label: &str, contents: &str| {
+		PackageSnapshot {
+			label: label.to_string(),
+			files: vec![source_file("greeter.go", contents)],
+		}
+	};
+	let before_snapshot = snapshot("before", before);
+	let after_snapshot = snapshot("after", after);
+
+	diff_public_symbols(
+		&snapshot_public_symbols(Some(&before_snapshot), &[]),
+		&snapshot_public_symbols(Some(&after_snapshot), &[]),
+	)
+}
+
+#[test]
+fn analyzer_applies_to_go_modules() {
+	let package = PackageRecord::new(
+		Ecosystem::Go,
+		"greeter",
+		PathBuf::from("/repo/greeter/go.mod"),
+		PathBuf::from("/repo"),
+		None,
+		PublishState::Public,
+	);
+
+	assert!(semantic_analyzer().applies_to(&package));
+}
+
+#[test]
+fn collect_public_symbols_finds_exported_functions_types_methods_and_members() {
+	let file = source_file(
+		"client/client.go",
+		concat!(
+			"// Package client talks to the greeting service.\n",
+			"package client\n",
+			"\n",
+			"import (\n",
+			"\t\"context\"\n",
+			"\t\"io\"\n",
+			")\n",
+			"\n",
+			"/* func Commented() {} */\n",
+			"const banner = `func Hidden() {`\n",
+			"\n",
+			"type (\n",
+			"\t// Client sends greetings.\n",
+			"\tClient struct {\n",
+			"\t\t*Base\n",
+			"\t\tName, Locale string `json:\"name\"`\n",
+			"\t\tretries      int\n",
+			"\t}\n",
+			"\n",
+			"\tSender interface {\n",
+			"\t\tio.Closer\n",
+			"\t\tSend(ctx context.Context, message string) (n int, err error)\n",
+			"\t\tflush()\n",
+			"\t}\n",
+			")\n",
+			"\n",
+			"type List[T any] []T\n",
+			"\n",
+			"type Alias = Client\n",
+			"\n",
+			"func New(name string, options ...Option) *Client {\n",
+			"\tif name == \"}\" {\n",
+			"\t\treturn nil\n",
+			"\t}\n",
+			"\treturn &Client{Name: name}\n",
+			"}\n",
+			"\n",
+			"func (c *Client) Greet(\n",
+			"\tctx context.Context,\n",
+			"\tfirst, last string,\n",
+			") error {\n",
+			"\treturn nil\n",
+			"}\n",
+			"\n",
+			"func (l List[T]) Len() int { return len(l) }\n",
+			"\n",
+			"func (c *Client) reset() {}\n",
+			"\n",
+			"func helper() {}\n",
+		),
+	);
+
+	let symbols = collect_public_symbols(&file)
+		.into_iter()
+		.map(|symbol| (symbol.item_kind, symbol.item_path, symbol.signature))
+		.collect::<Vec<_>>();
+
+	let expected = [
+		("field", "client.Client.Base", "*Base"),
+		("field", "client.Client.Name", "Name string"),
+		("field", "client.Client.Locale", "Locale string"),
+		("type", "client.Client", "type Client struct"),
+		(
+			"interface_method",
+			"client.Sender.Send",
+			"Send(context.Context, string) (int, error)",
+		),
+		(
+			"type",
+			"client.Sender",
+			"type Sender interface { io.Closer }",
+		),
+		("type", "client.List", "type List[T any] []T"),
+		("type", "client.Alias", "type Alias = Client"),
+		(
+			"function",
+			"client.New",
+			"func New(string, ...Option) *Client",
+		),
+		(
+			"method",
+			"client.Client.Greet",
+			"func (*Client) Greet(context.Context, string, string) error",
+		),
+		("method", "client.List.Len", "func (List[T]) Len() int"),
+	]
+	.map(|(kind, path, signature)| (kind.to_string(), path.to_string(), signature.to_string()));
+	assert_eq!(symbols, expected);
+}
+
+#[test]
+fn public_source_files_skip_tests_internal_packages_and_main() {
+	for path in ["greeter.go", "client/client.go", "api/v1/types.go"] {
+		assert!(is_public_go_source_file(Path::new(path)), "{path}");
+	}
+	for path in [
+		"greeter_test.go",
+		"internal/cache/cache.go",
+		"testdata/fixture.go",
+		"vendor/github.com/pkg/errors/errors.go",
+		"_examples/demo.go",
+		"README.md",
+	] {
+		assert!(!is_public_go_source_file(Path::new(path)), "{path}");
+	}
+
+	let main = source_file("cmd/greet/main.go", "package main\n\nfunc Run() {}\n");
+	assert!(collect_public_symbols(&main).is_empty());
+}
+
+#[test]
+fn snapshot_symbols_skip_nested_modules() {
+	let snapshot = PackageSnapshot {
+		label: "after".to_string(),
+		files: vec![
+			source_file("go.mod", "module example.com/greeter\n"),
+			source_file("greeter.go", "package greeter\n\nfunc Greet() {}\n"),
+			source_file("tools/go.mod", "module example.com/greeter/tools\n"),
+			source_file("tools/tools.go", "package tools\n\nfunc Lint() {}\n"),
+		],
+	};
+
+	let symbols = snapshot_public_symbols(Some(&snapshot), &[]);
+
+	assert_eq!(
+		symbols.keys().cloned().collect::<Vec<_>>(),
+		vec![("function".to_string(), "greeter.Greet".to_string())]
+	);
+}
+
+#[test]
+fn diff_reports_interface_method_additions_as_breaking() {
+	let changes = diff_package_sources(
+		concat!(
+			"package greeter\n",
+			"\n",
+			"type Greeter interface {\n",
+			"\tGreet(name string) string\n",
+			"}\n",
+			"\n",
+			"type Options struct {\n",
+			"\tLoud bool\n",
+			"}\n",
+			"\n",
+			"func Greet(name string) string { return name }\n",
+			"func Farewell() {}\n",
+		),
+		concat!(
+			"package greeter\n",
+			"\n",
+			"type Greeter interface {\n",
+			"\tGreet(who string) string\n",
+			"\tLocale() string\n",
+			"}\n",
+			"\n",
+			"type Options struct {\n",
+			"\tLoud   bool\n",
+			"\tLocale string\n",
+			"}\n",
+			"\n",
+			"func Greet(name string, times int) string { return name }\n",
+		),
+	);
+
+	let summaries = changes
+		.iter()
+		.map(|change| (change.summary.as_str(), change.implied_bump()))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		summaries,
+		vec![
+			("field `greeter.Options.Locale` added", BumpSeverity::Minor),
+			("function `greeter.Greet` modified", BumpSeverity::Major),
+			(
+				"method `Locale` added to interface `greeter.Greeter`",
+				BumpSeverity::Major
+			),
+			("function `greeter.Farewell` removed", BumpSeverity::Major),
+		]
+	);
+}
+
+#[test]
+fn analyze_manifest_change_reports_module_go_directive_and_require_diffs() {
+	let change = AnalyzedFileChange {
+		path: PathBuf::from("greeter/go.mod"),
+		package_path: PathBuf::from("go.mod"),
+		kind: FileChangeKind::Modified,
+		before_contents: Some(
+			concat!(
+				"module example.com/greeter\n",
+				"\n",
+				"go 1.21\n",
+				"\n",
+				"require (\n",
+				"\tgithub.com/google/uuid v1.3.0\n",
+				"\tgolang.org/x/text v0.14.0 // indirect\n",
+				")\n",
+			)
+			.to_string(),
+		),
+		after_contents: Some(
+			concat!(
+				"module example.com/greeter/v2\n",
+				"\n",
+				"go 1.22\n",
+				"\n",
+				"toolchain go1.22.3\n",
+				"\n",
+				"require github.com/google/uuid v1.6.0\n",
+				"\n",
+				"require (\n",
+				"\tgolang.org/x/text v0.14.0\n",
+				"\tgithub.com/spf13/cobra v1.8.0 // indirect\n",
+				")\n",
+			)
+			.to_string(),
+		),
+	};
+
+	let changes = analyze_manifest_change(&change)
+		.into_iter()
+		.map(|change| {
+			(
+				change.category,
+				change.kind,
+				change.item_path,
+				change.after_signature,
+			)
+		})
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		changes,
+		vec![
+			(
+				SemanticChangeCategory::Export,
+				SemanticChangeKind::Modified,
+				"module".to_string(),
+				Some("example.com/greeter/v2".to_string())
+			),
+			(
+				SemanticChangeCategory::Dependency,
+				SemanticChangeKind::Modified,
+				"github.com/google/uuid".to_string(),
+				Some("v1.6.0".to_string())
+			),
+			(
+				SemanticChangeCategory::Dependency,
+				SemanticChangeKind::Added,
+				"github.com/spf13/cobra".to_string(),
+				Some("v1.8.0 // indirect".to_string())
+			),
+			(
+				SemanticChangeCategory::Dependency,
+				SemanticChangeKind::Modified,
+				"golang.org/x/text".to_string(),
+				Some("v0.14.0".to_string())
+			),
+			(
+				SemanticChangeCategory::Metadata,
+				SemanticChangeKind::Modified,
+				"go".to_string(),
+				Some("1.22".to_string())
+			),
+			(
+				SemanticChangeCategory::Metadata,
+				SemanticChangeKind::Added,
+				"toolchain".to_string(),
+				Some("go1.22.3".to_string())
+			),
+		]
+	);
+}
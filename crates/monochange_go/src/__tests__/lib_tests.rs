@@ -448,24 +448,24 @@ fn update_go_mod_text_preserves_comments() {
 #[test]
 fn update_go_mod_text_handles_single_line_require() {
 	let input = "module github.com/example/app\n\nrequire github.com/example/shared v1.0.0\n";
-	let deps = BTreeMap::from([("shared".to_string(), "v2.0.0".to_string())]);
+	let deps = BTreeMap::from([("shared".to_string(), "v1.1.0".to_string())]);
 	let result = update_go_mod_text(input, &deps);
-	assert!(result.contains("require github.com/example/shared v2.0.0"));
+	assert!(result.contains("require github.com/example/shared v1.1.0"));
 }
 
 #[test]
 fn update_go_mod_text_adds_v_prefix_when_missing() {
 	let input = "module github.com/example/app\n\nrequire github.com/example/shared v1.0.0\n";
-	let deps = BTreeMap::from([("shared".to_string(), "2.0.0".to_string())]);
+	let deps = BTreeMap::from([("shared".to_string(), "1.1.0".to_string())]);
 	let result = update_go_mod_text(input, &deps);
-	assert!(result.contains("require github.com/example/shared v2.0.0"));
+	assert!(result.contains("require github.com/example/shared v1.1.0"));
 }
 
 #[test]
 fn update_go_mod_text_preserves_module_and_go_directives() {
 	let input =
 		"module github.com/example/app\n\ngo 1.22\n\nrequire github.com/example/shared v1.0.0\n";
-	let deps = BTreeMap::from([("shared".to_string(), "v2.0.0".to_string())]);
+	let deps = BTreeMap::from([("shared".to_string(), "v1.1.0".to_string())]);
 	let result = update_go_mod_text(input, &deps);
 	assert!(result.contains("module github.com/example/app"));
 	assert!(result.contains("go 1.22"));
@@ -486,10 +486,228 @@ require github.com/example/shared v1.0.0
 
 replace github.com/example/shared => ../shared
 ";
-	let deps = BTreeMap::from([("shared".to_string(), "v2.0.0".to_string())]);
+	let deps = BTreeMap::from([("shared".to_string(), "v1.1.0".to_string())]);
 	let result = update_go_mod_text(input, &deps);
 	assert!(result.contains("replace github.com/example/shared => ../shared"));
-	assert!(result.contains("require github.com/example/shared v2.0.0"));
+	assert!(result.contains("require github.com/example/shared v1.1.0"));
+}
+
+#[test]
+fn update_go_mod_text_moves_major_version_bumps_to_suffixed_module_paths() {
+	let input = r"module github.com/example/shared
+
+go 1.22
+
+require (
+	github.com/example/core v1.4.0
+	github.com/example/sdk/v2 v2.3.0 // indirect
+)
+
+replace (
+	github.com/example/core => ../core
+	github.com/example/sdk/v2 v2.3.0 => ../sdk
+)
+
+exclude github.com/example/core v1.0.0
+";
+	let deps = BTreeMap::from([
+		("shared".to_string(), "2.0.0".to_string()),
+		("core".to_string(), "2.0.0".to_string()),
+		("sdk".to_string(), "3.0.0".to_string()),
+	]);
+
+	let result = update_go_mod_text(input, &deps);
+
+	assert_eq!(
+		result,
+		r"module github.com/example/shared/v2
+
+go 1.22
+
+require (
+	github.com/example/core/v2 v2.0.0
+	github.com/example/sdk/v3 v3.0.0 // indirect
+)
+
+replace (
+	github.com/example/core/v2 => ../core
+	github.com/example/sdk/v3 v2.3.0 => ../sdk
+)
+
+exclude github.com/example/core v1.0.0
+"
+	);
+}
+
+#[test]
+fn module_path_for_version_applies_semantic_import_versioning() {
+	for (module_path, version, expected) in [
+		("example.com/greeter", "1.4.0", "example.com/greeter"),
+		("example.com/greeter", "v2.0.0", "example.com/greeter/v2"),
+		(
+			"example.com/greeter",
+			"2.0.0-rc.1",
+			"example.com/greeter/v2",
+		),
+		("example.com/greeter/v2", "3.0.0", "example.com/greeter/v3"),
+		("example.com/greeter/v2", "2.1.0", "example.com/greeter/v2"),
+		("example.com/greeter/v2", "1.0.0", "example.com/greeter"),
+		("example.com/v1beta", "2.0.0", "example.com/v1beta/v2"),
+		("gopkg.in/yaml.v3", "4.0.0", "gopkg.in/yaml.v3"),
+		(
+			"example.com/greeter",
+			"not-a-version",
+			"example.com/greeter",
+		),
+	] {
+		assert_eq!(
+			crate::module_path_for_version(module_path, version),
+			expected,
+			"{module_path} at {version}"
+		);
+	}
+}
+
+#[test]
+fn rewrite_go_import_paths_only_rewrites_imports_of_moved_modules() {
+	let module_paths = BTreeMap::from([
+		(
+			"example.com/greeter".to_string(),
+			"example.com/greeter/v2".to_string(),
+		),
+		(
+			"example.com/greeter/tools".to_string(),
+			"example.com/greeter/tools".to_string(),
+		),
+	]);
+	let input = r#"package app
+
+import "example.com/greeter"
+
+import (
+	"fmt"
+
+	g "example.com/greeter/format"
+	_ "example.com/greeter/tools/lint" // nested module
+	"example.com/greeterx"
+)
+
+var path = "example.com/greeter/format"
+
+func Greet() { fmt.Println(greeter.Greet(), g.Format()) }
+"#;
+
+	let result = crate::rewrite_go_import_paths(input, &module_paths);
+
+	assert_eq!(
+		result,
+		r#"package app
+
+import "example.com/greeter/v2"
+
+import (
+	"fmt"
+
+	g "example.com/greeter/v2/format"
+	_ "example.com/greeter/tools/lint" // nested module
+	"example.com/greeterx"
+)
+
+var path = "example.com/greeter/format"
+
+func Greet() { fmt.Println(greeter.Greet(), g.Format()) }
+"#
+	);
+}
+
+#[test]
+fn semantic_import_versioning_updates_rewrite_major_releases_across_the_workspace() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	let write = |relative: &str, contents: &str| {
+		let path = root.join(relative);
+		fs::create_dir_all(path.parent().unwrap_or(root))
+			.unwrap_or_else(|error| panic!("create {relative}: {error}"));
+		fs::write(&path, contents).unwrap_or_else(|error| panic!("write {relative}: {error}"));
+	};
+	write("greeter/go.mod", "module example.com/greeter\n\ngo 1.22\n");
+	write(
+		"greeter/greeter.go",
+		"package greeter\n\nimport \"example.com/greeter/format\"\n\nfunc Greet() string { return format.Name() }\n",
+	);
+	write(
+		"greeter/format/format.go",
+		"package format\n\nfunc Name() string { return \"greeter\" }\n",
+	);
+	write(
+		"app/go.mod",
+		"module example.com/app\n\ngo 1.22\n\nrequire example.com/greeter v1.4.0\n\nreplace example.com/greeter => ../greeter\n",
+	);
+	write(
+		"app/main.go",
+		"package main\n\nimport \"example.com/greeter\"\n\nfunc main() { greeter.Greet() }\n",
+	);
+	let discovery = discover_go_modules(root).unwrap_or_else(|error| panic!("discover: {error}"));
+	let greeter = discovery
+		.packages
+		.iter()
+		.find(|package| package.name == "greeter")
+		.unwrap_or_else(|| panic!("greeter module"));
+	let app = discovery
+		.packages
+		.iter()
+		.find(|package| package.name == "app")
+		.unwrap_or_else(|| panic!("app module"));
+
+	let minor = crate::semantic_import_versioning_updates(
+		&discovery.packages,
+		&BTreeMap::from([(greeter.id.clone(), "1.5.0".to_string())]),
+	)
+	.unwrap_or_else(|error| panic!("minor updates: {error}"));
+	assert!(minor.is_empty());
+
+	let updates = crate::semantic_import_versioning_updates(
+		&discovery.packages,
+		&BTreeMap::from([
+			(greeter.id.clone(), "2.0.0".to_string()),
+			(app.id.clone(), "1.1.0".to_string()),
+		]),
+	)
+	.unwrap_or_else(|error| panic!("major updates: {error}"));
+	let updates = updates
+		.into_iter()
+		.map(|(path, contents)| {
+			(
+				path.strip_prefix(root)
+					.unwrap_or(&path)
+					.to_string_lossy()
+					.replace('\\', "/"),
+				contents,
+			)
+		})
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		updates,
+		vec![
+			(
+				"app/go.mod".to_string(),
+				"module example.com/app\n\ngo 1.22\n\nrequire example.com/greeter/v2 v2.0.0\n\nreplace example.com/greeter/v2 => ../greeter\n".to_string()
+			),
+			(
+				"app/main.go".to_string(),
+				"package main\n\nimport \"example.com/greeter/v2\"\n\nfunc main() { greeter.Greet() }\n".to_string()
+			),
+			(
+				"greeter/go.mod".to_string(),
+				"module example.com/greeter/v2\n\ngo 1.22\n".to_string()
+			),
+			(
+				"greeter/greeter.go".to_string(),
+				"package greeter\n\nimport \"example.com/greeter/v2/format\"\n\nfunc Greet() string { return format.Name() }\n".to_string()
+			),
+		]
+	);
 }
 
 // -- should_descend --
@@ -547,10 +765,10 @@ fn parse_require_directives_handles_malformed_entries() {
 #[test]
 fn update_go_mod_text_preserves_content_without_trailing_newline() {
 	let input = "module github.com/example/app\n\nrequire github.com/example/shared v1.0.0";
-	let deps = BTreeMap::from([("shared".to_string(), "v2.0.0".to_string())]);
+	let deps = BTreeMap::from([("shared".to_string(), "v1.1.0".to_string())]);
 	let result = update_go_mod_text(input, &deps);
 	assert!(!result.ends_with('\n'), "should not add trailing newline");
-	assert!(result.contains("github.com/example/shared v2.0.0"));
+	assert!(result.contains("github.com/example/shared v1.1.0"));
 }
 
 #[test]
@@ -594,10 +812,10 @@ fn derive_module_name_handles_all_version_segments() {
 #[test]
 fn update_go_mod_text_handles_retract_directive() {
 	let input = "module github.com/example/app\n\nretract v0.1.0\n\nrequire github.com/example/shared v1.0.0\n";
-	let deps = BTreeMap::from([("shared".to_string(), "v2.0.0".to_string())]);
+	let deps = BTreeMap::from([("shared".to_string(), "v1.1.0".to_string())]);
 	let result = update_go_mod_text(input, &deps);
 	assert!(result.contains("retract v0.1.0"));
-	assert!(result.contains("require github.com/example/shared v2.0.0"));
+	assert!(result.contains("require github.com/example/shared v1.1.0"));
 }
 
 #[test]
@@ -0,0 +1,1038 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_core::AnalyzedFileChange;
+use monochange_core::DetectionLevel;
+use monochange_core::Ecosystem;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageAnalysisContext;
+use monochange_core::PackageAnalysisResult;
+use monochange_core::PackageRecord;
+use monochange_core::PackageSnapshot;
+use monochange_core::PackageSnapshotFile;
+use monochange_core::SemanticAnalyzer;
+use monochange_core::SemanticChange;
+use monochange_core::SemanticChangeCategory;
+use monochange_core::SemanticChangeKind;
+
+use crate::GO_MOD_FILE;
+
+/// Go analyzer that extracts exported identifiers and `go.mod` diffs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GoSemanticAnalyzer;
+
+/// Return the shared Go semantic analyzer.
+#[must_use]
+pub const fn semantic_analyzer() -> GoSemanticAnalyzer {
+	GoSemanticAnalyzer
+}
+
+impl SemanticAnalyzer for GoSemanticAnalyzer {
+	fn analyzer_id(&self) -> &'static str {
+		"go/exported-api"
+	}
+
+	fn applies_to(&self, package: &PackageRecord) -> bool {
+		package.ecosystem == Ecosystem::Go
+	}
+
+	fn analyze_package(
+		&self,
+		context: &PackageAnalysisContext<'_>,
+	) -> MonochangeResult<PackageAnalysisResult> {
+		let mut semantic_changes = Vec::new();
+
+		if context.detection_level != DetectionLevel::Basic {
+			let before_symbols =
+				snapshot_public_symbols(context.before_snapshot, context.changed_files);
+			let after_symbols =
+				snapshot_public_symbols(context.after_snapshot, context.changed_files);
+			semantic_changes.extend(diff_public_symbols(&before_symbols, &after_symbols));
+		}
+
+		if let Some(manifest_change) = context
+			.changed_files
+			.iter()
+			.find(|change| change.package_path == Path::new(GO_MOD_FILE))
+		{
+			semantic_changes.extend(analyze_manifest_change(manifest_change));
+		}
+
+		semantic_changes.sort_by(|left, right| {
+			(
+				left.category,
+				left.kind,
+				left.item_kind.as_str(),
+				left.item_path.as_str(),
+			)
+				.cmp(&(
+					right.category,
+					right.kind,
+					right.item_kind.as_str(),
+					right.item_path.as_str(),
+				))
+		});
+
+		Ok(PackageAnalysisResult {
+			analyzer_id: self.analyzer_id().to_string(),
+			package_id: display_package_id(context.package),
+			ecosystem: context.package.ecosystem,
+			changed_files: context
+				.changed_files
+				.iter()
+				.map(|file| file.package_path.clone())
+				.collect(),
+			semantic_changes,
+			warnings: Vec::new(),
+		})
+	}
+}
+
+fn display_package_id(package: &PackageRecord) -> String {
+	package
+		.metadata
+		.get("config_id")
+		.cloned()
+		.unwrap_or_else(|| package.id.clone())
+}
+
+#[derive(Debug, Clone, Default, Eq, Ord, PartialEq, PartialOrd)]
+struct PublicSymbol {
+	item_kind: String,
+	item_path: String,
+	signature: String,
+	file_path: PathBuf,
+	/// The type a field or interface method belongs to.
+	owner: Option<String>,
+}
+
+fn snapshot_public_symbols(
+	snapshot: Option<&PackageSnapshot>,
+	changed_files: &[AnalyzedFileChange],
+) -> BTreeMap<(String, String), PublicSymbol> {
+	let mut symbols = BTreeMap::new();
+
+	if let Some(snapshot) = snapshot {
+		let nested_modules = snapshot
+			.files
+			.iter()
+			.filter(|file| file.path.file_name() == Some(GO_MOD_FILE.as_ref()))
+			.filter_map(|file| file.path.parent())
+			.filter(|directory| !directory.as_os_str().is_empty())
+			.collect::<BTreeSet<_>>();
+		for file in &snapshot.files {
+			if !is_public_go_source_file(&file.path)
+				|| nested_modules
+					.iter()
+					.any(|directory| file.path.starts_with(directory))
+			{
+				continue;
+			}
+
+			for symbol in collect_public_symbols(file) {
+				symbols.insert((symbol.item_kind.clone(), symbol.item_path.clone()), symbol);
+			}
+		}
+
+		return symbols;
+	}
+
+	for change in changed_files {
+		let Some(contents) = change
+			.after_contents
+			.as_deref()
+			.or(change.before_contents.as_deref())
+		else {
+			continue;
+		};
+		if !is_public_go_source_file(&change.package_path) {
+			continue;
+		}
+
+		let file = PackageSnapshotFile {
+			path: change.package_path.clone(),
+			contents: contents.to_string(),
+		};
+		for symbol in collect_public_symbols(&file) {
+			symbols.insert((symbol.item_kind.clone(), symbol.item_path.clone()), symbol);
+		}
+	}
+
+	symbols
+}
+
+/// Go files that other modules can import: no tests, and nothing under
+/// `internal`, `testdata`, `vendor`, or directories the go tool ignores.
+fn is_public_go_source_file(path: &Path) -> bool {
+	if path.extension().and_then(|ext| ext.to_str()) != Some("go")
+		|| path
+			.file_stem()
+			.and_then(|stem| stem.to_str())
+			.is_none_or(|stem| stem.ends_with("_test"))
+	{
+		return false;
+	}
+
+	path.parent()
+		.into_iter()
+		.flat_map(Path::components)
+		.filter_map(|component| component.as_os_str().to_str())
+		.all(|directory| {
+			!directory.starts_with('_')
+				&& !directory.starts_with('.')
+				&& !matches!(directory, "internal" | "testdata" | "vendor")
+		})
+}
+
+fn collect_public_symbols(file: &PackageSnapshotFile) -> Vec<PublicSymbol> {
+	let source = sanitize_source(&file.contents);
+	let declarations = split_statements(&source);
+	let Some(package_name) = declarations
+		.iter()
+		.find_map(|declaration| declaration.strip_prefix("package "))
+		.map(normalize_whitespace)
+	else {
+		return Vec::new();
+	};
+	if package_name == "main" || package_name.ends_with("_test") {
+		return Vec::new();
+	}
+
+	let package_path = file
+		.path
+		.parent()
+		.map(|directory| {
+			directory
+				.components()
+				.filter_map(|component| component.as_os_str().to_str())
+				.collect::<Vec<_>>()
+				.join("/")
+		})
+		.filter(|directory| !directory.is_empty())
+		.unwrap_or_else(|| package_name.clone());
+	let mut symbols = Vec::new();
+	let mut push = |item_kind: &str, item_path: String, signature: String, owner: Option<&str>| {
+		symbols.push(PublicSymbol {
+			item_kind: item_kind.to_string(),
+			item_path,
+			signature,
+			file_path: file.path.clone(),
+			owner: owner.map(ToString::to_string),
+		});
+	};
+
+	for declaration in &declarations {
+		if let Some(function) = parse_function(&normalize_whitespace(declaration)) {
+			let visible = is_exported(&function.name)
+				&& function.receiver_type.as_deref().is_none_or(is_exported);
+			if !visible {
+				continue;
+			}
+			let (item_kind, item_path) = match &function.receiver_type {
+				Some(receiver) => {
+					(
+						"method",
+						format!("{package_path}.{receiver}.{}", function.name),
+					)
+				}
+				None => ("function", format!("{package_path}.{}", function.name)),
+			};
+			push(item_kind, item_path, function.signature, None);
+			continue;
+		}
+
+		let Some(specs) = declaration.strip_prefix("type") else {
+			continue;
+		};
+		let specs = specs.trim();
+		let specs = match specs
+			.strip_prefix('(')
+			.and_then(|group| group.strip_suffix(')'))
+		{
+			Some(group) => split_statements(group),
+			None => vec![specs.to_string()],
+		};
+		for spec in specs {
+			let Some(type_spec) = parse_type_spec(&spec) else {
+				continue;
+			};
+			if !is_exported(&type_spec.name) {
+				continue;
+			}
+			let type_path = format!("{package_path}.{}", type_spec.name);
+			for member in &type_spec.members {
+				push(
+					member.item_kind,
+					format!("{type_path}.{}", member.name),
+					member.signature.clone(),
+					Some(&type_path),
+				);
+			}
+			push("type", type_path, type_spec.signature, None);
+		}
+	}
+
+	symbols
+}
+
+fn is_exported(name: &str) -> bool {
+	name.chars().next().is_some_and(char::is_uppercase)
+}
+
+fn is_identifier(text: &str) -> bool {
+	!text.is_empty()
+		&& text
+			.chars()
+			.all(|character| character.is_alphanumeric() || character == '_')
+}
+
+/// Blank out comments and the contents of string and rune literals so that
+/// brackets inside them cannot confuse the declaration scanner.
+fn sanitize_source(contents: &str) -> String {
+	let mut source = String::with_capacity(contents.len());
+	let mut characters = contents.chars().peekable();
+
+	while let Some(character) = characters.next() {
+		match character {
+			'/' if characters.peek() == Some(&'/') => {
+				while characters.next_if(|next| *next != '\n').is_some() {}
+			}
+			'/' if characters.peek() == Some(&'*') => {
+				characters.next();
+				let mut previous = ' ';
+				let mut spans_lines = false;
+				for next in characters.by_ref() {
+					if previous == '*' && next == '/' {
+						break;
+					}
+					spans_lines |= next == '\n';
+					previous = next;
+				}
+				source.push(if spans_lines { '\n' } else { ' ' });
+			}
+			'"' | '\'' | '`' => {
+				source.push(character);
+				let mut escaped = false;
+				for next in characters.by_ref() {
+					if escaped {
+						escaped = false;
+					} else if next == '\\' && character != '`' {
+						escaped = true;
+					} else if next == character || (next == '\n' && character != '`') {
+						break;
+					}
+				}
+				source.push(character);
+			}
+			_ => source.push(character),
+		}
+	}
+
+	source
+}
+
+/// Split sanitized source into the statements Go's semicolon insertion would
+/// produce at bracket depth zero. Line breaks inside brackets are kept so that
+/// struct and interface bodies can be split again.
+fn split_statements(text: &str) -> Vec<String> {
+	let mut statements = Vec::new();
+	let mut current = String::new();
+	let mut depth = 0_usize;
+
+	for character in text.chars() {
+		match character {
+			'(' | '[' | '{' => depth += 1,
+			')' | ']' | '}' => depth = depth.saturating_sub(1),
+			'\n' | ';' if depth == 0 => {
+				push_statement(&mut statements, &current);
+				current.clear();
+				continue;
+			}
+			_ => {}
+		}
+		current.push(character);
+	}
+	push_statement(&mut statements, &current);
+
+	statements
+}
+
+fn push_statement(statements: &mut Vec<String>, text: &str) {
+	let statement = text.trim();
+	if !statement.is_empty() {
+		statements.push(statement.to_string());
+	}
+}
+
+fn normalize_whitespace(text: &str) -> String {
+	text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn matching_close(text: &str, open: usize) -> Option<usize> {
+	let mut depth = 0_usize;
+	for (index, character) in text.char_indices().skip_while(|(index, _)| *index < open) {
+		match character {
+			'(' | '[' | '{' => depth += 1,
+			')' | ']' | '}' => {
+				depth = depth.saturating_sub(1);
+				if depth == 0 {
+					return Some(index);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	None
+}
+
+fn split_top_level(text: &str, separator: char) -> Vec<String> {
+	let mut parts = Vec::new();
+	let mut current = String::new();
+	let mut depth = 0_usize;
+
+	for character in text.chars() {
+		match character {
+			'(' | '[' | '{' => depth += 1,
+			')' | ']' | '}' => depth = depth.saturating_sub(1),
+			_ if character == separator && depth == 0 => {
+				parts.push(std::mem::take(&mut current));
+				continue;
+			}
+			_ => {}
+		}
+		current.push(character);
+	}
+	parts.push(current);
+
+	parts
+		.into_iter()
+		.map(|part| part.trim().to_string())
+		.filter(|part| !part.is_empty())
+		.collect()
+}
+
+/// The `{ ... }` block that ends `text`, returned as the text before it and
+/// the block contents.
+fn split_trailing_block(text: &str) -> Option<(&str, &str)> {
+	let body = text.strip_suffix('}')?;
+	let mut depth = 1_usize;
+	for (index, character) in body.char_indices().rev() {
+		match character {
+			'}' | ')' | ']' => depth += 1,
+			'{' | '(' | '[' => {
+				depth -= 1;
+				if depth == 0 {
+					return Some((body.get(..index)?.trim_end(), body.get(index + 1..)?));
+				}
+			}
+			_ => {}
+		}
+	}
+
+	None
+}
+
+/// A parsed `func` declaration.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct FunctionDeclaration {
+	name: String,
+	receiver_type: Option<String>,
+	signature: String,
+}
+
+fn parse_function(declaration: &str) -> Option<FunctionDeclaration> {
+	let rest = declaration.strip_prefix("func")?.trim_start();
+	let header = split_trailing_block(rest).map_or(rest, |(header, _)| header);
+
+	let (receiver, rest) = if header.starts_with('(') {
+		let close = matching_close(header, 0)?;
+		(
+			Some(header.get(1..close)?.trim()),
+			header.get(close + 1..)?.trim_start(),
+		)
+	} else {
+		(None, header)
+	};
+	let name_end = rest.find(['(', '['])?;
+	let name = rest.get(..name_end)?.trim();
+	if !is_identifier(name) {
+		return None;
+	}
+	let (type_parameters, rest) = match rest.get(name_end..)? {
+		generic if generic.starts_with('[') => {
+			let close = matching_close(generic, 0)?;
+			(
+				generic.get(..=close)?,
+				generic.get(close + 1..)?.trim_start(),
+			)
+		}
+		rest => ("", rest),
+	};
+	let close = matching_close(rest, 0)?;
+	let parameters = parameter_types(rest.get(1..close)?);
+	let results = render_results(rest.get(close + 1..)?.trim());
+
+	// Receiver names are local to the method, so only the type is part of the
+	// signature.
+	let receiver = receiver.map(|receiver| {
+		receiver
+			.rsplit_once(' ')
+			.map_or(receiver, |(_, receiver_type)| receiver_type)
+	});
+	let receiver_type = receiver.map(|receiver| {
+		let base = receiver.trim_start_matches('*');
+		base.split('[').next().unwrap_or(base).to_string()
+	});
+	let signature = match receiver {
+		Some(receiver) => {
+			format!(
+				"func ({receiver}) {name}{type_parameters}({}){results}",
+				parameters.join(", ")
+			)
+		}
+		None => {
+			format!(
+				"func {name}{type_parameters}({}){results}",
+				parameters.join(", ")
+			)
+		}
+	};
+
+	Some(FunctionDeclaration {
+		name: name.to_string(),
+		receiver_type,
+		signature,
+	})
+}
+
+/// The types of a parameter or result list. Go names are either given for
+/// every entry or for none, and `a, b int` shares one type across names.
+fn parameter_types(list: &str) -> Vec<String> {
+	let parameters = split_top_level(list, ',');
+	let named = parameters.iter().any(|parameter| {
+		parameter.split_once(' ').is_some_and(|(name, _)| {
+			is_identifier(name) && !matches!(name, "chan" | "func" | "map" | "struct" | "interface")
+		})
+	});
+	if !named {
+		return parameters;
+	}
+
+	let mut types = Vec::with_capacity(parameters.len());
+	let mut pending = 0;
+	for parameter in parameters {
+		match parameter.split_once(' ') {
+			Some((_, parameter_type)) => {
+				types.extend(std::iter::repeat_n(parameter_type.to_string(), pending + 1));
+				pending = 0;
+			}
+			None => pending += 1,
+		}
+	}
+
+	types
+}
+
+fn render_results(results: &str) -> String {
+	if results.is_empty() {
+		return String::new();
+	}
+	let Some(list) = results
+		.strip_prefix('(')
+		.and_then(|list| list.strip_suffix(')'))
+	else {
+		return format!(" {results}");
+	};
+	match parameter_types(list).as_slice() {
+		[] => String::new(),
+		[single] => format!(" {single}"),
+		types => format!(" ({})", types.join(", ")),
+	}
+}
+
+/// One `type` spec together with the exported members of its struct or
+/// interface body.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct TypeSpec {
+	name: String,
+	signature: String,
+	members: Vec<TypeMember>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct TypeMember {
+	item_kind: &'static str,
+	name: String,
+	signature: String,
+}
+
+fn parse_type_spec(spec: &str) -> Option<TypeSpec> {
+	let name_end = spec
+		.find(|character: char| !(character.is_alphanumeric() || character == '_'))
+		.unwrap_or(spec.len());
+	let name = spec.get(..name_end)?;
+	if name.is_empty() {
+		return None;
+	}
+	let rest = spec.get(name_end..)?;
+	// `List[T any]` declares type parameters, while `Grid [4]int` is an
+	// array type; gofmt never separates the two with the same spacing.
+	let (type_parameters, definition) = if rest.starts_with('[') {
+		let close = matching_close(rest, 0)?;
+		(
+			normalize_whitespace(rest.get(..=close)?),
+			rest.get(close + 1..)?.trim(),
+		)
+	} else {
+		(String::new(), rest.trim())
+	};
+	let header = format!("type {name}{type_parameters}");
+
+	let block = split_trailing_block(definition);
+	let (signature, members) = match block {
+		Some(("struct", body)) => (format!("{header} struct"), struct_fields(body)),
+		Some(("interface", body)) => {
+			let (methods, elements): (Vec<_>, Vec<_>) = split_statements(body)
+				.into_iter()
+				.map(|element| normalize_whitespace(&element))
+				.partition(|element| is_interface_method(element));
+			let signature = if elements.is_empty() {
+				format!("{header} interface")
+			} else {
+				format!("{header} interface {{ {} }}", elements.join("; "))
+			};
+			(
+				signature,
+				methods
+					.iter()
+					.filter_map(|method| interface_method(method))
+					.collect(),
+			)
+		}
+		_ => {
+			(
+				format!("{header} {}", normalize_whitespace(definition)),
+				Vec::new(),
+			)
+		}
+	};
+
+	Some(TypeSpec {
+		name: name.to_string(),
+		signature,
+		members,
+	})
+}
+
+fn struct_fields(body: &str) -> Vec<TypeMember> {
+	let mut fields = Vec::new();
+
+	for field in split_statements(body) {
+		let field = normalize_whitespace(&field);
+		// Struct tags were blanked by `sanitize_source` and carry no type
+		// information.
+		let field = field
+			.strip_suffix("``")
+			.or_else(|| field.strip_suffix("\"\""))
+			.map_or(field.as_str(), str::trim_end);
+
+		let mut names = Vec::new();
+		let mut rest = field;
+		let field_type = loop {
+			let end = rest
+				.find(|character: char| !(character.is_alphanumeric() || character == '_'))
+				.unwrap_or(rest.len());
+			let (name, after) = rest.split_at(end);
+			if name.is_empty() {
+				break None;
+			}
+			names.push(name);
+			match after.trim_start().strip_prefix(',') {
+				Some(next) => rest = next.trim_start(),
+				None if after.starts_with(' ') => break Some(after.trim()),
+				None => break None,
+			}
+		};
+
+		if let Some(field_type) = field_type {
+			for name in names.into_iter().filter(|name| is_exported(name)) {
+				fields.push(TypeMember {
+					item_kind: "field",
+					name: name.to_string(),
+					signature: format!("{name} {field_type}"),
+				});
+			}
+			continue;
+		}
+
+		// An embedded field is named after its type.
+		let base = field.trim_start_matches('*');
+		let base = base.split('[').next().unwrap_or(base);
+		let name = base.rsplit('.').next().unwrap_or(base);
+		if is_exported(name) {
+			fields.push(TypeMember {
+				item_kind: "field",
+				name: name.to_string(),
+				signature: field.to_string(),
+			});
+		}
+	}
+
+	fields
+}
+
+/// Interface bodies mix methods with embedded interfaces and type-set
+/// elements such as `~int | ~string`; only methods start with `Name(`.
+fn is_interface_method(element: &str) -> bool {
+	element
+		.split_once('(')
+		.is_some_and(|(name, _)| is_identifier(name))
+}
+
+/// Parse an exported `Name(params) results` interface method.
+fn interface_method(element: &str) -> Option<TypeMember> {
+	let open = element.find('(')?;
+	let name = element.get(..open)?;
+	if !is_exported(name) {
+		return None;
+	}
+	let close = matching_close(element, open)?;
+	let parameters = parameter_types(element.get(open + 1..close)?);
+	let results = render_results(element.get(close + 1..)?.trim());
+
+	Some(TypeMember {
+		item_kind: "interface_method",
+		name: name.to_string(),
+		signature: format!("{name}({}){results}", parameters.join(", ")),
+	})
+}
+
+fn diff_public_symbols(
+	before: &BTreeMap<(String, String), PublicSymbol>,
+	after: &BTreeMap<(String, String), PublicSymbol>,
+) -> Vec<SemanticChange> {
+	let mut changes = Vec::new();
+
+	for (key, after_symbol) in after {
+		match before.get(key) {
+			None => changes.push(build_addition_change(before, after_symbol)),
+			Some(before_symbol) if before_symbol.signature != after_symbol.signature => {
+				changes.push(build_symbol_change(
+					SemanticChangeKind::Modified,
+					after_symbol,
+					Some(before_symbol.signature.clone()),
+					Some(after_symbol.signature.clone()),
+				));
+			}
+			Some(_) => {}
+		}
+	}
+
+	for (key, before_symbol) in before {
+		if after.contains_key(key) {
+			continue;
+		}
+
+		changes.push(build_symbol_change(
+			SemanticChangeKind::Removed,
+			before_symbol,
+			Some(before_symbol.signature.clone()),
+			None,
+		));
+	}
+
+	changes
+}
+
+/// A method added to an existing interface breaks every type outside the
+/// package that implemented it, so it is reported as a modification of the
+/// interface rather than an addition.
+fn build_addition_change(
+	before: &BTreeMap<(String, String), PublicSymbol>,
+	symbol: &PublicSymbol,
+) -> SemanticChange {
+	let existing_interface = symbol
+		.owner
+		.as_ref()
+		.filter(|_| symbol.item_kind == "interface_method")
+		.and_then(|owner| before.get(&("type".to_string(), owner.clone())));
+	let Some(interface) = existing_interface else {
+		return build_symbol_change(
+			SemanticChangeKind::Added,
+			symbol,
+			None,
+			Some(symbol.signature.clone()),
+		);
+	};
+
+	let name = symbol
+		.item_path
+		.rsplit('.')
+		.next()
+		.unwrap_or(&symbol.item_path);
+	SemanticChange {
+		category: SemanticChangeCategory::PublicApi,
+		kind: SemanticChangeKind::Modified,
+		item_kind: symbol.item_kind.clone(),
+		item_path: symbol.item_path.clone(),
+		summary: format!(
+			"method `{name}` added to interface `{}`",
+			interface.item_path
+		),
+		file_path: symbol.file_path.clone(),
+		before_signature: None,
+		after_signature: Some(symbol.signature.clone()),
+	}
+}
+
+fn build_symbol_change(
+	kind: SemanticChangeKind,
+	symbol: &PublicSymbol,
+	before_signature: Option<String>,
+	after_signature: Option<String>,
+) -> SemanticChange {
+	let verb = if kind == SemanticChangeKind::Added {
+		"added"
+	} else if kind == SemanticChangeKind::Removed {
+		"removed"
+	} else {
+		"modified"
+	};
+
+	SemanticChange {
+		category: SemanticChangeCategory::PublicApi,
+		kind,
+		item_kind: symbol.item_kind.clone(),
+		item_path: symbol.item_path.clone(),
+		summary: format!("{} `{}` {verb}", symbol.item_kind, symbol.item_path),
+		file_path: symbol.file_path.clone(),
+		before_signature,
+		after_signature,
+	}
+}
+
+/// The directives of a `go.mod` file that matter to importers.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+struct GoModFile {
+	module: Option<String>,
+	go: Option<String>,
+	toolchain: Option<String>,
+	requires: BTreeMap<String, String>,
+}
+
+fn parse_go_mod(contents: &str) -> GoModFile {
+	let mut file = GoModFile::default();
+	let mut block = None;
+
+	for line in contents.lines() {
+		let (code, comment) = line.split_once("//").unwrap_or((line, ""));
+		let code = code.trim();
+		if code.is_empty() {
+			continue;
+		}
+		if code == ")" {
+			block = None;
+			continue;
+		}
+		if let Some(directive) = code.strip_suffix('(') {
+			block = Some(directive.trim().to_string());
+			continue;
+		}
+
+		let (directive, arguments) = match &block {
+			Some(directive) => (directive.as_str(), code),
+			None => code.split_once(char::is_whitespace).unwrap_or((code, "")),
+		};
+		let arguments = arguments.trim();
+		match directive {
+			"module" => file.module = Some(arguments.trim_matches('"').to_string()),
+			"go" => file.go = Some(arguments.to_string()),
+			"toolchain" => file.toolchain = Some(arguments.to_string()),
+			"require" => {
+				let mut parts = arguments.split_whitespace();
+				let (Some(module), Some(version)) = (parts.next(), parts.next()) else {
+					continue;
+				};
+				let version = if comment.trim() == "indirect" {
+					format!("{version} // indirect")
+				} else {
+					version.to_string()
+				};
+				file.requires.insert(module.to_string(), version);
+			}
+			_ => {}
+		}
+	}
+
+	file
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct ManifestEntry {
+	item_kind: String,
+	value: String,
+}
+
+fn analyze_manifest_change(change: &AnalyzedFileChange) -> Vec<SemanticChange> {
+	let before = change.before_contents.as_deref().map(parse_go_mod);
+	let after = change.after_contents.as_deref().map(parse_go_mod);
+	let mut changes = Vec::new();
+
+	for (category, extract) in [
+		(
+			SemanticChangeCategory::Export,
+			extract_module_entries as fn(&GoModFile) -> BTreeMap<String, ManifestEntry>,
+		),
+		(SemanticChangeCategory::Dependency, extract_require_entries),
+		(SemanticChangeCategory::Metadata, extract_metadata_entries),
+	] {
+		changes.extend(compare_manifest_entries(
+			category,
+			&change.package_path,
+			&before.as_ref().map(extract).unwrap_or_default(),
+			&after.as_ref().map(extract).unwrap_or_default(),
+		));
+	}
+
+	changes
+}
+
+/// The module path is the import path of every package in the module, so
+/// changing it is an export change.
+fn extract_module_entries(file: &GoModFile) -> BTreeMap<String, ManifestEntry> {
+	file.module
+		.iter()
+		.map(|module| {
+			(
+				"module".to_string(),
+				ManifestEntry {
+					item_kind: "module_path".to_string(),
+					value: module.clone(),
+				},
+			)
+		})
+		.collect()
+}
+
+fn extract_require_entries(file: &GoModFile) -> BTreeMap<String, ManifestEntry> {
+	file.requires
+		.iter()
+		.map(|(module, version)| {
+			(
+				module.clone(),
+				ManifestEntry {
+					item_kind: "dependency".to_string(),
+					value: version.clone(),
+				},
+			)
+		})
+		.collect()
+}
+
+fn extract_metadata_entries(file: &GoModFile) -> BTreeMap<String, ManifestEntry> {
+	[("go", &file.go), ("toolchain", &file.toolchain)]
+		.into_iter()
+		.filter_map(|(directive, value)| {
+			value.as_ref().map(|value| {
+				(
+					directive.to_string(),
+					ManifestEntry {
+						item_kind: "manifest_field".to_string(),
+						value: value.clone(),
+					},
+				)
+			})
+		})
+		.collect()
+}
+
+fn compare_manifest_entries(
+	category: SemanticChangeCategory,
+	file_path: &Path,
+	before: &BTreeMap<String, ManifestEntry>,
+	after: &BTreeMap<String, ManifestEntry>,
+) -> Vec<SemanticChange> {
+	let mut changes = Vec::new();
+
+	for (name, after_entry) in after {
+		match before.get(name) {
+			None => {
+				changes.push(build_manifest_change(
+					category,
+					SemanticChangeKind::Added,
+					file_path,
+					name,
+					after_entry,
+					None,
+					Some(after_entry.value.clone()),
+				));
+			}
+			Some(before_entry) if before_entry != after_entry => {
+				changes.push(build_manifest_change(
+					category,
+					SemanticChangeKind::Modified,
+					file_path,
+					name,
+					after_entry,
+					Some(before_entry.value.clone()),
+					Some(after_entry.value.clone()),
+				));
+			}
+			Some(_) => {}
+		}
+	}
+
+	for (name, before_entry) in before {
+		if after.contains_key(name) {
+			continue;
+		}
+
+		changes.push(build_manifest_change(
+			category,
+			SemanticChangeKind::Removed,
+			file_path,
+			name,
+			before_entry,
+			Some(before_entry.value.clone()),
+			None,
+		));
+	}
+
+	changes
+}
+
+fn build_manifest_change(
+	category: SemanticChangeCategory,
+	kind: SemanticChangeKind,
+	file_path: &Path,
+	item_path: &str,
+	entry: &ManifestEntry,
+	before_signature: Option<String>,
+	after_signature: Option<String>,
+) -> SemanticChange {
+	let verb = if kind == SemanticChangeKind::Added {
+		"added"
+	} else if kind == SemanticChangeKind::Removed {
+		"removed"
+	} else {
+		"modified"
+	};
+
+	SemanticChange {
+		category,
+		kind,
+		item_kind: entry.item_kind.clone(),
+		item_path: item_path.to_string(),
+		summary: format!("{} `{}` {verb}", entry.item_kind, item_path),
+		file_path: file_path.to_path_buf(),
+		before_signature,
+		after_signature,
+	}
+}
+
+#[cfg(test)]
+#[path = "__tests__/analysis_tests.rs"]
+mod tests;
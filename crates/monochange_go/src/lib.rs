@@ -10,7 +10,9 @@
 //!
 //! - discover Go modules in single-module and multi-module repositories
 //! - normalize Go module dependency edges for the shared planner
+//! - move module paths, `require` lines, and imports to `/vN` on major releases
 //! - infer `go mod tidy` as the default lockfile refresh command
+//! - diff exported identifiers and `go.mod` for `mc analyze`
 //!
 //! ## Best for
 //!
@@ -22,6 +24,10 @@
 //!
 //! - `discover_go_modules(root)` discovers Go modules
 //! - `GoAdapter` exposes the shared adapter interface
+//! - `semantic_import_versioning_updates(packages, versions)` plans the `go.mod`
+//!   and `.go` rewrites a major release needs
+//! - `GoSemanticAnalyzer` extracts exported functions, types, methods, struct
+//!   fields, and interface methods for semantic diffs
 //!
 //! ## Scope
 //!
@@ -29,16 +35,26 @@
 //! - multi-module repository detection
 //! - `go mod tidy` lockfile command inference
 //! - `go.sum` lockfile discovery
+//! - source-level exported API extraction
 
+pub mod analysis;
+pub mod lints;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 
+pub use analysis::GoSemanticAnalyzer;
+pub use analysis::semantic_analyzer;
 use monochange_core::AdapterDiscovery;
 use monochange_core::DependencyKind;
+use monochange_core::DiscoveryPathFilter;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageDependency;
@@ -71,6 +87,14 @@ impl EcosystemAdapter for GoAdapter {
 		discover_go_modules(root)
 	}
 
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_go_modules_with_filter(root, filter)
+	}
+
 	fn load_configured(
 		&self,
 		_root: &Path,
@@ -160,17 +184,45 @@ go 1.22
 /// ```text
 /// require github.com/org/repo/shared v1.3.0
 /// ```
-pub fn update_go_mod_text(
-	contents: &str,
-	versioned_deps: &std::collections::BTreeMap<String, String>,
-) -> String {
+///
+/// A bump to `v2` or later also moves the module path to its `/vN` form, as
+/// Go's semantic import versioning requires, in the `module`, `require`, and
+/// `replace` directives.
+pub fn update_go_mod_text(contents: &str, versioned_deps: &BTreeMap<String, String>) -> String {
 	if versioned_deps.is_empty() {
 		return contents.to_string();
 	}
 
+	rewrite_go_mod(contents, |module_path| {
+		versioned_deps
+			.get(&derive_module_name(module_path))
+			.cloned()
+	})
+}
+
+/// Rewrite `go.mod` directives for every module path `new_version` returns a
+/// version for.
+fn rewrite_go_mod(contents: &str, new_version: impl Fn(&str) -> Option<String>) -> String {
 	let mut result = String::with_capacity(contents.len());
+	let mut block = None;
 	for line in contents.lines() {
-		let updated = update_require_line(line, versioned_deps);
+		let trimmed = line.trim();
+		let updated = if trimmed == ")" {
+			block = None;
+			line.to_string()
+		} else if let Some(directive) = trimmed.strip_suffix('(') {
+			block = Some(directive.trim().to_string());
+			line.to_string()
+		} else {
+			match block.as_deref() {
+				Some("require") => update_require_line(line, &new_version),
+				Some("replace") => update_replace_line(line, &new_version),
+				Some(_) => line.to_string(),
+				None if trimmed.starts_with("module ") => update_module_line(line, &new_version),
+				None if trimmed.starts_with("replace ") => update_replace_line(line, &new_version),
+				None => update_require_line(line, &new_version),
+			}
+		};
 		result.push_str(&updated);
 		result.push('\n');
 	}
@@ -187,10 +239,7 @@ pub fn update_go_mod_text(
 /// - `require github.com/org/shared v1.0.0`
 /// - `  github.com/org/shared v1.0.0`
 /// - `  github.com/org/shared v1.0.0 // indirect`
-fn update_require_line(
-	line: &str,
-	versioned_deps: &std::collections::BTreeMap<String, String>,
-) -> String {
+fn update_require_line(line: &str, new_version: &impl Fn(&str) -> Option<String>) -> String {
 	let trimmed = line.trim();
 
 	// Skip empty lines, comments, and non-require directives
@@ -222,28 +271,10 @@ fn update_require_line(
 
 	let module_path = parts.first().copied().unwrap_or_default();
 
-	// Extract the module name (last path segment) for matching
-	let module_name = module_path.rsplit('/').next().unwrap_or(module_path);
-
-	// Strip version suffix from module name (e.g., `shared/v2` → `shared`)
-	let clean_name = module_name
-		.strip_prefix('v')
-		.and_then(|rest| {
-			rest.chars()
-				.all(|ch| ch.is_ascii_digit())
-				.then_some(module_name)
-		})
-		.map_or(module_name, |_| {
-			module_path.rsplit('/').nth(1).unwrap_or(module_path)
-		});
-
-	if let Some(new_version) = versioned_deps.get(clean_name) {
+	if let Some(new_version) = new_version(module_path) {
 		// Ensure the version has a `v` prefix for Go
-		let go_version = if new_version.starts_with('v') {
-			new_version.clone()
-		} else {
-			format!("v{new_version}")
-		};
+		let go_version = go_version_text(&new_version);
+		let module_path = module_path_for_version(module_path, &go_version);
 
 		// Preserve the original line structure (indentation, comments)
 		let prefix = &line[..line.len() - line.trim_start().len()];
@@ -264,12 +295,249 @@ fn update_require_line(
 	}
 }
 
+/// Move the module's own path to its `/vN` form when it is released as a new
+/// major version.
+fn update_module_line(line: &str, new_version: &impl Fn(&str) -> Option<String>) -> String {
+	let Some((directive, module_path)) = line.split_once("module ") else {
+		return line.to_string();
+	};
+	let (module_path, comment) = module_path
+		.split_once("//")
+		.map_or((module_path, None), |(path, comment)| (path, Some(comment)));
+	let module_path = module_path.trim();
+	let Some(new_version) = new_version(module_path) else {
+		return line.to_string();
+	};
+	let comment = comment.map_or_else(String::new, |comment| format!(" //{comment}"));
+	format!(
+		"{directive}module {}{comment}",
+		module_path_for_version(module_path, &go_version_text(&new_version))
+	)
+}
+
+/// Rename the left-hand module path of a `replace` directive. Replacement
+/// targets are local directories or other modules and stay untouched.
+fn update_replace_line(line: &str, new_version: &impl Fn(&str) -> Option<String>) -> String {
+	let Some((source, target)) = line.split_once("=>") else {
+		return line.to_string();
+	};
+	let indent = &source[..source.len() - source.trim_start().len()];
+	let source = source.trim();
+	let (directive, source) = source
+		.strip_prefix("replace ")
+		.map_or(("", source), |rest| ("replace ", rest.trim_start()));
+	let mut parts = source.split_whitespace();
+	let Some(module_path) = parts.next() else {
+		return line.to_string();
+	};
+	let Some(new_version) = new_version(module_path) else {
+		return line.to_string();
+	};
+	let renamed = module_path_for_version(module_path, &go_version_text(&new_version));
+	if renamed == module_path {
+		return line.to_string();
+	}
+	let pinned_version = parts
+		.next()
+		.map_or_else(String::new, |version| format!(" {version}"));
+	format!("{indent}{directive}{renamed}{pinned_version} =>{target}")
+}
+
+fn go_version_text(version: &str) -> String {
+	if version.starts_with('v') {
+		version.to_string()
+	} else {
+		format!("v{version}")
+	}
+}
+
+/// The module path a release at `version` must use.
+///
+/// Go's semantic import versioning puts the major version in the module path
+/// from `v2` on: `example.com/greeter` becomes `example.com/greeter/v2`, and
+/// a later `v3` release replaces the suffix. `gopkg.in` paths encode the
+/// major version differently and are returned unchanged, as are versions
+/// that do not parse.
+#[must_use]
+pub fn module_path_for_version(module_path: &str, version: &str) -> String {
+	let Some(version) = parse_go_version(version) else {
+		return module_path.to_string();
+	};
+	if module_path.starts_with("gopkg.in/") {
+		return module_path.to_string();
+	}
+	let base = module_path
+		.rsplit_once('/')
+		.filter(|(_, last)| is_major_version_suffix(last))
+		.map_or(module_path, |(base, _)| base);
+	if version.major >= 2 {
+		format!("{base}/v{}", version.major)
+	} else {
+		base.to_string()
+	}
+}
+
+/// Rewrite import paths in Go source for modules that moved.
+///
+/// `module_paths` maps every workspace module path to the path it is
+/// released under. Each import belongs to the longest module path it starts
+/// with, so packages of a nested module are never mistaken for packages of
+/// the module that contains it.
+#[must_use]
+pub fn rewrite_go_import_paths(contents: &str, module_paths: &BTreeMap<String, String>) -> String {
+	let mut result = String::with_capacity(contents.len());
+	let mut in_import_block = false;
+	for line in contents.split_inclusive('\n') {
+		let trimmed = line.trim();
+		let is_import = if in_import_block {
+			in_import_block = !trimmed.starts_with(')');
+			in_import_block
+		} else if trimmed.starts_with("import (") || trimmed == "import(" {
+			in_import_block = true;
+			false
+		} else {
+			trimmed.starts_with("import ")
+		};
+		if !is_import {
+			result.push_str(line);
+			continue;
+		}
+		result.push_str(&rewrite_import_line(line, module_paths));
+	}
+	result
+}
+
+fn rewrite_import_line(line: &str, module_paths: &BTreeMap<String, String>) -> String {
+	let Some(start) = line.find(['"', '`']) else {
+		return line.to_string();
+	};
+	let quote = &line[start..=start];
+	let Some(length) = line[start + 1..].find(quote) else {
+		return line.to_string();
+	};
+	let import_path = &line[start + 1..start + 1 + length];
+	let owner = module_paths
+		.iter()
+		.filter(|(module_path, _)| {
+			import_path == module_path.as_str()
+				|| import_path
+					.strip_prefix(module_path.as_str())
+					.is_some_and(|rest| rest.starts_with('/'))
+		})
+		.max_by_key(|(module_path, _)| module_path.len());
+	let Some((module_path, renamed)) =
+		owner.filter(|(module_path, renamed)| module_path != renamed)
+	else {
+		return line.to_string();
+	};
+	format!(
+		"{}{renamed}{}",
+		&line[..=start],
+		&line[start + 1 + module_path.len()..]
+	)
+}
+
+/// Compute the file rewrites a release needs when Go modules cross a major
+/// version boundary.
+///
+/// `released_versions` maps package record ids to their new versions. Every
+/// released Go module whose path must change gets its `module` directive
+/// rewritten, and every workspace module that requires, replaces, or imports
+/// it is rewritten to the new path. Returns the changed files with their new
+/// contents.
+pub fn semantic_import_versioning_updates(
+	packages: &[PackageRecord],
+	released_versions: &BTreeMap<String, String>,
+) -> MonochangeResult<Vec<(PathBuf, String)>> {
+	let go_modules = packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Go)
+		.filter_map(|package| {
+			package
+				.metadata
+				.get("module_path")
+				.map(|module_path| (package, module_path))
+		})
+		.collect::<Vec<_>>();
+	let mut module_paths = BTreeMap::new();
+	let mut major_versions = BTreeMap::new();
+	for &(package, module_path) in &go_modules {
+		let released = released_versions.get(&package.id);
+		let renamed = released.map_or_else(
+			|| module_path.clone(),
+			|version| module_path_for_version(module_path, version),
+		);
+		if let Some(version) = released.filter(|_| renamed != *module_path) {
+			major_versions.insert(module_path.clone(), go_version_text(version));
+		}
+		module_paths.insert(module_path.clone(), renamed);
+	}
+	if major_versions.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let mut updates = Vec::new();
+	let mut visited = BTreeSet::new();
+	for (package, _) in &go_modules {
+		let contents = read_go_file(&package.manifest_path)?;
+		let rendered = rewrite_go_mod(&contents, |module_path| {
+			major_versions.get(module_path).cloned()
+		});
+		if rendered != contents {
+			updates.push((package.manifest_path.clone(), rendered));
+		}
+
+		let module_dir = package
+			.manifest_path
+			.parent()
+			.unwrap_or(&package.workspace_root);
+		for entry in WalkDir::new(module_dir)
+			.into_iter()
+			.filter_entry(should_descend)
+			.filter_map(Result::ok)
+		{
+			let path = entry.path();
+			if path.extension().and_then(|extension| extension.to_str()) != Some("go")
+				|| !visited.insert(path.to_path_buf())
+			{
+				continue;
+			}
+			let contents = read_go_file(path)?;
+			let rendered = rewrite_go_import_paths(&contents, &module_paths);
+			if rendered != contents {
+				updates.push((path.to_path_buf(), rendered));
+			}
+		}
+	}
+
+	updates.sort_by(|left, right| left.0.cmp(&right.0));
+	Ok(updates)
+}
+
+fn read_go_file(path: &Path) -> MonochangeResult<String> {
+	fs::read_to_string(path)
+		.map_err(|error| MonochangeError::Io(format!("failed to read {}: {error}", path.display())))
+}
+
 #[tracing::instrument(skip_all)]
 pub fn discover_go_modules(root: &Path) -> MonochangeResult<AdapterDiscovery> {
+	discover_go_modules_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+/// Discover Go modules rooted at `root` without parsing `go.mod` files outside
+/// `filter`'s discovery scope.
+#[tracing::instrument(skip_all)]
+pub fn discover_go_modules_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_go_mod_files(root, filter);
+	let mut skipped = walk.skipped;
 	let mut packages = Vec::new();
 	let mut warnings = Vec::new();
 
-	for go_mod_path in find_all_go_mod_files(root) {
+	for go_mod_path in filter.retain_manifests_in_scope(Ecosystem::Go, walk.manifests, &mut skipped)
+	{
 		match parse_go_module(&go_mod_path, root) {
 			Ok(Some(package)) => packages.push(package),
 			Ok(None) => {}
@@ -284,7 +552,11 @@ pub fn discover_go_modules(root: &Path) -> MonochangeResult<AdapterDiscovery> {
 
 	tracing::debug!(packages = packages.len(), "discovered go modules");
 
-	Ok(AdapterDiscovery { packages, warnings })
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
 }
 
 fn parse_go_module(go_mod_path: &Path, root: &Path) -> MonochangeResult<Option<PackageRecord>> {
@@ -457,15 +729,10 @@ pub fn parse_go_version(version_str: &str) -> Option<Version> {
 	Version::parse(stripped).ok()
 }
 
-fn find_all_go_mod_files(root: &Path) -> Vec<PathBuf> {
-	WalkDir::new(root)
-		.into_iter()
-		.filter_entry(should_descend)
-		.filter_map(Result::ok)
-		.filter(|entry| entry.file_name() == GO_MOD_FILE)
-		.map(DirEntry::into_path)
-		.map(|path| normalize_path(&path))
-		.collect()
+fn find_all_go_mod_files(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(Ecosystem::Go, root, should_descend, |entry| {
+		entry.file_name() == GO_MOD_FILE
+	})
 }
 
 fn should_descend(entry: &DirEntry) -> bool {
@@ -0,0 +1,211 @@
+use monochange_config::load_workspace_configuration;
+use monochange_core::lint::LintSuite;
+use monochange_core::lint::LintTarget;
+use monochange_test_helpers::fixture_path;
+use serde_json::json;
+
+use super::*;
+
+fn workspace_hygiene_targets() -> Vec<LintTarget> {
+	let root = fixture_path!("go-lints/workspace-hygiene/workspace");
+	let configuration = load_workspace_configuration(&root)
+		.unwrap_or_else(|error| panic!("load go lint fixture config: {error}"));
+	lint_suite()
+		.collect_targets(&root, &configuration)
+		.unwrap_or_else(|error| panic!("collect go lint fixture targets: {error}"))
+}
+
+fn find_target<'a>(targets: &'a [LintTarget], package_name: &str) -> &'a LintTarget {
+	targets
+		.iter()
+		.find(|target| target.metadata.package_name.as_deref() == Some(package_name))
+		.unwrap_or_else(|| panic!("missing target for {package_name}"))
+}
+
+fn ctx(target: &LintTarget) -> LintContext<'_> {
+	LintContext {
+		workspace_root: &target.workspace_root,
+		manifest_path: &target.manifest_path,
+		contents: &target.contents,
+		metadata: &target.metadata,
+		parsed: target.parsed.as_ref(),
+	}
+}
+
+fn config() -> LintRuleConfig {
+	LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("fix".to_string(), json!(true))]),
+	}
+}
+
+fn apply_fix(contents: &str, result: &LintResult) -> String {
+	let edit = result
+		.fix
+		.as_ref()
+		.and_then(|fix| fix.edits.first())
+		.unwrap_or_else(|| panic!("expected an autofix for {}", result.rule_id));
+	let mut fixed = contents.to_string();
+	fixed.replace_range(edit.span.0..edit.span.1, &edit.replacement);
+	fixed
+}
+
+#[test]
+fn presets_are_exposed() {
+	let presets = GoLintSuite.presets();
+	assert_eq!(presets.len(), 2);
+
+	let recommended = presets.first().expect("expected recommended preset");
+	assert_eq!(recommended.id, "go/recommended");
+	assert_eq!(
+		recommended.rules.get("go/sorted-dependencies"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Warning))
+	);
+	assert_eq!(
+		recommended.rules.get("go/no-local-replace-directives"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Error))
+	);
+
+	let strict = presets.get(1).expect("expected strict preset");
+	assert_eq!(strict.id, "go/strict");
+	assert_eq!(
+		strict.rules.get("go/go-directive-consistent"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Error))
+	);
+}
+
+#[test]
+fn collect_targets_marks_managed_modules() {
+	let targets = workspace_hygiene_targets();
+	assert_eq!(targets.len(), 4);
+
+	let api = find_target(&targets, "api");
+	assert!(api.metadata.managed);
+	assert_eq!(api.metadata.package_id.as_deref(), Some("api"));
+	assert_eq!(api.metadata.ecosystem, "go");
+	assert_eq!(api.metadata.publishable, Some(true));
+
+	let tools = find_target(&targets, "tools");
+	assert!(!tools.metadata.managed);
+	assert_eq!(tools.metadata.package_id, None);
+}
+
+#[test]
+fn required_directives_rule_reports_missing_go_directive() {
+	let targets = workspace_hygiene_targets();
+	let rule = RequiredDirectivesRule::new();
+
+	let results = rule.run(&ctx(find_target(&targets, "tools")), &config());
+	assert_eq!(results.len(), 1);
+	assert_eq!(
+		results.first().map(|result| result.message.as_str()),
+		Some("go.mod must declare the `go` directive")
+	);
+	assert!(
+		rule.run(&ctx(find_target(&targets, "api")), &config())
+			.is_empty()
+	);
+
+	let toolchain = LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("directives".to_string(), json!(["go", "toolchain"]))]),
+	};
+	assert_eq!(
+		rule.run(&ctx(find_target(&targets, "api")), &toolchain).len(),
+		1
+	);
+}
+
+#[test]
+fn sorted_dependencies_rule_sorts_each_require_group() {
+	let targets = workspace_hygiene_targets();
+	let rule = SortedDependenciesRule::new();
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "shared")), &config())
+			.is_empty()
+	);
+
+	let api = find_target(&targets, "api");
+	let results = rule.run(&ctx(api), &config());
+	assert_eq!(results.len(), 1);
+	let result = results.first().expect("expected a result");
+	assert_eq!(result.location.line, 6);
+
+	let fixed = apply_fix(&api.contents, result);
+	assert!(fixed.contains(
+		"require (\n\t// shared helpers live next door\n\tgithub.com/acme/platform/shared v1.2.0\n\tgithub.com/gin-gonic/gin v1.9.1\n\n\tgithub.com/stretchr/testify v1.9.0 // indirect\n\tgolang.org/x/sync v0.7.0\n)\n"
+	));
+}
+
+#[test]
+fn no_local_replace_directives_rule_reports_filesystem_replacements() {
+	let targets = workspace_hygiene_targets();
+	let rule = NoLocalReplaceDirectivesRule::new();
+
+	let api_results = rule.run(&ctx(find_target(&targets, "api")), &config());
+	assert_eq!(api_results.len(), 1);
+	let api_result = api_results.first().expect("expected an api result");
+	assert_eq!(api_result.location.line, 14);
+	assert!(
+		api_result
+			.message
+			.contains("`github.com/acme/platform/shared` with local path `../shared`")
+	);
+
+	// Replacements that point at another module version are fine.
+	let worker_results = rule.run(&ctx(find_target(&targets, "worker")), &config());
+	assert_eq!(worker_results.len(), 1);
+
+	let allow = LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([(
+			"allow".to_string(),
+			json!(["github.com/acme/platform/shared"]),
+		)]),
+	};
+	assert!(
+		rule.run(&ctx(find_target(&targets, "worker")), &allow)
+			.is_empty()
+	);
+}
+
+#[test]
+fn go_directive_consistent_rule_fixes_outliers_to_the_workspace_version() {
+	let targets = workspace_hygiene_targets();
+	let rule = GoDirectiveConsistentRule::new();
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "api")), &config())
+			.is_empty()
+	);
+	// Missing directives are left to `go/required-directives`.
+	assert!(
+		rule.run(&ctx(find_target(&targets, "tools")), &config())
+			.is_empty()
+	);
+
+	let worker = find_target(&targets, "worker");
+	let results = rule.run(&ctx(worker), &config());
+	let result = results.first().expect("expected a worker result");
+	assert_eq!(
+		result.message,
+		"go directive `1.21` differs from the workspace version `1.22`"
+	);
+	assert_eq!((result.location.line, result.location.column), (3, 4));
+	assert!(apply_fix(&worker.contents, result).contains("go 1.22 // pinned for the old runner"));
+}
+
+#[test]
+fn go_directive_consistent_rule_accepts_an_explicit_expected_version() {
+	let targets = workspace_hygiene_targets();
+	let config = LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("expected".to_string(), json!("1.23"))]),
+	};
+
+	let results = GoDirectiveConsistentRule::new().run(&ctx(find_target(&targets, "api")), &config);
+	let result = results.first().expect("expected an api result");
+	assert!(result.fix.is_some());
+	assert!(result.message.contains("`1.23`"));
+}
@@ -0,0 +1,662 @@
+#![forbid(clippy::indexing_slicing)]
+
+//! Go `go.mod` lint suite.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use monochange_core::MonochangeResult;
+use monochange_core::PackageDefinition;
+use monochange_core::PublishState;
+use monochange_core::WorkspaceConfiguration;
+use monochange_core::lint::LintCategory;
+use monochange_core::lint::LintContext;
+use monochange_core::lint::LintFix;
+use monochange_core::lint::LintLocation;
+use monochange_core::lint::LintMaturity;
+use monochange_core::lint::LintOptionDefinition;
+use monochange_core::lint::LintOptionKind;
+use monochange_core::lint::LintPreset;
+use monochange_core::lint::LintResult;
+use monochange_core::lint::LintRule;
+use monochange_core::lint::LintRuleConfig;
+use monochange_core::lint::LintRuleRunner;
+use monochange_core::lint::LintSeverity;
+use monochange_core::lint::LintSuite;
+use monochange_core::lint::LintTarget;
+use monochange_core::lint::LintTargetMetadata;
+use monochange_core::relative_to_root;
+
+use crate::discover_go_modules;
+
+/// Return the shared Go lint suite.
+#[must_use]
+pub fn lint_suite() -> GoLintSuite {
+	GoLintSuite
+}
+
+/// Go lint suite implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GoLintSuite;
+
+#[derive(Debug, Clone)]
+struct GoLintFile {
+	/// `go` directive versions declared across the workspace, keyed by
+	/// manifest path.
+	workspace_go_versions: Arc<BTreeMap<PathBuf, String>>,
+}
+
+impl LintSuite for GoLintSuite {
+	fn suite_id(&self) -> &'static str {
+		"go"
+	}
+
+	fn rules(&self) -> Vec<Box<dyn LintRuleRunner>> {
+		vec![
+			Box::new(GoDirectiveConsistentRule::new()),
+			Box::new(NoLocalReplaceDirectivesRule::new()),
+			Box::new(RequiredDirectivesRule::new()),
+			Box::new(SortedDependenciesRule::new()),
+		]
+	}
+
+	fn presets(&self) -> Vec<LintPreset> {
+		vec![
+			LintPreset::new(
+				"go/recommended",
+				"Go recommended",
+				"Balanced go.mod linting for required directives and publishable modules",
+				LintMaturity::Stable,
+			)
+			.with_rules(BTreeMap::from([
+				(
+					"go/go-directive-consistent".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Warning),
+				),
+				(
+					"go/no-local-replace-directives".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"go/required-directives".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"go/sorted-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Warning),
+				),
+			])),
+			LintPreset::new(
+				"go/strict",
+				"Go strict",
+				"Opinionated go.mod linting with style and workspace rules promoted to errors",
+				LintMaturity::Strict,
+			)
+			.with_rules(BTreeMap::from([
+				(
+					"go/go-directive-consistent".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"go/no-local-replace-directives".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"go/required-directives".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"go/sorted-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+			])),
+		]
+	}
+
+	fn collect_targets(
+		&self,
+		workspace_root: &Path,
+		configuration: &WorkspaceConfiguration,
+	) -> MonochangeResult<Vec<LintTarget>> {
+		let discovery = discover_go_modules(workspace_root)?;
+		let mut manifests = Vec::new();
+		for package in discovery.packages {
+			if !is_lintable_workspace_manifest(workspace_root, &package.manifest_path) {
+				continue;
+			}
+			let contents = fs::read_to_string(&package.manifest_path).map_err(|error| {
+				monochange_core::MonochangeError::IoSource {
+					path: package.manifest_path.clone(),
+					source: error,
+				}
+			})?;
+			manifests.push((package, contents));
+		}
+
+		let workspace_go_versions = Arc::new(
+			manifests
+				.iter()
+				.filter_map(|(package, contents)| {
+					go_directive(contents)
+						.map(|directive| (package.manifest_path.clone(), directive.version))
+				})
+				.collect::<BTreeMap<_, _>>(),
+		);
+
+		Ok(manifests
+			.into_iter()
+			.map(|(package, contents)| {
+				let manifest_dir = package.manifest_path.parent().unwrap_or(workspace_root);
+				let configured_package =
+					configured_package(configuration, workspace_root, manifest_dir);
+				let package_id = configured_package.map(|package| package.id.clone());
+				let group_id = configured_package.and_then(|package| {
+					configuration
+						.group_for_package(&package.id)
+						.map(|group| group.id.clone())
+				});
+				let relative_path = relative_to_root(workspace_root, &package.manifest_path)
+					.unwrap_or_else(|| package.manifest_path.clone());
+				let private = matches!(package.publish_state, PublishState::Private);
+				let publish_enabled =
+					configured_package.is_none_or(|package| package.publish.enabled);
+
+				LintTarget::new(
+					workspace_root.to_path_buf(),
+					package.manifest_path.clone(),
+					contents,
+					LintTargetMetadata {
+						ecosystem: "go".to_string(),
+						relative_path,
+						package_name: Some(package.name),
+						package_id,
+						group_id,
+						managed: configured_package.is_some(),
+						private: Some(private),
+						publishable: Some(!private && publish_enabled),
+					},
+					Box::new(GoLintFile {
+						workspace_go_versions: Arc::clone(&workspace_go_versions),
+					}),
+				)
+			})
+			.collect())
+	}
+}
+
+fn is_lintable_workspace_manifest(workspace_root: &Path, manifest_path: &Path) -> bool {
+	!(manifest_path.starts_with(workspace_root.join("fixtures"))
+		|| manifest_path.starts_with(workspace_root.join("target"))
+		|| manifest_path.starts_with(workspace_root.join(".git")))
+}
+
+fn configured_package<'a>(
+	configuration: &'a WorkspaceConfiguration,
+	workspace_root: &Path,
+	manifest_dir: &Path,
+) -> Option<&'a PackageDefinition> {
+	let relative_dir = relative_to_root(workspace_root, manifest_dir)?;
+	configuration
+		.packages
+		.iter()
+		.find(|package| package.path == relative_dir)
+}
+
+fn go_file<'a>(ctx: &'a LintContext<'a>) -> Option<&'a GoLintFile> {
+	ctx.parsed_as::<GoLintFile>()
+}
+
+fn location(ctx: &LintContext<'_>, offset: usize) -> LintLocation {
+	let prefix = ctx.contents.get(..offset).unwrap_or_default();
+	let line = prefix.bytes().filter(|byte| *byte == b'\n').count() + 1;
+	let column = prefix
+		.rsplit('\n')
+		.next()
+		.map_or(1, |segment| segment.chars().count() + 1);
+	LintLocation::new(ctx.manifest_path, line, column)
+}
+
+/// One `go.mod` line with the byte offset it starts at.
+#[derive(Debug, Clone, Copy)]
+struct GoModLine<'a> {
+	start: usize,
+	text: &'a str,
+}
+
+impl GoModLine<'_> {
+	/// The line without its `//` comment or surrounding whitespace.
+	fn code(&self) -> &str {
+		self.text
+			.split_once("//")
+			.map_or(self.text, |(code, _)| code)
+			.trim()
+	}
+
+	fn end(&self) -> usize {
+		self.start + self.text.len()
+	}
+}
+
+fn go_mod_lines(contents: &str) -> Vec<GoModLine<'_>> {
+	let mut start = 0;
+	contents
+		.split('\n')
+		.map(|text| {
+			let line = GoModLine { start, text };
+			start += text.len() + 1;
+			line
+		})
+		.collect()
+}
+
+fn directive_keyword(code: &str) -> Option<&str> {
+	code.split(|ch: char| ch.is_whitespace() || ch == '(')
+		.next()
+		.filter(|keyword| !keyword.is_empty())
+}
+
+fn declares_directive(contents: &str, directive: &str) -> bool {
+	go_mod_lines(contents)
+		.iter()
+		.any(|line| directive_keyword(line.code()) == Some(directive))
+}
+
+/// The `go` directive version and the byte span it occupies.
+#[derive(Debug, Clone)]
+struct GoDirective {
+	version: String,
+	span: (usize, usize),
+}
+
+fn go_directive(contents: &str) -> Option<GoDirective> {
+	go_mod_lines(contents).into_iter().find_map(|line| {
+		let code = line.code();
+		let version = code.strip_prefix("go")?;
+		if !version.starts_with(char::is_whitespace) {
+			return None;
+		}
+		let version = version.trim();
+		let offset = line.start + line.text.find(code)? + code.len() - version.len();
+		Some(GoDirective {
+			version: version.to_string(),
+			span: (offset, offset + version.len()),
+		})
+	})
+}
+
+/// Inner line ranges of every `<keyword> ( ... )` block, as indexes into the
+/// line list.
+fn directive_blocks(lines: &[GoModLine<'_>], keyword: &str) -> Vec<(usize, usize)> {
+	let mut blocks = Vec::new();
+	let mut open = None;
+	for (index, line) in lines.iter().enumerate() {
+		let code = line.code();
+		match open {
+			None => {
+				if code.strip_prefix(keyword).map(str::trim) == Some("(") {
+					open = Some(index + 1);
+				}
+			}
+			Some(first) => {
+				if code == ")" {
+					blocks.push((first, index));
+					open = None;
+				}
+			}
+		}
+	}
+	blocks
+}
+
+fn module_sort_key(code: &str) -> String {
+	code.split_whitespace()
+		.next()
+		.unwrap_or_default()
+		.trim_matches('"')
+		.to_string()
+}
+
+/// Render the inner lines of a `require` block with each blank-line separated
+/// group sorted by module path. Comment lines stay attached to the entry that
+/// follows them.
+fn sorted_block_lines(block: &[GoModLine<'_>]) -> Vec<String> {
+	let mut rendered = Vec::new();
+	let mut entries: Vec<(String, Vec<&str>)> = Vec::new();
+	let mut pending: Vec<&str> = Vec::new();
+	let flush = |entries: &mut Vec<(String, Vec<&str>)>, rendered: &mut Vec<String>| {
+		entries.sort_by(|left, right| left.0.cmp(&right.0));
+		for (_, lines) in entries.drain(..) {
+			rendered.extend(lines.into_iter().map(ToString::to_string));
+		}
+	};
+
+	for line in block {
+		let code = line.code();
+		if line.text.trim().is_empty() {
+			flush(&mut entries, &mut rendered);
+			rendered.extend(pending.drain(..).map(ToString::to_string));
+			rendered.push(line.text.to_string());
+		} else if code.is_empty() {
+			pending.push(line.text);
+		} else {
+			let mut lines = std::mem::take(&mut pending);
+			lines.push(line.text);
+			entries.push((module_sort_key(code), lines));
+		}
+	}
+	flush(&mut entries, &mut rendered);
+	rendered.extend(pending.into_iter().map(ToString::to_string));
+	rendered
+}
+
+fn is_local_replacement(target: &str) -> bool {
+	target.starts_with("./") || target.starts_with("../") || target.starts_with('/')
+}
+
+/// Local `replace` directives as `(offset, module, target)` triples.
+fn local_replacements(contents: &str) -> Vec<(usize, String, String)> {
+	let lines = go_mod_lines(contents);
+	let block_lines = directive_blocks(&lines, "replace")
+		.into_iter()
+		.flat_map(|(first, last)| first..last)
+		.collect::<BTreeSet<_>>();
+
+	lines
+		.iter()
+		.enumerate()
+		.filter_map(|(index, line)| {
+			let code = line.code();
+			let spec = if block_lines.contains(&index) {
+				code
+			} else {
+				code.strip_prefix("replace")
+					.filter(|rest| rest.starts_with(char::is_whitespace))?
+					.trim()
+			};
+			let (module, target) = spec.split_once("=>")?;
+			let module = module_sort_key(module);
+			let target = target.split_whitespace().next()?.trim_matches('"');
+			is_local_replacement(target).then(|| (line.start, module, target.to_string()))
+		})
+		.collect()
+}
+
+/// The `go` version this workspace expects: the configured `expected` option,
+/// or the version most modules already declare.
+fn expected_go_version(
+	config: &LintRuleConfig,
+	workspace_versions: &BTreeMap<PathBuf, String>,
+) -> Option<String> {
+	if let Some(expected) = config.string_option("expected") {
+		return Some(expected);
+	}
+	let mut counts = BTreeMap::<&str, usize>::new();
+	for version in workspace_versions.values() {
+		*counts.entry(version.as_str()).or_default() += 1;
+	}
+	// Ties resolve to the lowest version string so the choice is stable.
+	counts
+		.into_iter()
+		.fold(None::<(&str, usize)>, |best, (version, count)| {
+			match best {
+				Some((_, best_count)) if best_count >= count => best,
+				_ => Some((version, count)),
+			}
+		})
+		.map(|(version, _)| version.to_string())
+}
+
+#[derive(Debug)]
+struct GoDirectiveConsistentRule {
+	rule: LintRule,
+}
+
+impl GoDirectiveConsistentRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"go/go-directive-consistent",
+				"Consistent go directive",
+				"Requires every Go module in the workspace to declare the same go directive version",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				true,
+			)
+			.with_options(vec![
+				LintOptionDefinition::new(
+					"expected",
+					"the go version every module must declare; defaults to the version most modules already use",
+					LintOptionKind::String,
+				),
+				LintOptionDefinition::new(
+					"fix",
+					"apply an autofix that rewrites the go directive",
+					LintOptionKind::Boolean,
+				),
+			]),
+		}
+	}
+}
+
+impl LintRuleRunner for GoDirectiveConsistentRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		let Some(file) = go_file(ctx) else {
+			return Vec::new();
+		};
+		// A missing directive is reported by `go/required-directives`.
+		let Some(directive) = go_directive(ctx.contents) else {
+			return Vec::new();
+		};
+		let Some(expected) = expected_go_version(config, &file.workspace_go_versions) else {
+			return Vec::new();
+		};
+		if directive.version == expected {
+			return Vec::new();
+		}
+
+		let mut result = LintResult::new(
+			self.rule.id.clone(),
+			location(ctx, directive.span.0),
+			format!(
+				"go directive `{}` differs from the workspace version `{expected}`",
+				directive.version
+			),
+			config.severity(),
+		);
+		if config.bool_option("fix", true) {
+			result = result.with_fix(LintFix::single(
+				format!("set the go directive to `{expected}`"),
+				directive.span,
+				expected,
+			));
+		}
+		vec![result]
+	}
+}
+
+#[derive(Debug)]
+struct NoLocalReplaceDirectivesRule {
+	rule: LintRule,
+}
+
+impl NoLocalReplaceDirectivesRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"go/no-local-replace-directives",
+				"No local replace directives",
+				"Prevents published Go modules from replacing dependencies with local filesystem paths",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				false,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"allow",
+				"list of module paths that may be replaced with a local path",
+				LintOptionKind::StringList,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for NoLocalReplaceDirectivesRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		if ctx.metadata.publishable != Some(true) {
+			return Vec::new();
+		}
+		let allowed = config
+			.string_list_option("allow")
+			.unwrap_or_default()
+			.into_iter()
+			.collect::<BTreeSet<_>>();
+
+		local_replacements(ctx.contents)
+			.into_iter()
+			.filter(|(_, module, _)| !allowed.contains(module))
+			.map(|(offset, module, target)| {
+				LintResult::new(
+					self.rule.id.clone(),
+					location(ctx, offset),
+					format!(
+						"published Go modules must not replace `{module}` with local path `{target}`; use a go.work file for local development"
+					),
+					config.severity(),
+				)
+			})
+			.collect()
+	}
+}
+
+#[derive(Debug)]
+struct RequiredDirectivesRule {
+	rule: LintRule,
+}
+
+impl RequiredDirectivesRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"go/required-directives",
+				"Required directives",
+				"Requires selected go.mod directives, such as the go version, to be declared",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				false,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"directives",
+				"list of go.mod directives that must be present",
+				LintOptionKind::StringList,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for RequiredDirectivesRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		config
+			.string_list_option("directives")
+			.unwrap_or_else(|| vec!["go".to_string()])
+			.into_iter()
+			.filter(|directive| !declares_directive(ctx.contents, directive))
+			.map(|directive| {
+				LintResult::new(
+					self.rule.id.clone(),
+					location(ctx, 0),
+					format!("go.mod must declare the `{directive}` directive"),
+					config.severity(),
+				)
+			})
+			.collect()
+	}
+}
+
+#[derive(Debug)]
+struct SortedDependenciesRule {
+	rule: LintRule,
+}
+
+impl SortedDependenciesRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"go/sorted-dependencies",
+				"Sorted dependencies",
+				"Requires go.mod require blocks to be sorted by module path",
+				LintCategory::Style,
+				LintMaturity::Stable,
+				true,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"fix",
+				"apply an autofix that sorts each require block",
+				LintOptionKind::Boolean,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for SortedDependenciesRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		let lines = go_mod_lines(ctx.contents);
+		let mut results = Vec::new();
+
+		for (first, last) in directive_blocks(&lines, "require") {
+			let Some(block) = lines.get(first..last) else {
+				continue;
+			};
+			let (Some(first_line), Some(last_line)) = (block.first(), block.last()) else {
+				continue;
+			};
+			let current = block
+				.iter()
+				.map(|line| line.text.to_string())
+				.collect::<Vec<_>>();
+			let sorted = sorted_block_lines(block);
+			if current == sorted {
+				continue;
+			}
+
+			let mut result = LintResult::new(
+				self.rule.id.clone(),
+				location(ctx, first_line.start),
+				"require block is not sorted by module path",
+				config.severity(),
+			);
+			if config.bool_option("fix", true) {
+				result = result.with_fix(LintFix::single(
+					"sort require block by module path",
+					(first_line.start, last_line.end()),
+					sorted.join("\n"),
+				));
+			}
+			results.push(result);
+		}
+
+		results
+	}
+}
+
+#[cfg(test)]
+#[path = "__tests__/mod_tests.rs"]
+mod tests;
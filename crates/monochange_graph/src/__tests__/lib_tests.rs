@@ -1,22 +1,36 @@
 mod mutant_killers_tests;
 mod prop_tests;
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
+use chrono::NaiveDate;
 use monochange_core::BumpSeverity;
+use monochange_core::CalverFormat;
 use monochange_core::ChangeSignal;
 use monochange_core::CompatibilityAssessment;
 use monochange_core::DependencyEdge;
 use monochange_core::DependencyKind;
+use monochange_core::DependencyKindRule;
+use monochange_core::DependencyPropagation;
 use monochange_core::DependencySourceKind;
 use monochange_core::Ecosystem;
 use monochange_core::PackageRecord;
+use monochange_core::PreStablePolicy;
+use monochange_core::PropagationByKind;
 use monochange_core::PublishState;
+use monochange_core::ReleaseCause;
+use monochange_core::ReleaseOwnerKind;
+use monochange_core::VersionConflict;
 use monochange_core::VersionGroup;
+use monochange_core::VersionScheme;
 use semver::Version;
 
 use crate::NormalizedGraph;
+use crate::ReleasePlanOptions;
 use crate::build_release_plan;
+use crate::build_release_plan_with_options;
 
 fn package(id: &str, version: Version) -> PackageRecord {
 	let manifest_path = PathBuf::from(id.replace(':', "/")).join("manifest");
@@ -74,6 +88,43 @@ fn transitive_dependents_handles_cycles_without_looping_forever() {
 	assert_eq!(dependents.len(), 2);
 }
 
+#[test]
+fn publish_levels_place_packages_one_level_above_their_deepest_dependency() {
+	let packages = [
+		package("core", Version::new(1, 0, 0)),
+		package("util", Version::new(1, 0, 0)),
+		package("api", Version::new(1, 0, 0)),
+		package("app", Version::new(1, 0, 0)),
+		package("loop-a", Version::new(1, 0, 0)),
+		package("loop-b", Version::new(1, 0, 0)),
+		package("on-loop", Version::new(1, 0, 0)),
+	];
+	let mut dev_edge = edge("core", "app");
+	dev_edge.dependency_kind = DependencyKind::Development;
+	let edges = [
+		edge("api", "core"),
+		edge("app", "api"),
+		edge("app", "util"),
+		edge("app", "external"),
+		dev_edge,
+		edge("loop-a", "loop-b"),
+		edge("loop-b", "loop-a"),
+		edge("on-loop", "loop-a"),
+	];
+
+	let levels = crate::publish_levels(&packages, &edges);
+
+	assert_eq!(
+		levels,
+		BTreeMap::from([
+			("api".to_string(), 1),
+			("app".to_string(), 2),
+			("core".to_string(), 0),
+			("util".to_string(), 0),
+		])
+	);
+}
+
 #[test]
 fn build_release_plan_patches_direct_parents_when_a_dependency_changes() {
 	let packages = vec![
@@ -448,6 +499,14 @@ fn build_release_plan_uses_highest_conflicting_explicit_version_with_warning() {
 		.unwrap_or_else(|| panic!("expected one warning"));
 	assert_eq!(decision.planned_version, Some(Version::new(2, 0, 0)));
 	assert_eq!(plan.warnings.len(), 1);
+	assert_eq!(
+		plan.version_conflicts,
+		vec![VersionConflict {
+			owner_kind: ReleaseOwnerKind::Package,
+			owner_id: "cargo:core".to_string(),
+			message: warning.clone(),
+		}]
+	);
 	insta::assert_snapshot!(
 		"build_release_plan_conflicting_explicit_versions_warning",
 		warning
@@ -827,3 +886,559 @@ fn build_release_plan_suppresses_matching_dependency_propagation_when_caused_by_
 		vec!["cargo:core".to_string(), "cargo:util".to_string()]
 	);
 }
+
+fn change_signal(package_id: &str, bump: BumpSeverity) -> ChangeSignal {
+	ChangeSignal {
+		package_id: package_id.to_string(),
+		requested_bump: Some(bump),
+		explicit_version: None,
+		change_origin: "direct-change".to_string(),
+		evidence_refs: Vec::new(),
+		notes: Some("change".to_string()),
+		details: None,
+		change_type: None,
+		caused_by: Vec::new(),
+		source_path: PathBuf::from(".changeset/change.md"),
+	}
+}
+
+#[test]
+fn build_release_plan_with_options_plans_prerelease_versions_for_channel_owners() {
+	let packages = vec![
+		package("cargo:core", Version::new(1, 4, 0)),
+		package("cargo:app", Version::new(1, 0, 0)),
+	];
+	let options = ReleasePlanOptions {
+		default_parent_bump: BumpSeverity::Patch,
+		prerelease_channels: BTreeMap::from([("cargo:core".to_string(), "beta".to_string())]),
+		..ReleasePlanOptions::default()
+	};
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&packages,
+		&[edge("cargo:app", "cargo:core")],
+		&[],
+		&[change_signal("cargo:core", BumpSeverity::Major)],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let core = plan
+		.decisions
+		.iter()
+		.find(|decision| decision.package_id == "cargo:core")
+		.unwrap_or_else(|| panic!("expected core decision"));
+	let app = plan
+		.decisions
+		.iter()
+		.find(|decision| decision.package_id == "cargo:app")
+		.unwrap_or_else(|| panic!("expected app decision"));
+	assert_eq!(
+		core.planned_version,
+		Some(Version::parse("2.0.0-beta.1").unwrap())
+	);
+	assert_eq!(app.planned_version, Some(Version::new(1, 0, 1)));
+}
+
+#[test]
+fn build_release_plan_with_options_increments_group_prerelease_counters() {
+	let mut core = package("cargo:core", Version::parse("2.0.0-beta.1").unwrap());
+	core.version_group_id = Some("sdk".to_string());
+	let mut web = package("npm:web", Version::parse("2.0.0-beta.1").unwrap());
+	web.version_group_id = Some("sdk".to_string());
+	let version_group = VersionGroup {
+		group_id: "sdk".to_string(),
+		display_name: "sdk".to_string(),
+		members: vec![core.id.clone(), web.id.clone()],
+		mismatch_detected: false,
+	};
+	let options = ReleasePlanOptions {
+		prerelease_channels: BTreeMap::from([("sdk".to_string(), "beta".to_string())]),
+		..ReleasePlanOptions::default()
+	};
+
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/mixed").as_path(),
+		&[core.clone(), web],
+		&[],
+		&[version_group],
+		&[change_signal(&core.id, BumpSeverity::Minor)],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let group = plan
+		.groups
+		.first()
+		.unwrap_or_else(|| panic!("expected planned version group"));
+	assert_eq!(
+		group.planned_version,
+		Some(Version::parse("2.0.0-beta.2").unwrap())
+	);
+}
+
+#[test]
+fn build_release_plan_with_options_rejects_non_numeric_prerelease_counters() {
+	let packages = vec![package("cargo:core", Version::parse("2.0.0-beta.next").unwrap())];
+	let options = ReleasePlanOptions {
+		prerelease_channels: BTreeMap::from([("cargo:core".to_string(), "beta".to_string())]),
+		..ReleasePlanOptions::default()
+	};
+	let error = build_release_plan_with_options(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&packages,
+		&[],
+		&[],
+		&[change_signal("cargo:core", BumpSeverity::Patch)],
+		&[],
+		&options,
+	)
+	.err()
+	.unwrap_or_else(|| panic!("expected non-numeric prerelease counter error"));
+
+	assert_eq!(
+		error.to_string(),
+		"config error: cannot continue prerelease `2.0.0-beta.next` on channel `beta`: `next` is not a numeric counter"
+	);
+}
+
+#[test]
+fn build_release_plan_graduates_prereleases_without_a_channel() {
+	let packages = vec![package("cargo:core", Version::parse("2.0.0-rc.3").unwrap())];
+	let plan = build_release_plan(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&packages,
+		&[],
+		&[],
+		&[change_signal("cargo:core", BumpSeverity::Patch)],
+		&[],
+		BumpSeverity::Patch,
+		false,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let core = plan
+		.decisions
+		.first()
+		.unwrap_or_else(|| panic!("expected core decision"));
+	assert_eq!(core.planned_version, Some(Version::new(2, 0, 0)));
+}
+
+#[test]
+fn build_release_plan_with_options_plans_calendar_versions_from_the_release_date() {
+	let packages = vec![
+		package("cargo:core", Version::new(1, 4, 0)),
+		package("cargo:app", Version::new(2026, 10, 0)),
+	];
+	let options = ReleasePlanOptions {
+		default_parent_bump: BumpSeverity::Patch,
+		version_schemes: BTreeMap::from([(
+			"cargo:app".to_string(),
+			VersionScheme::Calver {
+				format: CalverFormat::default(),
+			},
+		)]),
+		release_date: NaiveDate::from_ymd_opt(2026, 10, 17),
+		..ReleasePlanOptions::default()
+	};
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&packages,
+		&[edge("cargo:app", "cargo:core")],
+		&[],
+		&[change_signal("cargo:core", BumpSeverity::Major)],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let core = plan
+		.decisions
+		.iter()
+		.find(|decision| decision.package_id == "cargo:core")
+		.unwrap_or_else(|| panic!("expected core decision"));
+	let app = plan
+		.decisions
+		.iter()
+		.find(|decision| decision.package_id == "cargo:app")
+		.unwrap_or_else(|| panic!("expected app decision"));
+	assert_eq!(core.planned_version, Some(Version::new(2, 0, 0)));
+	// The propagated patch bump only decides that `app` releases; the
+	// calendar decides its version.
+	assert_eq!(app.recommended_bump, BumpSeverity::Patch);
+	assert_eq!(app.planned_version, Some(Version::new(2026, 10, 1)));
+}
+
+#[test]
+fn build_release_plan_with_options_plans_calendar_versions_for_groups() {
+	let mut chart = package("cargo:chart", Version::new(26, 3, 2));
+	chart.version_group_id = Some("bundle".to_string());
+	let version_group = VersionGroup {
+		group_id: "bundle".to_string(),
+		display_name: "bundle".to_string(),
+		members: vec![chart.id.clone()],
+		mismatch_detected: false,
+	};
+	let options = ReleasePlanOptions {
+		version_schemes: BTreeMap::from([(
+			"bundle".to_string(),
+			VersionScheme::Calver {
+				format: CalverFormat::parse("YY.MM.PATCH").unwrap(),
+			},
+		)]),
+		prerelease_channels: BTreeMap::from([("bundle".to_string(), "rc".to_string())]),
+		release_date: NaiveDate::from_ymd_opt(2026, 10, 17),
+		..ReleasePlanOptions::default()
+	};
+
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&[chart.clone()],
+		&[],
+		&[version_group],
+		&[change_signal(&chart.id, BumpSeverity::Major)],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let group = plan
+		.groups
+		.first()
+		.unwrap_or_else(|| panic!("expected planned version group"));
+	assert_eq!(
+		group.planned_version,
+		Some(Version::parse("26.10.0-rc.1").unwrap())
+	);
+}
+
+#[test]
+fn build_release_plan_with_options_applies_pre_stable_policies_and_graduations() {
+	let packages = vec![
+		package("cargo:core", Version::new(0, 4, 2)),
+		package("cargo:cli", Version::new(0, 3, 1)),
+		package("cargo:web", Version::new(0, 9, 0)),
+	];
+	let options = ReleasePlanOptions {
+		pre_stable_policies: BTreeMap::from([("cargo:core".to_string(), PreStablePolicy::Semver)]),
+		graduations: BTreeSet::from(["cargo:web".to_string()]),
+		..ReleasePlanOptions::default()
+	};
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&packages,
+		&[],
+		&[],
+		&[
+			change_signal("cargo:core", BumpSeverity::Major),
+			change_signal("cargo:cli", BumpSeverity::Major),
+			change_signal("cargo:web", BumpSeverity::Patch),
+		],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let planned_version = |package_id: &str| {
+		plan.decisions
+			.iter()
+			.find(|decision| decision.package_id == package_id)
+			.and_then(|decision| decision.planned_version.clone())
+			.unwrap_or_else(|| panic!("expected planned version for {package_id}"))
+	};
+	assert_eq!(planned_version("cargo:core"), Version::new(1, 0, 0));
+	assert_eq!(planned_version("cargo:cli"), Version::new(0, 4, 0));
+	assert_eq!(planned_version("cargo:web"), Version::new(1, 0, 0));
+}
+
+fn ranged_edge(from: &str, to: &str, constraint: &str) -> DependencyEdge {
+	DependencyEdge {
+		version_constraint: Some(constraint.to_string()),
+		..edge(from, to)
+	}
+}
+
+#[test]
+fn build_release_plan_with_options_only_propagates_out_of_range_dependents() {
+	let packages = vec![
+		package("cargo:core", Version::new(1, 2, 0)),
+		package("cargo:caret", Version::new(1, 0, 0)),
+		package("cargo:tilde", Version::new(1, 0, 0)),
+		package("cargo:unversioned", Version::new(1, 0, 0)),
+		package("cargo:leaf", Version::new(1, 0, 0)),
+	];
+	let edges = [
+		ranged_edge("cargo:caret", "cargo:core", "1.2"),
+		ranged_edge("cargo:tilde", "cargo:core", "~1.2.0"),
+		edge("cargo:unversioned", "cargo:core"),
+		ranged_edge("cargo:leaf", "cargo:caret", "1"),
+	];
+	let plan_for = |propagation, bump| {
+		let options = ReleasePlanOptions {
+			default_parent_bump: BumpSeverity::Patch,
+			propagation,
+			..ReleasePlanOptions::default()
+		};
+		build_release_plan_with_options(
+			PathBuf::from("fixtures/cargo").as_path(),
+			&packages,
+			&edges,
+			&[],
+			&[change_signal("cargo:core", bump)],
+			&[],
+			&options,
+		)
+		.unwrap_or_else(|error| panic!("release plan: {error}"))
+	};
+	let released = |plan: &monochange_core::ReleasePlan| {
+		plan.decisions
+			.iter()
+			.filter(|decision| decision.recommended_bump.is_release())
+			.map(|decision| decision.package_id.clone())
+			.collect::<BTreeSet<_>>()
+	};
+
+	let plan = plan_for(DependencyPropagation::OutOfRange, BumpSeverity::Minor);
+	assert_eq!(
+		released(&plan),
+		BTreeSet::from(["cargo:core", "cargo:tilde", "cargo:unversioned"].map(String::from))
+	);
+
+	let plan = plan_for(DependencyPropagation::OutOfRange, BumpSeverity::Major);
+	assert_eq!(
+		released(&plan),
+		BTreeSet::from(
+			[
+				"cargo:caret",
+				"cargo:core",
+				"cargo:tilde",
+				"cargo:unversioned",
+			]
+			.map(String::from)
+		)
+	);
+
+	let plan = plan_for(DependencyPropagation::Always, BumpSeverity::Minor);
+	assert_eq!(
+		released(&plan),
+		BTreeSet::from(
+			[
+				"cargo:caret",
+				"cargo:core",
+				"cargo:leaf",
+				"cargo:tilde",
+				"cargo:unversioned",
+			]
+			.map(String::from)
+		)
+	);
+}
+
+#[test]
+fn build_release_plan_with_options_checks_ranges_against_the_group_version() {
+	let mut core = package("cargo:core", Version::new(1, 2, 0));
+	core.version_group_id = Some("sdk".to_string());
+	let mut macros = package("cargo:macros", Version::new(1, 2, 0));
+	macros.version_group_id = Some("sdk".to_string());
+	let packages = vec![core, macros, package("cargo:app", Version::new(1, 0, 0))];
+	let groups = [VersionGroup {
+		group_id: "sdk".to_string(),
+		display_name: "sdk".to_string(),
+		members: vec!["cargo:core".to_string(), "cargo:macros".to_string()],
+		mismatch_detected: false,
+	}];
+	let options = ReleasePlanOptions {
+		default_parent_bump: BumpSeverity::Patch,
+		propagation: DependencyPropagation::OutOfRange,
+		..ReleasePlanOptions::default()
+	};
+	// `macros` only asks for a patch, but the group releases 2.0.0, which the
+	// `1.2` requirement in `app` rejects.
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&packages,
+		&[ranged_edge("cargo:app", "cargo:macros", "1.2")],
+		&groups,
+		&[
+			change_signal("cargo:macros", BumpSeverity::Patch),
+			change_signal("cargo:core", BumpSeverity::Major),
+		],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let app = plan
+		.decisions
+		.iter()
+		.find(|decision| decision.package_id == "cargo:app")
+		.unwrap_or_else(|| panic!("expected app decision"));
+	assert_eq!(app.recommended_bump, BumpSeverity::Patch);
+	assert_eq!(app.planned_version, Some(Version::new(1, 0, 1)));
+}
+
+#[test]
+fn build_release_plan_with_options_applies_propagation_rules_per_dependency_kind() {
+	let packages = vec![
+		package("npm:core", Version::new(1, 2, 0)),
+		package("npm:tests", Version::new(1, 0, 0)),
+		package("npm:plugin", Version::new(1, 0, 0)),
+		package("npm:bundler", Version::new(1, 0, 0)),
+		package("npm:legacy", Version::new(1, 0, 0)),
+	];
+	let kind_edge = |from: &str, kind| {
+		DependencyEdge {
+			dependency_kind: kind,
+			..edge(from, "npm:core")
+		}
+	};
+	let edges = [
+		kind_edge("npm:tests", DependencyKind::Development),
+		kind_edge("npm:plugin", DependencyKind::Peer),
+		kind_edge("npm:plugin", DependencyKind::Development),
+		kind_edge("npm:bundler", DependencyKind::Build),
+		kind_edge("npm:legacy", DependencyKind::Development),
+	];
+	let rules = PropagationByKind {
+		development: DependencyKindRule::None,
+		build: DependencyKindRule::Patch,
+		peer: DependencyKindRule::Match,
+		..PropagationByKind::default()
+	};
+	let options = ReleasePlanOptions {
+		default_parent_bump: BumpSeverity::Minor,
+		propagation_by_kind: ["npm:tests", "npm:plugin", "npm:bundler"]
+			.into_iter()
+			.map(|package_id| (package_id.to_string(), rules))
+			.collect(),
+		..ReleasePlanOptions::default()
+	};
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/npm").as_path(),
+		&packages,
+		&edges,
+		&[],
+		&[change_signal("npm:core", BumpSeverity::Major)],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let decision = |package_id: &str| {
+		plan.decisions
+			.iter()
+			.find(|decision| decision.package_id == package_id)
+			.unwrap_or_else(|| panic!("expected decision for {package_id}"))
+	};
+	assert_eq!(decision("npm:tests").recommended_bump, BumpSeverity::None);
+	assert_eq!(decision("npm:plugin").recommended_bump, BumpSeverity::Major);
+	assert_eq!(
+		decision("npm:plugin").reasons,
+		["depends on `npm:core` as a peer dependency (`match` propagation)"]
+	);
+	assert_eq!(
+		decision("npm:plugin").causes,
+		[ReleaseCause::Dependency {
+			package_id: "npm:core".to_string(),
+			dependency_kind: DependencyKind::Peer,
+		}]
+	);
+	assert_eq!(
+		decision("npm:bundler").recommended_bump,
+		BumpSeverity::Patch
+	);
+	assert_eq!(
+		decision("npm:bundler").reasons,
+		["depends on `npm:core` as a build dependency (`patch` propagation)"]
+	);
+	// Packages without rules keep applying `parent_bump` to every kind.
+	assert_eq!(decision("npm:legacy").recommended_bump, BumpSeverity::Minor);
+	assert_eq!(decision("npm:legacy").reasons, ["depends on `npm:core`"]);
+}
+
+#[test]
+fn build_release_plan_with_options_shares_linked_group_severity_between_releasing_members() {
+	let packages = vec![
+		package("cargo:core", Version::new(1, 0, 0)),
+		package("cargo:cli", Version::new(2, 3, 0)),
+		package("cargo:docs", Version::new(0, 5, 0)),
+		package("cargo:app", Version::new(1, 0, 0)),
+	];
+	let options = ReleasePlanOptions {
+		default_parent_bump: BumpSeverity::Patch,
+		linked_groups: BTreeMap::from([(
+			"tools".to_string(),
+			BTreeSet::from([
+				"cargo:core".to_string(),
+				"cargo:cli".to_string(),
+				"cargo:docs".to_string(),
+			]),
+		)]),
+		..ReleasePlanOptions::default()
+	};
+	let plan = build_release_plan_with_options(
+		PathBuf::from("fixtures/cargo").as_path(),
+		&packages,
+		&[edge("cargo:app", "cargo:core")],
+		&[],
+		&[
+			change_signal("cargo:cli", BumpSeverity::Patch),
+			change_signal("cargo:core", BumpSeverity::Minor),
+		],
+		&[],
+		&options,
+	)
+	.unwrap_or_else(|error| panic!("release plan: {error}"));
+
+	let decisions = plan
+		.decisions
+		.iter()
+		.map(|decision| {
+			(
+				decision.package_id.as_str(),
+				decision.recommended_bump,
+				decision.planned_version.clone(),
+			)
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(
+		decisions,
+		[
+			(
+				"cargo:core",
+				BumpSeverity::Minor,
+				Some(Version::new(1, 1, 0))
+			),
+			(
+				"cargo:cli",
+				BumpSeverity::Minor,
+				Some(Version::new(2, 4, 0))
+			),
+			("cargo:docs", BumpSeverity::None, None),
+			(
+				"cargo:app",
+				BumpSeverity::Patch,
+				Some(Version::new(1, 0, 1))
+			),
+		]
+	);
+	let cli = plan
+		.decisions
+		.iter()
+		.find(|decision| decision.package_id == "cargo:cli")
+		.unwrap_or_else(|| panic!("expected cli decision"));
+	assert!(
+		cli.reasons
+			.iter()
+			.any(|reason| reason == "shares linked group `tools`")
+	);
+	assert_eq!(
+		cli.causes,
+		[ReleaseCause::LinkedGroup {
+			group_id: "tools".to_string(),
+		}]
+	);
+	assert!(plan.groups.is_empty());
+}
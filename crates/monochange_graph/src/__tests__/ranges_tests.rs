@@ -0,0 +1,188 @@
+use monochange_core::Ecosystem;
+use semver::Version;
+
+use super::constraint_allows;
+
+fn assert_ranges(ecosystem: Ecosystem, cases: &[(&str, &str, Option<bool>)]) {
+	for (constraint, version, expected) in cases {
+		let version = Version::parse(version).unwrap_or_else(|error| panic!("version: {error}"));
+		assert_eq!(
+			constraint_allows(ecosystem, constraint, &version),
+			*expected,
+			"{ecosystem:?} `{constraint}` against {version}"
+		);
+	}
+}
+
+#[test]
+fn cargo_ranges_follow_cargo_requirement_semantics() {
+	assert_ranges(
+		Ecosystem::Cargo,
+		&[
+			("1.2", "1.9.0", Some(true)),
+			("1.2", "2.0.0", Some(false)),
+			("0.4.2", "0.4.9", Some(true)),
+			("0.4.2", "0.5.0", Some(false)),
+			("~1.2.3", "1.3.0", Some(false)),
+			("=1.2.3", "1.2.4", Some(false)),
+			(">=1.0, <3", "2.5.0", Some(true)),
+			("*", "7.0.0", Some(true)),
+			("^1.2.3", "1.3.0-beta.1", Some(false)),
+			("not a range", "1.0.0", None),
+		],
+	);
+}
+
+#[test]
+fn npm_ranges_support_carets_tildes_x_ranges_hyphens_and_unions() {
+	assert_ranges(
+		Ecosystem::Npm,
+		&[
+			("^1.2.3", "1.9.0", Some(true)),
+			("^1.2.3", "2.0.0", Some(false)),
+			("^0.2.3", "0.2.9", Some(true)),
+			("^0.2.3", "0.3.0", Some(false)),
+			("^0.0.3", "0.0.4", Some(false)),
+			("~1.2.3", "1.2.9", Some(true)),
+			("~1.2.3", "1.3.0", Some(false)),
+			("1.x", "1.7.0", Some(true)),
+			("1.2", "1.3.0", Some(false)),
+			("1.2.3", "1.2.3", Some(true)),
+			(">= 1.0.0 < 2", "1.5.0", Some(true)),
+			(">1.2", "1.2.9", Some(false)),
+			("<=1.2", "1.2.9", Some(true)),
+			("1.0.0 - 1.4", "1.4.7", Some(true)),
+			("1.0.0 - 1.4.0", "1.4.1", Some(false)),
+			("^1.0.0 || ^2.0.0", "2.3.0", Some(true)),
+			("^2.0.0-beta.1", "2.0.0-beta.2", Some(true)),
+			("^1.0.0", "2.0.0-beta.1", Some(false)),
+			("*", "3.0.0", Some(true)),
+			("workspace:*", "9.0.0", Some(true)),
+			("workspace:^1.0.0", "2.0.0", Some(false)),
+			("npm:other@^1.0.0", "1.1.0", Some(true)),
+			("file:../core", "1.0.0", None),
+		],
+	);
+}
+
+#[test]
+fn deno_ranges_read_the_range_from_jsr_and_npm_specifiers() {
+	assert_ranges(
+		Ecosystem::Deno,
+		&[
+			("jsr:@scope/core@^1.2.0", "1.4.0", Some(true)),
+			("jsr:@scope/core@^1.2.0", "2.0.0", Some(false)),
+			("npm:left-pad@~1.3.0", "1.3.4", Some(true)),
+			("./core/mod.ts", "1.0.0", None),
+		],
+	);
+}
+
+#[test]
+fn dart_ranges_treat_zero_major_carets_as_minor_compatible() {
+	assert_ranges(
+		Ecosystem::Dart,
+		&[
+			("^1.2.3", "1.9.0", Some(true)),
+			("^0.0.3", "0.0.9", Some(true)),
+			("^0.0.3", "0.1.0", Some(false)),
+			(">=1.2.0 <2.0.0", "2.0.0", Some(false)),
+			("any", "4.0.0", Some(true)),
+			("1.2.3", "1.2.4", Some(false)),
+		],
+	);
+}
+
+#[test]
+fn python_ranges_follow_pep_440_specifiers() {
+	assert_ranges(
+		Ecosystem::Python,
+		&[
+			(">=1.0,<2", "1.9.0", Some(true)),
+			(">=1.0,<2", "2.0.0", Some(false)),
+			("~=1.4.2", "1.4.9", Some(true)),
+			("~=1.4.2", "1.5.0", Some(false)),
+			("~=1.4", "1.9.0", Some(true)),
+			("==1.2.*", "1.2.7", Some(true)),
+			("==1.2", "1.2.0", Some(true)),
+			("!=1.3.0, >=1.0", "1.3.0", Some(false)),
+			("(>=2.0)", "2.1.0", Some(true)),
+			("^1.2", "1.8.0", Some(true)),
+			("*", "1.0.0", Some(true)),
+			("!=1.2.*", "1.3.0", None),
+			("==1.0a1", "1.0.0", None),
+		],
+	);
+}
+
+#[test]
+fn go_requirements_are_minimums_within_the_module_major() {
+	assert_ranges(
+		Ecosystem::Go,
+		&[
+			("1.2.0", "1.4.0", Some(true)),
+			("v1.2.0", "1.1.0", Some(false)),
+			("0.3.0", "1.0.0", Some(true)),
+			("1.2.0", "2.0.0", Some(false)),
+			("2.1.0", "2.3.0", Some(true)),
+			("1.2.0", "1.3.0-rc.1", Some(false)),
+			("latest", "1.0.0", None),
+		],
+	);
+}
+
+#[test]
+fn helm_constraints_accept_comma_joined_comparators() {
+	assert_ranges(
+		Ecosystem::Helm,
+		&[
+			("~0.3.0", "0.3.4", Some(true)),
+			("~0.3.0", "0.4.0", Some(false)),
+			(">=1.2.0, <2.0.0", "1.9.0", Some(true)),
+			(">=1.2.0, <2.0.0", "2.0.0", Some(false)),
+			("^0.2.3", "0.2.9", Some(true)),
+			("1.2.x || 2.0.0", "2.0.0", Some(true)),
+			("1.2.3", "1.2.4", Some(false)),
+			("*", "3.0.0", Some(true)),
+		],
+	);
+}
+
+#[test]
+fn ruby_requirements_use_pessimistic_operator_and_zero_padding() {
+	assert_ranges(
+		Ecosystem::Ruby,
+		&[
+			("~> 1.2", "1.9.0", Some(true)),
+			("~> 1.2", "2.0.0", Some(false)),
+			("~> 1.2.3", "1.2.9", Some(true)),
+			("~> 1.2.3", "1.3.0", Some(false)),
+			(">= 1.0, < 2", "1.5.0", Some(true)),
+			(">= 1.0, < 2", "2.0.0", Some(false)),
+			("= 1.2", "1.2.0", Some(true)),
+			("1.2.3", "1.2.4", Some(false)),
+			("!= 1.2.3", "1.2.3", Some(false)),
+			("~> x", "1.0.0", None),
+		],
+	);
+}
+
+#[test]
+fn maven_versions_pin_bare_versions_and_read_bracketed_ranges() {
+	assert_ranges(
+		Ecosystem::Jvm,
+		&[
+			("1.2.0", "1.2.0", Some(true)),
+			("1.2", "1.2.0", Some(true)),
+			("1.2.0", "1.3.0", Some(false)),
+			("[1.0,2.0)", "1.9.9", Some(true)),
+			("[1.0,2.0)", "2.0.0", Some(false)),
+			("[1.0,2.0]", "2.0.0", Some(true)),
+			("(1.0,)", "1.0.0", Some(false)),
+			("[1.5]", "1.5.0", Some(true)),
+			("(,1.0],[1.2,)", "1.1.0", Some(false)),
+			("(,1.0],[1.2,)", "3.0.0", Some(true)),
+			("${project.version}", "1.0.0", None),
+		],
+	);
+}
@@ -23,12 +23,16 @@
 //!
 //! - `NormalizedGraph` builds adjacency and reverse-dependency views over package data
 //! - `build_release_plan(workspace_root, packages, dependency_edges, defaults, version_groups, change_signals, providers)` computes the release plan
+//! - `build_release_plan_with_options(..., &ReleasePlanOptions)` computes the release plan with extra planning knobs such as prerelease channels, calendar versioning, and range-aware dependency propagation
+//! - `constraint_allows(ecosystem, constraint, version)` checks a version against a dependency range declared in a cargo, npm, Deno, pub, Python, or Go manifest
+//! - `publish_levels(packages, dependency_edges)` groups packages into the levels at which they can be published, ignoring development dependencies
 //!
 //! ## Responsibilities
 //!
 //! - build reverse dependency views
 //! - propagate release impact across direct and transitive dependents
 //! - synchronize version groups
+//! - share bump severity across linked groups
 //! - calculate planned group versions
 //! <!-- {/monochangeGraphCrateDocs} -->
 
@@ -38,22 +42,37 @@ use std::collections::VecDeque;
 use std::path::Path;
 use std::path::PathBuf;
 
+use chrono::NaiveDate;
 use monochange_core::BumpSeverity;
 use monochange_core::ChangeSignal;
 use monochange_core::CompatibilityAssessment;
 use monochange_core::DependencyEdge;
+use monochange_core::DependencyKind;
+use monochange_core::DependencyKindRule;
+use monochange_core::DependencyPropagation;
+use monochange_core::Ecosystem;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageRecord;
 use monochange_core::PlannedVersionGroup;
+use monochange_core::PreStablePolicy;
+use monochange_core::PropagationByKind;
+use monochange_core::ReleaseCause;
 use monochange_core::ReleaseDecision;
+use monochange_core::ReleaseOwnerKind;
 use monochange_core::ReleasePlan;
+use monochange_core::VersionConflict;
 use monochange_core::VersionGroup;
+use monochange_core::VersionScheme;
 use monochange_semver::direct_release_severity;
 use monochange_semver::propagated_release_severity;
 use monochange_semver::strongest_assessment_for_package;
 use semver::Version;
 
+mod ranges;
+
+pub use ranges::constraint_allows;
+
 /// Reverse-dependency graph over discovered packages.
 ///
 /// `NormalizedGraph` borrows string slices from the input `PackageRecord` and
@@ -71,6 +90,7 @@ struct DecisionState {
 	severity: BumpSeverity,
 	trigger_type: String,
 	reasons: BTreeSet<String>,
+	causes: BTreeSet<ReleaseCause>,
 	upstream_sources: BTreeSet<String>,
 	warnings: Vec<String>,
 }
@@ -81,6 +101,7 @@ impl Default for DecisionState {
 			severity: BumpSeverity::None,
 			trigger_type: "none".to_string(),
 			reasons: BTreeSet::new(),
+			causes: BTreeSet::new(),
 			upstream_sources: BTreeSet::new(),
 			warnings: Vec::new(),
 		}
@@ -140,8 +161,140 @@ impl<'a> NormalizedGraph<'a> {
 	}
 }
 
+/// Assign every package the level at which it can be published.
+///
+/// Level `0` holds packages without internal dependencies; every other
+/// package sits one level above its deepest dependency, so all packages on
+/// one level can publish together once the previous levels are out.
+/// Development dependencies never have to be published first and are
+/// ignored. Packages that sit on, or depend on, a dependency cycle have no
+/// level and are left out of the result.
+#[must_use]
+pub fn publish_levels(
+	packages: &[PackageRecord],
+	dependency_edges: &[DependencyEdge],
+) -> BTreeMap<String, usize> {
+	let mut dependencies = packages
+		.iter()
+		.map(|package| (package.id.as_str(), BTreeSet::new()))
+		.collect::<BTreeMap<&str, BTreeSet<&str>>>();
+	for edge in dependency_edges.iter().filter(|edge| {
+		edge.dependency_kind != DependencyKind::Development
+			&& edge.from_package_id != edge.to_package_id
+	}) {
+		if let Some(package_dependencies) = dependencies.get_mut(edge.from_package_id.as_str()) {
+			package_dependencies.insert(edge.to_package_id.as_str());
+		}
+	}
+
+	let mut levels = BTreeMap::<String, usize>::new();
+	loop {
+		let ready = dependencies
+			.iter()
+			.filter(|(package_id, _)| !levels.contains_key(**package_id))
+			.filter_map(|(package_id, package_dependencies)| {
+				package_dependencies
+					.iter()
+					.filter(|dependency| dependencies.contains_key(**dependency))
+					.map(|dependency| levels.get(*dependency).map(|level| level + 1))
+					.try_fold(0, |deepest, level| level.map(|level| deepest.max(level)))
+					.map(|level| (package_id.to_string(), level))
+			})
+			.collect::<Vec<_>>();
+		if ready.is_empty() {
+			return levels;
+		}
+		levels.extend(ready);
+	}
+}
+
+/// Planning knobs that apply across the whole release plan.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ReleasePlanOptions {
+	/// Severity applied to direct dependents of a released package.
+	pub default_parent_bump: BumpSeverity,
+	/// Whether every dependent receives `default_parent_bump` or only those
+	/// whose declared range no longer admits the dependency's planned version.
+	pub propagation: DependencyPropagation,
+	/// Propagation rules per dependency kind keyed by dependent package id.
+	/// Packages without an entry apply `default_parent_bump` to every kind.
+	pub propagation_by_kind: BTreeMap<String, PropagationByKind>,
+	/// Fail instead of warning when changesets request conflicting explicit versions.
+	pub strict_version_conflicts: bool,
+	/// Prerelease channel (for example `beta`) keyed by package id or version
+	/// group id. Matching owners receive prerelease versions such as
+	/// `2.0.0-beta.1` instead of stable versions.
+	pub prerelease_channels: BTreeMap<String, String>,
+	/// Version scheme keyed by package id or version group id. Owners without
+	/// an entry use semver.
+	pub version_schemes: BTreeMap<String, VersionScheme>,
+	/// Release date used to compute calendar versions. Defaults to today.
+	pub release_date: Option<NaiveDate>,
+	/// Pre-1.0 bump policy keyed by package id or version group id. Owners
+	/// without an entry use [`PreStablePolicy::Shift`].
+	pub pre_stable_policies: BTreeMap<String, PreStablePolicy>,
+	/// Package ids or version group ids that graduate from `0.x` to `1.0.0`
+	/// when they release, regardless of the requested severity.
+	pub graduations: BTreeSet<String>,
+	/// Member package ids keyed by linked group id. Releasing members of a
+	/// linked group receive the strongest severity among them while keeping
+	/// their own versions.
+	pub linked_groups: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl ReleasePlanOptions {
+	/// Compute the next version of the package or version group `owner_id`.
+	///
+	/// Semver owners move by `severity` under their pre-1.0 policy, and
+	/// graduating `0.x` owners jump to `1.0.0`. Calendar-versioned owners
+	/// derive the version from the release date, so `severity` only has to be
+	/// a release.
+	fn next_version(
+		&self,
+		owner_id: &str,
+		severity: BumpSeverity,
+		version: &Version,
+	) -> MonochangeResult<Version> {
+		let prerelease_channel = self.prerelease_channels.get(owner_id);
+		match self.version_schemes.get(owner_id) {
+			Some(VersionScheme::Calver { format }) if severity.is_release() => {
+				let release_date = self
+					.release_date
+					.unwrap_or_else(|| chrono::Local::now().date_naive());
+				match prerelease_channel {
+					Some(channel) => {
+						format.next_prerelease_version(Some(version), release_date, channel)
+					}
+					None => format.next_version(Some(version), release_date),
+				}
+			}
+			_ => {
+				let (severity, policy) = if severity.is_release()
+					&& BumpSeverity::is_pre_stable(version)
+					&& self.graduations.contains(owner_id)
+				{
+					(BumpSeverity::Major, PreStablePolicy::Semver)
+				} else {
+					(
+						severity,
+						self.pre_stable_policies
+							.get(owner_id)
+							.copied()
+							.unwrap_or_default(),
+					)
+				};
+				match prerelease_channel {
+					Some(channel) => {
+						severity.apply_to_prerelease_version_with_policy(version, channel, policy)
+					}
+					None => Ok(severity.apply_to_version_with_policy(version, policy)),
+				}
+			}
+		}
+	}
+}
+
 #[allow(clippy::too_many_arguments)]
-#[tracing::instrument(skip_all)]
 /// Compute a release plan from normalized packages, changes, and compatibility evidence.
 #[must_use = "the release plan result must be checked"]
 pub fn build_release_plan(
@@ -154,6 +307,35 @@ pub fn build_release_plan(
 	default_parent_bump: BumpSeverity,
 	strict_version_conflicts: bool,
 ) -> MonochangeResult<ReleasePlan> {
+	build_release_plan_with_options(
+		workspace_root,
+		packages,
+		dependency_edges,
+		version_groups,
+		change_signals,
+		compatibility_evidence,
+		&ReleasePlanOptions {
+			default_parent_bump,
+			strict_version_conflicts,
+			..ReleasePlanOptions::default()
+		},
+	)
+}
+
+#[tracing::instrument(skip_all)]
+/// Compute a release plan using the planning knobs in `options`.
+#[must_use = "the release plan result must be checked"]
+pub fn build_release_plan_with_options(
+	workspace_root: &Path,
+	packages: &[PackageRecord],
+	dependency_edges: &[DependencyEdge],
+	version_groups: &[VersionGroup],
+	change_signals: &[ChangeSignal],
+	compatibility_evidence: &[CompatibilityAssessment],
+	options: &ReleasePlanOptions,
+) -> MonochangeResult<ReleasePlan> {
+	let default_parent_bump = options.default_parent_bump;
+	let strict_version_conflicts = options.strict_version_conflicts;
 	let graph = NormalizedGraph::new(packages, dependency_edges);
 	let package_by_id = packages
 		.iter()
@@ -164,14 +346,22 @@ pub fn build_release_plan(
 		.map(|group| (group.group_id.as_str(), group))
 		.collect::<BTreeMap<_, _>>();
 
-	let (explicit_package_versions, explicit_group_versions, warnings) = resolve_explicit_versions(
-		&package_by_id,
-		&group_by_id,
-		change_signals,
-		strict_version_conflicts,
-	)?;
+	let (explicit_package_versions, explicit_group_versions, version_conflicts) =
+		resolve_explicit_versions(
+			&package_by_id,
+			&group_by_id,
+			change_signals,
+			strict_version_conflicts,
+		)?;
 	let propagation_suppression =
 		build_propagation_suppression(change_signals, &package_by_id, &group_by_id);
+	let mut edges_by_pair = BTreeMap::<(&str, &str), Vec<&DependencyEdge>>::new();
+	for edge in dependency_edges {
+		edges_by_pair
+			.entry((edge.from_package_id.as_str(), edge.to_package_id.as_str()))
+			.or_default()
+			.push(edge);
+	}
 
 	let mut states = packages
 		.iter()
@@ -207,6 +397,7 @@ pub fn build_release_plan(
 			direct_severity,
 			"direct-change",
 			&reason,
+			None,
 			&upstream_sources,
 		);
 	}
@@ -228,25 +419,90 @@ pub fn build_release_plan(
 		let propagated_severity =
 			propagated_release_severity(default_parent_bump, source_assessment.as_ref());
 
-		if propagated_severity.is_release() {
-			for dependent_id in graph.direct_dependents(source_package_id) {
-				if propagation_is_suppressed(
-					dependent_id,
-					&source_state.upstream_sources,
-					&propagation_suppression,
-				) {
-					continue;
+		let source_version = match options.propagation {
+			DependencyPropagation::OutOfRange => {
+				tentative_version(
+					source_package_id,
+					&package_by_id,
+					&group_by_id,
+					&states,
+					&explicit_package_versions,
+					&explicit_group_versions,
+					options,
+				)?
+			}
+			_ => None,
+		};
+		for dependent_id in graph.direct_dependents(source_package_id) {
+			if propagation_is_suppressed(
+				dependent_id,
+				&source_state.upstream_sources,
+				&propagation_suppression,
+			) {
+				continue;
+			}
+			let Some((severity, reason, cause)) = dependent_propagation(
+				dependent_id,
+				source_package_id,
+				source_state.severity,
+				propagated_severity,
+				source_version.as_ref(),
+				&package_by_id,
+				&edges_by_pair,
+				options,
+			) else {
+				continue;
+			};
+			apply_decision(
+				&mut states,
+				&mut queue,
+				dependent_id,
+				severity,
+				"transitive-dependency",
+				&reason,
+				Some(cause),
+				&source_state.upstream_sources,
+			);
+		}
+
+		if let Some((linked_group_id, linked_members)) = options
+			.linked_groups
+			.iter()
+			.find(|(_, members)| members.contains(source_package_id))
+		{
+			// Linked members only share the strongest severity among the
+			// members that already release; untouched members stay put.
+			let releasing_members = linked_members
+				.iter()
+				.filter_map(|member| {
+					states
+						.get(member.as_str())
+						.filter(|state| state.severity.is_release())
+						.map(|state| (member.as_str(), state.severity))
+				})
+				.collect::<Vec<_>>();
+			let linked_max = releasing_members
+				.iter()
+				.map(|(_, severity)| *severity)
+				.max()
+				.unwrap_or(BumpSeverity::None);
+			let reason = format!("shares linked group `{linked_group_id}`");
+			let cause = ReleaseCause::LinkedGroup {
+				group_id: linked_group_id.clone(),
+			};
+			for (member_id, severity) in releasing_members {
+				if severity < linked_max {
+					apply_decision(
+						&mut states,
+						&mut queue,
+						member_id,
+						linked_max,
+						"linked-group-synchronization",
+						&reason,
+						Some(cause.clone()),
+						&source_state.upstream_sources,
+					);
 				}
-				let reason = format!("depends on `{source_package_id}`");
-				apply_decision(
-					&mut states,
-					&mut queue,
-					dependent_id,
-					propagated_severity,
-					"transitive-dependency",
-					&reason,
-					&source_state.upstream_sources,
-				);
 			}
 		}
 
@@ -279,6 +535,9 @@ pub fn build_release_plan(
 
 		if group_max.is_release() {
 			let reason = format!("shares version group `{group_id}`");
+			let cause = ReleaseCause::VersionGroup {
+				group_id: group_id.to_string(),
+			};
 			for member_id in &group.members {
 				apply_decision(
 					&mut states,
@@ -287,6 +546,7 @@ pub fn build_release_plan(
 					group_max,
 					"version-group-synchronization",
 					&reason,
+					Some(cause.clone()),
 					&source_state.upstream_sources,
 				);
 			}
@@ -295,8 +555,17 @@ pub fn build_release_plan(
 
 	let planned_groups = version_groups
 		.iter()
-		.filter_map(|group| planned_group(group, &package_by_id, &states, &explicit_group_versions))
-		.collect::<Vec<_>>();
+		.filter_map(|group| {
+			planned_group(
+				group,
+				&package_by_id,
+				&states,
+				&explicit_group_versions,
+				options,
+			)
+			.transpose()
+		})
+		.collect::<MonochangeResult<Vec<_>>>()?;
 	let planned_group_by_id: BTreeMap<&str, &PlannedVersionGroup> = planned_groups
 		.iter()
 		.map(|group| (group.group_id.as_str(), group))
@@ -313,37 +582,45 @@ pub fn build_release_plan(
 			});
 			let standalone_planned_version =
 				if planned_version.is_none() && state.severity.is_release() {
-					explicit_package_versions
-						.get(&package.id)
-						.cloned()
-						.or_else(|| {
+					match explicit_package_versions.get(&package.id) {
+						Some(version) => Some(version.clone()),
+						None => {
 							package
 								.current_version
 								.as_ref()
-								.map(|version| state.severity.apply_to_version(version))
-						})
+								.map(|version| {
+									options.next_version(&package.id, state.severity, version)
+								})
+								.transpose()?
+						}
+					}
 				} else {
 					None
 				};
 
-			ReleaseDecision {
+			Ok(ReleaseDecision {
 				package_id: package.id.clone(),
 				trigger_type: state.trigger_type,
 				recommended_bump: state.severity,
 				planned_version: planned_version.or(standalone_planned_version),
 				group_id: package.version_group_id.clone(),
 				reasons: state.reasons.into_iter().collect(),
+				causes: state.causes.into_iter().collect(),
 				upstream_sources: state.upstream_sources.into_iter().collect(),
 				warnings: state.warnings,
-			}
+			})
 		})
-		.collect();
+		.collect::<MonochangeResult<Vec<_>>>()?;
 
 	Ok(ReleasePlan {
 		workspace_root: workspace_root.to_path_buf(),
 		decisions,
 		groups: planned_groups,
-		warnings,
+		warnings: version_conflicts
+			.iter()
+			.map(|conflict| conflict.message.clone())
+			.collect(),
+		version_conflicts,
 		unresolved_items: Vec::new(),
 		compatibility_evidence: compatibility_evidence.to_vec(),
 	})
@@ -352,7 +629,7 @@ pub fn build_release_plan(
 type ExplicitVersionResolution = (
 	BTreeMap<String, Version>,
 	BTreeMap<String, Version>,
-	Vec<String>,
+	Vec<VersionConflict>,
 );
 
 type PropagationSuppression = BTreeMap<String, BTreeSet<String>>;
@@ -365,7 +642,7 @@ fn resolve_explicit_versions(
 ) -> MonochangeResult<ExplicitVersionResolution> {
 	let mut package_inputs = BTreeMap::<String, Vec<ExplicitVersionInput>>::new();
 	let mut group_inputs = BTreeMap::<String, Vec<ExplicitVersionInput>>::new();
-	let mut warnings = Vec::new();
+	let mut conflicts = Vec::new();
 
 	for signal in change_signals {
 		let Some(version) = signal.explicit_version.clone() else {
@@ -400,13 +677,14 @@ fn resolve_explicit_versions(
 					"changeset references package `{package_id}` which was not found in the workspace"
 				))
 			})?;
-			let owner = format!("package `{package_id}`");
 			resolve_explicit_version_choice(
-				&owner,
+				ReleaseOwnerKind::Package,
+				&package_id,
+				&format!("package `{package_id}`"),
 				&inputs,
 				package.current_version.as_ref(),
 				strict_version_conflicts,
-				&mut warnings,
+				&mut conflicts,
 			)
 			.map(|version| (package_id, version))
 		})
@@ -431,25 +709,29 @@ fn resolve_explicit_versions(
 				group.members.join(", ")
 			);
 			resolve_explicit_version_choice(
+				ReleaseOwnerKind::Group,
+				&group_id,
 				&owner,
 				&inputs,
 				current_version,
 				strict_version_conflicts,
-				&mut warnings,
+				&mut conflicts,
 			)
 			.map(|version| (group_id, version))
 		})
 		.collect::<MonochangeResult<BTreeMap<_, _>>>()?;
 
-	Ok((package_versions, group_versions, warnings))
+	Ok((package_versions, group_versions, conflicts))
 }
 
 fn resolve_explicit_version_choice(
+	owner_kind: ReleaseOwnerKind,
+	owner_id: &str,
 	owner: &str,
 	inputs: &[ExplicitVersionInput],
 	current_version: Option<&Version>,
 	strict_version_conflicts: bool,
-	warnings: &mut Vec<String>,
+	conflicts: &mut Vec<VersionConflict>,
 ) -> MonochangeResult<Version> {
 	let chosen_version = inputs
 		.iter()
@@ -481,7 +763,11 @@ fn resolve_explicit_version_choice(
 		if strict_version_conflicts {
 			return Err(MonochangeError::Config(message));
 		}
-		warnings.push(message);
+		conflicts.push(VersionConflict {
+			owner_kind,
+			owner_id: owner_id.to_string(),
+			message,
+		});
 	}
 	if let Some(current_version) = current_version
 		&& chosen_version <= *current_version
@@ -575,6 +861,7 @@ fn propagation_is_suppressed(
 		})
 }
 
+#[allow(clippy::too_many_arguments)]
 fn apply_decision<'a>(
 	states: &mut BTreeMap<&'a str, DecisionState>,
 	queue: &mut VecDeque<&'a str>,
@@ -582,6 +869,7 @@ fn apply_decision<'a>(
 	new_severity: BumpSeverity,
 	trigger_type: &str,
 	reason: &str,
+	cause: Option<ReleaseCause>,
 	upstream_sources: &BTreeSet<String>,
 ) {
 	let Some(state) = states.get_mut(package_id) else {
@@ -596,6 +884,7 @@ fn apply_decision<'a>(
 		state.trigger_type = trigger_type.to_string();
 	}
 	state.reasons.insert(reason.to_string());
+	state.causes.extend(cause);
 	state
 		.upstream_sources
 		.extend(upstream_sources.iter().cloned());
@@ -604,18 +893,122 @@ fn apply_decision<'a>(
 fn trigger_priority(trigger_type: &str) -> u8 {
 	match trigger_type {
 		"direct-change" => 3,
-		"version-group-synchronization" => 2,
+		"version-group-synchronization" | "linked-group-synchronization" => 2,
 		"transitive-dependency" => 1,
 		_ => 0,
 	}
 }
 
+/// The version `package_id` would release with given the decisions made so
+/// far. Grouped packages share the version of their group.
+fn tentative_version(
+	package_id: &str,
+	package_by_id: &BTreeMap<&str, &PackageRecord>,
+	group_by_id: &BTreeMap<&str, &VersionGroup>,
+	states: &BTreeMap<&str, DecisionState>,
+	explicit_package_versions: &BTreeMap<String, Version>,
+	explicit_group_versions: &BTreeMap<String, Version>,
+	options: &ReleasePlanOptions,
+) -> MonochangeResult<Option<Version>> {
+	let Some(package) = package_by_id.get(package_id) else {
+		return Ok(None);
+	};
+	if let Some(group) = package
+		.version_group_id
+		.as_deref()
+		.and_then(|group_id| group_by_id.get(group_id))
+	{
+		let group = planned_group(
+			group,
+			package_by_id,
+			states,
+			explicit_group_versions,
+			options,
+		)?;
+		return Ok(group.and_then(|group| group.planned_version));
+	}
+	if let Some(version) = explicit_package_versions.get(package_id) {
+		return Ok(Some(version.clone()));
+	}
+	let Some(state) = states.get(package_id) else {
+		return Ok(None);
+	};
+	package
+		.current_version
+		.as_ref()
+		.map(|version| options.next_version(package_id, state.severity, version))
+		.transpose()
+}
+
+/// The bump `dependent_id` receives when `source_id` releases with
+/// `source_bump`, together with the reason and cause to record on its decision.
+///
+/// Every edge from the dependent to the source is weighed by the rule for
+/// its dependency kind and the strongest result wins. When `source_version`
+/// is given, edges whose declared range already admits it are ignored.
+#[allow(clippy::too_many_arguments)]
+fn dependent_propagation(
+	dependent_id: &str,
+	source_id: &str,
+	source_bump: BumpSeverity,
+	parent_bump: BumpSeverity,
+	source_version: Option<&Version>,
+	package_by_id: &BTreeMap<&str, &PackageRecord>,
+	edges_by_pair: &BTreeMap<(&str, &str), Vec<&DependencyEdge>>,
+	options: &ReleasePlanOptions,
+) -> Option<(BumpSeverity, String, ReleaseCause)> {
+	let dependent = package_by_id.get(dependent_id)?;
+	let rules = options
+		.propagation_by_kind
+		.get(dependent_id)
+		.copied()
+		.unwrap_or_default();
+	let (severity, kind, rule) = edges_by_pair
+		.get(&(dependent_id, source_id))?
+		.iter()
+		.filter(|edge| {
+			source_version
+				.is_none_or(|version| !declared_range_allows(edge, dependent.ecosystem, version))
+		})
+		.map(|edge| {
+			let rule = rules.rule_for(edge.dependency_kind);
+			(
+				rule.dependent_bump(parent_bump, source_bump),
+				edge.dependency_kind,
+				rule,
+			)
+		})
+		.max_by_key(|(severity, ..)| *severity)?;
+	if !severity.is_release() {
+		return None;
+	}
+	let reason = match rule {
+		DependencyKindRule::ParentBump => format!("depends on `{source_id}`"),
+		_ => format!("depends on `{source_id}` as a {kind} dependency (`{rule}` propagation)"),
+	};
+	let cause = ReleaseCause::Dependency {
+		package_id: source_id.to_string(),
+		dependency_kind: kind,
+	};
+	Some((severity, reason, cause))
+}
+
+/// Returns `true` when the range declared on `edge` admits `version`. Edges
+/// without a range, or with a range that cannot be parsed, never do.
+fn declared_range_allows(edge: &DependencyEdge, ecosystem: Ecosystem, version: &Version) -> bool {
+	edge.version_constraint
+		.as_deref()
+		.and_then(|constraint| constraint_allows(ecosystem, constraint, version))
+		.unwrap_or(false)
+}
+
 fn planned_group(
 	group: &VersionGroup,
 	package_by_id: &BTreeMap<&str, &PackageRecord>,
 	states: &BTreeMap<&str, DecisionState>,
 	explicit_group_versions: &BTreeMap<String, Version>,
-) -> Option<PlannedVersionGroup> {
+	options: &ReleasePlanOptions,
+) -> MonochangeResult<Option<PlannedVersionGroup>> {
 	let recommended_bump = group
 		.members
 		.iter()
@@ -624,7 +1017,7 @@ fn planned_group(
 		.max()
 		.unwrap_or(BumpSeverity::None);
 	if !recommended_bump.is_release() {
-		return None;
+		return Ok(None);
 	}
 
 	let base_version = group
@@ -633,23 +1026,24 @@ fn planned_group(
 		.filter_map(|member| package_by_id.get(member.as_str()))
 		.filter_map(|package| package.current_version.clone())
 		.max();
-	let planned_version = explicit_group_versions
-		.get(&group.group_id)
-		.cloned()
-		.or_else(|| {
+	let planned_version = match explicit_group_versions.get(&group.group_id) {
+		Some(version) => Some(version.clone()),
+		None => {
 			base_version
 				.as_ref()
-				.map(|version| recommended_bump.apply_to_version(version))
-		});
+				.map(|version| options.next_version(&group.group_id, recommended_bump, version))
+				.transpose()?
+		}
+	};
 
-	Some(PlannedVersionGroup {
+	Ok(Some(PlannedVersionGroup {
 		group_id: group.group_id.clone(),
 		display_name: group.display_name.clone(),
 		members: group.members.clone(),
 		mismatch_detected: group.mismatch_detected,
 		planned_version,
 		recommended_bump,
-	})
+	}))
 }
 
 #[cfg(test)]
@@ -0,0 +1,528 @@
+//! Matching planned versions against the dependency ranges declared in
+//! package manifests.
+//!
+//! Each ecosystem spells ranges differently, so constraints are first
+//! desugared into a disjunction of comparator sets and then evaluated with
+//! semver ordering. Constraints that cannot be understood are reported as
+//! `None` so callers can fall back to the conservative behavior.
+
+use monochange_core::Ecosystem;
+use semver::Version;
+use semver::VersionReq;
+
+/// Returns whether `version` satisfies the dependency `constraint` written in
+/// a manifest of `ecosystem`.
+///
+/// Returns `None` when the constraint uses syntax that cannot be evaluated,
+/// such as path, git, or URL dependencies.
+#[must_use]
+pub fn constraint_allows(
+	ecosystem: Ecosystem,
+	constraint: &str,
+	version: &Version,
+) -> Option<bool> {
+	let constraint = constraint.trim();
+	match ecosystem {
+		Ecosystem::Cargo => cargo_allows(constraint, version),
+		Ecosystem::Npm => npm_allows(constraint, version),
+		Ecosystem::Deno => deno_allows(constraint, version),
+		Ecosystem::Dart | Ecosystem::Flutter => dart_allows(constraint, version),
+		Ecosystem::Python => python_allows(constraint, version),
+		Ecosystem::Go => go_allows(constraint, version),
+		Ecosystem::Helm => helm_allows(constraint, version),
+		Ecosystem::Ruby => ruby_allows(constraint, version),
+		Ecosystem::Jvm => maven_allows(constraint, version),
+		_ => None,
+	}
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Operator {
+	Exact,
+	NotEqual,
+	Greater,
+	GreaterEq,
+	Less,
+	LessEq,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Comparator {
+	operator: Operator,
+	version: Version,
+}
+
+impl Comparator {
+	fn new(operator: Operator, version: Version) -> Self {
+		Self { operator, version }
+	}
+
+	fn matches(&self, version: &Version) -> bool {
+		let ordering = compare(version, &self.version);
+		match self.operator {
+			Operator::Exact => ordering.is_eq(),
+			Operator::NotEqual => !ordering.is_eq(),
+			Operator::Greater => ordering.is_gt(),
+			Operator::GreaterEq => ordering.is_ge(),
+			Operator::Less => ordering.is_lt(),
+			Operator::LessEq => ordering.is_le(),
+		}
+	}
+}
+
+/// A range is satisfied when every comparator of any one set matches.
+type Range = Vec<Vec<Comparator>>;
+
+/// Compare versions by precedence, ignoring build metadata.
+fn compare(left: &Version, right: &Version) -> std::cmp::Ordering {
+	(left.major, left.minor, left.patch, &left.pre).cmp(&(
+		right.major,
+		right.minor,
+		right.patch,
+		&right.pre,
+	))
+}
+
+fn range_allows(range: &Range, version: &Version) -> bool {
+	range.iter().any(|set| {
+		set.iter().all(|comparator| comparator.matches(version))
+			&& (version.pre.is_empty() || set_opts_into_prerelease(set, version))
+	})
+}
+
+/// Prereleases only satisfy a set that names a prerelease of the same
+/// `major.minor.patch`, matching npm, cargo and pub behavior.
+fn set_opts_into_prerelease(set: &[Comparator], version: &Version) -> bool {
+	set.iter().any(|comparator| {
+		!comparator.version.pre.is_empty()
+			&& (
+				comparator.version.major,
+				comparator.version.minor,
+				comparator.version.patch,
+			) == (version.major, version.minor, version.patch)
+	})
+}
+
+/// A possibly incomplete version such as `1`, `1.2`, or `1.x`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+struct Partial {
+	major: Option<u64>,
+	minor: Option<u64>,
+	patch: Option<u64>,
+	pre: semver::Prerelease,
+}
+
+impl Partial {
+	fn parse(text: &str) -> Option<Self> {
+		let text = text.trim().trim_start_matches(['v', '=']).trim();
+		let text = text.split_once('+').map_or(text, |(version, _)| version);
+		let (core, pre) = match text.split_once('-') {
+			Some((core, pre)) => (core, semver::Prerelease::new(pre).ok()?),
+			None => (text, semver::Prerelease::EMPTY),
+		};
+		let mut parts = core.split('.');
+		let mut component = || -> Option<Option<u64>> {
+			match parts.next() {
+				None | Some("x" | "X" | "*") => Some(None),
+				Some(part) => part.parse().ok().map(Some),
+			}
+		};
+		let major = component()?;
+		let minor = major.and(component()?);
+		let patch = minor.and(component()?);
+		if parts.next().is_some() {
+			return None;
+		}
+		Some(Self {
+			major,
+			minor,
+			patch,
+			pre,
+		})
+	}
+
+	fn floor(&self) -> Version {
+		Version {
+			major: self.major.unwrap_or(0),
+			minor: self.minor.unwrap_or(0),
+			patch: self.patch.unwrap_or(0),
+			pre: self.pre.clone(),
+			build: semver::BuildMetadata::EMPTY,
+		}
+	}
+
+	fn is_full(&self) -> bool {
+		self.patch.is_some()
+	}
+
+	/// The first version past the components spelled out in `self`.
+	fn ceiling(&self) -> Option<Version> {
+		match (self.major, self.minor, self.patch) {
+			(Some(major), Some(minor), _) => Some(Version::new(major, minor + 1, 0)),
+			(Some(major), None, _) => Some(Version::new(major + 1, 0, 0)),
+			_ => None,
+		}
+	}
+}
+
+/// Bounds for a bare or `=` partial: `1.2` means `>=1.2.0 <1.3.0`.
+fn x_range(partial: &Partial) -> Vec<Comparator> {
+	if partial.is_full() {
+		return vec![Comparator::new(Operator::Exact, partial.floor())];
+	}
+	let mut set = Vec::new();
+	if partial.major.is_some() {
+		set.push(Comparator::new(Operator::GreaterEq, partial.floor()));
+	}
+	if let Some(ceiling) = partial.ceiling() {
+		set.push(Comparator::new(Operator::Less, ceiling));
+	}
+	set
+}
+
+/// Bounds for `~1.2.3`: patch-level changes are allowed when a minor
+/// component is given, otherwise minor-level changes.
+fn tilde_range(partial: &Partial) -> Vec<Comparator> {
+	let mut set = vec![Comparator::new(Operator::GreaterEq, partial.floor())];
+	if let Some(ceiling) = partial.ceiling() {
+		set.push(Comparator::new(Operator::Less, ceiling));
+	}
+	set
+}
+
+/// Bounds for `^1.2.3`: changes that do not modify the left-most non-zero
+/// component. Pub treats every `0.x` caret as minor-compatible, so
+/// `zero_patch_is_breaking` is `false` there.
+fn caret_range(partial: &Partial, zero_patch_is_breaking: bool) -> Vec<Comparator> {
+	let floor = partial.floor();
+	let ceiling = match (partial.major, partial.minor, partial.patch) {
+		(Some(0), Some(0), Some(patch)) if zero_patch_is_breaking => {
+			Some(Version::new(0, 0, patch + 1))
+		}
+		(Some(0), Some(minor), _) => Some(Version::new(0, minor + 1, 0)),
+		(Some(major), ..) => Some(Version::new(major + 1, 0, 0)),
+		(None, ..) => None,
+	};
+	let mut set = vec![Comparator::new(Operator::GreaterEq, floor)];
+	set.extend(ceiling.map(|ceiling| Comparator::new(Operator::Less, ceiling)));
+	set
+}
+
+/// Desugar a comparison against a partial version, so `>1.2` means `>=1.3.0`
+/// and `<=1.2` means `<1.3.0`.
+fn comparison(operator: Operator, partial: &Partial) -> Option<Vec<Comparator>> {
+	if partial.major.is_none() {
+		return Some(match operator {
+			Operator::Less | Operator::Greater | Operator::NotEqual => {
+				vec![Comparator::new(Operator::Less, Version::new(0, 0, 0))]
+			}
+			_ => Vec::new(),
+		});
+	}
+	if partial.is_full() {
+		return Some(vec![Comparator::new(operator, partial.floor())]);
+	}
+	let ceiling = partial.ceiling()?;
+	Some(match operator {
+		Operator::Greater => vec![Comparator::new(Operator::GreaterEq, ceiling)],
+		Operator::GreaterEq => vec![Comparator::new(Operator::GreaterEq, partial.floor())],
+		Operator::Less => vec![Comparator::new(Operator::Less, partial.floor())],
+		Operator::LessEq => vec![Comparator::new(Operator::Less, ceiling)],
+		Operator::Exact => x_range(partial),
+		Operator::NotEqual => return None,
+	})
+}
+
+fn split_operator(token: &str) -> (&str, &str) {
+	let end = token
+		.find(|character: char| !matches!(character, '<' | '>' | '=' | '!' | '~' | '^'))
+		.unwrap_or(token.len());
+	token.split_at(end)
+}
+
+/// Parse one npm/pub/poetry style comparator token such as `^1.2`, `>=1.0.0`,
+/// or `1.x`.
+fn parse_token(token: &str, zero_patch_is_breaking: bool) -> Option<Vec<Comparator>> {
+	let (operator, version) = split_operator(token);
+	let partial = Partial::parse(version)?;
+	match operator {
+		"" | "=" => Some(x_range(&partial)),
+		"^" => Some(caret_range(&partial, zero_patch_is_breaking)),
+		"~" | "~>" => Some(tilde_range(&partial)),
+		">" => comparison(Operator::Greater, &partial),
+		">=" => comparison(Operator::GreaterEq, &partial),
+		"<" => comparison(Operator::Less, &partial),
+		"<=" => comparison(Operator::LessEq, &partial),
+		_ => None,
+	}
+}
+
+/// Glue detached operators back onto their versions, so `>= 1.2` becomes a
+/// single `>=1.2` token.
+fn tokens(set: &str) -> Vec<String> {
+	let mut tokens = Vec::<String>::new();
+	for word in set.split_whitespace() {
+		match tokens.last_mut() {
+			Some(last) if split_operator(last).1.is_empty() => last.push_str(word),
+			_ => tokens.push(word.to_string()),
+		}
+	}
+	tokens
+}
+
+fn parse_space_separated_set(set: &str, zero_patch_is_breaking: bool) -> Option<Vec<Comparator>> {
+	let set = set.trim();
+	if let Some((low, high)) = set.split_once(" - ") {
+		let low = Partial::parse(low)?;
+		let high = Partial::parse(high)?;
+		let mut comparators = vec![Comparator::new(Operator::GreaterEq, low.floor())];
+		if high.is_full() {
+			comparators.push(Comparator::new(Operator::LessEq, high.floor()));
+		} else if let Some(ceiling) = high.ceiling() {
+			comparators.push(Comparator::new(Operator::Less, ceiling));
+		}
+		return Some(comparators);
+	}
+	let mut comparators = Vec::new();
+	for token in tokens(set) {
+		comparators.extend(parse_token(&token, zero_patch_is_breaking)?);
+	}
+	Some(comparators)
+}
+
+fn cargo_allows(constraint: &str, version: &Version) -> Option<bool> {
+	VersionReq::parse(constraint)
+		.ok()
+		.map(|requirement| requirement.matches(version))
+}
+
+fn npm_allows(constraint: &str, version: &Version) -> Option<bool> {
+	if let Some(protocol_range) = constraint.strip_prefix("workspace:") {
+		// `workspace:*`, `workspace:^` and `workspace:~` are replaced with the
+		// current version at publish time, so they always follow the package.
+		if matches!(protocol_range, "" | "*" | "^" | "~") {
+			return Some(true);
+		}
+		return npm_allows(protocol_range, version);
+	}
+	if let Some(alias) = constraint.strip_prefix("npm:") {
+		let (_, range) = alias.rsplit_once('@')?;
+		return npm_allows(range, version);
+	}
+	if matches!(constraint, "" | "*" | "x" | "X") {
+		return Some(true);
+	}
+	let range = constraint
+		.split("||")
+		.map(|set| parse_space_separated_set(set, true))
+		.collect::<Option<Range>>()?;
+	Some(range_allows(&range, version))
+}
+
+fn deno_allows(constraint: &str, version: &Version) -> Option<bool> {
+	let specifier = constraint
+		.strip_prefix("jsr:")
+		.or_else(|| constraint.strip_prefix("npm:"))?;
+	let (_, range) = specifier.trim_start_matches('@').rsplit_once('@')?;
+	npm_allows(range, version)
+}
+
+fn dart_allows(constraint: &str, version: &Version) -> Option<bool> {
+	if matches!(constraint, "" | "any") {
+		return Some(true);
+	}
+	let set = parse_space_separated_set(constraint, false)?;
+	Some(range_allows(&vec![set], version))
+}
+
+fn python_allows(constraint: &str, version: &Version) -> Option<bool> {
+	let constraint = constraint
+		.trim()
+		.trim_start_matches('(')
+		.trim_end_matches(')')
+		.trim();
+	if matches!(constraint, "" | "*") {
+		return Some(true);
+	}
+	let mut range = Range::new();
+	for set in constraint.split("||") {
+		let mut comparators = Vec::new();
+		for specifier in set.split(',').map(str::trim) {
+			comparators.extend(parse_python_specifier(specifier)?);
+		}
+		range.push(comparators);
+	}
+	Some(range_allows(&range, version))
+}
+
+/// Parse a single PEP 440 specifier. Poetry's `^` and `~` shorthands are
+/// accepted too because `pyproject.toml` files using Poetry declare them.
+fn parse_python_specifier(specifier: &str) -> Option<Vec<Comparator>> {
+	let specifier = specifier.replace(' ', "");
+	if let Some(version) = specifier.strip_prefix("===") {
+		return Some(vec![Comparator::new(
+			Operator::Exact,
+			Version::parse(version).ok()?,
+		)]);
+	}
+	if let Some(version) = specifier.strip_prefix("~=") {
+		// `~=1.4.2` means `>=1.4.2, ==1.4.*`.
+		let partial = Partial::parse(version)?;
+		let (prefix, _) = version.rsplit_once('.')?;
+		let mut comparators = vec![Comparator::new(Operator::GreaterEq, partial.floor())];
+		comparators.extend(x_range(&Partial::parse(prefix)?));
+		return Some(comparators);
+	}
+	if let Some(version) = specifier.strip_prefix("==") {
+		if let Some(prefix) = version.strip_suffix(".*") {
+			return Some(x_range(&Partial::parse(prefix)?));
+		}
+		let partial = Partial::parse(version)?;
+		return Some(vec![Comparator::new(Operator::Exact, partial.floor())]);
+	}
+	if let Some(version) = specifier.strip_prefix("!=") {
+		// Excluding a whole prefix such as `!=1.2.*` splits the range in two,
+		// which a single comparator set cannot express.
+		if version.ends_with(".*") {
+			return None;
+		}
+		let partial = Partial::parse(version)?;
+		return Some(vec![Comparator::new(Operator::NotEqual, partial.floor())]);
+	}
+	let (operator, version) = split_operator(&specifier);
+	let partial = Partial::parse(version)?;
+	match operator {
+		// PEP 440 compares with zero padding, so `<2` and `<2.0.0` agree.
+		">" => Some(vec![Comparator::new(Operator::Greater, partial.floor())]),
+		">=" => Some(vec![Comparator::new(Operator::GreaterEq, partial.floor())]),
+		"<" => Some(vec![Comparator::new(Operator::Less, partial.floor())]),
+		"<=" => Some(vec![Comparator::new(Operator::LessEq, partial.floor())]),
+		"" => Some(vec![Comparator::new(Operator::Exact, partial.floor())]),
+		"^" => Some(caret_range(&partial, true)),
+		"~" => Some(tilde_range(&partial)),
+		_ => None,
+	}
+}
+
+/// Go's minimal version selection treats a `require` as a floor within the
+/// same module path, and the module path changes with every major version
+/// from `v2` onwards.
+fn go_allows(constraint: &str, version: &Version) -> Option<bool> {
+	let required = Version::parse(constraint.trim_start_matches('v')).ok()?;
+	let module_major = |version: &Version| version.major.max(1);
+	if module_major(version) != module_major(&required) {
+		return Some(false);
+	}
+	Some(version.pre.is_empty() && compare(version, &required).is_ge())
+}
+
+/// Helm reads `Chart.yaml` constraints with Masterminds semver: comparators
+/// are joined by commas or spaces and alternatives by `||`, and a `0.x` caret
+/// stays minor-compatible.
+fn helm_allows(constraint: &str, version: &Version) -> Option<bool> {
+	if matches!(constraint, "" | "*" | "x" | "X") {
+		return Some(true);
+	}
+	let range = constraint
+		.split("||")
+		.map(|set| parse_space_separated_set(&set.replace(',', " "), false))
+		.collect::<Option<Range>>()?;
+	Some(range_allows(&range, version))
+}
+
+/// `RubyGems` requirements are comma-joined clauses compared with zero
+/// padding, so `< 2` and `< 2.0.0` agree. The pessimistic `~>` operator drops
+/// the last spelled-out component: `~> 1.2` allows `1.x`, while `~> 1.2.3`
+/// allows `1.2.x`.
+fn ruby_allows(constraint: &str, version: &Version) -> Option<bool> {
+	let mut comparators = Vec::new();
+	for clause in constraint.split(',').map(str::trim) {
+		let clause = clause.replace(' ', "");
+		let (operator, required) = split_operator(&clause);
+		let partial = Partial::parse(required)?;
+		let floor = partial.floor();
+		match operator {
+			"" | "=" => comparators.push(Comparator::new(Operator::Exact, floor)),
+			"!=" => comparators.push(Comparator::new(Operator::NotEqual, floor)),
+			">" => comparators.push(Comparator::new(Operator::Greater, floor)),
+			">=" => comparators.push(Comparator::new(Operator::GreaterEq, floor)),
+			"<" => comparators.push(Comparator::new(Operator::Less, floor)),
+			"<=" => comparators.push(Comparator::new(Operator::LessEq, floor)),
+			"~>" => {
+				let ceiling = match (partial.major, partial.minor, partial.patch) {
+					(Some(major), Some(minor), Some(_)) => Version::new(major, minor + 1, 0),
+					(Some(major), ..) => Version::new(major + 1, 0, 0),
+					(None, ..) => return None,
+				};
+				comparators.push(Comparator::new(Operator::GreaterEq, floor));
+				comparators.push(Comparator::new(Operator::Less, ceiling));
+			}
+			_ => return None,
+		}
+	}
+	Some(range_allows(&vec![comparators], version))
+}
+
+/// Maven versions are either a bare version, which pins internal modules to
+/// that release, or a union of bracketed ranges such as `[1.0,2.0)` and
+/// `(,1.0],[1.2,)`. Missing components are zero padded, and property
+/// references such as `${project.version}` cannot be evaluated.
+fn maven_allows(constraint: &str, version: &Version) -> Option<bool> {
+	if constraint.contains("${") {
+		return None;
+	}
+	if !constraint.starts_with(['[', '(']) {
+		let pinned = Partial::parse(constraint)?.floor();
+		return Some(range_allows(
+			&vec![vec![Comparator::new(Operator::Exact, pinned)]],
+			version,
+		));
+	}
+
+	let mut range = Range::new();
+	let mut rest = constraint;
+	while !rest.is_empty() {
+		let inclusive_floor = rest.starts_with('[');
+		let close = rest.find([']', ')'])?;
+		let inclusive_ceiling = rest.get(close..=close)? == "]";
+		let bounds = rest.get(1..close)?;
+		let set = match bounds.split_once(',') {
+			None => {
+				vec![Comparator::new(
+					Operator::Exact,
+					Partial::parse(bounds)?.floor(),
+				)]
+			}
+			Some((floor, ceiling)) => {
+				let mut set = Vec::new();
+				if !floor.trim().is_empty() {
+					let operator = if inclusive_floor {
+						Operator::GreaterEq
+					} else {
+						Operator::Greater
+					};
+					set.push(Comparator::new(operator, Partial::parse(floor)?.floor()));
+				}
+				if !ceiling.trim().is_empty() {
+					let operator = if inclusive_ceiling {
+						Operator::LessEq
+					} else {
+						Operator::Less
+					};
+					set.push(Comparator::new(operator, Partial::parse(ceiling)?.floor()));
+				}
+				set
+			}
+		};
+		range.push(set);
+		rest = rest.get(close + 1..)?.trim_start_matches([',', ' ']);
+		if !rest.is_empty() && !rest.starts_with(['[', '(']) {
+			return None;
+		}
+	}
+	Some(range_allows(&range, version))
+}
+
+#[cfg(test)]
+#[path = "__tests__/ranges_tests.rs"]
+mod tests;
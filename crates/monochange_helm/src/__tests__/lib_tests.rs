@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_core::DependencyKind;
+use monochange_core::Ecosystem;
+use monochange_core::EcosystemAdapter;
+use monochange_core::PackageRecord;
+use monochange_core::PublishState;
+use monochange_core::ShellConfig;
+use semver::Version;
+use tempfile::tempdir;
+
+use crate::HelmAdapter;
+use crate::HelmVersionedFileKind;
+use crate::adapter;
+use crate::chart_manifest_updates;
+use crate::discover_helm_charts;
+use crate::discover_lockfiles;
+use crate::load_configured_helm_chart;
+use crate::lockfile_refresh_commands;
+use crate::parse_chart;
+use crate::supported_versioned_file_kind;
+use crate::update_chart_text;
+use crate::validate_versioned_file;
+
+fn fixture_path(relative: &str) -> PathBuf {
+	monochange_test_helpers::fs::fixture_path_from(env!("CARGO_MANIFEST_DIR"), relative)
+}
+
+fn workspace_charts() -> Vec<PackageRecord> {
+	discover_helm_charts(&fixture_path("helm/workspace"))
+		.unwrap_or_else(|error| panic!("helm discovery: {error}"))
+		.packages
+}
+
+fn chart<'a>(charts: &'a [PackageRecord], name: &str) -> &'a PackageRecord {
+	charts
+		.iter()
+		.find(|chart| chart.name == name)
+		.unwrap_or_else(|| panic!("missing chart {name}"))
+}
+
+fn released(charts: &[PackageRecord], versions: &[(&str, &str)]) -> BTreeMap<String, String> {
+	versions
+		.iter()
+		.map(|(name, version)| (chart(charts, name).id.clone(), (*version).to_string()))
+		.collect()
+}
+
+#[test]
+fn adapter_reports_helm_ecosystem() {
+	assert_eq!(HelmAdapter.ecosystem(), Ecosystem::Helm);
+	assert_eq!(adapter().ecosystem(), Ecosystem::Helm);
+}
+
+#[test]
+fn discover_helm_charts_links_file_dependencies() {
+	let charts = workspace_charts();
+	assert_eq!(charts.len(), 2);
+
+	let api = chart(&charts, "api");
+	assert_eq!(api.current_version, Some(Version::new(1, 2, 0)));
+	assert_eq!(api.publish_state, PublishState::Public);
+	assert_eq!(
+		api.metadata.get("app_version").map(String::as_str),
+		Some("2.4.1")
+	);
+	assert_eq!(
+		api.metadata.get("chart_type").map(String::as_str),
+		Some("application")
+	);
+	assert_eq!(api.declared_dependencies.len(), 1);
+	let dependency = api.declared_dependencies.first().expect("dependency");
+	assert_eq!(dependency.name, "common");
+	assert_eq!(dependency.kind, DependencyKind::Runtime);
+	assert_eq!(dependency.version_constraint.as_deref(), Some("~0.3.0"));
+	assert_eq!(dependency.source_field.as_deref(), Some("dependencies"));
+	assert!(!dependency.optional);
+
+	let common = chart(&charts, "common");
+	assert_eq!(common.current_version, Some(Version::new(0, 3, 0)));
+	assert_eq!(
+		common.metadata.get("chart_type").map(String::as_str),
+		Some("library")
+	);
+	assert!(common.declared_dependencies.is_empty());
+}
+
+#[test]
+fn discover_helm_charts_skips_vendored_tmpcharts_and_reports_invalid_charts() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	for (relative, contents) in [
+		(
+			"chart/Chart.yaml",
+			"apiVersion: v2\nname: chart\nversion: 0.1.0\n",
+		),
+		(
+			"chart/tmpcharts/vendored/Chart.yaml",
+			"apiVersion: v2\nname: vendored\nversion: 9.9.9\n",
+		),
+		("broken/Chart.yaml", "apiVersion: v2\nname: [broken\n"),
+		("nameless/Chart.yaml", "apiVersion: v2\nversion: 0.1.0\n"),
+	] {
+		let path = root.join(relative);
+		fs::create_dir_all(path.parent().unwrap_or(root))
+			.unwrap_or_else(|error| panic!("mkdir {relative}: {error}"));
+		fs::write(&path, contents).unwrap_or_else(|error| panic!("write {relative}: {error}"));
+	}
+
+	let discovery = discover_helm_charts(root).unwrap_or_else(|error| panic!("discovery: {error}"));
+
+	let names = discovery
+		.packages
+		.iter()
+		.map(|package| package.name.as_str())
+		.collect::<Vec<_>>();
+	assert_eq!(names, vec!["chart"]);
+	assert_eq!(discovery.warnings.len(), 1);
+	assert!(
+		discovery
+			.warnings
+			.first()
+			.expect("warning")
+			.contains("skipped")
+	);
+}
+
+#[test]
+fn load_configured_helm_chart_accepts_directories_and_manifest_paths() {
+	let root = fixture_path("helm/workspace");
+	let from_dir = load_configured_helm_chart(&root, &root.join("charts/common"))
+		.unwrap_or_else(|error| panic!("load dir: {error}"))
+		.expect("chart");
+	let from_manifest = load_configured_helm_chart(&root, &root.join("charts/common/Chart.yaml"))
+		.unwrap_or_else(|error| panic!("load manifest: {error}"))
+		.expect("chart");
+	assert_eq!(from_dir.id, from_manifest.id);
+	assert!(parse_chart(&fixture_path("helm/invalid-chart/Chart.yaml"), &root).is_err());
+}
+
+#[test]
+fn supported_versioned_file_kind_only_accepts_chart_manifests() {
+	assert_eq!(
+		supported_versioned_file_kind(Path::new("charts/api/Chart.yaml")),
+		Some(HelmVersionedFileKind::Chart)
+	);
+	assert_eq!(
+		supported_versioned_file_kind(Path::new("charts/api/Chart.lock")),
+		None
+	);
+	assert_eq!(
+		supported_versioned_file_kind(Path::new("values.yaml")),
+		None
+	);
+}
+
+#[test]
+fn update_chart_text_rewrites_versions_and_local_dependency_constraints() {
+	let contents = "# api chart\napiVersion: v2\nname: api\nversion: 1.2.0 # bumped by release\nappVersion: \"2.4.1\"\ndependencies:\n  - name: common\n    version: ~0.3.0\n    repository: file://../common\n  - name: postgresql\n    version: 15.5.0\n    repository: oci://registry-1.docker.io/bitnamicharts\n  - repository: \"file://../worker\"\n    name: worker\n    version: \">=1.0.0, <2.0.0\"\n";
+	let dependency_versions = BTreeMap::from([
+		("common".to_string(), "0.4.0".to_string()),
+		("postgresql".to_string(), "16.0.0".to_string()),
+		("worker".to_string(), "1.1.0".to_string()),
+	]);
+
+	let rendered = update_chart_text(contents, Some("1.3.0"), Some("2.5.0"), &dependency_versions)
+		.unwrap_or_else(|error| panic!("update chart: {error}"));
+
+	assert_eq!(
+		rendered,
+		"# api chart\napiVersion: v2\nname: api\nversion: 1.3.0 # bumped by release\nappVersion: \"2.5.0\"\ndependencies:\n  - name: common\n    version: ~0.4.0\n    repository: file://../common\n  - name: postgresql\n    version: 15.5.0\n    repository: oci://registry-1.docker.io/bitnamicharts\n  - repository: \"file://../worker\"\n    name: worker\n    version: \"1.1.0\"\n"
+	);
+}
+
+#[test]
+fn update_chart_text_leaves_unrequested_fields_untouched() {
+	let contents = "apiVersion: v2\nname: api\nversion: 1.2.0\nappVersion: 2.4.1\n";
+	let rendered = update_chart_text(contents, None, Some("2.5.0"), &BTreeMap::new())
+		.unwrap_or_else(|error| panic!("update chart: {error}"));
+	assert_eq!(
+		rendered,
+		"apiVersion: v2\nname: api\nversion: 1.2.0\nappVersion: 2.5.0\n"
+	);
+	assert!(update_chart_text("name: [broken", None, None, &BTreeMap::new()).is_err());
+}
+
+#[test]
+fn chart_manifest_updates_covers_released_charts_and_their_dependents() {
+	let charts = workspace_charts();
+	let updates = chart_manifest_updates(&charts, &released(&charts, &[("common", "0.4.0")]))
+		.unwrap_or_else(|error| panic!("chart updates: {error}"));
+
+	assert_eq!(updates.len(), 2);
+	let api = updates
+		.iter()
+		.find(|(path, _)| path.ends_with("charts/api/Chart.yaml"))
+		.expect("api update");
+	assert!(api.1.contains("version: 1.2.0\n"));
+	assert!(api.1.contains("    version: ~0.4.0\n"));
+	assert!(api.1.contains("    version: 15.5.0\n"));
+	let common = updates
+		.iter()
+		.find(|(path, _)| path.ends_with("charts/common/Chart.yaml"))
+		.expect("common update");
+	assert!(common.1.contains("version: 0.4.0\n"));
+
+	let untouched = chart_manifest_updates(&charts, &released(&charts, &[("api", "1.3.0")]))
+		.unwrap_or_else(|error| panic!("chart updates: {error}"));
+	assert_eq!(untouched.len(), 1);
+}
+
+#[test]
+fn lockfile_refresh_commands_run_helm_dependency_update_for_dependents() {
+	let charts = workspace_charts();
+	let api = chart(&charts, "api");
+	assert_eq!(discover_lockfiles(api).len(), 1);
+	assert!(discover_lockfiles(chart(&charts, "common")).is_empty());
+
+	let commands = lockfile_refresh_commands(&charts, &released(&charts, &[("common", "0.4.0")]));
+	assert_eq!(commands.len(), 1);
+	let command = commands.first().expect("command");
+	assert_eq!(command.command, "helm dependency update");
+	assert!(command.cwd.ends_with("charts/api"));
+	assert_eq!(command.shell, ShellConfig::None);
+
+	assert!(lockfile_refresh_commands(&charts, &released(&charts, &[("api", "1.3.0")])).is_empty());
+	assert!(lockfile_refresh_commands(&charts, &BTreeMap::new()).is_empty());
+}
+
+#[test]
+fn validate_versioned_file_checks_the_field_that_will_be_rewritten() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let path = tempdir.path().join("Chart.yaml");
+	fs::write(&path, "apiVersion: v2\nname: api\nversion: 1.2.0\n")
+		.unwrap_or_else(|error| panic!("write: {error}"));
+
+	assert!(validate_versioned_file(&path, "Chart.yaml", None).is_ok());
+	let error = validate_versioned_file(&path, "Chart.yaml", Some(&["appVersion".to_string()]))
+		.expect_err("missing appVersion");
+	assert!(error.to_string().contains("`appVersion`"));
+	assert!(
+		validate_versioned_file(&tempdir.path().join("missing.yaml"), "missing.yaml", None)
+			.is_err()
+	);
+}
@@ -0,0 +1,718 @@
+#![forbid(clippy::indexing_slicing)]
+
+//! # `monochange_helm`
+//!
+//! <!-- {=monochangeHelmCrateDocs|trim|linePrefix:"//! ":true} -->
+//! `monochange_helm` discovers Helm charts for the shared planner.
+//!
+//! Reach for this crate when you need to scan `Chart.yaml` files, link charts through local `file://` dependencies, and keep chart versions, `appVersion`, and `Chart.lock` files in step with the services a monorepo releases.
+//!
+//! ## Why use it?
+//!
+//! - release Helm charts from the same plan as the services they deploy
+//! - turn `file://` chart dependencies into dependency edges so subchart releases propagate to parent charts
+//! - sync a chart's `appVersion` to another package through `versioned_files`
+//! - refresh `Chart.lock` with `helm dependency update` after local dependency versions move
+//!
+//! ## Best for
+//!
+//! - monorepos that ship services and their Kubernetes charts together
+//! - umbrella charts composed from local subcharts
+//! - packaging charts to an OCI registry or a local chart repository directory
+//!
+//! ## Public entry points
+//!
+//! - `discover_helm_charts(root)` discovers `Chart.yaml` charts under a repository root
+//! - `HelmAdapter` exposes the shared adapter interface
+//! - `update_chart_text(contents, version, app_version, dependencies)` rewrites chart versions and local dependency constraints in place
+//! - `chart_manifest_updates(packages, versions)` plans the `Chart.yaml` rewrites a release needs
+//! - `lockfile_refresh_commands(packages, versions)` infers `helm dependency update` for charts whose local dependencies moved
+//!
+//! ## Scope
+//!
+//! - `Chart.yaml` (`apiVersion: v2`) parsing for name, version, `appVersion`, and `dependencies`
+//! - `file://` dependency edges; remote repository dependencies stay external
+//! - `Chart.lock` discovery and command-based refreshes
+//! - placeholder charts for `helm package` publishing
+//! <!-- {/monochangeHelmCrateDocs} -->
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_core::AdapterDiscovery;
+use monochange_core::DependencyKind;
+use monochange_core::DiscoveryPathFilter;
+use monochange_core::Ecosystem;
+use monochange_core::EcosystemAdapter;
+use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageDependency;
+use monochange_core::PackageRecord;
+use monochange_core::PublishState;
+use monochange_core::ShellConfig;
+use monochange_core::normalize_path;
+use monochange_publish::PublishRequest;
+use semver::Version;
+use serde_yaml_ng::Mapping;
+use serde_yaml_ng::Value;
+use walkdir::DirEntry;
+
+pub const CHART_FILE: &str = "Chart.yaml";
+pub const CHART_LOCK_FILE: &str = "Chart.lock";
+/// Repository prefix Helm uses for dependencies that live on disk.
+pub const LOCAL_REPOSITORY_PREFIX: &str = "file://";
+/// Versioned-file field that targets `appVersion` instead of the chart `version`.
+pub const APP_VERSION_FIELD: &str = "appVersion";
+
+pub struct HelmAdapter;
+
+/// Return the shared Helm ecosystem adapter.
+#[must_use]
+pub const fn adapter() -> HelmAdapter {
+	HelmAdapter
+}
+
+impl EcosystemAdapter for HelmAdapter {
+	fn ecosystem(&self) -> Ecosystem {
+		Ecosystem::Helm
+	}
+
+	fn discover(&self, root: &Path) -> MonochangeResult<AdapterDiscovery> {
+		discover_helm_charts(root)
+	}
+
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_helm_charts_with_filter(root, filter)
+	}
+
+	fn load_configured(
+		&self,
+		root: &Path,
+		package_path: &Path,
+	) -> MonochangeResult<Option<PackageRecord>> {
+		load_configured_helm_chart(root, package_path)
+	}
+
+	fn supported_versioned_file_kind(&self, path: &Path) -> bool {
+		supported_versioned_file_kind(path).is_some()
+	}
+
+	fn validate_versioned_file(
+		&self,
+		full_path: &Path,
+		display_path: &str,
+		custom_fields: Option<&[String]>,
+	) -> MonochangeResult<()> {
+		validate_versioned_file(full_path, display_path, custom_fields)
+	}
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HelmVersionedFileKind {
+	Chart,
+}
+
+/// Classify a Helm versioned file path.
+///
+/// `Chart.lock` is not a versioned file: it carries a digest of the resolved
+/// dependencies, so it is refreshed through `helm dependency update` instead.
+#[must_use]
+pub fn supported_versioned_file_kind(path: &Path) -> Option<HelmVersionedFileKind> {
+	let file_name = path
+		.file_name()
+		.and_then(|name| name.to_str())
+		.unwrap_or_default();
+	(file_name == CHART_FILE).then_some(HelmVersionedFileKind::Chart)
+}
+
+/// Discover the `Chart.lock` that belongs to `package`.
+pub fn discover_lockfiles(package: &PackageRecord) -> Vec<PathBuf> {
+	let chart_dir = package
+		.manifest_path
+		.parent()
+		.map_or_else(|| package.workspace_root.clone(), Path::to_path_buf);
+	[chart_dir.join(CHART_LOCK_FILE)]
+		.into_iter()
+		.filter(|path| path.exists())
+		.collect()
+}
+
+/// Return the default lockfile refresh commands for `package`.
+pub fn default_lockfile_commands(package: &PackageRecord) -> Vec<LockfileCommandExecution> {
+	if package.ecosystem != Ecosystem::Helm {
+		return Vec::new();
+	}
+
+	discover_lockfiles(package)
+		.into_iter()
+		.map(|lockfile| {
+			LockfileCommandExecution {
+				command: "helm dependency update".to_string(),
+				cwd: lockfile
+					.parent()
+					.unwrap_or(&package.workspace_root)
+					.to_path_buf(),
+				shell: ShellConfig::None,
+			}
+		})
+		.collect()
+}
+
+/// Infer `helm dependency update` for every chart with a `Chart.lock` that
+/// depends on a released workspace chart through a `file://` reference.
+///
+/// A chart's own version is not recorded in its lockfile, so releasing a
+/// chart on its own never requires a refresh.
+pub fn lockfile_refresh_commands(
+	packages: &[PackageRecord],
+	released_versions_by_record_id: &BTreeMap<String, String>,
+) -> Vec<LockfileCommandExecution> {
+	let released_charts = packages
+		.iter()
+		.filter(|package| {
+			package.ecosystem == Ecosystem::Helm
+				&& released_versions_by_record_id.contains_key(&package.id)
+		})
+		.map(|package| package.name.as_str())
+		.collect::<BTreeSet<_>>();
+	if released_charts.is_empty() {
+		return Vec::new();
+	}
+
+	packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Helm)
+		.filter(|package| {
+			package
+				.declared_dependencies
+				.iter()
+				.any(|dependency| released_charts.contains(dependency.name.as_str()))
+		})
+		.flat_map(default_lockfile_commands)
+		.collect()
+}
+
+/// Write a minimal chart that `helm package` accepts for placeholder releases.
+pub fn write_helm_placeholder_manifest(
+	dir: &Path,
+	request: &PublishRequest,
+) -> MonochangeResult<()> {
+	let rendered = format!(
+		"apiVersion: v2\nname: {}\nversion: {}\ndescription: Placeholder chart published by monochange.\ntype: application\n",
+		request.package_name, request.version
+	);
+	fs::write(dir.join(CHART_FILE), rendered).map_err(|error| {
+		MonochangeError::Io(format!("failed to write placeholder Chart.yaml: {error}"))
+	})
+}
+
+#[must_use = "the chart update result must be checked"]
+/// Update `Chart.yaml` text while preserving comments, quoting, and layout.
+///
+/// `version` and `app_version` replace the top-level scalars when present.
+/// `dependency_versions` maps chart names to released versions; only entries
+/// in `dependencies` that point at a `file://` repository are rewritten, and
+/// each keeps the operator of its existing constraint.
+pub fn update_chart_text(
+	contents: &str,
+	version: Option<&str>,
+	app_version: Option<&str>,
+	dependency_versions: &BTreeMap<String, String>,
+) -> MonochangeResult<String> {
+	serde_yaml_ng::from_str::<Mapping>(contents)
+		.map_err(|error| MonochangeError::Config(format!("failed to parse chart yaml: {error}")))?;
+
+	let lines = yaml_lines(contents);
+	let mut replacements = Vec::<((usize, usize), String)>::new();
+
+	for (key, value) in [("version", version), (APP_VERSION_FIELD, app_version)] {
+		let Some(value) = value else {
+			continue;
+		};
+		if let Some(span) = lines
+			.iter()
+			.find(|line| line.indent == 0 && !line.is_item && line.key == key)
+			.and_then(|line| line.value_span)
+		{
+			replacements.push((span, render_yaml_scalar(&contents[span.0..span.1], value)));
+		}
+	}
+
+	for entry in dependency_entries(&lines) {
+		let Some(name) = entry.get("name") else {
+			continue;
+		};
+		let Some(released) = dependency_versions.get(name) else {
+			continue;
+		};
+		let is_local = entry
+			.get("repository")
+			.is_some_and(|repository| unquote(repository).starts_with(LOCAL_REPOSITORY_PREFIX));
+		let Some(span) = entry.version_span.filter(|_| is_local) else {
+			continue;
+		};
+		let existing = &contents[span.0..span.1];
+		let constraint = constraint_for_version(unquote(existing), released);
+		replacements.push((span, render_yaml_scalar(existing, &constraint)));
+	}
+
+	replacements.sort_by_key(|replacement| std::cmp::Reverse(replacement.0.0));
+
+	let mut rendered = contents.to_string();
+	for ((start, end), replacement) in replacements {
+		rendered.replace_range(start..end, &replacement);
+	}
+
+	Ok(rendered)
+}
+
+/// Plan the `Chart.yaml` rewrites for a release: released charts get their
+/// new `version`, and charts that depend on released charts through `file://`
+/// references get matching dependency constraints.
+pub fn chart_manifest_updates(
+	packages: &[PackageRecord],
+	released_versions_by_record_id: &BTreeMap<String, String>,
+) -> MonochangeResult<Vec<(PathBuf, String)>> {
+	let charts = packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Helm)
+		.collect::<Vec<_>>();
+	let released_chart_versions = released_chart_versions(&charts, released_versions_by_record_id);
+	let mut updates = Vec::new();
+
+	for chart in charts {
+		let version = released_versions_by_record_id.get(&chart.id);
+		let moves_dependency = chart
+			.declared_dependencies
+			.iter()
+			.any(|dependency| released_chart_versions.contains_key(&dependency.name));
+		if version.is_none() && !moves_dependency {
+			continue;
+		}
+
+		let contents = fs::read_to_string(&chart.manifest_path).map_err(|error| {
+			MonochangeError::Io(format!(
+				"failed to read {}: {error}",
+				chart.manifest_path.display()
+			))
+		})?;
+		let rendered = update_chart_text(
+			&contents,
+			version.map(String::as_str),
+			None,
+			&released_chart_versions,
+		)
+		.map_err(|error| {
+			MonochangeError::Config(format!(
+				"failed to parse {}: {error}",
+				chart.manifest_path.display()
+			))
+		})?;
+		if rendered != contents {
+			updates.push((chart.manifest_path.clone(), rendered));
+		}
+	}
+
+	Ok(updates)
+}
+
+/// Map released chart names to their planned versions.
+#[must_use]
+pub fn released_chart_versions(
+	charts: &[&PackageRecord],
+	released_versions_by_record_id: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+	charts
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Helm)
+		.filter_map(|package| {
+			released_versions_by_record_id
+				.get(&package.id)
+				.map(|version| (package.name.clone(), version.clone()))
+		})
+		.collect()
+}
+
+/// Keep the operator of an existing dependency constraint and swap in `version`.
+///
+/// Constraints that are not a single comparison, such as ranges joined with
+/// commas or `||`, are replaced with the exact version.
+fn constraint_for_version(current: &str, version: &str) -> String {
+	let trimmed = current.trim();
+	let operator_len = trimmed
+		.find(|ch: char| ch.is_ascii_alphanumeric())
+		.unwrap_or(trimmed.len());
+	let (operator, rest) = trimmed.split_at(operator_len);
+	let is_single_comparison = !rest.contains([',', '|', ' ', '*'])
+		&& !rest.contains(".x")
+		&& matches!(operator.trim(), "" | "^" | "~" | "=" | ">=" | "v");
+	if is_single_comparison {
+		format!("{}{version}", operator.trim())
+	} else {
+		version.to_string()
+	}
+}
+
+struct YamlLine<'a> {
+	indent: usize,
+	is_item: bool,
+	key: &'a str,
+	value: &'a str,
+	value_span: Option<(usize, usize)>,
+}
+
+#[derive(Default)]
+struct DependencyEntry<'a> {
+	values: BTreeMap<&'a str, &'a str>,
+	version_span: Option<(usize, usize)>,
+}
+
+impl<'a> DependencyEntry<'a> {
+	fn get(&self, key: &str) -> Option<&'a str> {
+		self.values.get(key).copied()
+	}
+}
+
+/// Collect the `dependencies` sequence entries of a chart as line-level spans.
+fn dependency_entries<'a>(lines: &[YamlLine<'a>]) -> Vec<DependencyEntry<'a>> {
+	let mut entries = Vec::new();
+	let Some(section_index) = lines
+		.iter()
+		.position(|line| line.indent == 0 && !line.is_item && line.key == "dependencies")
+	else {
+		return entries;
+	};
+
+	let mut current: Option<DependencyEntry<'a>> = None;
+	let mut item_indent = None;
+	for line in lines.iter().skip(section_index + 1) {
+		if line.indent == 0 && !line.is_item {
+			break;
+		}
+		if line.is_item && item_indent.is_none_or(|indent| line.indent <= indent) {
+			item_indent = Some(line.indent);
+			entries.extend(current.take());
+			current = Some(DependencyEntry::default());
+		}
+		let Some(entry) = current.as_mut() else {
+			continue;
+		};
+		if line.value_span.is_none() {
+			continue;
+		}
+		if line.key == "version" {
+			entry.version_span = line.value_span;
+		}
+		entry.values.insert(line.key, line.value);
+	}
+	entries.extend(current);
+	entries
+}
+
+fn yaml_lines(contents: &str) -> Vec<YamlLine<'_>> {
+	let mut lines = Vec::new();
+	let mut start = 0usize;
+	for line in contents.split_inclusive('\n') {
+		let end = start + line.trim_end_matches(['\n', '\r']).len();
+		if let Some(parsed) = parse_yaml_line(contents, (start, end)) {
+			lines.push(parsed);
+		}
+		start += line.len();
+	}
+	lines
+}
+
+fn parse_yaml_line(contents: &str, range: (usize, usize)) -> Option<YamlLine<'_>> {
+	let line = &contents[range.0..range.1];
+	let trimmed = line.trim_start_matches([' ', '\t']);
+	if trimmed.is_empty() || trimmed.starts_with('#') {
+		return None;
+	}
+	let indent = line.len() - trimmed.len();
+	let (is_item, body, body_offset) = match trimmed.strip_prefix("- ") {
+		Some(body) => {
+			let body_trimmed = body.trim_start_matches(' ');
+			(
+				true,
+				body_trimmed,
+				indent + 2 + body.len() - body_trimmed.len(),
+			)
+		}
+		None => (false, trimmed, indent),
+	};
+	let colon = body.find(':')?;
+	let key = body[..colon].trim();
+	if key.is_empty() {
+		return None;
+	}
+	let value_span = yaml_value_span(line, range.0, body_offset + colon + 1);
+	Some(YamlLine {
+		indent,
+		is_item,
+		key,
+		value: value_span.map_or("", |(start, end)| &contents[start..end]),
+		value_span,
+	})
+}
+
+fn yaml_value_span(
+	line: &str,
+	line_start: usize,
+	value_start_in_line: usize,
+) -> Option<(usize, usize)> {
+	let suffix = line.get(value_start_in_line..)?;
+	let value_offset = suffix.find(|ch: char| !matches!(ch, ' ' | '\t'))?;
+	let value = &suffix[value_offset..];
+	if value.starts_with('#') {
+		return None;
+	}
+	let span_start = line_start + value_start_in_line + value_offset;
+	let span_end = if let Some(quote) = value
+		.chars()
+		.next()
+		.filter(|quote| *quote == '"' || *quote == '\'')
+	{
+		let quote_end = value.char_indices().skip(1).find(|(_, ch)| *ch == quote)?.0;
+		span_start + quote_end + 1
+	} else {
+		let comment_index = value.find(" #").unwrap_or(value.len());
+		let trimmed_end = value[..comment_index].trim_end_matches([' ', '\t']).len();
+		span_start + trimmed_end
+	};
+	(span_end > span_start).then_some((span_start, span_end))
+}
+
+fn unquote(value: &str) -> &str {
+	value
+		.strip_prefix('"')
+		.and_then(|value| value.strip_suffix('"'))
+		.or_else(|| {
+			value
+				.strip_prefix('\'')
+				.and_then(|value| value.strip_suffix('\''))
+		})
+		.unwrap_or(value)
+}
+
+fn render_yaml_scalar(existing: &str, value: &str) -> String {
+	if existing.starts_with('"') && existing.ends_with('"') {
+		return format!("\"{value}\"");
+	}
+	if existing.starts_with('\'') && existing.ends_with('\'') {
+		return format!("'{value}'");
+	}
+	value.to_string()
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Helm charts rooted at `root`.
+pub fn discover_helm_charts(root: &Path) -> MonochangeResult<AdapterDiscovery> {
+	discover_helm_charts_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Helm charts rooted at `root` without parsing charts outside
+/// `filter`'s discovery scope.
+pub fn discover_helm_charts_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_charts(root, filter);
+	let mut skipped = walk.skipped;
+	let mut packages = Vec::new();
+	let mut warnings = Vec::new();
+
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Helm, walk.manifests, &mut skipped)
+	{
+		match parse_chart(&manifest_path, root) {
+			Ok(Some(package)) => packages.push(package),
+			Ok(None) => {}
+			Err(error) => {
+				warnings.push(format!("skipped {}: {error}", manifest_path.display()));
+			}
+		}
+	}
+
+	packages.sort_by(|left, right| left.id.cmp(&right.id));
+	packages.dedup_by(|left, right| left.id == right.id);
+	tracing::debug!(packages = packages.len(), "discovered helm charts");
+
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
+}
+
+/// Load one explicitly configured Helm chart without walking the repo.
+#[must_use = "the package result must be checked"]
+pub fn load_configured_helm_chart(
+	root: &Path,
+	package_path: &Path,
+) -> MonochangeResult<Option<PackageRecord>> {
+	let manifest_path =
+		if package_path.file_name().and_then(|name| name.to_str()) == Some(CHART_FILE) {
+			package_path.to_path_buf()
+		} else {
+			package_path.join(CHART_FILE)
+		};
+	parse_chart(&manifest_path, root)
+}
+
+/// Parse one `Chart.yaml` into a package record.
+///
+/// Charts without a `name` are skipped. `file://` dependencies become
+/// runtime dependency edges; dependencies fetched from remote repositories
+/// are left out because they are not released from this workspace.
+pub fn parse_chart(
+	manifest_path: &Path,
+	workspace_root: &Path,
+) -> MonochangeResult<Option<PackageRecord>> {
+	let contents = fs::read_to_string(manifest_path).map_err(|error| {
+		MonochangeError::Io(format!(
+			"failed to read {}: {error}",
+			manifest_path.display()
+		))
+	})?;
+	let parsed = serde_yaml_ng::from_str::<Mapping>(&contents).map_err(|error| {
+		MonochangeError::Discovery(format!(
+			"failed to parse {}: {error}",
+			manifest_path.display()
+		))
+	})?;
+	let Some(name) = yaml_string(&parsed, "name") else {
+		return Ok(None);
+	};
+	let version = yaml_string(&parsed, "version").and_then(|value| Version::parse(&value).ok());
+
+	let mut package = PackageRecord::new(
+		Ecosystem::Helm,
+		name,
+		normalize_path(manifest_path),
+		normalize_path(workspace_root),
+		version,
+		PublishState::Public,
+	);
+	package.declared_dependencies = parse_local_dependencies(&parsed);
+	if let Some(app_version) = yaml_string(&parsed, APP_VERSION_FIELD) {
+		package
+			.metadata
+			.insert("app_version".to_string(), app_version);
+	}
+	if let Some(chart_type) = yaml_string(&parsed, "type") {
+		package
+			.metadata
+			.insert("chart_type".to_string(), chart_type);
+	}
+	Ok(Some(package))
+}
+
+fn parse_local_dependencies(parsed: &Mapping) -> Vec<PackageDependency> {
+	parsed
+		.get(Value::String("dependencies".to_string()))
+		.and_then(Value::as_sequence)
+		.into_iter()
+		.flatten()
+		.filter_map(Value::as_mapping)
+		.filter(|dependency| {
+			yaml_string(dependency, "repository")
+				.is_some_and(|repository| repository.starts_with(LOCAL_REPOSITORY_PREFIX))
+		})
+		.filter_map(|dependency| {
+			let name = yaml_string(dependency, "name")?;
+			Some(PackageDependency {
+				name,
+				kind: DependencyKind::Runtime,
+				version_constraint: yaml_string(dependency, "version"),
+				optional: yaml_string(dependency, "condition").is_some(),
+				source_field: Some("dependencies".to_string()),
+			})
+		})
+		.collect()
+}
+
+/// Validate that a Helm versioned file contains the fields it will rewrite.
+pub fn validate_versioned_file(
+	full_path: &Path,
+	display_path: &str,
+	custom_fields: Option<&[String]>,
+) -> MonochangeResult<()> {
+	let contents = fs::read_to_string(full_path).map_err(|error| {
+		MonochangeError::Config(format!(
+			"versioned file `{display_path}` is not readable: {error}"
+		))
+	})?;
+	let parsed = serde_yaml_ng::from_str::<Mapping>(&contents).map_err(|error| {
+		MonochangeError::Config(format!(
+			"versioned file `{display_path}` is not valid YAML: {error}"
+		))
+	})?;
+	let field = if custom_fields
+		.is_some_and(|fields| fields.iter().any(|field| field == APP_VERSION_FIELD))
+	{
+		APP_VERSION_FIELD
+	} else {
+		"version"
+	};
+	if yaml_string(&parsed, field).is_none() {
+		return Err(MonochangeError::Config(format!(
+			"versioned file `{display_path}` does not contain a `{field}` string field"
+		)));
+	}
+
+	Ok(())
+}
+
+/// Return the default dependency-version prefix for this ecosystem.
+///
+/// Helm dependency constraints keep whatever operator they already use, so
+/// there is no prefix to add.
+#[must_use]
+pub fn default_dependency_version_prefix() -> &'static str {
+	""
+}
+
+/// Return the manifest fields that usually contain dependency versions.
+#[must_use]
+pub fn default_dependency_fields() -> &'static [&'static str] {
+	&["dependencies"]
+}
+
+fn yaml_string(mapping: &Mapping, key: &str) -> Option<String> {
+	match mapping.get(Value::String(key.to_string()))? {
+		Value::String(value) => Some(value.clone()),
+		Value::Number(value) => Some(value.to_string()),
+		_ => None,
+	}
+}
+
+fn find_all_charts(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(
+		Ecosystem::Helm,
+		root,
+		|entry| filter.should_descend(entry.path()) && !is_packaged_subchart_dir(entry),
+		|entry| entry.file_name() == CHART_FILE,
+	)
+}
+
+/// Skip `tmpcharts`, the scratch directory `helm dependency update` leaves
+/// behind while it vendors dependencies.
+fn is_packaged_subchart_dir(entry: &DirEntry) -> bool {
+	entry.file_type().is_dir() && entry.file_name() == "tmpcharts"
+}
+
+#[cfg(test)]
+#[path = "__tests__/lib_tests.rs"]
+mod tests;
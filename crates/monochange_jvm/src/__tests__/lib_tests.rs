@@ -0,0 +1,416 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use monochange_core::DependencyKind;
+use monochange_core::Ecosystem;
+use monochange_core::EcosystemAdapter;
+use monochange_core::PackageRecord;
+use monochange_core::normalize_path;
+use semver::Version;
+
+use crate::JvmAdapter;
+use crate::JvmVersionedFileKind;
+use crate::adapter;
+use crate::discover_jvm_packages;
+use crate::jvm_manifest_updates;
+use crate::load_configured_jvm_package;
+use crate::parse_gradle_property;
+use crate::supported_versioned_file_kind;
+use crate::update_gradle_build_text;
+use crate::update_gradle_properties_text;
+use crate::update_pom_text;
+use crate::validate_versioned_file;
+
+fn fixture_path(relative: &str) -> PathBuf {
+	monochange_test_helpers::fs::fixture_path_from(env!("CARGO_MANIFEST_DIR"), relative)
+}
+
+fn workspace_packages() -> Vec<PackageRecord> {
+	discover_jvm_packages(&fixture_path("jvm/workspace"))
+		.unwrap_or_else(|error| panic!("jvm discovery: {error}"))
+		.packages
+}
+
+fn package<'a>(packages: &'a [PackageRecord], name: &str) -> &'a PackageRecord {
+	packages
+		.iter()
+		.find(|package| package.name == name)
+		.unwrap_or_else(|| panic!("missing package {name}"))
+}
+
+fn dependencies(package: &PackageRecord) -> Vec<(&str, DependencyKind, Option<&str>, &str)> {
+	package
+		.declared_dependencies
+		.iter()
+		.map(|dependency| {
+			(
+				dependency.name.as_str(),
+				dependency.kind,
+				dependency.version_constraint.as_deref(),
+				dependency.source_field.as_deref().unwrap_or_default(),
+			)
+		})
+		.collect()
+}
+
+fn versions(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+	entries
+		.iter()
+		.map(|(name, version)| ((*name).to_string(), (*version).to_string()))
+		.collect()
+}
+
+#[test]
+fn adapter_reports_jvm_ecosystem() {
+	assert_eq!(JvmAdapter.ecosystem(), Ecosystem::Jvm);
+	assert_eq!(adapter().ecosystem(), Ecosystem::Jvm);
+}
+
+#[test]
+fn discover_jvm_packages_reads_maven_modules_with_parent_inheritance() {
+	let packages = workspace_packages();
+	assert_eq!(packages.len(), 5);
+
+	let platform = package(&packages, "com.acme:platform");
+	assert_eq!(platform.current_version, Some(Version::new(1, 4, 0)));
+	assert_eq!(
+		dependencies(platform),
+		vec![(
+			"com.acme:core",
+			DependencyKind::Runtime,
+			Some("2.1.0"),
+			"dependencyManagement"
+		)]
+	);
+
+	let core = package(&packages, "com.acme:core");
+	assert_eq!(core.current_version, Some(Version::new(2, 1, 0)));
+	assert_eq!(
+		dependencies(core),
+		vec![
+			(
+				"com.acme:platform",
+				DependencyKind::Build,
+				Some("1.4.0"),
+				"parent"
+			),
+			(
+				"org.jetbrains.kotlin:kotlin-stdlib",
+				DependencyKind::Runtime,
+				Some("1.9.24"),
+				"dependencies"
+			),
+			(
+				"org.junit.jupiter:junit-jupiter",
+				DependencyKind::Development,
+				Some("5.10.2"),
+				"dependencies"
+			),
+		]
+	);
+
+	let api = package(&packages, "com.acme:api");
+	assert_eq!(api.current_version, Some(Version::new(1, 4, 0)));
+	assert_eq!(
+		dependencies(api),
+		vec![
+			(
+				"com.acme:platform",
+				DependencyKind::Build,
+				Some("1.4.0"),
+				"parent"
+			),
+			(
+				"com.acme:core",
+				DependencyKind::Runtime,
+				Some("2.1.0"),
+				"dependencies"
+			),
+			(
+				"jakarta.servlet:jakarta.servlet-api",
+				DependencyKind::Build,
+				Some("6.0.0"),
+				"dependencies"
+			),
+		]
+	);
+	assert!(
+		api.declared_dependencies
+			.iter()
+			.any(|dependency| dependency.optional)
+	);
+}
+
+#[test]
+fn discover_jvm_packages_reads_versioned_gradle_projects() {
+	let packages = workspace_packages();
+	assert!(
+		packages
+			.iter()
+			.all(|package| package.name != "com.acme.tools:tools"),
+		"gradle.properties without a version is not a package"
+	);
+
+	let cli = package(&packages, "com.acme.tools:cli");
+	assert_eq!(cli.current_version, Some(Version::new(0, 9, 0)));
+	assert!(cli.manifest_path.ends_with("tools/cli/gradle.properties"));
+	assert_eq!(
+		dependencies(cli),
+		vec![
+			(
+				"com.acme.tools:plugin",
+				DependencyKind::Runtime,
+				None,
+				"dependencies"
+			),
+			(
+				"com.acme:core",
+				DependencyKind::Runtime,
+				Some("2.1.0"),
+				"dependencies"
+			),
+			(
+				"org.junit.jupiter:junit-jupiter",
+				DependencyKind::Development,
+				Some("5.10.2"),
+				"dependencies"
+			),
+		]
+	);
+
+	let plugin = package(&packages, "com.acme.tools:plugin");
+	assert_eq!(
+		dependencies(plugin),
+		vec![
+			(
+				"com.acme:api",
+				DependencyKind::Build,
+				Some("1.4.0"),
+				"dependencies"
+			),
+			(
+				"org.ow2.asm:asm",
+				DependencyKind::Runtime,
+				None,
+				"dependencies"
+			),
+		]
+	);
+}
+
+#[test]
+fn load_configured_jvm_package_reads_a_module_directory() {
+	let root = fixture_path("jvm/workspace");
+	let core = load_configured_jvm_package(&root, &root.join("platform/core"))
+		.unwrap_or_else(|error| panic!("load core: {error}"))
+		.unwrap_or_else(|| panic!("core should load"));
+	assert_eq!(core.name, "com.acme:core");
+
+	let plugin = load_configured_jvm_package(&root, &root.join("tools/plugin/gradle.properties"))
+		.unwrap_or_else(|error| panic!("load plugin: {error}"))
+		.unwrap_or_else(|| panic!("plugin should load"));
+	assert_eq!(plugin.name, "com.acme.tools:plugin");
+
+	let missing = load_configured_jvm_package(&root, &root.join("tools"))
+		.unwrap_or_else(|error| panic!("load tools: {error}"));
+	assert!(missing.is_none());
+}
+
+#[test]
+fn update_pom_text_rewrites_versions_and_keeps_layout() {
+	let contents = r"<project>
+  <!-- <version>0.0.0</version> stays a comment -->
+  <parent>
+    <groupId>com.acme</groupId>
+    <artifactId>platform</artifactId>
+    <version>1.4.0</version>
+  </parent>
+  <artifactId>api</artifactId>
+  <version>1.4.0</version>
+  <dependencies>
+    <dependency>
+      <groupId>${project.groupId}</groupId>
+      <artifactId>core</artifactId>
+      <version> 2.1.0 </version>
+    </dependency>
+    <dependency>
+      <groupId>com.acme</groupId>
+      <artifactId>events</artifactId>
+      <version>[1.0,2.0)</version>
+    </dependency>
+    <dependency>
+      <groupId>com.example</groupId>
+      <artifactId>core</artifactId>
+      <version>2.1.0</version>
+    </dependency>
+  </dependencies>
+</project>
+";
+	let updated = update_pom_text(
+		contents,
+		Some("1.5.0"),
+		&versions(&[
+			("com.acme:platform", "1.5.0"),
+			("com.acme:core", "2.2.0"),
+			("com.acme:events", "2.0.0"),
+		]),
+	);
+
+	assert_eq!(
+		updated,
+		contents
+			.replacen("<version>1.4.0</version>", "<version>1.5.0</version>", 2)
+			.replace("<version> 2.1.0 </version>", "<version> 2.2.0 </version>")
+	);
+}
+
+#[test]
+fn update_pom_text_rewrites_the_property_behind_a_ci_friendly_version() {
+	let contents = "<project>\n  <artifactId>platform</artifactId>\n  <version>${revision}</version>\n  <properties>\n    <revision>1.4.0</revision>\n  </properties>\n</project>\n";
+	let updated = update_pom_text(contents, Some("1.5.0"), &BTreeMap::new());
+	assert_eq!(
+		updated,
+		contents.replace("<revision>1.4.0</revision>", "<revision>1.5.0</revision>")
+	);
+
+	let inherited = "<project>\n  <parent>\n    <groupId>com.acme</groupId>\n    <artifactId>platform</artifactId>\n    <version>1.4.0</version>\n  </parent>\n  <artifactId>api</artifactId>\n</project>\n";
+	assert_eq!(
+		update_pom_text(inherited, Some("9.9.9"), &BTreeMap::new()),
+		inherited
+	);
+}
+
+#[test]
+fn update_gradle_properties_text_keeps_separators_and_other_entries() {
+	let contents = "# release metadata\ngroup=com.acme\nversion = 0.9.0\norg.gradle.caching=true\n";
+	let updated = update_gradle_properties_text(contents, "version", "0.10.0");
+	assert_eq!(
+		updated,
+		"# release metadata\ngroup=com.acme\nversion = 0.10.0\norg.gradle.caching=true\n"
+	);
+	assert_eq!(parse_gradle_property(&updated, "version"), Some("0.10.0"));
+	assert_eq!(
+		update_gradle_properties_text(contents, "missing", "1.0.0"),
+		contents
+	);
+}
+
+#[test]
+fn update_gradle_build_text_rewrites_literal_internal_coordinates() {
+	let contents = "dependencies {\n    implementation(\"com.acme:core:2.1.0\")\n    compileOnly 'com.acme:api:1.4.0'\n    implementation(\"com.acme:events:1.+\")\n    implementation(\"com.acme:core-extras:2.1.0\")\n    testImplementation(\"org.junit.jupiter:junit-jupiter:5.10.2\")\n}\n";
+	let updated = update_gradle_build_text(
+		contents,
+		&versions(&[
+			("com.acme:core", "2.2.0"),
+			("com.acme:api", "1.5.0"),
+			("com.acme:events", "2.0.0"),
+		]),
+	);
+	assert_eq!(
+		updated,
+		contents
+			.replace("com.acme:core:2.1.0", "com.acme:core:2.2.0")
+			.replace("com.acme:api:1.4.0", "com.acme:api:1.5.0")
+	);
+}
+
+#[test]
+fn jvm_manifest_updates_plans_pom_and_gradle_rewrites() {
+	let tempdir = monochange_test_helpers::fs::setup_fixture_from(
+		env!("CARGO_MANIFEST_DIR"),
+		"jvm/workspace",
+	);
+	let root = normalize_path(tempdir.path());
+	let packages = discover_jvm_packages(&root)
+		.unwrap_or_else(|error| panic!("jvm discovery: {error}"))
+		.packages;
+	let released = [
+		("com.acme:core", "2.2.0"),
+		("com.acme.tools:plugin", "1.1.0"),
+	]
+	.into_iter()
+	.map(|(name, version)| (package(&packages, name).id.clone(), version.to_string()))
+	.collect::<BTreeMap<_, _>>();
+
+	let updates = jvm_manifest_updates(&packages, &released)
+		.unwrap_or_else(|error| panic!("manifest updates: {error}"));
+	let rewritten = updates
+		.iter()
+		.map(|(path, contents)| {
+			(
+				path.strip_prefix(&root)
+					.unwrap_or_else(|error| panic!("relative path: {error}"))
+					.display()
+					.to_string(),
+				contents.as_str(),
+			)
+		})
+		.collect::<BTreeMap<_, _>>();
+
+	assert_eq!(
+		rewritten.keys().map(String::as_str).collect::<Vec<_>>(),
+		vec![
+			"platform/api/pom.xml",
+			"platform/core/pom.xml",
+			"platform/pom.xml",
+			"tools/cli/build.gradle.kts",
+			"tools/plugin/gradle.properties",
+		]
+	);
+	assert!(
+		rewritten["platform/core/pom.xml"]
+			.contains("<artifactId>core</artifactId>\n  <version>2.2.0</version>")
+	);
+	assert!(rewritten["platform/pom.xml"].contains("<version>2.2.0</version>"));
+	assert!(rewritten["platform/api/pom.xml"].contains("<version>2.2.0</version>"));
+	assert!(rewritten["tools/cli/build.gradle.kts"].contains("\"com.acme:core:2.2.0\""));
+	assert_eq!(
+		rewritten["tools/plugin/gradle.properties"],
+		"version=1.1.0\n"
+	);
+	assert_eq!(
+		fs::read_to_string(root.join("platform/core/pom.xml"))
+			.unwrap_or_else(|error| panic!("read core pom: {error}"))
+			.matches("2.1.0")
+			.count(),
+		1,
+		"planning does not write files"
+	);
+}
+
+#[test]
+fn versioned_files_are_classified_and_validated() {
+	assert_eq!(
+		supported_versioned_file_kind(&PathBuf::from("platform/pom.xml")),
+		Some(JvmVersionedFileKind::Pom)
+	);
+	assert_eq!(
+		supported_versioned_file_kind(&PathBuf::from("tools/gradle.properties")),
+		Some(JvmVersionedFileKind::GradleProperties)
+	);
+	assert_eq!(
+		supported_versioned_file_kind(&PathBuf::from("tools/cli/build.gradle.kts")),
+		Some(JvmVersionedFileKind::GradleBuild)
+	);
+	assert_eq!(
+		supported_versioned_file_kind(&PathBuf::from("settings.xml")),
+		None
+	);
+
+	let root = fixture_path("jvm/workspace");
+	validate_versioned_file(
+		&root.join("tools/cli/gradle.properties"),
+		"tools/cli/gradle.properties",
+		None,
+	)
+	.unwrap_or_else(|error| panic!("version key: {error}"));
+	let error = validate_versioned_file(
+		&root.join("tools/cli/gradle.properties"),
+		"tools/cli/gradle.properties",
+		Some(&["sdkVersion".to_string()]),
+	)
+	.err()
+	.unwrap_or_else(|| panic!("missing key should fail"));
+	assert!(error.to_string().contains("does not set `sdkVersion`"));
+}
@@ -0,0 +1,1178 @@
+#![forbid(clippy::indexing_slicing)]
+
+//! # `monochange_jvm`
+//!
+//! <!-- {=monochangeJvmCrateDocs|trim|linePrefix:"//! ":true} -->
+//! `monochange_jvm` discovers Maven modules and Gradle projects for the shared planner.
+//!
+//! Reach for this crate when you need to scan `pom.xml` files and `gradle.properties` `version=` entries, link JVM modules through parents, `<dependency>` entries, and Gradle `project(...)` references, and rewrite their versions without reformatting the build files.
+//!
+//! ## Why use it?
+//!
+//! - release Kotlin and Java libraries through the same changesets as the rest of the monorepo
+//! - follow Maven parent/child aggregation, including inherited `groupId` and `version` values
+//! - rewrite `pom.xml`, `gradle.properties`, and build-script coordinates in place, keeping comments and layout
+//! - hand publishing to `mvn deploy` or `gradle publish`, which already own signing and repository credentials
+//!
+//! ## Best for
+//!
+//! - monorepos that keep JVM libraries next to Rust, TypeScript, or other packages
+//! - multi-module Maven builds with a shared parent or BOM
+//! - Gradle builds that version each project through its own `gradle.properties`
+//!
+//! ## Public entry points
+//!
+//! - `discover_jvm_packages(root)` discovers Maven modules and versioned Gradle projects under a repository root
+//! - `JvmAdapter` exposes the shared adapter interface
+//! - `update_pom_text(contents, version, dependencies)` rewrites a POM's version, parent version, and internal dependency versions
+//! - `update_gradle_properties_text(contents, key, version)` rewrites one `gradle.properties` entry
+//! - `update_gradle_build_text(contents, dependencies)` rewrites internal `group:artifact:version` coordinates in build scripts
+//! - `jvm_manifest_updates(packages, versions)` plans the file rewrites a release needs
+//!
+//! ## Scope
+//!
+//! - `pom.xml` coordinates, `<parent>`, `<properties>`, `<dependencies>`, and `<dependencyManagement>`
+//! - `gradle.properties` `version=` and `group=` entries
+//! - `build.gradle` and `build.gradle.kts` `project(":path")` references and string coordinates
+//! - external-mode publishing with `mvn deploy` or `gradle publish`
+//! <!-- {/monochangeJvmCrateDocs} -->
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use monochange_core::AdapterDiscovery;
+use monochange_core::DependencyKind;
+use monochange_core::DiscoveryPathFilter;
+use monochange_core::Ecosystem;
+use monochange_core::EcosystemAdapter;
+use monochange_core::ManifestWalk;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageDependency;
+use monochange_core::PackageRecord;
+use monochange_core::PublishState;
+use monochange_core::normalize_path;
+use regex::Regex;
+use semver::Version;
+use walkdir::DirEntry;
+
+/// The Maven project descriptor.
+pub const POM_XML: &str = "pom.xml";
+/// The Gradle properties file that holds a project's `version=` entry.
+pub const GRADLE_PROPERTIES: &str = "gradle.properties";
+/// The `gradle.properties` key holding a project's version.
+pub const VERSION_PROPERTY: &str = "version";
+/// The `gradle.properties` key holding a project's group.
+pub const GROUP_PROPERTY: &str = "group";
+
+const GRADLE_BUILD_SCRIPTS: [&str; 2] = ["build.gradle.kts", "build.gradle"];
+const GRADLE_SETTINGS_SCRIPTS: [&str; 2] = ["settings.gradle.kts", "settings.gradle"];
+const PARENT_FIELD: &str = "parent";
+const DEPENDENCIES_FIELD: &str = "dependencies";
+const DEPENDENCY_MANAGEMENT_FIELD: &str = "dependencyManagement";
+/// Parent chains deeper than this are treated as cycles.
+const MAX_PARENT_DEPTH: usize = 16;
+
+pub struct JvmAdapter;
+
+/// Return the shared JVM ecosystem adapter.
+#[must_use]
+pub const fn adapter() -> JvmAdapter {
+	JvmAdapter
+}
+
+impl EcosystemAdapter for JvmAdapter {
+	fn ecosystem(&self) -> Ecosystem {
+		Ecosystem::Jvm
+	}
+
+	fn discover(&self, root: &Path) -> MonochangeResult<AdapterDiscovery> {
+		discover_jvm_packages(root)
+	}
+
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_jvm_packages_with_filter(root, filter)
+	}
+
+	fn load_configured(
+		&self,
+		root: &Path,
+		package_path: &Path,
+	) -> MonochangeResult<Option<PackageRecord>> {
+		load_configured_jvm_package(root, package_path)
+	}
+
+	fn supported_versioned_file_kind(&self, path: &Path) -> bool {
+		supported_versioned_file_kind(path).is_some()
+	}
+
+	fn validate_versioned_file(
+		&self,
+		full_path: &Path,
+		display_path: &str,
+		custom_fields: Option<&[String]>,
+	) -> MonochangeResult<()> {
+		validate_versioned_file(full_path, display_path, custom_fields)
+	}
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum JvmVersionedFileKind {
+	Pom,
+	GradleProperties,
+	GradleBuild,
+}
+
+/// Classify a JVM versioned file path.
+#[must_use]
+pub fn supported_versioned_file_kind(path: &Path) -> Option<JvmVersionedFileKind> {
+	let name = path.file_name()?.to_str()?;
+	let extension = path.extension().and_then(|extension| extension.to_str());
+	if name == POM_XML || extension == Some("pom") {
+		Some(JvmVersionedFileKind::Pom)
+	} else if name.ends_with(".properties") {
+		Some(JvmVersionedFileKind::GradleProperties)
+	} else if name.ends_with(".gradle") || name.ends_with(".gradle.kts") {
+		Some(JvmVersionedFileKind::GradleBuild)
+	} else {
+		None
+	}
+}
+
+/// A leaf XML element: one with text and no child elements.
+#[derive(Debug, Clone)]
+struct XmlLeaf {
+	/// Slash-joined element names from the document root, such as
+	/// `project/parent/version`.
+	path: String,
+	/// Identifies the enclosing element so sibling leaves can be grouped.
+	parent: usize,
+	/// Byte span of the element text with surrounding whitespace trimmed.
+	span: (usize, usize),
+}
+
+struct OpenElement {
+	name: String,
+	id: usize,
+	content_start: usize,
+	has_children: bool,
+}
+
+/// Collect the leaf elements of an XML document.
+///
+/// This is not a validating parser: it tracks element nesting well enough to
+/// find POM fields and their exact text spans, so rewrites can leave every
+/// other byte of the file alone. Comments, CDATA sections, processing
+/// instructions, and doctype declarations are skipped.
+fn scan_xml_leaves(contents: &str) -> Vec<XmlLeaf> {
+	let mut stack = Vec::<OpenElement>::new();
+	let mut leaves = Vec::new();
+	let mut next_id = 0;
+	let mut position = 0;
+
+	while let Some(offset) = contents.get(position..).and_then(|rest| rest.find('<')) {
+		let start = position + offset;
+		let Some(rest) = contents.get(start..) else {
+			break;
+		};
+		let (terminator, is_markup) = if rest.starts_with("<!--") {
+			("-->", true)
+		} else if rest.starts_with("<![CDATA[") {
+			("]]>", true)
+		} else if rest.starts_with("<?") || rest.starts_with("<!") {
+			(">", true)
+		} else {
+			(">", false)
+		};
+		let Some(end) = rest
+			.find(terminator)
+			.map(|index| start + index + terminator.len())
+		else {
+			break;
+		};
+		position = end;
+		if is_markup {
+			continue;
+		}
+		let Some(tag) = contents.get(start + 1..end - 1) else {
+			break;
+		};
+
+		if tag.starts_with('/') {
+			let Some(open) = stack.pop() else {
+				continue;
+			};
+			if !open.has_children
+				&& let Some(text) = contents.get(open.content_start..start)
+			{
+				let leading = text.len() - text.trim_start().len();
+				let text_start = open.content_start + leading;
+				let path = stack
+					.iter()
+					.map(|element| element.name.as_str())
+					.chain([open.name.as_str()])
+					.collect::<Vec<_>>()
+					.join("/");
+				leaves.push(XmlLeaf {
+					path,
+					parent: stack.last().map_or(0, |element| element.id),
+					span: (text_start, text_start + text.trim().len()),
+				});
+			}
+			continue;
+		}
+
+		if let Some(parent) = stack.last_mut() {
+			parent.has_children = true;
+		}
+		if tag.ends_with('/') {
+			continue;
+		}
+		next_id += 1;
+		stack.push(OpenElement {
+			name: tag
+				.split(char::is_whitespace)
+				.next()
+				.unwrap_or_default()
+				.to_string(),
+			id: next_id,
+			content_start: end,
+			has_children: false,
+		});
+	}
+
+	leaves
+}
+
+/// Element text and where it sits in the source.
+#[derive(Debug, Clone)]
+struct XmlText {
+	value: String,
+	span: (usize, usize),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Coordinates {
+	group_id: Option<XmlText>,
+	artifact_id: Option<XmlText>,
+	version: Option<XmlText>,
+}
+
+impl Coordinates {
+	fn set(&mut self, field: &str, text: XmlText) {
+		match field {
+			"groupId" => self.group_id = Some(text),
+			"artifactId" => self.artifact_id = Some(text),
+			"version" => self.version = Some(text),
+			_ => {}
+		}
+	}
+
+	fn group(&self) -> Option<&str> {
+		self.group_id.as_ref().map(|text| text.value.as_str())
+	}
+
+	fn artifact(&self) -> Option<&str> {
+		self.artifact_id.as_ref().map(|text| text.value.as_str())
+	}
+
+	fn version(&self) -> Option<&str> {
+		self.version.as_ref().map(|text| text.value.as_str())
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+struct PomParent {
+	coordinates: Coordinates,
+	relative_path: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct PomDependency {
+	/// `dependencies` or `dependencyManagement`.
+	section: &'static str,
+	coordinates: Coordinates,
+	scope: Option<String>,
+	optional: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Pom {
+	project: Coordinates,
+	parent: Option<PomParent>,
+	properties: BTreeMap<String, XmlText>,
+	dependencies: Vec<PomDependency>,
+}
+
+impl Pom {
+	fn parse(contents: &str) -> Self {
+		let mut pom = Self::default();
+		let mut dependencies = BTreeMap::<usize, PomDependency>::new();
+
+		for leaf in scan_xml_leaves(contents) {
+			let text = XmlText {
+				value: contents
+					.get(leaf.span.0..leaf.span.1)
+					.unwrap_or_default()
+					.to_string(),
+				span: leaf.span,
+			};
+			let path = leaf.path.as_str();
+			if let Some(field) = path.strip_prefix("project/parent/") {
+				let parent = pom.parent.get_or_insert_with(PomParent::default);
+				if field == "relativePath" {
+					parent.relative_path = Some(text.value);
+				} else {
+					parent.coordinates.set(field, text);
+				}
+			} else if let Some(name) = path.strip_prefix("project/properties/") {
+				pom.properties.insert(name.to_string(), text);
+			} else if let Some((section, field)) = dependency_field(path) {
+				let dependency = dependencies.entry(leaf.parent).or_insert_with(|| {
+					PomDependency {
+						section,
+						coordinates: Coordinates::default(),
+						scope: None,
+						optional: false,
+					}
+				});
+				match field {
+					"scope" => dependency.scope = Some(text.value),
+					"optional" => dependency.optional = text.value == "true",
+					_ => dependency.coordinates.set(field, text),
+				}
+			} else if let Some(field) = path.strip_prefix("project/") {
+				pom.project.set(field, text);
+			}
+		}
+
+		pom.dependencies = dependencies.into_values().collect();
+		pom
+	}
+
+	/// The project's own `groupId`, or the one it inherits from `<parent>`.
+	fn group(&self) -> Option<&str> {
+		self.project.group().or_else(|| {
+			self.parent
+				.as_ref()
+				.and_then(|parent| parent.coordinates.group())
+		})
+	}
+
+	fn parent_coordinate(&self) -> Option<String> {
+		let parent = self.parent.as_ref()?;
+		Some(coordinate(
+			parent.coordinates.group()?,
+			parent.coordinates.artifact()?,
+		))
+	}
+}
+
+fn dependency_field(path: &str) -> Option<(&'static str, &str)> {
+	let (section, field) = if let Some(field) =
+		path.strip_prefix("project/dependencies/dependency/")
+	{
+		(DEPENDENCIES_FIELD, field)
+	} else {
+		let field = path.strip_prefix("project/dependencyManagement/dependencies/dependency/")?;
+		(DEPENDENCY_MANAGEMENT_FIELD, field)
+	};
+	// Nested elements such as `exclusions/exclusion/groupId` describe other
+	// artifacts.
+	(!field.contains('/')).then_some((section, field))
+}
+
+fn coordinate(group: &str, artifact: &str) -> String {
+	format!("{group}:{artifact}")
+}
+
+/// Expand `${name}` references from `properties`.
+///
+/// Returns `None` when a reference cannot be resolved.
+fn resolve_properties(value: &str, properties: &BTreeMap<String, String>) -> Option<String> {
+	let mut resolved = value.to_string();
+	for _ in 0..MAX_PARENT_DEPTH {
+		let Some(start) = resolved.find("${") else {
+			return Some(resolved);
+		};
+		let length = resolved.get(start..)?.find('}')?;
+		let name = resolved.get(start + 2..start + length)?;
+		let replacement = properties.get(name)?.clone();
+		resolved.replace_range(start..=start + length, &replacement);
+	}
+	None
+}
+
+/// A POM together with the parent POMs found through `relativePath`.
+struct PomHierarchy {
+	pom: Pom,
+	ancestors: Vec<Pom>,
+}
+
+impl PomHierarchy {
+	fn load(manifest_path: &Path) -> MonochangeResult<Self> {
+		let pom = Pom::parse(&read_text(manifest_path)?);
+		let mut ancestors = Vec::new();
+		let mut current_path = manifest_path.to_path_buf();
+		let mut parent = pom.parent.clone();
+
+		while let Some(declared) = parent.take() {
+			if ancestors.len() >= MAX_PARENT_DEPTH {
+				break;
+			}
+			let Some(parent_path) = parent_pom_path(&current_path, &declared) else {
+				break;
+			};
+			let Ok(contents) = fs::read_to_string(&parent_path) else {
+				break;
+			};
+			let parent_pom = Pom::parse(&contents);
+			// A relative path that resolves to a different artifact does not
+			// describe the declared parent.
+			if parent_pom.project.artifact() != declared.coordinates.artifact() {
+				break;
+			}
+			parent.clone_from(&parent_pom.parent);
+			ancestors.push(parent_pom);
+			current_path = parent_path;
+		}
+
+		Ok(Self { pom, ancestors })
+	}
+
+	/// Properties visible to the POM, with nearer POMs overriding farther
+	/// ones, plus the `project.*` built-ins Maven defines.
+	fn properties(&self) -> BTreeMap<String, String> {
+		let mut properties = BTreeMap::new();
+		for pom in self.ancestors.iter().rev().chain([&self.pom]) {
+			properties.extend(
+				pom.properties
+					.iter()
+					.map(|(name, text)| (name.clone(), text.value.clone())),
+			);
+		}
+		let builtins = [
+			("project.groupId", self.pom.group()),
+			("project.artifactId", self.pom.project.artifact()),
+			("project.version", self.version_text()),
+			(
+				"project.parent.groupId",
+				self.pom
+					.parent
+					.as_ref()
+					.and_then(|parent| parent.coordinates.group()),
+			),
+			(
+				"project.parent.version",
+				self.pom
+					.parent
+					.as_ref()
+					.and_then(|parent| parent.coordinates.version()),
+			),
+		];
+		let builtins = builtins
+			.into_iter()
+			.filter_map(|(name, value)| Some((name.to_string(), value?.to_string())))
+			.collect::<Vec<_>>();
+		for (name, value) in builtins {
+			let resolved = resolve_properties(&value, &properties).unwrap_or(value);
+			properties.insert(name, resolved);
+		}
+		properties
+	}
+
+	fn version_text(&self) -> Option<&str> {
+		self.pom.project.version().or_else(|| {
+			self.pom
+				.parent
+				.as_ref()
+				.and_then(|parent| parent.coordinates.version())
+		})
+	}
+}
+
+fn parent_pom_path(child_path: &Path, parent: &PomParent) -> Option<PathBuf> {
+	let relative = parent.relative_path.as_deref().unwrap_or("../pom.xml");
+	if relative.trim().is_empty() {
+		return None;
+	}
+	let path = child_path.parent()?.join(relative);
+	let path = if path.is_dir() {
+		path.join(POM_XML)
+	} else {
+		path
+	};
+	path.is_file().then_some(path)
+}
+
+#[must_use = "the POM update result must be checked"]
+/// Update POM text in place.
+///
+/// `version` replaces the project's literal `<version>`. When the version is
+/// a single property reference such as `${revision}` defined in the same POM,
+/// that property is rewritten instead; an inherited version is left for the
+/// parent POM. `dependency_versions` maps `groupId:artifactId` coordinates to
+/// released versions and moves the matching `<parent>` version and literal
+/// `<dependency>` versions in both `<dependencies>` and
+/// `<dependencyManagement>`. Version ranges and property references are not
+/// touched.
+pub fn update_pom_text(
+	contents: &str,
+	version: Option<&str>,
+	dependency_versions: &BTreeMap<String, String>,
+) -> String {
+	let pom = Pom::parse(contents);
+	let mut replacements = Vec::<((usize, usize), String)>::new();
+
+	if let Some(version) = version
+		&& let Some(current) = &pom.project.version
+	{
+		let target = property_reference(&current.value)
+			.and_then(|name| pom.properties.get(name))
+			.unwrap_or(current);
+		if !target.value.contains("${") {
+			replacements.push((target.span, version.to_string()));
+		}
+	}
+
+	if let Some(parent) = &pom.parent
+		&& let Some(current) = &parent.coordinates.version
+		&& let Some(released) = pom
+			.parent_coordinate()
+			.and_then(|coordinate| dependency_versions.get(&coordinate))
+		&& is_literal_version(&current.value)
+	{
+		replacements.push((current.span, released.clone()));
+	}
+
+	let group = pom.group();
+	for dependency in &pom.dependencies {
+		let (Some(dependency_group), Some(artifact), Some(current)) = (
+			dependency.coordinates.group(),
+			dependency.coordinates.artifact(),
+			&dependency.coordinates.version,
+		) else {
+			continue;
+		};
+		let dependency_group = if dependency_group == "${project.groupId}" {
+			group.unwrap_or(dependency_group)
+		} else {
+			dependency_group
+		};
+		if let Some(released) = dependency_versions.get(&coordinate(dependency_group, artifact))
+			&& is_literal_version(&current.value)
+		{
+			replacements.push((current.span, released.clone()));
+		}
+	}
+
+	apply_replacements(contents, replacements)
+}
+
+fn property_reference(value: &str) -> Option<&str> {
+	value
+		.strip_prefix("${")
+		.and_then(|rest| rest.strip_suffix('}'))
+		.filter(|name| !name.contains(['$', '{', '}']))
+}
+
+/// Literal versions are rewritten; ranges, dynamic versions, and
+/// interpolated values are left to the build tool.
+fn is_literal_version(value: &str) -> bool {
+	!value.is_empty() && !value.contains(['$', '[', '(', '+', ',']) && !value.starts_with("latest.")
+}
+
+fn apply_replacements(contents: &str, mut replacements: Vec<((usize, usize), String)>) -> String {
+	replacements.sort_by_key(|replacement| std::cmp::Reverse(replacement.0.0));
+	replacements.dedup_by_key(|replacement| replacement.0);
+
+	let mut rendered = contents.to_string();
+	for ((start, end), replacement) in replacements {
+		rendered.replace_range(start..end, &replacement);
+	}
+	rendered
+}
+
+/// Rewrite the value of `key` in a Java properties file, keeping the key's
+/// separator, spacing, and any trailing whitespace.
+///
+/// The text is returned unchanged when `key` is not set.
+#[must_use]
+pub fn update_gradle_properties_text(contents: &str, key: &str, version: &str) -> String {
+	let Some(span) = property_value_span(contents, key) else {
+		return contents.to_string();
+	};
+	apply_replacements(contents, vec![(span, version.to_string())])
+}
+
+/// Read the value of `key` from a Java properties file.
+#[must_use]
+pub fn parse_gradle_property<'a>(contents: &'a str, key: &str) -> Option<&'a str> {
+	property_value_span(contents, key).and_then(|(start, end)| contents.get(start..end))
+}
+
+fn property_value_span(contents: &str, key: &str) -> Option<(usize, usize)> {
+	let pattern = format!(
+		r"(?m)^[ \t]*{}[ \t]*[=:][ \t]*([^\r\n]*?)[ \t]*\r?$",
+		regex::escape(key)
+	);
+	let regex = Regex::new(&pattern).ok()?;
+	let value = regex.captures(contents)?.get(1)?;
+	Some((value.start(), value.end()))
+}
+
+#[must_use = "the build script update result must be checked"]
+/// Rewrite internal `"group:artifact:version"` coordinates in a Gradle build
+/// script.
+///
+/// `dependency_versions` maps `group:artifact` coordinates to released
+/// versions. Versions built from variables or version catalogs are left
+/// alone, as are dynamic versions such as `1.+`.
+pub fn update_gradle_build_text(
+	contents: &str,
+	dependency_versions: &BTreeMap<String, String>,
+) -> String {
+	let replacements = gradle_dependency_regex()
+		.captures_iter(contents)
+		.filter_map(|captures| {
+			let coordinates = captures.name("coordinates")?;
+			let (module, version) = coordinates.as_str().rsplit_once(':')?;
+			let released = dependency_versions.get(module)?;
+			if !module.contains(':') || !is_literal_version(version) {
+				return None;
+			}
+			let version_start = coordinates.start() + module.len() + 1;
+			Some(((version_start, coordinates.end()), released.clone()))
+		})
+		.collect();
+	apply_replacements(contents, replacements)
+}
+
+/// Plan the file rewrites for a release.
+///
+/// Released Maven modules get their POM version rewritten, and POMs whose
+/// parent or dependencies were released follow them. Released Gradle
+/// projects get their `gradle.properties` version, and build scripts that
+/// pin released coordinates are updated.
+pub fn jvm_manifest_updates(
+	packages: &[PackageRecord],
+	released_versions_by_record_id: &BTreeMap<String, String>,
+) -> MonochangeResult<Vec<(PathBuf, String)>> {
+	let jvm_packages = packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Jvm)
+		.collect::<Vec<_>>();
+	let released_versions = jvm_packages
+		.iter()
+		.filter_map(|package| {
+			released_versions_by_record_id
+				.get(&package.id)
+				.map(|version| (package.name.clone(), version.clone()))
+		})
+		.collect::<BTreeMap<_, _>>();
+	let mut updates = Vec::new();
+
+	for package in jvm_packages {
+		let version = released_versions_by_record_id
+			.get(&package.id)
+			.map(String::as_str);
+		let moves_dependency = package
+			.declared_dependencies
+			.iter()
+			.any(|dependency| released_versions.contains_key(&dependency.name));
+		if version.is_none() && !moves_dependency {
+			continue;
+		}
+
+		let mut rewrite = |path: &Path, render: &dyn Fn(&str) -> String| {
+			let contents = read_text(path)?;
+			let rendered = render(&contents);
+			if rendered != contents {
+				updates.push((path.to_path_buf(), rendered));
+			}
+			MonochangeResult::Ok(())
+		};
+
+		if package
+			.manifest_path
+			.file_name()
+			.is_some_and(|name| name == POM_XML)
+		{
+			rewrite(&package.manifest_path, &|contents| {
+				update_pom_text(contents, version, &released_versions)
+			})?;
+			continue;
+		}
+
+		if let Some(version) = version {
+			rewrite(&package.manifest_path, &|contents| {
+				update_gradle_properties_text(contents, VERSION_PROPERTY, version)
+			})?;
+		}
+		if moves_dependency && let Some(build_script) = gradle_build_script(&package_dir(package)) {
+			rewrite(&build_script, &|contents| {
+				update_gradle_build_text(contents, &released_versions)
+			})?;
+		}
+	}
+
+	Ok(updates)
+}
+
+fn read_text(path: &Path) -> MonochangeResult<String> {
+	fs::read_to_string(path)
+		.map_err(|error| MonochangeError::Io(format!("failed to read {}: {error}", path.display())))
+}
+
+fn package_dir(package: &PackageRecord) -> PathBuf {
+	package
+		.manifest_path
+		.parent()
+		.map_or_else(|| package.workspace_root.clone(), Path::to_path_buf)
+}
+
+fn gradle_dependency_regex() -> &'static Regex {
+	static GRADLE_DEPENDENCY_RE: OnceLock<Regex> = OnceLock::new();
+	GRADLE_DEPENDENCY_RE.get_or_init(|| {
+		Regex::new(r#"(?m)^[ \t]*(?P<configuration>[A-Za-z]+)[ \t]*\(?[ \t]*(?:project[ \t]*\([ \t]*(?:path[ \t]*[:=][ \t]*)?["'](?P<project>:[^"'\n]*)["'][ \t]*\)|["'](?P<coordinates>[^"'\s:$]+:[^"'\s:$]+(?::[^"'\s]+)?)["'])"#)
+			.unwrap_or_else(|error| panic!("gradle dependency regex should compile: {error}"))
+	})
+}
+
+fn gradle_group_regex() -> &'static Regex {
+	static GRADLE_GROUP_RE: OnceLock<Regex> = OnceLock::new();
+	GRADLE_GROUP_RE.get_or_init(|| {
+		Regex::new(r#"(?m)^[ \t]*group[ \t]*=[ \t]*["']([^"'\n]+)["']"#)
+			.unwrap_or_else(|error| panic!("gradle group regex should compile: {error}"))
+	})
+}
+
+fn gradle_root_project_name_regex() -> &'static Regex {
+	static ROOT_PROJECT_NAME_RE: OnceLock<Regex> = OnceLock::new();
+	ROOT_PROJECT_NAME_RE.get_or_init(|| {
+		Regex::new(r#"(?m)^[ \t]*rootProject\.name[ \t]*=[ \t]*["']([^"'\n]+)["']"#)
+			.unwrap_or_else(|error| panic!("gradle rootProject.name regex should compile: {error}"))
+	})
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Maven modules and versioned Gradle projects rooted at `root`.
+pub fn discover_jvm_packages(root: &Path) -> MonochangeResult<AdapterDiscovery> {
+	discover_jvm_packages_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Maven modules and versioned Gradle projects rooted at `root`
+/// without parsing manifests outside `filter`'s discovery scope.
+pub fn discover_jvm_packages_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_manifests(root, filter);
+	let mut skipped = walk.skipped;
+	let mut packages = Vec::new();
+	let mut warnings = Vec::new();
+
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Jvm, walk.manifests, &mut skipped)
+	{
+		match parse_manifest(&manifest_path, root) {
+			Ok(Some(package)) => packages.push(package),
+			Ok(None) => {}
+			Err(error) => {
+				warnings.push(format!("skipped {}: {error}", manifest_path.display()));
+			}
+		}
+	}
+
+	packages.sort_by(|left, right| left.id.cmp(&right.id));
+	packages.dedup_by(|left, right| left.id == right.id);
+	tracing::debug!(packages = packages.len(), "discovered jvm packages");
+
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
+}
+
+/// Load one explicitly configured JVM package without walking the repo.
+///
+/// `package_path` may name the `pom.xml` or `gradle.properties` itself, or
+/// the directory that holds it. A directory with both is read as a Maven
+/// module.
+#[must_use = "the package result must be checked"]
+pub fn load_configured_jvm_package(
+	root: &Path,
+	package_path: &Path,
+) -> MonochangeResult<Option<PackageRecord>> {
+	let manifest_path = if package_path.is_file() {
+		Some(package_path.to_path_buf())
+	} else {
+		[POM_XML, GRADLE_PROPERTIES]
+			.into_iter()
+			.map(|name| package_path.join(name))
+			.find(|path| path.is_file())
+	};
+	match manifest_path {
+		Some(manifest_path) => parse_manifest(&manifest_path, root),
+		None => Ok(None),
+	}
+}
+
+fn parse_manifest(manifest_path: &Path, root: &Path) -> MonochangeResult<Option<PackageRecord>> {
+	match manifest_path.file_name().and_then(|name| name.to_str()) {
+		Some(POM_XML) => parse_pom(manifest_path, root).map(Some),
+		Some(GRADLE_PROPERTIES) => parse_gradle_project(manifest_path, root),
+		_ => Ok(None),
+	}
+}
+
+/// Parse one `pom.xml` into a package record.
+///
+/// The package is named `groupId:artifactId`. A missing `groupId` or
+/// `version` is inherited from `<parent>`, and `${...}` references are
+/// resolved from the properties of the POM and the parents found through
+/// `relativePath`. The parent becomes a dependency recorded under the
+/// `parent` field, and `<dependencyManagement>` entries are recorded so a BOM
+/// follows the modules it pins.
+pub fn parse_pom(manifest_path: &Path, workspace_root: &Path) -> MonochangeResult<PackageRecord> {
+	let hierarchy = PomHierarchy::load(manifest_path)?;
+	let pom = &hierarchy.pom;
+	let properties = hierarchy.properties();
+	let (Some(group), Some(artifact)) = (pom.group(), pom.project.artifact()) else {
+		return Err(MonochangeError::Discovery(format!(
+			"{} does not declare a groupId and artifactId",
+			manifest_path.display()
+		)));
+	};
+	let resolve = |value: &str| resolve_properties(value, &properties);
+	let group = resolve(group).unwrap_or_else(|| group.to_string());
+	let version = hierarchy
+		.version_text()
+		.and_then(resolve)
+		.and_then(|version| parse_maven_version(&version));
+
+	let mut package = PackageRecord::new(
+		Ecosystem::Jvm,
+		coordinate(&group, artifact),
+		normalize_path(manifest_path),
+		normalize_path(workspace_root),
+		version,
+		PublishState::Public,
+	);
+
+	if let Some(parent) = &pom.parent
+		&& let (Some(parent_group), Some(parent_artifact)) =
+			(parent.coordinates.group(), parent.coordinates.artifact())
+	{
+		package.declared_dependencies.push(PackageDependency {
+			name: coordinate(parent_group, parent_artifact),
+			kind: DependencyKind::Build,
+			version_constraint: parent.coordinates.version().map(str::to_string),
+			optional: false,
+			source_field: Some(PARENT_FIELD.to_string()),
+		});
+	}
+
+	for dependency in &pom.dependencies {
+		let (Some(dependency_group), Some(dependency_artifact)) = (
+			dependency.coordinates.group(),
+			dependency.coordinates.artifact(),
+		) else {
+			continue;
+		};
+		let dependency_group =
+			resolve(dependency_group).unwrap_or_else(|| dependency_group.to_string());
+		package.declared_dependencies.push(PackageDependency {
+			name: coordinate(&dependency_group, dependency_artifact),
+			kind: match dependency.scope.as_deref() {
+				Some("test") => DependencyKind::Development,
+				Some("provided" | "system") => DependencyKind::Build,
+				_ => DependencyKind::Runtime,
+			},
+			version_constraint: dependency.coordinates.version().and_then(resolve),
+			optional: dependency.optional,
+			source_field: Some(dependency.section.to_string()),
+		});
+	}
+
+	Ok(package)
+}
+
+/// Parse a `gradle.properties` file into a package record.
+///
+/// Only projects with a `version=` entry are packages. The package is named
+/// `group:name`, where `group` comes from the nearest `group=` property or
+/// build-script `group = "..."` assignment and `name` is the project
+/// directory name, or `rootProject.name` for the settings root.
+pub fn parse_gradle_project(
+	manifest_path: &Path,
+	workspace_root: &Path,
+) -> MonochangeResult<Option<PackageRecord>> {
+	let manifest_path = &normalize_path(manifest_path);
+	let workspace_root = &normalize_path(workspace_root);
+	let contents = read_text(manifest_path)?;
+	let Some(version) = parse_gradle_property(&contents, VERSION_PROPERTY) else {
+		return Ok(None);
+	};
+	let project_dir = manifest_path.parent().unwrap_or(workspace_root);
+	let Some(name) = gradle_project_name(project_dir, workspace_root) else {
+		return Err(MonochangeError::Discovery(format!(
+			"failed to read a project name for {}",
+			manifest_path.display()
+		)));
+	};
+
+	let mut package = PackageRecord::new(
+		Ecosystem::Jvm,
+		name,
+		manifest_path.clone(),
+		workspace_root.clone(),
+		parse_maven_version(version),
+		PublishState::Public,
+	);
+	if let Some(build_script) = gradle_build_script(project_dir) {
+		let build_contents = read_text(&build_script)?;
+		package.declared_dependencies =
+			parse_gradle_dependencies(&build_contents, project_dir, workspace_root);
+	}
+	Ok(Some(package))
+}
+
+fn parse_gradle_dependencies(
+	contents: &str,
+	project_dir: &Path,
+	workspace_root: &Path,
+) -> Vec<PackageDependency> {
+	gradle_dependency_regex()
+		.captures_iter(contents)
+		.filter_map(|captures| {
+			let configuration = captures.name("configuration")?.as_str();
+			let kind = gradle_dependency_kind(configuration)?;
+			let (name, version_constraint) = if let Some(project) = captures.name("project") {
+				let dir = gradle_settings_root(project_dir, workspace_root)?.join(
+					project
+						.as_str()
+						.split(':')
+						.filter(|segment| !segment.is_empty())
+						.collect::<PathBuf>(),
+				);
+				(gradle_project_name(&dir, workspace_root)?, None)
+			} else {
+				let coordinates = captures.name("coordinates")?.as_str();
+				let mut parts = coordinates.splitn(3, ':');
+				let name = coordinate(parts.next()?, parts.next()?);
+				(
+					name,
+					parts
+						.next()
+						.filter(|version| !version.contains('$'))
+						.map(str::to_string),
+				)
+			};
+			Some(PackageDependency {
+				name,
+				kind,
+				version_constraint,
+				optional: false,
+				source_field: Some(DEPENDENCIES_FIELD.to_string()),
+			})
+		})
+		.collect()
+}
+
+/// Map a Gradle configuration to a dependency kind, or `None` when the call
+/// is not a dependency declaration.
+fn gradle_dependency_kind(configuration: &str) -> Option<DependencyKind> {
+	if configuration.starts_with("test") || configuration.starts_with("androidTest") {
+		Some(DependencyKind::Development)
+	} else if matches!(
+		configuration,
+		"compileOnly" | "compileOnlyApi" | "annotationProcessor" | "kapt" | "ksp" | "classpath"
+	) {
+		Some(DependencyKind::Build)
+	} else if matches!(
+		configuration,
+		"api" | "implementation" | "runtimeOnly" | "compile" | "runtime"
+	) || configuration.ends_with("Implementation")
+		|| configuration.ends_with("Api")
+	{
+		Some(DependencyKind::Runtime)
+	} else {
+		None
+	}
+}
+
+fn gradle_project_name(project_dir: &Path, workspace_root: &Path) -> Option<String> {
+	let artifact = GRADLE_SETTINGS_SCRIPTS
+		.iter()
+		.filter_map(|name| fs::read_to_string(project_dir.join(name)).ok())
+		.find_map(|contents| {
+			gradle_root_project_name_regex()
+				.captures(&contents)
+				.and_then(|captures| captures.get(1))
+				.map(|name| name.as_str().to_string())
+		})
+		.or_else(|| {
+			project_dir
+				.file_name()
+				.and_then(|name| name.to_str())
+				.map(str::to_string)
+		})?;
+	Some(match gradle_group(project_dir, workspace_root) {
+		Some(group) => coordinate(&group, &artifact),
+		None => artifact,
+	})
+}
+
+/// Find the project's group in its own or an ancestor's `gradle.properties`
+/// or build script.
+fn gradle_group(project_dir: &Path, workspace_root: &Path) -> Option<String> {
+	project_dir
+		.ancestors()
+		.take_while(|dir| dir.starts_with(workspace_root))
+		.find_map(|dir| {
+			let from_properties = fs::read_to_string(dir.join(GRADLE_PROPERTIES))
+				.ok()
+				.and_then(|contents| {
+					parse_gradle_property(&contents, GROUP_PROPERTY).map(str::to_string)
+				});
+			from_properties.or_else(|| {
+				let contents = fs::read_to_string(gradle_build_script(dir)?).ok()?;
+				gradle_group_regex()
+					.captures(&contents)
+					.and_then(|captures| captures.get(1))
+					.map(|group| group.as_str().to_string())
+			})
+		})
+}
+
+/// The nearest directory with a settings script, which `project(":a:b")`
+/// paths are relative to.
+fn gradle_settings_root(project_dir: &Path, workspace_root: &Path) -> Option<PathBuf> {
+	project_dir
+		.ancestors()
+		.take_while(|dir| dir.starts_with(workspace_root))
+		.find(|dir| {
+			GRADLE_SETTINGS_SCRIPTS
+				.iter()
+				.any(|name| dir.join(name).is_file())
+		})
+		.map(Path::to_path_buf)
+}
+
+fn gradle_build_script(project_dir: &Path) -> Option<PathBuf> {
+	GRADLE_BUILD_SCRIPTS
+		.iter()
+		.map(|name| project_dir.join(name))
+		.find(|path| path.is_file())
+}
+
+/// Parse a Maven version into semver, padding missing segments so `1.2`
+/// reads as `1.2.0` and keeping qualifiers such as `-SNAPSHOT` as
+/// pre-release identifiers.
+fn parse_maven_version(version: &str) -> Option<Version> {
+	Version::parse(version).ok().or_else(|| {
+		let (release, qualifier) = version
+			.split_once('-')
+			.map_or((version, None), |(release, qualifier)| {
+				(release, Some(qualifier))
+			});
+		let segments = release
+			.split('.')
+			.map(|segment| segment.parse::<u64>().ok())
+			.collect::<Option<Vec<_>>>()?;
+		if segments.is_empty() || segments.len() > 3 {
+			return None;
+		}
+		let segment = |index: usize| segments.get(index).copied().unwrap_or(0);
+		let padded = format!("{}.{}.{}", segment(0), segment(1), segment(2));
+		Version::parse(&qualifier.map_or_else(
+			|| padded.clone(),
+			|qualifier| format!("{padded}-{qualifier}"),
+		))
+		.ok()
+	})
+}
+
+/// Validate that a JVM versioned file contains what it will rewrite.
+///
+/// Properties files need each configured key, `version` by default, to be
+/// set.
+pub fn validate_versioned_file(
+	full_path: &Path,
+	display_path: &str,
+	custom_fields: Option<&[String]>,
+) -> MonochangeResult<()> {
+	let contents = fs::read_to_string(full_path).map_err(|error| {
+		MonochangeError::Config(format!(
+			"versioned file `{display_path}` is not readable: {error}"
+		))
+	})?;
+	if supported_versioned_file_kind(full_path) != Some(JvmVersionedFileKind::GradleProperties) {
+		return Ok(());
+	}
+	let default_keys = [VERSION_PROPERTY.to_string()];
+	for key in custom_fields.unwrap_or(&default_keys) {
+		if parse_gradle_property(&contents, key).is_none() {
+			return Err(MonochangeError::Config(format!(
+				"versioned file `{display_path}` does not set `{key}`"
+			)));
+		}
+	}
+
+	Ok(())
+}
+
+/// Return the default dependency-version prefix for this ecosystem.
+///
+/// Maven and Gradle read a bare version as the exact release to use.
+#[must_use]
+pub fn default_dependency_version_prefix() -> &'static str {
+	""
+}
+
+/// Return the manifest sections that usually declare dependency versions.
+#[must_use]
+pub fn default_dependency_fields() -> &'static [&'static str] {
+	&[DEPENDENCIES_FIELD]
+}
+
+fn find_all_manifests(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(
+		Ecosystem::Jvm,
+		root,
+		|entry| filter.should_descend(entry.path()) && !is_build_output_dir(entry),
+		|entry| {
+			entry.file_type().is_file()
+				&& matches!(
+					entry.file_name().to_str(),
+					Some(POM_XML | GRADLE_PROPERTIES)
+				)
+		},
+	)
+}
+
+/// Skip build output and tool state, which can hold copies of POMs and
+/// properties files.
+fn is_build_output_dir(entry: &DirEntry) -> bool {
+	entry.file_type().is_dir()
+		&& matches!(
+			entry.file_name().to_str(),
+			Some("target" | "build" | ".gradle" | ".mvn")
+		)
+}
+
+#[cfg(test)]
+#[path = "__tests__/lib_tests.rs"]
+mod tests;
@@ -3,6 +3,7 @@ use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 
+use monochange_core::DiscoveryPathFilter;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::PackageRecord;
@@ -19,20 +20,26 @@ use crate::default_lockfile_commands;
 use crate::detect_npm_manager;
 use crate::discover_lockfiles;
 use crate::discover_npm_packages;
+use crate::discover_npm_packages_with_filter;
 use crate::discover_package_json_workspace;
 use crate::discover_pnpm_workspace;
 use crate::expand_member_patterns;
+use crate::find_pnpm_workspace_manifest;
 use crate::load_configured_npm_package;
 use crate::package_json_declares_workspaces;
 use crate::parse_package_json;
+use crate::pnpm_catalog_updates;
 use crate::supported_versioned_file_kind;
 use crate::update_bun_lock;
 use crate::update_bun_lock_binary;
 use crate::update_json_dependency_fields;
+use crate::update_manifest_text;
 use crate::update_package_lock;
+use crate::update_pnpm_catalog_text;
 use crate::update_pnpm_lock;
 use crate::update_pnpm_lock_text;
 use crate::workspace_patterns_from_package_json;
+use crate::workspace_protocol_specifier;
 
 #[test]
 fn discovers_npm_workspace_packages() {
@@ -73,6 +80,55 @@ fn discovers_pnpm_workspace_globs() {
 	);
 }
 
+#[test]
+fn discovers_pnpm_catalog_dependencies_with_resolved_ranges() {
+	let fixture_root =
+		Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/npm/workspace-pnpm-catalog");
+	let discovery = discover_npm_packages(&fixture_root)
+		.unwrap_or_else(|error| panic!("pnpm catalog discovery: {error}"));
+
+	let constraints = discovery
+		.packages
+		.iter()
+		.flat_map(|package| {
+			package.declared_dependencies.iter().map(|dependency| {
+				(
+					package.name.as_str(),
+					dependency.name.as_str(),
+					dependency.version_constraint.as_deref(),
+				)
+			})
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(
+		constraints,
+		vec![
+			("pnpm-catalog-legacy", "pnpm-catalog-shared", Some("~1.0.0")),
+			("pnpm-catalog-legacy", "typescript", Some("5.4.0")),
+			("pnpm-catalog-web", "pnpm-catalog-shared", Some("^1.0.0")),
+			("pnpm-catalog-web", "react", Some("^18.2.0")),
+		]
+	);
+	let edges = materialize_dependency_edges(&discovery.packages);
+	assert_eq!(edges.len(), 2);
+	assert!(edges.iter().all(|edge| {
+		edge.to_package_id.ends_with("packages/shared/package.json")
+			&& edge.version_constraint.as_deref() != Some("catalog:")
+	}));
+
+	let configured =
+		load_configured_npm_package(&fixture_root, &fixture_root.join("packages/legacy"))
+			.unwrap_or_else(|error| panic!("load configured: {error}"))
+			.unwrap_or_else(|| panic!("legacy package"));
+	assert_eq!(
+		configured
+			.declared_dependencies
+			.first()
+			.and_then(|dependency| dependency.version_constraint.as_deref()),
+		Some("~1.0.0")
+	);
+}
+
 #[test]
 fn discovers_bun_workspace_packages() {
 	let fixture_root =
@@ -92,6 +148,100 @@ fn discovers_bun_workspace_packages() {
 	);
 }
 
+#[test]
+fn discovers_yarn_workspace_packages() {
+	let fixture_root =
+		Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/npm/workspace-yarn");
+	let discovery = discover_npm_packages(&fixture_root)
+		.unwrap_or_else(|error| panic!("yarn discovery: {error}"));
+
+	assert_eq!(discovery.packages.len(), 2);
+	let web_package = discovery
+		.packages
+		.iter()
+		.find(|package| package.name == "yarn-web")
+		.unwrap_or_else(|| panic!("yarn web package should exist"));
+	assert_eq!(
+		web_package.metadata.get("manager").map(String::as_str),
+		Some("yarn")
+	);
+	assert_eq!(
+		web_package
+			.declared_dependencies
+			.first()
+			.and_then(|dependency| dependency.version_constraint.as_deref()),
+		Some("workspace:^")
+	);
+	assert_eq!(materialize_dependency_edges(&discovery.packages).len(), 1);
+	assert_eq!(
+		default_lockfile_commands(web_package),
+		vec![monochange_core::LockfileCommandExecution {
+			command: "yarn install --mode=update-lockfile".to_string(),
+			cwd: monochange_core::normalize_path(&fixture_root),
+			shell: monochange_core::ShellConfig::None,
+		}]
+	);
+}
+
+#[test]
+fn discover_npm_packages_with_filter_never_parses_manifests_outside_the_scope() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	for (manifest, contents) in [
+		(
+			"package.json",
+			r#"{ "name": "root", "private": true, "workspaces": ["packages/*"] }"#,
+		),
+		(
+			"packages/web/package.json",
+			r#"{ "name": "web", "version": "1.0.0" }"#,
+		),
+		("packages/web/examples/broken/package.json", "{ not json"),
+		("vendor/lib/package.json", "{ not json"),
+	] {
+		let path = root.join(manifest);
+		fs::create_dir_all(path.parent().unwrap_or(root))
+			.unwrap_or_else(|error| panic!("create dir: {error}"));
+		fs::write(&path, contents).unwrap_or_else(|error| panic!("write manifest: {error}"));
+	}
+	assert!(discover_npm_packages(root).is_err());
+	let filter = DiscoveryPathFilter::new(root)
+		.with_scope(&["packages/*".to_string()], &["**/examples/**".to_string()])
+		.unwrap_or_else(|error| panic!("scope: {error}"));
+
+	let discovery = discover_npm_packages_with_filter(root, &filter)
+		.unwrap_or_else(|error| panic!("scoped discovery: {error}"));
+
+	assert_eq!(
+		discovery
+			.packages
+			.iter()
+			.map(|package| package.name.as_str())
+			.collect::<Vec<_>>(),
+		vec!["web"]
+	);
+	assert!(discovery.warnings.is_empty());
+	let mut skipped = discovery
+		.skipped
+		.iter()
+		.map(|skip| {
+			skip.path
+				.strip_prefix(monochange_core::normalize_path(root))
+				.unwrap_or(&skip.path)
+				.to_path_buf()
+		})
+		.collect::<Vec<_>>();
+	skipped.sort();
+	assert_eq!(
+		skipped,
+		vec![
+			PathBuf::from("package.json"),
+			PathBuf::from("packages/web/examples/broken"),
+			PathBuf::from("vendor"),
+		]
+	);
+}
+
 #[test]
 fn adapter_reports_npm_ecosystem() {
 	assert_eq!(adapter().ecosystem(), Ecosystem::Npm);
@@ -259,6 +409,166 @@ fn update_json_dependency_fields_only_changes_declared_dependencies() {
 	);
 }
 
+#[test]
+fn update_manifest_text_keeps_workspace_protocol_dependencies_on_the_protocol() {
+	let contents = r#"{
+  "name": "web",
+  "version": "1.0.0",
+  "dependencies": {
+    "caret": "workspace:^",
+    "pinned": "workspace:^1.0.0",
+    "plain": "^1.0.0",
+    "catalogued": "catalog:"
+  },
+  "devDependencies": {
+    "caret": "^1.0.0",
+    "path": "workspace:packages/path"
+  }
+}
+"#;
+	let raw_versions = ["caret", "pinned", "plain", "path", "catalogued"]
+		.into_iter()
+		.map(|name| (name.to_string(), "1.2.0".to_string()))
+		.collect::<BTreeMap<_, _>>();
+	let versioned_deps = raw_versions
+		.iter()
+		.map(|(name, version)| (name.clone(), format!("^{version}")))
+		.collect::<BTreeMap<_, _>>();
+
+	let updated = update_manifest_text(
+		contents,
+		Some("1.1.0"),
+		&["dependencies", "devDependencies"],
+		&versioned_deps,
+		&raw_versions,
+	)
+	.unwrap_or_else(|error| panic!("update manifest: {error}"));
+
+	assert_eq!(
+		updated,
+		r#"{
+  "name": "web",
+  "version": "1.1.0",
+  "dependencies": {
+    "caret": "workspace:^",
+    "pinned": "workspace:^1.2.0",
+    "plain": "^1.2.0",
+    "catalogued": "catalog:"
+  },
+  "devDependencies": {
+    "caret": "^1.2.0",
+    "path": "workspace:packages/path"
+  }
+}
+"#
+	);
+}
+
+#[test]
+fn workspace_protocol_specifier_only_rewrites_explicit_ranges() {
+	for (current, expected) in [
+		("workspace:*", None),
+		("workspace:^", None),
+		("workspace:~", None),
+		("workspace:packages/shared", None),
+		("workspace:1.0.0", Some("workspace:2.0.0")),
+		("workspace:~1.0.0", Some("workspace:~2.0.0")),
+		("workspace:>=1.0.0", Some("workspace:>=2.0.0")),
+		("^1.0.0", None),
+	] {
+		assert_eq!(
+			workspace_protocol_specifier(current, "2.0.0").as_deref(),
+			expected,
+			"{current}"
+		);
+	}
+}
+
+#[test]
+fn update_pnpm_catalog_text_moves_default_and_named_catalog_entries() {
+	let contents = r#"packages:
+  - packages/*
+
+catalog:
+  shared: ^1.0.0 # internal package
+  react: ^18.2.0
+
+catalogs:
+  legacy:
+    "shared": "~1.0.0"
+    other: ^1.0.0 || ^2.0.0
+  tooling:
+    typescript: 5.4.0
+"#;
+	let raw_versions = BTreeMap::from([
+		("shared".to_string(), "1.3.0".to_string()),
+		("other".to_string(), "3.0.0".to_string()),
+	]);
+
+	let updated = update_pnpm_catalog_text(contents, &raw_versions)
+		.unwrap_or_else(|error| panic!("update catalog: {error}"));
+
+	assert_eq!(
+		updated,
+		r#"packages:
+  - packages/*
+
+catalog:
+  shared: ^1.3.0 # internal package
+  react: ^18.2.0
+
+catalogs:
+  legacy:
+    "shared": "~1.3.0"
+    other: ^1.0.0 || ^2.0.0
+  tooling:
+    typescript: 5.4.0
+"#
+	);
+	assert!(update_pnpm_catalog_text("catalog: [", &raw_versions).is_err());
+}
+
+#[test]
+fn pnpm_catalog_updates_rewrite_the_owning_workspace_file() {
+	let fixture_root =
+		Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/npm/workspace-pnpm-catalog");
+	let packages = discover_npm_packages(&fixture_root)
+		.unwrap_or_else(|error| panic!("pnpm catalog discovery: {error}"))
+		.packages;
+	let shared = packages
+		.iter()
+		.find(|package| package.name == "pnpm-catalog-shared")
+		.unwrap_or_else(|| panic!("shared package"));
+	let workspace_manifest =
+		monochange_core::normalize_path(&fixture_root.join("pnpm-workspace.yaml"));
+	assert_eq!(
+		find_pnpm_workspace_manifest(&fixture_root, &shared.manifest_path),
+		Some(workspace_manifest.clone())
+	);
+	assert_eq!(
+		find_pnpm_workspace_manifest(&fixture_root.join("packages"), &shared.manifest_path),
+		None
+	);
+
+	let updates = pnpm_catalog_updates(
+		&fixture_root,
+		&packages,
+		&BTreeMap::from([(shared.id.clone(), "2.0.0".to_string())]),
+	)
+	.unwrap_or_else(|error| panic!("catalog updates: {error}"));
+
+	assert_eq!(updates.len(), 1);
+	let (path, contents) = updates.first().unwrap_or_else(|| panic!("catalog update"));
+	assert_eq!(path, &workspace_manifest);
+	assert!(contents.contains("  pnpm-catalog-shared: ^2.0.0 # internal package\n"));
+	assert!(contents.contains("    \"pnpm-catalog-shared\": \"~2.0.0\"\n"));
+	assert!(
+		pnpm_catalog_updates(&fixture_root, &packages, &BTreeMap::new())
+			.unwrap_or_else(|error| panic!("catalog updates: {error}"))
+			.is_empty()
+	);
+}
+
 #[test]
 fn update_package_lock_updates_root_packages_and_dependencies() {
 	let mut lock = json!({
@@ -696,11 +1006,20 @@ fn workspace_pattern_helpers_cover_array_object_and_missing_cases() {
 }
 
 #[test]
-fn detect_npm_manager_prefers_bun_then_pnpm_then_npm() {
+fn detect_npm_manager_prefers_bun_then_pnpm_then_yarn_then_npm() {
 	let bun_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/npm/workspace-bun");
 	assert_eq!(detect_npm_manager(&bun_root), "bun");
 	let pnpm_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/npm/workspace-pnpm");
 	assert_eq!(detect_npm_manager(&pnpm_root), "pnpm");
+	let yarn_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/npm/workspace-yarn");
+	assert_eq!(detect_npm_manager(&yarn_root), "yarn");
+	let package_manager_root = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	fs::write(
+		package_manager_root.path().join("package.json"),
+		r#"{ "private": true, "packageManager": "yarn@4.5.0" }"#,
+	)
+	.unwrap_or_else(|error| panic!("write package.json: {error}"));
+	assert_eq!(detect_npm_manager(package_manager_root.path()), "yarn");
 	let npm_root =
 		Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/tests/npm/standalone-package");
 	assert_eq!(detect_npm_manager(&npm_root), "npm");
@@ -747,14 +1066,16 @@ fn package_json_parsing_and_workspace_detection_report_parse_errors() {
 		.unwrap_or_else(|| panic!("expected invalid workspace parse error"));
 	assert!(error.to_string().contains("failed to parse"));
 
-	let workspace_error = discover_package_json_workspace(&invalid_workspace)
-		.err()
-		.unwrap_or_else(|| panic!("expected workspace discovery error"));
+	let filter = DiscoveryPathFilter::new(Path::new(env!("CARGO_MANIFEST_DIR")));
+	let workspace_error =
+		discover_package_json_workspace(&invalid_workspace, &filter, &mut Vec::new())
+			.err()
+			.unwrap_or_else(|| panic!("expected workspace discovery error"));
 	assert!(workspace_error.to_string().contains("failed to parse"));
 
 	let invalid_pnpm = Path::new(env!("CARGO_MANIFEST_DIR"))
 		.join("../../fixtures/tests/npm/invalid-pnpm-workspace/invalid-pnpm-workspace.yaml");
-	let pnpm_error = discover_pnpm_workspace(&invalid_pnpm)
+	let pnpm_error = discover_pnpm_workspace(&invalid_pnpm, &filter, &mut Vec::new())
 		.err()
 		.unwrap_or_else(|| panic!("expected pnpm parse error"));
 	assert!(pnpm_error.to_string().contains("failed to parse"));
@@ -5,30 +5,33 @@
 //! <!-- {=monochangeNpmCrateDocs|trim|linePrefix:"//! ":true} -->
 //! `monochange_npm` discovers npm-family packages and normalizes them for shared planning.
 //!
-//! Reach for this crate when you want one adapter for npm, pnpm, and Bun workspaces that emits `monochange_core` package and dependency records.
+//! Reach for this crate when you want one adapter for npm, pnpm, Yarn, and Bun workspaces that emits `monochange_core` package and dependency records.
 //!
 //! ## Why use it?
 //!
 //! - discover several JavaScript package-manager layouts with one crate
 //! - normalize workspace metadata into the same graph used by the rest of `monochange`
-//! - capture dependency edges from `package.json` and `pnpm-workspace.yaml`
+//! - capture dependency edges from `package.json` and `pnpm-workspace.yaml`, including pnpm catalogs
 //!
 //! ## Best for
 //!
 //! - scanning JavaScript or TypeScript monorepos into normalized package records
-//! - supporting npm, pnpm, and Bun with one discovery surface
+//! - supporting npm, pnpm, Yarn, and Bun with one discovery surface
 //! - feeding JS workspace topology into shared planning code
 //!
 //! ## Public entry points
 //!
-//! - `discover_npm_packages(root)` discovers npm, pnpm, and Bun workspaces plus standalone packages
+//! - `discover_npm_packages(root)` discovers npm, pnpm, Yarn, and Bun workspaces plus standalone packages
+//! - `update_manifest_text(...)` rewrites `package.json` versions while keeping `workspace:` protocol ranges
 //! - `NpmAdapter` exposes the shared adapter interface
 //!
 //! ## Scope
 //!
 //! - `package.json` workspaces
 //! - `pnpm-workspace.yaml`
-//! - Bun lockfile detection
+//! - Bun and Yarn lockfile detection
+//! - `workspace:` protocol ranges
+//! - pnpm `catalog` and `catalogs` entries
 //! - normalized dependency extraction
 //! <!-- {/monochangeNpmCrateDocs} -->
 
@@ -48,9 +51,11 @@ use glob::glob;
 use monochange_core::AdapterDiscovery;
 use monochange_core::DependencyKind;
 use monochange_core::DiscoveryPathFilter;
+use monochange_core::DiscoverySkip;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageDependency;
@@ -61,6 +66,7 @@ use monochange_core::ShellConfig;
 use monochange_core::SourceConfiguration;
 use monochange_core::normalize_path;
 use monochange_core::relative_to_root;
+use monochange_core::update_json_manifest_text;
 use monochange_github::GitHubTrustContext;
 use monochange_publish::CommandSpec;
 use monochange_publish::PublishRequest;
@@ -68,11 +74,11 @@ use monochange_publish::render_command;
 use semver::Version;
 use serde_json::Value;
 use serde_yaml_ng::Value as YamlValue;
-use walkdir::DirEntry;
-use walkdir::WalkDir;
 
 pub const PACKAGE_JSON_FILE: &str = "package.json";
 pub const PNPM_WORKSPACE_FILE: &str = "pnpm-workspace.yaml";
+pub const YARN_LOCK_FILE: &str = "yarn.lock";
+pub const YARNRC_FILE: &str = ".yarnrc.yml";
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum NpmVersionedFileKind {
@@ -118,6 +124,7 @@ pub fn discover_lockfiles(package: &PackageRecord) -> Vec<PathBuf> {
 		"package-lock.json",
 		"bun.lock",
 		"bun.lockb",
+		YARN_LOCK_FILE,
 	];
 
 	let mut discovered = candidate_names
@@ -151,6 +158,8 @@ pub fn default_lockfile_commands(package: &PackageRecord) -> Vec<LockfileCommand
 				"npm install --package-lock-only"
 			} else if file_name == "pnpm-lock.yaml" {
 				"pnpm install --lockfile-only"
+			} else if file_name == YARN_LOCK_FILE {
+				"yarn install --mode=update-lockfile"
 			} else {
 				"bun install --lockfile-only"
 			};
@@ -184,6 +193,327 @@ pub fn update_json_dependency_fields(
 	}
 }
 
+/// Update `package.json` text, keeping `workspace:` protocol dependencies on
+/// the protocol.
+///
+/// `workspace:*`, `workspace:^`, and `workspace:~` are resolved by Yarn and
+/// pnpm at publish time, so they are left alone. A protocol with an explicit
+/// range such as `workspace:^1.2.0` keeps its operator and moves to the
+/// released version. `catalog:` references are skipped because their range
+/// lives in `pnpm-workspace.yaml`. Every other dependency receives its entry
+/// from `versioned_deps`.
+#[must_use = "the manifest update result must be checked"]
+pub fn update_manifest_text(
+	contents: &str,
+	owner_version: Option<&str>,
+	fields: &[&str],
+	versioned_deps: &BTreeMap<String, String>,
+	raw_versions: &BTreeMap<String, String>,
+) -> MonochangeResult<String> {
+	let Some(parsed) = serde_json::from_str::<Value>(contents)
+		.ok()
+		.filter(|_| !fields.is_empty())
+	else {
+		return update_json_manifest_text(contents, owner_version, fields, versioned_deps);
+	};
+	let mut updated = contents.to_string();
+	for field in fields {
+		let section = field
+			.split('.')
+			.try_fold(&parsed, |value, segment| value.get(segment));
+		let field_deps = versioned_deps
+			.iter()
+			.filter_map(|(name, rendered)| {
+				match section
+					.and_then(|section| section.get(name))
+					.and_then(Value::as_str)
+				{
+					Some(current) if current.starts_with("workspace:") => {
+						workspace_protocol_specifier(current, raw_versions.get(name)?)
+							.map(|specifier| (name.clone(), specifier))
+					}
+					Some(current) if current.starts_with("catalog:") => None,
+					_ => Some((name.clone(), rendered.clone())),
+				}
+			})
+			.collect::<BTreeMap<_, _>>();
+		updated = update_json_manifest_text(&updated, owner_version, &[field], &field_deps)?;
+	}
+	Ok(updated)
+}
+
+/// Render a `workspace:` dependency specifier for a released `version`.
+///
+/// Returns `None` when the specifier already follows the workspace package
+/// (`workspace:*`, `workspace:^`, `workspace:~`) or points at a path instead
+/// of a version range.
+#[must_use]
+pub fn workspace_protocol_specifier(current: &str, version: &str) -> Option<String> {
+	let range = current.strip_prefix("workspace:")?;
+	range_for_version(range, version).map(|range| format!("workspace:{range}"))
+}
+
+/// Move a single-version range such as `^1.2.0` to `version`, keeping its
+/// operator. Compound ranges, tags, and paths return `None`.
+fn range_for_version(range: &str, version: &str) -> Option<String> {
+	let operator_len = range.len() - range.trim_start_matches(['^', '~', '>', '<', '=']).len();
+	let (operator, current_version) = range.split_at(operator_len);
+	Version::parse(current_version.trim()).ok()?;
+	Some(format!("{operator}{version}"))
+}
+
+/// Catalogs declared in `pnpm-workspace.yaml`.
+///
+/// `catalog` is the default catalog and `catalogs` holds named ones. A
+/// `catalogs.default` entry is merged into the default catalog, matching pnpm.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct PnpmCatalogs {
+	pub default: BTreeMap<String, String>,
+	pub named: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl PnpmCatalogs {
+	/// Read the catalogs from a parsed `pnpm-workspace.yaml` document.
+	#[must_use]
+	pub fn from_workspace(parsed: &YamlValue) -> Self {
+		let mut named = parsed
+			.get("catalogs")
+			.and_then(YamlValue::as_mapping)
+			.map(|catalogs| {
+				catalogs
+					.iter()
+					.filter_map(|(name, entries)| {
+						Some((name.as_str()?.to_string(), yaml_string_map(entries)))
+					})
+					.collect::<BTreeMap<_, _>>()
+			})
+			.unwrap_or_default();
+		let mut default = named.remove("default").unwrap_or_default();
+		default.extend(
+			parsed
+				.get("catalog")
+				.map(yaml_string_map)
+				.unwrap_or_default(),
+		);
+		Self { default, named }
+	}
+
+	/// Resolve a `catalog:` or `catalog:<name>` specifier for `dependency`.
+	#[must_use]
+	pub fn resolve(&self, specifier: &str, dependency: &str) -> Option<&str> {
+		let catalog = match specifier.strip_prefix("catalog:")?.trim() {
+			"" | "default" => &self.default,
+			name => self.named.get(name)?,
+		};
+		catalog.get(dependency).map(String::as_str)
+	}
+}
+
+fn yaml_string_map(value: &YamlValue) -> BTreeMap<String, String> {
+	value
+		.as_mapping()
+		.map(|entries| {
+			entries
+				.iter()
+				.filter_map(|(name, range)| {
+					Some((name.as_str()?.to_string(), range.as_str()?.to_string()))
+				})
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// Replace `catalog:` dependency constraints on `package` with the ranges
+/// they resolve to, so dependency edges carry real ranges.
+fn resolve_catalog_dependencies(package: &mut PackageRecord, catalogs: &PnpmCatalogs) {
+	for dependency in &mut package.declared_dependencies {
+		let Some(range) = dependency
+			.version_constraint
+			.as_deref()
+			.and_then(|constraint| catalogs.resolve(constraint, &dependency.name))
+		else {
+			continue;
+		};
+		dependency.version_constraint = Some(range.to_string());
+	}
+}
+
+/// Find the `pnpm-workspace.yaml` that owns `manifest_path`, searching from
+/// the manifest directory up to `root`.
+#[must_use]
+pub fn find_pnpm_workspace_manifest(root: &Path, manifest_path: &Path) -> Option<PathBuf> {
+	let root = normalize_path(root);
+	let mut directory = manifest_path
+		.parent()
+		.map(normalize_path)
+		.filter(|directory| directory.starts_with(&root));
+	while let Some(current) = directory {
+		let candidate = current.join(PNPM_WORKSPACE_FILE);
+		if candidate.is_file() {
+			return Some(candidate);
+		}
+		if current == root {
+			return None;
+		}
+		directory = current.parent().map(Path::to_path_buf);
+	}
+	None
+}
+
+/// Update catalog entries in `pnpm-workspace.yaml` text for released packages
+/// while preserving formatting.
+///
+/// Only entries with a single-version range such as `^1.2.0` are rewritten;
+/// the operator and quoting are kept.
+#[must_use = "the catalog update result must be checked"]
+pub fn update_pnpm_catalog_text(
+	contents: &str,
+	raw_versions: &BTreeMap<String, String>,
+) -> MonochangeResult<String> {
+	serde_yaml_ng::from_str::<YamlValue>(contents).map_err(|error| {
+		MonochangeError::Config(format!("failed to parse pnpm workspace yaml: {error}"))
+	})?;
+	let line_ranges = yaml_line_ranges(contents);
+	let mut replacements = Vec::<((usize, usize), String)>::new();
+	if let Some(index) = find_yaml_key_line(contents, &line_ranges, 0, "catalog") {
+		collect_catalog_replacements(
+			contents,
+			&line_ranges,
+			index,
+			raw_versions,
+			&mut replacements,
+		);
+	}
+	if let Some(index) = find_yaml_key_line(contents, &line_ranges, 0, "catalogs") {
+		for catalog_index in yaml_child_lines(contents, &line_ranges, index) {
+			collect_catalog_replacements(
+				contents,
+				&line_ranges,
+				catalog_index,
+				raw_versions,
+				&mut replacements,
+			);
+		}
+	}
+	replacements.sort_by_key(|right| std::cmp::Reverse(right.0.0));
+	let mut updated = contents.to_string();
+	for ((start, end), replacement) in replacements {
+		updated.replace_range(start..end, &replacement);
+	}
+	Ok(updated)
+}
+
+/// Indexes of the direct children of the mapping key at `parent_index`.
+fn yaml_child_lines(
+	contents: &str,
+	line_ranges: &[(usize, usize)],
+	parent_index: usize,
+) -> Vec<usize> {
+	let Some(parent) = line_ranges
+		.get(parent_index)
+		.and_then(|range| parse_yaml_line(contents, *range))
+	else {
+		return Vec::new();
+	};
+	let mut children = Vec::new();
+	let mut child_indent = None;
+	for (index, range) in line_ranges.iter().enumerate().skip(parent_index + 1) {
+		let Some(line) = parse_yaml_line(contents, *range) else {
+			continue;
+		};
+		if line.indent <= parent.indent {
+			break;
+		}
+		if *child_indent.get_or_insert(line.indent) == line.indent {
+			children.push(index);
+		}
+	}
+	children
+}
+
+fn collect_catalog_replacements(
+	contents: &str,
+	line_ranges: &[(usize, usize)],
+	catalog_index: usize,
+	raw_versions: &BTreeMap<String, String>,
+	replacements: &mut Vec<((usize, usize), String)>,
+) {
+	for index in yaml_child_lines(contents, line_ranges, catalog_index) {
+		let Some(line) = line_ranges
+			.get(index)
+			.and_then(|range| parse_yaml_line(contents, *range))
+		else {
+			continue;
+		};
+		let Some(version) = raw_versions.get(line.key.trim_matches(['"', '\''])) else {
+			continue;
+		};
+		let Some((start, end)) = line.value_span else {
+			continue;
+		};
+		let Some(existing) = contents.get(start..end) else {
+			continue;
+		};
+		let Some(range) = range_for_version(existing.trim_matches(['"', '\'']), version) else {
+			continue;
+		};
+		let replacement = render_yaml_scalar(existing, &range);
+		if replacement != existing {
+			replacements.push(((start, end), replacement));
+		}
+	}
+}
+
+/// Compute the `pnpm-workspace.yaml` rewrites needed when packages referenced
+/// through pnpm catalogs are released.
+///
+/// `released_versions` maps package record ids to their new versions. Every
+/// workspace file that owns an npm package in `packages` is checked, and its
+/// `catalog` and `catalogs` entries for released packages move to the new
+/// version. Returns the changed files with their new contents.
+pub fn pnpm_catalog_updates(
+	root: &Path,
+	packages: &[PackageRecord],
+	released_versions: &BTreeMap<String, String>,
+) -> MonochangeResult<Vec<(PathBuf, String)>> {
+	let raw_versions = packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Npm)
+		.filter_map(|package| {
+			released_versions
+				.get(&package.id)
+				.map(|version| (package.name.clone(), version.clone()))
+		})
+		.collect::<BTreeMap<_, _>>();
+	if raw_versions.is_empty() {
+		return Ok(Vec::new());
+	}
+	let workspace_manifests = packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Npm)
+		.filter_map(|package| find_pnpm_workspace_manifest(root, &package.manifest_path))
+		.collect::<BTreeSet<_>>();
+	let mut updates = Vec::new();
+	for workspace_manifest in workspace_manifests {
+		let contents = fs::read_to_string(&workspace_manifest).map_err(|error| {
+			MonochangeError::Io(format!(
+				"failed to read {}: {error}",
+				workspace_manifest.display()
+			))
+		})?;
+		let rendered = update_pnpm_catalog_text(&contents, &raw_versions).map_err(|error| {
+			MonochangeError::Config(format!(
+				"failed to parse {}: {error}",
+				workspace_manifest.display()
+			))
+		})?;
+		if rendered != contents {
+			updates.push((workspace_manifest, rendered));
+		}
+	}
+	Ok(updates)
+}
+
 /// Update versions embedded in a parsed `package-lock.json` document.
 pub fn update_package_lock(
 	value: &mut Value,
@@ -621,6 +951,14 @@ impl EcosystemAdapter for NpmAdapter {
 		discover_npm_packages(root)
 	}
 
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_npm_packages_with_filter(root, filter)
+	}
+
 	fn load_configured(
 		&self,
 		root: &Path,
@@ -645,15 +983,28 @@ impl EcosystemAdapter for NpmAdapter {
 
 #[tracing::instrument(skip_all)]
 #[must_use = "the discovery result must be checked"]
-/// Discover npm, pnpm, and Bun packages rooted at `root`.
+/// Discover npm, pnpm, Yarn, and Bun packages rooted at `root`.
 pub fn discover_npm_packages(root: &Path) -> MonochangeResult<AdapterDiscovery> {
+	discover_npm_packages_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover npm, pnpm, Yarn, and Bun packages rooted at `root` without parsing
+/// manifests outside `filter`'s discovery scope.
+pub fn discover_npm_packages_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_package_json(root, filter);
+	let mut skipped = walk.skipped;
 	let mut included_manifests = HashSet::new();
 	let mut packages = Vec::new();
 	let mut warnings = Vec::new();
 
-	for workspace_manifest in find_package_json_workspaces(root) {
+	for workspace_manifest in find_package_json_workspaces(&walk.manifests) {
 		let (workspace_packages, workspace_warnings) =
-			discover_package_json_workspace(&workspace_manifest)?;
+			discover_package_json_workspace(&workspace_manifest, filter, &mut skipped)?;
 		warnings.extend(workspace_warnings);
 		for package in workspace_packages {
 			included_manifests.insert(package.manifest_path.clone());
@@ -661,9 +1012,9 @@ pub fn discover_npm_packages(root: &Path) -> MonochangeResult<AdapterDiscovery>
 		}
 	}
 
-	for workspace_manifest in find_pnpm_workspaces(root) {
+	for workspace_manifest in find_pnpm_workspaces(root, filter) {
 		let (workspace_packages, workspace_warnings) =
-			discover_pnpm_workspace(&workspace_manifest)?;
+			discover_pnpm_workspace(&workspace_manifest, filter, &mut skipped)?;
 		warnings.extend(workspace_warnings);
 		for package in workspace_packages {
 			included_manifests.insert(package.manifest_path.clone());
@@ -671,7 +1022,9 @@ pub fn discover_npm_packages(root: &Path) -> MonochangeResult<AdapterDiscovery>
 		}
 	}
 
-	for manifest_path in find_all_package_json(root) {
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Npm, walk.manifests, &mut skipped)
+	{
 		if included_manifests.contains(&manifest_path) {
 			continue;
 		}
@@ -690,7 +1043,11 @@ pub fn discover_npm_packages(root: &Path) -> MonochangeResult<AdapterDiscovery>
 	packages.dedup_by(|left, right| left.id == right.id);
 	tracing::debug!(packages = packages.len(), "discovered npm packages");
 
-	Ok(AdapterDiscovery { packages, warnings })
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
 }
 
 /// Load one explicitly configured npm package without recursively scanning the repo.
@@ -717,11 +1074,30 @@ pub fn load_configured_npm_package(
 		detect_npm_manager(workspace_root),
 	)?;
 	if let Some(package) = package.as_mut() {
+		if let Some(workspace_manifest) = find_pnpm_workspace_manifest(root, &manifest_path) {
+			resolve_catalog_dependencies(package, &read_pnpm_catalogs(&workspace_manifest)?);
+		}
 		normalize_package_id(root, package);
 	}
 	Ok(package)
 }
 
+fn read_pnpm_catalogs(workspace_manifest: &Path) -> MonochangeResult<PnpmCatalogs> {
+	let contents = fs::read_to_string(workspace_manifest).map_err(|error| {
+		MonochangeError::Io(format!(
+			"failed to read {}: {error}",
+			workspace_manifest.display()
+		))
+	})?;
+	let parsed = serde_yaml_ng::from_str::<YamlValue>(&contents).map_err(|error| {
+		MonochangeError::Discovery(format!(
+			"failed to parse {}: {error}",
+			workspace_manifest.display()
+		))
+	})?;
+	Ok(PnpmCatalogs::from_workspace(&parsed))
+}
+
 fn normalize_package_ids(root: &Path, packages: &mut [PackageRecord]) {
 	for package in packages {
 		normalize_package_id(root, package);
@@ -741,6 +1117,8 @@ fn normalize_package_id(root: &Path, package: &mut PackageRecord) {
 
 fn discover_package_json_workspace(
 	workspace_manifest: &Path,
+	filter: &DiscoveryPathFilter,
+	skipped: &mut Vec<DiscoverySkip>,
 ) -> MonochangeResult<(Vec<PackageRecord>, Vec<String>)> {
 	let contents = fs::read_to_string(workspace_manifest).map_err(|error| {
 		MonochangeError::Io(format!(
@@ -759,7 +1137,13 @@ fn discover_package_json_workspace(
 		.unwrap_or_else(|| Path::new("."));
 	let patterns = workspace_patterns_from_package_json(&parsed);
 	let mut warnings = Vec::new();
-	let manifests = expand_member_patterns(workspace_root, &patterns, &mut warnings);
+	let manifests = filter.retain_manifests_in_scope(
+		Ecosystem::Npm,
+		expand_member_patterns(workspace_root, &patterns, &mut warnings)
+			.into_iter()
+			.collect(),
+		skipped,
+	);
 	let mut packages = Vec::new();
 
 	for manifest in manifests {
@@ -777,6 +1161,8 @@ fn discover_package_json_workspace(
 
 fn discover_pnpm_workspace(
 	workspace_manifest: &Path,
+	filter: &DiscoveryPathFilter,
+	skipped: &mut Vec<DiscoverySkip>,
 ) -> MonochangeResult<(Vec<PackageRecord>, Vec<String>)> {
 	let contents = fs::read_to_string(workspace_manifest).map_err(|error| {
 		MonochangeError::Io(format!(
@@ -804,12 +1190,20 @@ fn discover_pnpm_workspace(
 				.collect::<Vec<_>>()
 		})
 		.unwrap_or_default();
+	let catalogs = PnpmCatalogs::from_workspace(&parsed);
 	let mut warnings = Vec::new();
-	let manifests = expand_member_patterns(workspace_root, &patterns, &mut warnings);
+	let manifests = filter.retain_manifests_in_scope(
+		Ecosystem::Npm,
+		expand_member_patterns(workspace_root, &patterns, &mut warnings)
+			.into_iter()
+			.collect(),
+		skipped,
+	);
 	let mut packages = Vec::new();
 
 	for manifest in manifests {
-		if let Some(package) = parse_package_json(&manifest, workspace_root, "pnpm")? {
+		if let Some(mut package) = parse_package_json(&manifest, workspace_root, "pnpm")? {
+			resolve_catalog_dependencies(&mut package, &catalogs);
 			packages.push(package);
 		}
 	}
@@ -929,15 +1323,33 @@ fn detect_npm_manager(workspace_root: &Path) -> &'static str {
 		"bun"
 	} else if workspace_root.join(PNPM_WORKSPACE_FILE).exists() {
 		"pnpm"
+	} else if uses_yarn(workspace_root) {
+		"yarn"
 	} else {
 		"npm"
 	}
 }
 
-fn find_package_json_workspaces(root: &Path) -> Vec<PathBuf> {
-	let mut manifests = find_all_package_json(root)
-		.into_iter()
+fn uses_yarn(workspace_root: &Path) -> bool {
+	workspace_root.join(YARNRC_FILE).exists()
+		|| workspace_root.join(YARN_LOCK_FILE).exists()
+		|| fs::read_to_string(workspace_root.join(PACKAGE_JSON_FILE))
+			.ok()
+			.and_then(|contents| serde_json::from_str::<Value>(&contents).ok())
+			.and_then(|parsed| {
+				parsed
+					.get("packageManager")
+					.and_then(Value::as_str)
+					.map(|manager| manager.starts_with("yarn@"))
+			})
+			.unwrap_or(false)
+}
+
+fn find_package_json_workspaces(manifests: &[PathBuf]) -> Vec<PathBuf> {
+	let mut manifests = manifests
+		.iter()
 		.filter(|manifest_path| package_json_declares_workspaces(manifest_path).unwrap_or(false))
+		.cloned()
 		.collect::<Vec<_>>();
 	manifests.sort();
 	manifests
@@ -997,28 +1409,26 @@ fn expand_member_patterns(
 	manifests
 }
 
-fn find_pnpm_workspaces(root: &Path) -> Vec<PathBuf> {
-	let filter = DiscoveryPathFilter::new(root);
-	WalkDir::new(root)
-		.into_iter()
-		.filter_entry(|entry| filter.should_descend(entry.path()))
-		.filter_map(Result::ok)
-		.filter(|entry| entry.file_name() == PNPM_WORKSPACE_FILE)
-		.map(DirEntry::into_path)
-		.map(|path| normalize_path(&path))
-		.collect()
+/// `pnpm-workspace.yaml` files in directories the scoped walk enters. Their
+/// members are checked against the scope before they are parsed.
+fn find_pnpm_workspaces(root: &Path, filter: &DiscoveryPathFilter) -> Vec<PathBuf> {
+	filter
+		.walk_manifests(
+			Ecosystem::Npm,
+			root,
+			|entry| filter.should_descend(entry.path()),
+			|entry| entry.file_name() == PNPM_WORKSPACE_FILE,
+		)
+		.manifests
 }
 
-fn find_all_package_json(root: &Path) -> Vec<PathBuf> {
-	let filter = DiscoveryPathFilter::new(root);
-	WalkDir::new(root)
-		.into_iter()
-		.filter_entry(|entry| filter.should_descend(entry.path()))
-		.filter_map(Result::ok)
-		.filter(|entry| entry.file_name() == PACKAGE_JSON_FILE)
-		.map(DirEntry::into_path)
-		.map(|path| normalize_path(&path))
-		.collect()
+fn find_all_package_json(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(
+		Ecosystem::Npm,
+		root,
+		|entry| filter.should_descend(entry.path()),
+		|entry| entry.file_name() == PACKAGE_JSON_FILE,
+	)
 }
 
 pub fn write_npm_placeholder_manifest(
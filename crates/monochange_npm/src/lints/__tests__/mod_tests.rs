@@ -79,6 +79,29 @@ fn workspace_protocol_rule_reports_internal_ranges() {
 	);
 }
 
+#[test]
+fn workspace_protocol_rule_accepts_pnpm_catalog_references() {
+	let target = npm_target(
+		r#"{
+  "name": "example",
+  "dependencies": {
+    "@scope/internal": "catalog:",
+    "shared": "catalog:legacy"
+  }
+}"#,
+		true,
+		false,
+	);
+	let ctx = LintContext {
+		workspace_root: &target.workspace_root,
+		manifest_path: &target.manifest_path,
+		contents: &target.contents,
+		metadata: &target.metadata,
+		parsed: target.parsed.as_ref(),
+	};
+	assert!(WorkspaceProtocolRule::new().run(&ctx, &config()).is_empty());
+}
+
 #[test]
 fn sorted_dependencies_rule_reports_unsorted_sections() {
 	let target = npm_target(
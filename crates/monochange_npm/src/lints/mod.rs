@@ -340,6 +340,7 @@ impl LintRuleRunner for WorkspaceProtocolRule {
 				};
 				if !file.workspace_package_names.contains(dep_name)
 					|| version.starts_with("workspace:")
+					|| version.starts_with("catalog:")
 				{
 					continue;
 				}
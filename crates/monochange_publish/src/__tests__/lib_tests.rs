@@ -5,8 +5,10 @@ use monochange_core::ChangesetSettings;
 use monochange_core::DependencyKind;
 use monochange_core::GroupChangelogInclude;
 use monochange_core::PackageDependency;
+use monochange_core::PreStablePolicy;
 use monochange_core::PublishOrderSettings;
 use monochange_core::VersionFormat;
+use monochange_core::VersionScheme;
 use monochange_core::WorkspaceDefaults;
 use monochange_core::lint::WorkspaceLintSettings;
 
@@ -19,6 +21,7 @@ fn empty_configuration(root: &Path) -> WorkspaceConfiguration {
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: ChangesetSettings::default(),
 		source: None,
@@ -29,6 +32,9 @@ fn empty_configuration(root: &Path) -> WorkspaceConfiguration {
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	}
 }
 
@@ -360,6 +366,8 @@ fn group_definition(id: &str, packages: &[&str]) -> GroupDefinition {
 		tag: true,
 		release: true,
 		version_format: VersionFormat::default(),
+		version_scheme: VersionScheme::Semver,
+		pre_stable_policy: PreStablePolicy::Shift,
 	}
 }
 
@@ -411,6 +419,7 @@ fn sample_publish_request_for_registry(registry: RegistryKind) -> PublishRequest
 		trusted_publishing: TrustedPublishingSettings::default(),
 		attestations: PublishAttestationSettings::default(),
 		placeholder_readme: "placeholder".to_string(),
+		dist_tag: None,
 	}
 }
 
@@ -933,6 +942,57 @@ fn go_publish_order_can_disable_require_dependencies() {
 	);
 }
 
+#[test]
+fn go_module_tag_names_drop_major_version_directories_from_the_prefix() {
+	let go_request = |module_path: &str, relative_path: Option<&str>, version: &str| {
+		let mut request = cargo_publish_request();
+		request.ecosystem = Ecosystem::Go;
+		request.registry = RegistryKind::GoProxy;
+		request.package_root = PathBuf::from(".");
+		request.version = version.to_string();
+		request
+			.package_metadata
+			.insert("module_path".to_string(), module_path.to_string());
+		if let Some(relative_path) = relative_path {
+			request
+				.package_metadata
+				.insert("relative_path".to_string(), relative_path.to_string());
+		}
+		request
+	};
+
+	for (module_path, relative_path, version, tag) in [
+		("example.com/repo", None, "1.2.3", "v1.2.3"),
+		("example.com/repo/v2", None, "2.0.0", "v2.0.0"),
+		("example.com/repo/v2", Some("v2"), "2.0.0", "v2.0.0"),
+		("example.com/repo/pkg", Some("pkg"), "1.4.0", "pkg/v1.4.0"),
+		(
+			"example.com/repo/pkg/v2",
+			Some("pkg"),
+			"2.0.0",
+			"pkg/v2.0.0",
+		),
+		(
+			"example.com/repo/pkg/v2",
+			Some("pkg/v2"),
+			"2.1.0",
+			"pkg/v2.1.0",
+		),
+		(
+			"example.com/repo/tools/v2",
+			Some("tools/v2"),
+			"v2.0.0",
+			"tools/v2.0.0",
+		),
+	] {
+		assert_eq!(
+			go_module_tag_name(&go_request(module_path, relative_path, version)),
+			tag,
+			"{module_path} in {relative_path:?}"
+		);
+	}
+}
+
 #[test]
 fn dart_publish_order_can_remove_dev_dependencies() {
 	let test_tool = ecosystem_publish_order_package(Ecosystem::Dart, "test_tool", Vec::new());
@@ -1122,6 +1182,7 @@ fn publish_order_configuration(npm_dependency_fields: Option<Vec<&str>>) -> Work
 		changelog: ChangelogSettings::default(),
 		packages: Vec::new(),
 		groups: Vec::new(),
+		linked_groups: Vec::new(),
 		cli: Vec::new(),
 		changesets: ChangesetSettings::default(),
 		source: None,
@@ -1132,6 +1193,9 @@ fn publish_order_configuration(npm_dependency_fields: Option<Vec<&str>>) -> Work
 		dart: EcosystemSettings::default(),
 		python: EcosystemSettings::default(),
 		go: EcosystemSettings::default(),
+		helm: EcosystemSettings::default(),
+		ruby: EcosystemSettings::default(),
+		jvm: EcosystemSettings::default(),
 	}
 }
 
@@ -1180,6 +1244,7 @@ fn publish_order_request_for_package(package: &PackageRecord) -> PublishRequest
 		trusted_publishing: TrustedPublishingSettings::default(),
 		attestations: PublishAttestationSettings::default(),
 		placeholder_readme: String::new(),
+		dist_tag: None,
 	}
 }
 
@@ -1199,6 +1264,7 @@ fn publish_order_request(package: &str) -> PublishRequest {
 		trusted_publishing: TrustedPublishingSettings::default(),
 		attestations: PublishAttestationSettings::default(),
 		placeholder_readme: String::new(),
+		dist_tag: None,
 	}
 }
 
@@ -1265,6 +1331,7 @@ fn cargo_publish_request() -> PublishRequest {
 		package_metadata: BTreeMap::new(),
 		mode: PublishMode::Builtin,
 		version: "1.2.3".to_string(),
+		dist_tag: None,
 		placeholder: false,
 		trusted_publishing: TrustedPublishingSettings {
 			enabled: false,
@@ -1325,3 +1392,207 @@ async fn dry_run_publish_executes_registry_dry_run_and_captures_output() {
 			.is_some_and(|command| command.contains("--dry-run"))
 	);
 }
+
+fn helm_publish_request(destination: &str) -> PublishRequest {
+	let mut request = cargo_publish_request();
+	request.ecosystem = Ecosystem::Helm;
+	request.registry = RegistryKind::Helm;
+	request.package_root = PathBuf::from("charts/pkg");
+	request.manifest_path = PathBuf::from("charts/pkg/Chart.yaml");
+	request.package_metadata.insert(
+		HELM_CHART_REPOSITORY_METADATA_KEY.to_string(),
+		destination.to_string(),
+	);
+	request
+}
+
+#[test]
+fn helm_publish_commands_push_to_oci_or_index_a_repository_directory() {
+	let oci = build_publish_command(
+		&helm_publish_request("oci://ghcr.io/acme/charts"),
+		PackagePublishRunMode::Release,
+		None,
+		false,
+	);
+	assert_eq!(oci.program, "sh");
+	assert!(oci.args[1].contains("helm push"));
+	assert_eq!(&oci.args[3..], ["charts/pkg", "oci://ghcr.io/acme/charts"]);
+
+	let directory = build_publish_command(
+		&helm_publish_request("/repo/dist/charts"),
+		PackagePublishRunMode::Placeholder,
+		Some(Path::new("/tmp/placeholder")),
+		false,
+	);
+	assert!(directory.args[1].contains("helm repo index"));
+	assert_eq!(
+		&directory.args[3..],
+		["/tmp/placeholder", "/repo/dist/charts"]
+	);
+
+	let dry_run = build_publish_command(
+		&helm_publish_request("oci://ghcr.io/acme/charts"),
+		PackagePublishRunMode::Release,
+		None,
+		true,
+	);
+	assert!(!dry_run.args[1].contains("helm push"));
+	assert!(dry_run.args[1].starts_with("helm package"));
+}
+
+#[test]
+fn helm_publish_metadata_requires_a_custom_registry_destination() {
+	let package = PackageRecord::new(
+		Ecosystem::Helm,
+		"api",
+		PathBuf::from("/repo/charts/api/Chart.yaml"),
+		PathBuf::from("/repo"),
+		None,
+		PublishState::Public,
+	);
+	let root = Path::new("/repo");
+
+	let error = publish_request_metadata(root, "api", &package, None)
+		.expect_err("helm charts need a destination");
+	assert!(error.to_string().contains("publish.registry"));
+
+	let oci = publish_request_metadata(
+		root,
+		"api",
+		&package,
+		Some(&PublishRegistry::Custom("oci://ghcr.io/acme".to_string())),
+	)
+	.unwrap_or_else(|error| panic!("oci metadata: {error}"));
+	assert_eq!(
+		oci.get(HELM_CHART_REPOSITORY_METADATA_KEY)
+			.map(String::as_str),
+		Some("oci://ghcr.io/acme")
+	);
+
+	let directory = publish_request_metadata(
+		root,
+		"api",
+		&package,
+		Some(&PublishRegistry::Custom("dist/charts".to_string())),
+	)
+	.unwrap_or_else(|error| panic!("directory metadata: {error}"));
+	assert_eq!(
+		directory
+			.get(HELM_CHART_REPOSITORY_METADATA_KEY)
+			.map(PathBuf::from),
+		Some(root.join("dist/charts"))
+	);
+}
+
+fn rubygems_publish_request() -> PublishRequest {
+	let mut request = cargo_publish_request();
+	request.ecosystem = Ecosystem::Ruby;
+	request.registry = RegistryKind::Rubygems;
+	request.package_root = PathBuf::from("engines/pkg");
+	request.manifest_path = PathBuf::from("engines/pkg/pkg.gemspec");
+	request
+}
+
+fn maven_publish_request(manifest: &str) -> PublishRequest {
+	let mut request = cargo_publish_request();
+	request.ecosystem = Ecosystem::Jvm;
+	request.registry = RegistryKind::Maven;
+	request.mode = PublishMode::External;
+	request.package_root = PathBuf::from("platform/core");
+	request.manifest_path = PathBuf::from("platform/core").join(manifest);
+	request
+}
+
+#[test]
+fn maven_external_commands_deploy_poms_and_publish_gradle_projects() {
+	let builder = build_publish_command_builder();
+	let maven = builder
+		.build_external_command(&maven_publish_request("pom.xml"), false)
+		.unwrap();
+	assert_eq!(render_command(&maven), "mvn -B -N deploy");
+	assert_eq!(maven.cwd, PathBuf::from("platform/core"));
+	let maven_dry_run = builder
+		.build_external_command(&maven_publish_request("pom.xml"), true)
+		.unwrap();
+	assert_eq!(render_command(&maven_dry_run), "mvn -B -N verify");
+
+	let gradle = builder
+		.build_external_command(&maven_publish_request("gradle.properties"), true)
+		.unwrap();
+	assert_eq!(render_command(&gradle), "gradle publish --dry-run");
+
+	assert!(
+		builder
+			.build_external_command(
+				&sample_publish_request_for_registry(RegistryKind::Npm),
+				false
+			)
+			.is_none()
+	);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn external_maven_packages_report_the_command_to_run() {
+	let requests = vec![maven_publish_request("pom.xml")];
+	let mut executor = PanickingCommandExecutor;
+
+	let report = execute_publish_requests_with_progress(
+		Path::new("."),
+		None,
+		PackagePublishRunMode::Release,
+		false,
+		&requests,
+		&registry_client().unwrap(),
+		&RegistryEndpoints::from_env(),
+		&BTreeMap::new(),
+		&mut executor,
+		&build_publish_command_builder(),
+		&PlaceholderManifestWriterRegistry::new(),
+		&PublishReadinessRegistry::new(),
+		&TestPublishTrustHandler,
+		&NoopPublishProgressReporter,
+	)
+	.await
+	.unwrap();
+
+	let outcome = &report.packages[0];
+	assert_eq!(outcome.status, PackagePublishStatus::SkippedExternal);
+	assert_eq!(outcome.command.as_deref(), Some("mvn -B -N deploy"));
+	assert_eq!(
+		outcome.message,
+		"package is published externally with `mvn -B -N deploy`"
+	);
+}
+
+#[test]
+fn rubygems_publish_commands_build_the_gemspec_and_push_the_gem() {
+	let release = build_publish_command(
+		&rubygems_publish_request(),
+		PackagePublishRunMode::Release,
+		None,
+		false,
+	);
+	assert_eq!(release.program, "sh");
+	assert!(release.args[1].contains("gem build"));
+	assert!(release.args[1].contains("gem push"));
+	assert_eq!(&release.args[3..], ["pkg.gemspec", "pkg-1.2.3.gem"]);
+	assert_eq!(release.cwd, PathBuf::from("engines/pkg"));
+
+	let placeholder = build_publish_command(
+		&rubygems_publish_request(),
+		PackagePublishRunMode::Placeholder,
+		Some(Path::new("/tmp/placeholder")),
+		false,
+	);
+	assert_eq!(placeholder.args[3], "pkg.gemspec");
+	assert_eq!(placeholder.cwd, PathBuf::from("/tmp/placeholder"));
+
+	let dry_run = build_publish_command(
+		&rubygems_publish_request(),
+		PackagePublishRunMode::Release,
+		None,
+		true,
+	);
+	assert!(dry_run.args[1].starts_with("gem build"));
+	assert!(!dry_run.args[1].contains("gem push"));
+}
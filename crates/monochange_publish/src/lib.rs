@@ -34,6 +34,9 @@ use tracing::info;
 use urlencoding::encode;
 
 pub const PLACEHOLDER_VERSION: &str = "0.0.0";
+/// Package metadata key carrying the resolved destination of a Helm chart:
+/// an `oci://` reference or an absolute chart repository directory.
+pub const HELM_CHART_REPOSITORY_METADATA_KEY: &str = "chart_repository";
 
 pub trait EcosystemProgressPresentation {
 	fn progress_emoji(self) -> &'static str;
@@ -380,6 +383,12 @@ pub fn manual_setup_url(request: &PublishRequest) -> String {
 		)
 	} else if request.registry == RegistryKind::GoProxy {
 		format!("https://pkg.go.dev/{}", go_module_path(request))
+	} else if request.registry == RegistryKind::Helm {
+		"https://helm.sh/docs/topics/registries/".to_string()
+	} else if request.registry == RegistryKind::Rubygems {
+		format!("https://rubygems.org/gems/{}", request.package_name)
+	} else if request.registry == RegistryKind::Maven {
+		"https://central.sonatype.org/publish/publish-portal-maven/".to_string()
 	} else {
 		format!(
 			"https://www.npmjs.com/package/{}/access",
@@ -404,6 +413,8 @@ pub struct PublishRequest {
 	pub trusted_publishing: TrustedPublishingSettings,
 	pub attestations: PublishAttestationSettings,
 	pub placeholder_readme: String,
+	/// Registry dist-tag to publish under instead of the default (`latest` on npm).
+	pub dist_tag: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -703,16 +714,22 @@ pub async fn execute_publish_requests_with_progress(
 				package: publish_progress_package(request),
 				message: "package opted out of built-in publishing".to_string(),
 			});
+			let external_command = command_builder
+				.build_external_command(request, dry_run)
+				.map(|command| render_command(&command));
 			outcomes.push(PackagePublishOutcome {
 				package: request.package_id.clone(),
 				ecosystem: request.ecosystem,
 				registry: request.registry.to_string(),
 				version: request.version.clone(),
 				status: PackagePublishStatus::SkippedExternal,
-				message: "package opted out of built-in publishing".to_string(),
+				message: external_command.as_ref().map_or_else(
+					|| "package opted out of built-in publishing".to_string(),
+					|command| format!("package is published externally with `{command}`"),
+				),
 				placeholder: mode == PackagePublishRunMode::Placeholder,
 				trusted_publishing: disabled_trust_outcome(),
-				command: None,
+				command: external_command,
 				stdout: None,
 				stderr: None,
 			});
@@ -1023,7 +1040,12 @@ pub fn build_placeholder_requests(
 					package.ecosystem,
 				)?,
 				package_manager: package.metadata.get("manager").cloned(),
-				package_metadata: package.metadata.clone(),
+				package_metadata: publish_request_metadata(
+					&configuration.root_path,
+					&package_definition.id,
+					package,
+					package_definition.publish.registry.as_ref(),
+				)?,
 				mode: package_definition.publish.mode,
 				version: PLACEHOLDER_VERSION.to_string(),
 				placeholder: true,
@@ -1039,6 +1061,7 @@ pub fn build_placeholder_requests(
 						.as_deref(),
 					&package.name,
 				)?,
+				dist_tag: None,
 			});
 		}
 	}
@@ -1132,13 +1155,19 @@ pub fn build_release_requests(
 				.to_path_buf(),
 			registry: resolve_registry_kind(publication.registry.as_ref(), package.ecosystem)?,
 			package_manager: package.metadata.get("manager").cloned(),
-			package_metadata: package.metadata.clone(),
+			package_metadata: publish_request_metadata(
+				&configuration.root_path,
+				&publication.package,
+				package,
+				publication.registry.as_ref(),
+			)?,
 			mode: publication.mode,
 			version: publication.version.clone(),
 			placeholder: false,
 			trusted_publishing: publication.trusted_publishing.clone(),
 			attestations: publication.attestations.clone(),
 			placeholder_readme: default_placeholder_readme(&package.name),
+			dist_tag: None,
 		});
 	}
 
@@ -1151,6 +1180,7 @@ pub fn resolve_registry_kind(
 ) -> MonochangeResult<RegistryKind> {
 	match registry {
 		Some(PublishRegistry::Builtin(registry)) => Ok(*registry),
+		Some(PublishRegistry::Custom(_)) if ecosystem == Ecosystem::Helm => Ok(RegistryKind::Helm),
 		Some(PublishRegistry::Custom(name)) => {
 			Err(MonochangeError::Config(format!(
 				"built-in package publishing does not support custom registry `{name}`"
@@ -1160,6 +1190,36 @@ pub fn resolve_registry_kind(
 	}
 }
 
+/// Build the package metadata a publish request carries.
+///
+/// Helm charts have no canonical public registry, so their `publish.registry`
+/// names the destination instead: an `oci://` reference, or a chart repository
+/// directory relative to the workspace root.
+fn publish_request_metadata(
+	root: &Path,
+	package_id: &str,
+	package: &PackageRecord,
+	registry: Option<&PublishRegistry>,
+) -> MonochangeResult<BTreeMap<String, String>> {
+	let mut metadata = package.metadata.clone();
+	if package.ecosystem != Ecosystem::Helm {
+		return Ok(metadata);
+	}
+
+	let Some(PublishRegistry::Custom(destination)) = registry else {
+		return Err(MonochangeError::Config(format!(
+			"helm chart `{package_id}` needs `publish.registry` set to an `oci://` reference or a chart repository directory"
+		)));
+	};
+	let destination = if destination.starts_with("oci://") {
+		destination.clone()
+	} else {
+		root.join(destination).display().to_string()
+	};
+	metadata.insert(HELM_CHART_REPOSITORY_METADATA_KEY.to_string(), destination);
+	Ok(metadata)
+}
+
 pub fn default_registry_kind_for_ecosystem(ecosystem: &str) -> MonochangeResult<RegistryKind> {
 	let parsed = ecosystem.parse::<Ecosystem>().map_err(|()| {
 		MonochangeError::Config(format!(
@@ -1206,6 +1266,14 @@ pub trait PublishAdapter {
 		placeholder_path: &Path,
 	) -> Option<CommandSpec>;
 	fn build_release_command(&self, request: &PublishRequest) -> Option<CommandSpec>;
+	/// Return the command that publishes an external-mode package.
+	///
+	/// monochange does not run it; the command is reported so release
+	/// workflows know what to run for registries that only support external
+	/// publishing.
+	fn build_external_command(&self, _request: &PublishRequest) -> Option<CommandSpec> {
+		None
+	}
 	fn append_dry_run_args(&self, args: &mut Vec<String>) {
 		args.push("--dry-run".to_string());
 	}
@@ -1275,6 +1343,21 @@ impl PublishCommandBuilder {
 		}
 		command
 	}
+
+	/// Build the command an external-mode package is published with, when
+	/// its registry adapter suggests one.
+	pub fn build_external_command(
+		&self,
+		request: &PublishRequest,
+		dry_run: bool,
+	) -> Option<CommandSpec> {
+		let adapter = self.adapter_for_registry(request.registry)?;
+		let mut command = adapter.build_external_command(request)?;
+		if dry_run {
+			adapter.append_dry_run_args(&mut command.args);
+		}
+		Some(command)
+	}
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -1342,6 +1425,9 @@ pub fn build_publish_command_builder() -> PublishCommandBuilder {
 		.with_adapter(Box::new(JsrPublishAdapter))
 		.with_adapter(Box::new(PythonPublishAdapter))
 		.with_adapter(Box::new(GoPublishAdapter))
+		.with_adapter(Box::new(HelmPublishAdapter))
+		.with_adapter(Box::new(RubygemsPublishAdapter))
+		.with_adapter(Box::new(MavenPublishAdapter))
 }
 
 struct NpmPublishAdapter;
@@ -1586,12 +1672,189 @@ impl PublishAdapter for GoPublishAdapter {
 	}
 }
 
+struct HelmPublishAdapter;
+
+impl PublishAdapter for HelmPublishAdapter {
+	fn registry_kind(&self) -> RegistryKind {
+		RegistryKind::Helm
+	}
+
+	fn build_placeholder_command(
+		&self,
+		request: &PublishRequest,
+		placeholder_path: &Path,
+	) -> Option<CommandSpec> {
+		build_helm_publish_command(request, placeholder_path)
+	}
+
+	fn build_release_command(&self, request: &PublishRequest) -> Option<CommandSpec> {
+		build_helm_publish_command(request, &request.package_root)
+	}
+
+	/// Helm has no dry-run flag for `package` or `push`, so a dry run only
+	/// packages the chart into a scratch directory.
+	fn append_dry_run_args(&self, args: &mut Vec<String>) {
+		if let Some(script) = args.get_mut(1) {
+			*script = HELM_PACKAGE_ONLY_SCRIPT.to_string();
+		}
+	}
+
+	fn supported_providers(&self) -> Vec<CiProviderKind> {
+		Vec::new()
+	}
+
+	fn registry_setup_url(&self) -> Option<&'static str> {
+		Some("https://helm.sh/docs/topics/registries/")
+	}
+
+	fn registry_notes(&self) -> Vec<String> {
+		[
+			"Helm charts are pushed with the registry credentials configured through `helm registry login`".to_string(),
+			"local chart repository directories are re-indexed with `helm repo index` after each package".to_string(),
+		]
+		.to_vec()
+	}
+}
+
+struct RubygemsPublishAdapter;
+
+impl PublishAdapter for RubygemsPublishAdapter {
+	fn registry_kind(&self) -> RegistryKind {
+		RegistryKind::Rubygems
+	}
+
+	fn build_placeholder_command(
+		&self,
+		request: &PublishRequest,
+		placeholder_path: &Path,
+	) -> Option<CommandSpec> {
+		Some(build_rubygems_publish_command(request, placeholder_path))
+	}
+
+	fn build_release_command(&self, request: &PublishRequest) -> Option<CommandSpec> {
+		Some(build_rubygems_publish_command(
+			request,
+			&request.package_root,
+		))
+	}
+
+	/// `gem push` has no dry-run flag, so a dry run only builds the gem.
+	fn append_dry_run_args(&self, args: &mut Vec<String>) {
+		if let Some(script) = args.get_mut(1) {
+			*script = RUBYGEMS_BUILD_ONLY_SCRIPT.to_string();
+		}
+	}
+
+	fn supported_providers(&self) -> Vec<CiProviderKind> {
+		Vec::new()
+	}
+
+	fn registry_setup_url(&self) -> Option<&'static str> {
+		Some("https://guides.rubygems.org/trusted-publishing/")
+	}
+
+	fn registry_notes(&self) -> Vec<String> {
+		[
+			"gems are pushed with the API key in `GEM_HOST_API_KEY` or `~/.gem/credentials`".to_string(),
+			"RubyGems trusted publishing exchanges a GitHub OIDC token for that key through `rubygems/configure-rubygems-credentials`".to_string(),
+		]
+		.to_vec()
+	}
+}
+
+struct MavenPublishAdapter;
+
+impl PublishAdapter for MavenPublishAdapter {
+	fn registry_kind(&self) -> RegistryKind {
+		RegistryKind::Maven
+	}
+
+	fn build_placeholder_command(
+		&self,
+		_request: &PublishRequest,
+		_placeholder_path: &Path,
+	) -> Option<CommandSpec> {
+		None
+	}
+
+	fn build_release_command(&self, _request: &PublishRequest) -> Option<CommandSpec> {
+		None
+	}
+
+	fn build_external_command(&self, request: &PublishRequest) -> Option<CommandSpec> {
+		Some(build_maven_publish_command(request))
+	}
+
+	fn append_dry_run_args(&self, args: &mut Vec<String>) {
+		append_maven_dry_run_args(args);
+	}
+
+	fn registry_setup_url(&self) -> Option<&'static str> {
+		Some("https://central.sonatype.org/publish/publish-portal-maven/")
+	}
+
+	fn registry_notes(&self) -> Vec<String> {
+		[
+			"JVM packages publish in external mode; monochange reports the `mvn deploy` or `gradle publish` command to run".to_string(),
+			"repository credentials and signing keys come from Maven `settings.xml` or Gradle properties".to_string(),
+		]
+		.to_vec()
+	}
+}
+
+/// Build the `mvn deploy` or `gradle publish` invocation for a JVM package.
+///
+/// Maven modules deploy without recursing, because aggregated child modules
+/// are released as their own packages. The nearest `mvnw` or `gradlew`
+/// wrapper is preferred over a globally installed tool.
+fn build_maven_publish_command(request: &PublishRequest) -> CommandSpec {
+	let is_maven = request
+		.manifest_path
+		.file_name()
+		.is_some_and(|name| name == "pom.xml");
+	let (tool, wrapper, args) = if is_maven {
+		("mvn", "mvnw", vec!["-B", "-N", "deploy"])
+	} else {
+		("gradle", "gradlew", vec!["publish"])
+	};
+	let program = request
+		.package_root
+		.ancestors()
+		.map(|dir| dir.join(wrapper))
+		.find(|path| path.is_file())
+		.map_or_else(|| tool.to_string(), |path| path.display().to_string());
+	CommandSpec {
+		program,
+		args: args.into_iter().map(str::to_string).collect(),
+		cwd: request.package_root.clone(),
+	}
+}
+
+/// `mvn deploy` has no dry-run flag, so a dry run stops at `verify`; Gradle
+/// accepts `--dry-run`.
+fn append_maven_dry_run_args(args: &mut Vec<String>) {
+	if let Some(goal) = args.iter_mut().find(|arg| *arg == "deploy") {
+		*goal = "verify".to_string();
+	} else {
+		args.push("--dry-run".to_string());
+	}
+}
+
 pub fn append_publish_dry_run_args(args: &mut Vec<String>, registry: RegistryKind, dry_run: bool) {
 	if !dry_run {
 		return;
 	}
 
-	if registry == RegistryKind::Pypi || registry == RegistryKind::GoProxy {
+	if registry == RegistryKind::Maven {
+		append_maven_dry_run_args(args);
+		return;
+	}
+
+	if registry == RegistryKind::Pypi
+		|| registry == RegistryKind::GoProxy
+		|| registry == RegistryKind::Helm
+		|| registry == RegistryKind::Rubygems
+	{
 		return;
 	}
 
@@ -1629,6 +1892,18 @@ pub fn build_npm_release_publish_command(request: &PublishRequest) -> CommandSpe
 	if request.attestations.require_registry_provenance {
 		args.push("--provenance".to_string());
 	}
+	if let Some(dist_tag) = &request.dist_tag {
+		args.push("--tag".to_string());
+		args.push(dist_tag.clone());
+	}
+	if uses_yarn_publish_manager(request) && !request.trusted_publishing.enabled {
+		args.insert(0, "npm".to_string());
+		return CommandSpec {
+			program: "yarn".to_string(),
+			args,
+			cwd: request.package_root.clone(),
+		};
+	}
 	CommandSpec {
 		program: npm_publish_program(request).to_string(),
 		args,
@@ -1652,6 +1927,12 @@ pub fn uses_pnpm_publish_manager(request: &PublishRequest) -> bool {
 	request.registry == RegistryKind::Npm && request.package_manager.as_deref() == Some("pnpm")
 }
 
+/// Yarn publishes the workspace in its working directory through
+/// `yarn npm publish`, which resolves `workspace:` dependencies first.
+pub fn uses_yarn_publish_manager(request: &PublishRequest) -> bool {
+	request.registry == RegistryKind::Npm && request.package_manager.as_deref() == Some("yarn")
+}
+
 fn build_cargo_placeholder_publish_command(
 	request: &PublishRequest,
 	placeholder_path: &Path,
@@ -1722,6 +2003,74 @@ fn build_python_publish_command(request: &PublishRequest, cwd: &Path) -> Command
 	}
 }
 
+const HELM_PACKAGE_ONLY_SCRIPT: &str = r#"helm package "$1" --destination "$(mktemp -d)""#;
+const HELM_OCI_PUSH_SCRIPT: &str = r#"dir="$(mktemp -d)" && helm package "$1" --destination "$dir" && helm push "$dir"/*.tgz "$2""#;
+const HELM_REPOSITORY_DIRECTORY_SCRIPT: &str =
+	r#"mkdir -p "$2" && helm package "$1" --destination "$2" && helm repo index "$2""#;
+
+/// Return the destination a Helm chart publishes to.
+pub fn helm_chart_repository(request: &PublishRequest) -> Option<&str> {
+	request
+		.package_metadata
+		.get(HELM_CHART_REPOSITORY_METADATA_KEY)
+		.map(String::as_str)
+}
+
+/// Package the chart at `chart_dir` and push it to an OCI registry, or write
+/// it into a chart repository directory and refresh that directory's index.
+///
+/// The chart directory and destination are passed as positional shell
+/// arguments so paths never need quoting inside the script.
+fn build_helm_publish_command(request: &PublishRequest, chart_dir: &Path) -> Option<CommandSpec> {
+	let destination = helm_chart_repository(request)?;
+	let script = if destination.starts_with("oci://") {
+		HELM_OCI_PUSH_SCRIPT
+	} else {
+		HELM_REPOSITORY_DIRECTORY_SCRIPT
+	};
+	Some(CommandSpec {
+		program: "sh".to_string(),
+		args: vec![
+			"-c".to_string(),
+			script.to_string(),
+			"sh".to_string(),
+			chart_dir.display().to_string(),
+			destination.to_string(),
+		],
+		cwd: request.package_root.clone(),
+	})
+}
+
+const RUBYGEMS_BUILD_ONLY_SCRIPT: &str = r#"gem build "$1" --output "$(mktemp -d)/$2""#;
+const RUBYGEMS_PUSH_SCRIPT: &str =
+	r#"dir="$(mktemp -d)" && gem build "$1" --output "$dir/$2" && gem push "$dir/$2""#;
+
+/// Build the gem from its gemspec into a scratch directory and push it.
+///
+/// The gemspec is built from the gem root because gemspecs resolve `files`
+/// and `require_relative` paths against the working directory.
+fn build_rubygems_publish_command(request: &PublishRequest, gem_dir: &Path) -> CommandSpec {
+	let gemspec = request
+		.manifest_path
+		.file_name()
+		.filter(|_| gem_dir == request.package_root)
+		.map_or_else(
+			|| format!("{}.gemspec", request.package_name),
+			|name| name.to_string_lossy().into_owned(),
+		);
+	CommandSpec {
+		program: "sh".to_string(),
+		args: vec![
+			"-c".to_string(),
+			RUBYGEMS_PUSH_SCRIPT.to_string(),
+			"sh".to_string(),
+			gemspec,
+			format!("{}-{}.gem", request.package_name, request.version),
+		],
+		cwd: gem_dir.to_path_buf(),
+	}
+}
+
 fn build_go_publish_command(request: &PublishRequest) -> CommandSpec {
 	CommandSpec {
 		program: "git".to_string(),
@@ -1738,12 +2087,30 @@ fn go_module_tag_name(request: &PublishRequest) -> String {
 		.cloned()
 		.unwrap_or_else(|| fallback_go_tag_prefix(request));
 	let root = root.trim_matches('/');
+	// A `/vN` major version subdirectory is part of the module path but not
+	// of the tag prefix: `greeter/v2` is tagged `greeter/v2.0.0`.
+	let module_major = go_module_path(request)
+		.rsplit('/')
+		.next()
+		.filter(|segment| is_go_major_version_suffix(segment));
+	let root = match (module_major, root.rsplit_once('/')) {
+		(Some(major), Some((parent, last))) if last == major => parent,
+		(Some(major), None) if root == major => "",
+		_ => root,
+	};
 	if root.is_empty() || root == "." {
 		return version;
 	}
 	format!("{root}/{version}")
 }
 
+fn is_go_major_version_suffix(segment: &str) -> bool {
+	segment
+		.strip_prefix('v')
+		.and_then(|major| major.parse::<u64>().ok())
+		.is_some_and(|major| major >= 2)
+}
+
 fn fallback_go_tag_prefix(request: &PublishRequest) -> String {
 	env::current_dir()
 		.ok()
@@ -2169,6 +2536,7 @@ fn circle_project_slug(env_map: &BTreeMap<String, String>) -> Option<String> {
 		.or_else(|| env_map.get("CIRCLE_PROJECT_REPONAME").cloned())
 }
 
+use monochange_core::detect_dependency_cycles;
 use monochange_core::materialize_dependency_edges;
 
 fn publish_order_dependency_edges(
@@ -2254,6 +2622,9 @@ fn ecosystem_settings(
 		Ecosystem::Dart | Ecosystem::Flutter => &configuration.dart,
 		Ecosystem::Python => &configuration.python,
 		Ecosystem::Go => &configuration.go,
+		Ecosystem::Helm => &configuration.helm,
+		Ecosystem::Ruby => &configuration.ruby,
+		Ecosystem::Jvm => &configuration.jvm,
 		_ => &configuration.npm,
 	}
 }
@@ -2443,6 +2814,7 @@ pub fn order_release_requests_by_publish_dependencies(
 		.map(|package_id| (package_id.clone(), BTreeSet::<String>::new()))
 		.collect::<BTreeMap<_, _>>();
 	let mut dependents_by_package = BTreeMap::<String, BTreeSet<String>>::new();
+	let mut ordering_edges = Vec::new();
 
 	for edge in publish_order_dependency_edges(configuration, packages) {
 		let from_package = packages
@@ -2472,6 +2844,11 @@ pub fn order_release_requests_by_publish_dependencies(
 			.entry(to_package_id.clone())
 			.or_default()
 			.insert(from_package_id.clone());
+		ordering_edges.push(DependencyEdge {
+			from_package_id: from_package_id.clone(),
+			to_package_id: to_package_id.clone(),
+			..edge
+		});
 	}
 
 	let mut ready = dependencies_by_package
@@ -2503,8 +2880,13 @@ pub fn order_release_requests_by_publish_dependencies(
 	}
 
 	if !dependencies_by_package.is_empty() {
+		let cycles = detect_dependency_cycles(&ordering_edges)
+			.iter()
+			.map(|cycle| format!("\n- {cycle}"))
+			.collect::<Vec<_>>()
+			.concat();
 		return Err(MonochangeError::Config(format!(
-			"cyclic publish dependencies detected among package publications: {}",
+			"cyclic publish dependencies detected among package publications: {}{cycles}",
 			render_publish_dependency_cycle(&dependencies_by_package)
 		)));
 	}
@@ -2568,6 +2950,7 @@ pub struct RegistryEndpoints {
 	pub jsr_base: String,
 	pub pypi_api: String,
 	pub go_proxy: String,
+	pub rubygems_api: String,
 }
 
 impl RegistryEndpoints {
@@ -2587,6 +2970,8 @@ impl RegistryEndpoints {
 				.unwrap_or_else(|_| "https://pypi.org/pypi".to_string()),
 			go_proxy: env::var("MONOCHANGE_GO_PROXY_URL")
 				.unwrap_or_else(|_| "https://proxy.golang.org".to_string()),
+			rubygems_api: env::var("MONOCHANGE_RUBYGEMS_API_URL")
+				.unwrap_or_else(|_| "https://rubygems.org/api/v1".to_string()),
 		}
 	}
 }
@@ -2740,6 +3125,55 @@ pub async fn registry_version_exists(
 		return Ok(exists);
 	}
 
+	if request.registry == RegistryKind::Helm {
+		// OCI registries need credentials to query, so only chart repository
+		// directories can be checked before `helm push` runs.
+		return Ok(helm_chart_repository(request)
+			.filter(|destination| !destination.starts_with("oci://"))
+			.is_some_and(|directory| {
+				Path::new(directory)
+					.join(format!("{}-{}.tgz", request.package_name, request.version))
+					.exists()
+			}));
+	}
+
+	// JVM packages only publish in external mode, so there is no built-in
+	// publish to skip.
+	if request.registry == RegistryKind::Maven {
+		return Ok(false);
+	}
+
+	if request.registry == RegistryKind::Rubygems {
+		let url = format!(
+			"{}/versions/{}.json",
+			endpoints.rubygems_api.trim_end_matches('/'),
+			encode(&request.package_name)
+		);
+		let response = client
+			.get(url)
+			.send()
+			.await
+			.map_err(http_error("RubyGems lookup"))?;
+		if response.status() == StatusCode::NOT_FOUND {
+			return Ok(false);
+		}
+		let response = response
+			.error_for_status()
+			.map_err(http_error("RubyGems lookup"))?;
+		let json = response
+			.json::<JsonValue>()
+			.await
+			.map_err(http_error("RubyGems decode"))?;
+		let exists = json.as_array().is_some_and(|versions| {
+			request.placeholder && !versions.is_empty()
+				|| versions.iter().any(|version| {
+					version.get("number").and_then(JsonValue::as_str)
+						== Some(request.version.as_str())
+				})
+		});
+		return Ok(exists);
+	}
+
 	if request.registry == RegistryKind::GoProxy {
 		let url = format!(
 			"{}/{}/@v/{}.info",
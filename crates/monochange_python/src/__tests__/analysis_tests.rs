@@ -0,0 +1,324 @@
+use monochange_core::BumpSeverity;
+use monochange_core::FileChangeKind;
+use monochange_core::PublishState;
+
+use super::*;
+
+fn source_file(path: &str, contents: &str) -> PackageSnapshotFile {
+	PackageSnapshotFile {
+		path: PathBuf::from(path),
+		contents: contents.to_string(),
+	}
+}
+
+fn diff_module_sources(before: &str, after: &str) -> Vec<SemanticChange> {
+	let snapshot = |label: &str, contents: &str| {
+		PackageSnapshot {
+			label: label.to_string(),
+			files: vec![source_file("src/greeter/__init__.py", contents)],
+		}
+	};
+	let before_snapshot = snapshot("before", before);
+	let after_snapshot = snapshot("after", after);
+
+	diff_public_symbols(
+		&snapshot_public_symbols(Some(&before_snapshot), &[]),
+		&snapshot_public_symbols(Some(&after_snapshot), &[]),
+	)
+}
+
+#[test]
+fn analyzer_applies_to_python_packages() {
+	let package = PackageRecord::new(
+		Ecosystem::Python,
+		"greeter",
+		PathBuf::from("/repo/packages/greeter/pyproject.toml"),
+		PathBuf::from("/repo"),
+		None,
+		PublishState::Public,
+	);
+
+	assert!(semantic_analyzer().applies_to(&package));
+}
+
+#[test]
+fn collect_public_symbols_finds_functions_classes_methods_and_exports() {
+	let file = source_file(
+		"src/greeter/api.py",
+		concat!(
+			"\"\"\"Greeting helpers.\n",
+			"\n",
+			"def not_a_function(): ...\n",
+			"\"\"\"\n",
+			"__all__ = [\"greet\", 'Greeter']\n",
+			"\n",
+			"async def greet(\n",
+			"    name: str,  # who to greet\n",
+			"    punctuation: str = \"!\",\n",
+			") -> str:\n",
+			"    def inner():\n",
+			"        pass\n",
+			"    return name\n",
+			"\n",
+			"def _helper(): ...\n",
+			"\n",
+			"class Greeter(Base):\n",
+			"    def __init__(self, name): ...\n",
+			"\n",
+			"    @staticmethod\n",
+			"    def default() -> \"Greeter\": ...\n",
+			"\n",
+			"    def _cache(self): ...\n",
+			"\n",
+			"    class Options:\n",
+			"        def render(self): ...\n",
+			"\n",
+			"class _Private:\n",
+			"    def hidden(self): ...\n",
+		),
+	);
+
+	let symbols = collect_public_symbols(&file)
+		.into_iter()
+		.map(|symbol| (symbol.item_kind, symbol.item_path, symbol.signature))
+		.collect::<Vec<_>>();
+
+	let expected = [
+		("export", "greeter.api.greet", "__all__ += [\"greet\"]"),
+		("export", "greeter.api.Greeter", "__all__ += [\"Greeter\"]"),
+		(
+			"function",
+			"greeter.api.greet",
+			"async def greet(name: str, punctuation: str = \"!\") -> str",
+		),
+		("class", "greeter.api.Greeter", "class Greeter(Base)"),
+		(
+			"method",
+			"greeter.api.Greeter.__init__",
+			"def __init__(self, name)",
+		),
+		(
+			"method",
+			"greeter.api.Greeter.default",
+			"@staticmethod def default() -> \"Greeter\"",
+		),
+		("class", "greeter.api.Greeter.Options", "class Options"),
+		(
+			"method",
+			"greeter.api.Greeter.Options.render",
+			"def render(self)",
+		),
+	]
+	.map(|(kind, path, signature)| (kind.to_string(), path.to_string(), signature.to_string()));
+	assert_eq!(symbols, expected);
+}
+
+#[test]
+fn public_source_files_skip_tests_private_modules_and_tooling() {
+	for path in [
+		"src/greeter/__init__.py",
+		"src/greeter/api.py",
+		"greeter/types.pyi",
+	] {
+		assert!(is_public_python_source_file(Path::new(path)), "{path}");
+	}
+	for path in [
+		"src/greeter/_internal.py",
+		"src/greeter/_vendor/six.py",
+		"tests/test_api.py",
+		"src/greeter/api_test.py",
+		"conftest.py",
+		".venv/lib/site.py",
+		"README.md",
+	] {
+		assert!(!is_public_python_source_file(Path::new(path)), "{path}");
+	}
+	assert_eq!(
+		module_path_for_file(Path::new("src/greeter/api.py")),
+		vec!["greeter".to_string(), "api".to_string()]
+	);
+	assert_eq!(
+		module_path_for_file(Path::new("greeter/__init__.py")),
+		vec!["greeter".to_string()]
+	);
+}
+
+#[test]
+fn signature_diff_separates_optional_parameters_from_breaking_changes() {
+	let changes = diff_module_sources(
+		concat!(
+			"def greet(name, **options): ...\n",
+			"def render(text, width=80): ...\n",
+			"def parse(text): ...\n",
+			"def remove_me(): ...\n",
+		),
+		concat!(
+			"def greet(name, loud: bool = False, **options): ...\n",
+			"def render(text, width=100): ...\n",
+			"def parse(text, strict): ...\n",
+			"def added(): ...\n",
+		),
+	);
+
+	let summaries = changes
+		.iter()
+		.map(|change| (change.summary.as_str(), change.implied_bump()))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		summaries,
+		vec![
+			("function `greeter.added` added", BumpSeverity::Minor),
+			(
+				"optional parameter `loud` added to function `greeter.greet`",
+				BumpSeverity::Minor
+			),
+			("function `greeter.parse` modified", BumpSeverity::Major),
+			("function `greeter.render` modified", BumpSeverity::Major),
+			("function `greeter.remove_me` removed", BumpSeverity::Major),
+		]
+	);
+}
+
+#[test]
+fn analyze_manifest_change_reports_requires_python_dependency_entry_point_and_extra_diffs() {
+	let change = AnalyzedFileChange {
+		path: PathBuf::from("packages/greeter/pyproject.toml"),
+		package_path: PathBuf::from("pyproject.toml"),
+		kind: FileChangeKind::Modified,
+		before_contents: Some(
+			concat!(
+				"[project]\n",
+				"name = \"greeter\"\n",
+				"requires-python = \">=3.9\"\n",
+				"dependencies = [\"requests>=2.28\", \"Rich_Text\"]\n",
+				"\n",
+				"[project.optional-dependencies]\n",
+				"cli = [\"click>=8\"]\n",
+				"\n",
+				"[project.scripts]\n",
+				"greet = \"greeter.cli:main\"\n",
+			)
+			.to_string(),
+		),
+		after_contents: Some(
+			concat!(
+				"[project]\n",
+				"name = \"greeter\"\n",
+				"requires-python = \">=3.10\"\n",
+				"dependencies = [\"requests>=2.31; python_version >= '3.10'\", \"rich-text\", \"attrs\"]\n",
+				"\n",
+				"[project.optional-dependencies]\n",
+				"yaml = [\"pyyaml\"]\n",
+				"\n",
+				"[project.entry-points.\"greeter.plugins\"]\n",
+				"loud = \"greeter.plugins:loud\"\n",
+			)
+			.to_string(),
+		),
+	};
+	let mut warnings = Vec::new();
+	let changes = analyze_manifest_change(&change, &mut warnings)
+		.into_iter()
+		.map(|change| (change.category, change.kind, change.item_path))
+		.collect::<Vec<_>>();
+
+	assert!(warnings.is_empty());
+	assert_eq!(
+		changes,
+		vec![
+			(
+				SemanticChangeCategory::Export,
+				SemanticChangeKind::Added,
+				"entry-points.greeter.plugins.loud".to_string()
+			),
+			(
+				SemanticChangeCategory::Export,
+				SemanticChangeKind::Removed,
+				"scripts.greet".to_string()
+			),
+			(
+				SemanticChangeCategory::Dependency,
+				SemanticChangeKind::Added,
+				"attrs".to_string()
+			),
+			(
+				SemanticChangeCategory::Dependency,
+				SemanticChangeKind::Modified,
+				"requests".to_string()
+			),
+			(
+				SemanticChangeCategory::Dependency,
+				SemanticChangeKind::Modified,
+				"rich-text".to_string()
+			),
+			(
+				SemanticChangeCategory::Metadata,
+				SemanticChangeKind::Added,
+				"extra.yaml".to_string()
+			),
+			(
+				SemanticChangeCategory::Metadata,
+				SemanticChangeKind::Modified,
+				"project.requires-python".to_string()
+			),
+			(
+				SemanticChangeCategory::Metadata,
+				SemanticChangeKind::Removed,
+				"extra.cli".to_string()
+			),
+		]
+	);
+}
+
+#[test]
+fn analyze_manifest_change_reads_poetry_tables_and_reports_parse_failures() {
+	let change = AnalyzedFileChange {
+		path: PathBuf::from("pyproject.toml"),
+		package_path: PathBuf::from("pyproject.toml"),
+		kind: FileChangeKind::Modified,
+		before_contents: Some("[tool.poetry\n".to_string()),
+		after_contents: Some(
+			concat!(
+				"[tool.poetry.dependencies]\n",
+				"python = \"^3.11\"\n",
+				"httpx = { version = \"^0.27\", optional = true }\n",
+				"\n",
+				"[tool.poetry.extras]\n",
+				"http = [\"httpx\"]\n",
+				"\n",
+				"[tool.poetry.scripts]\n",
+				"greet = \"greeter.cli:main\"\n",
+			)
+			.to_string(),
+		),
+	};
+	let mut warnings = Vec::new();
+	let changes = analyze_manifest_change(&change, &mut warnings);
+
+	assert_eq!(warnings.len(), 1);
+	assert!(
+		warnings
+			.first()
+			.is_some_and(|warning| warning.contains("failed to parse pyproject.toml"))
+	);
+	for (item_kind, item_path, value) in [
+		("entry_point", "scripts.greet", "greeter.cli:main"),
+		(
+			"dependency",
+			"httpx",
+			"[tool.poetry.dependencies] optional=true, version=^0.27",
+		),
+		("extra", "extra.http", "httpx"),
+		("manifest_field", "tool.poetry.dependencies.python", "^3.11"),
+	] {
+		assert!(
+			changes.iter().any(|change| {
+				change.kind == SemanticChangeKind::Added
+					&& change.item_kind == item_kind
+					&& change.item_path == item_path
+					&& change.after_signature.as_deref() == Some(value)
+			}),
+			"missing {item_path} in {changes:#?}"
+		);
+	}
+}
@@ -0,0 +1,1143 @@
+use std::collections::BTreeMap;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_core::AnalyzedFileChange;
+use monochange_core::DetectionLevel;
+use monochange_core::Ecosystem;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageAnalysisContext;
+use monochange_core::PackageAnalysisResult;
+use monochange_core::PackageRecord;
+use monochange_core::PackageSnapshot;
+use monochange_core::PackageSnapshotFile;
+use monochange_core::SemanticAnalyzer;
+use monochange_core::SemanticChange;
+use monochange_core::SemanticChangeCategory;
+use monochange_core::SemanticChangeKind;
+use toml::Value;
+
+use crate::PYPROJECT_FILE;
+
+/// Python analyzer that extracts the public module API and `pyproject.toml` diffs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PythonSemanticAnalyzer;
+
+/// Return the shared Python semantic analyzer.
+#[must_use]
+pub const fn semantic_analyzer() -> PythonSemanticAnalyzer {
+	PythonSemanticAnalyzer
+}
+
+impl SemanticAnalyzer for PythonSemanticAnalyzer {
+	fn analyzer_id(&self) -> &'static str {
+		"python/public-api"
+	}
+
+	fn applies_to(&self, package: &PackageRecord) -> bool {
+		package.ecosystem == Ecosystem::Python
+	}
+
+	fn analyze_package(
+		&self,
+		context: &PackageAnalysisContext<'_>,
+	) -> MonochangeResult<PackageAnalysisResult> {
+		let mut semantic_changes = Vec::new();
+		let mut warnings = Vec::new();
+
+		if context.detection_level != DetectionLevel::Basic {
+			let before_symbols =
+				snapshot_public_symbols(context.before_snapshot, context.changed_files);
+			let after_symbols =
+				snapshot_public_symbols(context.after_snapshot, context.changed_files);
+			semantic_changes.extend(diff_public_symbols(&before_symbols, &after_symbols));
+		}
+
+		if let Some(manifest_change) = context
+			.changed_files
+			.iter()
+			.find(|change| change.package_path == Path::new(PYPROJECT_FILE))
+		{
+			semantic_changes.extend(analyze_manifest_change(manifest_change, &mut warnings));
+		}
+
+		semantic_changes.sort_by(|left, right| {
+			(
+				left.category,
+				left.kind,
+				left.item_kind.as_str(),
+				left.item_path.as_str(),
+			)
+				.cmp(&(
+					right.category,
+					right.kind,
+					right.item_kind.as_str(),
+					right.item_path.as_str(),
+				))
+		});
+
+		Ok(PackageAnalysisResult {
+			analyzer_id: self.analyzer_id().to_string(),
+			package_id: display_package_id(context.package),
+			ecosystem: context.package.ecosystem,
+			changed_files: context
+				.changed_files
+				.iter()
+				.map(|file| file.package_path.clone())
+				.collect(),
+			semantic_changes,
+			warnings,
+		})
+	}
+}
+
+fn display_package_id(package: &PackageRecord) -> String {
+	package
+		.metadata
+		.get("config_id")
+		.cloned()
+		.unwrap_or_else(|| package.id.clone())
+}
+
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
+struct PublicSymbol {
+	item_kind: String,
+	item_path: String,
+	signature: String,
+	file_path: PathBuf,
+}
+
+fn snapshot_public_symbols(
+	snapshot: Option<&PackageSnapshot>,
+	changed_files: &[AnalyzedFileChange],
+) -> BTreeMap<(String, String), PublicSymbol> {
+	let mut symbols = BTreeMap::new();
+
+	if let Some(snapshot) = snapshot {
+		for file in &snapshot.files {
+			if !is_public_python_source_file(&file.path) {
+				continue;
+			}
+
+			for symbol in collect_public_symbols(file) {
+				symbols.insert((symbol.item_kind.clone(), symbol.item_path.clone()), symbol);
+			}
+		}
+
+		return symbols;
+	}
+
+	for change in changed_files {
+		let Some(contents) = change
+			.after_contents
+			.as_deref()
+			.or(change.before_contents.as_deref())
+		else {
+			continue;
+		};
+		if !is_public_python_source_file(&change.package_path) {
+			continue;
+		}
+
+		let file = PackageSnapshotFile {
+			path: change.package_path.clone(),
+			contents: contents.to_string(),
+		};
+		for symbol in collect_public_symbols(&file) {
+			symbols.insert((symbol.item_kind.clone(), symbol.item_path.clone()), symbol);
+		}
+	}
+
+	symbols
+}
+
+/// Python modules that belong to the importable API: `.py` and `.pyi` files
+/// outside test, tooling, and virtualenv directories whose path has no
+/// `_private` component.
+fn is_public_python_source_file(path: &Path) -> bool {
+	if !matches!(
+		path.extension().and_then(|ext| ext.to_str()),
+		Some("py" | "pyi")
+	) {
+		return false;
+	}
+
+	let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+		return false;
+	};
+	if (stem != "__init__" && stem.starts_with('_'))
+		|| stem.starts_with("test_")
+		|| stem.ends_with("_test")
+		|| matches!(stem, "conftest" | "setup" | "noxfile")
+	{
+		return false;
+	}
+
+	path.parent()
+		.into_iter()
+		.flat_map(Path::components)
+		.filter_map(|component| component.as_os_str().to_str())
+		.all(|directory| {
+			!directory.starts_with('_')
+				&& !directory.starts_with('.')
+				&& !matches!(
+					directory,
+					"tests"
+						| "test" | "docs" | "examples"
+						| "scripts" | "benchmarks"
+						| "venv" | "build" | "dist"
+				)
+		})
+}
+
+fn module_path_for_file(path: &Path) -> Vec<String> {
+	let mut components = path
+		.components()
+		.filter_map(|component| {
+			match component {
+				Component::Normal(name) => name.to_str().map(ToString::to_string),
+				_ => None,
+			}
+		})
+		.collect::<Vec<_>>();
+
+	if components
+		.first()
+		.is_some_and(|component| component == "src")
+	{
+		components.remove(0);
+	}
+
+	let Some(file_name) = components.pop() else {
+		return Vec::new();
+	};
+	let stem = file_name
+		.strip_suffix(".pyi")
+		.or_else(|| file_name.strip_suffix(".py"))
+		.unwrap_or(&file_name);
+	if stem != "__init__" {
+		components.push(stem.to_string());
+	}
+
+	components
+}
+
+/// One statement after joining bracketed and backslash continuations,
+/// with comments removed and whitespace collapsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct LogicalLine {
+	indent: usize,
+	text: String,
+}
+
+fn logical_lines(contents: &str) -> Vec<LogicalLine> {
+	let mut lines = Vec::new();
+	let mut text = String::new();
+	let mut indent = 0;
+	let mut at_line_start = true;
+	let mut depth = 0_usize;
+	let mut string: Option<(char, bool)> = None;
+	let mut characters = contents.chars().peekable();
+
+	while let Some(character) = characters.next() {
+		if at_line_start {
+			if character == ' ' || character == '\t' {
+				indent += 1;
+				continue;
+			}
+			at_line_start = false;
+		}
+
+		if let Some((quote, triple)) = string {
+			match character {
+				'\\' => {
+					text.push(character);
+					if let Some(escaped) = characters.next() {
+						text.push(escaped);
+					}
+				}
+				'\n' => text.push(' '),
+				_ if character == quote && !triple => {
+					text.push(character);
+					string = None;
+				}
+				_ if character == quote => {
+					let mut run = 1;
+					while run < 3 && characters.next_if_eq(&quote).is_some() {
+						run += 1;
+					}
+					text.extend(std::iter::repeat_n(quote, run));
+					if run == 3 {
+						string = None;
+					}
+				}
+				_ => text.push(character),
+			}
+			continue;
+		}
+
+		match character {
+			'#' => while characters.next_if(|next| *next != '\n').is_some() {},
+			'\'' | '"' => {
+				let triple = characters.next_if_eq(&character).is_some();
+				if triple && characters.next_if_eq(&character).is_none() {
+					// An empty string literal such as `""`.
+					text.extend([character; 2]);
+					continue;
+				}
+				text.extend(std::iter::repeat_n(character, if triple { 3 } else { 1 }));
+				string = Some((character, triple));
+			}
+			'\\' if characters.peek() == Some(&'\n') => {
+				characters.next();
+				text.push(' ');
+			}
+			'(' | '[' | '{' => {
+				depth += 1;
+				text.push(character);
+			}
+			')' | ']' | '}' => {
+				depth = depth.saturating_sub(1);
+				text.push(character);
+			}
+			'\n' if depth > 0 => text.push(' '),
+			'\n' => {
+				push_logical_line(&mut lines, indent, &text);
+				text.clear();
+				indent = 0;
+				at_line_start = true;
+			}
+			_ => text.push(character),
+		}
+	}
+	push_logical_line(&mut lines, indent, &text);
+
+	lines
+}
+
+fn push_logical_line(lines: &mut Vec<LogicalLine>, indent: usize, text: &str) {
+	let text = normalize_signature(text);
+	if !text.is_empty() {
+		lines.push(LogicalLine { indent, text });
+	}
+}
+
+fn normalize_signature(text: &str) -> String {
+	text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A block opened by `class` or `def` that later lines may be nested in.
+enum Scope {
+	Class(String),
+	Hidden,
+}
+
+fn collect_public_symbols(file: &PackageSnapshotFile) -> Vec<PublicSymbol> {
+	let module_path = module_path_for_file(&file.path);
+	let module_name = module_path.join(".");
+	let qualify = |name: &str| {
+		if module_name.is_empty() {
+			name.to_string()
+		} else {
+			format!("{module_name}.{name}")
+		}
+	};
+	let mut symbols = Vec::new();
+	let mut scopes = Vec::<(usize, Scope)>::new();
+	let mut decorators = Vec::new();
+
+	for line in logical_lines(&file.contents) {
+		while scopes
+			.last()
+			.is_some_and(|(indent, _)| *indent >= line.indent)
+		{
+			scopes.pop();
+		}
+
+		let owner = match scopes.last() {
+			None => None,
+			Some((_, Scope::Class(class_path))) => Some(class_path.clone()),
+			Some((_, Scope::Hidden)) => continue,
+		};
+
+		if let Some(decorator) = line.text.strip_prefix('@') {
+			decorators.push(decorator.to_string());
+			continue;
+		}
+		let line_decorators = std::mem::take(&mut decorators);
+
+		if let Some(function) = parse_function(&line.text) {
+			let visible = match &owner {
+				None => is_public_name(&function.name),
+				Some(_) => is_public_name(&function.name) || is_dunder_name(&function.name),
+			};
+			scopes.push((line.indent, Scope::Hidden));
+			if !visible {
+				continue;
+			}
+
+			let (item_kind, item_path) = match &owner {
+				None => ("function", qualify(&function.name)),
+				Some(class_path) => ("method", format!("{class_path}.{}", function.name)),
+			};
+			symbols.push(PublicSymbol {
+				item_kind: item_kind.to_string(),
+				item_path,
+				signature: render_function_signature(&function, &line_decorators),
+				file_path: file.path.clone(),
+			});
+			continue;
+		}
+
+		if let Some((name, signature)) = parse_class(&line.text) {
+			if !is_public_name(name) {
+				scopes.push((line.indent, Scope::Hidden));
+				continue;
+			}
+
+			let class_path = match &owner {
+				None => qualify(name),
+				Some(owner_path) => format!("{owner_path}.{name}"),
+			};
+			symbols.push(PublicSymbol {
+				item_kind: "class".to_string(),
+				item_path: class_path.clone(),
+				signature,
+				file_path: file.path.clone(),
+			});
+			scopes.push((line.indent, Scope::Class(class_path)));
+			continue;
+		}
+
+		if owner.is_none() {
+			for name in parse_dunder_all(&line.text) {
+				symbols.push(PublicSymbol {
+					item_kind: "export".to_string(),
+					item_path: qualify(&name),
+					signature: format!("__all__ += [{name:?}]"),
+					file_path: file.path.clone(),
+				});
+			}
+		}
+	}
+
+	symbols
+}
+
+fn is_public_name(name: &str) -> bool {
+	!name.starts_with('_')
+}
+
+fn is_dunder_name(name: &str) -> bool {
+	name.len() > 4 && name.starts_with("__") && name.ends_with("__")
+}
+
+/// A parsed `def` header.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct FunctionSignature {
+	is_async: bool,
+	name: String,
+	parameters: Vec<String>,
+	returns: Option<String>,
+}
+
+fn parse_function(text: &str) -> Option<FunctionSignature> {
+	let (is_async, rest) = match text.strip_prefix("async def ") {
+		Some(rest) => (true, rest),
+		None => (false, text.strip_prefix("def ")?),
+	};
+	let open = rest.find('(')?;
+	let name = rest.get(..open)?.trim();
+	if name.is_empty()
+		|| !name
+			.chars()
+			.all(|character| character.is_alphanumeric() || character == '_')
+	{
+		return None;
+	}
+	let close = matching_close(rest, open)?;
+	let parameters = split_top_level(rest.get(open + 1..close)?, ',');
+	let tail = rest.get(close + 1..)?.trim_start();
+	let returns = tail.strip_prefix("->").and_then(|annotation| {
+		let end = find_top_level(annotation, ':').unwrap_or(annotation.len());
+		annotation
+			.get(..end)
+			.map(str::trim)
+			.filter(|annotation| !annotation.is_empty())
+			.map(ToString::to_string)
+	});
+
+	Some(FunctionSignature {
+		is_async,
+		name: name.to_string(),
+		parameters,
+		returns,
+	})
+}
+
+fn parse_class(text: &str) -> Option<(&str, String)> {
+	let rest = text.strip_prefix("class ")?;
+	let end = find_top_level(rest, ':')?;
+	let header = rest.get(..end)?.trim();
+	let name_end = header
+		.find(|character: char| !(character.is_alphanumeric() || character == '_'))
+		.unwrap_or(header.len());
+	let name = header.get(..name_end)?;
+	(!name.is_empty()).then(|| (name, format!("class {header}")))
+}
+
+/// Names listed by `__all__ = [...]`, `__all__ = (...)`, or `__all__ += [...]`.
+fn parse_dunder_all(text: &str) -> Vec<String> {
+	let Some(rest) = text.strip_prefix("__all__") else {
+		return Vec::new();
+	};
+	let rest = rest.trim_start();
+	let Some(value) = rest
+		.strip_prefix("+=")
+		.or_else(|| rest.strip_prefix('='))
+		.or_else(|| {
+			rest.strip_prefix(':')
+				.and_then(|rest| rest.split_once('=').map(|(_, value)| value))
+		})
+	else {
+		return Vec::new();
+	};
+
+	let mut names = Vec::new();
+	let mut remaining = value;
+	while let Some(start) = remaining.find(['\'', '"']) {
+		let Some(quote) = remaining.get(start..).and_then(|text| text.chars().next()) else {
+			break;
+		};
+		let Some(after_quote) = remaining.get(start + 1..) else {
+			break;
+		};
+		let Some(end) = after_quote.find(quote) else {
+			break;
+		};
+		if let Some(name) = after_quote.get(..end).filter(|name| !name.is_empty()) {
+			names.push(name.to_string());
+		}
+		remaining = after_quote.get(end + 1..).unwrap_or_default();
+	}
+
+	names
+}
+
+fn render_function_signature(function: &FunctionSignature, decorators: &[String]) -> String {
+	let decorators = decorators
+		.iter()
+		.filter(|decorator| is_signature_decorator(decorator))
+		.map(|decorator| format!("@{decorator} "))
+		.collect::<Vec<_>>()
+		.concat();
+	let asynchronous = if function.is_async { "async " } else { "" };
+	let returns = function
+		.returns
+		.as_ref()
+		.map_or_else(String::new, |returns| format!(" -> {returns}"));
+	format!(
+		"{decorators}{asynchronous}def {}({}){returns}",
+		function.name,
+		function.parameters.join(", ")
+	)
+}
+
+/// Decorators that change how a function is called rather than what it does.
+fn is_signature_decorator(decorator: &str) -> bool {
+	let name = decorator.split('(').next().unwrap_or(decorator);
+	let last_segment = name.rsplit('.').next().unwrap_or(name);
+	matches!(
+		last_segment,
+		"staticmethod" | "classmethod" | "property" | "abstractmethod" | "setter" | "deleter"
+	)
+}
+
+fn matching_close(text: &str, open: usize) -> Option<usize> {
+	let mut depth = 0_usize;
+	let mut string = None;
+	let mut escaped = false;
+	for (index, character) in text.char_indices().skip_while(|(index, _)| *index < open) {
+		if let Some(quote) = string {
+			if escaped {
+				escaped = false;
+			} else if character == '\\' {
+				escaped = true;
+			} else if character == quote {
+				string = None;
+			}
+			continue;
+		}
+
+		match character {
+			'\'' | '"' => string = Some(character),
+			'(' | '[' | '{' => depth += 1,
+			')' | ']' | '}' => {
+				depth = depth.saturating_sub(1);
+				if depth == 0 {
+					return Some(index);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	None
+}
+
+fn find_top_level(text: &str, target: char) -> Option<usize> {
+	let mut depth = 0_usize;
+	let mut string = None;
+	let mut escaped = false;
+	for (index, character) in text.char_indices() {
+		if let Some(quote) = string {
+			if escaped {
+				escaped = false;
+			} else if character == '\\' {
+				escaped = true;
+			} else if character == quote {
+				string = None;
+			}
+			continue;
+		}
+
+		match character {
+			'\'' | '"' => string = Some(character),
+			'(' | '[' | '{' => depth += 1,
+			')' | ']' | '}' => depth = depth.saturating_sub(1),
+			_ if character == target && depth == 0 => return Some(index),
+			_ => {}
+		}
+	}
+
+	None
+}
+
+fn split_top_level(text: &str, separator: char) -> Vec<String> {
+	let mut parts = Vec::new();
+	let mut remaining = text;
+	while let Some(index) = find_top_level(remaining, separator) {
+		parts.push(remaining.get(..index).unwrap_or_default());
+		remaining = remaining.get(index + 1..).unwrap_or_default();
+	}
+	parts.push(remaining);
+
+	parts
+		.into_iter()
+		.map(str::trim)
+		.filter(|part| !part.is_empty())
+		.map(ToString::to_string)
+		.collect()
+}
+
+fn diff_public_symbols(
+	before: &BTreeMap<(String, String), PublicSymbol>,
+	after: &BTreeMap<(String, String), PublicSymbol>,
+) -> Vec<SemanticChange> {
+	let mut changes = Vec::new();
+
+	for (key, after_symbol) in after {
+		match before.get(key) {
+			None => {
+				changes.push(build_symbol_change(
+					SemanticChangeKind::Added,
+					after_symbol,
+					None,
+					Some(after_symbol.signature.clone()),
+				));
+			}
+			Some(before_symbol) if before_symbol.signature != after_symbol.signature => {
+				changes.extend(compare_signatures(before_symbol, after_symbol));
+			}
+			Some(_) => {}
+		}
+	}
+
+	for (key, before_symbol) in before {
+		if after.contains_key(key) {
+			continue;
+		}
+
+		changes.push(build_symbol_change(
+			SemanticChangeKind::Removed,
+			before_symbol,
+			Some(before_symbol.signature.clone()),
+			None,
+		));
+	}
+
+	changes
+}
+
+/// Appending optional parameters keeps every existing call working, so it is
+/// reported as added parameters. Any other signature change, including a new
+/// default value, is a modification.
+fn compare_signatures(before: &PublicSymbol, after: &PublicSymbol) -> Vec<SemanticChange> {
+	let modified = || {
+		vec![build_symbol_change(
+			SemanticChangeKind::Modified,
+			after,
+			Some(before.signature.clone()),
+			Some(after.signature.clone()),
+		)]
+	};
+	let Some(added) = matches!(after.item_kind.as_str(), "function" | "method")
+		.then(|| appended_optional_parameters(&before.signature, &after.signature))
+		.flatten()
+	else {
+		return modified();
+	};
+
+	added
+		.into_iter()
+		.map(|parameter| {
+			let name = parameter_name(&parameter);
+			SemanticChange {
+				category: SemanticChangeCategory::PublicApi,
+				kind: SemanticChangeKind::Added,
+				item_kind: "parameter".to_string(),
+				item_path: format!("{}.{name}", after.item_path),
+				summary: format!(
+					"optional parameter `{name}` added to {} `{}`",
+					after.item_kind, after.item_path
+				),
+				file_path: after.file_path.clone(),
+				before_signature: Some(before.signature.clone()),
+				after_signature: Some(after.signature.clone()),
+			}
+		})
+		.collect()
+}
+
+/// The parameters `after` adds to `before` when every addition is optional
+/// and lands after the existing parameters, or just before a trailing
+/// `**kwargs`.
+fn appended_optional_parameters(before: &str, after: &str) -> Option<Vec<String>> {
+	let before = parse_rendered_signature(before)?;
+	let after = parse_rendered_signature(after)?;
+	if before.0 != after.0 || before.2 != after.2 {
+		return None;
+	}
+
+	let mut before_parameters = before.1;
+	let mut after_parameters = after.1;
+	if before_parameters
+		.last()
+		.is_some_and(|parameter| parameter.starts_with("**"))
+	{
+		if after_parameters.last() != before_parameters.last() {
+			return None;
+		}
+		before_parameters.pop();
+		after_parameters.pop();
+	}
+
+	let added = after_parameters.strip_prefix(before_parameters.as_slice())?;
+	let optional = !added.is_empty()
+		&& added.iter().all(|parameter| {
+			parameter.starts_with('*') || find_top_level(parameter, '=').is_some()
+		});
+	optional.then(|| {
+		added
+			.iter()
+			.filter(|parameter| *parameter != "*")
+			.cloned()
+			.collect()
+	})
+}
+
+/// Split a rendered signature into its prefix up to `(`, its parameters, and
+/// everything after the closing parenthesis.
+fn parse_rendered_signature(signature: &str) -> Option<(String, Vec<String>, String)> {
+	let open = signature.find('(')?;
+	let close = matching_close(signature, open)?;
+	Some((
+		signature.get(..open)?.to_string(),
+		split_top_level(signature.get(open + 1..close)?, ','),
+		signature.get(close + 1..)?.to_string(),
+	))
+}
+
+fn parameter_name(parameter: &str) -> &str {
+	let end = parameter.find([':', '=']).unwrap_or(parameter.len());
+	parameter
+		.get(..end)
+		.unwrap_or(parameter)
+		.trim()
+		.trim_start_matches('*')
+}
+
+fn build_symbol_change(
+	kind: SemanticChangeKind,
+	symbol: &PublicSymbol,
+	before_signature: Option<String>,
+	after_signature: Option<String>,
+) -> SemanticChange {
+	let verb = if kind == SemanticChangeKind::Added {
+		"added"
+	} else if kind == SemanticChangeKind::Removed {
+		"removed"
+	} else {
+		"modified"
+	};
+	let category = if symbol.item_kind == "export" {
+		SemanticChangeCategory::Export
+	} else {
+		SemanticChangeCategory::PublicApi
+	};
+
+	SemanticChange {
+		category,
+		kind,
+		item_kind: symbol.item_kind.clone(),
+		item_path: symbol.item_path.clone(),
+		summary: format!("{} `{}` {verb}", symbol.item_kind, symbol.item_path),
+		file_path: symbol.file_path.clone(),
+		before_signature,
+		after_signature,
+	}
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct ManifestEntry {
+	item_kind: String,
+	value: String,
+}
+
+fn analyze_manifest_change(
+	change: &AnalyzedFileChange,
+	warnings: &mut Vec<String>,
+) -> Vec<SemanticChange> {
+	let mut changes = Vec::new();
+
+	let before_manifest = parse_manifest(
+		change.before_contents.as_deref(),
+		&change.package_path,
+		warnings,
+	);
+	let after_manifest = parse_manifest(
+		change.after_contents.as_deref(),
+		&change.package_path,
+		warnings,
+	);
+
+	let before_entry_points = before_manifest
+		.as_ref()
+		.map(extract_entry_point_entries)
+		.unwrap_or_default();
+	let after_entry_points = after_manifest
+		.as_ref()
+		.map(extract_entry_point_entries)
+		.unwrap_or_default();
+	changes.extend(compare_manifest_entries(
+		SemanticChangeCategory::Export,
+		&change.package_path,
+		&before_entry_points,
+		&after_entry_points,
+	));
+
+	let before_dependencies = before_manifest
+		.as_ref()
+		.map(extract_dependency_entries)
+		.unwrap_or_default();
+	let after_dependencies = after_manifest
+		.as_ref()
+		.map(extract_dependency_entries)
+		.unwrap_or_default();
+	changes.extend(compare_manifest_entries(
+		SemanticChangeCategory::Dependency,
+		&change.package_path,
+		&before_dependencies,
+		&after_dependencies,
+	));
+
+	let before_metadata = before_manifest
+		.as_ref()
+		.map(extract_metadata_entries)
+		.unwrap_or_default();
+	let after_metadata = after_manifest
+		.as_ref()
+		.map(extract_metadata_entries)
+		.unwrap_or_default();
+	changes.extend(compare_manifest_entries(
+		SemanticChangeCategory::Metadata,
+		&change.package_path,
+		&before_metadata,
+		&after_metadata,
+	));
+
+	changes
+}
+
+fn parse_manifest(
+	contents: Option<&str>,
+	path: &Path,
+	warnings: &mut Vec<String>,
+) -> Option<Value> {
+	let contents = contents?;
+	match toml::from_str::<Value>(contents) {
+		Ok(value) => Some(value),
+		Err(error) => {
+			warnings.push(format!("failed to parse {}: {error}", path.display()));
+			None
+		}
+	}
+}
+
+fn table_at<'a>(value: &'a Value, path: &[&str]) -> Option<&'a toml::Table> {
+	path.iter()
+		.try_fold(value, |current, key| current.get(key))
+		.and_then(Value::as_table)
+}
+
+fn extract_entry_point_entries(value: &Value) -> BTreeMap<String, ManifestEntry> {
+	let mut entries = BTreeMap::new();
+	let mut insert_table = |prefix: String, table: &toml::Table| {
+		for (name, target) in table {
+			entries.insert(
+				format!("{prefix}.{name}"),
+				ManifestEntry {
+					item_kind: "entry_point".to_string(),
+					value: describe_manifest_value(target),
+				},
+			);
+		}
+	};
+
+	for (path, prefix) in [
+		(&["project", "scripts"][..], "scripts"),
+		(&["project", "gui-scripts"][..], "gui-scripts"),
+		(&["tool", "poetry", "scripts"][..], "scripts"),
+	] {
+		if let Some(table) = table_at(value, path) {
+			insert_table(prefix.to_string(), table);
+		}
+	}
+
+	if let Some(groups) = table_at(value, &["project", "entry-points"]) {
+		for (group, table) in groups {
+			if let Some(table) = table.as_table() {
+				insert_table(format!("entry-points.{group}"), table);
+			}
+		}
+	}
+
+	entries
+}
+
+fn extract_dependency_entries(value: &Value) -> BTreeMap<String, ManifestEntry> {
+	let mut entries = BTreeMap::new();
+
+	if let Some(requirements) = value
+		.get("project")
+		.and_then(|project| project.get("dependencies"))
+		.and_then(Value::as_array)
+	{
+		for requirement in requirements.iter().filter_map(Value::as_str) {
+			let Some(name) = requirement_name(requirement) else {
+				continue;
+			};
+			entries.insert(
+				name,
+				ManifestEntry {
+					item_kind: "dependency".to_string(),
+					value: format!("[project.dependencies] {requirement}"),
+				},
+			);
+		}
+	}
+
+	if let Some(dependencies) = table_at(value, &["tool", "poetry", "dependencies"]) {
+		for (name, dependency) in dependencies {
+			if name == "python" {
+				continue;
+			}
+			entries.insert(
+				normalize_requirement_name(name),
+				ManifestEntry {
+					item_kind: "dependency".to_string(),
+					value: format!(
+						"[tool.poetry.dependencies] {}",
+						describe_manifest_value(dependency)
+					),
+				},
+			);
+		}
+	}
+
+	entries
+}
+
+fn extract_metadata_entries(value: &Value) -> BTreeMap<String, ManifestEntry> {
+	let mut entries = BTreeMap::new();
+
+	for (path, item_path) in [
+		(
+			&["project", "requires-python"][..],
+			"project.requires-python",
+		),
+		(
+			&["tool", "poetry", "dependencies", "python"][..],
+			"tool.poetry.dependencies.python",
+		),
+	] {
+		if let Some(field_value) = path.iter().try_fold(value, |current, key| current.get(key)) {
+			entries.insert(
+				item_path.to_string(),
+				ManifestEntry {
+					item_kind: "manifest_field".to_string(),
+					value: describe_manifest_value(field_value),
+				},
+			);
+		}
+	}
+
+	for path in [
+		&["project", "optional-dependencies"][..],
+		&["tool", "poetry", "extras"][..],
+	] {
+		let Some(extras) = table_at(value, path) else {
+			continue;
+		};
+		for (name, requirements) in extras {
+			entries.insert(
+				format!("extra.{name}"),
+				ManifestEntry {
+					item_kind: "extra".to_string(),
+					value: describe_manifest_value(requirements),
+				},
+			);
+		}
+	}
+
+	entries
+}
+
+/// The distribution name of a PEP 508 requirement such as
+/// `requests[socks]>=2.31; python_version >= "3.9"`.
+fn requirement_name(requirement: &str) -> Option<String> {
+	let name = requirement
+		.trim()
+		.split(|character: char| {
+			!(character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | '.'))
+		})
+		.next()
+		.filter(|name| !name.is_empty())?;
+	Some(normalize_requirement_name(name))
+}
+
+/// Normalize a distribution name as PEP 503 does, so `Foo_Bar` and
+/// `foo-bar` refer to the same dependency.
+fn normalize_requirement_name(name: &str) -> String {
+	name.to_ascii_lowercase().replace(['_', '.'], "-")
+}
+
+fn compare_manifest_entries(
+	category: SemanticChangeCategory,
+	file_path: &Path,
+	before: &BTreeMap<String, ManifestEntry>,
+	after: &BTreeMap<String, ManifestEntry>,
+) -> Vec<SemanticChange> {
+	let mut changes = Vec::new();
+
+	for (name, after_entry) in after {
+		match before.get(name) {
+			None => {
+				changes.push(build_manifest_change(
+					category,
+					SemanticChangeKind::Added,
+					file_path,
+					name,
+					after_entry,
+					None,
+					Some(after_entry.value.clone()),
+				));
+			}
+			Some(before_entry) if before_entry != after_entry => {
+				changes.push(build_manifest_change(
+					category,
+					SemanticChangeKind::Modified,
+					file_path,
+					name,
+					after_entry,
+					Some(before_entry.value.clone()),
+					Some(after_entry.value.clone()),
+				));
+			}
+			Some(_) => {}
+		}
+	}
+
+	for (name, before_entry) in before {
+		if after.contains_key(name) {
+			continue;
+		}
+
+		changes.push(build_manifest_change(
+			category,
+			SemanticChangeKind::Removed,
+			file_path,
+			name,
+			before_entry,
+			Some(before_entry.value.clone()),
+			None,
+		));
+	}
+
+	changes
+}
+
+fn build_manifest_change(
+	category: SemanticChangeCategory,
+	kind: SemanticChangeKind,
+	file_path: &Path,
+	item_path: &str,
+	entry: &ManifestEntry,
+	before_signature: Option<String>,
+	after_signature: Option<String>,
+) -> SemanticChange {
+	let verb = if kind == SemanticChangeKind::Added {
+		"added"
+	} else if kind == SemanticChangeKind::Removed {
+		"removed"
+	} else {
+		"modified"
+	};
+
+	SemanticChange {
+		category,
+		kind,
+		item_kind: entry.item_kind.clone(),
+		item_path: item_path.to_string(),
+		summary: format!("{} `{}` {verb}", entry.item_kind, item_path),
+		file_path: file_path.to_path_buf(),
+		before_signature,
+		after_signature,
+	}
+}
+
+fn describe_manifest_value(value: &Value) -> String {
+	match value {
+		Value::String(text) => text.clone(),
+		Value::Array(items) => {
+			items
+				.iter()
+				.map(describe_manifest_value)
+				.collect::<Vec<_>>()
+				.join(", ")
+		}
+		Value::Table(table) => {
+			table
+				.iter()
+				.map(|(key, value)| format!("{key}={}", describe_manifest_value(value)))
+				.collect::<Vec<_>>()
+				.join(", ")
+		}
+		other => other.to_string(),
+	}
+}
+
+#[cfg(test)]
+#[path = "__tests__/analysis_tests.rs"]
+mod tests;
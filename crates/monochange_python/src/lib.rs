@@ -12,11 +12,14 @@
 //! - normalize Python package manifests and dependency edges for the shared
 //!   planner
 //! - infer lockfile refresh commands for uv and Poetry
+//! - diff the public module API and `pyproject.toml` for `mc analyze`
 //!
 //! ## Public entry points
 //!
 //! - `discover_python_packages(root)` discovers Python packages
 //! - `PythonAdapter` exposes the shared adapter interface
+//! - `PythonSemanticAnalyzer` extracts public functions, classes, methods,
+//!   and `__all__` exports for semantic diffs
 //!
 //! ## Scope
 //!
@@ -24,6 +27,10 @@
 //! - `pyproject.toml` parsing (`[project]` and `[tool.poetry]`)
 //! - normalized dependency extraction from PEP 621 metadata
 //! - lockfile command inference for uv and Poetry
+//! - source-level public API extraction
+
+pub mod analysis;
+pub mod lints;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -31,12 +38,16 @@ use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 
+pub use analysis::PythonSemanticAnalyzer;
+pub use analysis::semantic_analyzer;
 use glob::glob;
 use monochange_core::AdapterDiscovery;
 use monochange_core::DependencyKind;
+use monochange_core::DiscoveryPathFilter;
 use monochange_core::Ecosystem;
 use monochange_core::EcosystemAdapter;
 use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
 use monochange_core::MonochangeError;
 use monochange_core::MonochangeResult;
 use monochange_core::PackageDependency;
@@ -51,7 +62,6 @@ use toml::Value;
 use toml_edit::DocumentMut;
 use toml_edit::Item;
 use walkdir::DirEntry;
-use walkdir::WalkDir;
 
 pub const PYPROJECT_FILE: &str = "pyproject.toml";
 pub const UV_LOCK_FILE: &str = "uv.lock";
@@ -130,6 +140,14 @@ impl EcosystemAdapter for PythonAdapter {
 		discover_python_packages(root)
 	}
 
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_python_packages_with_filter(root, filter)
+	}
+
 	fn load_configured(
 		&self,
 		_root: &Path,
@@ -362,6 +380,21 @@ fn normalize_python_package_name(name: &str) -> String {
 
 #[tracing::instrument(skip_all)]
 pub fn discover_python_packages(root: &Path) -> MonochangeResult<AdapterDiscovery> {
+	discover_python_packages_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+/// Discover Python packages rooted at `root` without parsing manifests outside
+/// `filter`'s discovery scope.
+///
+/// A uv workspace root outside the scope still names its members, but is not
+/// discovered as a package itself.
+#[tracing::instrument(skip_all)]
+pub fn discover_python_packages_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_pyproject_files(root, filter);
+	let mut skipped = walk.skipped;
 	let mut packages = Vec::new();
 	let mut warnings = Vec::new();
 	let mut included_manifests = BTreeSet::new();
@@ -379,8 +412,13 @@ pub fn discover_python_packages(root: &Path) -> MonochangeResult<AdapterDiscover
 		if let Some(workspace_members) = workspace_members {
 			// Exclude the workspace root manifest from standalone discovery
 			included_manifests.insert(normalize_path(&root_manifest));
-			let member_manifests =
-				expand_workspace_members(root, &workspace_members, &mut warnings);
+			let member_manifests = filter.retain_manifests_in_scope(
+				Ecosystem::Python,
+				expand_workspace_members(root, &workspace_members, &mut warnings)
+					.into_iter()
+					.collect(),
+				&mut skipped,
+			);
 			for manifest_path in member_manifests {
 				if let Some(package) = parse_python_package(&manifest_path, root)? {
 					included_manifests.insert(normalize_path(&manifest_path));
@@ -393,7 +431,9 @@ pub fn discover_python_packages(root: &Path) -> MonochangeResult<AdapterDiscover
 	// Phase 2: scan for standalone pyproject.toml files not already discovered.
 	// Parse errors are treated as warnings since the walker picks up all
 	// pyproject.toml files including test fixtures and generated files.
-	for manifest_path in find_all_pyproject_files(root) {
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Python, walk.manifests, &mut skipped)
+	{
 		let normalized = normalize_path(&manifest_path);
 		if included_manifests.contains(&normalized) {
 			continue;
@@ -416,7 +456,11 @@ pub fn discover_python_packages(root: &Path) -> MonochangeResult<AdapterDiscover
 
 	tracing::debug!(packages = packages.len(), "discovered python packages");
 
-	Ok(AdapterDiscovery { packages, warnings })
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
 }
 
 fn parse_uv_workspace_members(manifest_path: &Path) -> MonochangeResult<Option<Vec<String>>> {
@@ -695,15 +739,10 @@ fn parse_pep440_as_semver(version: &str) -> Option<Version> {
 	}
 }
 
-fn find_all_pyproject_files(root: &Path) -> Vec<PathBuf> {
-	WalkDir::new(root)
-		.into_iter()
-		.filter_entry(should_descend)
-		.filter_map(Result::ok)
-		.filter(|entry| entry.file_name() == PYPROJECT_FILE)
-		.map(DirEntry::into_path)
-		.map(|path| normalize_path(&path))
-		.collect()
+fn find_all_pyproject_files(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(Ecosystem::Python, root, should_descend, |entry| {
+		entry.file_name() == PYPROJECT_FILE
+	})
 }
 
 fn should_descend(entry: &DirEntry) -> bool {
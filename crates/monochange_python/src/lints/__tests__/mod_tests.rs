@@ -0,0 +1,248 @@
+use monochange_config::load_workspace_configuration;
+use monochange_core::lint::LintSuite;
+use monochange_core::lint::LintTarget;
+use monochange_test_helpers::fixture_path;
+use serde_json::json;
+
+use super::*;
+
+fn workspace_hygiene_targets() -> Vec<LintTarget> {
+	let root = fixture_path!("python-lints/workspace-hygiene/workspace");
+	let configuration = load_workspace_configuration(&root)
+		.unwrap_or_else(|error| panic!("load python lint fixture config: {error}"));
+	lint_suite()
+		.collect_targets(&root, &configuration)
+		.unwrap_or_else(|error| panic!("collect python lint fixture targets: {error}"))
+}
+
+fn find_target<'a>(targets: &'a [LintTarget], package_name: &str) -> &'a LintTarget {
+	targets
+		.iter()
+		.find(|target| target.metadata.package_name.as_deref() == Some(package_name))
+		.unwrap_or_else(|| panic!("missing target for {package_name}"))
+}
+
+fn ctx(target: &LintTarget) -> LintContext<'_> {
+	LintContext {
+		workspace_root: &target.workspace_root,
+		manifest_path: &target.manifest_path,
+		contents: &target.contents,
+		metadata: &target.metadata,
+		parsed: target.parsed.as_ref(),
+	}
+}
+
+fn config() -> LintRuleConfig {
+	LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("fix".to_string(), json!(true))]),
+	}
+}
+
+fn fixed_contents(result: &LintResult) -> &str {
+	result
+		.fix
+		.as_ref()
+		.and_then(|fix| fix.edits.first())
+		.map_or_else(
+			|| panic!("expected an autofix for {}", result.rule_id),
+			|edit| edit.replacement.as_str(),
+		)
+}
+
+#[test]
+fn presets_are_exposed() {
+	let presets = PythonLintSuite.presets();
+	assert_eq!(presets.len(), 2);
+
+	let recommended = presets.first().expect("expected recommended preset");
+	assert_eq!(recommended.id, "python/recommended");
+	assert_eq!(
+		recommended.rules.get("python/sorted-dependencies"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Warning))
+	);
+	assert_eq!(
+		recommended.rules.get("python/no-path-or-git-dependencies"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Error))
+	);
+
+	let strict = presets.get(1).expect("expected strict preset");
+	assert_eq!(strict.id, "python/strict");
+	assert_eq!(
+		strict.rules.get("python/requires-python-consistent"),
+		Some(&LintRuleConfig::Severity(LintSeverity::Error))
+	);
+}
+
+#[test]
+fn collect_targets_marks_managed_and_private_packages() {
+	let targets = workspace_hygiene_targets();
+	assert_eq!(targets.len(), 4);
+
+	let api = find_target(&targets, "acme-api");
+	assert!(api.metadata.managed);
+	assert_eq!(api.metadata.package_id.as_deref(), Some("api"));
+	assert_eq!(api.metadata.publishable, Some(true));
+
+	let internal = find_target(&targets, "acme-internal");
+	assert!(!internal.metadata.managed);
+	assert_eq!(internal.metadata.private, Some(true));
+	assert_eq!(internal.metadata.publishable, Some(false));
+}
+
+#[test]
+fn required_package_fields_rule_honors_dynamic_fields_and_poetry_metadata() {
+	let targets = workspace_hygiene_targets();
+	let rule = RequiredPackageFieldsRule::new();
+
+	let cli_results = rule.run(&ctx(find_target(&targets, "acme-cli")), &config());
+	assert_eq!(cli_results.len(), 1);
+	assert!(
+		cli_results
+			.first()
+			.is_some_and(|result| result.message.contains("`license`"))
+	);
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "acme-api")), &config())
+			.is_empty()
+	);
+	assert!(
+		rule.run(&ctx(find_target(&targets, "acme-legacy")), &config())
+			.is_empty()
+	);
+	// Unmanaged packages are not released, so their metadata is not checked.
+	assert!(
+		rule.run(&ctx(find_target(&targets, "acme-internal")), &config())
+			.is_empty()
+	);
+}
+
+#[test]
+fn sorted_dependencies_rule_sorts_pep_621_arrays_in_place() {
+	let targets = workspace_hygiene_targets();
+	let rule = SortedDependenciesRule::new();
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "acme-api")), &config())
+			.is_empty()
+	);
+
+	let results = rule.run(&ctx(find_target(&targets, "acme-cli")), &config());
+	let sections = results
+		.iter()
+		.map(|result| result.message.as_str())
+		.collect::<Vec<_>>();
+	assert_eq!(
+		sections,
+		vec![
+			"dependencies in `project.dependencies` are not sorted by package name",
+			"dependencies in `project.optional-dependencies.docs` are not sorted by package name",
+		]
+	);
+	let fixed = fixed_contents(results.first().expect("expected a result"));
+	assert!(fixed.contains(
+		"dependencies = [\n\t\"acme-api>=1.2\",\n\t\"acme-tools @ git+https://github.com/acme/tools\",\n\t\"click>=8.1\",\n\t\"rich>=13\",\n]"
+	));
+	assert!(fixed.contains("docs = [\"furo\", \"sphinx\"]"));
+	assert!(fixed.contains("requires-python = \">=3.10\" # older runtime"));
+}
+
+#[test]
+fn sorted_dependencies_rule_keeps_poetry_python_constraint_first() {
+	let targets = workspace_hygiene_targets();
+	let results =
+		SortedDependenciesRule::new().run(&ctx(find_target(&targets, "acme-legacy")), &config());
+	assert_eq!(results.len(), 2);
+
+	let fixed = fixed_contents(results.first().expect("expected a result"));
+	assert!(fixed.contains(
+		"[tool.poetry.dependencies]\npython = \">=3.11\"\nacme-shared = { path = \"../shared\", develop = true }\nattrs = \"^23.2\"\nrequests = \"^2.31\"\n"
+	));
+	assert!(
+		fixed.contains(
+			"[tool.poetry.group.dev.dependencies]\nblack = \"^24.0\"\npytest = \"^8.0\"\n"
+		)
+	);
+}
+
+#[test]
+fn no_path_or_git_dependencies_rule_reports_published_direct_references() {
+	let targets = workspace_hygiene_targets();
+	let rule = NoPathOrGitDependenciesRule::new();
+
+	let cli_results = rule.run(&ctx(find_target(&targets, "acme-cli")), &config());
+	assert_eq!(cli_results.len(), 1);
+	assert!(cli_results.first().is_some_and(|result| {
+		result
+			.message
+			.contains("`acme-tools` through `git+https://github.com/acme/tools`")
+	}));
+
+	let legacy_results = rule.run(&ctx(find_target(&targets, "acme-legacy")), &config());
+	assert_eq!(legacy_results.len(), 1);
+	assert!(
+		legacy_results
+			.first()
+			.is_some_and(|result| result.message.contains("path = \"../shared\""))
+	);
+
+	// Private packages may keep local sources.
+	assert!(
+		rule.run(&ctx(find_target(&targets, "acme-internal")), &config())
+			.is_empty()
+	);
+
+	let allow = LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("allow".to_string(), json!(["acme_tools"]))]),
+	};
+	assert!(
+		rule.run(&ctx(find_target(&targets, "acme-cli")), &allow)
+			.is_empty()
+	);
+}
+
+#[test]
+fn requires_python_consistent_rule_fixes_outliers_to_the_workspace_range() {
+	let targets = workspace_hygiene_targets();
+	let rule = RequiresPythonConsistentRule::new();
+
+	assert!(
+		rule.run(&ctx(find_target(&targets, "acme-api")), &config())
+			.is_empty()
+	);
+
+	let cli_results = rule.run(&ctx(find_target(&targets, "acme-cli")), &config());
+	let cli_result = cli_results.first().expect("expected a cli result");
+	assert_eq!(
+		cli_result.message,
+		"requires-python `>=3.10` differs from the workspace range `>=3.11`"
+	);
+	assert!(fixed_contents(cli_result).contains("requires-python = \">=3.11\" # older runtime"));
+
+	let internal_results = rule.run(&ctx(find_target(&targets, "acme-internal")), &config());
+	let internal_result = internal_results
+		.first()
+		.expect("expected an internal result");
+	assert!(
+		internal_result
+			.message
+			.contains("requires-python is missing")
+	);
+	assert!(fixed_contents(internal_result).contains("requires-python = \">=3.11\""));
+}
+
+#[test]
+fn requires_python_consistent_rule_accepts_an_explicit_expected_range() {
+	let targets = workspace_hygiene_targets();
+	let config = LintRuleConfig::Detailed {
+		level: LintSeverity::Error,
+		options: BTreeMap::from([("expected".to_string(), json!(">=3.12"))]),
+	};
+
+	let results = RequiresPythonConsistentRule::new()
+		.run(&ctx(find_target(&targets, "acme-legacy")), &config);
+	let result = results.first().expect("expected a legacy result");
+	assert!(fixed_contents(result).contains("[tool.poetry.dependencies]\npython = \">=3.12\"\n"));
+}
@@ -0,0 +1,851 @@
+#![forbid(clippy::indexing_slicing)]
+
+//! Python `pyproject.toml` lint suite.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use monochange_core::MonochangeResult;
+use monochange_core::PackageDefinition;
+use monochange_core::PublishState;
+use monochange_core::WorkspaceConfiguration;
+use monochange_core::lint::LintCategory;
+use monochange_core::lint::LintContext;
+use monochange_core::lint::LintFix;
+use monochange_core::lint::LintLocation;
+use monochange_core::lint::LintMaturity;
+use monochange_core::lint::LintOptionDefinition;
+use monochange_core::lint::LintOptionKind;
+use monochange_core::lint::LintPreset;
+use monochange_core::lint::LintResult;
+use monochange_core::lint::LintRule;
+use monochange_core::lint::LintRuleConfig;
+use monochange_core::lint::LintRuleRunner;
+use monochange_core::lint::LintSeverity;
+use monochange_core::lint::LintSuite;
+use monochange_core::lint::LintTarget;
+use monochange_core::lint::LintTargetMetadata;
+use monochange_core::relative_to_root;
+use toml_edit::Array;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+use toml_edit::Table;
+use toml_edit::Value;
+
+use crate::discover_python_packages;
+use crate::normalize_python_package_name;
+use crate::parse_dependency_name;
+
+/// Trove classifier that `PyPI` uses to refuse uploads of a package.
+const PRIVATE_CLASSIFIER: &str = "Private :: Do Not Upload";
+
+/// Return the shared Python lint suite.
+#[must_use]
+pub fn lint_suite() -> PythonLintSuite {
+	PythonLintSuite
+}
+
+/// Python lint suite implementation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PythonLintSuite;
+
+#[derive(Debug, Clone)]
+struct PythonLintFile {
+	document: DocumentMut,
+	/// `requires-python` values declared across the workspace, keyed by
+	/// manifest path.
+	workspace_requires_python: Arc<BTreeMap<PathBuf, String>>,
+}
+
+impl LintSuite for PythonLintSuite {
+	fn suite_id(&self) -> &'static str {
+		"python"
+	}
+
+	fn rules(&self) -> Vec<Box<dyn LintRuleRunner>> {
+		vec![
+			Box::new(NoPathOrGitDependenciesRule::new()),
+			Box::new(RequiredPackageFieldsRule::new()),
+			Box::new(RequiresPythonConsistentRule::new()),
+			Box::new(SortedDependenciesRule::new()),
+		]
+	}
+
+	fn presets(&self) -> Vec<LintPreset> {
+		vec![
+			LintPreset::new(
+				"python/recommended",
+				"Python recommended",
+				"Balanced pyproject.toml linting for metadata and publishability",
+				LintMaturity::Stable,
+			)
+			.with_rules(BTreeMap::from([
+				(
+					"python/no-path-or-git-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"python/required-package-fields".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"python/requires-python-consistent".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Warning),
+				),
+				(
+					"python/sorted-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Warning),
+				),
+			])),
+			LintPreset::new(
+				"python/strict",
+				"Python strict",
+				"Opinionated pyproject.toml linting with style and workspace rules promoted to errors",
+				LintMaturity::Strict,
+			)
+			.with_rules(BTreeMap::from([
+				(
+					"python/no-path-or-git-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"python/required-package-fields".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"python/requires-python-consistent".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+				(
+					"python/sorted-dependencies".to_string(),
+					LintRuleConfig::Severity(LintSeverity::Error),
+				),
+			])),
+		]
+	}
+
+	fn collect_targets(
+		&self,
+		workspace_root: &Path,
+		configuration: &WorkspaceConfiguration,
+	) -> MonochangeResult<Vec<LintTarget>> {
+		let discovery = discover_python_packages(workspace_root)?;
+		let mut manifests = Vec::new();
+		for package in discovery.packages {
+			if !is_lintable_workspace_manifest(workspace_root, &package.manifest_path) {
+				continue;
+			}
+			let contents = fs::read_to_string(&package.manifest_path).map_err(|error| {
+				monochange_core::MonochangeError::IoSource {
+					path: package.manifest_path.clone(),
+					source: error,
+				}
+			})?;
+			let document = contents.parse::<DocumentMut>().map_err(|error| {
+				monochange_core::MonochangeError::Parse {
+					path: package.manifest_path.clone(),
+					source: Box::new(error),
+				}
+			})?;
+			manifests.push((package, contents, document));
+		}
+
+		let workspace_requires_python = Arc::new(
+			manifests
+				.iter()
+				.filter_map(|(package, _, document)| {
+					requires_python_value(document)
+						.map(|value| (package.manifest_path.clone(), value.to_string()))
+				})
+				.collect::<BTreeMap<_, _>>(),
+		);
+
+		Ok(manifests
+			.into_iter()
+			.map(|(package, contents, document)| {
+				let manifest_dir = package.manifest_path.parent().unwrap_or(workspace_root);
+				let configured_package =
+					configured_package(configuration, workspace_root, manifest_dir);
+				let package_id = configured_package.map(|package| package.id.clone());
+				let group_id = configured_package.and_then(|package| {
+					configuration
+						.group_for_package(&package.id)
+						.map(|group| group.id.clone())
+				});
+				let relative_path = relative_to_root(workspace_root, &package.manifest_path)
+					.unwrap_or_else(|| package.manifest_path.clone());
+				let private = matches!(package.publish_state, PublishState::Private)
+					|| declares_private_classifier(&document);
+				let publish_enabled =
+					configured_package.is_none_or(|package| package.publish.enabled);
+
+				LintTarget::new(
+					workspace_root.to_path_buf(),
+					package.manifest_path.clone(),
+					contents,
+					LintTargetMetadata {
+						ecosystem: "python".to_string(),
+						relative_path,
+						package_name: Some(package.name),
+						package_id,
+						group_id,
+						managed: configured_package.is_some(),
+						private: Some(private),
+						publishable: Some(!private && publish_enabled),
+					},
+					Box::new(PythonLintFile {
+						document,
+						workspace_requires_python: Arc::clone(&workspace_requires_python),
+					}),
+				)
+			})
+			.collect())
+	}
+}
+
+fn is_lintable_workspace_manifest(workspace_root: &Path, manifest_path: &Path) -> bool {
+	!(manifest_path.starts_with(workspace_root.join("fixtures"))
+		|| manifest_path.starts_with(workspace_root.join("target"))
+		|| manifest_path.starts_with(workspace_root.join(".git")))
+}
+
+fn configured_package<'a>(
+	configuration: &'a WorkspaceConfiguration,
+	workspace_root: &Path,
+	manifest_dir: &Path,
+) -> Option<&'a PackageDefinition> {
+	let relative_dir = relative_to_root(workspace_root, manifest_dir)?;
+	configuration
+		.packages
+		.iter()
+		.find(|package| package.path == relative_dir)
+}
+
+fn python_file<'a>(ctx: &'a LintContext<'a>) -> Option<&'a PythonLintFile> {
+	ctx.parsed_as::<PythonLintFile>()
+}
+
+fn location(ctx: &LintContext<'_>) -> LintLocation {
+	LintLocation::new(ctx.manifest_path, 1, 1)
+}
+
+fn project_table(document: &DocumentMut) -> Option<&Table> {
+	document.get("project").and_then(Item::as_table)
+}
+
+fn poetry_table(document: &DocumentMut) -> Option<&Table> {
+	document
+		.get("tool")
+		.and_then(Item::as_table)
+		.and_then(|tool| tool.get("poetry"))
+		.and_then(Item::as_table)
+}
+
+fn poetry_dependencies(document: &DocumentMut) -> Option<&Table> {
+	poetry_table(document)
+		.and_then(|poetry| poetry.get("dependencies"))
+		.and_then(Item::as_table)
+}
+
+/// The table that carries package metadata: PEP 621 `[project]`, falling back
+/// to `[tool.poetry]` the same way discovery does.
+fn metadata_table(document: &DocumentMut) -> Option<&Table> {
+	project_table(document).or_else(|| poetry_table(document))
+}
+
+fn declares_private_classifier(document: &DocumentMut) -> bool {
+	metadata_table(document)
+		.and_then(|table| table.get("classifiers"))
+		.and_then(Item::as_array)
+		.is_some_and(|classifiers| {
+			classifiers
+				.iter()
+				.any(|classifier| classifier.as_str() == Some(PRIVATE_CLASSIFIER))
+		})
+}
+
+fn requires_python_value(document: &DocumentMut) -> Option<&str> {
+	if let Some(project) = project_table(document) {
+		return project.get("requires-python").and_then(Item::as_str);
+	}
+	poetry_dependencies(document)
+		.and_then(|dependencies| dependencies.get("python"))
+		.and_then(Item::as_str)
+}
+
+fn requirement_sort_key(requirement: &str) -> String {
+	parse_dependency_name(requirement.trim()).map_or_else(
+		|| requirement.trim().to_string(),
+		|name| normalize_python_package_name(&name),
+	)
+}
+
+/// Named PEP 508 requirement arrays: `project.dependencies`,
+/// `project.optional-dependencies.*`, and PEP 735 `dependency-groups.*`.
+fn requirement_arrays(document: &DocumentMut) -> Vec<(String, &Array)> {
+	let mut arrays = Vec::new();
+	if let Some(project) = project_table(document) {
+		if let Some(dependencies) = project.get("dependencies").and_then(Item::as_array) {
+			arrays.push(("project.dependencies".to_string(), dependencies));
+		}
+		if let Some(optional) = project
+			.get("optional-dependencies")
+			.and_then(Item::as_table_like)
+		{
+			for (group, value) in optional.iter() {
+				if let Some(dependencies) = value.as_array() {
+					arrays.push((
+						format!("project.optional-dependencies.{group}"),
+						dependencies,
+					));
+				}
+			}
+		}
+	}
+	if let Some(groups) = document
+		.get("dependency-groups")
+		.and_then(Item::as_table_like)
+	{
+		for (group, value) in groups.iter() {
+			if let Some(dependencies) = value.as_array() {
+				arrays.push((format!("dependency-groups.{group}"), dependencies));
+			}
+		}
+	}
+	arrays
+}
+
+/// Named Poetry dependency tables: `tool.poetry.dependencies` and
+/// `tool.poetry.group.*.dependencies`.
+fn poetry_dependency_tables(document: &DocumentMut) -> Vec<(String, &Table)> {
+	let mut tables = Vec::new();
+	let Some(poetry) = poetry_table(document) else {
+		return tables;
+	};
+	if let Some(dependencies) = poetry.get("dependencies").and_then(Item::as_table) {
+		tables.push(("tool.poetry.dependencies".to_string(), dependencies));
+	}
+	if let Some(groups) = poetry.get("group").and_then(Item::as_table) {
+		for (group, value) in groups {
+			if let Some(dependencies) = value
+				.as_table()
+				.and_then(|group| group.get("dependencies"))
+				.and_then(Item::as_table)
+			{
+				tables.push((
+					format!("tool.poetry.group.{group}.dependencies"),
+					dependencies,
+				));
+			}
+		}
+	}
+	tables
+}
+
+/// String entries of a requirement array, or `None` when the array holds
+/// anything else (such as PEP 735 `include-group` tables).
+fn requirement_strings(array: &Array) -> Option<Vec<&str>> {
+	array.iter().map(Value::as_str).collect()
+}
+
+fn requirements_sorted(requirements: &[&str]) -> bool {
+	requirements.windows(2).all(|pair| {
+		match pair {
+			[left, right] => requirement_sort_key(left) <= requirement_sort_key(right),
+			_ => true,
+		}
+	})
+}
+
+/// Poetry keeps the `python` constraint first, so it sorts ahead of every
+/// dependency name.
+fn poetry_key_sort_key(key: &str) -> (bool, String) {
+	(key != "python", normalize_python_package_name(key))
+}
+
+fn poetry_table_sorted(table: &Table) -> bool {
+	let keys = table.iter().map(|(key, _)| key).collect::<Vec<_>>();
+	keys.windows(2).all(|pair| {
+		match pair {
+			[left, right] => poetry_key_sort_key(left) <= poetry_key_sort_key(right),
+			_ => true,
+		}
+	})
+}
+
+/// Sort a requirement array in place while each position keeps its original
+/// whitespace, so single-line and multi-line layouts both survive.
+fn sort_requirement_array(array: &mut Array) {
+	let decors = array
+		.iter()
+		.map(|value| value.decor().clone())
+		.collect::<Vec<_>>();
+	let mut values = array.iter().cloned().collect::<Vec<_>>();
+	values.sort_by_key(|value| requirement_sort_key(value.as_str().unwrap_or_default()));
+	array.clear();
+	for (mut value, decor) in values.into_iter().zip(decors) {
+		*value.decor_mut() = decor;
+		array.push_formatted(value);
+	}
+}
+
+fn sort_dependencies(document: &mut DocumentMut) {
+	if let Some(project) = document.get_mut("project").and_then(Item::as_table_mut) {
+		if let Some(dependencies) = project
+			.get_mut("dependencies")
+			.and_then(Item::as_array_mut)
+			.filter(|array| requirement_strings(array).is_some())
+		{
+			sort_requirement_array(dependencies);
+		}
+		if let Some(optional) = project
+			.get_mut("optional-dependencies")
+			.and_then(Item::as_table_like_mut)
+		{
+			for (_, value) in optional.iter_mut() {
+				if let Some(dependencies) = value
+					.as_array_mut()
+					.filter(|array| requirement_strings(array).is_some())
+				{
+					sort_requirement_array(dependencies);
+				}
+			}
+		}
+	}
+	if let Some(groups) = document
+		.get_mut("dependency-groups")
+		.and_then(Item::as_table_like_mut)
+	{
+		for (_, value) in groups.iter_mut() {
+			if let Some(dependencies) = value
+				.as_array_mut()
+				.filter(|array| requirement_strings(array).is_some())
+			{
+				sort_requirement_array(dependencies);
+			}
+		}
+	}
+	let Some(poetry) = document
+		.get_mut("tool")
+		.and_then(Item::as_table_mut)
+		.and_then(|tool| tool.get_mut("poetry"))
+		.and_then(Item::as_table_mut)
+	else {
+		return;
+	};
+	let sort_poetry_table = |table: &mut Table| {
+		table.sort_values_by(|left, _, right, _| {
+			poetry_key_sort_key(left.get()).cmp(&poetry_key_sort_key(right.get()))
+		});
+	};
+	if let Some(dependencies) = poetry.get_mut("dependencies").and_then(Item::as_table_mut) {
+		sort_poetry_table(dependencies);
+	}
+	if let Some(groups) = poetry.get_mut("group").and_then(Item::as_table_mut) {
+		for (_, group) in groups.iter_mut() {
+			if let Some(dependencies) = group
+				.as_table_mut()
+				.and_then(|group| group.get_mut("dependencies"))
+				.and_then(Item::as_table_mut)
+			{
+				sort_poetry_table(dependencies);
+			}
+		}
+	}
+}
+
+/// Return the URL of a PEP 508 direct reference such as
+/// `shared @ file:///../shared`.
+fn direct_reference_url(requirement: &str) -> Option<&str> {
+	let (_, url) = requirement.split_once('@')?;
+	let url = url.split(';').next().unwrap_or_default().trim();
+	(!url.is_empty()).then_some(url)
+}
+
+fn is_path_or_git_url(url: &str) -> bool {
+	url.starts_with("file:") || url.starts_with("git+")
+}
+
+/// The `requires-python` value this workspace expects: the configured
+/// `expected` option, or the value most manifests already declare.
+fn expected_requires_python(
+	config: &LintRuleConfig,
+	workspace_values: &BTreeMap<PathBuf, String>,
+) -> Option<String> {
+	if let Some(expected) = config.string_option("expected") {
+		return Some(expected);
+	}
+	most_common_value(workspace_values.values())
+}
+
+/// Pick the most frequent value, breaking ties by sort order so the choice is
+/// stable across runs.
+fn most_common_value<'a>(values: impl Iterator<Item = &'a String>) -> Option<String> {
+	let mut counts = BTreeMap::<&str, usize>::new();
+	for value in values {
+		*counts.entry(value.as_str()).or_default() += 1;
+	}
+	counts
+		.into_iter()
+		.fold(None::<(&str, usize)>, |best, (value, count)| {
+			match best {
+				Some((_, best_count)) if best_count >= count => best,
+				_ => Some((value, count)),
+			}
+		})
+		.map(|(value, _)| value.to_string())
+}
+
+fn set_requires_python(document: &mut DocumentMut, expected: &str) {
+	let table = if document.get("project").is_some_and(Item::is_table) {
+		document
+			.get_mut("project")
+			.and_then(Item::as_table_mut)
+			.map(|project| (project, "requires-python"))
+	} else {
+		document
+			.get_mut("tool")
+			.and_then(Item::as_table_mut)
+			.and_then(|tool| tool.get_mut("poetry"))
+			.and_then(Item::as_table_mut)
+			.and_then(|poetry| poetry.get_mut("dependencies"))
+			.and_then(Item::as_table_mut)
+			.map(|dependencies| (dependencies, "python"))
+	};
+	let Some((table, key)) = table else {
+		return;
+	};
+	if let Some(value) = table.get_mut(key).and_then(Item::as_value_mut) {
+		let decor = value.decor().clone();
+		*value = Value::from(expected);
+		*value.decor_mut() = decor;
+	} else {
+		table.insert(key, toml_edit::value(expected));
+	}
+}
+
+#[derive(Debug)]
+struct NoPathOrGitDependenciesRule {
+	rule: LintRule,
+}
+
+impl NoPathOrGitDependenciesRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"python/no-path-or-git-dependencies",
+				"No path or git dependencies",
+				"Prevents published Python packages from declaring runtime dependencies on local paths or git repositories",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				false,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"allow",
+				"list of dependency names that may use path or git sources",
+				LintOptionKind::StringList,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for NoPathOrGitDependenciesRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		if ctx.metadata.publishable != Some(true) {
+			return Vec::new();
+		}
+		let Some(file) = python_file(ctx) else {
+			return Vec::new();
+		};
+		let allowed = config
+			.string_list_option("allow")
+			.unwrap_or_default()
+			.iter()
+			.map(|name| normalize_python_package_name(name))
+			.collect::<BTreeSet<_>>();
+		let mut findings = Vec::new();
+
+		// Only metadata that ends up in the published distribution matters;
+		// dependency groups and Poetry groups stay local.
+		for (section, array) in requirement_arrays(&file.document) {
+			if section.starts_with("dependency-groups.") {
+				continue;
+			}
+			for requirement in array.iter().filter_map(Value::as_str) {
+				let Some(name) = parse_dependency_name(requirement.trim()) else {
+					continue;
+				};
+				if let Some(url) = direct_reference_url(requirement)
+					&& is_path_or_git_url(url)
+				{
+					findings.push((section.clone(), name, url.to_string()));
+				}
+			}
+		}
+		if let Some(dependencies) = poetry_dependencies(&file.document) {
+			for (name, item) in dependencies {
+				let Some(source) = item.as_table_like() else {
+					continue;
+				};
+				for key in ["path", "git"] {
+					if let Some(location) = source.get(key).and_then(Item::as_str) {
+						findings.push((
+							"tool.poetry.dependencies".to_string(),
+							name.to_string(),
+							format!("{key} = \"{location}\""),
+						));
+					}
+				}
+			}
+		}
+
+		findings
+			.into_iter()
+			.filter(|(_, name, _)| !allowed.contains(&normalize_python_package_name(name)))
+			.map(|(section, name, source)| {
+				LintResult::new(
+					self.rule.id.clone(),
+					location(ctx),
+					format!(
+						"published Python packages must not depend on `{name}` through `{source}` in `{section}`"
+					),
+					config.severity(),
+				)
+			})
+			.collect()
+	}
+}
+
+#[derive(Debug)]
+struct RequiredPackageFieldsRule {
+	rule: LintRule,
+}
+
+impl RequiredPackageFieldsRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"python/required-package-fields",
+				"Required package fields",
+				"Requires selected pyproject.toml metadata fields for managed publishable Python packages",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				false,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"fields",
+				"list of `[project]` (or `[tool.poetry]`) fields that must be present",
+				LintOptionKind::StringList,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for RequiredPackageFieldsRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		if !ctx.metadata.managed || ctx.metadata.publishable != Some(true) {
+			return Vec::new();
+		}
+		let Some(file) = python_file(ctx) else {
+			return Vec::new();
+		};
+		let Some(table) = metadata_table(&file.document) else {
+			return Vec::new();
+		};
+		// PEP 621 lets build backends fill `dynamic` fields at build time.
+		let dynamic = table
+			.get("dynamic")
+			.and_then(Item::as_array)
+			.map(|fields| fields.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+			.unwrap_or_default();
+
+		config
+			.string_list_option("fields")
+			.unwrap_or_else(|| {
+				vec![
+					"description".to_string(),
+					"license".to_string(),
+					"readme".to_string(),
+				]
+			})
+			.into_iter()
+			.filter(|field| !table.contains_key(field) && !dynamic.contains(&field.as_str()))
+			.map(|field| {
+				LintResult::new(
+					self.rule.id.clone(),
+					location(ctx),
+					format!("missing required pyproject.toml field `{field}`"),
+					config.severity(),
+				)
+			})
+			.collect()
+	}
+}
+
+#[derive(Debug)]
+struct RequiresPythonConsistentRule {
+	rule: LintRule,
+}
+
+impl RequiresPythonConsistentRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"python/requires-python-consistent",
+				"Consistent requires-python",
+				"Requires every Python package in the workspace to declare the same requires-python range",
+				LintCategory::Correctness,
+				LintMaturity::Stable,
+				true,
+			)
+			.with_options(vec![
+				LintOptionDefinition::new(
+					"expected",
+					"the requires-python range every package must declare; defaults to the range most packages already use",
+					LintOptionKind::String,
+				),
+				LintOptionDefinition::new(
+					"fix",
+					"apply an autofix that writes the expected requires-python range",
+					LintOptionKind::Boolean,
+				),
+			]),
+		}
+	}
+}
+
+impl LintRuleRunner for RequiresPythonConsistentRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		let Some(file) = python_file(ctx) else {
+			return Vec::new();
+		};
+		let Some(expected) = expected_requires_python(config, &file.workspace_requires_python)
+		else {
+			return Vec::new();
+		};
+		let message = match requires_python_value(&file.document) {
+			Some(actual) if actual == expected => return Vec::new(),
+			Some(actual) => {
+				format!("requires-python `{actual}` differs from the workspace range `{expected}`")
+			}
+			None => format!("requires-python is missing; the workspace uses `{expected}`"),
+		};
+
+		let mut result = LintResult::new(
+			self.rule.id.clone(),
+			location(ctx),
+			message,
+			config.severity(),
+		);
+		if config.bool_option("fix", true) {
+			let mut rewritten = file.document.clone();
+			set_requires_python(&mut rewritten, &expected);
+			result = result.with_fix(LintFix::single(
+				format!("set requires-python to `{expected}`"),
+				(0, ctx.contents.len()),
+				rewritten.to_string(),
+			));
+		}
+		vec![result]
+	}
+}
+
+#[derive(Debug)]
+struct SortedDependenciesRule {
+	rule: LintRule,
+}
+
+impl SortedDependenciesRule {
+	fn new() -> Self {
+		Self {
+			rule: LintRule::new(
+				"python/sorted-dependencies",
+				"Sorted dependencies",
+				"Requires pyproject.toml dependency lists and Poetry dependency tables to be sorted by package name",
+				LintCategory::Style,
+				LintMaturity::Stable,
+				true,
+			)
+			.with_options(vec![LintOptionDefinition::new(
+				"fix",
+				"apply an autofix that sorts every dependency list in place",
+				LintOptionKind::Boolean,
+			)]),
+		}
+	}
+}
+
+impl LintRuleRunner for SortedDependenciesRule {
+	fn rule(&self) -> &LintRule {
+		&self.rule
+	}
+
+	fn run(&self, ctx: &LintContext<'_>, config: &LintRuleConfig) -> Vec<LintResult> {
+		let Some(file) = python_file(ctx) else {
+			return Vec::new();
+		};
+		let mut unsorted = requirement_arrays(&file.document)
+			.into_iter()
+			.filter(|(_, array)| {
+				requirement_strings(array)
+					.is_some_and(|requirements| !requirements_sorted(&requirements))
+			})
+			.map(|(section, _)| section)
+			.collect::<Vec<_>>();
+		unsorted.extend(
+			poetry_dependency_tables(&file.document)
+				.into_iter()
+				.filter(|(_, table)| !poetry_table_sorted(table))
+				.map(|(section, _)| section),
+		);
+		if unsorted.is_empty() {
+			return Vec::new();
+		}
+
+		let fix = config.bool_option("fix", true).then(|| {
+			let mut rewritten = file.document.clone();
+			sort_dependencies(&mut rewritten);
+			LintFix::single(
+				"sort dependency lists by package name",
+				(0, ctx.contents.len()),
+				rewritten.to_string(),
+			)
+		});
+
+		unsorted
+			.into_iter()
+			.map(|section| {
+				let mut result = LintResult::new(
+					self.rule.id.clone(),
+					location(ctx),
+					format!("dependencies in `{section}` are not sorted by package name"),
+					config.severity(),
+				);
+				if let Some(fix) = fix.clone() {
+					result = result.with_fix(fix);
+				}
+				result
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+#[path = "__tests__/mod_tests.rs"]
+mod tests;
@@ -0,0 +1,324 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use monochange_core::DependencyKind;
+use monochange_core::Ecosystem;
+use monochange_core::EcosystemAdapter;
+use monochange_core::PackageRecord;
+use monochange_core::PublishState;
+use monochange_core::ShellConfig;
+use semver::Version;
+use tempfile::tempdir;
+
+use crate::RubyAdapter;
+use crate::RubyVersionedFileKind;
+use crate::adapter;
+use crate::discover_lockfiles;
+use crate::discover_ruby_gems;
+use crate::gem_manifest_updates;
+use crate::load_configured_ruby_gem;
+use crate::lockfile_refresh_commands;
+use crate::path_gems_in_lockfile;
+use crate::requirement_for_version;
+use crate::supported_versioned_file_kind;
+use crate::update_gemspec_text;
+use crate::update_version_constant_text;
+use crate::validate_versioned_file;
+
+fn fixture_path(relative: &str) -> PathBuf {
+	monochange_test_helpers::fs::fixture_path_from(env!("CARGO_MANIFEST_DIR"), relative)
+}
+
+fn workspace_gems() -> Vec<PackageRecord> {
+	discover_ruby_gems(&fixture_path("ruby/workspace"))
+		.unwrap_or_else(|error| panic!("ruby discovery: {error}"))
+		.packages
+}
+
+fn gem<'a>(gems: &'a [PackageRecord], name: &str) -> &'a PackageRecord {
+	gems.iter()
+		.find(|gem| gem.name == name)
+		.unwrap_or_else(|| panic!("missing gem {name}"))
+}
+
+fn released(gems: &[PackageRecord], versions: &[(&str, &str)]) -> BTreeMap<String, String> {
+	versions
+		.iter()
+		.map(|(name, version)| (gem(gems, name).id.clone(), (*version).to_string()))
+		.collect()
+}
+
+#[test]
+fn adapter_reports_ruby_ecosystem() {
+	assert_eq!(RubyAdapter.ecosystem(), Ecosystem::Ruby);
+	assert_eq!(adapter().ecosystem(), Ecosystem::Ruby);
+}
+
+#[test]
+fn discover_ruby_gems_reads_version_constants_and_links_dependencies() {
+	let gems = workspace_gems();
+	assert_eq!(gems.len(), 2);
+
+	let core = gem(&gems, "core");
+	assert_eq!(core.current_version, Some(Version::new(1, 2, 0)));
+	assert_eq!(core.publish_state, PublishState::Public);
+	assert_eq!(
+		core.metadata.get("version_file").map(String::as_str),
+		Some("lib/core/version.rb")
+	);
+
+	let billing = gem(&gems, "billing");
+	assert_eq!(billing.current_version, Some(Version::new(0, 4, 1)));
+	assert!(!billing.metadata.contains_key("version_file"));
+	let dependencies = billing
+		.declared_dependencies
+		.iter()
+		.map(|dependency| {
+			(
+				dependency.name.as_str(),
+				dependency.kind,
+				dependency.version_constraint.as_deref(),
+				dependency.source_field.as_deref(),
+			)
+		})
+		.collect::<Vec<_>>();
+	assert_eq!(
+		dependencies,
+		vec![
+			(
+				"core",
+				DependencyKind::Runtime,
+				Some("~> 1.2, < 2.0"),
+				Some("add_dependency"),
+			),
+			(
+				"rails",
+				DependencyKind::Runtime,
+				Some(">= 7.1"),
+				Some("add_dependency"),
+			),
+			(
+				"rspec-rails",
+				DependencyKind::Development,
+				Some("~> 6.1"),
+				Some("add_development_dependency"),
+			),
+			(
+				"audit_log",
+				DependencyKind::Development,
+				None,
+				Some("Gemfile"),
+			),
+		]
+	);
+}
+
+#[test]
+fn discover_ruby_gems_skips_vendored_gems_and_pads_short_versions() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let root = tempdir.path();
+	for (relative, contents) in [
+		(
+			"gems/tooling/tooling.gemspec",
+			"Gem::Specification.new do |s|\n  s.name = 'tooling'\n  s.version = '2.1'\nend\n",
+		),
+		(
+			"vendor/bundle/ruby/3.3.0/specifications/rack.gemspec",
+			"Gem::Specification.new do |s|\n  s.name = \"rack\"\n  s.version = \"3.0.0\"\nend\n",
+		),
+		(
+			"gems/unversioned/unversioned.gemspec",
+			"Gem::Specification.new do |s|\n  s.name = \"unversioned\"\nend\n",
+		),
+	] {
+		let path = root.join(relative);
+		fs::create_dir_all(path.parent().unwrap_or(root))
+			.unwrap_or_else(|error| panic!("mkdir {relative}: {error}"));
+		fs::write(&path, contents).unwrap_or_else(|error| panic!("write {relative}: {error}"));
+	}
+
+	let discovery = discover_ruby_gems(root).unwrap_or_else(|error| panic!("discovery: {error}"));
+
+	let gems = discovery
+		.packages
+		.iter()
+		.map(|package| (package.name.as_str(), package.current_version.clone()))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		gems,
+		vec![
+			("tooling", Some(Version::new(2, 1, 0))),
+			("unversioned", None),
+		]
+	);
+	assert!(discovery.warnings.is_empty());
+}
+
+#[test]
+fn load_configured_ruby_gem_accepts_directories_and_gemspec_paths() {
+	let root = fixture_path("ruby/workspace");
+	let from_dir = load_configured_ruby_gem(&root, &root.join("engines/core"))
+		.unwrap_or_else(|error| panic!("load dir: {error}"))
+		.expect("gem");
+	let from_manifest = load_configured_ruby_gem(&root, &root.join("engines/core/core.gemspec"))
+		.unwrap_or_else(|error| panic!("load manifest: {error}"))
+		.expect("gem");
+	assert_eq!(from_dir.id, from_manifest.id);
+	assert!(
+		load_configured_ruby_gem(&root, &root.join("engines"))
+			.unwrap_or_else(|error| panic!("load empty dir: {error}"))
+			.is_none()
+	);
+}
+
+#[test]
+fn supported_versioned_file_kind_accepts_gemspecs_and_ruby_sources() {
+	assert_eq!(
+		supported_versioned_file_kind(Path::new("engines/core/core.gemspec")),
+		Some(RubyVersionedFileKind::Gemspec)
+	);
+	assert_eq!(
+		supported_versioned_file_kind(Path::new("engines/core/lib/core/version.rb")),
+		Some(RubyVersionedFileKind::VersionFile)
+	);
+	assert_eq!(
+		supported_versioned_file_kind(Path::new("engines/core/Gemfile.lock")),
+		None
+	);
+}
+
+#[test]
+fn update_version_constant_text_preserves_quotes_and_freeze() {
+	let contents = "module Core\n  VERSION = '1.2.0'.freeze\n  RAILS_VERSION = \"7.1\"\nend\n";
+	assert_eq!(
+		update_version_constant_text(contents, "VERSION", "1.3.0"),
+		"module Core\n  VERSION = '1.3.0'.freeze\n  RAILS_VERSION = \"7.1\"\nend\n"
+	);
+	assert_eq!(
+		update_version_constant_text(contents, "MISSING", "1.3.0"),
+		contents
+	);
+}
+
+#[test]
+fn requirement_for_version_keeps_operators_and_precision() {
+	let cases = [
+		("~> 1.2", "1.3.4", Some("~> 1.3")),
+		("~> 1.2.0", "2.0.0", Some("~> 2.0.0")),
+		("~>1.2", "1.3.0", Some("~>1.3")),
+		(">= 1.0", "1.5.0", Some(">= 1.5.0")),
+		("= 1.0.0", "1.5.0", Some("= 1.5.0")),
+		("1.0.0", "1.5.0", Some("1.5.0")),
+		("< 2.0", "1.5.0", Some("< 2.0")),
+		("< 2.0", "2.0.0", None),
+		("<= 2.0", "2.0.0", Some("<= 2.0")),
+		("!= 1.1.0", "1.5.0", Some("!= 1.1.0")),
+	];
+	for (requirement, version, expected) in cases {
+		assert_eq!(
+			requirement_for_version(requirement, version).as_deref(),
+			expected,
+			"{requirement} -> {version}"
+		);
+	}
+}
+
+#[test]
+fn update_gemspec_text_rewrites_literal_versions_and_internal_requirements() {
+	let contents = "Gem::Specification.new do |spec|\n  spec.name = \"billing\"\n  spec.version = \"0.4.1\"\n  spec.add_dependency \"core\", \"~> 1.2\", \"< 2.0\"\n  spec.add_runtime_dependency('audit_log', '>= 0.3')\n  spec.add_dependency \"rails\", \">= 7.1\"\n  spec.add_dependency \"ledger\", Ledger::VERSION\nend\n";
+	let dependency_versions = BTreeMap::from([
+		("core".to_string(), "2.0.0".to_string()),
+		("audit_log".to_string(), "0.4.0".to_string()),
+		("ledger".to_string(), "3.0.0".to_string()),
+	]);
+
+	let rendered = update_gemspec_text(contents, Some("0.5.0"), &dependency_versions);
+
+	assert_eq!(
+		rendered,
+		"Gem::Specification.new do |spec|\n  spec.name = \"billing\"\n  spec.version = \"0.5.0\"\n  spec.add_dependency \"core\", \"~> 2.0\"\n  spec.add_runtime_dependency('audit_log', '>= 0.4.0')\n  spec.add_dependency \"rails\", \">= 7.1\"\n  spec.add_dependency \"ledger\", Ledger::VERSION\nend\n"
+	);
+	let constant_version = "spec.version = Core::VERSION\n";
+	assert_eq!(
+		update_gemspec_text(constant_version, Some("1.3.0"), &BTreeMap::new()),
+		constant_version
+	);
+}
+
+#[test]
+fn gem_manifest_updates_write_version_files_and_dependent_gemspecs() {
+	let gems = workspace_gems();
+	let updates = gem_manifest_updates(&gems, &released(&gems, &[("core", "1.3.0")]))
+		.unwrap_or_else(|error| panic!("gem updates: {error}"));
+
+	assert_eq!(updates.len(), 2);
+	let version_file = updates
+		.iter()
+		.find(|(path, _)| path.ends_with("engines/core/lib/core/version.rb"))
+		.expect("core version file update");
+	assert!(version_file.1.contains("VERSION = \"1.3.0\".freeze"));
+	let billing = updates
+		.iter()
+		.find(|(path, _)| path.ends_with("engines/billing/billing.gemspec"))
+		.expect("billing gemspec update");
+	assert!(billing.1.contains("spec.version = \"0.4.1\""));
+	assert!(
+		billing
+			.1
+			.contains("add_dependency \"core\", \"~> 1.3\", \"< 2.0\"")
+	);
+
+	let own_release = gem_manifest_updates(&gems, &released(&gems, &[("billing", "0.5.0")]))
+		.unwrap_or_else(|error| panic!("gem updates: {error}"));
+	assert_eq!(own_release.len(), 1);
+	assert!(
+		own_release
+			.first()
+			.expect("billing update")
+			.1
+			.contains("spec.version = \"0.5.0\"")
+	);
+}
+
+#[test]
+fn lockfile_refresh_commands_run_bundle_lock_for_path_gems() {
+	let gems = workspace_gems();
+	let billing = gem(&gems, "billing");
+	assert_eq!(discover_lockfiles(billing).len(), 1);
+	assert!(discover_lockfiles(gem(&gems, "core")).is_empty());
+	let lockfile = fs::read_to_string(fixture_path("ruby/workspace/engines/billing/Gemfile.lock"))
+		.unwrap_or_else(|error| panic!("read lockfile: {error}"));
+	assert_eq!(
+		path_gems_in_lockfile(&lockfile)
+			.into_iter()
+			.collect::<Vec<_>>(),
+		vec!["billing".to_string(), "core".to_string()]
+	);
+
+	let commands = lockfile_refresh_commands(&gems, &released(&gems, &[("core", "1.3.0")]));
+	assert_eq!(commands.len(), 1);
+	let command = commands.first().expect("command");
+	assert_eq!(command.command, "bundle lock");
+	assert!(command.cwd.ends_with("engines/billing"));
+	assert_eq!(command.shell, ShellConfig::None);
+
+	assert!(lockfile_refresh_commands(&gems, &BTreeMap::new()).is_empty());
+}
+
+#[test]
+fn validate_versioned_file_checks_version_constants() {
+	let tempdir = tempdir().unwrap_or_else(|error| panic!("tempdir: {error}"));
+	let path = tempdir.path().join("version.rb");
+	fs::write(&path, "module Core\n  VERSION = \"1.2.0\"\nend\n")
+		.unwrap_or_else(|error| panic!("write: {error}"));
+
+	assert!(validate_versioned_file(&path, "version.rb", None).is_ok());
+	let error = validate_versioned_file(&path, "version.rb", Some(&["API_VERSION".to_string()]))
+		.expect_err("missing API_VERSION");
+	assert!(error.to_string().contains("`API_VERSION`"));
+	assert!(
+		validate_versioned_file(&tempdir.path().join("missing.rb"), "missing.rb", None).is_err()
+	);
+}
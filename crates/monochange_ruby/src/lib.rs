@@ -0,0 +1,886 @@
+#![forbid(clippy::indexing_slicing)]
+
+//! # `monochange_ruby`
+//!
+//! <!-- {=monochangeRubyCrateDocs|trim|linePrefix:"//! ":true} -->
+//! `monochange_ruby` discovers Ruby gems for the shared planner.
+//!
+//! Reach for this crate when you need to scan `*.gemspec` files, read gem versions from `lib/**/version.rb` constants, link gems through `add_dependency` and Gemfile `path:` entries, and publish released gems with `gem build` and `gem push`.
+//!
+//! ## Why use it?
+//!
+//! - release Rails engines and other internal gems through the same changesets as the rest of the monorepo
+//! - follow the common `VERSION` constant layout instead of requiring literal versions in gemspecs
+//! - rewrite internal `add_dependency` requirements while keeping their operators and precision
+//! - refresh `Gemfile.lock` with `bundle lock` when a path gem it pins is released
+//!
+//! ## Best for
+//!
+//! - monorepos that keep several engines or gems side by side
+//! - gems whose Gemfiles point at sibling gems through `path:`
+//! - publishing gems to rubygems.org from release automation
+//!
+//! ## Public entry points
+//!
+//! - `discover_ruby_gems(root)` discovers `*.gemspec` gems under a repository root
+//! - `RubyAdapter` exposes the shared adapter interface
+//! - `update_version_constant_text(contents, constant, version)` rewrites a `VERSION = "..."` constant in place
+//! - `update_gemspec_text(contents, version, dependencies)` rewrites literal gemspec versions and internal dependency requirements
+//! - `gem_manifest_updates(packages, versions)` plans the version-file and gemspec rewrites a release needs
+//! - `lockfile_refresh_commands(packages, versions)` infers `bundle lock` for `Gemfile.lock` files that pin released path gems
+//!
+//! ## Scope
+//!
+//! - gemspec `name`, `version`, and `add_dependency` / `add_runtime_dependency` / `add_development_dependency` parsing
+//! - `VERSION` constants in `lib/**/version.rb`
+//! - Gemfile `gem "name", path: "..."` entries as development dependency edges
+//! - `Gemfile.lock` discovery and command-based refreshes
+//! - placeholder gemspecs for `gem build` publishing
+//! <!-- {/monochangeRubyCrateDocs} -->
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use monochange_core::AdapterDiscovery;
+use monochange_core::DependencyKind;
+use monochange_core::DiscoveryPathFilter;
+use monochange_core::Ecosystem;
+use monochange_core::EcosystemAdapter;
+use monochange_core::LockfileCommandExecution;
+use monochange_core::ManifestWalk;
+use monochange_core::MonochangeError;
+use monochange_core::MonochangeResult;
+use monochange_core::PackageDependency;
+use monochange_core::PackageRecord;
+use monochange_core::PublishState;
+use monochange_core::ShellConfig;
+use monochange_core::normalize_path;
+use monochange_publish::PublishRequest;
+use regex::Regex;
+use semver::Version;
+use walkdir::DirEntry;
+use walkdir::WalkDir;
+
+/// The Bundler manifest that sits next to a gem or application.
+pub const GEMFILE: &str = "Gemfile";
+/// The Bundler lockfile refreshed after releases.
+pub const GEMFILE_LOCK: &str = "Gemfile.lock";
+/// The constant that holds a gem's version in `lib/**/version.rb`.
+pub const VERSION_CONSTANT: &str = "VERSION";
+/// Package metadata key naming the version file, relative to the gem root.
+pub const VERSION_FILE_METADATA_KEY: &str = "version_file";
+
+pub struct RubyAdapter;
+
+/// Return the shared Ruby ecosystem adapter.
+#[must_use]
+pub const fn adapter() -> RubyAdapter {
+	RubyAdapter
+}
+
+impl EcosystemAdapter for RubyAdapter {
+	fn ecosystem(&self) -> Ecosystem {
+		Ecosystem::Ruby
+	}
+
+	fn discover(&self, root: &Path) -> MonochangeResult<AdapterDiscovery> {
+		discover_ruby_gems(root)
+	}
+
+	fn discover_with_filter(
+		&self,
+		root: &Path,
+		filter: &DiscoveryPathFilter,
+	) -> MonochangeResult<AdapterDiscovery> {
+		discover_ruby_gems_with_filter(root, filter)
+	}
+
+	fn load_configured(
+		&self,
+		root: &Path,
+		package_path: &Path,
+	) -> MonochangeResult<Option<PackageRecord>> {
+		load_configured_ruby_gem(root, package_path)
+	}
+
+	fn supported_versioned_file_kind(&self, path: &Path) -> bool {
+		supported_versioned_file_kind(path).is_some()
+	}
+
+	fn validate_versioned_file(
+		&self,
+		full_path: &Path,
+		display_path: &str,
+		custom_fields: Option<&[String]>,
+	) -> MonochangeResult<()> {
+		validate_versioned_file(full_path, display_path, custom_fields)
+	}
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RubyVersionedFileKind {
+	Gemspec,
+	VersionFile,
+}
+
+/// Classify a Ruby versioned file path.
+///
+/// `Gemfile.lock` is not a versioned file: Bundler rewrites it through
+/// `bundle lock` so resolved dependencies stay consistent.
+#[must_use]
+pub fn supported_versioned_file_kind(path: &Path) -> Option<RubyVersionedFileKind> {
+	match path.extension().and_then(|extension| extension.to_str()) {
+		Some("gemspec") => Some(RubyVersionedFileKind::Gemspec),
+		Some("rb") => Some(RubyVersionedFileKind::VersionFile),
+		_ => None,
+	}
+}
+
+/// Discover the `Gemfile.lock` that belongs to `package`.
+pub fn discover_lockfiles(package: &PackageRecord) -> Vec<PathBuf> {
+	[gem_root(package).join(GEMFILE_LOCK)]
+		.into_iter()
+		.filter(|path| path.exists())
+		.collect()
+}
+
+/// Return the default lockfile refresh commands for `package`.
+pub fn default_lockfile_commands(package: &PackageRecord) -> Vec<LockfileCommandExecution> {
+	if package.ecosystem != Ecosystem::Ruby {
+		return Vec::new();
+	}
+
+	discover_lockfiles(package)
+		.into_iter()
+		.map(|lockfile| bundle_lock_command(&lockfile, &package.workspace_root))
+		.collect()
+}
+
+/// Infer `bundle lock` for every `Gemfile.lock` that pins a released gem
+/// through a `PATH` source.
+///
+/// Lockfiles next to each gem and at the workspace root are considered, so
+/// an application Gemfile that mounts engines with `path:` is refreshed too.
+pub fn lockfile_refresh_commands(
+	packages: &[PackageRecord],
+	released_versions_by_record_id: &BTreeMap<String, String>,
+) -> Vec<LockfileCommandExecution> {
+	let gems = packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Ruby)
+		.collect::<Vec<_>>();
+	let released_gems = gems
+		.iter()
+		.filter(|package| released_versions_by_record_id.contains_key(&package.id))
+		.map(|package| package.name.as_str())
+		.collect::<BTreeSet<_>>();
+	if released_gems.is_empty() {
+		return Vec::new();
+	}
+
+	let lockfiles = gems
+		.iter()
+		.flat_map(|package| {
+			[
+				gem_root(package).join(GEMFILE_LOCK),
+				package.workspace_root.join(GEMFILE_LOCK),
+			]
+		})
+		.collect::<BTreeSet<_>>();
+
+	lockfiles
+		.into_iter()
+		.filter(|lockfile| {
+			fs::read_to_string(lockfile).is_ok_and(|contents| {
+				path_gems_in_lockfile(&contents)
+					.iter()
+					.any(|name| released_gems.contains(name.as_str()))
+			})
+		})
+		.filter_map(|lockfile| {
+			gems.first()
+				.map(|package| bundle_lock_command(&lockfile, &package.workspace_root))
+		})
+		.collect()
+}
+
+fn bundle_lock_command(lockfile: &Path, fallback_dir: &Path) -> LockfileCommandExecution {
+	LockfileCommandExecution {
+		command: "bundle lock".to_string(),
+		cwd: lockfile.parent().unwrap_or(fallback_dir).to_path_buf(),
+		shell: ShellConfig::None,
+	}
+}
+
+/// List the gems a `Gemfile.lock` resolves from `PATH` sources.
+#[must_use]
+pub fn path_gems_in_lockfile(contents: &str) -> BTreeSet<String> {
+	let mut names = BTreeSet::new();
+	let mut in_path_section = false;
+	for line in contents.lines() {
+		if !line.starts_with(' ') {
+			in_path_section = line.trim() == "PATH";
+			continue;
+		}
+		// Spec entries are indented four spaces; their own dependencies six.
+		let Some(entry) = line.strip_prefix("    ") else {
+			continue;
+		};
+		if !in_path_section || entry.starts_with(' ') {
+			continue;
+		}
+		if let Some((name, _)) = entry.split_once(" (") {
+			names.insert(name.to_string());
+		}
+	}
+	names
+}
+
+/// Write a minimal gemspec that `gem build` accepts for placeholder releases.
+pub fn write_ruby_placeholder_manifest(
+	dir: &Path,
+	request: &PublishRequest,
+) -> MonochangeResult<()> {
+	let rendered = format!(
+		"Gem::Specification.new do |spec|\n  spec.name = \"{name}\"\n  spec.version = \"{version}\"\n  spec.summary = \"Placeholder gem published by monochange.\"\n  spec.authors = [\"monochange\"]\n  spec.files = [\"README.md\"]\nend\n",
+		name = request.package_name,
+		version = request.version,
+	);
+	fs::write(
+		dir.join(format!("{}.gemspec", request.package_name)),
+		rendered,
+	)
+	.map_err(|error| MonochangeError::Io(format!("failed to write placeholder gemspec: {error}")))
+}
+
+/// Rewrite the string assigned to `constant`, keeping quotes and any
+/// trailing `.freeze`.
+///
+/// The text is returned unchanged when the constant is not assigned a string
+/// literal.
+#[must_use]
+pub fn update_version_constant_text(contents: &str, constant: &str, version: &str) -> String {
+	let Some(span) = version_constant_span(contents, constant) else {
+		return contents.to_string();
+	};
+	let mut rendered = contents.to_string();
+	rendered.replace_range(span.0..span.1, version);
+	rendered
+}
+
+/// Read the string assigned to `constant` in Ruby source.
+#[must_use]
+pub fn parse_version_constant<'a>(contents: &'a str, constant: &str) -> Option<&'a str> {
+	version_constant_span(contents, constant).and_then(|(start, end)| contents.get(start..end))
+}
+
+fn version_constant_span(contents: &str, constant: &str) -> Option<(usize, usize)> {
+	let pattern = format!(
+		r#"(?m)^[ \t]*{}[ \t]*=[ \t]*["']([^"'\n]*)["']"#,
+		regex::escape(constant)
+	);
+	let regex = Regex::new(&pattern).ok()?;
+	let value = regex.captures(contents)?.get(1)?;
+	Some((value.start(), value.end()))
+}
+
+#[must_use = "the gemspec update result must be checked"]
+/// Update gemspec text in place.
+///
+/// `version` replaces a literal `spec.version = "..."` assignment; versions
+/// read from a constant are left alone because the constant's file is
+/// rewritten instead. `dependency_versions` maps gem names to released
+/// versions, and matching `add_dependency` requirements are rewritten with
+/// `requirements_for_version`. Requirements built from Ruby expressions, such
+/// as `Core::VERSION`, already follow the release and are not touched.
+pub fn update_gemspec_text(
+	contents: &str,
+	version: Option<&str>,
+	dependency_versions: &BTreeMap<String, String>,
+) -> String {
+	let mut replacements = Vec::<((usize, usize), String)>::new();
+
+	if let Some(version) = version
+		&& let Some(literal) = gemspec_version_regex()
+			.captures(contents)
+			.and_then(|captures| captures.get(2))
+	{
+		replacements.push(((literal.start(), literal.end()), version.to_string()));
+	}
+
+	for captures in dependency_regex().captures_iter(contents) {
+		let (Some(name), Some(arguments)) = (captures.name("name"), captures.name("arguments"))
+		else {
+			continue;
+		};
+		let Some(released) = dependency_versions.get(name.as_str()) else {
+			continue;
+		};
+		let literals = requirement_literals(arguments.as_str(), arguments.start());
+		if literals.is_empty() {
+			continue;
+		}
+		replacements.extend(rewrite_requirement_literals(contents, &literals, released));
+	}
+
+	replacements.sort_by_key(|replacement| std::cmp::Reverse(replacement.0.0));
+
+	let mut rendered = contents.to_string();
+	for ((start, end), replacement) in replacements {
+		rendered.replace_range(start..end, &replacement);
+	}
+	rendered
+}
+
+/// A quoted requirement inside an `add_dependency` call.
+#[derive(Debug, Clone, Copy)]
+struct RequirementLiteral<'a> {
+	/// Span of the whole quoted literal, quotes included.
+	span: (usize, usize),
+	/// The unquoted requirement text.
+	value: &'a str,
+}
+
+/// Collect the leading string-literal arguments after the gem name. Anything
+/// else, such as a constant or an interpolated string, ends the list.
+fn requirement_literals(arguments: &str, offset: usize) -> Vec<RequirementLiteral<'_>> {
+	let mut literals = Vec::new();
+	let mut rest = arguments;
+	let mut position = offset;
+	loop {
+		let trimmed = rest.trim_start_matches([' ', '\t']);
+		position += rest.len() - trimmed.len();
+		let Some(after_comma) = trimmed.strip_prefix(',') else {
+			break;
+		};
+		let value_start = after_comma.trim_start_matches([' ', '\t']);
+		position += 1 + after_comma.len() - value_start.len();
+		let Some(quote) = value_start
+			.chars()
+			.next()
+			.filter(|quote| matches!(quote, '"' | '\''))
+		else {
+			break;
+		};
+		let Some(length) = value_start.get(1..).and_then(|body| body.find(quote)) else {
+			break;
+		};
+		let Some(value) = value_start.get(1..=length) else {
+			break;
+		};
+		if quote == '"' && value.contains("#{") {
+			break;
+		}
+		literals.push(RequirementLiteral {
+			span: (position, position + length + 2),
+			value,
+		});
+		position += length + 2;
+		rest = value_start.get(length + 2..).unwrap_or_default();
+	}
+	literals
+}
+
+fn rewrite_requirement_literals(
+	contents: &str,
+	literals: &[RequirementLiteral<'_>],
+	version: &str,
+) -> Vec<((usize, usize), String)> {
+	let mut replacements = Vec::new();
+	let mut kept = 0;
+	for (index, literal) in literals.iter().enumerate() {
+		let quote = contents
+			.get(literal.span.0..=literal.span.0)
+			.unwrap_or("\"");
+		match requirement_for_version(literal.value, version) {
+			Some(requirement) => {
+				kept += 1;
+				if requirement != literal.value {
+					replacements.push((literal.span, format!("{quote}{requirement}{quote}")));
+				}
+			}
+			// Drop the clause together with the separator that joins it to
+			// its neighbour.
+			None if kept > 0 || index + 1 < literals.len() => {
+				let span = match (
+					index.checked_sub(1).and_then(|i| literals.get(i)),
+					literals.get(index + 1),
+				) {
+					(Some(previous), _) => (previous.span.1, literal.span.1),
+					(None, Some(next)) => (literal.span.0, next.span.0),
+					(None, None) => literal.span,
+				};
+				replacements.push((span, String::new()));
+			}
+			None => {
+				replacements.push((literal.span, format!("{quote}>= {version}{quote}")));
+			}
+		}
+	}
+	replacements
+}
+
+/// Move one requirement clause to `version`.
+///
+/// Lower bounds and exact pins keep their operator, and `~>` keeps the number
+/// of segments it was written with, so `~> 1.2` becomes `~> 1.3` rather than
+/// the narrower `~> 1.3.0`. Upper bounds stay while `version` still satisfies
+/// them and are dropped (`None`) otherwise.
+#[must_use]
+pub fn requirement_for_version(requirement: &str, version: &str) -> Option<String> {
+	let trimmed = requirement.trim();
+	let split = trimmed
+		.find(|character: char| character.is_ascii_alphanumeric())
+		.unwrap_or(trimmed.len());
+	let (operator, current) = trimmed.split_at(split);
+	let spacing = if operator.ends_with(' ') { " " } else { "" };
+	let operator = operator.trim();
+	match operator {
+		"~>" => {
+			let precision = current.split('.').count().max(2);
+			let segments = version.split('.').take(precision).collect::<Vec<_>>();
+			Some(format!("~>{spacing}{}", segments.join(".")))
+		}
+		"" | "=" | ">=" => Some(format!("{operator}{spacing}{version}")),
+		"<" | "<=" => {
+			let allows = match (segments_of(version), segments_of(current)) {
+				(Some(version), Some(bound)) => {
+					let ordering = compare_segments(&version, &bound);
+					if operator == "<" {
+						ordering.is_lt()
+					} else {
+						ordering.is_le()
+					}
+				}
+				_ => true,
+			};
+			allows.then(|| requirement.to_string())
+		}
+		_ => Some(requirement.to_string()),
+	}
+}
+
+fn segments_of(version: &str) -> Option<Vec<u64>> {
+	version
+		.split(['.', '-'])
+		.map_while(|segment| segment.parse::<u64>().ok().map(Some))
+		.collect::<Option<Vec<_>>>()
+		.filter(|segments| !segments.is_empty())
+}
+
+/// Compare numeric segments with zero padding, the way `Gem::Version` does.
+fn compare_segments(left: &[u64], right: &[u64]) -> std::cmp::Ordering {
+	let length = left.len().max(right.len());
+	(0..length)
+		.map(|index| {
+			left.get(index)
+				.copied()
+				.unwrap_or(0)
+				.cmp(&right.get(index).copied().unwrap_or(0))
+		})
+		.find(|ordering| ordering.is_ne())
+		.unwrap_or(std::cmp::Ordering::Equal)
+}
+
+/// Plan the file rewrites for a release.
+///
+/// Released gems get their new version written to the `VERSION` constant
+/// they read it from, or to a literal gemspec version. Gems that depend on
+/// released gems through `add_dependency` get matching requirements.
+pub fn gem_manifest_updates(
+	packages: &[PackageRecord],
+	released_versions_by_record_id: &BTreeMap<String, String>,
+) -> MonochangeResult<Vec<(PathBuf, String)>> {
+	let gems = packages
+		.iter()
+		.filter(|package| package.ecosystem == Ecosystem::Ruby)
+		.collect::<Vec<_>>();
+	let released_gem_versions = gems
+		.iter()
+		.filter_map(|package| {
+			released_versions_by_record_id
+				.get(&package.id)
+				.map(|version| (package.name.clone(), version.clone()))
+		})
+		.collect::<BTreeMap<_, _>>();
+	let mut updates = Vec::new();
+
+	for gem in gems {
+		let version = released_versions_by_record_id.get(&gem.id);
+		let version_file = gem
+			.metadata
+			.get(VERSION_FILE_METADATA_KEY)
+			.map(|relative| gem_root(gem).join(relative));
+
+		if let (Some(version), Some(version_file)) = (version, &version_file) {
+			let contents = read_text(version_file)?;
+			let rendered = update_version_constant_text(&contents, VERSION_CONSTANT, version);
+			if rendered != contents {
+				updates.push((version_file.clone(), rendered));
+			}
+		}
+
+		let gemspec_version = version.filter(|_| version_file.is_none());
+		let moves_dependency = gem.declared_dependencies.iter().any(|dependency| {
+			dependency.source_field.as_deref() != Some(GEMFILE)
+				&& released_gem_versions.contains_key(&dependency.name)
+		});
+		if gemspec_version.is_none() && !moves_dependency {
+			continue;
+		}
+
+		let contents = read_text(&gem.manifest_path)?;
+		let rendered = update_gemspec_text(
+			&contents,
+			gemspec_version.map(String::as_str),
+			&released_gem_versions,
+		);
+		if rendered != contents {
+			updates.push((gem.manifest_path.clone(), rendered));
+		}
+	}
+
+	Ok(updates)
+}
+
+fn read_text(path: &Path) -> MonochangeResult<String> {
+	fs::read_to_string(path)
+		.map_err(|error| MonochangeError::Io(format!("failed to read {}: {error}", path.display())))
+}
+
+fn gem_root(package: &PackageRecord) -> PathBuf {
+	package
+		.manifest_path
+		.parent()
+		.map_or_else(|| package.workspace_root.clone(), Path::to_path_buf)
+}
+
+fn gemspec_name_regex() -> &'static Regex {
+	static GEMSPEC_NAME_RE: OnceLock<Regex> = OnceLock::new();
+	GEMSPEC_NAME_RE.get_or_init(|| {
+		Regex::new(r#"(?m)^[ \t]*\w+\.name[ \t]*=[ \t]*["']([^"'\n]+)["']"#)
+			.unwrap_or_else(|error| panic!("gemspec name regex should compile: {error}"))
+	})
+}
+
+fn gemspec_version_regex() -> &'static Regex {
+	static GEMSPEC_VERSION_RE: OnceLock<Regex> = OnceLock::new();
+	GEMSPEC_VERSION_RE.get_or_init(|| {
+		Regex::new(r#"(?m)^[ \t]*\w+\.version[ \t]*=[ \t]*(["'])([^"'\n]+)["']"#)
+			.unwrap_or_else(|error| panic!("gemspec version regex should compile: {error}"))
+	})
+}
+
+fn dependency_regex() -> &'static Regex {
+	static DEPENDENCY_RE: OnceLock<Regex> = OnceLock::new();
+	DEPENDENCY_RE.get_or_init(|| {
+		Regex::new(r#"(?m)^[ \t]*\w+\.(?P<method>add_(?:runtime_|development_)?dependency)[ \t]*\(?[ \t]*["'](?P<name>[^"'\n]+)["'](?P<arguments>[^\n)]*)"#)
+			.unwrap_or_else(|error| panic!("gemspec dependency regex should compile: {error}"))
+	})
+}
+
+fn gemfile_path_regex() -> &'static Regex {
+	static GEMFILE_PATH_RE: OnceLock<Regex> = OnceLock::new();
+	GEMFILE_PATH_RE.get_or_init(|| {
+		Regex::new(r#"(?m)^[ \t]*gem[ \t]+["'](?P<name>[^"'\n]+)["'][^\n#]*?\bpath(?::[ \t]*|[ \t]*=>[ \t]*)["'](?P<path>[^"'\n]+)["']"#)
+			.unwrap_or_else(|error| panic!("Gemfile path regex should compile: {error}"))
+	})
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Ruby gems rooted at `root`.
+pub fn discover_ruby_gems(root: &Path) -> MonochangeResult<AdapterDiscovery> {
+	discover_ruby_gems_with_filter(root, &DiscoveryPathFilter::new(root))
+}
+
+#[tracing::instrument(skip_all)]
+#[must_use = "the discovery result must be checked"]
+/// Discover Ruby gems rooted at `root` without parsing gemspecs outside
+/// `filter`'s discovery scope.
+pub fn discover_ruby_gems_with_filter(
+	root: &Path,
+	filter: &DiscoveryPathFilter,
+) -> MonochangeResult<AdapterDiscovery> {
+	let walk = find_all_gemspecs(root, filter);
+	let mut skipped = walk.skipped;
+	let mut packages = Vec::new();
+	let mut warnings = Vec::new();
+
+	for manifest_path in
+		filter.retain_manifests_in_scope(Ecosystem::Ruby, walk.manifests, &mut skipped)
+	{
+		match parse_gemspec(&manifest_path, root) {
+			Ok(package) => packages.push(package),
+			Err(error) => {
+				warnings.push(format!("skipped {}: {error}", manifest_path.display()));
+			}
+		}
+	}
+
+	packages.sort_by(|left, right| left.id.cmp(&right.id));
+	packages.dedup_by(|left, right| left.id == right.id);
+	tracing::debug!(packages = packages.len(), "discovered ruby gems");
+
+	Ok(AdapterDiscovery {
+		packages,
+		warnings,
+		skipped,
+	})
+}
+
+/// Load one explicitly configured gem without walking the repo.
+///
+/// `package_path` may name the gemspec itself or the directory that holds it.
+#[must_use = "the package result must be checked"]
+pub fn load_configured_ruby_gem(
+	root: &Path,
+	package_path: &Path,
+) -> MonochangeResult<Option<PackageRecord>> {
+	let manifest_path = if is_gemspec(package_path) {
+		Some(package_path.to_path_buf())
+	} else {
+		gemspecs_in_dir(package_path).into_iter().next()
+	};
+	manifest_path
+		.map(|manifest_path| parse_gemspec(&manifest_path, root))
+		.transpose()
+}
+
+/// Parse one gemspec into a package record.
+///
+/// The gem name falls back to the file stem when `spec.name` is not a string
+/// literal. A version that is not a literal is read from the `VERSION`
+/// constant in `lib/**/version.rb`.
+pub fn parse_gemspec(
+	manifest_path: &Path,
+	workspace_root: &Path,
+) -> MonochangeResult<PackageRecord> {
+	let contents = read_text(manifest_path)?;
+	let gem_dir = manifest_path.parent().unwrap_or(workspace_root);
+	let name = gemspec_name_regex()
+		.captures(&contents)
+		.and_then(|captures| captures.get(1))
+		.map(|name| name.as_str().to_string())
+		.or_else(|| {
+			manifest_path
+				.file_stem()
+				.and_then(|stem| stem.to_str())
+				.map(str::to_string)
+		})
+		.ok_or_else(|| {
+			MonochangeError::Discovery(format!(
+				"failed to read a gem name from {}",
+				manifest_path.display()
+			))
+		})?;
+
+	let literal_version = gemspec_version_regex()
+		.captures(&contents)
+		.and_then(|captures| captures.get(2))
+		.map(|version| version.as_str().to_string());
+	let version_file = if literal_version.is_some() {
+		None
+	} else {
+		find_version_file(gem_dir, &name)
+	};
+	let version = match (&literal_version, &version_file) {
+		(Some(version), _) => Some(version.clone()),
+		(None, Some(version_file)) => {
+			let version_source = read_text(version_file)?;
+			parse_version_constant(&version_source, VERSION_CONSTANT).map(str::to_string)
+		}
+		(None, None) => None,
+	};
+
+	let mut package = PackageRecord::new(
+		Ecosystem::Ruby,
+		name,
+		normalize_path(manifest_path),
+		normalize_path(workspace_root),
+		version.and_then(|version| parse_gem_version(&version)),
+		PublishState::Public,
+	);
+	package.declared_dependencies = parse_gemspec_dependencies(&contents);
+	let gemfile = gem_dir.join(GEMFILE);
+	if gemfile.is_file() {
+		let gemfile_contents = read_text(&gemfile)?;
+		for dependency in parse_gemfile_path_dependencies(&gemfile_contents) {
+			let already_declared = package
+				.declared_dependencies
+				.iter()
+				.any(|declared| declared.name == dependency.name);
+			if !already_declared && dependency.name != package.name {
+				package.declared_dependencies.push(dependency);
+			}
+		}
+	}
+	if let Some(relative) = version_file
+		.as_deref()
+		.and_then(|version_file| version_file.strip_prefix(gem_dir).ok())
+	{
+		package.metadata.insert(
+			VERSION_FILE_METADATA_KEY.to_string(),
+			relative.display().to_string(),
+		);
+	}
+	Ok(package)
+}
+
+/// Parse a `Gem::Version` string into semver, padding missing segments so
+/// `1.2` reads as `1.2.0`.
+fn parse_gem_version(version: &str) -> Option<Version> {
+	Version::parse(version).ok().or_else(|| {
+		let segments = segments_of(version)?;
+		let segment = |index: usize| segments.get(index).copied().unwrap_or(0);
+		(segments.len() <= 3).then(|| Version::new(segment(0), segment(1), segment(2)))
+	})
+}
+
+fn parse_gemspec_dependencies(contents: &str) -> Vec<PackageDependency> {
+	dependency_regex()
+		.captures_iter(contents)
+		.filter_map(|captures| {
+			let method = captures.name("method")?.as_str();
+			let arguments = captures.name("arguments")?;
+			let requirements = requirement_literals(arguments.as_str(), 0)
+				.iter()
+				.map(|literal| literal.value.trim().to_string())
+				.collect::<Vec<_>>();
+			Some(PackageDependency {
+				name: captures.name("name")?.as_str().to_string(),
+				kind: if method == "add_development_dependency" {
+					DependencyKind::Development
+				} else {
+					DependencyKind::Runtime
+				},
+				version_constraint: (!requirements.is_empty()).then(|| requirements.join(", ")),
+				optional: false,
+				source_field: Some(method.to_string()),
+			})
+		})
+		.collect()
+}
+
+/// Gemfile entries with `path:` point at sibling gems that Bundler loads for
+/// development and tests, so they become development edges.
+fn parse_gemfile_path_dependencies(contents: &str) -> Vec<PackageDependency> {
+	gemfile_path_regex()
+		.captures_iter(contents)
+		.filter_map(|captures| {
+			Some(PackageDependency {
+				name: captures.name("name")?.as_str().to_string(),
+				kind: DependencyKind::Development,
+				version_constraint: None,
+				optional: false,
+				source_field: Some(GEMFILE.to_string()),
+			})
+		})
+		.collect()
+}
+
+/// Find the file holding the gem's `VERSION` constant: the conventional
+/// `lib/<gem/path>/version.rb` first, then any `version.rb` under `lib`.
+fn find_version_file(gem_dir: &Path, name: &str) -> Option<PathBuf> {
+	let lib = gem_dir.join("lib");
+	let conventional = [
+		lib.join(name.replace('-', "/")).join("version.rb"),
+		lib.join(name).join("version.rb"),
+	];
+	let mut discovered = WalkDir::new(&lib)
+		.into_iter()
+		.filter_map(Result::ok)
+		.filter(|entry| entry.file_name() == "version.rb")
+		.map(DirEntry::into_path)
+		.collect::<Vec<_>>();
+	discovered.sort();
+
+	conventional.into_iter().chain(discovered).find(|path| {
+		fs::read_to_string(path)
+			.is_ok_and(|contents| parse_version_constant(&contents, VERSION_CONSTANT).is_some())
+	})
+}
+
+/// Validate that a Ruby versioned file contains what it will rewrite.
+///
+/// Version files need each configured constant, `VERSION` by default, to be
+/// assigned a string literal.
+pub fn validate_versioned_file(
+	full_path: &Path,
+	display_path: &str,
+	custom_fields: Option<&[String]>,
+) -> MonochangeResult<()> {
+	let contents = fs::read_to_string(full_path).map_err(|error| {
+		MonochangeError::Config(format!(
+			"versioned file `{display_path}` is not readable: {error}"
+		))
+	})?;
+	if supported_versioned_file_kind(full_path) != Some(RubyVersionedFileKind::VersionFile) {
+		return Ok(());
+	}
+	let default_constants = [VERSION_CONSTANT.to_string()];
+	for constant in custom_fields.unwrap_or(&default_constants) {
+		if parse_version_constant(&contents, constant).is_none() {
+			return Err(MonochangeError::Config(format!(
+				"versioned file `{display_path}` does not assign a string to `{constant}`"
+			)));
+		}
+	}
+
+	Ok(())
+}
+
+/// Return the default dependency-version prefix for this ecosystem.
+///
+/// Gem requirements keep the operator they already use, so there is no
+/// prefix to add.
+#[must_use]
+pub fn default_dependency_version_prefix() -> &'static str {
+	""
+}
+
+/// Return the gemspec methods that usually declare dependency versions.
+#[must_use]
+pub fn default_dependency_fields() -> &'static [&'static str] {
+	&["add_dependency", "add_runtime_dependency"]
+}
+
+fn is_gemspec(path: &Path) -> bool {
+	path.extension()
+		.is_some_and(|extension| extension == "gemspec")
+}
+
+fn gemspecs_in_dir(dir: &Path) -> Vec<PathBuf> {
+	let mut gemspecs = fs::read_dir(dir)
+		.into_iter()
+		.flatten()
+		.filter_map(Result::ok)
+		.map(|entry| entry.path())
+		.filter(|path| is_gemspec(path) && path.is_file())
+		.collect::<Vec<_>>();
+	gemspecs.sort();
+	gemspecs
+}
+
+fn find_all_gemspecs(root: &Path, filter: &DiscoveryPathFilter) -> ManifestWalk {
+	filter.walk_manifests(
+		Ecosystem::Ruby,
+		root,
+		|entry| filter.should_descend(entry.path()) && !is_installed_gems_dir(entry),
+		|entry| entry.file_type().is_file() && is_gemspec(entry.path()),
+	)
+}
+
+/// Skip directories where Bundler installs or vendors third-party gems, which
+/// carry their own gemspecs.
+fn is_installed_gems_dir(entry: &DirEntry) -> bool {
+	entry.file_type().is_dir() && matches!(entry.file_name().to_str(), Some("vendor" | ".bundle"))
+}
+
+#[cfg(test)]
+#[path = "__tests__/lib_tests.rs"]
+mod tests;
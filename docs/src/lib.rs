@@ -17,11 +17,14 @@ doc_comment::doctest!("../../crates/monochange_forgejo/readme.md");
 doc_comment::doctest!("../../crates/monochange_gitea/readme.md");
 doc_comment::doctest!("../../crates/monochange_gitlab/readme.md");
 doc_comment::doctest!("../../crates/monochange_go/readme.md");
+doc_comment::doctest!("../../crates/monochange_helm/readme.md");
 doc_comment::doctest!("../../crates/monochange_hosting/readme.md");
+doc_comment::doctest!("../../crates/monochange_jvm/readme.md");
 doc_comment::doctest!("../../crates/monochange_lint/readme.md");
 doc_comment::doctest!("../../crates/monochange_linting/readme.md");
 doc_comment::doctest!("../../crates/monochange_publish/readme.md");
 doc_comment::doctest!("../../crates/monochange_python/readme.md");
+doc_comment::doctest!("../../crates/monochange_ruby/readme.md");
 doc_comment::doctest!("../../crates/monochange_schema/readme.md");
 doc_comment::doctest!("../../crates/monochange_telemetry/readme.md");
 
@@ -0,0 +1,7 @@
+pub fn parse(input: &str) -> usize {
+	input.len()
+}
+
+pub fn render(value: usize) -> String {
+	value.to_string()
+}
@@ -0,0 +1,34 @@
+// Package greeter builds greetings.
+package greeter
+
+// Greeter renders a greeting for a name.
+type Greeter interface {
+	Greet(name string) string
+	Locale() string
+}
+
+// Options configures a Formal greeter.
+type Options struct {
+	Title  string
+	Locale string
+}
+
+// Formal greets people by title.
+type Formal struct {
+	Options
+}
+
+// Greet implements Greeter.
+func (f *Formal) Greet(name string) string {
+	return f.Title + " " + name
+}
+
+// Locale implements Greeter.
+func (f *Formal) Locale() string {
+	return f.Options.Locale
+}
+
+// New returns a greeter for the given options.
+func New(options Options) Greeter {
+	return &Formal{Options: options}
+}
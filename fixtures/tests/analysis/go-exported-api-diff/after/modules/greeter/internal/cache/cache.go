@@ -0,0 +1,9 @@
+package cache
+
+// Get is only importable inside the greeter module.
+func Get(key string) string {
+	return key
+}
+
+// Set is only importable inside the greeter module.
+func Set(key, value string) {}
@@ -0,0 +1,32 @@
+// Package greeter builds greetings.
+package greeter
+
+// Greeter renders a greeting for a name.
+type Greeter interface {
+	Greet(name string) string
+}
+
+// Options configures a Formal greeter.
+type Options struct {
+	Title string
+}
+
+// Formal greets people by title.
+type Formal struct {
+	Options
+}
+
+// Greet implements Greeter.
+func (f *Formal) Greet(name string) string {
+	return f.Title + " " + name
+}
+
+// Farewell says goodbye.
+func Farewell(name string) string {
+	return "bye " + name
+}
+
+// New returns the default greeter.
+func New() Greeter {
+	return &Formal{}
+}
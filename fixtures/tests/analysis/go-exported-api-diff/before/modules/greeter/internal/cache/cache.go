@@ -0,0 +1,6 @@
+package cache
+
+// Get is only importable inside the greeter module.
+func Get(key string) string {
+	return key
+}
@@ -0,0 +1,5 @@
+"""Friendly greetings."""
+
+from greeter.core import Greeter, greet, shout
+
+__all__ = ["Greeter", "greet", "shout"]
@@ -0,0 +1,20 @@
+class Greeter:
+    def __init__(self, name: str, *, locale: str) -> None:
+        self.name = name
+        self.locale = locale
+
+    def greet(self, punctuation: str = "!", *, loud: bool = False) -> str:
+        message = f"Hello, {self.name}{punctuation}"
+        return message.upper() if loud else message
+
+
+def greet(name: str, locale: str = "en") -> str:
+    return Greeter(name, locale=locale).greet()
+
+
+def shout(name: str) -> str:
+    return greet(name).upper()
+
+
+def _format(name: str, width: int) -> str:
+    return name.title().ljust(width)
@@ -0,0 +1,5 @@
+"""Friendly greetings."""
+
+from greeter.core import Greeter, greet
+
+__all__ = ["Greeter", "greet"]
@@ -0,0 +1,17 @@
+class Greeter:
+    def __init__(self, name: str) -> None:
+        self.name = name
+
+    def greet(self, punctuation: str = "!") -> str:
+        return f"Hello, {self.name}{punctuation}"
+
+    def farewell(self) -> str:
+        return f"Goodbye, {self.name}"
+
+
+def greet(name: str) -> str:
+    return Greeter(name).greet()
+
+
+def _format(name: str) -> str:
+    return name.title()
@@ -0,0 +1 @@
+export const core = true;
@@ -0,0 +1,14 @@
+module github.com/acme/platform/api
+
+go 1.22
+
+require (
+	github.com/gin-gonic/gin v1.9.1
+	// shared helpers live next door
+	github.com/acme/platform/shared v1.2.0
+
+	golang.org/x/sync v0.7.0
+	github.com/stretchr/testify v1.9.0 // indirect
+)
+
+replace github.com/acme/platform/shared => ../shared
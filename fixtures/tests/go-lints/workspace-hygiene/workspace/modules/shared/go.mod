@@ -0,0 +1,8 @@
+module github.com/acme/platform/shared
+
+go 1.22
+
+require (
+	github.com/google/uuid v1.6.0
+	golang.org/x/text v0.15.0
+)
@@ -0,0 +1,3 @@
+module github.com/acme/platform/tools
+
+require github.com/spf13/cobra v1.8.0
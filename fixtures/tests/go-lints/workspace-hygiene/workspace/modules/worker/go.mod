@@ -0,0 +1,10 @@
+module github.com/acme/platform/worker
+
+go 1.21 // pinned for the old runner
+
+require github.com/acme/platform/shared v1.2.0
+
+replace (
+	github.com/acme/platform/shared => ../shared
+	golang.org/x/net => golang.org/x/net v0.25.0
+)
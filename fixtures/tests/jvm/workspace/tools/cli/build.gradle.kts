@@ -0,0 +1,10 @@
+plugins {
+    kotlin("jvm")
+    `maven-publish`
+}
+
+dependencies {
+    implementation(project(":plugin"))
+    implementation("com.acme:core:2.1.0")
+    testImplementation("org.junit.jupiter:junit-jupiter:5.10.2")
+}
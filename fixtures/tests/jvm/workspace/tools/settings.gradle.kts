@@ -0,0 +1,3 @@
+rootProject.name = "tools"
+
+include(":cli", ":plugin")
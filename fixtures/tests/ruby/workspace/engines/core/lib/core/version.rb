@@ -0,0 +1,3 @@
+module Core
+  VERSION = "1.2.0".freeze
+end